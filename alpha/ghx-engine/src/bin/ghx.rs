@@ -0,0 +1,391 @@
+#[cfg(target_arch = "wasm32")]
+fn main() {}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() {
+    if let Err(err) = native::run() {
+        eprintln!("ghx error: {err}");
+        std::process::exit(1);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod native {
+    use ghx_engine::Engine;
+    use ghx_engine::export;
+    use ghx_engine::graph::Graph;
+    use ghx_engine::graph::value::Value;
+    use ghx_engine::parse::{gh_binary, ghx_xml};
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    const USAGE: &str = r#"ghx (ghx-engine)
+
+USAGE:
+  ghx inputs <file.ghx|file.gh>
+  ghx eval <file.ghx|file.gh> [options]
+  ghx coverage <file.ghx|file.gh> [options]
+
+COMMANDS:
+  inputs             Print the sliders, toggles and value lists as JSON
+  eval               Evaluate the definition and export its geometry
+  coverage           List every node as resolved, not implemented or missing
+
+OPTIONS (eval):
+  --set <name>=<v>   Override an input by nickname or id; <v> is a number,
+                     `true` or `false` (value lists take the item index).
+                     May be repeated; applied after --inputs
+  --inputs <path>    JSON object mapping input names to numbers/booleans
+  --obj <path>       Write geometry as Wavefront OBJ
+  --gltf <path>      Write geometry as glTF 2.0 with an embedded buffer
+  --json <path>      Write geometry as JSON
+  --report <path>    Write the structured error/warning report as JSON
+  --ghx <path>       Write the definition, with the applied input values,
+                     back out as a GHX archive
+  --overwrite        Overwrite existing output files
+  --allow-errors     Exit with status 0 even if components reported errors
+  -h, --help         Show this help
+
+OPTIONS (coverage):
+  --nodelist <dir>   Directory with nodelist/*.json files used to name
+                     components the engine does not know
+                     (default: the first `nodelist` directory found in
+                     the current directory or one of its parents)
+  --json <path>      Write the coverage report as JSON
+  --overwrite        Overwrite an existing JSON file
+  --strict           Exit with status 1 unless every node is resolved
+
+Evaluation errors are printed to stderr; without --allow-errors they make
+the command exit with status 1 (after all outputs have been written).
+"#;
+
+    pub fn run() -> Result<(), String> {
+        let args: Vec<String> = std::env::args().skip(1).collect();
+        let mut args = Args::new(args);
+
+        let Some(command) = args.next() else {
+            print_usage();
+            return Ok(());
+        };
+
+        match command.as_str() {
+            "inputs" => cmd_inputs(&mut args),
+            "eval" => cmd_eval(&mut args),
+            "coverage" => cmd_coverage(&mut args),
+            "-h" | "--help" | "help" => {
+                print_usage();
+                Ok(())
+            }
+            other => Err(format!("unknown command `{other}`\n\n{USAGE}")),
+        }
+    }
+
+    fn print_usage() {
+        println!("{USAGE}");
+    }
+
+    fn cmd_inputs(args: &mut Args) -> Result<(), String> {
+        let path = PathBuf::from(args.next().ok_or("missing definition file")?);
+        if let Some(extra) = args.next() {
+            return Err(format!("unexpected argument `{extra}`\n\n{USAGE}"));
+        }
+
+        let engine = load_engine(&path)?;
+        println!("{}", engine.input_controls_json()?);
+        Ok(())
+    }
+
+    fn cmd_eval(args: &mut Args) -> Result<(), String> {
+        let path = PathBuf::from(args.next().ok_or("missing definition file")?);
+
+        let mut inputs_path: Option<PathBuf> = None;
+        let mut overrides: Vec<(String, Value)> = Vec::new();
+        let mut obj_path: Option<PathBuf> = None;
+        let mut gltf_path: Option<PathBuf> = None;
+        let mut json_path: Option<PathBuf> = None;
+        let mut report_path: Option<PathBuf> = None;
+        let mut ghx_path: Option<PathBuf> = None;
+        let mut overwrite = false;
+        let mut allow_errors = false;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--set" => overrides.push(parse_assignment(&args.value("--set")?)?),
+                "--inputs" => inputs_path = Some(PathBuf::from(args.value("--inputs")?)),
+                "--obj" => obj_path = Some(PathBuf::from(args.value("--obj")?)),
+                "--gltf" => gltf_path = Some(PathBuf::from(args.value("--gltf")?)),
+                "--json" => json_path = Some(PathBuf::from(args.value("--json")?)),
+                "--report" => report_path = Some(PathBuf::from(args.value("--report")?)),
+                "--ghx" => ghx_path = Some(PathBuf::from(args.value("--ghx")?)),
+                "--overwrite" => overwrite = true,
+                "--allow-errors" => allow_errors = true,
+                "-h" | "--help" => {
+                    print_usage();
+                    return Ok(());
+                }
+                other => return Err(format!("unknown option `{other}`\n\n{USAGE}")),
+            }
+        }
+
+        let mut engine = load_engine(&path)?;
+
+        let mut assignments = match inputs_path.as_deref() {
+            Some(path) => read_inputs_file(path)?,
+            None => Vec::new(),
+        };
+        assignments.extend(overrides);
+        for (name, value) in assignments {
+            engine
+                .update_input_value(&name, value)
+                .map_err(|err| format!("input `{name}`: {err}"))?;
+        }
+
+        engine.evaluate_graph()?;
+        let graph = engine.graph().ok_or("no graph loaded")?;
+        let result = engine
+            .last_result()
+            .ok_or("evaluation produced no result")?;
+
+        if let Some(path) = obj_path.as_deref() {
+            write_text_file(path, &export::to_obj(graph, result), overwrite)?;
+            eprintln!("wrote {}", path.display());
+        }
+        if let Some(path) = gltf_path.as_deref() {
+            write_text_file(path, &export::to_gltf(graph, result), overwrite)?;
+            eprintln!("wrote {}", path.display());
+        }
+        if let Some(path) = json_path.as_deref() {
+            write_text_file(path, &export::to_json(graph, result), overwrite)?;
+            eprintln!("wrote {}", path.display());
+        }
+
+        if let Some(path) = report_path.as_deref() {
+            let report = serde_json::to_string_pretty(&engine.error_report())
+                .map_err(|e| format!("serialize report: {e}"))?;
+            write_text_file(path, &report, overwrite)?;
+            eprintln!("wrote {}", path.display());
+        }
+        if let Some(path) = ghx_path.as_deref() {
+            write_text_file(path, &engine.to_ghx()?, overwrite)?;
+            eprintln!("wrote {}", path.display());
+        }
+
+        for warning in &result.warnings {
+            eprintln!("warning: {warning}");
+        }
+        for error in &result.errors {
+            eprintln!("error: {error}");
+        }
+        eprintln!(
+            "{}: nodes={} geometry={} warnings={} errors={}",
+            path.display(),
+            graph.node_count(),
+            result.geometry.len(),
+            result.warnings.len(),
+            result.errors.len()
+        );
+
+        if !result.errors.is_empty() && !allow_errors {
+            return Err(format!("{} evaluation error(s)", result.errors.len()));
+        }
+        Ok(())
+    }
+
+    fn cmd_coverage(args: &mut Args) -> Result<(), String> {
+        let path = PathBuf::from(args.next().ok_or("missing definition file")?);
+
+        let mut nodelist_dir: Option<PathBuf> = None;
+        let mut json_path: Option<PathBuf> = None;
+        let mut overwrite = false;
+        let mut strict = false;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--nodelist" => nodelist_dir = Some(PathBuf::from(args.value("--nodelist")?)),
+                "--json" => json_path = Some(PathBuf::from(args.value("--json")?)),
+                "--overwrite" => overwrite = true,
+                "--strict" => strict = true,
+                "-h" | "--help" => {
+                    print_usage();
+                    return Ok(());
+                }
+                other => return Err(format!("unknown option `{other}`\n\n{USAGE}")),
+            }
+        }
+
+        let mut engine = load_engine(&path)?;
+        if let Some(dir) = nodelist_dir.or_else(default_nodelist_dir) {
+            load_nodelist(&mut engine, &dir)?;
+        }
+
+        let report = engine.coverage_report()?;
+
+        for node in &report.nodes {
+            let label = node
+                .nickname
+                .as_deref()
+                .or(node.name.as_deref())
+                .unwrap_or("<unnamed>");
+            let component = match (node.component, node.catalog.as_ref()) {
+                (Some(component), _) => component.to_owned(),
+                (None, Some(entry)) => {
+                    format!("{} ({}/{})", entry.name, entry.category, entry.subcategory)
+                }
+                (None, None) => "unknown component".to_owned(),
+            };
+            println!(
+                "{:>5}  {:<15}  {:<24}  {:<38}  {component}",
+                node.node_id,
+                node.status.as_str(),
+                label,
+                node.guid.as_deref().unwrap_or("-"),
+            );
+        }
+
+        if let Some(path) = json_path.as_deref() {
+            let json = serde_json::to_string_pretty(&report)
+                .map_err(|e| format!("serialize coverage: {e}"))?;
+            write_text_file(path, &json, overwrite)?;
+            eprintln!("wrote {}", path.display());
+        }
+
+        eprintln!(
+            "{}: nodes={} resolved={} not_implemented={} missing={}",
+            path.display(),
+            report.nodes.len(),
+            report.resolved,
+            report.not_implemented,
+            report.missing
+        );
+
+        if strict && !report.is_complete() {
+            return Err(format!(
+                "{} node(s) not covered",
+                report.not_implemented + report.missing
+            ));
+        }
+        Ok(())
+    }
+
+    fn load_nodelist(engine: &mut Engine, dir: &Path) -> Result<(), String> {
+        engine
+            .catalog_mut()
+            .add_nodelist_dir(dir)
+            .map(|_| ())
+            .map_err(|e| format!("read {}: {e}", dir.display()))
+    }
+
+    fn default_nodelist_dir() -> Option<PathBuf> {
+        let cwd = std::env::current_dir().ok()?;
+        cwd.ancestors()
+            .map(|dir| dir.join("nodelist"))
+            .find(|dir| dir.is_dir())
+    }
+
+    fn load_engine(path: &Path) -> Result<Engine, String> {
+        let graph = read_graph(path)?;
+        let mut engine = Engine::new();
+        engine
+            .load_graph(graph)
+            .map_err(|err| format!("{}: {err}", path.display()))?;
+        Ok(engine)
+    }
+
+    fn read_graph(path: &Path) -> Result<Graph, String> {
+        let is_binary = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("gh"));
+
+        let parsed = if is_binary {
+            let bytes = fs::read(path).map_err(|e| format!("read {}: {e}", path.display()))?;
+            gh_binary::parse_bytes(&bytes)
+        } else {
+            let xml =
+                fs::read_to_string(path).map_err(|e| format!("read {}: {e}", path.display()))?;
+            ghx_xml::parse_str(&xml)
+        };
+        parsed.map_err(|e| format!("parse {}: {e}", path.display()))
+    }
+
+    fn read_inputs_file(path: &Path) -> Result<Vec<(String, Value)>, String> {
+        let text = fs::read_to_string(path).map_err(|e| format!("read {}: {e}", path.display()))?;
+        let json: serde_json::Value =
+            serde_json::from_str(&text).map_err(|e| format!("parse {}: {e}", path.display()))?;
+        let object = json
+            .as_object()
+            .ok_or_else(|| format!("{}: expected a JSON object", path.display()))?;
+
+        object
+            .iter()
+            .map(|(name, value)| {
+                let value = if let Some(number) = value.as_f64() {
+                    Value::Number(number)
+                } else if let Some(flag) = value.as_bool() {
+                    Value::Boolean(flag)
+                } else {
+                    return Err(format!(
+                        "{}: input `{name}` must be a number or boolean",
+                        path.display()
+                    ));
+                };
+                Ok((name.clone(), value))
+            })
+            .collect()
+    }
+
+    fn parse_assignment(text: &str) -> Result<(String, Value), String> {
+        let (name, raw) = text
+            .split_once('=')
+            .ok_or_else(|| format!("expected <name>=<value>, got `{text}`"))?;
+
+        let raw = raw.trim();
+        let value = match raw {
+            "true" => Value::Boolean(true),
+            "false" => Value::Boolean(false),
+            _ => Value::Number(
+                raw.parse::<f64>()
+                    .map_err(|_| format!("invalid value `{raw}` for input `{name}`"))?,
+            ),
+        };
+        Ok((name.trim().to_owned(), value))
+    }
+
+    fn write_text_file(path: &Path, text: &str, overwrite: bool) -> Result<(), String> {
+        if path.exists() && !overwrite {
+            return Err(format!(
+                "refusing to overwrite existing file {} (use --overwrite)",
+                path.display()
+            ));
+        }
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("create dir {}: {e}", parent.display()))?;
+        }
+        fs::write(path, text).map_err(|e| format!("write {}: {e}", path.display()))
+    }
+
+    struct Args {
+        args: Vec<String>,
+        pos: usize,
+    }
+
+    impl Args {
+        fn new(args: Vec<String>) -> Self {
+            Self { args, pos: 0 }
+        }
+
+        fn next(&mut self) -> Option<String> {
+            let arg = self.args.get(self.pos)?.clone();
+            self.pos += 1;
+            Some(arg)
+        }
+
+        fn value(&mut self, flag: &str) -> Result<String, String> {
+            self.next()
+                .ok_or_else(|| format!("missing value for {flag}"))
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! Genereert `src/components/pin_table.rs` opnieuw uit `nodelist/*.json`.
+//!
+//! Gebruik: `cargo run --bin pin_table [nodelist-map]`. Zonder argument wordt
+//! de `nodelist`-map van de repository gebruikt, gezocht vanaf de map van het
+//! crate-manifest.
+
+#[cfg(target_arch = "wasm32")]
+fn main() {}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() {
+    if let Err(err) = native::run() {
+        eprintln!("pin_table error: {err}");
+        std::process::exit(1);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod native {
+    use ghx_engine::catalog::Catalog;
+    use ghx_engine::components::ComponentRegistry;
+    use ghx_engine::components::schema::render_pin_table;
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+
+    pub fn run() -> Result<(), String> {
+        let manifest_dir = env::var_os("CARGO_MANIFEST_DIR")
+            .map(PathBuf::from)
+            .ok_or("run via `cargo run --bin pin_table`")?;
+        let nodelist_dir = env::args_os()
+            .nth(1)
+            .map_or_else(|| manifest_dir.join("../../nodelist"), PathBuf::from);
+
+        let mut catalog = Catalog::new();
+        catalog
+            .add_nodelist_dir(&nodelist_dir)
+            .map_err(|e| format!("read {}: {e}", nodelist_dir.display()))?;
+
+        let table = render_pin_table(&ComponentRegistry::default(), &catalog);
+        let path = manifest_dir.join("src/components/pin_table.rs");
+        fs::write(&path, table).map_err(|e| format!("write {}: {e}", path.display()))?;
+        eprintln!("wrote {} ({} nodelist entries)", path.display(), catalog.len());
+        Ok(())
+    }
+}
@@ -0,0 +1,251 @@
+//! Catalogus van bekende Grasshopper-componenten uit de `nodelist/*.json`
+//! bestanden.
+//!
+//! De catalogus wordt gebruikt om GUIDs die de [`ComponentRegistry`] niet kent
+//! toch een naam en categorie te geven. Een deel van de nodelist-bestanden is
+//! geen geldige JSON (lege `inputs`/`outputs` arrays met losse komma's, of
+//! arrays die nooit gesloten worden), dus er wordt niet met een JSON-parser
+//! gelezen. Per entry worden de kopvelden `category`, `subcategory`, `name`,
+//! `nickname` en `guid` gezocht, die in elk bestand in die volgorde voorkomen.
+//! De tekst tot de volgende kop hoort bij die entry; daarin staan de pinnen
+//! vóór `"outputs"` als inputs en de pinnen erna als outputs.
+//!
+//! [`ComponentRegistry`]: crate::components::ComponentRegistry
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::{fs, io};
+
+use regex::Regex;
+use serde::Serialize;
+
+/// Eén component uit de nodelist.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CatalogEntry {
+    pub guid: String,
+    pub name: String,
+    pub nickname: String,
+    pub category: String,
+    pub subcategory: String,
+    #[serde(skip)]
+    pub inputs: Vec<CatalogPin>,
+    #[serde(skip)]
+    pub outputs: Vec<CatalogPin>,
+}
+
+/// Eén pin van een nodelist-entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CatalogPin {
+    pub name: String,
+    pub nickname: String,
+    /// `item`, `list` of `tree`.
+    pub access: String,
+    /// Grasshopper-typenaam (`Point`, `Number`, …) als de nodelist die
+    /// vermeldt.
+    pub kind: Option<String>,
+    pub optional: bool,
+}
+
+/// Alle ingelezen nodelist-entries, opzoekbaar op GUID.
+#[derive(Debug, Clone, Default)]
+pub struct Catalog {
+    entries: HashMap<String, CatalogEntry>,
+}
+
+impl Catalog {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Leest de entries uit de tekst van één nodelist-bestand en geeft het
+    /// aantal gevonden entries terug. Een GUID die al bekend is wordt
+    /// overschreven.
+    pub fn add_nodelist_json(&mut self, text: &str) -> usize {
+        let header = Regex::new(
+            r#""category"\s*:\s*"([^"]*)"\s*,\s*"subcategory"\s*:\s*"([^"]*)"\s*,\s*"name"\s*:\s*"([^"]*)"\s*,\s*"nickname"\s*:\s*"([^"]*)"\s*,\s*"guid"\s*:\s*"([^"]*)""#,
+        )
+        .expect("geldige regex");
+        let pin = Regex::new(
+            r#"\{\s*"name"\s*:\s*"((?:[^"\\]|\\.)*)"\s*,\s*"nickname"\s*:\s*"((?:[^"\\]|\\.)*)"\s*,\s*"access"\s*:\s*"(\w+)"(?:\s*,\s*"type"\s*:\s*"((?:[^"\\]|\\.)*)")?(?:\s*,\s*"description"\s*:\s*"(?:[^"\\]|\\.)*")?(?:\s*,\s*"optional"\s*:\s*(true|false))?\s*\}"#,
+        )
+        .expect("geldige regex");
+
+        let headers: Vec<_> = header.captures_iter(text).collect();
+        for (index, captures) in headers.iter().enumerate() {
+            let start = captures.get(0).map_or(0, |m| m.end());
+            let end = headers
+                .get(index + 1)
+                .and_then(|next| next.get(0))
+                .map_or(text.len(), |m| m.start());
+            let body = &text[start..end];
+            let (inputs, outputs) = match body.find("\"outputs\"") {
+                Some(split) => (&body[..split], &body[split..]),
+                None => (body, ""),
+            };
+
+            let entry = CatalogEntry {
+                category: captures[1].to_owned(),
+                subcategory: captures[2].to_owned(),
+                name: captures[3].to_owned(),
+                nickname: captures[4].to_owned(),
+                guid: captures[5].to_owned(),
+                inputs: parse_pins(&pin, inputs),
+                outputs: parse_pins(&pin, outputs),
+            };
+            self.entries.insert(normalize_guid(&entry.guid), entry);
+        }
+        headers.len()
+    }
+
+    /// Leest alle `*.json` bestanden uit een nodelist-map, in alfabetische
+    /// volgorde, en geeft het totale aantal gevonden entries terug.
+    pub fn add_nodelist_dir(&mut self, dir: &Path) -> io::Result<usize> {
+        let mut paths = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        let mut count = 0;
+        for path in paths {
+            count += self.add_nodelist_json(&fs::read_to_string(&path)?);
+        }
+        Ok(count)
+    }
+
+    /// Alle entries, in willekeurige volgorde.
+    pub fn entries(&self) -> impl Iterator<Item = &CatalogEntry> {
+        self.entries.values()
+    }
+
+    /// Zoekt een component op GUID; accolades en hoofdletters worden genegeerd.
+    #[must_use]
+    pub fn lookup(&self, guid: &str) -> Option<&CatalogEntry> {
+        self.entries.get(&normalize_guid(guid))
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+fn parse_pins(pattern: &Regex, text: &str) -> Vec<CatalogPin> {
+    pattern
+        .captures_iter(text)
+        .map(|captures| CatalogPin {
+            name: unescape(&captures[1]),
+            nickname: unescape(&captures[2]),
+            access: captures[3].to_owned(),
+            kind: captures.get(4).map(|m| unescape(m.as_str())),
+            optional: captures.get(5).is_some_and(|m| m.as_str() == "true"),
+        })
+        .collect()
+}
+
+fn unescape(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            if let Some(escaped) = chars.next() {
+                result.push(escaped);
+            }
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+fn normalize_guid(guid: &str) -> String {
+    guid.trim()
+        .trim_matches(|c| c == '{' || c == '}')
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Catalog;
+
+    #[test]
+    fn reads_headers_from_malformed_nodelist() {
+        let text = r#"[
+  {
+    "category": "Params",
+    "subcategory": "Input",
+    "name": "Value List",
+    "nickname": "List",
+    "guid": "00027467-0d24-4fa7-b178-8dc0ac5f42ec",
+    "inputs": [
+      ,
+      "outputs":
+    ]
+  },
+  {
+    "category": "Params",
+    "subcategory": "Input",
+    "name": "Image Resource",
+    "nickname": "",
+    "guid": "216bccd8-bf29-4d3c-b791-54c89a180db3",
+    "inputs": [
+      {
+        "name": "Point \"A\"",
+        "nickname": "A",
+        "access": "item",
+        "type": "Point",
+        "description": "First point",
+        "optional": true
+      }
+    ],
+    "outputs": [
+      {
+        "name": "Image",
+        "nickname": "I",
+        "access": "list",
+        "description": "Image"
+      }
+    ]
+  }
+]"#;
+
+        let mut catalog = Catalog::new();
+        assert_eq!(catalog.add_nodelist_json(text), 2);
+
+        let entry = catalog
+            .lookup("{00027467-0D24-4FA7-B178-8DC0AC5F42EC}")
+            .expect("value list entry");
+        assert_eq!(entry.name, "Value List");
+        assert_eq!(entry.category, "Params");
+        assert_eq!(entry.subcategory, "Input");
+        assert_eq!(
+            catalog
+                .lookup("216bccd8-bf29-4d3c-b791-54c89a180db3")
+                .map(|entry| entry.nickname.as_str()),
+            Some("")
+        );
+
+        let value_list = catalog
+            .lookup("00027467-0d24-4fa7-b178-8dc0ac5f42ec")
+            .unwrap();
+        assert!(value_list.inputs.is_empty() && value_list.outputs.is_empty());
+        let image = catalog
+            .lookup("216bccd8-bf29-4d3c-b791-54c89a180db3")
+            .unwrap();
+        assert_eq!(image.inputs.len(), 1);
+        assert_eq!(image.inputs[0].name, "Point \"A\"");
+        assert!(image.inputs[0].optional);
+        assert_eq!(image.inputs[0].kind.as_deref(), Some("Point"));
+        assert_eq!(image.outputs[0].access, "list");
+        assert_eq!(image.outputs[0].kind, None);
+    }
+}
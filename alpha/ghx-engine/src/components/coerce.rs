@@ -18,7 +18,8 @@
 //! ```
 
 use super::ComponentError;
-use crate::graph::value::{Domain, Domain1D, MeshData, MeshDiagnostics, PlaneValue, Value};
+use crate::geom::Transform;
+use crate::graph::value::{BrepValue, Domain, Domain1D, MeshData, MeshDiagnostics, PlaneValue, Value};
 use time::{Date, Month, PrimitiveDateTime, Time};
 
 // ============================================================================
@@ -86,6 +87,16 @@ impl SurfaceOwned {
         }
     }
 
+    /// Meshes an analytic brep into polygon faces.
+    #[must_use]
+    pub fn from_brep(brep: &BrepValue) -> Self {
+        let mesh = brep.to_mesh(BrepValue::DEFAULT_TESSELLATION);
+        Self {
+            faces: mesh.indices.chunks_exact(3).map(<[u32]>::to_vec).collect(),
+            vertices: mesh.positions,
+        }
+    }
+
     /// Creates a borrowed `Surface` reference from this owned data.
     #[must_use]
     pub fn as_surface(&self) -> Surface<'_> {
@@ -166,6 +177,13 @@ impl Mesh {
         }
     }
 
+    /// Meshes an analytic brep with the default display tessellation.
+    #[must_use]
+    pub fn from_brep(brep: &BrepValue) -> Self {
+        let mesh = brep.to_mesh(BrepValue::DEFAULT_TESSELLATION);
+        Self::with_attributes(mesh.positions, mesh.indices, mesh.normals, mesh.uvs)
+    }
+
     /// Returns the number of vertices in the mesh.
     #[must_use]
     pub fn vertex_count(&self) -> usize {
@@ -755,6 +773,7 @@ pub fn coerce_surface_like(value: &Value) -> Result<SurfaceOwned, ComponentError
                 faces,
             })
         }
+        Value::Brep(brep) => Ok(SurfaceOwned::from_brep(brep)),
         Value::List(l) if l.len() == 1 => coerce_surface_like(&l[0]),
         other => Err(ComponentError::new(format!(
             "Verwachtte een surface of mesh, kreeg {}",
@@ -794,6 +813,7 @@ pub fn coerce_surface_like_with_context(
                 faces,
             })
         }
+        Value::Brep(brep) => Ok(SurfaceOwned::from_brep(brep)),
         Value::List(l) if l.len() == 1 => coerce_surface_like_with_context(&l[0], context),
         other => Err(ComponentError::new(format!(
             "{} verwacht een surface of mesh, kreeg {}",
@@ -828,6 +848,57 @@ pub fn coerce_surface_list(value: &Value, context: &str) -> Result<Vec<SurfaceOw
     }
 }
 
+/// Coerces a `Value::Brep` to its analytic representation.
+///
+/// Unlike the `*_like` helpers this does not accept meshes: use it in
+/// components that need exact `(u, v)` evaluation and fall back to the mesh
+/// helpers when it fails.
+///
+/// # Errors
+///
+/// Returns an error if the value is not a `Value::Brep`.
+pub fn coerce_brep<'a>(value: &'a Value, context: &str) -> Result<&'a BrepValue, ComponentError> {
+    match value {
+        Value::Brep(brep) => Ok(brep),
+        Value::List(l) if l.len() == 1 => coerce_brep(&l[0], context),
+        other => Err(ComponentError::new(format!(
+            "{} verwacht een brep, kreeg {}",
+            context,
+            other.kind()
+        ))),
+    }
+}
+
+// ============================================================================
+// Transform coercion
+// ============================================================================
+
+/// Coerces a value to a 4x4 transform.
+///
+/// Accepts `Value::Transform` as well as a 4x4 `Value::Matrix`, so matrices
+/// built with the Maths → Matrix components can drive transform inputs.
+///
+/// # Errors
+///
+/// Returns an error if the value is neither a transform nor a 4x4 matrix.
+pub fn coerce_transform(value: &Value, context: &str) -> Result<Transform, ComponentError> {
+    match value {
+        Value::Transform(transform) => Ok(*transform),
+        Value::Matrix(matrix) => matrix.to_transform().ok_or_else(|| {
+            ComponentError::new(format!(
+                "{} verwacht een 4x4-matrix, kreeg {}x{}",
+                context, matrix.rows, matrix.columns
+            ))
+        }),
+        Value::List(l) if l.len() == 1 => coerce_transform(&l[0], context),
+        other => Err(ComponentError::new(format!(
+            "{} verwacht een transformatie, kreeg {}",
+            context,
+            other.kind()
+        ))),
+    }
+}
+
 // ============================================================================
 // Mesh coercion functions
 // ============================================================================
@@ -909,6 +980,7 @@ pub fn coerce_mesh_like(value: &Value) -> Result<Mesh, ComponentError> {
                 uvs: None,
             })
         }
+        Value::Brep(brep) => Ok(Mesh::from_brep(brep)),
         Value::List(l) if l.len() == 1 => coerce_mesh_like(&l[0]),
         other => Err(ComponentError::new(format!(
             "Verwachtte een mesh of surface, kreeg {}",
@@ -946,6 +1018,7 @@ pub fn coerce_mesh_like_with_context(value: &Value, context: &str) -> Result<Mes
                 uvs: None,
             })
         }
+        Value::Brep(brep) => Ok(Mesh::from_brep(brep)),
         Value::List(l) if l.len() == 1 => coerce_mesh_like_with_context(&l[0], context),
         other => Err(ComponentError::new(format!(
             "{} verwacht een mesh of surface, kreeg {}",
@@ -1155,6 +1228,12 @@ impl Plane {
 
 pub fn coerce_plane(value: &Value, context: &str) -> Result<Plane, ComponentError> {
     match value {
+        Value::Plane(plane) => Ok(Plane::normalize_axes(
+            plane.origin,
+            plane.x_axis,
+            plane.y_axis,
+            plane.z_axis,
+        )),
         Value::List(values) if values.len() >= 3 => {
             let a = coerce_point_with_context(&values[0], context)?;
             let b = coerce_point_with_context(&values[1], context)?;
@@ -3113,6 +3192,9 @@ pub fn coerce_vector_with_default(value: Option<&Value>) -> [f64; 3] {
 }
 
 pub fn coerce_plane_with_default(value: Option<&Value>) -> PlaneValue {
+    if let Some(Value::Plane(plane)) = value {
+        return *plane;
+    }
     if let Some(value) = value {
         if let Value::List(l) = value {
             if l.len() >= 3 {
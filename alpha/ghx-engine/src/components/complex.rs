@@ -5,7 +5,7 @@ use std::collections::BTreeMap;
 use crate::graph::node::MetaMap;
 use crate::graph::value::{ComplexValue, Value};
 
-use super::{Component, ComponentError, ComponentResult};
+use super::{Component, ComponentError, ComponentResult, PinAccess};
 
 const PIN_OUTPUT_RESULT: &str = "R";
 const PIN_OUTPUT_VALUE: &str = "y";
@@ -158,6 +158,29 @@ impl Component for ComponentKind {
             Self::ArcSine => evaluate_unary(inputs, |c| c.asin(), PIN_OUTPUT_VALUE),
         }
     }
+
+    fn input_access(&self) -> &'static [PinAccess] {
+        match self {
+            Self::Square
+            | Self::Tangent
+            | Self::Power
+            | Self::Multiplication
+            | Self::CoTangent
+            | Self::ArcTangent
+            | Self::Exponential
+            | Self::Addition
+            | Self::SquareRoot
+            | Self::Cosine
+            | Self::ArcCosine
+            | Self::Cosecant
+            | Self::Subtraction
+            | Self::Logarithm
+            | Self::Sine
+            | Self::Division
+            | Self::Secant
+            | Self::ArcSine => &[PinAccess::Item],
+        }
+    }
 }
 
 impl ComponentKind {
@@ -11,7 +11,7 @@ use crate::geom::{
     Point3 as GeomPoint3, Vec3 as GeomVec3,
     // Curve analysis functions
     analyze_curvature_at, analyze_polyline_segments,
-    curve_angle_at, curve_arc_length,
+    curve_angle_at, curve_arc_length, curve_closest_point,
     curve_length_at, curve_parameter_at_length,
     curve_third_derivative_at, curve_torsion_at,
     // Frame functions
@@ -20,8 +20,9 @@ use crate::geom::{
     sample_curve_at,
 };
 use crate::graph::node::MetaMap;
-use crate::graph::value::{Domain, Domain1D, Value};
+use crate::graph::value::{CurveValue, Domain, Domain1D, Value};
 
+use super::schema::PinDefault;
 use super::{Component, ComponentError, ComponentResult};
 
 const PIN_OUTPUT_START: &str = "S";
@@ -139,6 +140,9 @@ fn compute_third_derivative<C: Curve3>(curve: &C, t: f64) -> GeomVec3 {
 /// Default number of samples for arc-length estimation.
 const DEFAULT_ARC_LENGTH_SAMPLES: usize = 64;
 
+/// Samples for the length of exact curves without a closed-form length.
+const EXACT_CURVE_LENGTH_SAMPLES: usize = 1024;
+
 /// Beschikbare componenten binnen deze module.
 #[derive(Debug, Clone, Copy)]
 pub enum ComponentKind {
@@ -462,6 +466,46 @@ impl Component for ComponentKind {
 }
 
 impl ComponentKind {
+    /// Waarden die gebruikt worden als de betreffende input leeg blijft.
+    #[must_use]
+    pub fn input_defaults(&self) -> &'static [(&'static str, PinDefault)] {
+        match self {
+            Self::Discontinuity => &[("L", PinDefault::Number(1.0))],
+            Self::DerivativesList => &[("N", PinDefault::Number(1.0))],
+            Self::EvaluateLength => &[("N", PinDefault::Boolean(false))],
+            _ => &[],
+        }
+    }
+
+    /// Inputs die leeg mogen blijven zonder vaste standaardwaarde.
+    #[must_use]
+    pub fn optional_input_pins(&self) -> &'static [&'static str] {
+        match self {
+            Self::CurveDepth => &["Min", "Max"],
+            _ => &[],
+        }
+    }
+
+    /// Componenten die exacte `Value::Curve` invoer rechtstreeks evalueren.
+    #[must_use]
+    pub fn handles_curves(self) -> bool {
+        matches!(
+            self,
+            Self::EndPoints
+                | Self::CurveDomain
+                | Self::EvaluateCurveBasic
+                | Self::EvaluateCurveAngle
+                | Self::EvaluateCurveLength
+                | Self::CurveClosestPoint
+                | Self::Closed
+                | Self::Length
+                | Self::CurveMiddle
+                | Self::PerpFrame
+                | Self::CurveFrame
+                | Self::HorizontalFrame
+        )
+    }
+
     #[must_use]
     pub fn name(&self) -> &'static str {
         match self {
@@ -511,17 +555,24 @@ impl ComponentKind {
 }
 
 fn evaluate_end_points(inputs: &[Value]) -> ComponentResult {
-    let points = coerce_polyline(inputs.get(0), "End Points")?;
+    let curve = coerce_curve(inputs.get(0), "End Points")?;
     let mut outputs = BTreeMap::new();
-    outputs.insert(PIN_OUTPUT_START.to_owned(), Value::Point(points[0]));
-    outputs.insert(
-        PIN_OUTPUT_END.to_owned(),
-        Value::Point(*points.last().unwrap()),
-    );
+    outputs.insert(PIN_OUTPUT_START.to_owned(), Value::Point(curve.start_point()));
+    outputs.insert(PIN_OUTPUT_END.to_owned(), Value::Point(curve.end_point()));
     Ok(outputs)
 }
 
 fn evaluate_curve_domain(inputs: &[Value]) -> ComponentResult {
+    if let Some(Value::Curve(curve)) = inputs.first() {
+        let (start, end) = curve.domain();
+        let mut outputs = BTreeMap::new();
+        outputs.insert(
+            PIN_OUTPUT_DOMAIN.to_owned(),
+            Value::Domain(Domain::One(create_domain1d(start, end))),
+        );
+        return Ok(outputs);
+    }
+
     let points = coerce_polyline(inputs.get(0), "Curve Domain")?;
     // Use geom curve_arc_length for more accurate length calculation
     let polyline = create_geom_polyline(&points, is_closed(&points))?;
@@ -549,12 +600,11 @@ fn evaluate_curve(inputs: &[Value], mode: EvaluateOutput) -> ComponentResult {
         ));
     }
 
-    let points = coerce_polyline(inputs.get(0), "Evaluate Curve")?;
+    let curve = coerce_curve(inputs.get(0), "Evaluate Curve")?;
     let parameter = coerce_number(inputs.get(1), "Evaluate Curve")?;
 
     // Use geom::curve for evaluation
-    let polyline = create_geom_polyline(&points, is_closed(&points))?;
-    let sample = sample_curve_at(&polyline, parameter);
+    let sample = sample_curve_at(&curve, parameter);
 
     let mut outputs = BTreeMap::new();
     outputs.insert(PIN_OUTPUT_POINT.to_owned(), Value::Point(from_geom_point(sample.point)));
@@ -568,12 +618,12 @@ fn evaluate_curve(inputs: &[Value], mode: EvaluateOutput) -> ComponentResult {
         EvaluateOutput::PointTangentAngle => {
             // For angle, we compute the angle change at this parameter
             // by comparing tangents slightly before and after
-            let angle = compute_curve_angle(&polyline, parameter);
+            let angle = compute_curve_angle(&curve, parameter);
             outputs.insert(PIN_OUTPUT_ANGLE.to_owned(), Value::Number(angle));
         }
         EvaluateOutput::PointTangentLength => {
             // For length along, we compute arc length from start to parameter
-            let length_along = compute_length_along(&polyline, parameter);
+            let length_along = compute_length_along(&curve, parameter);
             outputs.insert(PIN_OUTPUT_LENGTH.to_owned(), Value::Number(length_along));
         }
     }
@@ -729,8 +779,22 @@ fn evaluate_curve_closest_point(inputs: &[Value]) -> ComponentResult {
     }
 
     let point = coerce_point(inputs.get(0), "Curve Closest Point")?;
-    let points = coerce_polyline(inputs.get(1), "Curve Closest Point")?;
-    let result = closest_point_on_polyline(point, &points);
+    let result = match inputs.get(1) {
+        Some(Value::Curve(curve)) => {
+            let (parameter, closest) =
+                curve_closest_point(curve, to_geom_point(point), DEFAULT_ARC_LENGTH_SAMPLES);
+            let closest = from_geom_point(closest);
+            ClosestPointResult {
+                point: closest,
+                parameter,
+                distance: distance(point, closest),
+            }
+        }
+        other => {
+            let points = coerce_polyline(other, "Curve Closest Point")?;
+            closest_point_on_polyline(point, &points)
+        }
+    };
 
     let mut outputs = BTreeMap::new();
     outputs.insert(PIN_OUTPUT_POINT.to_owned(), Value::Point(result.point));
@@ -765,15 +829,14 @@ fn evaluate_frame(inputs: &[Value], context: &str, mode: FrameMode) -> Component
         )));
     }
 
-    let points = coerce_polyline(inputs.get(0), context)?;
+    let curve = coerce_curve(inputs.get(0), context)?;
     let parameter = coerce_number(inputs.get(1), context)?;
 
     // Use geom::curve frame functions
-    let polyline = create_geom_polyline(&points, is_closed(&points))?;
     let geom_frame = match mode {
-        FrameMode::Frenet => frenet_frame_at(&polyline, parameter),
-        FrameMode::Parallel => parallel_frame_at(&polyline, parameter, GeomVec3::Z),
-        FrameMode::Horizontal => horizontal_frame_at(&polyline, parameter),
+        FrameMode::Frenet => frenet_frame_at(&curve, parameter),
+        FrameMode::Parallel => parallel_frame_at(&curve, parameter, GeomVec3::Z),
+        FrameMode::Horizontal => horizontal_frame_at(&curve, parameter),
     };
 
     let mut outputs = BTreeMap::new();
@@ -1464,8 +1527,7 @@ fn extract_points(value: &Value) -> Vec<[f64; 3]> {
 }
 
 fn evaluate_closed(inputs: &[Value]) -> ComponentResult {
-    let points = coerce_polyline(inputs.get(0), "Curve Closed")?;
-    let closed = is_closed(&points);
+    let closed = coerce_curve(inputs.get(0), "Curve Closed")?.is_closed();
     let mut outputs = BTreeMap::new();
     outputs.insert(PIN_OUTPUT_CLOSED.to_owned(), Value::Boolean(closed));
     outputs.insert(PIN_OUTPUT_PERIODIC.to_owned(), Value::Boolean(closed));
@@ -1628,11 +1690,15 @@ fn evaluate_length_parameter(inputs: &[Value]) -> ComponentResult {
 }
 
 fn evaluate_curve_length(inputs: &[Value]) -> ComponentResult {
-    let points = coerce_polyline(inputs.get(0), "Curve Length")?;
-
-    // Use geom::curve for accurate arc length calculation
-    let polyline = create_geom_polyline(&points, is_closed(&points))?;
-    let length = curve_arc_length(&polyline, DEFAULT_ARC_LENGTH_SAMPLES);
+    let curve = coerce_curve(inputs.get(0), "Curve Length")?;
+
+    // Analytic curves know their exact length; everything else is sampled.
+    let length = match &curve {
+        CurveValue::Polyline(_) => curve_arc_length(&curve, DEFAULT_ARC_LENGTH_SAMPLES),
+        _ => curve
+            .total_arc_length()
+            .unwrap_or_else(|| curve_arc_length(&curve, EXACT_CURVE_LENGTH_SAMPLES)),
+    };
 
     let mut outputs = BTreeMap::new();
     outputs.insert(PIN_OUTPUT_LENGTH.to_owned(), Value::Number(length));
@@ -1640,11 +1706,11 @@ fn evaluate_curve_length(inputs: &[Value]) -> ComponentResult {
 }
 
 fn evaluate_curve_middle(inputs: &[Value]) -> ComponentResult {
-    let points = coerce_polyline(inputs.get(0), "Curve Middle")?;
+    let curve = coerce_curve(inputs.get(0), "Curve Middle")?;
 
     // Use geom::curve for midpoint sampling
-    let polyline = create_geom_polyline(&points, is_closed(&points))?;
-    let sample = sample_curve_at(&polyline, 0.5);
+    let (start, end) = curve.domain();
+    let sample = sample_curve_at(&curve, 0.5 * (start + end));
 
     let mut outputs = BTreeMap::new();
     outputs.insert(
@@ -2078,6 +2144,23 @@ fn coerce_polyline(value: Option<&Value>, context: &str) -> Result<Vec<[f64; 3]>
     Ok(points)
 }
 
+/// Leest een exacte curve; puntenlijsten en lijnen worden een `Polyline3`.
+fn coerce_curve(value: Option<&Value>, context: &str) -> Result<CurveValue, ComponentError> {
+    match value {
+        Some(Value::Curve(curve)) => Ok(curve.clone()),
+        Some(Value::List(values)) if matches!(values.as_slice(), [Value::Curve(_)]) => {
+            coerce_curve(values.first(), context)
+        }
+        other => {
+            let points = coerce_polyline(other, context)?;
+            Ok(CurveValue::Polyline(create_geom_polyline(
+                &points,
+                is_closed(&points),
+            )?))
+        }
+    }
+}
+
 fn coerce_polyline_collection(
     value: Option<&Value>,
     context: &str,
@@ -2127,6 +2210,10 @@ fn collect_points(
             output.push(*p2);
             Ok(())
         }
+        Value::Curve(curve) => {
+            output.extend(curve.tessellate(CurveValue::DEFAULT_TESSELLATION));
+            Ok(())
+        }
         Value::List(values) => {
             for entry in values {
                 collect_points(entry, output, context)?;
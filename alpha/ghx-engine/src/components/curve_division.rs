@@ -15,8 +15,9 @@ use crate::geom::{
     sample_curve_at, shatter_curve,
 };
 use crate::graph::node::MetaMap;
-use crate::graph::value::Value;
+use crate::graph::value::{CurveValue, Value};
 
+use super::schema::PinDefault;
 use super::{Component, ComponentError, ComponentResult};
 
 const PIN_OUTPUT_FRAMES: &str = "F";
@@ -131,6 +132,31 @@ impl Component for ComponentKind {
 }
 
 impl ComponentKind {
+    /// Waarden die gebruikt worden als de betreffende input leeg blijft.
+    #[must_use]
+    pub fn input_defaults(&self) -> &'static [(&'static str, PinDefault)] {
+        match self {
+            Self::DivideCurve => &[("K", PinDefault::Boolean(false))],
+            Self::PerpFrames => &[("A", PinDefault::Boolean(false))],
+            _ => &[],
+        }
+    }
+
+    /// Componenten die exacte `Value::Curve` invoer rechtstreeks evalueren.
+    #[must_use]
+    pub fn handles_curves(self) -> bool {
+        matches!(
+            self,
+            Self::CurveFrames
+                | Self::DivideDistance
+                | Self::DivideLength
+                | Self::DivideCurve
+                | Self::DivideByDeviation
+                | Self::PerpFrames
+                | Self::HorizontalFrames
+        )
+    }
+
     #[must_use]
     pub fn name(&self) -> &'static str {
         match self {
@@ -271,12 +297,10 @@ fn evaluate_curve_frames(inputs: &[Value]) -> ComponentResult {
         ));
     }
 
-    let points = coerce_polyline(inputs.get(0), "Curve Frames")?;
+    let curve = coerce_curve(inputs.get(0), "Curve Frames")?;
     let segments = coerce_positive_integer(inputs.get(1), "Curve Frames")?;
 
-    // Build geom Polyline3 and use geom::curve frenet_frames
-    let polyline = create_polyline(&points)?;
-    let (geom_frames, params) = frenet_frames(&polyline, segments);
+    let (geom_frames, params) = frenet_frames(&curve, segments);
 
     let frames: Vec<Value> = geom_frames.iter().map(frame_to_value).collect();
     let parameters: Vec<Value> = params.into_iter().map(Value::Number).collect();
@@ -294,12 +318,10 @@ fn evaluate_divide_distance(inputs: &[Value]) -> ComponentResult {
         ));
     }
 
-    let points = coerce_polyline(inputs.get(0), "Divide Distance")?;
+    let curve = coerce_curve(inputs.get(0), "Divide Distance")?;
     let distance = coerce_positive_number(inputs.get(1), "Divide Distance")?;
 
-    // Build geom Polyline3 and use geom::curve divide_curve_by_distance
-    let polyline = create_polyline(&points)?;
-    let result = divide_curve_by_distance(&polyline, distance, DEFAULT_ARC_LENGTH_SAMPLES);
+    let result = divide_curve_by_distance(&curve, distance, DEFAULT_ARC_LENGTH_SAMPLES);
     Ok(division_result_to_outputs(result))
 }
 
@@ -310,12 +332,10 @@ fn evaluate_divide_length(inputs: &[Value]) -> ComponentResult {
         ));
     }
 
-    let points = coerce_polyline(inputs.get(0), "Divide Length")?;
+    let curve = coerce_curve(inputs.get(0), "Divide Length")?;
     let distance = coerce_positive_number(inputs.get(1), "Divide Length")?;
 
-    // Build geom Polyline3 and use geom::curve divide_curve_by_distance
-    let polyline = create_polyline(&points)?;
-    let result = divide_curve_by_distance(&polyline, distance, DEFAULT_ARC_LENGTH_SAMPLES);
+    let result = divide_curve_by_distance(&curve, distance, DEFAULT_ARC_LENGTH_SAMPLES);
     Ok(division_result_to_outputs(result))
 }
 
@@ -326,7 +346,7 @@ fn evaluate_divide_curve(inputs: &[Value]) -> ComponentResult {
         ));
     }
 
-    let points = coerce_polyline(inputs.get(0), "Divide Curve")?;
+    let curve = coerce_curve(inputs.get(0), "Divide Curve")?;
     let segments = coerce_positive_integer(inputs.get(1), "Divide Curve")?;
     let include_kinks = inputs
         .get(2)
@@ -334,18 +354,24 @@ fn evaluate_divide_curve(inputs: &[Value]) -> ComponentResult {
         .transpose()?
         .unwrap_or(false);
 
-    // Build geom Polyline3
-    let polyline = create_polyline(&points)?;
-
     // Use geom::curve divide_curve_by_count for basic division
-    let result = divide_curve_by_count(&polyline, segments);
+    let result = divide_curve_by_count(&curve, segments);
 
+    // Only polylines have kinks; exact curves are smooth over their domain.
+    let CurveValue::Polyline(polyline) = &curve else {
+        return Ok(division_result_to_outputs(result));
+    };
     if !include_kinks {
         return Ok(division_result_to_outputs(result));
     }
 
+    let mut points: Vec<[f64; 3]> = polyline.points().iter().map(|p| from_geom_point(*p)).collect();
+    if polyline.is_closed() {
+        points.push(points[0]);
+    }
+
     // If including kinks, add additional samples at polyline vertices
-    let total_length = curve_arc_length(&polyline, DEFAULT_ARC_LENGTH_SAMPLES);
+    let total_length = curve_arc_length(polyline, DEFAULT_ARC_LENGTH_SAMPLES);
     if total_length < EPSILON {
         return Ok(division_result_to_outputs(result));
     }
@@ -374,7 +400,7 @@ fn evaluate_divide_curve(inputs: &[Value]) -> ComponentResult {
     let mut params_out = Vec::new();
 
     for param in parameters {
-        let sample = sample_curve_at(&polyline, param);
+        let sample = sample_curve_at(polyline, param);
         points_out.push(Value::Point(from_geom_point(sample.point)));
         tangents.push(Value::Vector(from_geom_vec(sample.tangent)));
         params_out.push(Value::Number(sample.parameter));
@@ -394,13 +420,11 @@ fn evaluate_divide_by_deviation(inputs: &[Value]) -> ComponentResult {
         ));
     }
 
-    let points = coerce_polyline(inputs.get(0), "Divide By Deviation")?;
+    let curve = coerce_curve(inputs.get(0), "Divide By Deviation")?;
     let segments = coerce_positive_integer(inputs.get(1), "Divide By Deviation")?;
 
-    // Build geom Polyline3 and use geom::curve divide_curve_by_count
     // Note: For polylines, deviation is always 0 since they're linear segments
-    let polyline = create_polyline(&points)?;
-    let result = divide_curve_by_count(&polyline, segments);
+    let result = divide_curve_by_count(&curve, segments);
 
     // Convert to output format with deviation values (0.0 for polylines)
     let count = result.points.len();
@@ -418,7 +442,7 @@ fn evaluate_perp_frames(inputs: &[Value]) -> ComponentResult {
         ));
     }
 
-    let points = coerce_polyline(inputs.get(0), "Perp Frames")?;
+    let curve = coerce_curve(inputs.get(0), "Perp Frames")?;
     let segments = coerce_positive_integer(inputs.get(1), "Perp Frames")?;
     let align = inputs
         .get(2)
@@ -426,9 +450,7 @@ fn evaluate_perp_frames(inputs: &[Value]) -> ComponentResult {
         .transpose()?
         .unwrap_or(false);
 
-    // Build geom Polyline3 and use geom::curve perp_frames
-    let polyline = create_polyline(&points)?;
-    let (geom_frames, params) = perp_frames(&polyline, segments, align);
+    let (geom_frames, params) = perp_frames(&curve, segments, align);
 
     let frames: Vec<Value> = geom_frames.iter().map(frame_to_value).collect();
     let parameters: Vec<Value> = params.into_iter().map(Value::Number).collect();
@@ -446,12 +468,10 @@ fn evaluate_horizontal_frames(inputs: &[Value]) -> ComponentResult {
         ));
     }
 
-    let points = coerce_polyline(inputs.get(0), "Horizontal Frames")?;
+    let curve = coerce_curve(inputs.get(0), "Horizontal Frames")?;
     let segments = coerce_positive_integer(inputs.get(1), "Horizontal Frames")?;
 
-    // Build geom Polyline3 and use geom::curve horizontal_frames
-    let polyline = create_polyline(&points)?;
-    let (geom_frames, params) = horizontal_frames(&polyline, segments);
+    let (geom_frames, params) = horizontal_frames(&curve, segments);
 
     let frames: Vec<Value> = geom_frames.iter().map(frame_to_value).collect();
     let parameters: Vec<Value> = params.into_iter().map(Value::Number).collect();
@@ -781,6 +801,20 @@ fn coerce_polyline(value: Option<&Value>, context: &str) -> Result<Vec<[f64; 3]>
     Ok(points)
 }
 
+/// Leest een exacte curve; puntenlijsten en lijnen worden een `Polyline3`.
+fn coerce_curve(value: Option<&Value>, context: &str) -> Result<CurveValue, ComponentError> {
+    match value {
+        Some(Value::Curve(curve)) => Ok(curve.clone()),
+        Some(Value::List(values)) if matches!(values.as_slice(), [Value::Curve(_)]) => {
+            coerce_curve(values.first(), context)
+        }
+        other => {
+            let points = coerce_polyline(other, context)?;
+            Ok(CurveValue::Polyline(create_polyline(&points)?))
+        }
+    }
+}
+
 fn collect_points(
     value: &Value,
     output: &mut Vec<[f64; 3]>,
@@ -796,6 +830,10 @@ fn collect_points(
             output.push(*p2);
             Ok(())
         }
+        Value::Curve(curve) => {
+            output.extend(curve.tessellate(CurveValue::DEFAULT_TESSELLATION));
+            Ok(())
+        }
         Value::List(values) => {
             if values.is_empty() {
                 return Err(ComponentError::new(format!(
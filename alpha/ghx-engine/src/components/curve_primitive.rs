@@ -12,8 +12,9 @@ use crate::geom::{
     Vec3 as GeomVec3, tessellate_curve_adaptive_points,
 };
 use crate::graph::node::MetaMap;
-use crate::graph::value::{Domain, Value};
+use crate::graph::value::{CurveValue, Domain, Value};
 
+use super::schema::PinDefault;
 use super::{Component, ComponentError, ComponentResult};
 
 const PIN_OUTPUT_CIRCLE: &str = "C";
@@ -313,6 +314,46 @@ impl Component for ComponentKind {
 }
 
 impl ComponentKind {
+    /// Waarden die gebruikt worden als de betreffende input leeg blijft.
+    #[must_use]
+    pub fn input_defaults(&self) -> &'static [(&'static str, PinDefault)] {
+        match self {
+            Self::Rectangle | Self::Rectangle2Pt => &[("R", PinDefault::Number(0.0))],
+            Self::Polygon => &[("Rf", PinDefault::Number(0.0))],
+            _ => &[],
+        }
+    }
+
+    /// Inputs die leeg mogen blijven zonder vaste standaardwaarde.
+    #[must_use]
+    pub fn optional_input_pins(&self) -> &'static [&'static str] {
+        match self {
+            Self::Rectangle2Pt => &["P"],
+            _ => &[],
+        }
+    }
+
+    /// Returns `true` for kinds that evaluate to `not_implemented`.
+    #[must_use]
+    pub fn is_placeholder(&self) -> bool {
+        matches!(
+            self,
+            Self::InCircle
+                | Self::CircleTanTan
+                | Self::Line2Plane
+                | Self::InEllipse
+                | Self::BiArc
+                | Self::ModifiedArc
+                | Self::Line4Pt
+                | Self::TwoByFourJam
+                | Self::TangentLinesEx
+                | Self::CircleTanTanTan
+                | Self::TangentLinesIn
+                | Self::TangentLines
+                | Self::TangentArcs
+        )
+    }
+
     #[must_use]
     pub fn name(&self) -> &'static str {
         match self {
@@ -422,13 +463,10 @@ fn evaluate_arc_3pt(inputs: &[Value]) -> ComponentResult {
 
     angle1 = unwrap_angle(angle1, 0.0);
 
-    let (points, length) = create_arc_points_from_angles(&plane, radius, angle1, angle3);
+    let (arc, length) = arc_value(&plane, radius, angle1, angle3);
 
     let mut outputs = BTreeMap::new();
-    outputs.insert(
-        PIN_OUTPUT_ARC.to_owned(),
-        Value::List(points.into_iter().map(Value::Point).collect()),
-    );
+    outputs.insert(PIN_OUTPUT_ARC.to_owned(), arc);
     outputs.insert(PIN_OUTPUT_LENGTH.to_owned(), Value::Number(length));
     Ok(outputs)
 }
@@ -539,15 +577,6 @@ fn evaluate_circle_3pt(inputs: &[Value]) -> ComponentResult {
         radius,
     );
 
-    // Tessellate using adaptive algorithm
-    let (max_deviation, max_segments) = default_curve_tessellation_options();
-    let mut points = tessellate_curve_to_points(&circle, max_deviation, max_segments);
-
-    // Add closing point for backward compatibility
-    if let Some(first) = points.first().copied() {
-        points.push(first);
-    }
-
     // Build the output plane representation:
     // [origin, point_on_x_axis, point_on_y_axis]
     let y_axis = cross(normal, x_axis);
@@ -560,7 +589,7 @@ fn evaluate_circle_3pt(inputs: &[Value]) -> ComponentResult {
     let mut outputs = BTreeMap::new();
     outputs.insert(
         PIN_OUTPUT_CIRCLE.to_owned(),
-        Value::List(points.into_iter().map(Value::Point).collect()),
+        Value::Curve(CurveValue::Circle(circle)),
     );
     outputs.insert("P".to_owned(), Value::List(plane_points));
     outputs.insert("R".to_owned(), Value::Number(radius));
@@ -587,19 +616,10 @@ fn evaluate_circle_cnr(inputs: &[Value]) -> ComponentResult {
     // Build Circle3 directly from center, normal, and radius using geom types
     let circle = Circle3::new(to_geom_point(center), to_geom_vec(normal), radius);
 
-    // Tessellate using adaptive algorithm
-    let (max_deviation, max_segments) = default_curve_tessellation_options();
-    let mut points = tessellate_curve_to_points(&circle, max_deviation, max_segments);
-
-    // Add closing point for backward compatibility
-    if let Some(first) = points.first().copied() {
-        points.push(first);
-    }
-
     let mut outputs = BTreeMap::new();
     outputs.insert(
         PIN_OUTPUT_CIRCLE.to_owned(),
-        Value::List(points.into_iter().map(Value::Point).collect()),
+        Value::Curve(CurveValue::Circle(circle)),
     );
     Ok(outputs)
 }
@@ -711,15 +731,6 @@ fn evaluate_circle_fit(inputs: &[Value]) -> ComponentResult {
         radius,
     );
 
-    // Tessellate using adaptive algorithm
-    let (max_deviation, max_segments) = default_curve_tessellation_options();
-    let mut circle_points = tessellate_curve_to_points(&circle, max_deviation, max_segments);
-
-    // Add closing point for backward compatibility
-    if let Some(first) = circle_points.first().copied() {
-        circle_points.push(first);
-    }
-
     // Build the output plane representation
     let plane_points = vec![
         Value::Point(center),
@@ -730,7 +741,7 @@ fn evaluate_circle_fit(inputs: &[Value]) -> ComponentResult {
     let mut outputs = BTreeMap::new();
     outputs.insert(
         PIN_OUTPUT_CIRCLE.to_owned(),
-        Value::List(circle_points.into_iter().map(Value::Point).collect()),
+        Value::Curve(CurveValue::Circle(circle)),
     );
     outputs.insert("P".to_owned(), Value::List(plane_points));
     outputs.insert("R".to_owned(), Value::Number(radius));
@@ -939,10 +950,10 @@ fn evaluate_line(inputs: &[Value]) -> ComponentResult {
             .or_else(|| ends.last())
             .expect("ends is niet leeg");
 
-        // Use geom::Line3 for validation - check for degenerate lines
+        // Degenerate lines become Null; the others stay exact geom lines
         let geom_line = Line3::new(to_geom_point(start), to_geom_point(end));
         let value = if geom_line.direction().length_squared() > 0.0 {
-            Value::CurveLine { p1: start, p2: end }
+            Value::Curve(CurveValue::Line(geom_line))
         } else {
             Value::Null
         };
@@ -977,13 +988,11 @@ fn evaluate_arc(inputs: &[Value]) -> ComponentResult {
         ));
     }
 
-    let (max_deviation, max_segments) = default_curve_tessellation_options();
-
     let mut arcs = Vec::new();
     let mut lengths = Vec::new();
     for plane in planes {
-        let (points, length) = create_arc_points_geom(&plane, radius, 0.0, angle, max_deviation, max_segments);
-        arcs.push(Value::List(points.into_iter().map(Value::Point).collect()));
+        let (arc, length) = arc_value(&plane, radius, 0.0, angle);
+        arcs.push(arc);
         lengths.push(Value::Number(length));
     }
 
@@ -1181,9 +1190,6 @@ fn evaluate_arc_sed(inputs: &[Value]) -> ComponentResult {
         sweep_angle,
     );
 
-    // Tessellate using adaptive algorithm
-    let (max_deviation, max_segments) = default_curve_tessellation_options();
-    let points = tessellate_curve_to_points(&arc, max_deviation, max_segments);
     let length = radius * sweep_angle.abs();
 
     // Build the output plane representation
@@ -1195,60 +1201,32 @@ fn evaluate_arc_sed(inputs: &[Value]) -> ComponentResult {
     ];
 
     let mut outputs = BTreeMap::new();
-    outputs.insert(
-        PIN_OUTPUT_ARC.to_owned(),
-        Value::List(points.into_iter().map(Value::Point).collect()),
-    );
+    outputs.insert(PIN_OUTPUT_ARC.to_owned(), Value::Curve(CurveValue::Arc(arc)));
     outputs.insert(PIN_OUTPUT_LENGTH.to_owned(), Value::Number(length));
     outputs.insert("P".to_owned(), Value::List(plane_points));
     Ok(outputs)
 }
 
-/// Creates arc points using geom::Arc3 and adaptive tessellation.
+/// Builds an exact arc from `start_angle` to `end_angle` in the given plane.
 ///
-/// Builds an arc from start_angle to end_angle (sweep = end_angle - start_angle)
-/// in the given plane with the specified radius. The arc is tessellated adaptively
-/// based on curvature/deviation thresholds.
-fn create_arc_points_geom(
-    plane: &Plane,
-    radius: f64,
-    start_angle: f64,
-    end_angle: f64,
-    max_deviation: f64,
-    max_segments: usize,
-) -> (Vec<[f64; 3]>, f64) {
+/// Returns the arc output value and its length. A zero sweep degenerates to
+/// the single start point, as the tessellated arcs did before.
+fn arc_value(plane: &Plane, radius: f64, start_angle: f64, end_angle: f64) -> (Value, f64) {
     let sweep_angle = end_angle - start_angle;
-
-    // Handle zero or near-zero sweep (degenerate arc)
     if sweep_angle.abs() < 1e-12 {
         let point = plane.apply(radius * start_angle.cos(), radius * start_angle.sin());
-        return (vec![point], 0.0);
+        return (Value::List(vec![Value::Point(point)]), 0.0);
     }
 
-    let center = to_geom_point(plane.origin);
-    let normal = to_geom_vec(plane._z_axis);
-
-    // Build Arc3 using the plane's x-axis for consistent orientation
     let arc = Arc3::from_center_xaxis_normal(
-        center,
+        to_geom_point(plane.origin),
         to_geom_vec(plane.x_axis),
-        normal,
+        to_geom_vec(plane._z_axis),
         radius,
         start_angle,
         sweep_angle,
     );
-
-    // Tessellate using adaptive algorithm
-    let points = tessellate_curve_to_points(&arc, max_deviation, max_segments);
-    let length = radius * sweep_angle.abs();
-
-    (points, length)
-}
-
-/// Legacy create_arc_points wrapper for backward compatibility.
-fn create_arc_points(plane: &Plane, radius: f64, angle: f64) -> (Vec<[f64; 3]>, f64) {
-    let (max_deviation, max_segments) = default_curve_tessellation_options();
-    create_arc_points_geom(plane, radius, 0.0, angle, max_deviation, max_segments)
+    (Value::Curve(CurveValue::Arc(arc)), radius * sweep_angle.abs())
 }
 
 /// Legacy segments_for_angle - kept for backward compatibility with rectangle fillet.
@@ -1282,18 +1260,6 @@ fn create_arc_points_from_angles_legacy(
     (points, length)
 }
 
-/// Create arc points using geom::Arc3, using default tessellation options.
-/// This replaces the legacy create_arc_points_from_angles.
-fn create_arc_points_from_angles(
-    plane: &Plane,
-    radius: f64,
-    start_angle: f64,
-    end_angle: f64,
-) -> (Vec<[f64; 3]>, f64) {
-    let (max_deviation, max_segments) = default_curve_tessellation_options();
-    create_arc_points_geom(plane, radius, start_angle, end_angle, max_deviation, max_segments)
-}
-
 fn evaluate_polygon(inputs: &[Value]) -> ComponentResult {
     if inputs.len() < 3 {
         return Err(ComponentError::new(
@@ -1957,12 +1923,9 @@ fn evaluate_circle(inputs: &[Value]) -> ComponentResult {
         ));
     }
 
-    let (max_deviation, max_segments) = default_curve_tessellation_options();
-
     let mut circles = Vec::new();
     for plane in planes {
-        let points = sample_circle_points_geom(&plane, radius, max_deviation, max_segments);
-        circles.push(Value::List(points.into_iter().map(Value::Point).collect()));
+        circles.push(Value::Curve(CurveValue::Circle(circle_in_plane(&plane, radius))));
     }
 
     let circle_output = if circles.len() == 1 {
@@ -1976,39 +1939,15 @@ fn evaluate_circle(inputs: &[Value]) -> ComponentResult {
     Ok(outputs)
 }
 
-/// Creates a circle using geom::Circle3 and tessellates it adaptively.
-///
-/// The circle is constructed in the given plane with the specified radius.
-/// Points are tessellated adaptively based on curvature and deviation thresholds.
-/// The output includes a closing point (first point repeated at the end).
-fn sample_circle_points_geom(
-    plane: &Plane,
-    radius: f64,
-    max_deviation: f64,
-    max_segments: usize,
-) -> Vec<[f64; 3]> {
-    let center = to_geom_point(plane.origin);
-    let normal = to_geom_vec(plane._z_axis);
-
-    // Build the geom::Circle3 using the plane's x-axis for consistent orientation
-    let circle = Circle3::from_center_xaxis_normal(
-        center,
+/// Builds a geom::Circle3 in the given plane, using the plane's x-axis for a
+/// consistent seam position.
+fn circle_in_plane(plane: &Plane, radius: f64) -> Circle3 {
+    Circle3::from_center_xaxis_normal(
+        to_geom_point(plane.origin),
         to_geom_vec(plane.x_axis),
-        normal,
+        to_geom_vec(plane._z_axis),
         radius,
-    );
-
-    // Tessellate using adaptive algorithm
-    let mut points = tessellate_curve_to_points(&circle, max_deviation, max_segments);
-
-    // For closed curves, tessellate_curve_adaptive_points returns points without
-    // duplicating the first point. We need to add the closing point for
-    // backward compatibility with existing Grasshopper semantics.
-    if let Some(first) = points.first().copied() {
-        points.push(first);
-    }
-
-    points
+    )
 }
 
 /// Legacy sample_circle_points using fixed segment count (kept for backward compatibility).
@@ -2054,12 +1993,16 @@ fn evaluate_ellipse(inputs: &[Value]) -> ComponentResult {
         ));
     }
 
-    let (max_deviation, max_segments) = default_curve_tessellation_options();
-
     let mut ellipses = Vec::new();
     for plane in planes {
-        let points = sample_ellipse_points_geom(&plane, radius_x, radius_y, max_deviation, max_segments);
-        ellipses.push(Value::List(points.into_iter().map(Value::Point).collect()));
+        let ellipse = Ellipse3::new(
+            to_geom_point(plane.origin),
+            to_geom_vec(plane.x_axis),
+            to_geom_vec(plane.y_axis),
+            radius_x,
+            radius_y,
+        );
+        ellipses.push(Value::Curve(CurveValue::Ellipse(ellipse)));
     }
 
     let ellipse_output = if ellipses.len() == 1 {
@@ -2074,36 +2017,6 @@ fn evaluate_ellipse(inputs: &[Value]) -> ComponentResult {
     Ok(outputs)
 }
 
-/// Creates ellipse points using geom::Ellipse3 and adaptive tessellation.
-///
-/// The ellipse is constructed in the given plane with radius_x along the X-axis
-/// and radius_y along the Y-axis. Points are tessellated adaptively based on
-/// curvature and deviation thresholds.
-fn sample_ellipse_points_geom(
-    plane: &Plane,
-    radius_x: f64,
-    radius_y: f64,
-    max_deviation: f64,
-    max_segments: usize,
-) -> Vec<[f64; 3]> {
-    let center = to_geom_point(plane.origin);
-    let x_axis = to_geom_vec(plane.x_axis);
-    let y_axis = to_geom_vec(plane.y_axis);
-
-    // Build the geom::Ellipse3
-    let ellipse = Ellipse3::new(center, x_axis, y_axis, radius_x, radius_y);
-
-    // Tessellate using adaptive algorithm
-    let mut points = tessellate_curve_to_points(&ellipse, max_deviation, max_segments);
-
-    // Add closing point for backward compatibility
-    if let Some(first) = points.first().copied() {
-        points.push(first);
-    }
-
-    points
-}
-
 fn parse_plane(value: Option<&Value>, context: &str) -> Result<Plane, ComponentError> {
     match value {
         None => return Ok(Plane::default()),
@@ -2508,6 +2421,48 @@ mod tests {
         (dx * dx + dy * dy + dz * dz).sqrt()
     }
 
+    #[test]
+    fn circle_outputs_exact_curve() {
+        let outputs = evaluate_circle(&[Value::Null, Value::Number(2.5)]).unwrap();
+        let Some(Value::Curve(CurveValue::Circle(circle))) = outputs.get(PIN_OUTPUT_CIRCLE) else {
+            panic!("circle should be an exact curve");
+        };
+        assert!(approx_eq(circle.radius, 2.5, 1e-12));
+        assert!(crate::geom::Curve3::is_closed(circle));
+    }
+
+    #[test]
+    fn arc_outputs_exact_curve_with_length() {
+        let outputs = evaluate_arc(&[
+            Value::Null,
+            Value::Number(2.0),
+            Value::Number(std::f64::consts::FRAC_PI_2),
+        ])
+        .unwrap();
+        assert!(matches!(
+            outputs.get(PIN_OUTPUT_ARC),
+            Some(Value::Curve(CurveValue::Arc(_)))
+        ));
+        let Some(Value::Number(length)) = outputs.get(PIN_OUTPUT_LENGTH) else {
+            panic!("arc length missing");
+        };
+        assert!(approx_eq(*length, std::f64::consts::PI, 1e-12));
+    }
+
+    #[test]
+    fn line_outputs_exact_curve() {
+        let outputs =
+            evaluate_line(&[Value::Point([0.0, 0.0, 0.0]), Value::Point([2.0, 0.0, 0.0])]).unwrap();
+        let Some(Value::Curve(CurveValue::Line(line))) = outputs.get(PIN_OUTPUT_LINE) else {
+            panic!("line should be an exact curve");
+        };
+        assert!(approx_eq(line.direction().length(), 2.0, 1e-12));
+
+        let degenerate =
+            evaluate_line(&[Value::Point([1.0, 1.0, 1.0]), Value::Point([1.0, 1.0, 1.0])]).unwrap();
+        assert_eq!(degenerate.get(PIN_OUTPUT_LINE), Some(&Value::Null));
+    }
+
     #[test]
     fn polygon_without_fillet_creates_correct_vertex_count() {
         let plane = default_plane();
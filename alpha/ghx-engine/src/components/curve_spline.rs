@@ -2,7 +2,9 @@
 //!
 //! This module uses the `geom::curve` primitives for spline curve construction and
 //! `geom::tessellation` for adaptive tessellation. Components remain thin
-//! wrappers that coerce inputs, build geom curves, and return tessellated output.
+//! wrappers that coerce inputs and build geom curves. NURBS Curve and
+//! Interpolate return their curve exact; the other components return
+//! tessellated output.
 //!
 //! # Curve Types
 //!
@@ -18,8 +20,9 @@ use crate::geom::{
     Polyline3, Vec3 as GeomVec3, tessellate_curve_adaptive_points,
 };
 use crate::graph::node::MetaMap;
-use crate::graph::value::{Domain, Domain1D, Value};
+use crate::graph::value::{CurveValue, Domain, Domain1D, Value};
 
+use super::schema::PinDefault;
 use super::{Component, ComponentError, ComponentResult};
 
 // ============================================================================
@@ -324,6 +327,24 @@ impl Component for ComponentKind {
 }
 
 impl ComponentKind {
+    /// Waarden die gebruikt worden als de betreffende input leeg blijft.
+    #[must_use]
+    pub fn input_defaults(&self) -> &'static [(&'static str, PinDefault)] {
+        match self {
+            Self::NurbsCurve | Self::Interpolate => &[
+                ("D", PinDefault::Number(3.0)),
+                ("P", PinDefault::Boolean(false)),
+            ],
+            Self::Polyline => &[("C", PinDefault::Boolean(false))],
+            Self::KnotVector => &[("P", PinDefault::Boolean(false))],
+            Self::BlendCurve => &[
+                ("Fa", PinDefault::Number(0.5)),
+                ("Fb", PinDefault::Number(0.5)),
+            ],
+            _ => &[],
+        }
+    }
+
     #[must_use]
     pub fn name(&self) -> &'static str {
         match self {
@@ -431,7 +452,8 @@ fn evaluate_blend_curve_point(inputs: &[Value]) -> ComponentResult {
 /// Evaluates the NURBS Curve / Nurbs Curve PWK component.
 ///
 /// Creates a B-spline curve through control points using `geom::NurbsCurve3`.
-/// The curve is tessellated adaptively based on curvature.
+/// The curve is returned exact; tessellation happens in the buffer and export
+/// paths.
 ///
 /// # Inputs
 /// - `inputs[0]`: Control points list
@@ -440,7 +462,7 @@ fn evaluate_blend_curve_point(inputs: &[Value]) -> ComponentResult {
 /// - `inputs[3]`: (Optional) Weights list (for rational NURBS)
 ///
 /// # Outputs
-/// - `C`: Curve as a list of points (tessellated polyline)
+/// - `C`: Curve (`CurveValue::Nurbs`)
 /// - `L`: Length of the curve
 /// - `D`: Domain of the curve
 fn evaluate_nurbs(inputs: &[Value], context: &str) -> ComponentResult {
@@ -525,31 +547,8 @@ fn evaluate_nurbs(inputs: &[Value], context: &str) -> ComponentResult {
         final_weights,
     );
 
-    let tessellated_points = match nurbs_result {
-        Ok(nurbs) => {
-            let (max_deviation, max_segments) = default_curve_tessellation_options();
-            tessellate_curve_to_points(&nurbs, max_deviation, max_segments)
-        }
-        Err(_) => {
-            // Fallback: return the original control points as a simple polyline
-            points.clone()
-        }
-    };
-
-    // For closed curves, ensure the output closes properly
-    let final_output = if closed && !tessellated_points.is_empty() {
-        let mut result = tessellated_points;
-        if let Some(first) = result.first().copied() {
-            if result.last() != Some(&first) {
-                result.push(first);
-            }
-        }
-        result
-    } else {
-        tessellated_points
-    };
-
-    build_curve_outputs(final_output, 0.0, 1.0)
+    let curve = spline_curve_value(nurbs_result, &points, closed, context)?;
+    build_exact_curve_outputs(curve, 0.0, 1.0)
 }
 
 fn evaluate_iso_curve(inputs: &[Value]) -> ComponentResult {
@@ -677,8 +676,8 @@ fn evaluate_match_curve(inputs: &[Value]) -> ComponentResult {
 /// Evaluates the Interpolate component.
 ///
 /// Creates a smooth curve that interpolates exactly through the given points using
-/// `geom::NurbsCurve3::interpolate_through_points()`. The curve is tessellated
-/// adaptively based on curvature.
+/// `geom::NurbsCurve3::interpolate_through_points()`. The curve is returned
+/// exact; tessellation happens in the buffer and export paths.
 ///
 /// # Inputs
 /// - `inputs[0]`: Control points list (the curve passes through these)
@@ -686,7 +685,7 @@ fn evaluate_match_curve(inputs: &[Value]) -> ComponentResult {
 /// - `inputs[2]`: (Optional) Closed flag (defaults to false)
 ///
 /// # Outputs
-/// - `C`: Curve as a list of points (tessellated polyline)
+/// - `C`: Curve (`CurveValue::Nurbs`)
 /// - `L`: Length of the curve
 /// - `D`: Domain of the curve
 fn evaluate_interpolate(inputs: &[Value], domain_override: Option<(f64, f64)>) -> ComponentResult {
@@ -719,33 +718,9 @@ fn evaluate_interpolate(inputs: &[Value], domain_override: Option<(f64, f64)>) -
     // Build the interpolating NURBS curve using geom
     let nurbs_result = NurbsCurve3::interpolate_through_points(&geom_points, requested_degree, closed);
 
-    let tessellated_points = match nurbs_result {
-        Ok(nurbs) => {
-            // Use adaptive tessellation for smooth output
-            let (max_deviation, max_segments) = default_curve_tessellation_options();
-            tessellate_curve_to_points(&nurbs, max_deviation, max_segments)
-        }
-        Err(_) => {
-            // Fallback: return the original points as a simple polyline
-            points.clone()
-        }
-    };
-
-    // For closed curves, ensure the output closes properly
-    let final_result = if closed && !tessellated_points.is_empty() {
-        let mut result = tessellated_points;
-        if let Some(first) = result.first().copied() {
-            if result.last() != Some(&first) {
-                result.push(first);
-            }
-        }
-        result
-    } else {
-        tessellated_points
-    };
-
+    let curve = spline_curve_value(nurbs_result, &points, closed, "Interpolate")?;
     let (start, end) = domain_override.unwrap_or((0.0, 1.0));
-    build_curve_outputs(final_result, start, end)
+    build_exact_curve_outputs(curve, start, end)
 }
 
 /// Evaluates the Bezier Span component.
@@ -1170,6 +1145,50 @@ fn evaluate_curve_on_surface(inputs: &[Value]) -> ComponentResult {
     build_curve_outputs(points, 0.0, 1.0)
 }
 
+/// Wraps a spline as a curve value.
+///
+/// The NURBS curve is kept exact. A closed spline whose clamped knots do not
+/// bring it back to its start point becomes a closed polyline through its
+/// tessellation, and a spline that could not be built falls back to the
+/// polyline through its input points.
+fn spline_curve_value(
+    nurbs: Result<NurbsCurve3, String>,
+    fallback: &[[f64; 3]],
+    closed: bool,
+    context: &str,
+) -> Result<CurveValue, ComponentError> {
+    let points = match nurbs {
+        Ok(nurbs) => {
+            let curve = CurveValue::Nurbs(nurbs);
+            if !closed || curve.start_point() == curve.end_point() {
+                return Ok(curve);
+            }
+            curve.tessellate(CurveValue::DEFAULT_TESSELLATION)
+        }
+        Err(_) => fallback.to_vec(),
+    };
+
+    let points = points.into_iter().map(to_geom_point).collect();
+    Polyline3::new(points, closed)
+        .map(CurveValue::Polyline)
+        .map_err(|error| ComponentError::new(format!("{}: {}", context, error)))
+}
+
+/// Builds the `C`, `L` and `D` outputs for an exact curve.
+///
+/// The curve itself is returned untessellated; the length is measured on the
+/// same tessellation the buffers and exporters use.
+fn build_exact_curve_outputs(curve: CurveValue, start: f64, end: f64) -> ComponentResult {
+    let length = polyline_length(&curve.tessellate(CurveValue::DEFAULT_TESSELLATION));
+    let domain = Value::Domain(Domain::One(create_domain1d(start, end)));
+
+    let mut outputs = BTreeMap::new();
+    outputs.insert(PIN_OUTPUT_CURVE.to_owned(), Value::Curve(curve));
+    outputs.insert(PIN_OUTPUT_LENGTH.to_owned(), Value::Number(length));
+    outputs.insert(PIN_OUTPUT_DOMAIN.to_owned(), domain);
+    Ok(outputs)
+}
+
 fn build_curve_outputs(points: Vec<[f64; 3]>, start: f64, end: f64) -> ComponentResult {
     if points.len() < 2 {
         return Err(ComponentError::new(
@@ -1582,3 +1601,39 @@ fn bounding_box(points: &[[f64; 3]]) -> BoundingBox {
     }
     BoundingBox { min, max }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(coords: &[[f64; 3]]) -> Value {
+        Value::List(coords.iter().copied().map(Value::Point).collect())
+    }
+
+    #[test]
+    fn nurbs_curve_outputs_exact_curve() {
+        let control = [[0.0, 0.0, 0.0], [1.0, 2.0, 0.0], [3.0, 2.0, 0.0], [4.0, 0.0, 0.0]];
+        let outputs = evaluate_nurbs(&[points(&control)], "Nurbs Curve").unwrap();
+        let Some(Value::Curve(CurveValue::Nurbs(nurbs))) = outputs.get(PIN_OUTPUT_CURVE) else {
+            panic!("nurbs curve should be an exact curve");
+        };
+        assert_eq!(nurbs.degree, 3);
+        assert_eq!(nurbs.control_points.len(), 4);
+
+        let Some(Value::Number(length)) = outputs.get(PIN_OUTPUT_LENGTH) else {
+            panic!("length missing");
+        };
+        assert!(*length > 4.0 && *length < 7.0);
+    }
+
+    #[test]
+    fn interpolate_outputs_exact_curve_through_points() {
+        let through = [[0.0, 0.0, 0.0], [1.0, 1.0, 0.0], [2.0, 0.0, 0.0], [3.0, 1.0, 0.0]];
+        let outputs = evaluate_interpolate(&[points(&through)], None).unwrap();
+        let Some(Value::Curve(curve @ CurveValue::Nurbs(_))) = outputs.get(PIN_OUTPUT_CURVE) else {
+            panic!("interpolate should be an exact curve");
+        };
+        assert_eq!(curve.start_point(), through[0]);
+        assert_eq!(curve.end_point(), through[3]);
+    }
+}
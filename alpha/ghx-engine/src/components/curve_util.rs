@@ -34,8 +34,9 @@ use crate::geom::{
     Tolerance,
 };
 use crate::graph::node::MetaMap;
-use crate::graph::value::Value;
+use crate::graph::value::{CurveValue, Value};
 
+use super::schema::PinDefault;
 use super::{Component, ComponentError, ComponentResult};
 
 const PIN_OUTPUT_CURVES: &str = "C";
@@ -247,6 +248,70 @@ impl Component for ComponentKind {
 }
 
 impl ComponentKind {
+    /// Waarden die gebruikt worden als de betreffende input leeg blijft.
+    #[must_use]
+    pub fn input_defaults(&self) -> &'static [(&'static str, PinDefault)] {
+        match self {
+            Self::OffsetCurve
+            | Self::OffsetCurveLoose
+            | Self::OffsetLoose3d
+            | Self::OffsetPolyline
+            | Self::FilletDistance
+            | Self::OffsetOnSurface => &[("D", PinDefault::Number(0.0))],
+            Self::CurveToPolyline => &[
+                ("Td", PinDefault::Number(0.01)),
+                ("Ta", PinDefault::Number(0.0)),
+                ("E-", PinDefault::Number(0.0)),
+            ],
+            Self::SmoothPolyline => &[
+                ("S", PinDefault::Number(0.5)),
+                ("T", PinDefault::Number(1.0)),
+            ],
+            Self::JoinCurves => &[("P", PinDefault::Boolean(false))],
+            Self::Reduce => &[("T", PinDefault::Number(0.01))],
+            Self::SimplifyCurve => &[
+                ("t", PinDefault::Number(0.01)),
+                ("a", PinDefault::Number(0.0)),
+            ],
+            Self::FitCurve => &[
+                ("D", PinDefault::Number(3.0)),
+                ("Ft", PinDefault::Number(0.01)),
+            ],
+            Self::PolylineCollapse => &[("t", PinDefault::Number(0.01))],
+            Self::FilletRadius => &[("R", PinDefault::Number(0.0))],
+            Self::Seam => &[("t", PinDefault::Number(0.0))],
+            Self::ExtendCurve => &[
+                ("L0", PinDefault::Number(0.0)),
+                ("L1", PinDefault::Number(0.0)),
+            ],
+            Self::FilletParameter => &[
+                ("t", PinDefault::Number(0.5)),
+                ("R", PinDefault::Number(0.0)),
+            ],
+            _ => &[],
+        }
+    }
+
+    /// Inputs die leeg mogen blijven zonder vaste standaardwaarde.
+    #[must_use]
+    pub fn optional_input_pins(&self) -> &'static [&'static str] {
+        match self {
+            Self::FlipCurve => &["G"],
+            Self::CurveToPolyline => &["E+"],
+            Self::ProjectCurve => &["D"],
+            _ => &[],
+        }
+    }
+
+    /// Componenten die exacte `Value::Curve` invoer rechtstreeks evalueren.
+    #[must_use]
+    pub fn handles_curves(self) -> bool {
+        matches!(
+            self,
+            Self::OffsetCurve | Self::OffsetCurveLoose | Self::OffsetLoose3d
+        )
+    }
+
     #[must_use]
     pub fn name(&self) -> &'static str {
         match self {
@@ -285,7 +350,14 @@ fn evaluate_offset_curve(inputs: &[Value], closed_hint: bool) -> ComponentResult
         ));
     }
 
-    let points = coerce_polyline(inputs.get(0), "Offset Curve")?;
+    let exact = match inputs.first() {
+        Some(Value::Curve(curve)) => Some(curve),
+        _ => None,
+    };
+    let points = match exact {
+        Some(curve) => curve.tessellate(CurveValue::DEFAULT_TESSELLATION),
+        None => coerce_polyline(inputs.get(0), "Offset Curve")?,
+    };
     let distance = coerce_number(inputs.get(1), "Offset Curve").unwrap_or(0.0);
 
     // Build offset options using geom module
@@ -298,13 +370,14 @@ fn evaluate_offset_curve(inputs: &[Value], closed_hint: bool) -> ComponentResult
     let mut options = OffsetPolylineOptions::new(distance).closed(closed);
 
     // If a plane is provided, extract its properties
-    if let Some(plane_value) = inputs.get(2) {
-        if let Ok(plane) = coerce_plane(Some(plane_value), "Offset Curve") {
-            options = options
-                .with_plane_origin(plane.origin)
-                .with_plane_normal(plane.normal)
-                .with_plane_x_axis(plane.x_axis);
-        }
+    let plane = inputs
+        .get(2)
+        .and_then(|value| coerce_plane(Some(value), "Offset Curve").ok());
+    if let Some(plane) = &plane {
+        options = options
+            .with_plane_origin(plane.origin)
+            .with_plane_normal(plane.normal)
+            .with_plane_x_axis(plane.x_axis);
     }
 
     // Call geom offset function
@@ -315,14 +388,73 @@ fn evaluate_offset_curve(inputs: &[Value], closed_hint: bool) -> ComponentResult
         }
     };
 
+    let curve = exact
+        .and_then(|curve| offset_exact_curve(curve, &offset, distance, plane.as_ref()))
+        .map_or_else(|| polyline_to_value(offset), Value::Curve);
+
     let mut outputs = BTreeMap::new();
-    outputs.insert(
-        PIN_OUTPUT_CURVES.to_owned(),
-        Value::List(vec![polyline_to_value(offset)]),
-    );
+    outputs.insert(PIN_OUTPUT_CURVES.to_owned(), Value::List(vec![curve]));
     Ok(outputs)
 }
 
+/// Exacte offset van lijnen, cirkels en bogen in hun eigen vlak. De richting
+/// komt uit de polyline-offset `offset` van dezelfde curve, zodat beide paden
+/// naar dezelfde kant verschuiven. Geeft `None` voor andere curves, voor een
+/// vlak dat de curve niet bevat en als de straal zou verdwijnen.
+fn offset_exact_curve(
+    curve: &CurveValue,
+    offset: &[[f64; 3]],
+    distance: f64,
+    plane: Option<&Plane>,
+) -> Option<CurveValue> {
+    let (center, normal, radius) = match curve {
+        CurveValue::Line(_) => {
+            let (start, end) = (offset.first()?, offset.last()?);
+            return Some(CurveValue::Line(geom::Line3::new(
+                geom::Point3::from_array(*start),
+                geom::Point3::from_array(*end),
+            )));
+        }
+        CurveValue::Circle(circle) => {
+            (circle.center, circle.x_axis.cross(circle.y_axis), circle.radius)
+        }
+        CurveValue::Arc(arc) => (arc.center, arc.x_axis.cross(arc.y_axis), arc.radius),
+        _ => return None,
+    };
+
+    if let Some(plane) = plane {
+        let plane_normal = geom::Vec3::from_array(plane.normal);
+        let height = center
+            .sub_point(geom::Point3::from_array(plane.origin))
+            .dot(plane_normal);
+        if normal.cross(plane_normal).length() > EPSILON || height.abs() > EPSILON {
+            return None;
+        }
+    }
+
+    let reach = geom::Point3::from_array(*offset.first()?).sub_point(center).length();
+    let offset_radius = if reach > radius {
+        radius + distance.abs()
+    } else {
+        radius - distance.abs()
+    };
+    if offset_radius <= EPSILON {
+        return None;
+    }
+
+    Some(match curve {
+        CurveValue::Circle(circle) => CurveValue::Circle(geom::Circle3 {
+            radius: offset_radius,
+            ..*circle
+        }),
+        CurveValue::Arc(arc) => CurveValue::Arc(geom::Arc3 {
+            radius: offset_radius,
+            ..*arc
+        }),
+        _ => return None,
+    })
+}
+
 fn evaluate_offset_polyline(inputs: &[Value]) -> ComponentResult {
     if inputs.is_empty() {
         return Err(ComponentError::new(
@@ -0,0 +1,363 @@
+//! Gedeelde invoer- en uitvoerhulpen voor de Intersect-componenten.
+//!
+//! Item-pinnen krijgen van de evaluator één waarde per aanroep; de
+//! `coerce_*`-functies zetten die om en geven `None` voor een lege invoer.
+//! Lijstpinnen worden met de `collect_*`-functies in hun geheel gelezen.
+
+use crate::geom::{
+    Curve3, GeomMesh, Line3, MeshRayHit, ParamPolyline, Point3, Polyline3, Region2, RegionPlane,
+    Tolerance, Vec3,
+};
+use crate::graph::value::{CurveValue, Value};
+
+use super::ComponentError;
+use super::coerce::{self, Plane, geom_bridge};
+
+/// Aantal samples voor curves die geen polyline of lijn zijn.
+const CURVE_SAMPLES: usize = 128;
+
+/// Eén curve: een exacte curve, een lijn of een puntenlijst als polyline.
+pub(crate) fn coerce_curve(
+    value: Option<&Value>,
+    context: &str,
+) -> Result<Option<CurveValue>, ComponentError> {
+    match value {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::Curve(curve)) => Ok(Some(curve.clone())),
+        Some(Value::CurveLine { p1, p2 }) => Ok(Some(CurveValue::Line(Line3::new(
+            Point3::from(*p1),
+            Point3::from(*p2),
+        )))),
+        Some(Value::List(values)) if is_point_list(values) => {
+            let points: Vec<Point3> = values
+                .iter()
+                .filter_map(|value| match value {
+                    Value::Point(point) => Some(Point3::from(*point)),
+                    _ => None,
+                })
+                .collect();
+            let closed = points.len() > 2 && points.first() == points.last();
+            let polyline = Polyline3::new(points, closed).map_err(|_| {
+                ComponentError::new(format!(
+                    "{context} vereist een curve met minstens twee punten"
+                ))
+            })?;
+            Ok(Some(CurveValue::Polyline(polyline)))
+        }
+        Some(other) => Err(ComponentError::new(format!(
+            "{} verwacht een curve, kreeg {}",
+            context,
+            other.kind()
+        ))),
+    }
+}
+
+/// Eén lijn: een lijnwaarde of een puntenlijst van twee punten.
+pub(crate) fn coerce_line(
+    value: Option<&Value>,
+    context: &str,
+) -> Result<Option<Line3>, ComponentError> {
+    let from_points = |value: &Value| {
+        let line = coerce::coerce_line(value, context)?;
+        Ok(Some(Line3::new(line.start.into(), line.end.into())))
+    };
+    match value {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::Curve(CurveValue::Line(line))) => Ok(Some(*line)),
+        Some(value @ Value::CurveLine { .. }) => from_points(value),
+        Some(value @ Value::List(values)) if is_point_list(values) => from_points(value),
+        Some(other) => Err(ComponentError::new(format!(
+            "{} verwacht een lijn, kreeg {}",
+            context,
+            other.kind()
+        ))),
+    }
+}
+
+/// Eén vlak, ook als lijst van drie punten.
+pub(crate) fn coerce_plane(
+    value: Option<&Value>,
+    context: &str,
+) -> Result<Option<Plane>, ComponentError> {
+    match value {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::List(values)) if values.is_empty() => Ok(None),
+        Some(value) => coerce::coerce_plane(value, context).map(Some),
+    }
+}
+
+/// Eén mesh, brep of oppervlak als mesh.
+pub(crate) fn coerce_mesh(
+    value: Option<&Value>,
+    context: &str,
+) -> Result<Option<GeomMesh>, ComponentError> {
+    match value {
+        None | Some(Value::Null) => Ok(None),
+        Some(value) => coerce::coerce_mesh_like_with_context(value, context)
+            .map(|mesh| Some(geom_bridge::mesh_to_geom_mesh(mesh))),
+    }
+}
+
+/// Eén optionele waarde; `None` als de invoer leeg is.
+pub(crate) fn optional_item<T>(
+    value: Option<&Value>,
+    coerce_item: impl FnOnce(&Value) -> Result<T, ComponentError>,
+) -> Result<Option<T>, ComponentError> {
+    match value {
+        None | Some(Value::Null) => Ok(None),
+        Some(value) => coerce_item(value).map(Some),
+    }
+}
+
+/// Alle curves van een lijstpin; puntenlijsten tellen als één polyline.
+pub(crate) fn collect_curves(
+    value: Option<&Value>,
+    context: &str,
+) -> Result<Vec<CurveValue>, ComponentError> {
+    fn walk(
+        value: &Value,
+        context: &str,
+        output: &mut Vec<CurveValue>,
+    ) -> Result<(), ComponentError> {
+        match value {
+            Value::List(values) if !is_point_list(values) => values
+                .iter()
+                .try_for_each(|entry| walk(entry, context, output)),
+            other => {
+                output.extend(coerce_curve(Some(other), context)?);
+                Ok(())
+            }
+        }
+    }
+
+    let mut curves = Vec::new();
+    if let Some(value) = value {
+        walk(value, context, &mut curves)?;
+    }
+    Ok(curves)
+}
+
+/// Alle meshes, breps en oppervlakken van een lijstpin als mesh.
+pub(crate) fn collect_meshes(
+    value: Option<&Value>,
+    context: &str,
+) -> Result<Vec<GeomMesh>, ComponentError> {
+    fn walk(
+        value: &Value,
+        context: &str,
+        output: &mut Vec<GeomMesh>,
+    ) -> Result<(), ComponentError> {
+        match value {
+            Value::List(values) => values
+                .iter()
+                .try_for_each(|entry| walk(entry, context, output)),
+            other => {
+                output.extend(coerce_mesh(Some(other), context)?);
+                Ok(())
+            }
+        }
+    }
+
+    let mut meshes = Vec::new();
+    if let Some(value) = value {
+        walk(value, context, &mut meshes)?;
+    }
+    Ok(meshes)
+}
+
+pub(crate) fn is_point_list(values: &[Value]) -> bool {
+    !values.is_empty() && values.iter().all(|value| matches!(value, Value::Point(_)))
+}
+
+/// Polyline-benadering van een curve met de curveparameter per punt. Lijnen
+/// en polylines zijn exact; andere curves worden bemonsterd.
+pub(crate) fn param_polyline(curve: &CurveValue) -> ParamPolyline {
+    let (t0, t1) = curve.domain();
+    match curve {
+        CurveValue::Line(line) => ParamPolyline::new(vec![line.start, line.end], vec![t0, t1]),
+        CurveValue::Polyline(polyline) => {
+            let mut points = polyline.points().to_vec();
+            if polyline.is_closed() {
+                points.push(points[0]);
+            }
+            let mut lengths = Vec::with_capacity(points.len());
+            let mut total = 0.0;
+            for (index, point) in points.iter().enumerate() {
+                if index > 0 {
+                    total += point.distance_to(points[index - 1]);
+                }
+                lengths.push(total);
+            }
+            let params = lengths
+                .into_iter()
+                .map(|length| {
+                    if total > 0.0 {
+                        t0 + (t1 - t0) * length / total
+                    } else {
+                        t0
+                    }
+                })
+                .collect();
+            ParamPolyline::new(points, params)
+        }
+        other => ParamPolyline::sample(other, CURVE_SAMPLES),
+    }
+}
+
+/// Een gesloten invoercurve met de hoekpunten waarmee ze als regio meedoet.
+#[derive(Debug, Clone)]
+pub(crate) struct CurveLoop {
+    pub(crate) curve: CurveValue,
+    pub(crate) points: Vec<Point3>,
+}
+
+pub(crate) fn curve_loops(
+    value: Option<&Value>,
+    context: &str,
+) -> Result<Vec<CurveLoop>, ComponentError> {
+    collect_curves(value, context)?
+        .into_iter()
+        .map(|curve| {
+            let mut points: Vec<Point3> = match &curve {
+                CurveValue::Polyline(polyline) => polyline.points().to_vec(),
+                other => other
+                    .tessellate(CurveValue::DEFAULT_TESSELLATION)
+                    .into_iter()
+                    .map(Point3::from_array)
+                    .collect(),
+            };
+            let closed = matches!(&curve, CurveValue::Polyline(polyline) if polyline.is_closed())
+                || match (points.first(), points.last()) {
+                    (Some(first), Some(last)) => {
+                        first.sub_point(*last).length() <= Tolerance::LOOSE.eps
+                    }
+                    _ => false,
+                };
+            if !closed || points.len() < 3 {
+                return Err(ComponentError::new(format!(
+                    "{context} vereist gesloten curves"
+                )));
+            }
+            // Het herhaalde sluitpunt hoort niet bij de regio.
+            if points.len() > 3
+                && points[0].sub_point(points[points.len() - 1]).length() <= Tolerance::LOOSE.eps
+            {
+                points.pop();
+            }
+            Ok(CurveLoop { curve, points })
+        })
+        .collect()
+}
+
+/// Het vlak uit de P-invoer; `None` als de invoer leeg is.
+pub(crate) fn explicit_plane(
+    value: Option<&Value>,
+    context: &str,
+) -> Result<Option<RegionPlane>, ComponentError> {
+    let Some(plane) = coerce_plane(value, context)? else {
+        return Ok(None);
+    };
+    RegionPlane::new(
+        Point3::from_array(plane.origin),
+        Vec3::from_array(plane.x_axis),
+        Vec3::from_array(plane.y_axis),
+    )
+    .map(Some)
+    .ok_or_else(|| ComponentError::new(format!("{context} vereist een geldig vlak")))
+}
+
+pub(crate) fn project_loops(loops: &[CurveLoop], plane: &RegionPlane) -> Vec<Region2> {
+    loops
+        .iter()
+        .map(|curve_loop| {
+            Region2::new(
+                curve_loop
+                    .points
+                    .iter()
+                    .map(|point| plane.to_local(*point))
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+/// Parameter-`uv` van een mesh-hit, als de mesh uv-coördinaten heeft.
+pub(crate) fn hit_uv_value(mesh: &GeomMesh, hit: &MeshRayHit) -> Value {
+    let Some(uvs) = mesh.uvs.as_ref() else {
+        return Value::Null;
+    };
+    let corners = &mesh.indices[hit.triangle * 3..hit.triangle * 3 + 3];
+    let mut uv = [0.0, 0.0, 0.0];
+    for (corner, weight) in corners.iter().zip(hit.barycentric) {
+        let Some(vertex_uv) = uvs.get(*corner as usize) else {
+            return Value::Null;
+        };
+        uv[0] += vertex_uv[0] * weight;
+        uv[1] += vertex_uv[1] * weight;
+    }
+    Value::Point(uv)
+}
+
+/// Normaal op een mesh-hit: geïnterpoleerde vertexnormalen, anders de
+/// driehoeksnormaal.
+pub(crate) fn hit_normal(mesh: &GeomMesh, hit: &MeshRayHit) -> Vec3 {
+    let Some(normals) = mesh.normals.as_ref() else {
+        return hit.normal;
+    };
+    let corners = &mesh.indices[hit.triangle * 3..hit.triangle * 3 + 3];
+    let mut normal = Vec3::ZERO;
+    for (corner, weight) in corners.iter().zip(hit.barycentric) {
+        let Some(vertex_normal) = normals.get(*corner as usize) else {
+            return hit.normal;
+        };
+        normal = normal + Vec3::from(*vertex_normal) * weight;
+    }
+    normal.normalized().unwrap_or(hit.normal)
+}
+
+pub(crate) fn point_value(point: Point3) -> Value {
+    Value::Point(point.to_array())
+}
+
+/// Gedeelde invoer voor de tests van de Intersect-modules.
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use crate::components::OutputMap;
+    use crate::graph::node::MetaMap;
+    use crate::graph::value::Value;
+
+    pub(crate) fn meta() -> MetaMap {
+        MetaMap::new()
+    }
+
+    /// Een kubus met ribbe `size` als oppervlak met zes vierhoekige vlakken.
+    pub(crate) fn cube(origin: [f64; 3], size: f64) -> Value {
+        let [x, y, z] = origin;
+        let vertices = vec![
+            [x, y, z],
+            [x + size, y, z],
+            [x + size, y + size, z],
+            [x, y + size, z],
+            [x, y, z + size],
+            [x + size, y, z + size],
+            [x + size, y + size, z + size],
+            [x, y + size, z + size],
+        ];
+        let faces = vec![
+            vec![0, 3, 2, 1],
+            vec![4, 5, 6, 7],
+            vec![0, 1, 5, 4],
+            vec![1, 2, 6, 5],
+            vec![2, 3, 7, 6],
+            vec![3, 0, 4, 7],
+        ];
+        Value::Surface { vertices, faces }
+    }
+
+    pub(crate) fn list(outputs: &OutputMap, pin: &str) -> Vec<Value> {
+        match outputs.get(pin) {
+            Some(Value::List(values)) => values.clone(),
+            other => panic!("expected list on {pin}, got {other:?}"),
+        }
+    }
+}
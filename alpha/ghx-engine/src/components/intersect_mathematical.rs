@@ -0,0 +1,1148 @@
+//! Implementaties van Grasshopper "Intersect → Mathematical" componenten.
+//!
+//! Kruisingen van curves met vlakken gebruiken
+//! `geom::curve_plane_intersections_refined`, doorsneden van meshes en breps
+//! gebruiken `geom::mesh_plane_section` en elke straal- of lijnquery tegen een
+//! mesh loopt via de BVH in `geom::mesh_ray_hits`. Breps en oppervlakken worden
+//! eerst met hun weergavetessellatie gemesht.
+
+use std::collections::BTreeMap;
+
+use crate::geom::{
+    Curve3, GeomMesh, Line3, Point3 as GeomPoint3, Tolerance, Vec3 as GeomVec3,
+    curve_plane_intersections_refined, mesh_plane_section, mesh_ray_hits,
+};
+use crate::graph::node::MetaMap;
+use crate::graph::value::{CurveValue, Value};
+
+use super::coerce::{self, Plane, geom_bridge};
+use super::intersect_common::{
+    coerce_curve, coerce_line, coerce_mesh, coerce_plane, collect_curves, hit_normal, hit_uv_value,
+    is_point_list, optional_item, point_value,
+};
+use super::{Component, ComponentError, ComponentResult, OutputMap, PinAccess};
+
+const PIN_OUTPUT_POINT: &str = "P";
+const PIN_OUTPUT_POINTS: &str = "P";
+const PIN_OUTPUT_PARAMS: &str = "t";
+const PIN_OUTPUT_COUNT: &str = "N";
+const PIN_OUTPUT_UV: &str = "uv";
+const PIN_OUTPUT_LINE: &str = "L";
+const PIN_OUTPUT_CURVES: &str = "C";
+const PIN_OUTPUT_NORMALS: &str = "N";
+const PIN_OUTPUT_PARAM_A: &str = "tA";
+const PIN_OUTPUT_PARAM_B: &str = "tB";
+const PIN_OUTPUT_POINT_A: &str = "pA";
+const PIN_OUTPUT_POINT_B: &str = "pB";
+const PIN_OUTPUT_INTERSECTION: &str = "Pt";
+const PIN_OUTPUT_LINE_AB: &str = "AB";
+const PIN_OUTPUT_LINE_AC: &str = "AC";
+const PIN_OUTPUT_LINE_BC: &str = "BC";
+const PIN_OUTPUT_RAY_POINT: &str = "X";
+const PIN_OUTPUT_HIT: &str = "H";
+const PIN_OUTPUT_DISTANCE: &str = "D";
+const PIN_OUTPUT_INDEX: &str = "I";
+
+/// Minimaal aantal samples bij het zoeken naar curve/vlak-snijpunten.
+const CURVE_SAMPLES: usize = 128;
+
+/// Afstand waarbinnen een curvepunt als "op de lijn" geldt.
+const LINE_TOLERANCE: f64 = 1e-6;
+
+const EPSILON: f64 = 1e-9;
+
+/// Beschikbare componenten binnen deze module.
+#[derive(Debug, Clone, Copy)]
+pub enum ComponentKind {
+    CurveLine,
+    CurvePlane,
+    LineLine,
+    LinePlane,
+    PlanePlane,
+    PlanePlanePlane,
+    MeshPlane,
+    MeshRay,
+    BrepPlane,
+    BrepLine,
+    SurfaceLine,
+    IsoVistRayHits,
+    IsoVistRayIndex,
+    IsoVistHits,
+    IsoVistIndex,
+}
+
+/// Metadata voor registraties in de componentregistry.
+#[derive(Debug, Clone, Copy)]
+pub struct Registration {
+    pub guids: &'static [&'static str],
+    pub names: &'static [&'static str],
+    pub kind: ComponentKind,
+}
+
+/// Volledige lijst met componentregistraties voor de mathematische
+/// snijcomponenten. De bijnaam "Sec" van de sectiecomponenten wordt bewust
+/// niet geregistreerd; die hoort al bij Secant.
+pub const REGISTRATIONS: &[Registration] = &[
+    Registration {
+        guids: &[
+            "{0e3173b6-91c6-4845-a748-e45d4fdbc262}",
+            "{9396be03-8159-43bf-b3e7-2c86c8d04fc0}",
+        ],
+        names: &["Curve | Line", "CLX"],
+        kind: ComponentKind::CurveLine,
+    },
+    Registration {
+        guids: &["{b7c12ed1-b09a-4e15-996f-3fa9f3f16b1c}"],
+        names: &["Curve | Plane", "PCX"],
+        kind: ComponentKind::CurvePlane,
+    },
+    Registration {
+        guids: &["{6d4b82a7-8c1d-4bec-af7b-ca321ba4beb1}"],
+        names: &["Line | Line", "LLX"],
+        kind: ComponentKind::LineLine,
+    },
+    Registration {
+        guids: &["{75d0442c-1aa3-47cf-bd94-457b42c16e9f}"],
+        names: &["Line | Plane", "PLX"],
+        kind: ComponentKind::LinePlane,
+    },
+    Registration {
+        guids: &["{290cf9c4-0711-4704-851e-4c99e3343ac5}"],
+        names: &["Plane | Plane", "PPX"],
+        kind: ComponentKind::PlanePlane,
+    },
+    Registration {
+        guids: &["{f1ea5a4b-1a4f-4cf4-ad94-1ecfb9302b6e}"],
+        names: &["Plane | Plane | Plane", "3PX"],
+        kind: ComponentKind::PlanePlanePlane,
+    },
+    Registration {
+        guids: &["{3b1ae469-0e9b-461d-8c30-fa5a7de8b7a9}"],
+        names: &["Mesh | Plane"],
+        kind: ComponentKind::MeshPlane,
+    },
+    Registration {
+        guids: &["{4c02a168-9aba-4f42-8951-2719f24d391f}"],
+        names: &["Mesh | Ray", "MeshRay"],
+        kind: ComponentKind::MeshRay,
+    },
+    Registration {
+        guids: &["{4fe828e8-fa95-4cc5-9a8c-c33856ecc783}"],
+        names: &["Brep | Plane"],
+        kind: ComponentKind::BrepPlane,
+    },
+    Registration {
+        guids: &[
+            "{ed0742f9-6647-4d95-9dfd-9ad17080ae9c}",
+            "{ff880808-6daf-4f6c-88c1-058120ad6ba9}",
+        ],
+        names: &["Brep | Line", "BLX"],
+        kind: ComponentKind::BrepLine,
+    },
+    Registration {
+        guids: &[
+            "{a834e823-ae01-44d8-9066-c138eeb6f391}",
+            "{c2c73357-bfd2-45af-89ff-40ca02a3442f}",
+        ],
+        names: &["Surface | Line", "SLX", "SCX"],
+        kind: ComponentKind::SurfaceLine,
+    },
+    Registration {
+        guids: &["{769f5b35-1780-4823-b593-118ecc3560e0}"],
+        names: &[],
+        kind: ComponentKind::IsoVistRayHits,
+    },
+    Registration {
+        guids: &["{93d0dcbc-6207-4745-aaf7-fe57a880f959}"],
+        names: &["IsoVist Ray", "IVRay"],
+        kind: ComponentKind::IsoVistRayIndex,
+    },
+    Registration {
+        guids: &["{c08ac8f7-cf90-4cdb-9862-2ba66b8408ef}"],
+        names: &[],
+        kind: ComponentKind::IsoVistHits,
+    },
+    Registration {
+        guids: &["{cab92254-1c79-4e5a-9972-0a4412b35c88}"],
+        names: &["IsoVist", "IVist"],
+        kind: ComponentKind::IsoVistIndex,
+    },
+];
+
+impl Component for ComponentKind {
+    fn evaluate(&self, inputs: &[Value], _meta: &MetaMap) -> ComponentResult {
+        match self {
+            Self::CurveLine => evaluate_curve_line(inputs),
+            Self::CurvePlane => evaluate_curve_plane(inputs),
+            Self::LineLine => evaluate_line_line(inputs),
+            Self::LinePlane => evaluate_line_plane(inputs),
+            Self::PlanePlane => evaluate_plane_plane(inputs),
+            Self::PlanePlanePlane => evaluate_plane_plane_plane(inputs),
+            Self::MeshPlane => evaluate_section(inputs, "Mesh | Plane", false),
+            Self::MeshRay => evaluate_mesh_ray(inputs),
+            Self::BrepPlane => evaluate_section(inputs, "Brep | Plane", true),
+            Self::BrepLine => evaluate_shape_line(inputs, "Brep | Line", false),
+            Self::SurfaceLine => evaluate_shape_line(inputs, "Surface | Line", true),
+            Self::IsoVistRayHits => evaluate_isovist_ray(inputs, IsoVistOutput::Hits),
+            Self::IsoVistRayIndex => evaluate_isovist_ray(inputs, IsoVistOutput::Index),
+            Self::IsoVistHits => evaluate_isovist(inputs, IsoVistOutput::Hits),
+            Self::IsoVistIndex => evaluate_isovist(inputs, IsoVistOutput::Index),
+        }
+    }
+
+    fn input_access(&self) -> &'static [PinAccess] {
+        match self {
+            Self::IsoVistRayHits | Self::IsoVistRayIndex => {
+                &[PinAccess::Item, PinAccess::Item, PinAccess::List]
+            }
+            Self::IsoVistHits | Self::IsoVistIndex => &[
+                PinAccess::Item,
+                PinAccess::Item,
+                PinAccess::Item,
+                PinAccess::List,
+            ],
+            _ => &[PinAccess::Item],
+        }
+    }
+}
+
+impl ComponentKind {
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::CurveLine => "Curve | Line",
+            Self::CurvePlane => "Curve | Plane",
+            Self::LineLine => "Line | Line",
+            Self::LinePlane => "Line | Plane",
+            Self::PlanePlane => "Plane | Plane",
+            Self::PlanePlanePlane => "Plane | Plane | Plane",
+            Self::MeshPlane => "Mesh | Plane",
+            Self::MeshRay => "Mesh | Ray",
+            Self::BrepPlane => "Brep | Plane",
+            Self::BrepLine => "Brep | Line",
+            Self::SurfaceLine => "Surface | Line",
+            Self::IsoVistRayHits | Self::IsoVistRayIndex => "IsoVist Ray",
+            Self::IsoVistHits | Self::IsoVistIndex => "IsoVist",
+        }
+    }
+}
+
+/// Welke derde uitvoer een IsoVist-component levert.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IsoVistOutput {
+    /// Booleans die aangeven of een straal een obstakel raakte.
+    Hits,
+    /// Index van het geraakte obstakel, of -1.
+    Index,
+}
+
+fn evaluate_curve_line(inputs: &[Value]) -> ComponentResult {
+    let context = "Curve | Line";
+    let (Some(curve), Some(line)) = (
+        coerce_curve(inputs.get(0), context)?,
+        coerce_line(inputs.get(1), context)?,
+    ) else {
+        return Ok(OutputMap::new());
+    };
+    let first_only = coerce_flag(inputs.get(2), context)?;
+
+    let mut hits = curve_line_intersections(&curve, line);
+    if first_only {
+        hits.truncate(1);
+    }
+
+    let mut outputs = BTreeMap::new();
+    outputs.insert(
+        PIN_OUTPUT_COUNT.to_owned(),
+        Value::Number(hits.len() as f64),
+    );
+    outputs.insert(
+        PIN_OUTPUT_PARAMS.to_owned(),
+        Value::List(hits.iter().map(|(_, t)| Value::Number(*t)).collect()),
+    );
+    outputs.insert(
+        PIN_OUTPUT_POINTS.to_owned(),
+        Value::List(hits.iter().map(|(point, _)| point_value(*point)).collect()),
+    );
+    Ok(outputs)
+}
+
+fn evaluate_curve_plane(inputs: &[Value]) -> ComponentResult {
+    let context = "Curve | Plane";
+    let (Some(curve), Some(plane)) = (
+        coerce_curve(inputs.get(0), context)?,
+        coerce_plane(inputs.get(1), context)?,
+    ) else {
+        return Ok(OutputMap::new());
+    };
+
+    let hits = curve_plane_intersections_refined(
+        &curve,
+        GeomPoint3::from(plane.origin),
+        GeomVec3::from(plane.z_axis),
+        curve_samples(&curve),
+    );
+
+    let mut outputs = BTreeMap::new();
+    outputs.insert(
+        PIN_OUTPUT_POINTS.to_owned(),
+        Value::List(hits.iter().map(|(point, _)| point_value(*point)).collect()),
+    );
+    outputs.insert(
+        PIN_OUTPUT_PARAMS.to_owned(),
+        Value::List(hits.iter().map(|(_, t)| Value::Number(*t)).collect()),
+    );
+    outputs.insert(
+        PIN_OUTPUT_UV.to_owned(),
+        Value::List(
+            hits.iter()
+                .map(|(point, _)| plane_uv_value(&plane, *point))
+                .collect(),
+        ),
+    );
+    Ok(outputs)
+}
+
+fn evaluate_line_line(inputs: &[Value]) -> ComponentResult {
+    let context = "Line | Line";
+    let (Some(a), Some(b)) = (
+        coerce_line(inputs.get(0), context)?,
+        coerce_line(inputs.get(1), context)?,
+    ) else {
+        return Ok(OutputMap::new());
+    };
+
+    let mut outputs = BTreeMap::new();
+    let Some((ta, tb)) = closest_line_parameters(a, b) else {
+        for pin in [
+            PIN_OUTPUT_PARAM_A,
+            PIN_OUTPUT_PARAM_B,
+            PIN_OUTPUT_POINT_A,
+            PIN_OUTPUT_POINT_B,
+        ] {
+            outputs.insert(pin.to_owned(), Value::Null);
+        }
+        return Ok(outputs);
+    };
+
+    outputs.insert(PIN_OUTPUT_PARAM_A.to_owned(), Value::Number(ta));
+    outputs.insert(PIN_OUTPUT_PARAM_B.to_owned(), Value::Number(tb));
+    outputs.insert(
+        PIN_OUTPUT_POINT_A.to_owned(),
+        point_value(a.start.lerp(a.end, ta)),
+    );
+    outputs.insert(
+        PIN_OUTPUT_POINT_B.to_owned(),
+        point_value(b.start.lerp(b.end, tb)),
+    );
+    Ok(outputs)
+}
+
+fn evaluate_line_plane(inputs: &[Value]) -> ComponentResult {
+    let context = "Line | Plane";
+    let (Some(line), Some(plane)) = (
+        coerce_line(inputs.get(0), context)?,
+        coerce_plane(inputs.get(1), context)?,
+    ) else {
+        return Ok(OutputMap::new());
+    };
+
+    let mut outputs = BTreeMap::new();
+    match line_plane_parameter(line, &plane) {
+        Some(t) => {
+            let point = line.start.lerp(line.end, t);
+            outputs.insert(PIN_OUTPUT_POINT.to_owned(), point_value(point));
+            outputs.insert(PIN_OUTPUT_PARAMS.to_owned(), Value::Number(t));
+            outputs.insert(PIN_OUTPUT_UV.to_owned(), plane_uv_value(&plane, point));
+        }
+        None => {
+            for pin in [PIN_OUTPUT_POINT, PIN_OUTPUT_PARAMS, PIN_OUTPUT_UV] {
+                outputs.insert(pin.to_owned(), Value::Null);
+            }
+        }
+    }
+    Ok(outputs)
+}
+
+fn evaluate_plane_plane(inputs: &[Value]) -> ComponentResult {
+    let context = "Plane | Plane";
+    let (Some(a), Some(b)) = (
+        coerce_plane(inputs.get(0), context)?,
+        coerce_plane(inputs.get(1), context)?,
+    ) else {
+        return Ok(OutputMap::new());
+    };
+
+    let mut outputs = BTreeMap::new();
+    outputs.insert(
+        PIN_OUTPUT_LINE.to_owned(),
+        plane_plane_line(&a, &b).map_or(Value::Null, line_value),
+    );
+    Ok(outputs)
+}
+
+fn evaluate_plane_plane_plane(inputs: &[Value]) -> ComponentResult {
+    let context = "Plane | Plane | Plane";
+    let (Some(a), Some(b), Some(c)) = (
+        coerce_plane(inputs.get(0), context)?,
+        coerce_plane(inputs.get(1), context)?,
+        coerce_plane(inputs.get(2), context)?,
+    ) else {
+        return Ok(OutputMap::new());
+    };
+
+    let mut outputs = BTreeMap::new();
+    outputs.insert(
+        PIN_OUTPUT_INTERSECTION.to_owned(),
+        three_plane_point(&a, &b, &c).map_or(Value::Null, point_value),
+    );
+    for (pin, (p, q)) in [
+        (PIN_OUTPUT_LINE_AB, (&a, &b)),
+        (PIN_OUTPUT_LINE_AC, (&a, &c)),
+        (PIN_OUTPUT_LINE_BC, (&b, &c)),
+    ] {
+        outputs.insert(
+            pin.to_owned(),
+            plane_plane_line(p, q).map_or(Value::Null, line_value),
+        );
+    }
+    Ok(outputs)
+}
+
+fn evaluate_section(inputs: &[Value], context: &str, with_points: bool) -> ComponentResult {
+    let (Some(mesh), Some(plane)) = (
+        coerce_mesh(inputs.get(0), context)?,
+        coerce_plane(inputs.get(1), context)?,
+    ) else {
+        return Ok(OutputMap::new());
+    };
+
+    let sections = mesh_plane_section(
+        &mesh,
+        GeomPoint3::from(plane.origin),
+        GeomVec3::from(plane.z_axis),
+        Tolerance::default_geom(),
+    );
+
+    let curves = sections
+        .into_iter()
+        .map(|section| {
+            let mut points: Vec<Value> = section.points.iter().copied().map(point_value).collect();
+            if section.closed {
+                points.push(points[0].clone());
+            }
+            Value::List(points)
+        })
+        .collect();
+
+    let mut outputs = BTreeMap::new();
+    outputs.insert(PIN_OUTPUT_CURVES.to_owned(), Value::List(curves));
+    if with_points {
+        // Geïsoleerde raakpunten ontstaan niet uit een mesh-doorsnede.
+        outputs.insert(PIN_OUTPUT_POINTS.to_owned(), Value::List(Vec::new()));
+    }
+    Ok(outputs)
+}
+
+fn evaluate_mesh_ray(inputs: &[Value]) -> ComponentResult {
+    let context = "Mesh | Ray";
+    let Some(mesh) = coerce_mesh(inputs.get(0), context)? else {
+        return Ok(OutputMap::new());
+    };
+    let (Some(origin), Some(direction)) = (
+        optional_item(inputs.get(1), |value| {
+            coerce::coerce_point_with_context(value, context)
+        })?,
+        optional_item(inputs.get(2), |value| coerce::coerce_vector(value, context))?,
+    ) else {
+        return Ok(OutputMap::new());
+    };
+
+    let hit = mesh_ray_hits(
+        &mesh,
+        GeomPoint3::from(origin),
+        GeomVec3::from(direction),
+        0.0,
+        f64::INFINITY,
+        Tolerance::default_geom(),
+    )
+    .into_iter()
+    .next();
+
+    let mut outputs = BTreeMap::new();
+    outputs.insert(
+        PIN_OUTPUT_RAY_POINT.to_owned(),
+        hit.map_or(Value::Null, |hit| point_value(hit.point)),
+    );
+    outputs.insert(PIN_OUTPUT_HIT.to_owned(), Value::Boolean(hit.is_some()));
+    Ok(outputs)
+}
+
+fn evaluate_shape_line(inputs: &[Value], context: &str, surface_outputs: bool) -> ComponentResult {
+    let (Some(mesh), Some(line)) = (
+        coerce_mesh(inputs.get(0), context)?,
+        coerce_line(inputs.get(1), context)?,
+    ) else {
+        return Ok(OutputMap::new());
+    };
+    let first_only = coerce_flag(inputs.get(2), context)?;
+
+    let mut hits = mesh_ray_hits(
+        &mesh,
+        line.start,
+        line.direction(),
+        0.0,
+        1.0,
+        Tolerance::default_geom(),
+    );
+    if first_only {
+        hits.truncate(1);
+    }
+
+    let mut outputs = BTreeMap::new();
+    // Overlappende curves worden niet berekend; een mesh raakt een lijn
+    // alleen in losse punten.
+    outputs.insert(PIN_OUTPUT_CURVES.to_owned(), Value::List(Vec::new()));
+    outputs.insert(
+        PIN_OUTPUT_POINTS.to_owned(),
+        Value::List(hits.iter().map(|hit| point_value(hit.point)).collect()),
+    );
+    if surface_outputs {
+        outputs.insert(
+            PIN_OUTPUT_UV.to_owned(),
+            Value::List(hits.iter().map(|hit| hit_uv_value(&mesh, hit)).collect()),
+        );
+        outputs.insert(
+            PIN_OUTPUT_NORMALS.to_owned(),
+            Value::List(
+                hits.iter()
+                    .map(|hit| Value::Vector(hit_normal(&mesh, hit).to_array()))
+                    .collect(),
+            ),
+        );
+    }
+    Ok(outputs)
+}
+
+fn evaluate_isovist_ray(inputs: &[Value], output: IsoVistOutput) -> ComponentResult {
+    let context = "IsoVist Ray";
+    let Some(sample) = coerce_line(inputs.get(0), context)? else {
+        return Ok(OutputMap::new());
+    };
+    let radius = coerce_radius(inputs.get(1), context)?;
+    let obstacles = collect_obstacles(inputs.get(2), context)?;
+
+    let Some(direction) = sample.direction().normalized() else {
+        return Err(ComponentError::new(format!(
+            "{context} vereist een straal met een lengte groter dan nul"
+        )));
+    };
+    let normal = GeomVec3::Z - direction * GeomVec3::Z.dot(direction);
+    let normal = normal.normalized().unwrap_or(GeomVec3::X);
+    let frame = (direction, normal.cross(direction));
+    let ray = cast_isovist_ray(sample.start, direction, frame, radius, &obstacles);
+
+    let mut outputs = BTreeMap::new();
+    outputs.insert(PIN_OUTPUT_POINT.to_owned(), point_value(ray.point));
+    outputs.insert(PIN_OUTPUT_DISTANCE.to_owned(), Value::Number(ray.distance));
+    insert_isovist_hit(&mut outputs, output, [ray.obstacle].into_iter(), false);
+    Ok(outputs)
+}
+
+fn evaluate_isovist(inputs: &[Value], output: IsoVistOutput) -> ComponentResult {
+    let context = "IsoVist";
+    let Some(plane) = coerce_plane(inputs.get(0), context)? else {
+        return Ok(OutputMap::new());
+    };
+    let count = optional_item(inputs.get(1), coerce::coerce_integer)?.unwrap_or(100);
+    if count < 1 {
+        return Err(ComponentError::new(format!(
+            "{context} vereist minstens één sample"
+        )));
+    }
+    let radius = coerce_radius(inputs.get(2), context)?;
+    let obstacles = collect_obstacles(inputs.get(3), context)?;
+
+    let origin = GeomPoint3::from(plane.origin);
+    let x_axis = GeomVec3::from(plane.x_axis);
+    let y_axis = GeomVec3::from(plane.y_axis);
+
+    let rays: Vec<IsoVistRay> = (0..count)
+        .map(|sample| {
+            let angle = std::f64::consts::TAU * sample as f64 / count as f64;
+            let direction = x_axis * angle.cos() + y_axis * angle.sin();
+            cast_isovist_ray(origin, direction, (x_axis, y_axis), radius, &obstacles)
+        })
+        .collect();
+
+    let mut outputs = BTreeMap::new();
+    outputs.insert(
+        PIN_OUTPUT_POINTS.to_owned(),
+        Value::List(rays.iter().map(|ray| point_value(ray.point)).collect()),
+    );
+    outputs.insert(
+        PIN_OUTPUT_DISTANCE.to_owned(),
+        Value::List(rays.iter().map(|ray| Value::Number(ray.distance)).collect()),
+    );
+    insert_isovist_hit(
+        &mut outputs,
+        output,
+        rays.iter().map(|ray| ray.obstacle),
+        true,
+    );
+    Ok(outputs)
+}
+
+fn insert_isovist_hit(
+    outputs: &mut OutputMap,
+    output: IsoVistOutput,
+    obstacles: impl Iterator<Item = Option<usize>>,
+    as_list: bool,
+) {
+    let (pin, values): (&str, Vec<Value>) = match output {
+        IsoVistOutput::Hits => (
+            PIN_OUTPUT_HIT,
+            obstacles.map(|hit| Value::Boolean(hit.is_some())).collect(),
+        ),
+        IsoVistOutput::Index => (
+            PIN_OUTPUT_INDEX,
+            obstacles
+                .map(|hit| Value::Number(hit.map_or(-1.0, |index| index as f64)))
+                .collect(),
+        ),
+    };
+    let value = if as_list {
+        Value::List(values)
+    } else {
+        values.into_iter().next().unwrap_or(Value::Null)
+    };
+    outputs.insert(pin.to_owned(), value);
+}
+
+/// Resultaat van één IsoVist-straal.
+#[derive(Debug, Clone, Copy)]
+struct IsoVistRay {
+    point: GeomPoint3,
+    distance: f64,
+    obstacle: Option<usize>,
+}
+
+/// Een obstakel voor IsoVist: curves als segmenten, meshes, oppervlakken en
+/// breps als mesh.
+#[derive(Debug, Clone)]
+enum Obstacle {
+    Segments(Vec<(GeomPoint3, GeomPoint3)>),
+    Mesh(GeomMesh),
+}
+
+/// Schiet een straal tot `radius` en geeft het dichtstbijzijnde obstakel.
+/// Curves worden in het vlak `frame` vergeleken, zodat obstakels die iets
+/// boven of onder het samplevlak liggen nog steeds meetellen.
+fn cast_isovist_ray(
+    origin: GeomPoint3,
+    direction: GeomVec3,
+    frame: (GeomVec3, GeomVec3),
+    radius: f64,
+    obstacles: &[Obstacle],
+) -> IsoVistRay {
+    let to_plane = |point: GeomPoint3| {
+        let offset = point.sub_point(origin);
+        (offset.dot(frame.0), offset.dot(frame.1))
+    };
+    let ray = (direction.dot(frame.0), direction.dot(frame.1));
+
+    let mut best: Option<(f64, usize)> = None;
+    for (index, obstacle) in obstacles.iter().enumerate() {
+        let distance = match obstacle {
+            Obstacle::Segments(segments) => segments
+                .iter()
+                .filter_map(|(a, b)| ray_segment_distance_2d(ray, to_plane(*a), to_plane(*b)))
+                .fold(None, |closest: Option<f64>, d| {
+                    Some(closest.map_or(d, |c| c.min(d)))
+                }),
+            Obstacle::Mesh(mesh) => mesh_ray_hits(
+                mesh,
+                origin,
+                direction,
+                0.0,
+                radius,
+                Tolerance::default_geom(),
+            )
+            .first()
+            .map(|hit| hit.t),
+        };
+        if let Some(distance) = distance.filter(|d| *d <= radius)
+            && best.is_none_or(|(current, _)| distance < current)
+        {
+            best = Some((distance, index));
+        }
+    }
+
+    let distance = best.map_or(radius, |(distance, _)| distance);
+    IsoVistRay {
+        point: origin.add_vec(direction * distance),
+        distance,
+        obstacle: best.map(|(_, index)| index),
+    }
+}
+
+/// Afstand langs een 2D-straal vanuit de oorsprong tot het segment `a`–`b`.
+fn ray_segment_distance_2d(ray: (f64, f64), a: (f64, f64), b: (f64, f64)) -> Option<f64> {
+    let edge = (b.0 - a.0, b.1 - a.1);
+    let denominator = ray.0 * edge.1 - ray.1 * edge.0;
+    if denominator.abs() < EPSILON {
+        return None;
+    }
+    let distance = (a.0 * edge.1 - a.1 * edge.0) / denominator;
+    let along = (a.0 * ray.1 - a.1 * ray.0) / denominator;
+    (distance >= 0.0 && (-EPSILON..=1.0 + EPSILON).contains(&along)).then_some(distance)
+}
+
+/// Snijpunten van een curve met de oneindige lijn door `line`.
+///
+/// De curve wordt gesneden met een vlak dat de lijn bevat; voor vlakke curves
+/// staat dat vlak loodrecht op het curvevlak. Alleen punten die werkelijk op
+/// de lijn liggen blijven over.
+fn curve_line_intersections(curve: &CurveValue, line: Line3) -> Vec<(GeomPoint3, f64)> {
+    let Some(direction) = line.direction().normalized() else {
+        return Vec::new();
+    };
+    let curve_normal = curve_plane_normal(curve);
+    let normal = curve_normal
+        .and_then(|curve_normal| direction.cross(curve_normal).normalized())
+        .unwrap_or_else(|| orthogonal(direction));
+
+    curve_plane_intersections_refined(curve, line.start, normal, curve_samples(curve))
+        .into_iter()
+        .filter(|(point, _)| {
+            let offset = point.sub_point(line.start);
+            let along = direction * offset.dot(direction);
+            (offset - along).length() <= LINE_TOLERANCE
+        })
+        .collect()
+}
+
+/// Normaal van het vlak waarin een curve ligt (Newell), of `None` voor
+/// rechte curves.
+fn curve_plane_normal(curve: &CurveValue) -> Option<GeomVec3> {
+    const SAMPLES: usize = 64;
+    let (t0, t1) = curve.domain();
+    let points: Vec<GeomPoint3> = (0..SAMPLES)
+        .map(|i| curve.point_at(t0 + (t1 - t0) * i as f64 / (SAMPLES - 1) as f64))
+        .collect();
+    let mut normal = GeomVec3::ZERO;
+    for (index, current) in points.iter().enumerate() {
+        let next = points[(index + 1) % points.len()];
+        normal = normal
+            + GeomVec3::new(
+                (current.y - next.y) * (current.z + next.z),
+                (current.z - next.z) * (current.x + next.x),
+                (current.x - next.x) * (current.y + next.y),
+            );
+    }
+    let scale = points
+        .iter()
+        .map(|point| point.sub_point(points[0]).length_squared())
+        .fold(0.0, f64::max);
+    if normal.length() <= EPSILON * scale.max(EPSILON) {
+        return None;
+    }
+    normal.normalized()
+}
+
+/// Parameters van de dichtste punten tussen twee oneindige lijnen, genormaliseerd
+/// op de lengte van elke lijn. `None` voor evenwijdige lijnen.
+fn closest_line_parameters(a: Line3, b: Line3) -> Option<(f64, f64)> {
+    let da = a.direction();
+    let db = b.direction();
+    let r = a.start.sub_point(b.start);
+    let aa = da.dot(da);
+    let bb = db.dot(db);
+    let ab = da.dot(db);
+    let denominator = aa * bb - ab * ab;
+    if aa < EPSILON || bb < EPSILON || denominator.abs() <= EPSILON * aa * bb {
+        return None;
+    }
+    let ar = da.dot(r);
+    let br = db.dot(r);
+    let ta = (ab * br - bb * ar) / denominator;
+    let tb = (aa * br - ab * ar) / denominator;
+    Some((ta, tb))
+}
+
+/// Parameter waarop de oneindige lijn het vlak snijdt.
+fn line_plane_parameter(line: Line3, plane: &Plane) -> Option<f64> {
+    let normal = GeomVec3::from(plane.z_axis);
+    let denominator = line.direction().dot(normal);
+    if denominator.abs() < EPSILON {
+        return None;
+    }
+    Some(
+        GeomPoint3::from(plane.origin)
+            .sub_point(line.start)
+            .dot(normal)
+            / denominator,
+    )
+}
+
+/// Snijlijn van twee vlakken met lengte één, beginnend bij het punt dat het
+/// dichtst bij de oorsprong van het eerste vlak ligt.
+fn plane_plane_line(a: &Plane, b: &Plane) -> Option<Line3> {
+    let na = GeomVec3::from(a.z_axis);
+    let nb = GeomVec3::from(b.z_axis);
+    let direction = na.cross(nb).normalized()?;
+    let da = na.dot(GeomVec3::from(a.origin));
+    let db = nb.dot(GeomVec3::from(b.origin));
+    let cos = na.dot(nb);
+    let denominator = 1.0 - cos * cos;
+    let base = na * ((da - db * cos) / denominator) + nb * ((db - da * cos) / denominator);
+    let base = GeomPoint3::from(base);
+    let origin = GeomPoint3::from(a.origin);
+    let start = base.add_vec(direction * origin.sub_point(base).dot(direction));
+    Some(Line3::new(start, start.add_vec(direction)))
+}
+
+fn three_plane_point(a: &Plane, b: &Plane, c: &Plane) -> Option<GeomPoint3> {
+    let na = GeomVec3::from(a.z_axis);
+    let nb = GeomVec3::from(b.z_axis);
+    let nc = GeomVec3::from(c.z_axis);
+    let determinant = na.dot(nb.cross(nc));
+    if determinant.abs() < EPSILON {
+        return None;
+    }
+    let da = na.dot(GeomVec3::from(a.origin));
+    let db = nb.dot(GeomVec3::from(b.origin));
+    let dc = nc.dot(GeomVec3::from(c.origin));
+    let sum = nb.cross(nc) * da + nc.cross(na) * db + na.cross(nb) * dc;
+    Some(GeomPoint3::from(sum / determinant))
+}
+
+fn plane_uv_value(plane: &Plane, point: GeomPoint3) -> Value {
+    let offset = point.sub_point(GeomPoint3::from(plane.origin));
+    Value::Point([
+        offset.dot(GeomVec3::from(plane.x_axis)),
+        offset.dot(GeomVec3::from(plane.y_axis)),
+        0.0,
+    ])
+}
+
+fn curve_samples(curve: &CurveValue) -> usize {
+    match curve {
+        CurveValue::Polyline(polyline) => (polyline.points().len() * 4).max(CURVE_SAMPLES),
+        _ => CURVE_SAMPLES,
+    }
+}
+
+fn orthogonal(direction: GeomVec3) -> GeomVec3 {
+    let helper = if direction.x.abs() < 0.9 {
+        GeomVec3::X
+    } else {
+        GeomVec3::Y
+    };
+    direction.cross(helper).normalized().unwrap_or(GeomVec3::Z)
+}
+
+fn line_value(line: Line3) -> Value {
+    Value::CurveLine {
+        p1: line.start.to_array(),
+        p2: line.end.to_array(),
+    }
+}
+
+// ============================================================================
+// Invoer
+// ============================================================================
+
+fn coerce_flag(value: Option<&Value>, context: &str) -> Result<bool, ComponentError> {
+    Ok(optional_item(value, |value| {
+        coerce::coerce_boolean_with_context(value, context)
+    })?
+    .unwrap_or(false))
+}
+
+fn coerce_radius(value: Option<&Value>, context: &str) -> Result<f64, ComponentError> {
+    let radius =
+        optional_item(value, |value| coerce::coerce_number(value, Some(context)))?.unwrap_or(1.0);
+    if !radius.is_finite() || radius <= 0.0 {
+        return Err(ComponentError::new(format!(
+            "{context} vereist een straal groter dan nul"
+        )));
+    }
+    Ok(radius)
+}
+
+fn collect_obstacles(
+    value: Option<&Value>,
+    context: &str,
+) -> Result<Vec<Obstacle>, ComponentError> {
+    let mut obstacles = Vec::new();
+    if let Some(value) = value {
+        collect_obstacles_into(value, context, &mut obstacles)?;
+    }
+    Ok(obstacles)
+}
+
+fn collect_obstacles_into(
+    value: &Value,
+    context: &str,
+    output: &mut Vec<Obstacle>,
+) -> Result<(), ComponentError> {
+    match value {
+        Value::Mesh { .. } | Value::Surface { .. } | Value::Brep(_) => {
+            let mesh = coerce::coerce_mesh_like_with_context(value, context)?;
+            output.push(Obstacle::Mesh(geom_bridge::mesh_to_geom_mesh(mesh)));
+        }
+        Value::List(values) if !is_point_list(values) => {
+            for entry in values {
+                collect_obstacles_into(entry, context, output)?;
+            }
+        }
+        _ => {
+            for curve in collect_curves(Some(value), context)? {
+                output.push(Obstacle::Segments(curve_segments(&curve)));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn curve_segments(curve: &CurveValue) -> Vec<(GeomPoint3, GeomPoint3)> {
+    let points: Vec<GeomPoint3> = match curve {
+        CurveValue::Line(line) => vec![line.start, line.end],
+        CurveValue::Polyline(polyline) => {
+            let mut points = polyline.points().to_vec();
+            if polyline.is_closed() {
+                points.push(points[0]);
+            }
+            points
+        }
+        other => other
+            .tessellate(CurveValue::DEFAULT_TESSELLATION)
+            .into_iter()
+            .map(GeomPoint3::from)
+            .collect(),
+    };
+    points.windows(2).map(|pair| (pair[0], pair[1])).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::intersect_common::fixtures::{cube, meta};
+    use crate::graph::tree::TreePath;
+
+    fn xy_plane(z: f64) -> Value {
+        Value::List(vec![
+            Value::Point([0.0, 0.0, z]),
+            Value::Point([1.0, 0.0, z]),
+            Value::Point([0.0, 1.0, z]),
+        ])
+    }
+
+    fn point_output(outputs: &OutputMap, pin: &str) -> [f64; 3] {
+        match outputs.get(pin) {
+            Some(Value::Point(point)) => *point,
+            other => panic!("expected point, got {other:?}"),
+        }
+    }
+
+    fn assert_point_eq(actual: [f64; 3], expected: [f64; 3]) {
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-9, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn line_line_reports_parameters_and_points() {
+        let outputs = ComponentKind::LineLine
+            .evaluate(
+                &[
+                    Value::CurveLine {
+                        p1: [0.0, 0.0, 0.0],
+                        p2: [2.0, 0.0, 0.0],
+                    },
+                    Value::CurveLine {
+                        p1: [1.0, -1.0, 1.0],
+                        p2: [1.0, 1.0, 1.0],
+                    },
+                ],
+                &meta(),
+            )
+            .unwrap();
+
+        assert!(matches!(outputs.get("tA"), Some(Value::Number(t)) if (t - 0.5).abs() < 1e-9));
+        assert!(matches!(outputs.get("tB"), Some(Value::Number(t)) if (t - 0.5).abs() < 1e-9));
+        assert_point_eq(point_output(&outputs, "pA"), [1.0, 0.0, 0.0]);
+        assert_point_eq(point_output(&outputs, "pB"), [1.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn plane_plane_plane_meets_in_a_point() {
+        let yz = Value::List(vec![
+            Value::Point([2.0, 0.0, 0.0]),
+            Value::Point([2.0, 1.0, 0.0]),
+            Value::Point([2.0, 0.0, 1.0]),
+        ]);
+        let xz = Value::List(vec![
+            Value::Point([0.0, 3.0, 0.0]),
+            Value::Point([1.0, 3.0, 0.0]),
+            Value::Point([0.0, 3.0, 1.0]),
+        ]);
+        let outputs = ComponentKind::PlanePlanePlane
+            .evaluate(&[xy_plane(4.0), yz, xz], &meta())
+            .unwrap();
+
+        assert_point_eq(point_output(&outputs, "Pt"), [2.0, 3.0, 4.0]);
+        let Some(Value::CurveLine { p1, p2 }) = outputs.get("AB") else {
+            panic!("expected line AB");
+        };
+        assert!((p1[0] - 2.0).abs() < 1e-9 && (p1[2] - 4.0).abs() < 1e-9);
+        assert!((p2[1] - p1[1]).abs() > 0.99);
+    }
+
+    #[test]
+    fn curve_line_treats_line_as_infinite() {
+        let outputs = ComponentKind::CurveLine
+            .evaluate(
+                &[
+                    Value::List(vec![
+                        Value::Point([0.0, 0.0, 0.0]),
+                        Value::Point([4.0, 0.0, 0.0]),
+                        Value::Point([4.0, 4.0, 0.0]),
+                    ]),
+                    Value::CurveLine {
+                        p1: [1.0, 1.0, 0.0],
+                        p2: [1.0, 2.0, 0.0],
+                    },
+                ],
+                &meta(),
+            )
+            .unwrap();
+
+        assert_eq!(outputs.get("N"), Some(&Value::Number(1.0)));
+        let Some(Value::List(points)) = outputs.get("P") else {
+            panic!("expected point list");
+        };
+        let Value::Point(point) = points[0] else {
+            panic!("expected point");
+        };
+        assert_point_eq(point, [1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn mesh_plane_sections_iterate_over_planes() {
+        use crate::components::ComponentRegistry;
+        use crate::graph::Graph;
+        use crate::graph::evaluator::evaluate;
+        use crate::graph::node::{Node, NodeId};
+        use crate::graph::value::PlaneValue;
+
+        let plane = |z: f64| {
+            Value::Plane(PlaneValue::new(
+                [0.0, 0.0, z],
+                [1.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0],
+                [0.0, 0.0, 1.0],
+            ))
+        };
+        let mut graph = Graph::new();
+        let mut node = Node::new(NodeId::new(0));
+        node.guid = Some("{3b1ae469-0e9b-461d-8c30-fa5a7de8b7a9}".to_string());
+        node.add_input_pin("M");
+        node.add_input_pin("P");
+        node.set_input("M", cube([0.0; 3], 1.0));
+        node.set_input("P", Value::List(vec![plane(0.25), plane(0.75)]));
+        let node_id = graph.add_node(node).unwrap();
+
+        let result = evaluate(&graph, &ComponentRegistry::default()).expect("evaluatie slaagt");
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        let outputs = &result.node_outputs[&node_id];
+
+        let Some(Value::Tree(tree)) = outputs.get("C") else {
+            panic!("expected a branch of curves per plane");
+        };
+        for (iteration, z) in [0.25, 0.75].into_iter().enumerate() {
+            let sections = tree
+                .branch(&TreePath::new(vec![iteration]))
+                .expect("branch");
+            assert_eq!(sections.len(), 1);
+            let Value::List(points) = &sections[0] else {
+                panic!("expected polyline");
+            };
+            assert!(points.len() >= 5);
+            assert_eq!(points.first(), points.last());
+            assert!(
+                points
+                    .iter()
+                    .all(|p| matches!(p, Value::Point(p) if (p[2] - z).abs() < 1e-9))
+            );
+        }
+    }
+
+    #[test]
+    fn mesh_ray_reports_first_hit_or_miss() {
+        let hit = ComponentKind::MeshRay
+            .evaluate(
+                &[
+                    cube([0.0; 3], 1.0),
+                    Value::Point([0.5, 0.5, 3.0]),
+                    Value::Vector([0.0, 0.0, -1.0]),
+                ],
+                &meta(),
+            )
+            .unwrap();
+        assert_point_eq(point_output(&hit, "X"), [0.5, 0.5, 1.0]);
+        assert!(matches!(hit.get("H"), Some(Value::Boolean(true))));
+
+        let miss = ComponentKind::MeshRay
+            .evaluate(
+                &[
+                    cube([0.0; 3], 1.0),
+                    Value::Point([0.5, 0.5, 3.0]),
+                    Value::Vector([0.0, 0.0, 1.0]),
+                ],
+                &meta(),
+            )
+            .unwrap();
+        assert!(matches!(miss.get("X"), Some(Value::Null)));
+        assert!(matches!(miss.get("H"), Some(Value::Boolean(false))));
+    }
+
+    #[test]
+    fn isovist_clips_rays_to_obstacles_and_radius() {
+        let wall = Value::CurveLine {
+            p1: [2.0, -5.0, 0.0],
+            p2: [2.0, 5.0, 0.0],
+        };
+        let outputs = ComponentKind::IsoVistIndex
+            .evaluate(
+                &[
+                    xy_plane(0.0),
+                    Value::Number(4.0),
+                    Value::Number(3.0),
+                    Value::List(vec![wall]),
+                ],
+                &meta(),
+            )
+            .unwrap();
+
+        let Some(Value::List(distances)) = outputs.get("D") else {
+            panic!("expected distances");
+        };
+        let distances: Vec<f64> = distances
+            .iter()
+            .map(|value| match value {
+                Value::Number(d) => *d,
+                other => panic!("expected number, got {other:?}"),
+            })
+            .collect();
+        assert!((distances[0] - 2.0).abs() < 1e-9);
+        assert!(distances[1..].iter().all(|d| (d - 3.0).abs() < 1e-9));
+
+        let Some(Value::List(indices)) = outputs.get("I") else {
+            panic!("expected indices");
+        };
+        assert_eq!(indices[0], Value::Number(0.0));
+        assert_eq!(indices[2], Value::Number(-1.0));
+    }
+}
@@ -0,0 +1,723 @@
+//! Implementaties van Grasshopper "Intersect → Physical" componenten.
+//!
+//! Curves worden benaderd met `geom::ParamPolyline`s: polylijnen en lijnen
+//! gebruiken hun eigen hoekpunten, andere curves worden gesampled en elke
+//! kruising wordt op de curve zelf verfijnd. Meshes, breps en oppervlakken
+//! lopen via hun weergavemesh; alle paarsgewijze zoekacties gebruiken de
+//! driehoeks-BVH in `geom::intersect` als grove fase en
+//! `triangle_triangle_intersection` als exacte test.
+
+use std::collections::BTreeMap;
+
+use crate::geom::{
+    BBox, Curve3, GeomMesh, ParamPolyline, Point3 as GeomPoint3, SectionPolyline, Tolerance,
+    curve_curve_intersections, curve_self_intersections, mesh_clash, mesh_mesh_intersection,
+    mesh_polyline_hits, meshes_collide, split_mesh_with_polylines,
+};
+use crate::graph::node::MetaMap;
+use crate::graph::value::Value;
+
+use super::coerce::{self, geom_bridge};
+use super::intersect_common::{
+    coerce_curve, coerce_mesh, collect_curves, collect_meshes, hit_normal, hit_uv_value,
+    optional_item, param_polyline, point_value,
+};
+use super::{Component, ComponentError, ComponentResult, OutputMap, PinAccess};
+
+const PIN_OUTPUT_POINTS: &str = "P";
+const PIN_OUTPUT_PARAMS: &str = "t";
+const PIN_OUTPUT_PARAM_A: &str = "tA";
+const PIN_OUTPUT_PARAM_B: &str = "tB";
+const PIN_OUTPUT_INDEX_A: &str = "iA";
+const PIN_OUTPUT_INDEX_B: &str = "iB";
+const PIN_OUTPUT_CURVES: &str = "C";
+const PIN_OUTPUT_MESH_POINTS: &str = "X";
+const PIN_OUTPUT_FACES: &str = "F";
+const PIN_OUTPUT_INTERSECTIONS: &str = "X";
+const PIN_OUTPUT_UV: &str = "uv";
+const PIN_OUTPUT_NORMALS: &str = "N";
+const PIN_OUTPUT_TANGENTS: &str = "T";
+const PIN_OUTPUT_FRAGMENTS: &str = "F";
+const PIN_OUTPUT_COUNT: &str = "N";
+const PIN_OUTPUT_RADII: &str = "R";
+const PIN_OUTPUT_FIRST_INDEX: &str = "i";
+const PIN_OUTPUT_SECOND_INDEX: &str = "j";
+const PIN_OUTPUT_COLLISION: &str = "C";
+const PIN_OUTPUT_INDEX: &str = "I";
+
+/// Standaard maximaal aantal resultaten van Clash.
+const DEFAULT_CLASH_LIMIT: usize = 1000;
+
+/// Beschikbare componenten binnen deze module.
+#[derive(Debug, Clone, Copy)]
+pub enum ComponentKind {
+    CurveCurve,
+    CurveSelf,
+    MultipleCurves,
+    MeshCurve,
+    MeshMesh,
+    BrepCurve,
+    BrepBrep,
+    SurfaceCurve,
+    SurfaceSplit,
+    CollisionOneMany,
+    CollisionManyMany,
+    Clash,
+}
+
+/// Metadata voor registraties in de componentregistry.
+#[derive(Debug, Clone, Copy)]
+pub struct Registration {
+    pub guids: &'static [&'static str],
+    pub names: &'static [&'static str],
+    pub kind: ComponentKind,
+}
+
+/// Volledige lijst met componentregistraties voor de fysieke
+/// snijcomponenten. "MCX" hoort zowel bij Mesh | Curve als bij Multiple
+/// Curves en "SCX" is al van Surface | Line; die bijnamen worden daarom niet
+/// geregistreerd.
+pub const REGISTRATIONS: &[Registration] = &[
+    Registration {
+        guids: &["{84627490-0fb2-4498-8138-ad134ee4cb36}"],
+        names: &["Curve | Curve", "CCX"],
+        kind: ComponentKind::CurveCurve,
+    },
+    Registration {
+        guids: &["{0991ac99-6a0b-47a9-b07d-dd510ca57f0f}"],
+        names: &["Curve | Self", "CX"],
+        kind: ComponentKind::CurveSelf,
+    },
+    Registration {
+        guids: &["{931e6030-ccb3-4a7b-a89a-99dcce8770cd}"],
+        names: &["Multiple Curves"],
+        kind: ComponentKind::MultipleCurves,
+    },
+    Registration {
+        guids: &["{19632848-4b95-4e5e-9e86-b79b47987a46}"],
+        names: &["Mesh | Curve"],
+        kind: ComponentKind::MeshCurve,
+    },
+    Registration {
+        guids: &["{21b6a605-9568-4bf8-acc1-631565d609d7}"],
+        names: &["Mesh | Mesh", "MMX"],
+        kind: ComponentKind::MeshMesh,
+    },
+    Registration {
+        guids: &["{20ef81e8-df15-4a0c-acf1-993a7607cafb}"],
+        names: &["Brep | Curve", "BCX"],
+        kind: ComponentKind::BrepCurve,
+    },
+    Registration {
+        guids: &["{904e4b56-484a-4814-b35f-aa4baf362117}"],
+        names: &["Brep | Brep", "BBX"],
+        kind: ComponentKind::BrepBrep,
+    },
+    Registration {
+        guids: &["{68546dd0-aa82-471c-87e9-81cb16ac50ed}"],
+        names: &["Surface | Curve"],
+        kind: ComponentKind::SurfaceCurve,
+    },
+    Registration {
+        guids: &["{7db14002-c09c-4d7b-9f80-e4e2b00dfa1d}"],
+        names: &["Surface Split", "SrfSplit"],
+        kind: ComponentKind::SurfaceSplit,
+    },
+    Registration {
+        guids: &["{bb6c6501-0500-4678-859b-b838348981d1}"],
+        names: &["Collision One|Many", "ColOM"],
+        kind: ComponentKind::CollisionOneMany,
+    },
+    Registration {
+        guids: &["{2168853c-acd8-4a63-9c9b-ecde9e239eae}"],
+        names: &["Collision Many|Many", "ColMM"],
+        kind: ComponentKind::CollisionManyMany,
+    },
+    Registration {
+        guids: &["{4439a51b-8d24-4924-b8e2-f77e7f8f5bec}"],
+        names: &["Clash"],
+        kind: ComponentKind::Clash,
+    },
+];
+
+impl Component for ComponentKind {
+    fn evaluate(&self, inputs: &[Value], _meta: &MetaMap) -> ComponentResult {
+        match self {
+            Self::CurveCurve => evaluate_curve_curve(inputs),
+            Self::CurveSelf => evaluate_curve_self(inputs),
+            Self::MultipleCurves => evaluate_multiple_curves(inputs),
+            Self::MeshCurve => evaluate_mesh_curve(inputs),
+            Self::MeshMesh => evaluate_mesh_mesh(inputs),
+            Self::BrepCurve => evaluate_shape_curve(inputs, "Brep | Curve", false),
+            Self::BrepBrep => evaluate_brep_brep(inputs),
+            Self::SurfaceCurve => evaluate_shape_curve(inputs, "Surface | Curve", true),
+            Self::SurfaceSplit => evaluate_surface_split(inputs),
+            Self::CollisionOneMany => evaluate_collision_one_many(inputs),
+            Self::CollisionManyMany => evaluate_collision_many_many(inputs),
+            Self::Clash => evaluate_clash(inputs),
+        }
+    }
+
+    fn input_access(&self) -> &'static [PinAccess] {
+        match self {
+            Self::MultipleCurves | Self::CollisionManyMany => &[PinAccess::List],
+            Self::SurfaceSplit | Self::CollisionOneMany => &[PinAccess::Item, PinAccess::List],
+            Self::Clash => &[
+                PinAccess::List,
+                PinAccess::List,
+                PinAccess::Item,
+                PinAccess::Item,
+            ],
+            _ => &[PinAccess::Item],
+        }
+    }
+}
+
+impl ComponentKind {
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::CurveCurve => "Curve | Curve",
+            Self::CurveSelf => "Curve | Self",
+            Self::MultipleCurves => "Multiple Curves",
+            Self::MeshCurve => "Mesh | Curve",
+            Self::MeshMesh => "Mesh | Mesh",
+            Self::BrepCurve => "Brep | Curve",
+            Self::BrepBrep => "Brep | Brep",
+            Self::SurfaceCurve => "Surface | Curve",
+            Self::SurfaceSplit => "Surface Split",
+            Self::CollisionOneMany => "Collision One|Many",
+            Self::CollisionManyMany => "Collision Many|Many",
+            Self::Clash => "Clash",
+        }
+    }
+}
+
+fn evaluate_curve_curve(inputs: &[Value]) -> ComponentResult {
+    let context = "Curve | Curve";
+    let (Some(a), Some(b)) = (
+        coerce_curve(inputs.get(0), context)?,
+        coerce_curve(inputs.get(1), context)?,
+    ) else {
+        return Ok(OutputMap::new());
+    };
+
+    let hits = curve_curve_intersections(
+        &a,
+        &param_polyline(&a),
+        &b,
+        &param_polyline(&b),
+        Tolerance::LOOSE,
+    );
+
+    let mut outputs = BTreeMap::new();
+    insert_list(
+        &mut outputs,
+        PIN_OUTPUT_POINTS,
+        hits.iter().map(|hit| point_value(hit.point)),
+    );
+    insert_list(
+        &mut outputs,
+        PIN_OUTPUT_PARAM_A,
+        hits.iter().map(|hit| Value::Number(hit.t_a)),
+    );
+    insert_list(
+        &mut outputs,
+        PIN_OUTPUT_PARAM_B,
+        hits.iter().map(|hit| Value::Number(hit.t_b)),
+    );
+    Ok(outputs)
+}
+
+fn evaluate_curve_self(inputs: &[Value]) -> ComponentResult {
+    let context = "Curve | Self";
+    let Some(curve) = coerce_curve(inputs.get(0), context)? else {
+        return Ok(OutputMap::new());
+    };
+
+    let hits = curve_self_intersections(&curve, &param_polyline(&curve), Tolerance::LOOSE);
+
+    // Elk zelfsnijpunt levert één punt; de parameter is die van de eerste
+    // passage langs het punt.
+    let mut outputs = BTreeMap::new();
+    insert_list(
+        &mut outputs,
+        PIN_OUTPUT_POINTS,
+        hits.iter().map(|hit| point_value(hit.point)),
+    );
+    insert_list(
+        &mut outputs,
+        PIN_OUTPUT_PARAMS,
+        hits.iter().map(|hit| Value::Number(hit.t_a)),
+    );
+    Ok(outputs)
+}
+
+fn evaluate_multiple_curves(inputs: &[Value]) -> ComponentResult {
+    let context = "Multiple Curves";
+    let curves = collect_curves(inputs.get(0), context)?;
+    let polylines: Vec<ParamPolyline> = curves.iter().map(param_polyline).collect();
+    let bounds: Vec<Option<BBox>> = polylines
+        .iter()
+        .map(|polyline| {
+            BBox::from_points(&polyline.points)
+                .map(|bbox| bbox.expand_by(polyline.deviation * 2.0 + Tolerance::LOOSE.eps))
+        })
+        .collect();
+
+    let mut events = Vec::new();
+    for a in 0..curves.len() {
+        for b in a + 1..curves.len() {
+            let (Some(bbox_a), Some(bbox_b)) = (bounds[a], bounds[b]) else {
+                continue;
+            };
+            if !bbox_a.intersects(bbox_b) {
+                continue;
+            }
+            let hits = curve_curve_intersections(
+                &curves[a],
+                &polylines[a],
+                &curves[b],
+                &polylines[b],
+                Tolerance::LOOSE,
+            );
+            events.extend(hits.into_iter().map(|hit| (a, b, hit)));
+        }
+    }
+
+    let mut outputs = BTreeMap::new();
+    insert_list(
+        &mut outputs,
+        PIN_OUTPUT_POINTS,
+        events.iter().map(|(_, _, hit)| point_value(hit.point)),
+    );
+    insert_list(
+        &mut outputs,
+        PIN_OUTPUT_INDEX_A,
+        events.iter().map(|(a, _, _)| Value::Number(*a as f64)),
+    );
+    insert_list(
+        &mut outputs,
+        PIN_OUTPUT_INDEX_B,
+        events.iter().map(|(_, b, _)| Value::Number(*b as f64)),
+    );
+    insert_list(
+        &mut outputs,
+        PIN_OUTPUT_PARAM_A,
+        events.iter().map(|(_, _, hit)| Value::Number(hit.t_a)),
+    );
+    insert_list(
+        &mut outputs,
+        PIN_OUTPUT_PARAM_B,
+        events.iter().map(|(_, _, hit)| Value::Number(hit.t_b)),
+    );
+    Ok(outputs)
+}
+
+fn evaluate_mesh_curve(inputs: &[Value]) -> ComponentResult {
+    let context = "Mesh | Curve";
+    let (Some(mesh), Some(curve)) = (
+        coerce_mesh(inputs.get(0), context)?,
+        coerce_curve(inputs.get(1), context)?,
+    ) else {
+        return Ok(OutputMap::new());
+    };
+
+    let hits = mesh_polyline_hits(&mesh, &param_polyline(&curve), Tolerance::default_geom());
+
+    let mut outputs = BTreeMap::new();
+    insert_list(
+        &mut outputs,
+        PIN_OUTPUT_MESH_POINTS,
+        hits.iter().map(|hit| point_value(hit.point)),
+    );
+    insert_list(
+        &mut outputs,
+        PIN_OUTPUT_FACES,
+        hits.iter().map(|hit| Value::Number(hit.triangle as f64)),
+    );
+    Ok(outputs)
+}
+
+fn evaluate_mesh_mesh(inputs: &[Value]) -> ComponentResult {
+    let context = "Mesh | Mesh";
+    let (Some(a), Some(b)) = (
+        coerce_mesh(inputs.get(0), context)?,
+        coerce_mesh(inputs.get(1), context)?,
+    ) else {
+        return Ok(OutputMap::new());
+    };
+
+    let intersection = mesh_mesh_intersection(&a, &b, Tolerance::default_geom());
+
+    let mut outputs = BTreeMap::new();
+    insert_list(
+        &mut outputs,
+        PIN_OUTPUT_INTERSECTIONS,
+        intersection.polylines.iter().map(section_value),
+    );
+    Ok(outputs)
+}
+
+fn evaluate_brep_brep(inputs: &[Value]) -> ComponentResult {
+    let context = "Brep | Brep";
+    let (Some(a), Some(b)) = (
+        coerce_mesh(inputs.get(0), context)?,
+        coerce_mesh(inputs.get(1), context)?,
+    ) else {
+        return Ok(OutputMap::new());
+    };
+
+    let intersection = mesh_mesh_intersection(&a, &b, Tolerance::default_geom());
+
+    let mut outputs = BTreeMap::new();
+    insert_list(
+        &mut outputs,
+        PIN_OUTPUT_CURVES,
+        intersection.polylines.iter().map(section_value),
+    );
+    insert_list(
+        &mut outputs,
+        PIN_OUTPUT_POINTS,
+        intersection.points.iter().copied().map(point_value),
+    );
+    Ok(outputs)
+}
+
+fn evaluate_shape_curve(inputs: &[Value], context: &str, surface_outputs: bool) -> ComponentResult {
+    let (Some(mesh), Some(curve)) = (
+        coerce_mesh(inputs.get(0), context)?,
+        coerce_curve(inputs.get(1), context)?,
+    ) else {
+        return Ok(OutputMap::new());
+    };
+
+    let hits = mesh_polyline_hits(&mesh, &param_polyline(&curve), Tolerance::default_geom());
+
+    let mut outputs = BTreeMap::new();
+    // Overlappende curves worden niet berekend; de curve raakt de mesh
+    // alleen in losse punten.
+    outputs.insert(PIN_OUTPUT_CURVES.to_owned(), Value::List(Vec::new()));
+    insert_list(
+        &mut outputs,
+        PIN_OUTPUT_POINTS,
+        hits.iter().map(|hit| point_value(hit.point)),
+    );
+    if surface_outputs {
+        insert_list(
+            &mut outputs,
+            PIN_OUTPUT_UV,
+            hits.iter().map(|hit| hit_uv_value(&mesh, hit)),
+        );
+        insert_list(
+            &mut outputs,
+            PIN_OUTPUT_NORMALS,
+            hits.iter()
+                .map(|hit| Value::Vector(hit_normal(&mesh, hit).to_array())),
+        );
+        insert_list(
+            &mut outputs,
+            PIN_OUTPUT_PARAMS,
+            hits.iter().map(|hit| Value::Number(hit.t)),
+        );
+        insert_list(
+            &mut outputs,
+            PIN_OUTPUT_TANGENTS,
+            hits.iter().map(|hit| {
+                let tangent = curve.derivative_at(hit.t);
+                Value::Vector(tangent.normalized().unwrap_or(tangent).to_array())
+            }),
+        );
+    }
+    Ok(outputs)
+}
+
+fn evaluate_surface_split(inputs: &[Value]) -> ComponentResult {
+    let context = "Surface Split";
+    let Some(surface) = coerce_mesh(inputs.get(0), context)? else {
+        return Ok(OutputMap::new());
+    };
+    let cutters: Vec<Vec<GeomPoint3>> = collect_curves(inputs.get(1), context)?
+        .iter()
+        .map(|curve| param_polyline(curve).points)
+        .collect();
+
+    let fragments = split_mesh_with_polylines(&surface, &cutters, Tolerance::default_geom());
+
+    let mut outputs = BTreeMap::new();
+    insert_list(
+        &mut outputs,
+        PIN_OUTPUT_FRAGMENTS,
+        fragments
+            .into_iter()
+            .map(geom_bridge::geom_mesh_to_surface_legacy),
+    );
+    Ok(outputs)
+}
+
+fn evaluate_collision_one_many(inputs: &[Value]) -> ComponentResult {
+    let context = "Collision One|Many";
+    let Some(collider) = coerce_mesh(inputs.get(0), context)? else {
+        return Ok(OutputMap::new());
+    };
+    let obstacles = collect_meshes(inputs.get(1), context)?;
+
+    let hit = obstacles
+        .iter()
+        .position(|obstacle| meshes_collide(&collider, obstacle, Tolerance::default_geom()));
+
+    let mut outputs = BTreeMap::new();
+    outputs.insert(
+        PIN_OUTPUT_COLLISION.to_owned(),
+        Value::Boolean(hit.is_some()),
+    );
+    outputs.insert(PIN_OUTPUT_INDEX.to_owned(), index_value(hit));
+    Ok(outputs)
+}
+
+fn evaluate_collision_many_many(inputs: &[Value]) -> ComponentResult {
+    let context = "Collision Many|Many";
+    let objects = collect_meshes(inputs.get(0), context)?;
+    let bounds: Vec<Option<BBox>> = objects.iter().map(mesh_bbox).collect();
+
+    let mut first_hit: Vec<Option<usize>> = vec![None; objects.len()];
+    for a in 0..objects.len() {
+        for b in a + 1..objects.len() {
+            if first_hit[a].is_some() && first_hit[b].is_some() {
+                continue;
+            }
+            let overlapping = match (bounds[a], bounds[b]) {
+                (Some(bbox_a), Some(bbox_b)) => bbox_a.intersects(bbox_b),
+                _ => false,
+            };
+            if overlapping && meshes_collide(&objects[a], &objects[b], Tolerance::default_geom()) {
+                first_hit[a].get_or_insert(b);
+                first_hit[b].get_or_insert(a);
+            }
+        }
+    }
+
+    let mut outputs = BTreeMap::new();
+    insert_list(
+        &mut outputs,
+        PIN_OUTPUT_COLLISION,
+        first_hit.iter().map(|hit| Value::Boolean(hit.is_some())),
+    );
+    insert_list(
+        &mut outputs,
+        PIN_OUTPUT_INDEX,
+        first_hit.iter().copied().map(index_value),
+    );
+    Ok(outputs)
+}
+
+fn evaluate_clash(inputs: &[Value]) -> ComponentResult {
+    let context = "Clash";
+    let first = collect_meshes(inputs.get(0), context)?;
+    let second = collect_meshes(inputs.get(1), context)?;
+    let distance = optional_item(inputs.get(2), |value| {
+        coerce::coerce_number(value, Some(context))
+    })?
+    .unwrap_or(0.0);
+    if !distance.is_finite() || distance < 0.0 {
+        return Err(ComponentError::new(format!(
+            "{context} vereist een afstand van nul of meer"
+        )));
+    }
+    let limit = optional_item(inputs.get(3), coerce::coerce_integer)?
+        .map_or(DEFAULT_CLASH_LIMIT, |limit| {
+            usize::try_from(limit.max(0)).unwrap_or(usize::MAX)
+        });
+
+    let bounds_b: Vec<Option<BBox>> = second.iter().map(mesh_bbox).collect();
+    let mut clashes = Vec::new();
+    'search: for (i, a) in first.iter().enumerate() {
+        let Some(bbox_a) = mesh_bbox(a).map(|bbox| bbox.expand_by(distance)) else {
+            continue;
+        };
+        for (j, b) in second.iter().enumerate() {
+            if clashes.len() >= limit {
+                break 'search;
+            }
+            if !bounds_b[j].is_some_and(|bbox_b| bbox_a.intersects(bbox_b)) {
+                continue;
+            }
+            if let Some(clash) = mesh_clash(a, b, distance, Tolerance::default_geom()) {
+                clashes.push((i, j, clash));
+            }
+        }
+    }
+
+    let mut outputs = BTreeMap::new();
+    outputs.insert(
+        PIN_OUTPUT_COUNT.to_owned(),
+        Value::Number(clashes.len() as f64),
+    );
+    insert_list(
+        &mut outputs,
+        PIN_OUTPUT_POINTS,
+        clashes
+            .iter()
+            .map(|(_, _, clash)| point_value(clash.center)),
+    );
+    insert_list(
+        &mut outputs,
+        PIN_OUTPUT_RADII,
+        clashes
+            .iter()
+            .map(|(_, _, clash)| Value::Number(clash.radius)),
+    );
+    insert_list(
+        &mut outputs,
+        PIN_OUTPUT_FIRST_INDEX,
+        clashes.iter().map(|(i, _, _)| Value::Number(*i as f64)),
+    );
+    insert_list(
+        &mut outputs,
+        PIN_OUTPUT_SECOND_INDEX,
+        clashes.iter().map(|(_, j, _)| Value::Number(*j as f64)),
+    );
+    Ok(outputs)
+}
+
+fn mesh_bbox(mesh: &GeomMesh) -> Option<BBox> {
+    let points: Vec<GeomPoint3> = mesh
+        .positions
+        .iter()
+        .copied()
+        .map(GeomPoint3::from)
+        .collect();
+    BBox::from_points(&points)
+}
+
+/// Een doorsnedepolyline als puntenlijst; gesloten polylines herhalen het
+/// eerste punt.
+fn section_value(section: &SectionPolyline) -> Value {
+    let mut points: Vec<Value> = section.points.iter().copied().map(point_value).collect();
+    if section.closed && !points.is_empty() {
+        points.push(points[0].clone());
+    }
+    Value::List(points)
+}
+
+fn index_value(index: Option<usize>) -> Value {
+    Value::Number(index.map_or(-1.0, |index| index as f64))
+}
+
+fn insert_list(outputs: &mut OutputMap, pin: &str, values: impl Iterator<Item = Value>) {
+    outputs.insert(pin.to_owned(), Value::List(values.collect()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::intersect_common::fixtures::{cube, list, meta};
+
+    #[test]
+    fn curve_curve_crosses_polyline_and_line() {
+        let polyline = Value::List(vec![
+            Value::Point([0.0, 0.0, 0.0]),
+            Value::Point([2.0, 0.0, 0.0]),
+            Value::Point([2.0, 2.0, 0.0]),
+        ]);
+        let line = Value::CurveLine {
+            p1: [0.5, -0.5, 0.0],
+            p2: [2.5, 1.5, 0.0],
+        };
+        let outputs = ComponentKind::CurveCurve
+            .evaluate(&[polyline, line], &meta())
+            .unwrap();
+
+        let points = list(&outputs, "P");
+        assert_eq!(points.len(), 2);
+        assert!(
+            matches!(points[0], Value::Point(p) if (p[0] - 1.0).abs() < 1e-9 && p[1].abs() < 1e-9)
+        );
+        assert!(
+            matches!(points[1], Value::Point(p) if (p[0] - 2.0).abs() < 1e-9 && (p[1] - 1.0).abs() < 1e-9)
+        );
+        assert!(matches!(list(&outputs, "tA")[0], Value::Number(t) if (t - 0.25).abs() < 1e-9));
+        assert!(matches!(list(&outputs, "tB")[1], Value::Number(t) if (t - 0.75).abs() < 1e-9));
+    }
+
+    #[test]
+    fn mesh_mesh_returns_closed_polyline() {
+        let outputs = ComponentKind::MeshMesh
+            .evaluate(&[cube([0.0; 3], 1.0), cube([0.5; 3], 1.0)], &meta())
+            .unwrap();
+
+        let curves = list(&outputs, "X");
+        assert_eq!(curves.len(), 1);
+        let Value::List(points) = &curves[0] else {
+            panic!("expected polyline points");
+        };
+        assert_eq!(points.first(), points.last());
+    }
+
+    #[test]
+    fn clash_reports_gap_within_distance() {
+        let inputs = [
+            Value::List(vec![cube([0.0; 3], 1.0)]),
+            Value::List(vec![cube([5.0, 0.0, 0.0], 1.0), cube([1.5, 0.0, 0.0], 1.0)]),
+            Value::Number(1.0),
+        ];
+        let outputs = ComponentKind::Clash.evaluate(&inputs, &meta()).unwrap();
+
+        assert_eq!(outputs.get("N"), Some(&Value::Number(1.0)));
+        assert_eq!(list(&outputs, "j"), vec![Value::Number(1.0)]);
+        assert!(matches!(list(&outputs, "R")[0], Value::Number(r) if (r - 0.25).abs() < 1e-9));
+    }
+
+    #[test]
+    fn collision_many_many_pairs_overlapping_objects() {
+        let inputs = [Value::List(vec![
+            cube([0.0; 3], 1.0),
+            cube([4.0, 0.0, 0.0], 1.0),
+            cube([0.5, 0.5, 0.5], 1.0),
+        ])];
+        let outputs = ComponentKind::CollisionManyMany
+            .evaluate(&inputs, &meta())
+            .unwrap();
+
+        assert_eq!(
+            list(&outputs, "C"),
+            vec![
+                Value::Boolean(true),
+                Value::Boolean(false),
+                Value::Boolean(true)
+            ]
+        );
+        assert_eq!(
+            list(&outputs, "I"),
+            vec![Value::Number(2.0), Value::Number(-1.0), Value::Number(0.0)]
+        );
+    }
+
+    #[test]
+    fn surface_split_cuts_square_in_two() {
+        let surface = Value::Surface {
+            vertices: vec![
+                [0.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [1.0, 1.0, 0.0],
+                [0.0, 1.0, 0.0],
+            ],
+            faces: vec![vec![0, 1, 2, 3]],
+        };
+        let cutter = Value::CurveLine {
+            p1: [0.3, -0.5, 0.0],
+            p2: [0.3, 1.5, 0.0],
+        };
+        let outputs = ComponentKind::SurfaceSplit
+            .evaluate(&[surface, Value::List(vec![cutter])], &meta())
+            .unwrap();
+
+        let fragments = list(&outputs, "F");
+        assert_eq!(fragments.len(), 2);
+        assert!(
+            fragments
+                .iter()
+                .all(|fragment| matches!(fragment, Value::Surface { .. }))
+        );
+    }
+}
@@ -0,0 +1,531 @@
+//! Implementaties van Grasshopper "Intersect → Region" componenten.
+//!
+//! Een curve wordt geknipt waar hij de trimgeometrie kruist en elk stuk wordt
+//! in zijn midden geclassificeerd. Vlakke regio's zijn gesloten curves,
+//! geprojecteerd op het oplossingsvlak; de kruisingen worden gezocht op de
+//! geprojecteerde `geom::ParamPolyline` van de curve en, voor gesamplede
+//! curves, op de curve zelf verfijnd met bisectie. Breps lopen via hun
+//! weergavemesh: `geom::mesh_polyline_hits` levert de knippunten en
+//! `geom::classify_point_in_mesh` bepaalt binnen of buiten. Stukken van lijnen
+//! blijven lijnen, andere stukken worden polylijnen, en een curve die nergens
+//! geknipt wordt gaat ongewijzigd door. Gesloten curves worden niet op hun
+//! naad gesplitst.
+
+use std::collections::BTreeMap;
+
+use crate::geom::{
+    Curve3, Line3, ParamPolyline, Point3, PointContainment, Polyline3, Region2, RegionPlane,
+    Tolerance, classify_point_in_mesh, mesh_polyline_hits,
+};
+use crate::graph::node::MetaMap;
+use crate::graph::value::{CurveValue, Value};
+
+use super::intersect_common::{
+    coerce_curve, collect_meshes, curve_loops, explicit_plane, param_polyline, point_value,
+    project_loops,
+};
+use super::{Component, ComponentError, ComponentResult, OutputMap, PinAccess};
+
+const PIN_OUTPUT_INSIDE: &str = "Ci";
+const PIN_OUTPUT_OUTSIDE: &str = "Co";
+const PIN_OUTPUT_CURVES: &str = "C";
+const PIN_OUTPUT_POINTS: &str = "P";
+
+/// Aantal bisectiestappen om een snijpunt op een bemonsterde curve te verfijnen.
+const REFINE_STEPS: usize = 48;
+
+/// Beschikbare componenten binnen deze module.
+#[derive(Debug, Clone, Copy)]
+pub enum ComponentKind {
+    TrimWithRegions,
+    TrimWithRegion,
+    TrimWithBrep,
+    TrimWithBreps,
+    SplitWithBrep,
+    SplitWithBreps,
+}
+
+/// Metadata voor registraties in de componentregistry.
+#[derive(Debug, Clone, Copy)]
+pub struct Registration {
+    pub guids: &'static [&'static str],
+    pub names: &'static [&'static str],
+    pub kind: ComponentKind,
+}
+
+/// Volledige lijst met componentregistraties voor de regio-snijcomponenten.
+/// De bijnamen "Trim" en "Split" worden door meerdere componenten gedeeld en
+/// worden daarom niet geregistreerd.
+pub const REGISTRATIONS: &[Registration] = &[
+    Registration {
+        guids: &["{26949c81-9b50-43b7-ac49-3203deb6eec7}"],
+        names: &["Trim with Regions"],
+        kind: ComponentKind::TrimWithRegions,
+    },
+    Registration {
+        guids: &["{3092caf0-7cf9-4885-bcc0-e635d878832a}"],
+        names: &["Trim with Region"],
+        kind: ComponentKind::TrimWithRegion,
+    },
+    Registration {
+        guids: &["{3eba04bc-00e8-416d-b58f-a3dc8b3e22e2}"],
+        names: &["Trim with Brep"],
+        kind: ComponentKind::TrimWithBrep,
+    },
+    Registration {
+        guids: &["{916e7ebc-524c-47ce-8936-e50a09a7b43c}"],
+        names: &["Trim with Breps"],
+        kind: ComponentKind::TrimWithBreps,
+    },
+    Registration {
+        guids: &["{4bdc2eb0-24ed-4c90-a27b-a32db069eaef}"],
+        names: &["Split with Brep"],
+        kind: ComponentKind::SplitWithBrep,
+    },
+    Registration {
+        guids: &["{5b742537-9bcb-4f06-9613-866da5bf845e}"],
+        names: &["Split with Breps"],
+        kind: ComponentKind::SplitWithBreps,
+    },
+];
+
+impl Component for ComponentKind {
+    fn evaluate(&self, inputs: &[Value], _meta: &MetaMap) -> ComponentResult {
+        let context = self.name();
+        match self {
+            Self::TrimWithRegions | Self::TrimWithRegion => evaluate_trim_regions(inputs, context),
+            Self::TrimWithBrep | Self::TrimWithBreps => evaluate_brep(inputs, context, false),
+            Self::SplitWithBrep | Self::SplitWithBreps => evaluate_brep(inputs, context, true),
+        }
+    }
+
+    fn input_access(&self) -> &'static [PinAccess] {
+        match self {
+            Self::TrimWithRegions => &[PinAccess::Item, PinAccess::List, PinAccess::Item],
+            Self::TrimWithBreps | Self::SplitWithBreps => &[PinAccess::Item, PinAccess::List],
+            Self::TrimWithRegion | Self::TrimWithBrep | Self::SplitWithBrep => &[PinAccess::Item],
+        }
+    }
+}
+
+impl ComponentKind {
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::TrimWithRegions => "Trim with Regions",
+            Self::TrimWithRegion => "Trim with Region",
+            Self::TrimWithBrep => "Trim with Brep",
+            Self::TrimWithBreps => "Trim with Breps",
+            Self::SplitWithBrep => "Split with Brep",
+            Self::SplitWithBreps => "Split with Breps",
+        }
+    }
+}
+
+/// Trim with Region(s): alle regio's van de R-invoer tellen samen; Trim with
+/// Region krijgt één regio per aanroep.
+fn evaluate_trim_regions(inputs: &[Value], context: &str) -> ComponentResult {
+    let Some(curve) = coerce_curve(inputs.get(0), context)? else {
+        return Ok(OutputMap::new());
+    };
+    let regions = curve_loops(inputs.get(1), context)?;
+    let plane = explicit_plane(inputs.get(2), context)?;
+    let polyline = param_polyline(&curve);
+
+    // Zonder vlak het vlak van de curve; rechte curves vallen terug op het
+    // vlak van de eerste regio.
+    let plane = plane
+        .or_else(|| RegionPlane::fit(&polyline.points))
+        .or_else(|| {
+            regions
+                .first()
+                .and_then(|region| RegionPlane::fit(&region.points))
+        })
+        .ok_or_else(|| ComponentError::new(format!("{context} kan geen oplossingsvlak bepalen")))?;
+    let projected = project_loops(&regions, &plane);
+
+    let cuts = region_cuts(&curve, &polyline, &plane, &projected);
+    let pieces = split_curve(&curve, &polyline, &cuts);
+    Ok(trim_outputs(pieces, |point| {
+        let local = plane.to_local(point);
+        projected.iter().any(|region| region.contains(local))
+    }))
+}
+
+/// Trim en Split with Brep(s): alle breps van de B-invoer snijden samen; de
+/// enkelvoudige varianten krijgen één brep per aanroep.
+fn evaluate_brep(inputs: &[Value], context: &str, split: bool) -> ComponentResult {
+    let Some(curve) = coerce_curve(inputs.get(0), context)? else {
+        return Ok(OutputMap::new());
+    };
+    let meshes = collect_meshes(inputs.get(1), context)?;
+    let tol = Tolerance::default_geom();
+    let polyline = param_polyline(&curve);
+
+    let mut hits: Vec<(f64, Point3)> = meshes
+        .iter()
+        .flat_map(|mesh| mesh_polyline_hits(mesh, &polyline, tol))
+        .map(|hit| (hit.t, hit.point))
+        .collect();
+    hits.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let cuts: Vec<f64> = hits.iter().map(|(t, _)| *t).collect();
+    let pieces = split_curve(&curve, &polyline, &cuts);
+
+    if split {
+        let mut outputs = BTreeMap::new();
+        outputs.insert(
+            PIN_OUTPUT_CURVES.to_owned(),
+            Value::List(pieces.into_iter().map(|piece| piece.value).collect()),
+        );
+        outputs.insert(
+            PIN_OUTPUT_POINTS.to_owned(),
+            Value::List(
+                hits.into_iter()
+                    .map(|(_, point)| point_value(point))
+                    .collect(),
+            ),
+        );
+        return Ok(outputs);
+    }
+
+    Ok(trim_outputs(pieces, |point| {
+        meshes.iter().any(|mesh| {
+            matches!(
+                classify_point_in_mesh(point, mesh, tol),
+                PointContainment::Inside | PointContainment::OnSurface
+            )
+        })
+    }))
+}
+
+/// Curveparameters waar de in het vlak geprojecteerde curve een regiorand
+/// kruist.
+fn region_cuts(
+    curve: &CurveValue,
+    polyline: &ParamPolyline,
+    plane: &RegionPlane,
+    regions: &[Region2],
+) -> Vec<f64> {
+    let local: Vec<[f64; 2]> = polyline
+        .points
+        .iter()
+        .map(|point| plane.to_local(*point))
+        .collect();
+
+    let mut cuts = Vec::new();
+    for (segment, pair) in local.windows(2).enumerate() {
+        let (p, r) = (pair[0], sub2(pair[1], pair[0]));
+        for ring in regions.iter().flat_map(Region2::loops) {
+            for index in 0..ring.len() {
+                let q = ring[index];
+                let s = sub2(ring[(index + 1) % ring.len()], q);
+                let denom = cross2(r, s);
+                if denom.abs() <= f64::EPSILON * (r[0].hypot(r[1]) * s[0].hypot(s[1])) {
+                    continue;
+                }
+                let w = sub2(q, p);
+                let t = cross2(w, s) / denom;
+                let u = cross2(w, r) / denom;
+                if !(0.0..=1.0).contains(&t) || !(0.0..1.0).contains(&u) {
+                    continue;
+                }
+
+                let (t0, t1) = (polyline.params[segment], polyline.params[segment + 1]);
+                let param = t0 + (t1 - t0) * t;
+                cuts.push(if polyline.deviation > 0.0 {
+                    refine_crossing(curve, plane, q, s, t0, t1).unwrap_or(param)
+                } else {
+                    param
+                });
+            }
+        }
+    }
+    cuts.sort_by(f64::total_cmp);
+    cuts
+}
+
+/// Bisectie op de curve zelf naar de parameter waar de projectie de lijn door
+/// `origin` met richting `direction` kruist.
+fn refine_crossing(
+    curve: &CurveValue,
+    plane: &RegionPlane,
+    origin: [f64; 2],
+    direction: [f64; 2],
+    mut low: f64,
+    mut high: f64,
+) -> Option<f64> {
+    let side = |t: f64| cross2(direction, sub2(plane.to_local(curve.point_at(t)), origin));
+    let mut low_side = side(low);
+    if low_side * side(high) > 0.0 {
+        return None;
+    }
+    for _ in 0..REFINE_STEPS {
+        let mid = 0.5 * (low + high);
+        let mid_side = side(mid);
+        if (mid_side <= 0.0) == (low_side <= 0.0) {
+            low = mid;
+            low_side = mid_side;
+        } else {
+            high = mid;
+        }
+    }
+    Some(0.5 * (low + high))
+}
+
+/// Een stuk curve met het punt waarop het geclassificeerd wordt.
+struct Piece {
+    value: Value,
+    probe: Point3,
+}
+
+/// Knipt de curve op de gegeven parameters. Voor gesloten curves worden het
+/// eerste en laatste stuk over de naad samengevoegd.
+fn split_curve(curve: &CurveValue, polyline: &ParamPolyline, cuts: &[f64]) -> Vec<Piece> {
+    let (t0, t1) = curve.domain();
+    let merge = (t1 - t0).abs() * 1e-9;
+    let mut bounds = vec![t0];
+    for &cut in cuts {
+        if cut - bounds[bounds.len() - 1] > merge && t1 - cut > merge {
+            bounds.push(cut);
+        }
+    }
+    bounds.push(t1);
+
+    if bounds.len() == 2 {
+        return vec![Piece {
+            value: Value::Curve(curve.clone()),
+            probe: curve.point_at(0.5 * (t0 + t1)),
+        }];
+    }
+
+    let mut ranges: Vec<Vec<(f64, f64)>> = bounds
+        .windows(2)
+        .map(|pair| vec![(pair[0], pair[1])])
+        .collect();
+    let closed = polyline.points.len() > 2
+        && polyline.points[0].distance_to(polyline.points[polyline.points.len() - 1])
+            <= Tolerance::LOOSE.eps;
+    if closed {
+        let first = ranges.remove(0);
+        ranges
+            .last_mut()
+            .expect("ten minste twee stukken")
+            .extend(first);
+    }
+
+    ranges
+        .into_iter()
+        .map(|spans| {
+            let mut points: Vec<Point3> = Vec::new();
+            for &(start, end) in &spans {
+                let mut span = vec![point_on(curve, polyline, start)];
+                span.extend(
+                    polyline
+                        .params
+                        .iter()
+                        .zip(&polyline.points)
+                        .filter(|(t, _)| **t > start + merge && **t < end - merge)
+                        .map(|(_, point)| *point),
+                );
+                span.push(point_on(curve, polyline, end));
+                let skip = usize::from(points.last().is_some());
+                points.extend(span.into_iter().skip(skip));
+            }
+
+            let length: f64 = spans.iter().map(|(start, end)| end - start).sum();
+            let mut half = 0.5 * length;
+            let mut probe_param = spans[0].0;
+            for &(start, end) in &spans {
+                if half <= end - start {
+                    probe_param = start + half;
+                    break;
+                }
+                half -= end - start;
+            }
+
+            Piece {
+                value: piece_value(curve, points),
+                probe: point_on(curve, polyline, probe_param),
+            }
+        })
+        .collect()
+}
+
+/// Punt op parameter `t`: exacte polylines worden geïnterpoleerd, andere
+/// curves geëvalueerd.
+fn point_on(curve: &CurveValue, polyline: &ParamPolyline, t: f64) -> Point3 {
+    if polyline.deviation > 0.0 {
+        return curve.point_at(t);
+    }
+    let params = &polyline.params;
+    let segment = params
+        .windows(2)
+        .position(|pair| t <= pair[1])
+        .unwrap_or(params.len().saturating_sub(2));
+    let (a, b) = (params[segment], params[segment + 1]);
+    let s = if b > a {
+        ((t - a) / (b - a)).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    polyline.points[segment].lerp(polyline.points[segment + 1], s)
+}
+
+fn piece_value(curve: &CurveValue, points: Vec<Point3>) -> Value {
+    match (curve, points.as_slice()) {
+        (CurveValue::Line(_), [start, .., end]) => {
+            Value::Curve(CurveValue::Line(Line3::new(*start, *end)))
+        }
+        _ => match Polyline3::new(points, false) {
+            Ok(polyline) => Value::Curve(CurveValue::Polyline(polyline)),
+            Err(_) => Value::Null,
+        },
+    }
+}
+
+fn trim_outputs(pieces: Vec<Piece>, inside: impl Fn(Point3) -> bool) -> OutputMap {
+    let (inner, outer): (Vec<Piece>, Vec<Piece>) =
+        pieces.into_iter().partition(|piece| inside(piece.probe));
+
+    let mut outputs = BTreeMap::new();
+    outputs.insert(
+        PIN_OUTPUT_INSIDE.to_owned(),
+        Value::List(inner.into_iter().map(|piece| piece.value).collect()),
+    );
+    outputs.insert(
+        PIN_OUTPUT_OUTSIDE.to_owned(),
+        Value::List(outer.into_iter().map(|piece| piece.value).collect()),
+    );
+    outputs
+}
+
+fn sub2(a: [f64; 2], b: [f64; 2]) -> [f64; 2] {
+    [a[0] - b[0], a[1] - b[1]]
+}
+
+fn cross2(a: [f64; 2], b: [f64; 2]) -> f64 {
+    a[0] * b[1] - a[1] * b[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::intersect_common::fixtures::{cube, list, meta};
+    use crate::geom::{Circle3, Vec3};
+
+    fn line(p1: [f64; 3], p2: [f64; 3]) -> Value {
+        Value::CurveLine { p1, p2 }
+    }
+
+    fn square(x0: f64, y0: f64, x1: f64, y1: f64) -> Value {
+        Value::List(
+            [[x0, y0], [x1, y0], [x1, y1], [x0, y1], [x0, y0]]
+                .into_iter()
+                .map(|[x, y]| Value::Point([x, y, 0.0]))
+                .collect(),
+        )
+    }
+
+    fn line_ends(value: &Value) -> (Point3, Point3) {
+        match value {
+            Value::Curve(CurveValue::Line(line)) => (line.start, line.end),
+            other => panic!("expected line, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn trim_with_region_cuts_line_at_boundary() {
+        let outputs = ComponentKind::TrimWithRegion
+            .evaluate(
+                &[
+                    line([-1.0, 0.5, 0.0], [3.0, 0.5, 0.0]),
+                    square(0.0, 0.0, 2.0, 1.0),
+                    Value::Null,
+                ],
+                &meta(),
+            )
+            .expect("trim");
+
+        let inside = list(&outputs, PIN_OUTPUT_INSIDE);
+        assert_eq!(inside.len(), 1);
+        let (start, end) = line_ends(&inside[0]);
+        assert!(start.distance_to(Point3::new(0.0, 0.5, 0.0)) < 1e-9);
+        assert!(end.distance_to(Point3::new(2.0, 0.5, 0.0)) < 1e-9);
+        assert_eq!(list(&outputs, PIN_OUTPUT_OUTSIDE).len(), 2);
+    }
+
+    #[test]
+    fn trim_with_regions_merges_circle_pieces_across_seam() {
+        let circle = Value::Curve(CurveValue::Circle(Circle3::new(
+            Point3::ORIGIN,
+            Vec3::Z,
+            1.0,
+        )));
+        let outputs = ComponentKind::TrimWithRegions
+            .evaluate(
+                &[circle, Value::List(vec![square(0.0, -2.0, 2.0, 2.0)])],
+                &meta(),
+            )
+            .expect("trim");
+
+        let inside = list(&outputs, PIN_OUTPUT_INSIDE);
+        let outside = list(&outputs, PIN_OUTPUT_OUTSIDE);
+        assert_eq!((inside.len(), outside.len()), (1, 1));
+        let Value::Curve(CurveValue::Polyline(half)) = &inside[0] else {
+            panic!("expected polyline, got {:?}", inside[0]);
+        };
+        assert!(half.points().iter().all(|point| point.x > -1e-6));
+        assert!(half.points()[0].distance_to(Point3::new(0.0, -1.0, 0.0)) < 1e-6);
+    }
+
+    #[test]
+    fn trim_with_brep_separates_inside_piece() {
+        let outputs = ComponentKind::TrimWithBrep
+            .evaluate(
+                &[
+                    line([-1.0, 0.5, 0.5], [2.0, 0.5, 0.5]),
+                    cube([0.0, 0.0, 0.0], 1.0),
+                ],
+                &meta(),
+            )
+            .expect("trim");
+
+        let inside = list(&outputs, PIN_OUTPUT_INSIDE);
+        assert_eq!(inside.len(), 1);
+        let (start, end) = line_ends(&inside[0]);
+        assert!((start.x - 0.0).abs() < 1e-9 && (end.x - 1.0).abs() < 1e-9);
+        assert_eq!(list(&outputs, PIN_OUTPUT_OUTSIDE).len(), 2);
+    }
+
+    #[test]
+    fn split_with_breps_returns_pieces_and_points() {
+        let outputs = ComponentKind::SplitWithBreps
+            .evaluate(
+                &[
+                    line([-1.0, 0.5, 0.5], [4.0, 0.5, 0.5]),
+                    Value::List(vec![cube([0.0, 0.0, 0.0], 1.0), cube([2.0, 0.0, 0.0], 1.0)]),
+                ],
+                &meta(),
+            )
+            .expect("split");
+
+        assert_eq!(list(&outputs, PIN_OUTPUT_CURVES).len(), 5);
+        assert_eq!(list(&outputs, PIN_OUTPUT_POINTS).len(), 4);
+    }
+
+    #[test]
+    fn untouched_curve_passes_through() {
+        let outputs = ComponentKind::TrimWithBrep
+            .evaluate(
+                &[
+                    line([5.0, 0.0, 0.0], [6.0, 0.0, 0.0]),
+                    cube([0.0, 0.0, 0.0], 1.0),
+                ],
+                &meta(),
+            )
+            .expect("trim");
+
+        assert!(list(&outputs, PIN_OUTPUT_INSIDE).is_empty());
+        assert_eq!(list(&outputs, PIN_OUTPUT_OUTSIDE).len(), 1);
+    }
+}
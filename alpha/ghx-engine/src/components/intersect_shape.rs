@@ -0,0 +1,1013 @@
+//! Implementaties van Grasshopper "Intersect → Shape" componenten.
+//!
+//! Breps en oppervlakken komen binnen als hun weergavemesh, zodat de solid- en
+//! meshvarianten één implementatie delen bovenop `geom::boolean_meshes` en
+//! `geom::split_mesh_by_mesh`. Meer dan twee operanden worden paarsgewijs
+//! gecombineerd; de `BooleanDiagnostics` van elke stap komen als waarschuwingen,
+//! voorafgegaan door de componentnaam, in de diagnostiek van de resulterende
+//! `Value::Mesh` terecht.
+//!
+//! De regiocomponenten projecteren gesloten curves op één vlak en gebruiken de
+//! vlakke polygoonclipping van `geom::region_boolean`. Resultaten zijn gesloten
+//! polylijnen, behalve lussen die ongewijzigd doorkomen: die houden hun
+//! oorspronkelijke curve. Region Slits en Box Slits snijden in elkaar grijpende
+//! sleuven met `geom::region_slits` en `geom::box_slits`.
+
+use std::collections::BTreeMap;
+
+use crate::geom::{
+    BooleanDiagnostics, BooleanError, BooleanOp, GeomMesh, GeomMeshDiagnostics, Point3, Polyline3,
+    Region2, RegionOp, RegionPlane, SlitBox, SlitRegion, Tolerance, Vec3, boolean_meshes,
+    box_slits, region_boolean, region_slits, split_mesh_by_mesh,
+};
+use crate::graph::node::MetaMap;
+use crate::graph::tree::{DataTree, TreePath};
+use crate::graph::value::{CurveValue, Value};
+
+use super::coerce::{self, geom_bridge};
+use super::intersect_common::{
+    CurveLoop, coerce_mesh, collect_meshes, curve_loops, explicit_plane, optional_item,
+    project_loops,
+};
+use super::{Component, ComponentError, ComponentOutput, ComponentResult, OutputMap, PinAccess};
+
+const PIN_OUTPUT_RESULT: &str = "R";
+const PIN_OUTPUT_BOXES: &str = "B";
+const PIN_OUTPUT_TOPOLOGY: &str = "T";
+
+/// Beschikbare componenten binnen deze module.
+#[derive(Debug, Clone, Copy)]
+pub enum ComponentKind {
+    SolidUnion,
+    SolidDifference,
+    SolidIntersection,
+    MeshUnion,
+    MeshDifference,
+    MeshIntersection,
+    MeshSplit,
+    TrimSolid,
+    SplitBrep,
+    SplitBrepMultiple,
+    RegionUnion,
+    RegionIntersection,
+    RegionDifference,
+    RegionSlits,
+    BoxSlits,
+}
+
+/// Metadata voor registraties in de componentregistry.
+#[derive(Debug, Clone, Copy)]
+pub struct Registration {
+    pub guids: &'static [&'static str],
+    pub names: &'static [&'static str],
+    pub kind: ComponentKind,
+}
+
+/// Volledige lijst met componentregistraties voor de vorm-snijcomponenten.
+/// "SUnion" is al van Set Union en "Trim" en "Split" zijn door meerdere
+/// componenten bezet; die bijnamen worden daarom niet geregistreerd.
+pub const REGISTRATIONS: &[Registration] = &[
+    Registration {
+        guids: &["{10434a15-da85-4281-bb64-a2b3a995b9c6}"],
+        names: &["Solid Union"],
+        kind: ComponentKind::SolidUnion,
+    },
+    Registration {
+        guids: &["{fab11c30-2d9c-4d15-ab3c-2289f1ae5c21}"],
+        names: &["Solid Difference", "SDiff"],
+        kind: ComponentKind::SolidDifference,
+    },
+    Registration {
+        guids: &["{5723c845-cafc-442d-a667-8c76532845e6}"],
+        names: &["Solid Intersection", "SInt"],
+        kind: ComponentKind::SolidIntersection,
+    },
+    Registration {
+        guids: &["{88060a82-0bf7-46bb-9af8-bdc860cf7e1d}"],
+        names: &["Mesh Union", "MUnion"],
+        kind: ComponentKind::MeshUnion,
+    },
+    Registration {
+        guids: &["{4f3147f4-9fcd-4a7e-be0e-b1841caa5f97}"],
+        names: &["Mesh Difference", "MDif"],
+        kind: ComponentKind::MeshDifference,
+    },
+    Registration {
+        guids: &["{95aef4f6-66fc-477e-b8f8-32395a837831}"],
+        names: &["Mesh Intersection", "MInt"],
+        kind: ComponentKind::MeshIntersection,
+    },
+    Registration {
+        guids: &["{afbf2fe0-4965-48d2-8470-9e991540093b}"],
+        names: &["Mesh Split", "MSplit"],
+        kind: ComponentKind::MeshSplit,
+    },
+    Registration {
+        guids: &["{f0b70e8e-7337-4ce4-a7bb-317fc971f918}"],
+        names: &["Trim Solid"],
+        kind: ComponentKind::TrimSolid,
+    },
+    Registration {
+        guids: &["{ef6b26f4-f820-48d6-b0c5-85898ef8888b}"],
+        names: &["Split Brep"],
+        kind: ComponentKind::SplitBrep,
+    },
+    Registration {
+        guids: &["{03f22640-ff80-484e-bb53-a4025c5faa07}"],
+        names: &["Split Brep Multiple", "SplitMul"],
+        kind: ComponentKind::SplitBrepMultiple,
+    },
+    Registration {
+        guids: &["{1222394f-0d33-4f31-9101-7281bde89fe5}"],
+        names: &["Region Union", "RUnion"],
+        kind: ComponentKind::RegionUnion,
+    },
+    Registration {
+        guids: &["{477c2e7b-c5e5-421e-b8b2-ba60cdf5398b}"],
+        names: &["Region Intersection", "RInt"],
+        kind: ComponentKind::RegionIntersection,
+    },
+    Registration {
+        guids: &["{f72c480b-7ee6-42ef-9821-c371e9203b44}"],
+        names: &["Region Difference", "RDiff"],
+        kind: ComponentKind::RegionDifference,
+    },
+    Registration {
+        guids: &["{0feeeaca-8f1f-4d7c-a24a-8e7dd68604a2}"],
+        names: &["Region Slits", "RSlits"],
+        kind: ComponentKind::RegionSlits,
+    },
+    Registration {
+        guids: &["{2d3b6ef3-5c26-4e2f-bcb3-8ffb9fb0f7c3}"],
+        names: &["Box Slits", "Slits"],
+        kind: ComponentKind::BoxSlits,
+    },
+];
+
+impl Component for ComponentKind {
+    fn evaluate(&self, inputs: &[Value], meta: &MetaMap) -> ComponentResult {
+        self.evaluate_with_warnings(inputs, meta)
+            .map(|output| output.outputs)
+    }
+
+    fn evaluate_with_warnings(
+        &self,
+        inputs: &[Value],
+        _meta: &MetaMap,
+    ) -> Result<ComponentOutput, ComponentError> {
+        let context = self.name();
+        match self {
+            Self::SolidUnion | Self::MeshUnion => evaluate_union(inputs, context),
+            Self::SolidDifference | Self::MeshDifference => evaluate_difference(inputs, context),
+            Self::SolidIntersection | Self::MeshIntersection => {
+                evaluate_intersection(inputs, context)
+            }
+            Self::TrimSolid => evaluate_trim_solid(inputs, context),
+            Self::MeshSplit | Self::SplitBrep | Self::SplitBrepMultiple => {
+                evaluate_split(inputs, context)
+            }
+            Self::RegionUnion => evaluate_region_union(inputs, context),
+            Self::RegionIntersection => {
+                evaluate_region_boolean(inputs, context, RegionOp::Intersection)
+            }
+            Self::RegionDifference => {
+                evaluate_region_boolean(inputs, context, RegionOp::Difference)
+            }
+            Self::RegionSlits => evaluate_region_slits(inputs, context),
+            Self::BoxSlits => evaluate_box_slits(inputs, context),
+        }
+    }
+
+    fn input_access(&self) -> &'static [PinAccess] {
+        match self {
+            Self::SolidUnion
+            | Self::SolidDifference
+            | Self::SolidIntersection
+            | Self::MeshUnion
+            | Self::MeshDifference
+            | Self::MeshIntersection => &[PinAccess::List],
+            Self::MeshSplit | Self::TrimSolid | Self::SplitBrepMultiple => {
+                &[PinAccess::Item, PinAccess::List]
+            }
+            Self::SplitBrep => &[PinAccess::Item],
+            Self::RegionUnion => &[PinAccess::List, PinAccess::Item],
+            Self::RegionIntersection | Self::RegionDifference => {
+                &[PinAccess::List, PinAccess::List, PinAccess::Item]
+            }
+            Self::RegionSlits => &[PinAccess::List, PinAccess::Item],
+            Self::BoxSlits => &[PinAccess::List, PinAccess::Item],
+        }
+    }
+}
+
+impl ComponentKind {
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::SolidUnion => "Solid Union",
+            Self::SolidDifference => "Solid Difference",
+            Self::SolidIntersection => "Solid Intersection",
+            Self::MeshUnion => "Mesh Union",
+            Self::MeshDifference => "Mesh Difference",
+            Self::MeshIntersection => "Mesh Intersection",
+            Self::MeshSplit => "Mesh Split",
+            Self::TrimSolid => "Trim Solid",
+            Self::SplitBrep => "Split Brep",
+            Self::SplitBrepMultiple => "Split Brep Multiple",
+            Self::RegionUnion => "Region Union",
+            Self::RegionIntersection => "Region Intersection",
+            Self::RegionDifference => "Region Difference",
+            Self::RegionSlits => "Region Slits",
+            Self::BoxSlits => "Box Slits",
+        }
+    }
+}
+
+fn evaluate_union(inputs: &[Value], context: &str) -> Result<ComponentOutput, ComponentError> {
+    let meshes = collect_meshes(inputs.get(0), context)?;
+
+    let mut log = BooleanLog::default();
+    let result = fold_boolean(meshes, BooleanOp::Union, &mut log, context)?;
+    Ok(result_list(result.into_iter().collect(), &log, context))
+}
+
+fn evaluate_difference(inputs: &[Value], context: &str) -> Result<ComponentOutput, ComponentError> {
+    let shapes = collect_meshes(inputs.get(0), context)?;
+    let cutters = collect_meshes(inputs.get(1), context)?;
+
+    // Elke vorm uit A verliest alle vormen uit B en levert één resultaat.
+    let mut output = ComponentOutput::default();
+    let mut values = Vec::with_capacity(shapes.len());
+    for shape in shapes {
+        let operands = std::iter::once(shape)
+            .chain(cutters.iter().cloned())
+            .collect();
+        let mut log = BooleanLog::default();
+        if let Some((mesh, diagnostics)) =
+            fold_boolean(operands, BooleanOp::Difference, &mut log, context)?
+        {
+            values.push(mesh_value(mesh, diagnostics, &log, context));
+        }
+        log.report(&mut output);
+    }
+
+    output
+        .outputs
+        .insert(PIN_OUTPUT_RESULT.to_owned(), Value::List(values));
+    Ok(output)
+}
+
+fn evaluate_intersection(
+    inputs: &[Value],
+    context: &str,
+) -> Result<ComponentOutput, ComponentError> {
+    let first = collect_meshes(inputs.get(0), context)?;
+    let second = collect_meshes(inputs.get(1), context)?;
+
+    // Het resultaat is de doorsnede van de vereniging van A met die van B.
+    let mut log = BooleanLog::default();
+    let first = fold_boolean(first, BooleanOp::Union, &mut log, context)?;
+    let second = fold_boolean(second, BooleanOp::Union, &mut log, context)?;
+    let result = match (first, second) {
+        (Some((first, _)), Some((second, _))) => fold_boolean(
+            vec![first, second],
+            BooleanOp::Intersection,
+            &mut log,
+            context,
+        )?,
+        _ => None,
+    };
+    Ok(result_list(result.into_iter().collect(), &log, context))
+}
+
+fn evaluate_trim_solid(inputs: &[Value], context: &str) -> Result<ComponentOutput, ComponentError> {
+    let Some(shape) = coerce_mesh(inputs.get(0), context)? else {
+        return Ok(ComponentOutput::default());
+    };
+    let cutters = collect_meshes(inputs.get(1), context)?;
+
+    let operands = std::iter::once(shape).chain(cutters).collect();
+    let mut log = BooleanLog::default();
+    let result = fold_boolean(operands, BooleanOp::Difference, &mut log, context)?;
+    Ok(result_list(result.into_iter().collect(), &log, context))
+}
+
+/// Mesh Split, Split Brep en Split Brep Multiple: de vorm wordt achtereenvolgens
+/// door alle snijvormen gesneden; de fragmenten binnen een snijvorm komen vóór
+/// de fragmenten erbuiten. Split Brep krijgt één snijvorm per aanroep.
+fn evaluate_split(inputs: &[Value], context: &str) -> Result<ComponentOutput, ComponentError> {
+    let Some(shape) = coerce_mesh(inputs.get(0), context)? else {
+        return Ok(ComponentOutput::default());
+    };
+    let cutters = collect_meshes(inputs.get(1), context)?;
+
+    let mut log = BooleanLog::default();
+    let mut pieces = vec![shape];
+    for cutter in &cutters {
+        let mut next = Vec::with_capacity(pieces.len() * 2);
+        for piece in &pieces {
+            let split = split_mesh_by_mesh(piece, cutter, Tolerance::default_geom())
+                .map_err(|error| boolean_error(context, error))?;
+            log.record(&split.diagnostics);
+            next.extend(
+                split
+                    .inside
+                    .into_iter()
+                    .chain(split.outside)
+                    .filter(|fragment| fragment.triangle_count() > 0),
+            );
+        }
+        pieces = next;
+    }
+
+    let fragments = pieces
+        .into_iter()
+        .map(|fragment| {
+            let diagnostics = fragment.compute_diagnostics(Tolerance::default_geom());
+            (fragment, diagnostics)
+        })
+        .collect();
+    Ok(result_list(fragments, &log, context))
+}
+
+/// Region Union: alle gesloten curves in C worden samengevoegd.
+fn evaluate_region_union(
+    inputs: &[Value],
+    context: &str,
+) -> Result<ComponentOutput, ComponentError> {
+    let loops = curve_loops(inputs.get(0), context)?;
+    let Some(plane) = region_plane(inputs.get(1), &loops, context)? else {
+        return Ok(region_list(Vec::new()).into());
+    };
+
+    let regions = project_loops(&loops, &plane);
+    let result = region_boolean(&regions, &[], RegionOp::Union, Tolerance::LOOSE);
+    let outputs = region_list(region_values(&result.regions, &plane, &loops, context)?);
+    Ok(ComponentOutput {
+        outputs,
+        warnings: result.diagnostics.warnings,
+    })
+}
+
+/// Region Intersection en Region Difference: A en B worden elk als de unie
+/// van hun curves behandeld.
+fn evaluate_region_boolean(
+    inputs: &[Value],
+    context: &str,
+    op: RegionOp,
+) -> Result<ComponentOutput, ComponentError> {
+    let a = curve_loops(inputs.get(0), context)?;
+    let b = curve_loops(inputs.get(1), context)?;
+    let all: Vec<CurveLoop> = a.iter().chain(&b).cloned().collect();
+    let Some(plane) = region_plane(inputs.get(2), &all, context)? else {
+        return Ok(region_list(Vec::new()).into());
+    };
+
+    let result = region_boolean(
+        &project_loops(&a, &plane),
+        &project_loops(&b, &plane),
+        op,
+        Tolerance::LOOSE,
+    );
+    let outputs = region_list(region_values(&result.regions, &plane, &all, context)?);
+    Ok(ComponentOutput {
+        outputs,
+        warnings: result.diagnostics.warnings,
+    })
+}
+
+/// Region Slits: elke curve ligt in zijn eigen vlak; waar twee regio's elkaar
+/// kruisen krijgen beide een sleuf van breedte W tot halverwege de overlap.
+fn evaluate_region_slits(
+    inputs: &[Value],
+    context: &str,
+) -> Result<ComponentOutput, ComponentError> {
+    let loops = curve_loops(inputs.get(0), context)?;
+    let width = coerce_length(inputs.get(1), context, "een sleufbreedte")?
+        .ok_or_else(|| ComponentError::new(format!("{context} vereist een sleufbreedte")))?;
+    let gap = coerce_length(inputs.get(2), context, "een eindspeling")?.unwrap_or(0.0);
+
+    let mut planes = Vec::with_capacity(loops.len());
+    let mut slit_regions = Vec::with_capacity(loops.len());
+    for curve_loop in &loops {
+        let plane = RegionPlane::fit(&curve_loop.points).ok_or_else(|| {
+            ComponentError::new(format!(
+                "{context} vereist vlakke, niet-gedegenereerde regio's"
+            ))
+        })?;
+        slit_regions.push(SlitRegion {
+            plane,
+            region: project_loops(std::slice::from_ref(curve_loop), &plane).remove(0),
+            slot_width: width,
+        });
+        planes.push(plane);
+    }
+
+    let result = region_slits(&slit_regions, gap, Tolerance::LOOSE);
+    let mut regions = DataTree::new();
+    for (index, (plane, pieces)) in planes.iter().zip(&result.regions).enumerate() {
+        let values = region_values(pieces, plane, &loops, context)?;
+        regions.insert_branch(TreePath::new(vec![index]), values);
+    }
+
+    let mut outputs = BTreeMap::new();
+    outputs.insert(PIN_OUTPUT_RESULT.to_owned(), Value::Tree(regions));
+    outputs.insert(
+        PIN_OUTPUT_TOPOLOGY.to_owned(),
+        Value::Tree(topology_tree(&result.topology)),
+    );
+    Ok(ComponentOutput {
+        outputs,
+        warnings: result.diagnostics.warnings,
+    })
+}
+
+/// Box Slits: elke box wordt een plaat met zijn kortste ribbe als dikte; de
+/// sleuven zijn zo breed als de kruisende plaat plus G.
+fn evaluate_box_slits(inputs: &[Value], context: &str) -> Result<ComponentOutput, ComponentError> {
+    let boxes = match inputs.get(0) {
+        Some(value) => collect_boxes(value, context)?,
+        None => Vec::new(),
+    };
+    let gap = coerce_length(inputs.get(1), context, "een speling")?.unwrap_or(0.0);
+
+    let result = box_slits(&boxes, gap, Tolerance::LOOSE)
+        .map_err(|error| ComponentError::new(format!("{context}: {error}")))?;
+    let log = BooleanLog {
+        warnings: result.diagnostics.warnings.clone(),
+        fallback_used: false,
+    };
+    let mut plates = DataTree::new();
+    for (index, meshes) in result.meshes.into_iter().enumerate() {
+        let values = meshes
+            .into_iter()
+            .map(|(mesh, diagnostics)| mesh_value(mesh, diagnostics, &log, context))
+            .collect();
+        plates.insert_branch(TreePath::new(vec![index]), values);
+    }
+
+    let mut outputs = BTreeMap::new();
+    outputs.insert(PIN_OUTPUT_BOXES.to_owned(), Value::Tree(plates));
+    outputs.insert(
+        PIN_OUTPUT_TOPOLOGY.to_owned(),
+        Value::Tree(topology_tree(&result.topology)),
+    );
+    Ok(ComponentOutput {
+        outputs,
+        warnings: log.warnings,
+    })
+}
+
+/// Het vlak uit de P-invoer of, zonder vlak, het vlak van de eerste curve.
+/// Zonder curves en zonder vlak is er niets te doen.
+fn region_plane(
+    value: Option<&Value>,
+    loops: &[CurveLoop],
+    context: &str,
+) -> Result<Option<RegionPlane>, ComponentError> {
+    if let Some(plane) = explicit_plane(value, context)? {
+        return Ok(Some(plane));
+    }
+
+    let Some(first) = loops.first() else {
+        return Ok(None);
+    };
+    RegionPlane::fit(&first.points).map(Some).ok_or_else(|| {
+        ComponentError::new(format!(
+            "{context} kan geen vlak bepalen uit de eerste curve"
+        ))
+    })
+}
+
+/// Zet regio's om naar gesloten curves: per regio de buitenrand gevolgd door
+/// de gaten. Een lus die exact overeenkomt met een invoercurve levert die
+/// invoercurve op, zodat cirkels en bogen analytisch blijven.
+fn region_values(
+    regions: &[Region2],
+    plane: &RegionPlane,
+    loops: &[CurveLoop],
+    context: &str,
+) -> Result<Vec<Value>, ComponentError> {
+    let projected: Vec<Vec<[f64; 2]>> = loops
+        .iter()
+        .map(|curve_loop| {
+            curve_loop
+                .points
+                .iter()
+                .map(|point| plane.to_local(*point))
+                .collect()
+        })
+        .collect();
+    let matches = |ring: &[[f64; 2]], candidate: &[[f64; 2]]| {
+        ring.len() == candidate.len()
+            && ring.iter().all(|point| {
+                candidate.iter().any(|other| {
+                    (point[0] - other[0]).hypot(point[1] - other[1]) <= Tolerance::LOOSE.eps
+                })
+            })
+    };
+
+    let mut values = Vec::new();
+    for ring in regions.iter().flat_map(Region2::loops) {
+        if let Some(index) = projected
+            .iter()
+            .position(|candidate| matches(ring, candidate))
+        {
+            values.push(Value::Curve(loops[index].curve.clone()));
+            continue;
+        }
+        let points = ring.iter().map(|point| plane.to_world(*point)).collect();
+        let polyline = Polyline3::new(points, true)
+            .map_err(|error| ComponentError::new(format!("{context}: {error}")))?;
+        values.push(Value::Curve(CurveValue::Polyline(polyline)));
+    }
+    Ok(values)
+}
+
+fn region_list(values: Vec<Value>) -> OutputMap {
+    let mut outputs = BTreeMap::new();
+    outputs.insert(PIN_OUTPUT_RESULT.to_owned(), Value::List(values));
+    outputs
+}
+
+fn topology_tree(topology: &[Vec<usize>]) -> DataTree {
+    let mut tree = DataTree::new();
+    for (index, partners) in topology.iter().enumerate() {
+        tree.insert_branch(
+            TreePath::new(vec![index]),
+            partners
+                .iter()
+                .map(|&partner| Value::Number(partner as f64))
+                .collect(),
+        );
+    }
+    tree
+}
+
+/// Optionele, niet-negatieve lengte; `None` als de invoer leeg is.
+fn coerce_length(
+    value: Option<&Value>,
+    context: &str,
+    expected: &str,
+) -> Result<Option<f64>, ComponentError> {
+    let Some(length) = optional_item(value, |value| coerce::coerce_number(value, Some(context)))?
+    else {
+        return Ok(None);
+    };
+    if !length.is_finite() || length < 0.0 {
+        return Err(ComponentError::new(format!(
+            "{context} vereist {expected} van nul of meer"
+        )));
+    }
+    Ok(Some(length))
+}
+
+/// Boxen komen binnen als lijst van acht hoekpunten (z, dan y, dan x); andere
+/// geometrie wordt vervangen door haar assen-uitgelijnde omhullende box.
+fn collect_boxes(value: &Value, context: &str) -> Result<Vec<SlitBox>, ComponentError> {
+    let corners = |values: &[Value]| -> Option<Vec<Point3>> {
+        (values.len() == 8)
+            .then(|| {
+                values
+                    .iter()
+                    .map(|value| match value {
+                        Value::Point(point) => Some(Point3::from_array(*point)),
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .flatten()
+    };
+
+    match value {
+        Value::Null => Ok(Vec::new()),
+        Value::List(values) => match corners(values) {
+            Some(points) => Ok(vec![SlitBox {
+                corner: points[0],
+                edges: [
+                    points[1].sub_point(points[0]),
+                    points[2].sub_point(points[0]),
+                    points[4].sub_point(points[0]),
+                ],
+            }]),
+            None => values.iter().try_fold(Vec::new(), |mut boxes, entry| {
+                boxes.extend(collect_boxes(entry, context)?);
+                Ok(boxes)
+            }),
+        },
+        other => {
+            let meshes = collect_meshes(Some(other), context)?;
+            Ok(meshes
+                .iter()
+                .filter_map(|mesh| {
+                    let (first, rest) = mesh.positions.split_first()?;
+                    let (min, max) = rest.iter().fold((*first, *first), |(min, max), point| {
+                        (
+                            [
+                                min[0].min(point[0]),
+                                min[1].min(point[1]),
+                                min[2].min(point[2]),
+                            ],
+                            [
+                                max[0].max(point[0]),
+                                max[1].max(point[1]),
+                                max[2].max(point[2]),
+                            ],
+                        )
+                    });
+                    Some(SlitBox {
+                        corner: Point3::from_array(min),
+                        edges: [
+                            Vec3::new(max[0] - min[0], 0.0, 0.0),
+                            Vec3::new(0.0, max[1] - min[1], 0.0),
+                            Vec3::new(0.0, 0.0, max[2] - min[2]),
+                        ],
+                    })
+                })
+                .collect())
+        }
+    }
+}
+
+/// Vouwt `op` over alle operanden van links naar rechts. Eén operand wordt
+/// ongewijzigd teruggegeven, zonder operanden is er geen resultaat.
+fn fold_boolean(
+    operands: Vec<GeomMesh>,
+    op: BooleanOp,
+    log: &mut BooleanLog,
+    context: &str,
+) -> Result<Option<(GeomMesh, GeomMeshDiagnostics)>, ComponentError> {
+    let mut operands = operands.into_iter();
+    let Some(first) = operands.next() else {
+        return Ok(None);
+    };
+
+    let diagnostics = first.compute_diagnostics(Tolerance::default_geom());
+    let mut current = (first, diagnostics);
+    for operand in operands {
+        let result = boolean_meshes(&current.0, &operand, op, Tolerance::default_geom())
+            .map_err(|error| boolean_error(context, error))?;
+        log.record(&result.diagnostics);
+        current = (result.mesh, result.mesh_diagnostics);
+    }
+    Ok(Some(current))
+}
+
+fn boolean_error(context: &str, error: BooleanError) -> ComponentError {
+    ComponentError::new(format!("{context}: boolean mislukt: {error}"))
+}
+
+/// Waarschuwingen van alle boolean-stappen achter één uitvoer, zonder
+/// dubbelingen en in volgorde van optreden.
+#[derive(Debug, Default)]
+struct BooleanLog {
+    warnings: Vec<String>,
+    fallback_used: bool,
+}
+
+impl BooleanLog {
+    fn record(&mut self, diagnostics: &BooleanDiagnostics) {
+        for warning in &diagnostics.warnings {
+            self.push(warning.clone());
+        }
+        if diagnostics.indeterminate_triangle_count > 0 {
+            self.push(format!(
+                "{} triangle(s) could not be classified",
+                diagnostics.indeterminate_triangle_count
+            ));
+        }
+        self.fallback_used |= diagnostics.tolerance_relaxed || diagnostics.voxel_fallback_used;
+    }
+
+    fn push(&mut self, warning: String) {
+        if !self.warnings.contains(&warning) {
+            self.warnings.push(warning);
+        }
+    }
+
+    /// Meldt de verzamelde waarschuwingen als componentwaarschuwingen.
+    fn report(&self, output: &mut ComponentOutput) {
+        for warning in &self.warnings {
+            output.push_warning(warning.clone());
+        }
+    }
+}
+
+/// Zet de resultaten om naar een lijst op de resultaatpin.
+fn result_list(
+    results: Vec<(GeomMesh, GeomMeshDiagnostics)>,
+    log: &BooleanLog,
+    context: &str,
+) -> ComponentOutput {
+    let values = results
+        .into_iter()
+        .map(|(mesh, diagnostics)| mesh_value(mesh, diagnostics, log, context))
+        .collect();
+
+    let mut outputs = BTreeMap::new();
+    outputs.insert(PIN_OUTPUT_RESULT.to_owned(), Value::List(values));
+    ComponentOutput {
+        outputs,
+        warnings: log.warnings.clone(),
+    }
+}
+
+/// Bouwt een `Value::Mesh` waarvan de diagnostics de meshcontroles van de
+/// laatste stap én alle boolean-waarschuwingen dragen.
+fn mesh_value(
+    mesh: GeomMesh,
+    mesh_diagnostics: GeomMeshDiagnostics,
+    log: &BooleanLog,
+    context: &str,
+) -> Value {
+    let mut diagnostics = geom_bridge::geom_diagnostics_to_value_diagnostics(mesh_diagnostics);
+    let mut warnings = std::mem::take(&mut diagnostics.warnings);
+    for warning in &log.warnings {
+        if !warnings.contains(warning) {
+            warnings.push(warning.clone());
+        }
+    }
+    diagnostics.warnings = warnings
+        .into_iter()
+        .map(|warning| format!("{context}: {warning}"))
+        .collect();
+    diagnostics.boolean_fallback_used |= log.fallback_used;
+    diagnostics.vertex_count = mesh.positions.len();
+    diagnostics.triangle_count = mesh.triangle_count();
+
+    Value::Mesh {
+        vertices: mesh.positions,
+        indices: mesh.indices,
+        normals: mesh.normals,
+        uvs: mesh.uvs,
+        diagnostics: Some(diagnostics),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::intersect_common::fixtures::{cube, meta};
+    use crate::geom::Circle3;
+    use crate::graph::value::MeshDiagnostics;
+
+    fn results(outputs: &OutputMap) -> Vec<Value> {
+        match outputs.get(PIN_OUTPUT_RESULT) {
+            Some(Value::List(values)) => values.clone(),
+            other => panic!("expected result list, got {other:?}"),
+        }
+    }
+
+    fn diagnostics(value: &Value) -> &MeshDiagnostics {
+        match value {
+            Value::Mesh {
+                diagnostics: Some(diagnostics),
+                ..
+            } => diagnostics,
+            other => panic!("expected mesh with diagnostics, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn mesh_union_folds_all_operands_into_one_mesh() {
+        let outputs = ComponentKind::MeshUnion
+            .evaluate(
+                &[Value::List(vec![
+                    cube([0.0, 0.0, 0.0], 1.0),
+                    cube([0.5, 0.3, 0.2], 1.0),
+                    cube([1.2, 0.6, 0.4], 1.0),
+                ])],
+                &meta(),
+            )
+            .expect("union");
+
+        let results = results(&outputs);
+        assert_eq!(results.len(), 1);
+        let diagnostics = diagnostics(&results[0]);
+        assert!(diagnostics.triangle_count > 0);
+        assert!(
+            diagnostics
+                .warnings
+                .iter()
+                .all(|warning| warning.starts_with("Mesh Union: "))
+        );
+    }
+
+    #[test]
+    fn boolean_warnings_reach_evaluation_result() {
+        use crate::graph::Graph;
+        use crate::graph::evaluator::{EvaluationWarning, evaluate};
+        use crate::graph::node::{Node, NodeId};
+
+        // Beide kubussen delen het grondvlak, wat de boolean waarschuwingen oplevert.
+        let mut graph = Graph::new();
+        let mut node = Node::new(NodeId::new(0));
+        node.guid = Some("{88060a82-0bf7-46bb-9af8-bdc860cf7e1d}".to_string());
+        node.add_input_pin("M");
+        node.set_input(
+            "M",
+            Value::List(vec![cube([0.0, 0.0, 0.0], 1.0), cube([0.5, 0.3, 0.0], 1.0)]),
+        );
+        let node_id = graph.add_node(node).unwrap();
+
+        let result = evaluate(&graph, &crate::components::ComponentRegistry::default())
+            .expect("evaluatie slaagt");
+
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        let messages: Vec<&str> = result
+            .warnings
+            .iter()
+            .filter_map(|warning| match warning {
+                EvaluationWarning::Component {
+                    node_id: id,
+                    component,
+                    message,
+                } if *id == node_id && component == "Mesh Union" => Some(message.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert!(!messages.is_empty());
+
+        // Dezelfde meldingen staan ook in de meshdiagnostics.
+        let outputs = &result.node_outputs[&node_id];
+        let mesh = match outputs.get(PIN_OUTPUT_RESULT) {
+            Some(Value::List(values)) => &values[0],
+            other => panic!("expected result list, got {other:?}"),
+        };
+        for message in messages {
+            let folded = format!("Mesh Union: {message}");
+            assert!(diagnostics(mesh).warnings.contains(&folded));
+        }
+    }
+
+    #[test]
+    fn solid_difference_returns_one_result_per_shape() {
+        let outputs = ComponentKind::SolidDifference
+            .evaluate(
+                &[
+                    Value::List(vec![cube([0.0, 0.0, 0.0], 1.0), cube([3.0, 0.0, 0.0], 1.0)]),
+                    Value::List(vec![cube([0.5, 0.3, 0.2], 1.0)]),
+                ],
+                &meta(),
+            )
+            .expect("difference");
+
+        let results = results(&outputs);
+        assert_eq!(results.len(), 2);
+        // De tweede vorm raakt de snijvorm niet en blijft onaangetast.
+        assert_eq!(diagnostics(&results[1]).triangle_count, 12);
+    }
+
+    #[test]
+    fn split_brep_returns_fragments_on_both_sides() {
+        let outputs = ComponentKind::SplitBrep
+            .evaluate(
+                &[cube([0.0, 0.0, 0.0], 1.0), cube([0.5, 0.3, 0.2], 1.0)],
+                &meta(),
+            )
+            .expect("split");
+
+        let results = results(&outputs);
+        assert_eq!(results.len(), 2);
+        assert!(
+            results
+                .iter()
+                .all(|fragment| diagnostics(fragment).open_edge_count > 0)
+        );
+    }
+
+    #[test]
+    fn trim_solid_without_cutters_returns_shape() {
+        let outputs = ComponentKind::TrimSolid
+            .evaluate(
+                &[cube([0.0, 0.0, 0.0], 1.0), Value::List(Vec::new())],
+                &meta(),
+            )
+            .expect("trim");
+
+        let results = results(&outputs);
+        assert_eq!(results.len(), 1);
+        assert_eq!(diagnostics(&results[0]).triangle_count, 12);
+    }
+
+    #[test]
+    fn boolean_rejects_non_mesh_input() {
+        let err = ComponentKind::MeshDifference
+            .evaluate(&[Value::Number(1.0), cube([0.0, 0.0, 0.0], 1.0)], &meta())
+            .unwrap_err();
+        assert!(err.to_string().contains("Mesh Difference"));
+    }
+
+    fn rectangle(x0: f64, y0: f64, x1: f64, y1: f64) -> Value {
+        Value::List(
+            [[x0, y0], [x1, y0], [x1, y1], [x0, y1], [x0, y0]]
+                .into_iter()
+                .map(|[x, y]| Value::Point([x, y, 0.0]))
+                .collect(),
+        )
+    }
+
+    fn box_corners(min: [f64; 3], max: [f64; 3]) -> Value {
+        let mut corners = Vec::new();
+        for z in [min[2], max[2]] {
+            for y in [min[1], max[1]] {
+                for x in [min[0], max[0]] {
+                    corners.push(Value::Point([x, y, z]));
+                }
+            }
+        }
+        Value::List(corners)
+    }
+
+    #[test]
+    fn region_union_merges_overlapping_rectangles() {
+        let outputs = ComponentKind::RegionUnion
+            .evaluate(
+                &[Value::List(vec![
+                    rectangle(0.0, 0.0, 2.0, 2.0),
+                    rectangle(1.0, 1.0, 3.0, 3.0),
+                ])],
+                &meta(),
+            )
+            .expect("region union");
+
+        let results = results(&outputs);
+        assert_eq!(results.len(), 1);
+        match &results[0] {
+            Value::Curve(CurveValue::Polyline(polyline)) => {
+                assert!(polyline.is_closed());
+                assert_eq!(polyline.points().len(), 8);
+            }
+            other => panic!("expected closed polyline, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn region_difference_keeps_untouched_circle_as_hole() {
+        let circle = Value::Curve(CurveValue::Circle(Circle3::new(
+            Point3::new(2.0, 2.0, 0.0),
+            Vec3::Z,
+            0.5,
+        )));
+        let outputs = ComponentKind::RegionDifference
+            .evaluate(
+                &[rectangle(0.0, 0.0, 4.0, 4.0), circle, Value::Null],
+                &meta(),
+            )
+            .expect("region difference");
+
+        let results = results(&outputs);
+        assert_eq!(results.len(), 2);
+        assert!(matches!(results[0], Value::Curve(CurveValue::Polyline(_))));
+        assert!(matches!(results[1], Value::Curve(CurveValue::Circle(_))));
+    }
+
+    #[test]
+    fn region_union_rejects_open_curves() {
+        let err = ComponentKind::RegionUnion
+            .evaluate(
+                &[Value::CurveLine {
+                    p1: [0.0, 0.0, 0.0],
+                    p2: [1.0, 0.0, 0.0],
+                }],
+                &meta(),
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("gesloten"));
+    }
+
+    #[test]
+    fn box_slits_returns_slotted_plates_and_topology() {
+        let outputs = ComponentKind::BoxSlits
+            .evaluate(
+                &[
+                    Value::List(vec![
+                        box_corners([-2.0, -0.1, 0.0], [2.0, 0.1, 2.0]),
+                        box_corners([-0.1, -2.0, 0.0], [0.1, 2.0, 2.0]),
+                        box_corners([5.0, 5.0, 0.0], [6.0, 5.1, 1.0]),
+                    ]),
+                    Value::Number(0.01),
+                ],
+                &meta(),
+            )
+            .expect("box slits");
+
+        let Some(Value::Tree(plates)) = outputs.get(PIN_OUTPUT_BOXES) else {
+            panic!("expected plate tree");
+        };
+        assert_eq!(plates.branch_count(), 3);
+        assert_eq!(plates.item_count(), 3);
+
+        let Some(Value::Tree(topology)) = outputs.get(PIN_OUTPUT_TOPOLOGY) else {
+            panic!("expected topology tree");
+        };
+        assert_eq!(
+            topology.branch(&TreePath::new(vec![0])),
+            Some([Value::Number(1.0)].as_slice())
+        );
+        assert_eq!(
+            topology.branch(&TreePath::new(vec![2])),
+            Some([].as_slice())
+        );
+    }
+}
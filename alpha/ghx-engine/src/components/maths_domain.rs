@@ -5,7 +5,8 @@ use std::collections::BTreeMap;
 use crate::graph::node::MetaMap;
 use crate::graph::value::{Domain, Domain1D, Domain2D, Value};
 
-use super::{Component, ComponentResult};
+use super::schema::PinDefault;
+use super::{Component, ComponentResult, PinAccess};
 
 const PIN_INDEX: &str = "I";
 const PIN_NEIGHBOUR: &str = "N";
@@ -162,9 +163,39 @@ impl Component for ComponentKind {
             Self::RemapNumbersSingle => evaluate_remap_numbers_single(inputs),
         }
     }
+
+    fn input_access(&self) -> &'static [PinAccess] {
+        match self {
+            Self::FindDomain | Self::ConsecutiveDomains | Self::RemapNumbersList => {
+                &[PinAccess::List, PinAccess::Item]
+            }
+            Self::RemapNumbers
+            | Self::DeconstructDomain2
+            | Self::DivideDomain2
+            | Self::DivideDomain
+            | Self::DeconstructDomain
+            | Self::ConstructDomain2
+            | Self::ConstructDomain2Numbers
+            | Self::ConstructDomain
+            | Self::DeconstructDomain2Components
+            | Self::Includes
+            | Self::RemapNumbersSingle => &[PinAccess::Item],
+            _ => &[],
+        }
+    }
 }
 
 impl ComponentKind {
+    /// Waarden die gebruikt worden als de betreffende input leeg blijft.
+    #[must_use]
+    pub fn input_defaults(&self) -> &'static [(&'static str, PinDefault)] {
+        match self {
+            Self::FindDomain => &[("S", PinDefault::Boolean(false))],
+            Self::ConsecutiveDomains => &[("A", PinDefault::Boolean(false))],
+            _ => &[],
+        }
+    }
+
     #[must_use]
     pub fn name(&self) -> &'static str {
         match self {
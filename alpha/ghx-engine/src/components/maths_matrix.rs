@@ -5,7 +5,7 @@ use std::collections::BTreeMap;
 use crate::graph::node::MetaMap;
 use crate::graph::value::{Matrix, Value};
 
-use super::{Component, ComponentError, ComponentResult};
+use super::{Component, ComponentError, ComponentResult, PinAccess};
 
 const PIN_MATRIX: &str = "M";
 const PIN_ROWS: &str = "R";
@@ -93,6 +93,17 @@ impl Component for ComponentKind {
             Self::InvertMatrix => evaluate_invert_matrix(inputs),
         }
     }
+
+    fn input_access(&self) -> &'static [PinAccess] {
+        match self {
+            Self::ConstructMatrix => &[PinAccess::Item, PinAccess::Item, PinAccess::List],
+            Self::DeconstructMatrix
+            | Self::TransposeMatrix
+            | Self::SwapRows
+            | Self::SwapColumns
+            | Self::InvertMatrix => &[PinAccess::Item],
+        }
+    }
 }
 
 fn evaluate_construct_matrix(inputs: &[Value]) -> ComponentResult {
@@ -266,6 +277,9 @@ fn collect_numbers_inner(value: &Value, result: &mut Vec<f64>) {
         Value::Number(number) => result.push(*number),
         Value::Boolean(boolean) => result.push(if *boolean { 1.0 } else { 0.0 }),
         Value::Matrix(matrix) => result.extend(matrix.values.iter().copied()),
+        Value::Transform(transform) => {
+            result.extend(transform.as_matrix().iter().flatten().copied())
+        }
         Value::List(values) => {
             for entry in values {
                 collect_numbers_inner(entry, result);
@@ -278,6 +292,7 @@ fn collect_numbers_inner(value: &Value, result: &mut Vec<f64>) {
 fn coerce_matrix(value: &Value, context: &str) -> Result<Matrix, ComponentError> {
     match value {
         Value::Matrix(matrix) => Ok(matrix.clone()),
+        Value::Transform(transform) => Ok(Matrix::from_transform(transform)),
         Value::List(values) if values.is_empty() => Err(ComponentError::new(format!(
             "{} verwacht een matrix met waarden",
             context
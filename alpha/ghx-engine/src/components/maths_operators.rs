@@ -5,7 +5,7 @@ use std::collections::BTreeMap;
 use crate::graph::node::MetaMap;
 use crate::graph::value::Value;
 
-use super::{Component, ComponentError, ComponentResult, coerce};
+use super::{Component, ComponentError, ComponentResult, PinAccess, coerce};
 
 const EPSILON: f64 = 1e-9;
 
@@ -242,6 +242,35 @@ impl Component for ComponentKind {
             Self::Similarity => evaluate_similarity(inputs),
         }
     }
+
+    fn input_access(&self) -> &'static [PinAccess] {
+        match self {
+            Self::Addition
+            | Self::GateAnd
+            | Self::GateOr
+            | Self::GateNot
+            | Self::GateNor
+            | Self::GateNand
+            | Self::GateXor
+            | Self::GateXnor
+            | Self::GateMajority
+            | Self::Absolute
+            | Self::Negative
+            | Self::Subtraction
+            | Self::Multiplication
+            | Self::Division
+            | Self::IntegerDivision
+            | Self::Modulus
+            | Self::Power
+            | Self::Factorial
+            | Self::LargerThan
+            | Self::SmallerThan
+            | Self::Equality
+            | Self::Similarity => &[PinAccess::Item],
+            Self::SeriesAddition => &[PinAccess::List, PinAccess::Item],
+            _ => &[],
+        }
+    }
 }
 
 impl ComponentKind {
@@ -5,7 +5,7 @@ use std::collections::BTreeMap;
 use crate::graph::node::MetaMap;
 use crate::graph::value::Value;
 
-use super::{Component, ComponentError, ComponentResult};
+use super::{Component, ComponentError, ComponentResult, PinAccess};
 
 const PIN_RESULT_Y: &str = "y";
 const PIN_RESULT_R: &str = "R";
@@ -145,6 +145,20 @@ impl Component for ComponentKind {
             Self::PowerOfE => evaluate_unary(inputs, "Power of E", PIN_RESULT_Y, |x| Ok(x.exp())),
         }
     }
+
+    fn input_access(&self) -> &'static [PinAccess] {
+        match self {
+            Self::Square
+            | Self::NaturalLogarithm
+            | Self::Logarithm
+            | Self::PowerOfTen
+            | Self::LogN
+            | Self::Cube
+            | Self::SquareRoot
+            | Self::PowerOfE => &[PinAccess::Item],
+            _ => &[],
+        }
+    }
 }
 
 impl ComponentKind {
@@ -8,7 +8,7 @@ use rand::{Rng, rng};
 use crate::graph::node::{MetaLookupExt, MetaMap, MetaValue};
 use crate::graph::value::Value;
 
-use super::{Component, ComponentError, ComponentResult};
+use super::{Component, ComponentError, ComponentResult, PinAccess};
 
 const PIN_RESULT_DEFAULT: &str = "R";
 const PIN_VALUE: &str = "V";
@@ -195,6 +195,13 @@ impl Component for ComponentKind {
             Self::Expression(component) => component.evaluate(inputs, meta),
         }
     }
+
+    fn input_access(&self) -> &'static [PinAccess] {
+        match self {
+            // De formule en alle variabelen zijn items.
+            Self::Expression(_) => &[PinAccess::Item],
+        }
+    }
 }
 
 impl ComponentKind {
@@ -344,13 +351,19 @@ fn coerce_expression(value: &Value) -> Option<String> {
         Value::Number(number) => Some(number.to_string()),
         Value::Boolean(boolean) => Some(boolean.to_string()),
         Value::List(values) => values.iter().find_map(coerce_expression),
+        Value::Tree(tree) => tree.items().find_map(coerce_expression),
         Value::Null
         | Value::Matrix(_)
+        | Value::Transform(_)
+        | Value::Field(_)
         | Value::Domain(_)
         | Value::Point(_)
         | Value::Vector(_)
         | Value::CurveLine { .. }
+        | Value::Curve(_)
+        | Value::Plane(_)
         | Value::Surface { .. }
+        | Value::Brep(_)
         | Value::Mesh { .. }
         | Value::DateTime(_)
         | Value::Complex(_)
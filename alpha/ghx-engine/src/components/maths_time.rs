@@ -9,7 +9,7 @@ use time::{Date, Duration, Month, PrimitiveDateTime, Time};
 use crate::graph::node::MetaMap;
 use crate::graph::value::{DateTimeValue, Value};
 
-use super::{Component, ComponentError, ComponentResult};
+use super::{Component, ComponentError, ComponentResult, PinAccess};
 
 const PIN_YEAR: &str = "Y";
 const PIN_MONTH: &str = "M";
@@ -104,6 +104,19 @@ impl Component for ComponentKind {
             Self::ConstructSmoothTime => evaluate_construct_smooth_time(inputs),
         }
     }
+
+    fn input_access(&self) -> &'static [PinAccess] {
+        match self {
+            Self::ConstructDate
+            | Self::CombineDateTime
+            | Self::DateRange
+            | Self::InterpolateDate
+            | Self::ConstructTime
+            | Self::DeconstructDate
+            | Self::ConstructExoticDate
+            | Self::ConstructSmoothTime => &[PinAccess::Item],
+        }
+    }
 }
 
 impl ComponentKind {
@@ -5,7 +5,7 @@ use std::collections::BTreeMap;
 use crate::graph::node::MetaMap;
 use crate::graph::value::Value;
 
-use super::{Component, ComponentError, ComponentResult, coerce};
+use super::{Component, ComponentError, ComponentResult, PinAccess, coerce};
 
 const EPSILON: f64 = 1e-9;
 
@@ -175,6 +175,29 @@ impl Component for ComponentKind {
             Self::Incentre => evaluate_incentre(inputs),
         }
     }
+
+    fn input_access(&self) -> &'static [PinAccess] {
+        match self {
+            Self::Degrees
+            | Self::Radians
+            | Self::Sine
+            | Self::Cosine
+            | Self::Tangent
+            | Self::Cotangent
+            | Self::Secant
+            | Self::Cosecant
+            | Self::ArcSine
+            | Self::ArcCosine
+            | Self::ArcTangent
+            | Self::Sinc
+            | Self::TriangleTrigonometry
+            | Self::RightTrigonometry
+            | Self::Circumcentre
+            | Self::Orthocentre
+            | Self::Centroid
+            | Self::Incentre => &[PinAccess::Item],
+        }
+    }
 }
 
 impl ComponentKind {
@@ -5,7 +5,7 @@ use std::collections::BTreeMap;
 use crate::graph::node::MetaMap;
 use crate::graph::value::{ComplexValue, Value};
 
-use super::{Component, ComponentError, ComponentResult, coerce};
+use super::{Component, ComponentError, ComponentResult, PinAccess, coerce};
 
 const PIN_RESULT: &str = "R";
 const PIN_OUTPUT_Y: &str = "y";
@@ -183,6 +183,28 @@ impl Component for ComponentKind {
             Self::InterpolateData => evaluate_interpolate_data(inputs),
         }
     }
+
+    fn input_access(&self) -> &'static [PinAccess] {
+        match self {
+            Self::Maximum
+            | Self::Pi
+            | Self::ComplexComponents
+            | Self::Extremes
+            | Self::Minimum
+            | Self::CreateComplex
+            | Self::ComplexConjugate
+            | Self::ComplexModulus
+            | Self::Round
+            | Self::NaturalNumber
+            | Self::ComplexArgument
+            | Self::GoldenRatio
+            | Self::Epsilon => &[PinAccess::Item],
+            Self::BlurNumbers | Self::Truncate | Self::InterpolateData => {
+                &[PinAccess::List, PinAccess::Item]
+            }
+            _ => &[],
+        }
+    }
 }
 
 impl ComponentKind {
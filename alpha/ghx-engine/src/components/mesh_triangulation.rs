@@ -164,6 +164,24 @@ impl ComponentKind {
         }
     }
 
+    /// Returns `true` for kinds backed by `placeholder_component!`.
+    pub fn is_placeholder(self) -> bool {
+        matches!(
+            self,
+            Self::QuadRemesh
+                | Self::Substrate
+                | Self::VoronoiCell
+                | Self::QuadTree
+                | Self::TriRemesh
+                | Self::VoronoiGroups
+                | Self::OcTree
+                | Self::Voronoi3D
+                | Self::MetaBallTCustom
+                | Self::MetaBallT
+                | Self::MetaBall
+        )
+    }
+
     pub fn name(self) -> &'static str {
         match self {
             Self::DelaunayMesh => "Delaunay Mesh",
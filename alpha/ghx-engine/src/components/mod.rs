@@ -3,6 +3,8 @@
 use std::collections::HashMap;
 use std::fmt;
 
+use serde::Serialize;
+
 use crate::graph::node::MetaMap;
 use crate::graph::value::Value;
 
@@ -15,6 +17,11 @@ pub mod curve_sampler;
 pub mod curve_spline;
 pub mod curve_util;
 pub mod display_preview;
+mod intersect_common;
+pub mod intersect_mathematical;
+pub mod intersect_physical;
+pub mod intersect_region;
+pub mod intersect_shape;
 pub mod maths_domain;
 pub mod maths_matrix;
 pub mod maths_operators;
@@ -30,7 +37,9 @@ pub mod params_geometry;
 pub mod params_input;
 pub mod params_primitive;
 pub mod params_util;
+mod pin_table;
 pub mod scalar;
+pub mod schema;
 pub mod sets_list;
 pub mod sets_sequence;
 pub mod sets_sets;
@@ -92,9 +101,66 @@ impl std::error::Error for ComponentError {}
 /// Resultaat van een component-executie.
 pub type ComponentResult = Result<OutputMap, ComponentError>;
 
+/// Uitvoer van een component samen met de waarschuwingen die het meldt. De
+/// evaluator meldt elke waarschuwing als `EvaluationWarning::Component`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ComponentOutput {
+    pub outputs: OutputMap,
+    pub warnings: Vec<String>,
+}
+
+impl ComponentOutput {
+    /// Voegt een waarschuwing toe.
+    pub fn push_warning(&mut self, message: impl Into<String>) {
+        self.warnings.push(message.into());
+    }
+}
+
+impl From<OutputMap> for ComponentOutput {
+    fn from(outputs: OutputMap) -> Self {
+        Self {
+            outputs,
+            warnings: Vec::new(),
+        }
+    }
+}
+
+/// Toegangsniveau van een invoerpin, gelijk aan Grasshopper's item/list/tree
+/// access.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PinAccess {
+    /// Het component ziet telkens één item; de evaluator doet longest-list
+    /// matching over de invoerlijsten.
+    Item,
+    /// Het component krijgt de volledige lijst van een tak.
+    List,
+    /// Het component krijgt de volledige datatree.
+    Tree,
+}
+
 /// Trait die alle componentimplementaties dienen te implementeren.
 pub trait Component {
     fn evaluate(&self, inputs: &[Value], meta: &MetaMap) -> ComponentResult;
+
+    /// Als `evaluate`, maar met de waarschuwingen van het component.
+    /// Componenten die waarschuwen implementeren deze methode en laten
+    /// `evaluate` alleen de uitvoer teruggeven.
+    fn evaluate_with_warnings(
+        &self,
+        inputs: &[Value],
+        meta: &MetaMap,
+    ) -> Result<ComponentOutput, ComponentError> {
+        self.evaluate(inputs, meta).map(ComponentOutput::from)
+    }
+
+    /// Toegangsniveau per invoerpin, in pinvolgorde. Extra pinnen van
+    /// componenten met een variabel aantal invoeren krijgen het laatste
+    /// niveau. Een lege slice betekent dat het component zelf lijsten
+    /// verwerkt en ongewijzigde invoer ontvangt.
+    fn input_access(&self) -> &'static [PinAccess] {
+        &[]
+    }
 }
 
 /// Beschikbare componenttypen binnen de registry.
@@ -142,6 +208,10 @@ pub enum ComponentKind {
     ParamsPrimitive(params_primitive::ComponentKind),
     ParamsInput(params_input::ComponentKind),
     ParamsUtil(params_util::ComponentKind),
+    IntersectMathematical(intersect_mathematical::ComponentKind),
+    IntersectPhysical(intersect_physical::ComponentKind),
+    IntersectShape(intersect_shape::ComponentKind),
+    IntersectRegion(intersect_region::ComponentKind),
 }
 
 impl ComponentKind {
@@ -194,6 +264,23 @@ impl ComponentKind {
             Self::ParamsPrimitive(component) => component.evaluate(inputs, meta),
             Self::ParamsInput(component) => component.evaluate(inputs, meta),
             Self::ParamsUtil(component) => component.evaluate(inputs, meta),
+            Self::IntersectMathematical(component) => component.evaluate(inputs, meta),
+            Self::IntersectPhysical(component) => component.evaluate(inputs, meta),
+            Self::IntersectShape(component) => component.evaluate(inputs, meta),
+            Self::IntersectRegion(component) => component.evaluate(inputs, meta),
+        }
+    }
+
+    /// De uitvoer van het component samen met zijn waarschuwingen.
+    pub fn evaluate_with_warnings(
+        &self,
+        inputs: &[Value],
+        meta: &MetaMap,
+    ) -> Result<ComponentOutput, ComponentError> {
+        match self {
+            Self::ParamsInput(component) => component.evaluate_with_warnings(inputs, meta),
+            Self::IntersectShape(component) => component.evaluate_with_warnings(inputs, meta),
+            _ => self.evaluate(inputs, meta).map(ComponentOutput::from),
         }
     }
 
@@ -242,16 +329,143 @@ impl ComponentKind {
             Self::ParamsPrimitive(component) => component.name(),
             Self::ParamsInput(component) => component.name(),
             Self::ParamsUtil(component) => component.name(),
+            Self::IntersectMathematical(component) => component.name(),
+            Self::IntersectPhysical(component) => component.name(),
+            Self::IntersectShape(component) => component.name(),
+            Self::IntersectRegion(component) => component.name(),
+        }
+    }
+
+    /// Geeft aan of het component alleen als placeholder geregistreerd is en
+    /// bij evaluatie altijd [`ComponentError::NotYetImplemented`] oplevert.
+    #[must_use]
+    pub fn is_placeholder(&self) -> bool {
+        match self {
+            Self::CurvePrimitive(component) => component.is_placeholder(),
+            Self::MeshTriangulation(component) => component.is_placeholder(),
+            Self::ParamsGeometry(component) => component.is_placeholder(),
+            Self::ParamsPrimitive(component) => component.is_placeholder(),
+            Self::ParamsInput(component) => component.is_placeholder(),
+            _ => false,
+        }
+    }
+
+    /// Geeft aan of het component zelf met `Value::Tree` invoer werkt. Voor alle
+    /// andere componenten verdeelt de evaluator datatrees per tak.
+    #[must_use]
+    pub fn handles_data_trees(&self) -> bool {
+        match self {
+            Self::SetsTree(component) => component.handles_data_trees(),
+            _ => false,
+        }
+    }
+
+    /// Geeft aan of het component exacte `Value::Curve` invoer begrijpt. Alle
+    /// andere componenten krijgen curves als puntenlijst aangeleverd, zoals
+    /// voordat curves als eigen waardetype bestonden.
+    #[must_use]
+    pub fn handles_curves(&self) -> bool {
+        match self {
+            Self::CurveAnalysis(component) => component.handles_curves(),
+            Self::CurveDivision(component) => component.handles_curves(),
+            Self::CurveUtil(component) => component.handles_curves(),
+            Self::TransformAffine(component) => component.handles_curves(),
+            Self::TransformArray(component) => component.handles_curves(),
+            Self::TransformUtil(component) => component.handles_curves(),
+            Self::TransformEuclidean(_)
+            | Self::SetsList(_)
+            | Self::SetsTree(_)
+            | Self::DisplayPreview(_)
+            | Self::ParamsGeometry(_)
+            | Self::ParamsUtil(_)
+            | Self::IntersectMathematical(_)
+            | Self::IntersectPhysical(_)
+            | Self::IntersectShape(_)
+            | Self::IntersectRegion(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Geeft aan of het component analytische `Value::Brep` invoer begrijpt.
+    /// Voor alle andere componenten mesht de evaluator breps vooraf naar een
+    /// legacy `Value::Surface`.
+    #[must_use]
+    pub fn handles_breps(&self) -> bool {
+        match self {
+            Self::SurfaceAnalysis(component) => component.handles_breps(),
+            Self::SetsList(_)
+            | Self::SetsTree(_)
+            | Self::DisplayPreview(_)
+            | Self::ParamsGeometry(_)
+            | Self::ParamsUtil(_)
+            | Self::IntersectMathematical(_)
+            | Self::IntersectPhysical(_)
+            | Self::IntersectShape(_)
+            | Self::IntersectRegion(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Pintoegang zoals gedeclareerd door het onderliggende component; zie
+    /// [`Component::input_access`]. Modules die vlakken, polylijnen of
+    /// willekeurige geometrie nog als puntenlijst ontvangen, declareren niets
+    /// en itereren zelf over hun invoer: centrale matching zou die lijsten
+    /// per punt opsplitsen.
+    #[must_use]
+    pub fn input_access(&self) -> &'static [PinAccess] {
+        match self {
+            Self::VectorPoint(component) => component.input_access(),
+            Self::TransformUtil(component) => component.input_access(),
+            Self::MathsOperator(component) => component.input_access(),
+            Self::MathsDomain(component) => component.input_access(),
+            Self::MathsPolynomial(component) => component.input_access(),
+            Self::MathsMatrix(component) => component.input_access(),
+            Self::MathsScript(component) => component.input_access(),
+            Self::MathsTime(component) => component.input_access(),
+            Self::MathsTrig(component) => component.input_access(),
+            Self::MathsUtil(component) => component.input_access(),
+            Self::Scalar(component) => component.input_access(),
+            Self::VectorVector(component) => component.input_access(),
+            Self::Complex(component) => component.input_access(),
+            Self::SetsSequence(component) => component.input_access(),
+            Self::SetsText(component) => component.input_access(),
+            Self::IntersectMathematical(component) => component.input_access(),
+            Self::IntersectPhysical(component) => component.input_access(),
+            Self::IntersectShape(component) => component.input_access(),
+            Self::IntersectRegion(component) => component.input_access(),
+            _ => &[],
         }
     }
 
     #[must_use]
     pub fn optional_input_pins(&self) -> &'static [&'static str] {
         match self {
+            Self::CurvePrimitive(component) => component.optional_input_pins(),
+            Self::CurveUtil(component) => component.optional_input_pins(),
+            Self::CurveAnalysis(component) => component.optional_input_pins(),
             Self::SurfaceUtil(component) => component.optional_input_pins(),
             _ => &[],
         }
     }
+
+    /// Defaults voor optionele inputs, per pinnickname.
+    #[must_use]
+    pub fn input_defaults(&self) -> &'static [(&'static str, schema::PinDefault)] {
+        match self {
+            Self::CurvePrimitive(component) => component.input_defaults(),
+            Self::CurveDivision(component) => component.input_defaults(),
+            Self::CurveAnalysis(component) => component.input_defaults(),
+            Self::CurveSpline(component) => component.input_defaults(),
+            Self::CurveUtil(component) => component.input_defaults(),
+            Self::SurfacePrimitive(component) => component.input_defaults(),
+            Self::SurfaceUtil(component) => component.input_defaults(),
+            Self::MathsDomain(component) => component.input_defaults(),
+            Self::VectorPoint(component) => component.input_defaults(),
+            Self::VectorVector(component) => component.input_defaults(),
+            Self::VectorPlane(component) => component.input_defaults(),
+            _ => &[],
+        }
+    }
 }
 
 /// Registry die componentimplementaties opzoekt op GUID of naam.
@@ -607,6 +821,38 @@ impl Default for ComponentRegistry {
             registry.register_names(registration.names, kind);
         }
 
+        for registration in intersect_mathematical::REGISTRATIONS {
+            let kind = ComponentKind::IntersectMathematical(registration.kind);
+            for guid in registration.guids {
+                registry.register_guid(guid, kind);
+            }
+            registry.register_names(registration.names, kind);
+        }
+
+        for registration in intersect_physical::REGISTRATIONS {
+            let kind = ComponentKind::IntersectPhysical(registration.kind);
+            for guid in registration.guids {
+                registry.register_guid(guid, kind);
+            }
+            registry.register_names(registration.names, kind);
+        }
+
+        for registration in intersect_shape::REGISTRATIONS {
+            let kind = ComponentKind::IntersectShape(registration.kind);
+            for guid in registration.guids {
+                registry.register_guid(guid, kind);
+            }
+            registry.register_names(registration.names, kind);
+        }
+
+        for registration in intersect_region::REGISTRATIONS {
+            let kind = ComponentKind::IntersectRegion(registration.kind);
+            for guid in registration.guids {
+                registry.register_guid(guid, kind);
+            }
+            registry.register_names(registration.names, kind);
+        }
+
         registry
     }
 }
@@ -659,6 +905,51 @@ impl ComponentRegistry {
 
         None
     }
+
+    /// Alle geregistreerde GUIDs (kleine letters, zonder accolades) met het
+    /// component waarnaar ze verwijzen.
+    pub fn guids(&self) -> impl Iterator<Item = (&str, ComponentKind)> {
+        self.by_guid
+            .iter()
+            .map(|(guid, kind)| (guid.as_str(), *kind))
+    }
+
+    /// Een GUID waarmee `kind` geregistreerd is. Bij meerdere GUIDs krijgt
+    /// de GUID met een pinschema voorrang, daarna de kleinste.
+    #[must_use]
+    pub fn guid_for(&self, kind: ComponentKind) -> Option<&str> {
+        // `ComponentKind` implementeert geen `PartialEq`; de Debug-weergave is
+        // per variant uniek.
+        let wanted = format!("{kind:?}");
+        self.by_guid
+            .iter()
+            .filter(|(_, candidate)| format!("{candidate:?}") == wanted)
+            .map(|(guid, _)| guid.as_str())
+            .min_by_key(|guid| (schema::record(guid).is_none(), *guid))
+    }
+
+    /// Het pinschema van het component met deze GUID, als de GUID
+    /// geregistreerd is en in de nodelist voorkomt.
+    #[must_use]
+    pub fn schema(&self, guid: &str) -> Option<schema::ComponentSchema> {
+        let guid = normalize_guid(guid);
+        let kind = self.by_guid.get(&guid)?;
+        schema::record(&guid).map(|record| schema::build(record, *kind))
+    }
+
+    /// De pinschema's van alle geregistreerde GUIDs, gesorteerd op GUID.
+    #[must_use]
+    pub fn schemas(&self) -> Vec<schema::ComponentSchema> {
+        let mut schemas: Vec<_> = self
+            .by_guid
+            .iter()
+            .filter_map(|(guid, kind)| {
+                schema::record(guid).map(|record| schema::build(record, *kind))
+            })
+            .collect();
+        schemas.sort_by(|a, b| a.guid.cmp(b.guid));
+        schemas
+    }
 }
 
 fn normalize_guid(guid: &str) -> String {
@@ -671,7 +962,11 @@ fn normalize_name(name: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::{ComponentKind, ComponentRegistry, maths_operators, params_input, surface_freeform};
+    use super::{
+        ComponentError, ComponentKind, ComponentRegistry, maths_operators, params_input,
+        surface_freeform,
+    };
+    use crate::graph::node::MetaMap;
 
     #[test]
     fn lookup_by_guid_and_name() {
@@ -697,4 +992,34 @@ mod tests {
             ComponentKind::SurfaceFreeform(surface_freeform::ComponentKind::Extrude)
         ));
     }
+
+    #[test]
+    fn placeholder_flag_matches_not_yet_implemented() {
+        let registry = ComponentRegistry::default();
+        let meta = MetaMap::new();
+
+        for kind in registry.by_guid.values() {
+            if !matches!(
+                kind,
+                ComponentKind::CurvePrimitive(_)
+                    | ComponentKind::MeshTriangulation(_)
+                    | ComponentKind::ParamsGeometry(_)
+                    | ComponentKind::ParamsPrimitive(_)
+                    | ComponentKind::ParamsInput(_)
+            ) {
+                continue;
+            }
+
+            let not_implemented = matches!(
+                kind.evaluate(&[], &meta),
+                Err(ComponentError::NotYetImplemented(_))
+            );
+            assert_eq!(
+                kind.is_placeholder(),
+                not_implemented,
+                "placeholder flag of {}",
+                kind.name()
+            );
+        }
+    }
 }
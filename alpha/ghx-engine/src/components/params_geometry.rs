@@ -5,7 +5,7 @@ use std::collections::BTreeMap;
 
 use crate::components::{Component, ComponentError, ComponentResult};
 use crate::graph::node::MetaMap;
-use crate::graph::value::{Value, ValueKind};
+use crate::graph::value::{BrepValue, Value, ValueKind};
 
 const SURFACE_EPSILON: f64 = 1e-9;
 const SURFACE_EPSILON_SQUARED: f64 = SURFACE_EPSILON * SURFACE_EPSILON;
@@ -72,14 +72,14 @@ impl ComponentKind {
             Self::Curve => CurveComponent.evaluate(inputs, meta),
             Self::MeshFace => MeshFaceComponent.evaluate(inputs, meta),
             Self::Plane => PlaneComponent.evaluate(inputs, meta),
+            Self::Brep => BrepComponent.evaluate(inputs, meta),
+            Self::Transform => TransformComponent.evaluate(inputs, meta),
+            Self::Field => FieldComponent.evaluate(inputs, meta),
             // Placeholders
             Self::CircularArc => Err(ComponentError::NotYetImplemented(self.name().to_string())),
-            Self::Transform => Err(ComponentError::NotYetImplemented(self.name().to_string())),
-            Self::Field => Err(ComponentError::NotYetImplemented(self.name().to_string())),
             Self::TwistedBox => Err(ComponentError::NotYetImplemented(self.name().to_string())),
             Self::Location => Err(ComponentError::NotYetImplemented(self.name().to_string())),
             Self::SubD => Err(ComponentError::NotYetImplemented(self.name().to_string())),
-            Self::Brep => Err(ComponentError::NotYetImplemented(self.name().to_string())),
             Self::Atom => Err(ComponentError::NotYetImplemented(self.name().to_string())),
             Self::Rectangle => Err(ComponentError::NotYetImplemented(self.name().to_string())),
             Self::Geometry => Err(ComponentError::NotYetImplemented(self.name().to_string())),
@@ -95,6 +95,28 @@ impl ComponentKind {
         }
     }
 
+    /// Returns `true` for kinds that are registered but always return
+    /// [`ComponentError::NotYetImplemented`].
+    pub fn is_placeholder(self) -> bool {
+        matches!(
+            self,
+            Self::CircularArc
+                | Self::TwistedBox
+                | Self::Location
+                | Self::SubD
+                | Self::Atom
+                | Self::Rectangle
+                | Self::Geometry
+                | Self::Group
+                | Self::GeometryPipeline
+                | Self::MesherSettings
+                | Self::Box
+                | Self::Circle
+                | Self::GeometryCache
+                | Self::MeshPoint
+        )
+    }
+
     pub fn name(self) -> &'static str {
         match self {
             Self::Point => "Point",
@@ -132,6 +154,7 @@ macro_rules! define_param_component {
         $struct_name:ident,
         $output_pin:expr,
         $expected_kind:path
+        $(, $extra_kind:path)*
     ) => {
         #[derive(Debug, Default, Clone, Copy)]
         struct $struct_name;
@@ -146,11 +169,13 @@ macro_rules! define_param_component {
 
                 let input_value = &inputs[0];
 
+                let accepts = |value: &Value| {
+                    let kind = value.kind();
+                    kind == $expected_kind $(|| kind == $extra_kind)* || matches!(value, Value::Null)
+                };
                 let is_valid = match input_value {
-                    Value::List(items) => items
-                        .iter()
-                        .all(|item| item.kind() == $expected_kind || matches!(item, Value::Null)),
-                    value => value.kind() == $expected_kind || matches!(value, Value::Null),
+                    Value::List(items) => items.iter().all(accepts),
+                    value => accepts(value),
                 };
 
                 if !is_valid {
@@ -241,6 +266,7 @@ impl Component for SurfaceComponent {
 ///
 /// - `Value::Mesh` → passed through unchanged
 /// - `Value::Surface` → faces are triangulated using fan triangulation (preserves all geometry)
+/// - `Value::Brep` → meshed with the default display tessellation
 /// - `Value::List` → each element is recursively converted
 /// - `Value::Null` → passed through unchanged
 fn convert_to_mesh_value(value: &Value) -> Result<Value, ComponentError> {
@@ -249,6 +275,7 @@ fn convert_to_mesh_value(value: &Value) -> Result<Value, ComponentError> {
     match value {
         Value::Null => Ok(Value::Null),
         Value::Mesh { .. } => Ok(value.clone()),
+        Value::Brep(brep) => Ok(brep.to_mesh_value(BrepValue::DEFAULT_TESSELLATION)),
         Value::Surface { vertices, faces } => {
             // Convert polygon faces to triangles using fan triangulation.
             // This properly handles quads and n-gons by producing (n-2) triangles
@@ -296,7 +323,7 @@ fn convert_to_mesh_value(value: &Value) -> Result<Value, ComponentError> {
 ///
 /// # Conversion Rules
 ///
-/// - `Value::Surface` → passed through unchanged
+/// - `Value::Surface` and `Value::Brep` → passed through unchanged
 /// - `Value::Mesh` → indices converted to face lists (triangles become 3-element face lists)
 /// - `Value::List` → each element is recursively converted
 /// - `Value::Null` → passed through unchanged
@@ -308,7 +335,7 @@ fn convert_to_mesh_value(value: &Value) -> Result<Value, ComponentError> {
 fn convert_to_surface_value(value: &Value) -> Result<Value, ComponentError> {
     match value {
         Value::Null => Ok(Value::Null),
-        Value::Surface { .. } => Ok(value.clone()),
+        Value::Surface { .. } | Value::Brep(_) => Ok(value.clone()),
         Value::Mesh { vertices, indices, .. } => {
             // Convert triangle indices to face lists
             let faces: Vec<Vec<u32>> = indices
@@ -355,7 +382,7 @@ fn convert_list_value_to_surface(value: &Value) -> Result<Value, ComponentError>
     let mut converted = Vec::with_capacity(entries.len());
     for entry in entries {
         let converted_entry = match entry {
-            Value::Surface { .. } => entry.clone(),
+            Value::Surface { .. } | Value::Brep(_) => entry.clone(),
             Value::Mesh { vertices, indices, .. } => {
                 // Convert Mesh to Surface for backward compatibility
                 let faces: Vec<Vec<u32>> = indices
@@ -387,12 +414,12 @@ fn convert_list_value_to_surface(value: &Value) -> Result<Value, ComponentError>
 // List Content Validation Helpers
 // ============================================================================
 
-/// Checks if a list contains only geometry-like values (Mesh or Surface) or nulls.
+/// Checks if a list contains only geometry-like values (Mesh, Surface or Brep) or nulls.
 ///
 /// This supports both the new `Value::Mesh` type and the legacy `Value::Surface` type
 /// for backward compatibility.
 fn list_contains_only_geometry_like_or_null(value: &Value) -> bool {
-    matches!(value, Value::List(items) if items.iter().all(|item| matches!(item, Value::Surface { .. } | Value::Mesh { .. } | Value::Null)))
+    matches!(value, Value::List(items) if items.iter().all(|item| matches!(item, Value::Surface { .. } | Value::Brep(_) | Value::Mesh { .. } | Value::Null)))
 }
 
 /// Legacy alias for backward compatibility - checks for surfaces or null only.
@@ -643,13 +670,14 @@ fn normalize(v: [f64; 3]) -> [f64; 3] {
     }
 }
 
-define_param_component!(CurveComponent, "Crv", ValueKind::CurveLine);
+define_param_component!(CurveComponent, "Crv", ValueKind::CurveLine, ValueKind::Curve);
 define_param_component!(MeshFaceComponent, "Face", ValueKind::Text);
+define_param_component!(BrepComponent, "Brep", ValueKind::Brep, ValueKind::Surface, ValueKind::Mesh);
+define_param_component!(TransformComponent, "Transform", ValueKind::Transform);
+define_param_component!(FieldComponent, "Field", ValueKind::Field);
 
 // --- Placeholder Components ---
 // define_param_component!(CircularArcComponent, "Arc", ValueKind::CircularArc);
-// define_param_component!(TransformComponent, "Transform", ValueKind::Transform);
-// define_param_component!(FieldComponent, "Field", ValueKind::Field);
 // define_param_component!(TwistedBoxComponent, "TBox", ValueKind::TwistedBox);
 
 #[derive(Debug, Default, Clone, Copy)]
@@ -672,7 +700,6 @@ impl Component for PlaneComponent {
 }
 // define_param_component!(LocationComponent, "Loc", ValueKind::Location);
 // define_param_component!(SubDComponent, "SubD", ValueKind::SubD);
-// define_param_component!(BrepComponent, "Brep", ValueKind::Brep);
 // define_param_component!(AtomComponent, "Atom", ValueKind::Atom);
 // define_param_component!(RectangleComponent, "Rec", ValueKind::Rectangle);
 // define_param_component!(GeometryComponent, "Geo", ValueKind::Geometry);
@@ -1185,4 +1212,27 @@ mod tests {
         
         assert!(result.is_err(), "SurfaceComponent should reject Value::Number");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn field_component_passes_fields_through() {
+        use crate::graph::value::{FieldSource, FieldValue};
+
+        let meta = MetaMap::new();
+        let field = Value::Field(FieldValue::from_source(
+            FieldSource::PointCharge {
+                point: [0.0, 0.0, 0.0],
+                charge: 1.0,
+                decay: 2.0,
+            },
+            None,
+        ));
+        let outputs = ComponentKind::Field.evaluate(&[field.clone()], &meta).unwrap();
+        assert_eq!(outputs.get("Field"), Some(&field));
+
+        assert!(
+            ComponentKind::Field
+                .evaluate(&[Value::Number(1.0)], &meta)
+                .is_err()
+        );
+    }
+}
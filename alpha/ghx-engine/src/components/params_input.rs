@@ -1,6 +1,6 @@
 //! Grasshopper Input Parameter Components
 
-use super::{Component, ComponentError, ComponentResult, coerce};
+use super::{Component, ComponentError, ComponentOutput, ComponentResult, coerce};
 use crate::graph::node::{MetaLookupExt, MetaMap, MetaValue};
 use crate::graph::value::Value;
 use std::collections::BTreeMap;
@@ -92,6 +92,39 @@ impl ComponentKind {
         }
     }
 
+    /// Like [`evaluate`](Self::evaluate), together with the warnings of the
+    /// component.
+    pub fn evaluate_with_warnings(
+        self,
+        inputs: &[Value],
+        meta: &MetaMap,
+    ) -> Result<ComponentOutput, ComponentError> {
+        match self {
+            Self::GraphMapper => GraphMapperComponent.evaluate_with_warnings(inputs, meta),
+            _ => self.evaluate(inputs, meta).map(ComponentOutput::from),
+        }
+    }
+
+    /// Returns `true` for the file/import components that still return
+    /// [`ComponentError::NotYetImplemented`].
+    pub fn is_placeholder(self) -> bool {
+        matches!(
+            self,
+            Self::ImageResource
+                | Self::Import3DM
+                | Self::ImportPDB
+                | Self::ReadFile
+                | Self::AtomData
+                | Self::ImportSHP
+                | Self::ImportCoordinates
+                | Self::ImportImage
+                | Self::ObjectDetails
+                | Self::ImageSampler
+                | Self::FlagFields
+                | Self::RobotsLibrary
+        )
+    }
+
     pub fn name(self) -> &'static str {
         match self {
             Self::ValueList => "Value List",
@@ -520,8 +553,107 @@ impl Component for GradientComponent {
     }
 }
 define_placeholder_component!(CalendarComponent, "Output");
-define_placeholder_component!(GraphMapperComponent, "Output");
 define_placeholder_component!(ControlKnobComponent, "Output");
+
+/// Maps numbers through a piecewise linear graph stored in the `GraphPoints`
+/// meta. Without points the graph is unknown: the component then warns and
+/// maps through the identity line from (0, 0) to (1, 1).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GraphMapperComponent;
+
+/// The graph a Graph Mapper uses when its file carries no points.
+pub const IDENTITY_GRAPH: [[f64; 2]; 2] = [[0.0, 0.0], [1.0, 1.0]];
+
+impl Component for GraphMapperComponent {
+    fn evaluate(&self, inputs: &[Value], meta: &MetaMap) -> ComponentResult {
+        self.evaluate_with_warnings(inputs, meta)
+            .map(|output| output.outputs)
+    }
+
+    fn evaluate_with_warnings(
+        &self,
+        inputs: &[Value],
+        meta: &MetaMap,
+    ) -> Result<ComponentOutput, ComponentError> {
+        let mut output = ComponentOutput::default();
+        let points = graph_mapper_points(meta).unwrap_or_else(|| {
+            output.push_warning(
+                "Graph Mapper heeft geen grafiekpunten; de identiteitslijn wordt gebruikt",
+            );
+            IDENTITY_GRAPH.to_vec()
+        });
+        let value = match inputs.first() {
+            Some(value) => map_through_graph(value, &points)?,
+            None => Value::Null,
+        };
+
+        output.outputs.insert("Output".to_string(), value);
+        Ok(output)
+    }
+}
+
+/// Reads the graph of a Graph Mapper as `[x, y]` pairs sorted by x, or `None`
+/// when the meta holds fewer than two points.
+pub fn graph_mapper_points(meta: &MetaMap) -> Option<Vec<[f64; 2]>> {
+    let mut points: Vec<[f64; 2]> = match meta.get_normalized("GraphPoints") {
+        Some(MetaValue::List(entries)) => entries
+            .iter()
+            .filter_map(|entry| match entry.as_value()? {
+                Value::List(pair) => match pair.as_slice() {
+                    [Value::Number(x), Value::Number(y)] => Some([*x, *y]),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    if points.len() < 2 {
+        return None;
+    }
+    points.sort_by(|a, b| a[0].total_cmp(&b[0]));
+    Some(points)
+}
+
+fn map_through_graph(value: &Value, points: &[[f64; 2]]) -> Result<Value, ComponentError> {
+    match value {
+        Value::Null => Ok(Value::Null),
+        Value::List(items) => items
+            .iter()
+            .map(|item| map_through_graph(item, points))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::List),
+        other => {
+            let x = coerce::coerce_number(other, None)
+                .map_err(|_| ComponentError::new("Graph Mapper verwacht getallen"))?;
+            Ok(Value::Number(interpolate_graph(points, x)))
+        }
+    }
+}
+
+fn interpolate_graph(points: &[[f64; 2]], x: f64) -> f64 {
+    let first = points[0];
+    let last = points[points.len() - 1];
+    if x <= first[0] {
+        return first[1];
+    }
+    if x >= last[0] {
+        return last[1];
+    }
+
+    for pair in points.windows(2) {
+        let [x0, y0] = pair[0];
+        let [x1, y1] = pair[1];
+        if x <= x1 {
+            if (x1 - x0).abs() < 1e-12 {
+                return y1;
+            }
+            return y0 + (x - x0) / (x1 - x0) * (y1 - y0);
+        }
+    }
+    last[1]
+}
 define_placeholder_component!(ClockComponent, "Output");
 define_placeholder_component!(ScribbleComponent, "Output");
 
@@ -701,3 +833,35 @@ pub const REGISTRATIONS: &[Registration<ComponentKind>] = &[
         &["Scribble"],
     ),
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::{Component, GraphMapperComponent};
+    use crate::graph::node::{MetaMap, MetaValue};
+    use crate::graph::value::Value;
+
+    #[test]
+    fn graph_mapper_maps_through_saved_points() {
+        let mut meta = MetaMap::new();
+        let points = [[0.0, 1.0], [1.0, 0.0]]
+            .into_iter()
+            .map(|[x, y]: [f64; 2]| MetaValue::List(vec![x.into(), y.into()]))
+            .collect();
+        meta.insert("GraphPoints".to_string(), MetaValue::List(points));
+
+        let output = GraphMapperComponent
+            .evaluate_with_warnings(&[Value::Number(0.25)], &meta)
+            .unwrap();
+        assert_eq!(output.outputs["Output"], Value::Number(0.75));
+        assert!(output.warnings.is_empty());
+    }
+
+    #[test]
+    fn graph_mapper_without_points_warns() {
+        let output = GraphMapperComponent
+            .evaluate_with_warnings(&[Value::Number(0.25)], &MetaMap::new())
+            .unwrap();
+        assert_eq!(output.outputs["Output"], Value::Number(0.25));
+        assert_eq!(output.warnings.len(), 1);
+    }
+}
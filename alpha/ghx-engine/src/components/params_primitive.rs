@@ -73,6 +73,19 @@ impl ComponentKind {
         }
     }
 
+    /// Returns `true` for the kinds listed under "Placeholders".
+    pub fn is_placeholder(self) -> bool {
+        matches!(
+            self,
+            Self::Shader
+                | Self::SymbolDisplay
+                | Self::Constant
+                | Self::Culture
+                | Self::Data
+                | Self::Receiver
+        )
+    }
+
     pub fn name(self) -> &'static str {
         match self {
             Self::Integer => "Integer",
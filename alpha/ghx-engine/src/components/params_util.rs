@@ -31,18 +31,23 @@ impl<T: Copy> Registration<T> {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ComponentKind {
     Relay,
+    ClusterInput,
+    ClusterOutput,
 }
 
 impl ComponentKind {
     pub fn evaluate(self, inputs: &[Value], meta: &MetaMap) -> ComponentResult {
         match self {
             Self::Relay => RelayComponent.evaluate(inputs, meta),
+            Self::ClusterInput | Self::ClusterOutput => ClusterHookComponent.evaluate(inputs, meta),
         }
     }
 
     pub fn name(self) -> &'static str {
         match self {
             Self::Relay => "Relay",
+            Self::ClusterInput => "Cluster Input",
+            Self::ClusterOutput => "Cluster Output",
         }
     }
 }
@@ -75,11 +80,37 @@ impl Component for RelayComponent {
     }
 }
 
+// --- Cluster Hook Component ---
+
+/// Cluster Input and Cluster Output hooks only survive in graphs whose
+/// clusters were inlined by the parser. They forward the value wired into
+/// their `Input` pin.
+#[derive(Debug, Default, Clone, Copy)]
+struct ClusterHookComponent;
+
+impl Component for ClusterHookComponent {
+    fn evaluate(&self, inputs: &[Value], _meta: &MetaMap) -> ComponentResult {
+        let value = inputs.first().cloned().unwrap_or(Value::Null);
+        Ok(BTreeMap::from([("Output".to_string(), value)]))
+    }
+}
+
 // --- Registrations ---
 pub const RELAY_GUID: &str = "b6236720-8d88-4289-93c3-ac4c99f9b97b";
 
-pub const REGISTRATIONS: &[Registration<ComponentKind>] = &[Registration::new(
-    ComponentKind::Relay,
-    &[RELAY_GUID],
-    &["Relay"],
-)];
\ No newline at end of file
+pub const CLUSTER_INPUT_GUID: &str = "448de216-3a12-43cf-a135-e3bfafc87744";
+pub const CLUSTER_OUTPUT_GUID: &str = "a4b285fe-2e13-4204-b65c-189aa6704da5";
+
+pub const REGISTRATIONS: &[Registration<ComponentKind>] = &[
+    Registration::new(ComponentKind::Relay, &[RELAY_GUID], &["Relay"]),
+    Registration::new(
+        ComponentKind::ClusterInput,
+        &[CLUSTER_INPUT_GUID],
+        &["Cluster Input"],
+    ),
+    Registration::new(
+        ComponentKind::ClusterOutput,
+        &[CLUSTER_OUTPUT_GUID],
+        &["Cluster Output"],
+    ),
+];
\ No newline at end of file
@@ -0,0 +1,7230 @@
+//! Pinnen per geregistreerde GUID, gegenereerd uit `nodelist/*.json`.
+//!
+//! Niet met de hand bewerken; opnieuw genereren met
+//! `cargo run --bin pin_table`.
+
+use super::PinAccess::{self, Item, List, Tree};
+use super::schema::{PinRecord, SchemaRecord};
+use crate::graph::value::ValueKind;
+
+const fn pin(nickname: &'static str, name: &'static str, access: PinAccess) -> PinRecord {
+    PinRecord {
+        nickname,
+        name,
+        kind: None,
+        access,
+        optional: false,
+    }
+}
+
+const fn opt(nickname: &'static str, name: &'static str, access: PinAccess) -> PinRecord {
+    PinRecord {
+        nickname,
+        name,
+        kind: None,
+        access,
+        optional: true,
+    }
+}
+
+pub(super) static SCHEMAS: &[SchemaRecord] = &[
+    SchemaRecord {
+        guid: "00027467-0d24-4fa7-b178-8dc0ac5f42ec",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "002b2feb-5d1b-41ea-913f-9f203c615792",
+        inputs: &[pin("x", "Input", Item)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "008e9a6f-478a-4813-8c8a-546273bc3a6b",
+        inputs: &[pin("L", "List", List), pin("P", "Cull Pattern", List)],
+        outputs: &[pin("L", "List", List)],
+    },
+    SchemaRecord {
+        guid: "00ec9ecd-4e1d-45ba-a8fc-dff716dbd9e4",
+        inputs: &[
+            pin("F", "Function", Item),
+            pin("x", "x", Item),
+            pin("y", "y", Item),
+        ],
+        outputs: &[pin("r", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "0148a65d-6f42-414a-9db7-9a9b2eb78437",
+        inputs: &[pin("B", "Brep", Item).of(ValueKind::Brep)],
+        outputs: &[
+            pin("En", "Naked", List),
+            pin("Ei", "Interior", List),
+            pin("Em", "Non-Manifold", List),
+        ],
+    },
+    SchemaRecord {
+        guid: "01cbd6e3-ccbe-4c24-baeb-46e10553e18b",
+        inputs: &[pin("A", "Start", Item), pin("B", "End", Item)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "01e3991d-18bd-474f-9fbd-076a8700159f",
+        inputs: &[
+            pin("M", "Mesh", Item).of(ValueKind::Mesh),
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("S", "Strict", Item).of(ValueKind::Boolean),
+        ],
+        outputs: &[pin("I", "Inside", Item)],
+    },
+    SchemaRecord {
+        guid: "0373008a-80ee-45be-887d-ab5a244afc29",
+        inputs: &[
+            pin("B", "Base", Item),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+            pin("L", "Length", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("C", "Cylinder", Item)],
+    },
+    SchemaRecord {
+        guid: "0381b555-bf9c-4d68-8e5c-10b2fcb16f30",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "03b801eb-87cd-476a-a591-257fe5d5bf0f",
+        inputs: &[
+            pin("P", "Pattern", List),
+            pin("0", "Stream 0", List),
+            pin("1", "Stream 1", List),
+        ],
+        outputs: &[pin("R", "Result", List)],
+    },
+    SchemaRecord {
+        guid: "03e331ed-c4d1-4a23-afa2-f57b87d2043c",
+        inputs: &[
+            pin("B", "Base", Item),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+            pin("L", "Length", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("C", "Cone", Item), pin("T", "Tip", Item)],
+    },
+    SchemaRecord {
+        guid: "03f22640-ff80-484e-bb53-a4025c5faa07",
+        inputs: &[
+            pin("B", "Brep", Item).of(ValueKind::Brep),
+            pin("C", "Cutters", List),
+        ],
+        outputs: &[pin("R", "Result", List)],
+    },
+    SchemaRecord {
+        guid: "040f195d-0b4e-4fe0-901f-fedb2fd3db15",
+        inputs: &[pin("A", "A", Item), pin("B", "B", Item)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "04887d01-504c-480e-b2a2-01ea19cc5922",
+        inputs: &[
+            pin("T", "Text", Item).of(ValueKind::Text),
+            pin("C", "Separators", Item),
+        ],
+        outputs: &[pin("R", "Result", List)],
+    },
+    SchemaRecord {
+        guid: "048b219e-284a-49f2-ae40-a60465b08447",
+        inputs: &[
+            pin("S", "SubD", Item),
+            pin("T", "Edge Tag", Item),
+            pin("E", "Edge IDs", List),
+        ],
+        outputs: &[pin("S", "SubD", Item)],
+    },
+    SchemaRecord {
+        guid: "04d3eace-deaa-475e-9e69-8f804d687998",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "059b72b0-9bb3-4542-a805-2dcd27493164",
+        inputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("C", "Colours", List).of(ValueKind::Color),
+            pin("S", "Size", Item).of(ValueKind::Number),
+        ],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "06953bda-1d37-4d58-9b38-4b3c74e54c8f",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "06b3086c-1e9d-41c2-bcfc-bb843156196e",
+        inputs: &[pin("T", "Tree", Tree)],
+        outputs: &[pin("T", "Tree", Tree)],
+    },
+    SchemaRecord {
+        guid: "06d7bc4a-ba3e-4445-8ab5-079613b52f28",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("D", "Direction", Item).of(ValueKind::Vector),
+        ],
+        outputs: &[
+            pin("G", "Geometry", Item),
+            pin("X", "Transform", Item).of(ValueKind::Transform),
+        ],
+    },
+    SchemaRecord {
+        guid: "071c3940-a12d-4b77-bb23-42b5d3314a0d",
+        inputs: &[
+            pin("N", "Remove Nulls", Item),
+            pin("X", "Remove Invalid", Item),
+            pin("E", "Remove Empty", Item),
+            pin("T", "Tree", Tree),
+        ],
+        outputs: &[pin("T", "Tree", Tree)],
+    },
+    SchemaRecord {
+        guid: "07a3b2a0-c4d0-4638-9044-39ac4681e782",
+        inputs: &[
+            pin("M", "Mesh", Item).of(ValueKind::Mesh),
+            pin("Min", "Minimum", Item).of(ValueKind::Number),
+            pin("Max", "Maximum", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("M", "Mesh", Item).of(ValueKind::Mesh),
+            pin("V", "Valid", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "07e0811f-034a-4504-bca0-2d03b2c46217",
+        inputs: &[
+            pin("T", "Text", Item).of(ValueKind::Text),
+            pin("i", "Start", Item),
+            pin("N", "Count", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("F", "Fragment", Item)],
+    },
+    SchemaRecord {
+        guid: "07efd5e1-d7f4-4205-ab99-83e68175564e",
+        inputs: &[
+            pin("F", "Function", Item),
+            pin("a", "a", Item),
+            pin("b", "b", Item),
+            pin("c", "c", Item),
+            pin("d", "d", Item),
+        ],
+        outputs: &[pin("r", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "082976f0-c741-4df8-a1d4-89891bf8619f",
+        inputs: &[
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+            pin("U", "U Count", Item).of(ValueKind::Number),
+            pin("V", "V Count", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("N", "Normals", List).of(ValueKind::Vector),
+            pin("uv", "Parameters", List).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "08619b6d-f9c4-4cb2-adcd-90959f08dc0d",
+        inputs: &[
+            pin("F", "Field", Item).of(ValueKind::Field),
+            pin("S", "Section", Item),
+            pin("N", "Samples", Item),
+        ],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "08d45f16-c708-4ede-8fd3-b70a0a7abd8f",
+        inputs: &[pin("M", "Mesh", Item).of(ValueKind::Mesh)],
+        outputs: &[pin("B", "Boundaries", List)],
+    },
+    SchemaRecord {
+        guid: "0991ac99-6a0b-47a9-b07d-dd510ca57f0f",
+        inputs: &[pin("C", "Curve", Item).of(ValueKind::Curve)],
+        outputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("t", "Params", List),
+        ],
+    },
+    SchemaRecord {
+        guid: "0a391eac-5048-443c-9c1b-f592299b6dd6",
+        inputs: &[
+            pin("B", "Base", Item),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+            pin("U", "U Count", Item).of(ValueKind::Number),
+            pin("V", "V Count", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("M", "Mesh", Item).of(ValueKind::Mesh)],
+    },
+    SchemaRecord {
+        guid: "0a80e903-e15b-4992-9675-19b2c488e853",
+        inputs: &[
+            pin("A", "Circle A", Item).of(ValueKind::Curve),
+            pin("B", "Circle B", Item).of(ValueKind::Curve),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("A", "Fit A", Item), pin("B", "Fit B", Item)],
+    },
+    SchemaRecord {
+        guid: "0ae07da9-951b-4b9b-98ca-d312c252374d",
+        inputs: &[
+            pin("N", "Numbers", List).of(ValueKind::Number),
+            pin("M", "Mask", Item),
+        ],
+        outputs: &[pin("P", "Points", List).of(ValueKind::Point)],
+    },
+    SchemaRecord {
+        guid: "0b04e8b9-00d7-47a7-95c3-0d51e654fe88",
+        inputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("C", "Curves", List).of(ValueKind::Curve),
+        ],
+        outputs: &[
+            pin("R", "Relationship", Item),
+            pin("I", "Index", Item).of(ValueKind::Number),
+            pin("P'", "Point", Item).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "0b0f1203-2ea8-4250-a45a-cca7ad2e5b76",
+        inputs: &[pin("x", "Input", Item)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "0b4ac802-fc4a-4201-9c66-0078b837c1eb",
+        inputs: &[pin("M", "Mesh", Item).of(ValueKind::Mesh)],
+        outputs: &[pin("B", "Boundaries", List)],
+    },
+    SchemaRecord {
+        guid: "0b5c7fad-0473-41aa-bf52-d7a861dcaa29",
+        inputs: &[
+            pin("D", "Domains", List),
+            pin("N", "Number", Item).of(ValueKind::Number),
+            opt("S", "Strict", Item).of(ValueKind::Boolean),
+        ],
+        outputs: &[
+            pin("I", "Index", Item).of(ValueKind::Number),
+            pin("N", "Neighbour", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "0b6c5dac-6c93-4158-b8d1-ca3187d45f25",
+        inputs: &[pin("0", "Stream 0", Tree), pin("1", "Stream 1", Tree)],
+        outputs: &[pin("S", "Stream", Tree)],
+    },
+    SchemaRecord {
+        guid: "0b7d1129-7b88-4322-aad3-56fd1036a8f6",
+        inputs: &[pin("F", "Function", Item), pin("x", "x", Item)],
+        outputs: &[pin("r", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "0bb3d234-9097-45db-9998-621639c87d3b",
+        inputs: &[
+            pin("C", "Content", List),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[pin("B", "Box", List), pin("B", "Box", List)],
+    },
+    SchemaRecord {
+        guid: "0bb7682f-333c-4bb7-b6fe-91ed2c886100",
+        inputs: &[pin("I", "Input", List)],
+        outputs: &[
+            pin("AM", "Arithmetic mean", Item),
+            pin("GM", "Geometric mean", Item),
+            pin("HM", "Harmonic mean", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "0bc93049-e1a7-44b5-8068-c7ddc85a9f46",
+        inputs: &[pin("x", "Input", Item)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "0c2f0932-5ddc-4ece-bd84-a3a059d3df7a",
+        inputs: &[
+            pin("Y", "Year", Item),
+            pin("M", "Month", Item),
+            pin("D", "Day", Item),
+            pin("h", "Hour", Item),
+            pin("m", "Minute", Item),
+            pin("s", "Second", Item),
+        ],
+        outputs: &[pin("D", "Date", Item)],
+    },
+    SchemaRecord {
+        guid: "0ca0a214-396c-44ea-b22f-d3a1757c32d6",
+        inputs: &[
+            opt("P", "Plane", Item).of(ValueKind::Plane),
+            pin("X", "X Size", Item),
+            pin("Y", "Y Size", Item),
+            opt("R", "Radius", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("R", "Rec", Item),
+            pin("L", "Length", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "0d1e2027-f153-460d-84c0-f9af431b08cb",
+        inputs: &[pin("A", "A", Item), pin("B", "B", Item)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "0d2ccfb3-9d41-4759-9452-da6a522c3eaa",
+        inputs: &[pin("N", "Factor", Item).of(ValueKind::Number)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "0d77c51e-584f-44e8-aed2-c2ddf4803888",
+        inputs: &[pin("R", "Radians", Item)],
+        outputs: &[pin("D", "Degrees", Item)],
+    },
+    SchemaRecord {
+        guid: "0e3173b6-91c6-4845-a748-e45d4fdbc262",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("L", "Line", Item).of(ValueKind::CurveLine),
+        ],
+        outputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("t", "Params", List),
+            pin("N", "Count", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "0e90b1f3-b870-4e09-8711-4bf819675d90",
+        inputs: &[pin("M", "Matrix", Item).of(ValueKind::Matrix)],
+        outputs: &[pin("M", "Matrix", Item).of(ValueKind::Matrix)],
+    },
+    SchemaRecord {
+        guid: "0e94542a-2e46-4793-9f98-2200b06b28f4",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("N", "Count", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("F", "Frames", List).of(ValueKind::Plane),
+            pin("t", "Parameters", List).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "0efd7f0c-f63d-446d-970e-9fb0e636ea41",
+        inputs: &[
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+            pin("T", "Tolerance", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("C", "Curves", List).of(ValueKind::Curve)],
+    },
+    SchemaRecord {
+        guid: "0f31784f-7177-4104-8500-1f4f4a306df4",
+        inputs: &[pin("x", "Value", Item)],
+        outputs: &[pin("y", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "0f3a13d4-5bb7-499e-9b57-56bb6dce93fd",
+        inputs: &[
+            pin("F", "Function", Item),
+            pin("a", "Variable a", Item),
+            pin("b", "Variable b", Item),
+            pin("c", "Variable c", Item),
+            pin("d", "Variable d", Item),
+        ],
+        outputs: &[pin("R", "Result R", Item)],
+    },
+    SchemaRecord {
+        guid: "0feeeaca-8f1f-4d7c-a24a-8e7dd68604a2",
+        inputs: &[
+            pin("R", "Regions", List),
+            pin("W", "Width", Item).of(ValueKind::Number),
+            pin("G", "Gap", Item),
+        ],
+        outputs: &[pin("R", "Regions", Tree), pin("T", "Topology", Tree)],
+    },
+    SchemaRecord {
+        guid: "10434a15-da85-4281-bb64-a2b3a995b9c6",
+        inputs: &[pin("B", "Breps", List).of(ValueKind::Brep)],
+        outputs: &[pin("R", "Result", List)],
+    },
+    SchemaRecord {
+        guid: "10487e4e-a405-48b5-b188-5a8a6328418b",
+        inputs: &[
+            pin("B", "Box", Item),
+            pin("D", "Density", Item),
+            pin("C", "Creases", Item),
+        ],
+        outputs: &[pin("S", "SubD", Item)],
+    },
+    SchemaRecord {
+        guid: "10a8674b-f4bb-4fdf-a56e-94dc606ecf33",
+        inputs: &[pin("D", "Data", Tree), pin("S", "Strip", Item)],
+        outputs: &[pin("T", "Tree", Item)],
+    },
+    SchemaRecord {
+        guid: "1177d6ee-3993-4226-9558-52b7fd63e1e3",
+        inputs: &[pin("T", "Tree", Tree), pin("D", "Depth", Item)],
+        outputs: &[pin("T", "Tree", Tree)],
+    },
+    SchemaRecord {
+        guid: "11bbd48b-bb0a-4f1b-8167-fa297590390d",
+        inputs: &[pin("C", "Curve", Item).of(ValueKind::Curve)],
+        outputs: &[pin("S", "Start", Item), pin("E", "End", Item)],
+    },
+    SchemaRecord {
+        guid: "1222394f-0d33-4f31-9101-7281bde89fe5",
+        inputs: &[
+            pin("C", "Curves", List).of(ValueKind::Curve),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[pin("R", "Result", List)],
+    },
+    SchemaRecord {
+        guid: "12278a4b-c131-4735-a3ee-bcb783083856",
+        inputs: &[pin("x", "Input", Item)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "125dc122-8544-4617-945e-bb9a0c101c50",
+        inputs: &[
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("S", "Size", Item).of(ValueKind::Number),
+            pin("Ex", "Extent X", Item),
+            pin("Ey", "Extent Y", Item),
+        ],
+        outputs: &[
+            pin("C", "Cells", Tree),
+            pin("P", "Points", Tree).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "1274d51a-81e6-4ccf-ad1f-0edf4c769cac",
+        inputs: &[
+            pin("T", "Text", List).of(ValueKind::Text),
+            pin("J", "Join", Item),
+        ],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "1303da7b-e339-4e65-a051-82c4dce8224d",
+        inputs: &[pin("T", "Tree", Tree), pin("F", "Front", Item)],
+        outputs: &[pin("T", "Tree", Tree)],
+    },
+    SchemaRecord {
+        guid: "139619d2-8b18-47b6-b3b9-bf4fec0d6eb1",
+        inputs: &[
+            pin("A", "Curve A", Item).of(ValueKind::Curve),
+            pin("B", "Curve B", Item).of(ValueKind::Curve),
+            pin("F", "Factor", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("T", "Tween", Item)],
+    },
+    SchemaRecord {
+        guid: "13b40e9c-3aed-4669-b2e8-60bd02091421",
+        inputs: &[
+            pin("B", "Box", Item),
+            pin("U", "U parameter", Item),
+            pin("V", "V parameter", Item),
+            pin("W", "W parameter", Item),
+        ],
+        outputs: &[
+            pin("Pl", "Plane", Item).of(ValueKind::Plane),
+            pin("Pt", "Point", Item).of(ValueKind::Point),
+            pin("I", "Include", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "14cf43b6-5eb9-460f-899c-bdece732213a",
+        inputs: &[
+            pin("A", "Curve A", Item).of(ValueKind::Curve),
+            pin("B", "Curve B", Item).of(ValueKind::Curve),
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("C", "Continuity", Item),
+        ],
+        outputs: &[pin("B", "Blend", Item)],
+    },
+    SchemaRecord {
+        guid: "15128198-399d-4d6c-9586-1f65db3ce7bf",
+        inputs: &[pin("S", "Surface", Item).of(ValueKind::Surface)],
+        outputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("W", "Weights", List),
+            pin("G", "Greville", List),
+            pin("U", "U Count", Item).of(ValueKind::Number),
+            pin("V", "V Count", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "15204c6d-bba8-403d-9e8f-6660ab8e0df5",
+        inputs: &[pin("A", "Group A", Item), pin("B", "Group B", Item)],
+        outputs: &[pin("G", "Group", Item)],
+    },
+    SchemaRecord {
+        guid: "152a264e-fc74-40e5-88cc-d1a681cd09c3",
+        inputs: &[
+            pin("A", "Vector A", Item).of(ValueKind::Vector),
+            pin("B", "Vector B", Item).of(ValueKind::Vector),
+        ],
+        outputs: &[
+            pin("A", "Angle", Item).of(ValueKind::Number),
+            pin("R", "Reflex", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "15ac45a8-b190-420a-bd66-e78ed6bcfaa4",
+        inputs: &[pin("C", "Curve", Item).of(ValueKind::Curve)],
+        outputs: &[pin("D", "Domain", Item)],
+    },
+    SchemaRecord {
+        guid: "15b7afe5-d0d0-43e1-b894-34fcfe3be384",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "1602b2cc-007c-4b79-8926-0067c6184e44",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("pA", "Point A", Item).of(ValueKind::Point),
+            pin("dA", "Direction A", Item).of(ValueKind::Vector),
+            pin("pB", "Point B", Item).of(ValueKind::Point),
+            pin("dB", "Direction B", Item).of(ValueKind::Vector),
+        ],
+        outputs: &[
+            pin("G", "Geometry", Item),
+            pin("X", "Transform", Item).of(ValueKind::Transform),
+        ],
+    },
+    SchemaRecord {
+        guid: "160c1df2-e2e8-48e5-b538-f2d6981007e3",
+        inputs: &[
+            pin("P", "Pattern", List),
+            pin("0", "Stream 0", List),
+            pin("1", "Stream 1", List),
+        ],
+        outputs: &[pin("W", "Weave", List)],
+    },
+    SchemaRecord {
+        guid: "164d0429-e5f5-4292-aa80-3f88d43cdac2",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("t", "Parameter", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("T", "Tangent", Item).of(ValueKind::Vector),
+        ],
+    },
+    SchemaRecord {
+        guid: "16ef3e75-e315-4899-b531-d3166b42dac9",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "17b7152b-d30d-4d50-b9ef-c9fe25576fc2",
+        inputs: &[pin("O", "Origin", Item).of(ValueKind::Point)],
+        outputs: &[pin("P", "Plane", Item).of(ValueKind::Plane)],
+    },
+    SchemaRecord {
+        guid: "17d40004-489e-42d9-ad10-857f7b436801",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("S", "Source", Item),
+            pin("T", "Target", Item),
+        ],
+        outputs: &[
+            pin("G", "Geometry", Item),
+            pin("X", "Transform", Item).of(ValueKind::Transform),
+        ],
+    },
+    SchemaRecord {
+        guid: "1817fd29-20ae-4503-b542-f0fb651e67d7",
+        inputs: &[pin("L", "List", List)],
+        outputs: &[pin("L", "Length", Item).of(ValueKind::Number)],
+    },
+    SchemaRecord {
+        guid: "18564c36-5652-4c63-bb6f-f0e1273666dd",
+        inputs: &[
+            pin("L", "Location", Item),
+            pin("T", "Text", Item).of(ValueKind::Text),
+            pin("S", "Size", Item).of(ValueKind::Number),
+            pin("C", "Colour", Item).of(ValueKind::Color),
+        ],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "188edd02-14a9-4828-a521-34995b0d1e4a",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("D", "Domain", Item),
+        ],
+        outputs: &[pin("L", "Length", Item).of(ValueKind::Number)],
+    },
+    SchemaRecord {
+        guid: "190c0070-8cbf-4347-94c2-d84bbb488d55",
+        inputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("B", "Box", Item),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("P", "Pattern", List), pin("D", "Dome", Item)],
+    },
+    SchemaRecord {
+        guid: "190d042c-2270-4bc1-81c0-4f90c170c9c9",
+        inputs: &[
+            pin("S", "Set", List),
+            pin("W", "Wrap", Item).of(ValueKind::Boolean),
+        ],
+        outputs: &[
+            pin("S", "Set", List),
+            pin("N", "Count", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "19632848-4b95-4e5e-9e86-b79b47987a46",
+        inputs: &[
+            pin("M", "Mesh", Item).of(ValueKind::Mesh),
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+        ],
+        outputs: &[
+            pin("X", "Points", List).of(ValueKind::Point),
+            pin("F", "Faces", List),
+        ],
+    },
+    SchemaRecord {
+        guid: "1a17d3f0-c8f8-4ee9-8dab-ea1c29db6a49",
+        inputs: &[
+            pin("M", "Mesh", Item).of(ValueKind::Mesh),
+            pin("G", "Guides", List),
+            pin("S", "Settings", Item),
+        ],
+        outputs: &[pin("Q", "Quad result", Item)],
+    },
+    SchemaRecord {
+        guid: "1a25aae0-0b56-497a-85b2-cc5bf7e4b96b",
+        inputs: &[
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("Sx", "Size X", Item).of(ValueKind::Number),
+            pin("Sy", "Size Y", Item).of(ValueKind::Number),
+            pin("Ex", "Extent X", Item),
+            pin("Ey", "Extent Y", Item),
+        ],
+        outputs: &[
+            pin("C", "Cells", Item),
+            pin("P", "Points", Tree).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "1a38d325-98de-455c-93f1-bca431bc1243",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            opt("D", "Distance", Item).of(ValueKind::Number),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("C", "Corners", Item),
+        ],
+        outputs: &[pin("C", "Curve", List).of(ValueKind::Curve)],
+    },
+    SchemaRecord {
+        guid: "1addcc85-b04e-46e6-bd4a-6f6c93bf7efd",
+        inputs: &[pin("B", "Breps", List).of(ValueKind::Brep)],
+        outputs: &[
+            pin("B", "Breps", List).of(ValueKind::Brep),
+            pin("C", "Closed", List).of(ValueKind::Boolean),
+        ],
+    },
+    SchemaRecord {
+        guid: "1cb59c86-7f6b-4e52-9a0c-6441850e9520",
+        inputs: &[
+            pin("A", "Corner A", Item).of(ValueKind::Point),
+            pin("B", "Corner B", Item).of(ValueKind::Point),
+            pin("C", "Corner C", Item).of(ValueKind::Point),
+            pin("D", "Corner D", Item).of(ValueKind::Point),
+        ],
+        outputs: &[pin("F", "Face", Item)],
+    },
+    SchemaRecord {
+        guid: "1d8b0e2c-e772-4fa9-b7f7-b158251b34b8",
+        inputs: &[pin("P", "Path", Item), pin("M", "Mask", Item)],
+        outputs: &[pin("C", "Comparison", Item)],
+    },
+    SchemaRecord {
+        guid: "1e531c08-9c80-46d6-8850-1b50d1dae69f",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("D", "Distance", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("T", "Tangents", List).of(ValueKind::Vector),
+            pin("t", "Parameters", List).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "1e936df3-0eea-4246-8549-514cb8862b7a",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "1eb4f6ff-3547-4184-bead-1b01e7cfd668",
+        inputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("Pl", "Plane", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[pin("M", "Mesh", Item).of(ValueKind::Mesh)],
+    },
+    SchemaRecord {
+        guid: "1eb7b856-ec7d-40b6-a76c-f216a11df37c",
+        inputs: &[pin("G", "Geometry", Item)],
+        outputs: &[
+            pin("A", "Area", Item).of(ValueKind::Number),
+            pin("C", "Centroid", Item).of(ValueKind::Point),
+            pin("I", "Inertia", Item),
+            pin("S", "Secondary", Item),
+            pin("G", "Gyration", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "1edcc3cf-cf84-41d4-8204-561162cfe510",
+        inputs: &[
+            pin("K", "Keys", List),
+            pin("V", "Values", List),
+            pin("S", "Search", Item),
+        ],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "1f384257-b26b-4160-a6d3-1dcd89b64acd",
+        inputs: &[pin("C", "Complex", Item).of(ValueKind::Complex)],
+        outputs: &[pin("R", "Real", Item), pin("i", "Imaginary", Item)],
+    },
+    SchemaRecord {
+        guid: "1f602c33-f38e-4f47-898b-359f0a4de3c2",
+        inputs: &[pin("x", "Value", Item)],
+        outputs: &[pin("y", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "1f798a28-9de6-47b5-8201-cac57256b777",
+        inputs: &[pin("P", "Points", List).of(ValueKind::Point)],
+        outputs: &[pin("L", "Line", Item).of(ValueKind::CurveLine)],
+    },
+    SchemaRecord {
+        guid: "1f8e1ff7-8278-4421-b39d-350e71d85d37",
+        inputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("W", "Weights", List),
+            pin("K", "Knots", List),
+        ],
+        outputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("L", "Length", Item).of(ValueKind::Number),
+            pin("D", "Domain", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "1ff80a00-1b1d-4fb3-926a-0c246261fc55",
+        inputs: &[pin("K", "Keys", List), pin("V", "Values", List)],
+        outputs: &[pin("K", "Keys", List), pin("V", "Values", List)],
+    },
+    SchemaRecord {
+        guid: "2013e425-8713-42e2-a661-b57e78840337",
+        inputs: &[pin("A", "Fragment A", Item), pin("B", "Fragment B", Item)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "203a91c3-287a-43b6-a9c5-ebb96240a650",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "20ef81e8-df15-4a0c-acf1-993a7607cafb",
+        inputs: &[
+            pin("B", "Brep", Item).of(ValueKind::Brep),
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+        ],
+        outputs: &[
+            pin("C", "Curves", List).of(ValueKind::Curve),
+            pin("P", "Points", List).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "2162e72e-72fc-4bf8-9459-d4d82fa8aa14",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("N", "Count", Item).of(ValueKind::Number),
+            opt("K", "Kinks", Item),
+        ],
+        outputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("T", "Tangents", List).of(ValueKind::Vector),
+            pin("t", "Parameters", List).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "2168853c-acd8-4a63-9c9b-ecde9e239eae",
+        inputs: &[pin("C", "Colliders", List)],
+        outputs: &[
+            pin("C", "Collision", List),
+            pin("I", "Indices", List).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "216bccd8-bf29-4d3c-b791-54c89a180db3",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "2183c4c6-b5b3-45d2-9261-2096c9357f92",
+        inputs: &[pin("S", "SubD", Item)],
+        outputs: &[
+            pin("L", "Line", List).of(ValueKind::CurveLine),
+            pin("E", "Edge", List),
+            pin("T", "Tag", List),
+            pin("I", "Id", List),
+        ],
+    },
+    SchemaRecord {
+        guid: "21b6a605-9568-4bf8-acc1-631565d609d7",
+        inputs: &[
+            pin("A", "Mesh A", Item).of(ValueKind::Mesh),
+            pin("B", "Mesh B", Item).of(ValueKind::Mesh),
+        ],
+        outputs: &[pin("X", "Intersections", List)],
+    },
+    SchemaRecord {
+        guid: "21ca41ee-bc18-4ac8-ba20-713e7edf541e",
+        inputs: &[
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+            pin("uv", "UV point", Item),
+        ],
+        outputs: &[pin("U", "U Isocurve", Item), pin("V", "V Isocurve", Item)],
+    },
+    SchemaRecord {
+        guid: "21d0767c-5340-4087-aa09-398d0e706908",
+        inputs: &[
+            pin("A", "Point A", Item).of(ValueKind::Point),
+            pin("B", "Point B", Item).of(ValueKind::Point),
+            pin("C", "Point C", Item).of(ValueKind::Point),
+        ],
+        outputs: &[
+            pin("C", "Circumcentre", Item),
+            pin("AB", "Bisector AB", Item),
+            pin("BC", "Bisector BC", Item),
+            pin("CA", "Bisector CA", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "224f7648-5956-4b26-80d9-8d771f3dfd5d",
+        inputs: &[pin("G", "Geometry", Item)],
+        outputs: &[
+            pin("V", "Volume", Item).of(ValueKind::Number),
+            pin("C", "Centroid", Item).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "2280dde4-9fa2-4b4a-ae2f-37d554861367",
+        inputs: &[pin("x", "Value", Item)],
+        outputs: &[pin("y", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "22990b1f-9be6-477c-ad89-f775cd347105",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            opt("G", "Guide", Item),
+        ],
+        outputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("F", "Flag", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "22bba82d-32e8-448c-a59c-f054c8843ee3",
+        inputs: &[pin("x", "Input", Item)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "22e61c07-c02f-4c53-b567-c821a164fd92",
+        inputs: &[
+            pin("B", "Base", Item),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+            pin("L", "Length", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("C", "Cone", Item)],
+    },
+    SchemaRecord {
+        guid: "22f66ff6-d281-453c-bd8c-36ed24026783",
+        inputs: &[
+            pin("A", "Stream A", Tree),
+            pin("B", "Stream B", Tree),
+            pin("C", "Stream C", Tree),
+            pin("D", "Stream D", Tree),
+            pin("E", "Stream E", Tree),
+            pin("F", "Stream F", Tree),
+            pin("G", "Stream G", Tree),
+            pin("H", "Stream H", Tree),
+            pin("I", "Stream I", Tree),
+            pin("J", "Stream J", Tree),
+        ],
+        outputs: &[pin("S", "Stream", Item)],
+    },
+    SchemaRecord {
+        guid: "2318aee8-01fe-4ea8-9524-6966023fc622",
+        inputs: &[
+            pin("P", "Planes", List).of(ValueKind::Plane),
+            pin("M", "Master", Item),
+        ],
+        outputs: &[pin("P", "Planes", List).of(ValueKind::Plane)],
+    },
+    SchemaRecord {
+        guid: "23285717-156c-468f-a691-b242488c06a6",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[
+            pin("G", "Geometry", Item),
+            pin("X", "Transform", Item).of(ValueKind::Transform),
+        ],
+    },
+    SchemaRecord {
+        guid: "23603075-be64-4d86-9294-c3c125a12104",
+        inputs: &[
+            pin("P", "Base plane", Item),
+            pin("A", "Angle", Item).of(ValueKind::Number),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+            pin("E", "Elevation", Item),
+        ],
+        outputs: &[pin("Pt", "Point", Item).of(ValueKind::Point)],
+    },
+    SchemaRecord {
+        guid: "23862862-049a-40be-b558-2418aacbd916",
+        inputs: &[pin("A", "Arc", Item).of(ValueKind::Curve)],
+        outputs: &[
+            pin("B", "Base Plane", Item),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+            pin("A", "Angle", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "23afc7aa-2d2f-4ae7-b876-bf366246b826",
+        inputs: &[pin("x", "Value", Item)],
+        outputs: &[pin("y", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "24e913c9-7530-436d-b81d-bc3aa27296a4",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[pin("G", "Geometry", Item)],
+    },
+    SchemaRecord {
+        guid: "264b4aa6-4915-4a67-86a7-22a5c4acf565",
+        inputs: &[
+            pin("A", "SubD A", Item),
+            pin("B", "SubD B", Item),
+            pin("O", "Boolean Option", Item),
+            pin("S", "Smoothing", Item),
+        ],
+        outputs: &[pin("F", "Fuse", Item)],
+    },
+    SchemaRecord {
+        guid: "2653b135-4df1-4a6b-820c-55e2ad3bc1e0",
+        inputs: &[
+            pin("A", "Tree A", Tree),
+            pin("B", "Tree B", Tree),
+            pin("O", "Offset", Item),
+            pin("Wp", "Wrap Paths", Item),
+            pin("Wi", "Wrap Items", Item),
+        ],
+        outputs: &[pin("A", "Item A", Tree), pin("B", "Item B", Tree)],
+    },
+    SchemaRecord {
+        guid: "26949c81-9b50-43b7-ac49-3203deb6eec7",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("R", "Regions", List),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[pin("Ci", "Inside", List), pin("Co", "Outside", List)],
+    },
+    SchemaRecord {
+        guid: "2696bd14-3fb5-4750-827f-86df6c31d664",
+        inputs: &[
+            pin("B", "Base", Item),
+            pin("X", "X Count", Item),
+            pin("Y", "Y Count", Item),
+            pin("Z", "Z Count", Item),
+        ],
+        outputs: &[pin("M", "Mesh", Item).of(ValueKind::Mesh)],
+    },
+    SchemaRecord {
+        guid: "269eaa85-9997-4d77-a9ba-4c58cb45c9d3",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            opt("L", "Level", Item),
+        ],
+        outputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("t", "Parameters", List).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "275671d4-3e87-40bd-8aff-8e6a5fdbb892",
+        inputs: &[
+            pin("A", "Point A", Item).of(ValueKind::Point),
+            pin("B", "Point B", Item).of(ValueKind::Point),
+            pin("L", "Length", Item).of(ValueKind::Number),
+            pin("G", "Gravity", Item),
+        ],
+        outputs: &[pin("C", "Catenary", Item)],
+    },
+    SchemaRecord {
+        guid: "27d6f724-a701-4585-992f-3897488abf08",
+        inputs: &[pin("x", "Value", Item)],
+        outputs: &[pin("y", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "28061aae-04fb-4cb5-ac45-16f3b66bc0a4",
+        inputs: &[
+            pin("B", "Base", Item),
+            pin("X", "X", Item),
+            pin("Y", "Y", Item),
+            pin("Z", "Z", Item),
+        ],
+        outputs: &[pin("B", "Box", Item)],
+    },
+    SchemaRecord {
+        guid: "28124995-cf99-4298-b6f4-c75a8e379f18",
+        inputs: &[pin("x", "Value", Item)],
+        outputs: &[pin("y", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "282bf4eb-668a-4a2c-81af-2432ac863ddd",
+        inputs: &[
+            pin("A", "Curve A", Item).of(ValueKind::Curve),
+            pin("B", "Curve B", Item).of(ValueKind::Curve),
+            pin("C", "Continuity", Item),
+        ],
+        outputs: &[pin("M", "Match", Item)],
+    },
+    SchemaRecord {
+        guid: "28504f1f-a8d9-40c8-b8aa-529413456258",
+        inputs: &[
+            pin("T", "Text", Item).of(ValueKind::Text),
+            pin("F", "Font", Item),
+            pin("H", "Height", Item).of(ValueKind::Number),
+            pin("D", "Depth", Item),
+            pin("B", "Base Line", Item),
+            pin("S", "Base Surface", Item),
+        ],
+        outputs: &[pin("S", "Symbols", List)],
+    },
+    SchemaRecord {
+        guid: "285ddd8a-5398-4a3e-b3c2-361025711a51",
+        inputs: &[
+            pin("L", "List", List),
+            pin("i", "Index", Item).of(ValueKind::Number),
+            pin("W", "Wrap", Item).of(ValueKind::Boolean),
+        ],
+        outputs: &[pin("E", "Element", Item)],
+    },
+    SchemaRecord {
+        guid: "288cfe66-f3dc-4c9a-bb96-ef81f47fe724",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "28b1c4d4-ab1c-4309-accd-1b7a954ed948",
+        inputs: &[
+            pin("A", "Corner A", Item).of(ValueKind::Point),
+            pin("B", "Corner B", Item).of(ValueKind::Point),
+            pin("C", "Corner C", Item).of(ValueKind::Point),
+        ],
+        outputs: &[
+            pin("C", "Circle", Item).of(ValueKind::Curve),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "28f40e48-e739-4211-91bd-f4aefa5965f8",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "290cf9c4-0711-4704-851e-4c99e3343ac5",
+        inputs: &[
+            pin("A", "Plane A", Item).of(ValueKind::Plane),
+            pin("B", "Plane B", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[pin("L", "Line", Item).of(ValueKind::CurveLine)],
+    },
+    SchemaRecord {
+        guid: "290f418a-65ee-406a-a9d0-35699815b512",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("X", "Scale X", Item),
+            pin("Y", "Scale Y", Item),
+            pin("Z", "Scale Z", Item),
+        ],
+        outputs: &[
+            pin("G", "Geometry", Item),
+            pin("X", "Transform", Item).of(ValueKind::Transform),
+        ],
+    },
+    SchemaRecord {
+        guid: "2956d989-3599-476f-bc92-1d847aff98b6",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            opt("Td", "Tolerance (distance)", Item),
+            opt("Ta", "Tolerance (angle)", Item),
+            opt("E-", "MinEdge", Item),
+            opt("E+", "MaxEdge", Item),
+        ],
+        outputs: &[
+            pin("P", "Polyline", Item).of(ValueKind::Curve),
+            pin("S", "Segments", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "2a43ef96-8f87-4892-8b94-237a47e8d3cf",
+        inputs: &[
+            pin("A", "Point A", Item).of(ValueKind::Point),
+            pin("B", "Point B", Item).of(ValueKind::Point),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[pin("B", "Box", Item)],
+    },
+    SchemaRecord {
+        guid: "2a5cfb31-028a-4b34-b4e1-9b20ae15312e",
+        inputs: &[
+            pin("A", "Vector A", Item).of(ValueKind::Vector),
+            pin("B", "Vector B", Item).of(ValueKind::Vector),
+            opt("U", "Unitize", Item).of(ValueKind::Boolean),
+        ],
+        outputs: &[
+            pin("V", "Vector", Item).of(ValueKind::Vector),
+            pin("L", "Length", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "2ab17f9a-d852-4405-80e1-938c5e57e78d",
+        inputs: &[
+            pin("R", "Range", Item),
+            pin("N", "Number", Item).of(ValueKind::Number),
+            pin("S", "Seed", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("R", "Random", List)],
+    },
+    SchemaRecord {
+        guid: "2ad2a4d4-3de1-42f6-a4b8-f71835f35710",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("t", "Parameters", List).of(ValueKind::Number),
+        ],
+        outputs: &[pin("S", "Segments", List).of(ValueKind::Number)],
+    },
+    SchemaRecord {
+        guid: "2b2628ea-3f43-4ce9-8435-9a045d54b5c6",
+        inputs: &[
+            pin("K", "Keys", List),
+            pin("A", "Values A", List),
+            pin("B", "Values B", List),
+            pin("C", "Values C", List),
+        ],
+        outputs: &[
+            pin("L", "List", List),
+            pin("A", "Values A", List),
+            pin("B", "Values B", List),
+            pin("C", "Values C", List),
+        ],
+    },
+    SchemaRecord {
+        guid: "2b2a4145-3dff-41d4-a8de-1ea9d29eef33",
+        inputs: &[
+            pin("V", "Vertices", List).of(ValueKind::Point),
+            opt("D", "Degree", Item).of(ValueKind::Number),
+            opt("P", "Periodic", Item).of(ValueKind::Boolean),
+            pin("K", "KnotStyle", Item),
+        ],
+        outputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("L", "Length", Item).of(ValueKind::Number),
+            pin("D", "Domain", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "2b9bf01d-5fe5-464c-b0b3-b469eb5f2efb",
+        inputs: &[pin("M", "Mesh", Item).of(ValueKind::Mesh)],
+        outputs: &[
+            pin("E1", "Naked Edges", List),
+            pin("E2", "Interior Edges", List),
+            pin("E3", "Non-Manifold Edges", List),
+        ],
+    },
+    SchemaRecord {
+        guid: "2ba64356-be21-4c12-bbd4-ced54f04c8ef",
+        inputs: &[
+            pin("B", "Brep", Item).of(ValueKind::Brep),
+            pin("S", "Shape", Item),
+        ],
+        outputs: &[pin("R", "Relation", Item)],
+    },
+    SchemaRecord {
+        guid: "2bcd153c-c964-4199-b8e4-4a19dfd34967",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "2c56ab33-c7cc-4129-886c-d5856b714010",
+        inputs: &[pin("A", "A", Item), pin("B", "B", Item)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "2cb4bf85-a282-464c-b42c-8e735d2a0a74",
+        inputs: &[pin("L", "List", List)],
+        outputs: &[pin("S", "Set", List)],
+    },
+    SchemaRecord {
+        guid: "2d3b6ef3-5c26-4e2f-bcb3-8ffb9fb0f7c3",
+        inputs: &[pin("B", "Boxes", List), pin("G", "Gap", Item)],
+        outputs: &[
+            pin("B", "Breps", Tree).of(ValueKind::Brep),
+            pin("T", "Topology", Tree),
+        ],
+    },
+    SchemaRecord {
+        guid: "2d61f4e0-47c5-41d6-a41d-6afa96ee63af",
+        inputs: &[pin("D", "Data", Tree), pin("O", "Offset", Item)],
+        outputs: &[pin("D", "Data", Tree)],
+    },
+    SchemaRecord {
+        guid: "2d6cb24f-da89-4fab-be0f-e5d439e0217a",
+        inputs: &[
+            pin("A", "First number", Item).of(ValueKind::Number),
+            pin("B", "Second number", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "2dc44b22-b1dd-460a-a704-6462d6e91096",
+        inputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+        ],
+        outputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("t", "Parameter", Item).of(ValueKind::Number),
+            pin("D", "Distance", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "2e205f24-9279-47b2-b414-d06dcd0b21a7",
+        inputs: &[pin("G", "Geometry", Item)],
+        outputs: &[
+            pin("A", "Area", Item).of(ValueKind::Number),
+            pin("C", "Centroid", Item).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "2e3ab970-8545-46bb-836c-1c11e5610bce",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "2e685fd9-7b8f-461b-b330-44857b099937",
+        inputs: &[pin("G", "Geometry", Item)],
+        outputs: &[
+            pin("V", "Volume", Item).of(ValueKind::Number),
+            pin("C", "Centroid", Item).of(ValueKind::Point),
+            pin("I", "Inertia", Item),
+            pin("S", "Secondary", Item),
+            pin("G", "Gyration", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "2e78987b-9dfb-42a2-8b76-3923ac8bd91a",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "2ebb82ef-1f90-4ac9-9a71-1fe0f4ef7044",
+        inputs: &[pin("x", "Value", Item)],
+        outputs: &[pin("y", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "2f407944-81c3-4062-a485-276454ec4b8c",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            opt("R", "Radius", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("C", "Curve", Item).of(ValueKind::Curve)],
+    },
+    SchemaRecord {
+        guid: "2f643ab6-b9a4-4923-b3da-f9d52b0cba14",
+        inputs: &[
+            pin("A", "First number", Item).of(ValueKind::Number),
+            pin("B", "Second number", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "2f77b45b-034d-4053-8872-f38d87cbc676",
+        inputs: &[
+            pin("F", "Function", Item),
+            pin("x", "x", Item),
+            pin("y", "y", Item),
+            pin("z", "z", Item),
+        ],
+        outputs: &[pin("r", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "2fcc2743-8339-4cdf-a046-a1f17439191d",
+        inputs: &[
+            pin("V", "Value", Item),
+            pin("S", "Source", Item),
+            pin("T", "Target", Item),
+        ],
+        outputs: &[pin("R", "Mapped", Item), pin("C", "Clipped", Item)],
+    },
+    SchemaRecord {
+        guid: "3092caf0-7cf9-4885-bcc0-e635d878832a",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("R", "Region", Item),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[pin("Ci", "Inside", List), pin("Co", "Outside", List)],
+    },
+    SchemaRecord {
+        guid: "30ce59ce-22a1-49ee-9e21-e6d16b3684a8",
+        inputs: &[
+            pin("A", "Start point", Item),
+            pin("At", "Start tangent", Item),
+            pin("B", "End point", Item),
+            pin("Bt", "End tangent", Item),
+        ],
+        outputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("L", "Length", Item).of(ValueKind::Number),
+            pin("D", "Domain", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "30d58600-1aab-42db-80a3-f1ea6c4269a0",
+        inputs: &[
+            pin("A", "First Number", Item).of(ValueKind::Number),
+            pin("B", "Second Number", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin(">", "Larger than", Item),
+            pin(">=", "… or Equal to", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "310e1065-d03a-4858-bcd1-809d39c042af",
+        inputs: &[
+            pin("V", "Vector", Item).of(ValueKind::Vector),
+            pin("F", "Factor", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("V", "Vector", Item).of(ValueKind::Vector),
+            pin("L", "Length", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "31534405-6573-4be6-8bf8-262e55847a3a",
+        inputs: &[pin("D", "Date", Item), pin("T", "Time", Item)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "3175e3eb-1ae0-4d0b-9395-53fd3e8f8a28",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "317f1cb2-820d-4a8f-b5c8-5de3594ddfba",
+        inputs: &[
+            pin("F", "File", Item),
+            pin("L", "Layer", Item),
+            pin("N", "Name", Item),
+        ],
+        outputs: &[pin("G", "Geometry", List)],
+    },
+    SchemaRecord {
+        guid: "318dacd7-9073-4ede-b043-a0c132eb77e0",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "322f0e6e-d434-4d07-9f8d-f214bb248cb1",
+        inputs: &[
+            pin("F", "Function", Item),
+            pin("a", "a", Item),
+            pin("b", "b", Item),
+            pin("c", "c", Item),
+            pin("d", "d", Item),
+            pin("x", "x", Item),
+        ],
+        outputs: &[pin("r", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "323f3245-af49-4489-8677-7a2c73664077",
+        inputs: &[pin("C", "Curve", Item).of(ValueKind::Curve)],
+        outputs: &[
+            pin("C", "Closed", Item).of(ValueKind::Boolean),
+            pin("P", "Periodic", Item).of(ValueKind::Boolean),
+        ],
+    },
+    SchemaRecord {
+        guid: "3249222f-f536-467a-89f4-f0353fba455a",
+        inputs: &[pin("L", "List", List), pin("P", "Sift Pattern", List)],
+        outputs: &[pin("0", "Output 0", List), pin("1", "Output 1", List)],
+    },
+    SchemaRecord {
+        guid: "32c57b97-b653-47dd-b78f-121e89fdd01c",
+        inputs: &[
+            pin("A", "Point A", Item).of(ValueKind::Point),
+            pin("B", "Point B", Item).of(ValueKind::Point),
+            pin("C", "Point C", Item).of(ValueKind::Point),
+        ],
+        outputs: &[
+            pin("A", "Arc", Item).of(ValueKind::Curve),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "332378f4-acb2-43fe-8593-ed22bfeb2721",
+        inputs: &[
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+            pin("U", "U Count", Item).of(ValueKind::Number),
+            pin("V", "V Count", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("F", "Frames", Tree).of(ValueKind::Plane),
+            pin("uv", "Parameters", Tree).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "338666eb-14c5-4d9b-82e2-2b5be60655df",
+        inputs: &[pin("I", "Input", List), pin("W", "Weights", List)],
+        outputs: &[pin("AM", "Arithmetic mean", Item)],
+    },
+    SchemaRecord {
+        guid: "339c0ee1-cf11-444f-8e10-65c9150ea755",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "33bcf975-a0b2-4b54-99fd-585c893b9e88",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "33bfc73c-19b2-480b-81e6-f3523a012ea6",
+        inputs: &[pin("P", "Points", List).of(ValueKind::Point)],
+        outputs: &[
+            pin("Pl", "Plane", Item).of(ValueKind::Plane),
+            pin("dx", "Deviation", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "342aa574-1327-4bc2-8daf-203da2a45676",
+        inputs: &[
+            pin("C", "Curves", List).of(ValueKind::Curve),
+            pin("Nu", "Count U", Item).of(ValueKind::Number),
+            pin("Du", "Degree U", Item).of(ValueKind::Number),
+            pin("Dv", "Degree V", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("S", "Surface", Item).of(ValueKind::Surface)],
+    },
+    SchemaRecord {
+        guid: "34493ef6-3dfb-47c0-b149-691d02a93588",
+        inputs: &[
+            pin("A", "Point A", Item).of(ValueKind::Point),
+            pin("B", "Point B", Item).of(ValueKind::Point),
+            pin("C", "Point C", Item).of(ValueKind::Point),
+        ],
+        outputs: &[
+            pin("R", "Rec", Item),
+            pin("L", "Length", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "353b206e-bde5-4f02-a913-b3b8a977d4b9",
+        inputs: &[
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+            pin("uv", "Point", Item).of(ValueKind::Point),
+        ],
+        outputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("N", "Normal", Item).of(ValueKind::Vector),
+            pin("U", "U direction", Item),
+            pin("V", "V direction", Item),
+            pin("F", "Frame", Item).of(ValueKind::Plane),
+        ],
+    },
+    SchemaRecord {
+        guid: "3581f42a-9592-4549-bd6b-1c0fc39d067b",
+        inputs: &[
+            pin("X", "X coordinate", Item).of(ValueKind::Number),
+            pin("Y", "Y coordinate", Item).of(ValueKind::Number),
+            pin("Z", "Z coordinate", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("Pt", "Point", Item).of(ValueKind::Point)],
+    },
+    SchemaRecord {
+        guid: "36132830-e2ef-4476-8ea1-6a43922344f0",
+        inputs: &[
+            pin("A", "Curve A", Item).of(ValueKind::Curve),
+            pin("B", "Curve B", Item).of(ValueKind::Curve),
+            pin("C", "Curve C", Item).of(ValueKind::Curve),
+            pin("D", "Curve D", Item).of(ValueKind::Curve),
+        ],
+        outputs: &[pin("S", "Surface", Item).of(ValueKind::Surface)],
+    },
+    SchemaRecord {
+        guid: "361790d6-9d66-4808-8c5a-8de9c218c227",
+        inputs: &[
+            pin("B", "Base", Item),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("S", "Sphere", Item)],
+    },
+    SchemaRecord {
+        guid: "36947590-f0cb-4807-a8f9-9c90c9b20621",
+        inputs: &[pin("A", "List (A)", List), pin("B", "List (B)", List)],
+        outputs: &[pin("A", "List (A)", List), pin("B", "List (B)", List)],
+    },
+    SchemaRecord {
+        guid: "36dd5551-b6bd-4246-bd2f-1fd91eb2f02d",
+        inputs: &[
+            pin("A", "Point A", Item).of(ValueKind::Point),
+            pin("B", "Point B", Item).of(ValueKind::Point),
+            pin("C", "Point C", Item).of(ValueKind::Point),
+        ],
+        outputs: &[
+            pin("C", "Orthocentre", Item),
+            pin("AB", "Altitude AB", Item),
+            pin("BC", "Altitude BC", Item),
+            pin("CA", "Altitude CA", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "37084b3f-2b66-4f3a-9737-80d0b0b7f0cb",
+        inputs: &[pin("A", "A", Item), pin("B", "B", Item)],
+        outputs: &[
+            pin("V-", "Mininum", Item),
+            pin("V+", "Maximum", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "3756c55f-95c3-442c-a027-6b3ab0455a94",
+        inputs: &[
+            pin("T", "Text", Item).of(ValueKind::Text),
+            pin("P", "Pattern", Item),
+            pin("R", "RegEx", Item),
+            pin("C", "Case", Item),
+        ],
+        outputs: &[pin("M", "Match", Item)],
+    },
+    SchemaRecord {
+        guid: "378d0690-9da0-4dd1-ab16-1d15246e7c22",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("A", "Source", Item),
+            pin("B", "Target", Item),
+        ],
+        outputs: &[
+            pin("G", "Geometry", Item),
+            pin("X", "Transform", Item).of(ValueKind::Transform),
+        ],
+    },
+    SchemaRecord {
+        guid: "383929c0-6515-4899-8b4b-3bd0d0b32471",
+        inputs: &[pin("F", "File", Item)],
+        outputs: &[pin("A", "Atoms", List), pin("B", "Bonds", List)],
+    },
+    SchemaRecord {
+        guid: "38a4e722-ad5a-4229-a170-e27ae1345538",
+        inputs: &[
+            pin("A", "Time A", Item),
+            pin("B", "Time B", Item),
+            pin("N", "Count", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("R", "Range", List)],
+    },
+    SchemaRecord {
+        guid: "38a5638b-6d01-4417-bf11-976d925f8a71",
+        inputs: &[
+            pin("B", "Base", Item),
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+        ],
+        outputs: &[pin("E", "Extrusion", Item)],
+    },
+    SchemaRecord {
+        guid: "39461433-ac44-4298-94a9-988f983e347c",
+        inputs: &[pin("x", "Input", Item)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "3a0c7bda-3d22-4588-8bab-03f57a52a6ea",
+        inputs: &[pin("P", "Base Plane", Item), opt("O", "Offset", Item)],
+        outputs: &[pin("Pl", "Plane", Item).of(ValueKind::Plane)],
+    },
+    SchemaRecord {
+        guid: "3a710c1e-1809-4e19-8c15-82adce31cd62",
+        inputs: &[pin("T", "Tree", Tree), pin("P", "Path", Item)],
+        outputs: &[pin("B", "Branch", Tree)],
+    },
+    SchemaRecord {
+        guid: "3aa2a080-e322-4be3-8c6e-baf6c8000cf1",
+        inputs: &[pin("M", "Matrix", Item).of(ValueKind::Matrix)],
+        outputs: &[
+            pin("R", "Rows", Item),
+            pin("C", "Columns", Item),
+            pin("V", "Values", List),
+        ],
+    },
+    SchemaRecord {
+        guid: "3ae3a462-38fb-4d49-9f86-7558dfed7c3e",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("P", "Base", Item),
+            pin("G", "Grip", Item),
+            pin("T", "Target", Item),
+        ],
+        outputs: &[pin("G", "Geometry", Item)],
+    },
+    SchemaRecord {
+        guid: "3b1ae469-0e9b-461d-8c30-fa5a7de8b7a9",
+        inputs: &[
+            pin("M", "Mesh", Item).of(ValueKind::Mesh),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[pin("C", "Curves", List).of(ValueKind::Curve)],
+    },
+    SchemaRecord {
+        guid: "3cadddef-1e2b-4c09-9390-0e8f78f7609f",
+        inputs: &[pin("D1", "Data 1", Tree), pin("D2", "Data 2", Tree)],
+        outputs: &[pin("R", "Result", Tree)],
+    },
+    SchemaRecord {
+        guid: "3cd2949b-4ea8-4ffb-a70c-5c380f9f46ea",
+        inputs: &[pin("P", "Plane", Item).of(ValueKind::Plane)],
+        outputs: &[
+            pin("O", "Origin", Item).of(ValueKind::Point),
+            pin("X", "X-Axis", Item),
+            pin("Y", "Y-Axis", Item),
+            pin("Z", "Z-Axis", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "3dfb9a77-6e05-4016-9f20-94f78607d672",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("A", "Angle", Item).of(ValueKind::Number),
+            pin("C", "Center", Item).of(ValueKind::Point),
+            pin("X", "Axis", Item),
+        ],
+        outputs: &[
+            pin("G", "Geometry", Item),
+            pin("X", "Transform", Item).of(ValueKind::Transform),
+        ],
+    },
+    SchemaRecord {
+        guid: "3e5582a1-901a-4f7c-b58d-f5d7e3166124",
+        inputs: &[
+            pin("G", "Gate", Item),
+            pin("0", "Stream 0", Tree),
+            pin("1", "Stream 1", Tree),
+        ],
+        outputs: &[pin("S", "Stream", Tree)],
+    },
+    SchemaRecord {
+        guid: "3e6383e9-af39-427b-801a-19ca916160fa",
+        inputs: &[
+            pin("A", "First number", Item).of(ValueKind::Number),
+            pin("B", "Second number", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "3e7e4827-6edd-4e10-93ac-cc234414d2b9",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("O", "Offsets", List),
+            pin("D", "Distances", List),
+        ],
+        outputs: &[
+            pin("C", "Contours", Tree),
+            pin("t", "Parameters", Tree).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "3e8ca6be-fda8-4aaf-b5c0-3c54c8bb7312",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "3eba04bc-00e8-416d-b58f-a3dc8b3e22e2",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("B", "Brep", Item).of(ValueKind::Brep),
+        ],
+        outputs: &[pin("Ci", "Inside", List), pin("Co", "Outside", List)],
+    },
+    SchemaRecord {
+        guid: "3edc4fbd-24c6-43de-aaa8-5bdf0704373d",
+        inputs: &[
+            pin("C", "Centers", List),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("A", "Curve A", Item).of(ValueKind::Curve),
+            pin("B", "Curve B", Item).of(ValueKind::Curve),
+            pin("C", "Circles", List).of(ValueKind::Curve),
+        ],
+    },
+    SchemaRecord {
+        guid: "3ede854e-c753-40eb-84cb-b48008f14fd4",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "3ff27857-b988-417a-b495-b24c733dbd00",
+        inputs: &[pin("S", "Set", List), pin("M", "Member", Item)],
+        outputs: &[
+            pin("I", "Index", List).of(ValueKind::Number),
+            pin("N", "Count", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "40177d8a-a35c-4622-bca7-d150031fe427",
+        inputs: &[
+            pin("A", "First Number", Item).of(ValueKind::Number),
+            pin("B", "Second Number", Item).of(ValueKind::Number),
+            pin("T%", "Threshold", Item),
+        ],
+        outputs: &[
+            pin("=", "Similarity", Item),
+            pin("dt", "Absolute difference", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "4018985c-f9e8-4a8f-8d4d-518aec276f60",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "4041be93-6746-4cdb-aa95-929bff544fb0",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("pA", "Point A", Item).of(ValueKind::Point),
+            pin("dA", "Direction A", Item).of(ValueKind::Vector),
+            pin("pB", "Point B", Item).of(ValueKind::Point),
+            pin("dB", "Direction B", Item).of(ValueKind::Vector),
+        ],
+        outputs: &[pin("G", "Geometry", Item)],
+    },
+    SchemaRecord {
+        guid: "404f75ac-5594-4c48-ad8a-7d0f472bbf8a",
+        inputs: &[
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+            pin("uv", "Point", Item).of(ValueKind::Point),
+        ],
+        outputs: &[
+            pin("F", "Frame", Item).of(ValueKind::Plane),
+            pin("C¹", "Maximum", Item).of(ValueKind::Number),
+            pin("C²", "Minimum", Item).of(ValueKind::Number),
+            pin("K¹", "Max direction", Item),
+            pin("K²", "Min direction", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "407e35c6-7c40-4652-bd80-fde1eb7ec034",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("F", "Factor", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("G", "Geometry", Item),
+            pin("X", "Transform", Item).of(ValueKind::Transform),
+        ],
+    },
+    SchemaRecord {
+        guid: "4083802b-3dd9-4b13-9756-bf5441213e70",
+        inputs: &[
+            pin("A", "Date A", Item),
+            pin("B", "Date B", Item),
+            pin("t", "Interpolation", Item),
+        ],
+        outputs: &[pin("D", "Date", Item)],
+    },
+    SchemaRecord {
+        guid: "40efea60-1902-4c28-8020-27abbb7a1449",
+        inputs: &[
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("S", "Size", Item).of(ValueKind::Number),
+            pin("Ex", "Extent X", Item),
+            pin("Ey", "Extent Y", Item),
+        ],
+        outputs: &[
+            pin("C", "Cells", Tree),
+            pin("P", "Points", Tree).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "4139f3a3-cf93-4fc0-b5e0-18a3acd0b003",
+        inputs: &[
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+            pin("uv", "Point", Item).of(ValueKind::Point),
+        ],
+        outputs: &[
+            pin("F", "Frame", Item).of(ValueKind::Plane),
+            pin("G", "Gaussian", Item),
+            pin("M", "Mean", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "415750fd-c0ec-4411-84d0-01f28ab23066",
+        inputs: &[
+            pin("B", "Border", Item),
+            pin("N", "Count", Item).of(ValueKind::Number),
+            pin("A", "Angles", List),
+            pin("D", "Deviation", Item).of(ValueKind::Number),
+            pin("S", "Seed", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("S", "Substrate", List)],
+    },
+    SchemaRecord {
+        guid: "41aa4112-9c9b-42f4-847e-503b9d90e4c7",
+        inputs: &[pin("D", "Data", Tree)],
+        outputs: &[pin("D", "Data", Tree)],
+    },
+    SchemaRecord {
+        guid: "424eb433-2b3a-4859-beaf-804d8af0afd7",
+        inputs: &[pin("C", "Curve", Item).of(ValueKind::Curve)],
+        outputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("W", "Weights", List),
+            pin("K", "Knots", List),
+        ],
+    },
+    SchemaRecord {
+        guid: "429cbba9-55ee-4e84-98ea-876c44db879a",
+        inputs: &[pin("C", "Base curve", Item), pin("D", "Domain", Item)],
+        outputs: &[pin("C", "Curve", Item).of(ValueKind::Curve)],
+    },
+    SchemaRecord {
+        guid: "42ad8dc1-b0c0-40df-91f5-2c46e589e6c2",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            opt("t", "Seam", Item),
+        ],
+        outputs: &[pin("C", "Curve", Item).of(ValueKind::Curve)],
+    },
+    SchemaRecord {
+        guid: "431bc610-8ae1-4090-b217-1a9d9c519fe2",
+        inputs: &[pin("A", "A", Item), pin("B", "B", Item)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "4356ef8f-0ca1-4632-9c39-9e6dcd2b9496",
+        inputs: &[
+            pin("P", "Pattern", List),
+            pin("0", "Stream 0", List),
+            pin("1", "Stream 1", List),
+        ],
+        outputs: &[pin("R", "Result", List)],
+    },
+    SchemaRecord {
+        guid: "439a55a5-2f9e-4f66-9de2-32f24fec2ef5",
+        inputs: &[
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("X", "X Size", Item),
+            pin("Y", "Y Size", Item),
+        ],
+        outputs: &[pin("P", "Plane", Item).of(ValueKind::Plane)],
+    },
+    SchemaRecord {
+        guid: "43b9ea8f-f772-40f2-9880-011a9c3cbbb0",
+        inputs: &[
+            pin("A", "Vector A", Item).of(ValueKind::Vector),
+            pin("B", "Vector B", Item).of(ValueKind::Vector),
+            opt("U", "Unitize", Item).of(ValueKind::Boolean),
+        ],
+        outputs: &[pin("D", "Dot product", Item)],
+    },
+    SchemaRecord {
+        guid: "4439a51b-8d24-4924-b8e2-f77e7f8f5bec",
+        inputs: &[
+            pin("A", "First Set", List),
+            pin("B", "Second Set", List),
+            pin("D", "Distance", Item).of(ValueKind::Number),
+            pin("L", "Result Limit", Item),
+        ],
+        outputs: &[
+            pin("N", "Clash Count", Item),
+            pin("P", "Clash Points", List),
+            pin("R", "Clash Radii", List),
+            pin("i", "First Index", List),
+            pin("j", "Second index", List),
+        ],
+    },
+    SchemaRecord {
+        guid: "446014c4-c11c-45a7-8839-c45dc60950d6",
+        inputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("C", "Cloud", List),
+            pin("N", "Count", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("P", "Closest Point", List),
+            pin("i", "CP Index", List),
+            pin("D", "Distance", List).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "448de216-3a12-43cf-a135-e3bfafc87744",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "455925fd-23ff-4e57-a0e7-913a4165e659",
+        inputs: &[
+            pin("L", "List", List),
+            pin("R", "Reduction", Item),
+            pin("S", "Seed", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("L", "List", List)],
+    },
+    SchemaRecord {
+        guid: "458ed0e0-19a3-419b-8ead-f524925b8a35",
+        inputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("Pl", "Plane", Item).of(ValueKind::Plane),
+            pin("G", "Group", Item),
+            pin("R-", "Min Radius", Item),
+            pin("R+", "Max Radius", Item),
+        ],
+        outputs: &[pin("L", "Links", Tree), pin("T", "Topology", Tree)],
+    },
+    SchemaRecord {
+        guid: "45f19d16-1c9f-4b0f-a9a6-45a77f3d206c",
+        inputs: &[
+            pin("Cls", "Closed", Item).of(ValueKind::Boolean),
+            pin("Adj", "Adjust", Item),
+            pin("Rbd", "Rebuild", Item),
+            pin("Rft", "Refit", Item),
+            pin("T", "Type", Item),
+        ],
+        outputs: &[pin("O", "Options", Item)],
+    },
+    SchemaRecord {
+        guid: "46372d0d-82dc-4acb-adc3-25d1fde04c4e",
+        inputs: &[pin("T", "Tree", Tree), pin("G", "Guide", Tree)],
+        outputs: &[pin("T", "Tree", Tree)],
+    },
+    SchemaRecord {
+        guid: "46b5564d-d3eb-4bf1-ae16-15ed132cfd88",
+        inputs: &[
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("R1", "Radius 1", Item).of(ValueKind::Number),
+            pin("R2", "Radius 2", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("E", "Ellipse", Item),
+            pin("F1", "Focus 1", Item),
+            pin("F2", "Focus 2", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "476c0cf8-bc3c-4f1c-a61a-6e91e1f8b91e",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "477c2e7b-c5e5-421e-b8b2-ba60cdf5398b",
+        inputs: &[
+            pin("A", "Curves A", List).of(ValueKind::Curve),
+            pin("B", "Curves B", List).of(ValueKind::Curve),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[pin("R", "Result", List)],
+    },
+    SchemaRecord {
+        guid: "4783b96f-6197-4058-a688-b4ba04c00962",
+        inputs: &[
+            pin("F", "Function", Item),
+            pin("a", "a", Item),
+            pin("b", "b", Item),
+            pin("c", "c", Item),
+            pin("d", "d", Item),
+            pin("x", "x", Item),
+            pin("y", "y", Item),
+        ],
+        outputs: &[pin("r", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "47886835-e3ff-4516-a3ed-1b419f055464",
+        inputs: &[
+            pin("A", "Point A", Item).of(ValueKind::Point),
+            pin("B", "Point B", Item).of(ValueKind::Point),
+            pin("C", "Point C", Item).of(ValueKind::Point),
+        ],
+        outputs: &[
+            pin("C", "Circle", Item).of(ValueKind::Curve),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "47c30f9d-b685-4d4d-9b20-5b60e48d5af8",
+        inputs: &[pin("I", "Domain", Item)],
+        outputs: &[
+            pin("U0", "U min", Item),
+            pin("U1", "U max", Item),
+            pin("V0", "V min", Item),
+            pin("V1", "V max", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "481e1f0d-a945-4662-809d-f49d1a8f40bd",
+        inputs: &[pin("A", "Base number", Item), pin("B", "Modulus", Item)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "481f0339-1299-43ba-b15c-c07891a8f822",
+        inputs: &[
+            pin("A", "Stream A", Tree),
+            pin("B", "Stream B", Tree),
+            pin("C", "Stream C", Tree),
+        ],
+        outputs: &[pin("S", "Stream", Item)],
+    },
+    SchemaRecord {
+        guid: "49584390-d541-41f7-b5f6-1f9515ac0f73",
+        inputs: &[pin("x", "Value", Item)],
+        outputs: &[pin("y", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "4a9e9a8e-0943-4438-b360-129c30f2bb0f",
+        inputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+        ],
+        outputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("uvP", "UV Point", Item),
+            pin("D", "Distance", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "4ad6703b-84cd-4957-a1b3-f1c6ec270d9c",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "4b04a1e1-cddf-405d-a7db-335aaa940541",
+        inputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("U", "U Count", Item).of(ValueKind::Number),
+            pin("I", "Interpolate", Item),
+        ],
+        outputs: &[
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+            pin("M", "Mesh", Item).of(ValueKind::Mesh),
+        ],
+    },
+    SchemaRecord {
+        guid: "4b3d38d3-0620-42e5-9ae8-0d4d9ad914cd",
+        inputs: &[
+            pin("L", "Location", Item),
+            pin("T", "Text", Item).of(ValueKind::Text),
+        ],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "4b59e893-d4ee-4e31-ae24-a489611d1088",
+        inputs: &[
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("S", "Strength", Item).of(ValueKind::Number),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+            pin("D", "Decay", Item),
+            pin("B", "Bounds", Item),
+        ],
+        outputs: &[pin("F", "Field", Item).of(ValueKind::Field)],
+    },
+    SchemaRecord {
+        guid: "4b5f79e1-c2b3-4b9c-b97d-470145a3ca74",
+        inputs: &[pin("G", "Geometry", Item)],
+        outputs: &[
+            pin("V", "Volume", Item).of(ValueKind::Number),
+            pin("C", "Centroid", Item).of(ValueKind::Point),
+            pin("I", "Inertia", Item),
+            pin("I±", "Inertia (error)", Item),
+            pin("S", "Secondary", Item),
+            pin("S±", "Secondary (error)", Item),
+            pin("G", "Gyration", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "4b87eb13-f87c-4ff1-ae0e-6c9f1f2aecbd",
+        inputs: &[
+            pin("S", "Shape", Item),
+            pin("B", "Blend", Item),
+            pin("M", "Metric", Item),
+            pin("E", "Edges", List),
+            pin("R", "Radii", List),
+        ],
+        outputs: &[pin("B", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "4bdc2eb0-24ed-4c90-a27b-a32db069eaef",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("B", "Brep", Item).of(ValueKind::Brep),
+        ],
+        outputs: &[
+            pin("C", "Curve", List).of(ValueKind::Curve),
+            pin("P", "Points", List).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "4beead95-8aa2-4613-8bb9-24758a0f5c4c",
+        inputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("B", "Brep", Item).of(ValueKind::Brep),
+        ],
+        outputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("N", "Normal", Item).of(ValueKind::Vector),
+            pin("D", "Distance", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "4bfe1bf6-fbc9-4ad2-bf28-a7402e1392ee",
+        inputs: &[
+            pin("Curves", "Curves", List).of(ValueKind::Curve),
+            pin("NodeSize", "NodeSize", List),
+            pin("SizePoints", "SizePoints", List),
+            pin("EndOffset", "EndOffset", Item),
+            pin("StrutSize", "StrutSize", Item),
+            pin("Segment", "Segment", Item),
+            pin("KinkAngle", "KinkAngle", Item),
+            pin("CubeFit", "CubeFit", Item),
+            pin("Caps", "Caps", Item),
+        ],
+        outputs: &[pin("P", "Pipe", Item)],
+    },
+    SchemaRecord {
+        guid: "4c02a168-9aba-4f42-8951-2719f24d391f",
+        inputs: &[
+            pin("M", "Mesh", Item).of(ValueKind::Mesh),
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("D", "Direction", Item).of(ValueKind::Vector),
+        ],
+        outputs: &[
+            pin("X", "Point", Item).of(ValueKind::Point),
+            pin("H", "Hit", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "4c4e56eb-2f04-43f9-95a3-cc46a14f495a",
+        inputs: &[pin("A", "Start Point", Item), pin("B", "End Point", Item)],
+        outputs: &[pin("L", "Line", Item).of(ValueKind::CurveLine)],
+    },
+    SchemaRecord {
+        guid: "4c619bc9-39fd-4717-82a6-1e07ea237bbe",
+        inputs: &[
+            pin("S", "Start", Item),
+            pin("D", "Direction", Item).of(ValueKind::Vector),
+            pin("L", "Length", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("L", "Line", Item).of(ValueKind::CurveLine)],
+    },
+    SchemaRecord {
+        guid: "4cebcaf7-9a6a-435b-8f8f-95a62bacb0f2",
+        inputs: &[
+            pin("M", "Matrix", Item).of(ValueKind::Matrix),
+            pin("A", "Column A", Item),
+            pin("B", "Column B", Item),
+        ],
+        outputs: &[pin("M", "Matrix", Item).of(ValueKind::Matrix)],
+    },
+    SchemaRecord {
+        guid: "4cfc0bb0-0745-4772-a520-39f9bf3d99bc",
+        inputs: &[pin("A", "Set A", List), pin("B", "Set B", List)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "4d2a06bd-4b0f-4c65-9ee0-4220e4c01703",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("C", "Center", Item).of(ValueKind::Point),
+            pin("F", "Factor", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("G", "Geometry", Item),
+            pin("X", "Transform", Item).of(ValueKind::Transform),
+        ],
+    },
+    SchemaRecord {
+        guid: "4df8df00-3635-45bd-95e6-f9206296c110",
+        inputs: &[
+            pin("T", "Text", Item).of(ValueKind::Text),
+            pin("F", "Find", Item),
+            pin("R", "Replace", Item),
+        ],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "4e86ba36-05e2-4cc0-a0f5-3ad57c91f04e",
+        inputs: &[pin("P", "Points", List).of(ValueKind::Point)],
+        outputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("I", "Indices", List).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "4e8aad42-9111-470c-9acd-7ae365d8bba4",
+        inputs: &[pin("x", "Input", Item)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "4f0dfac8-6c61-40ef-ad41-aad84533f382",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("C", "Center", Item).of(ValueKind::Point),
+            pin("F", "Factor", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("G", "Geometry", Item)],
+    },
+    SchemaRecord {
+        guid: "4f3147f4-9fcd-4a7e-be0e-b1841caa5f97",
+        inputs: &[
+            pin("A", "Meshes A", List).of(ValueKind::Mesh),
+            pin("B", "Meshes B", List).of(ValueKind::Mesh),
+        ],
+        outputs: &[pin("R", "Result", List)],
+    },
+    SchemaRecord {
+        guid: "4f8984c4-7c7a-4d69-b0a2-183cbb330d20",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "4fdfe351-6c07-47ce-9fb9-be027fb62186",
+        inputs: &[
+            pin("L", "List", List),
+            pin("S", "Shift", Item),
+            pin("W", "Wrap", Item).of(ValueKind::Boolean),
+        ],
+        outputs: &[pin("L", "List", List)],
+    },
+    SchemaRecord {
+        guid: "4fe828e8-fa95-4cc5-9a8c-c33856ecc783",
+        inputs: &[
+            pin("B", "Brep", Item).of(ValueKind::Brep),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[
+            pin("C", "Curves", List).of(ValueKind::Curve),
+            pin("P", "Points", List).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "501aecbb-c191-4d13-83d6-7ee32445ac50",
+        inputs: &[
+            pin("L", "List", List),
+            pin("I", "Indices", List).of(ValueKind::Number),
+            pin("W", "Wrap", Item).of(ValueKind::Boolean),
+        ],
+        outputs: &[pin("L", "List", List)],
+    },
+    SchemaRecord {
+        guid: "50870118-be51-4872-ab3c-410d79f2356e",
+        inputs: &[
+            pin("V", "Vertices", List).of(ValueKind::Point),
+            pin("D", "Degree", Item).of(ValueKind::Number),
+            pin("Ts", "Tangent Start", Item),
+            pin("Te", "Tangent End", Item),
+            pin("K", "KnotStyle", Item),
+        ],
+        outputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("L", "Length", Item).of(ValueKind::Number),
+            pin("D", "Domain", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "50b204ef-d3de-41bb-a006-02fba2d3f709",
+        inputs: &[
+            pin("A", "Curve A", Item).of(ValueKind::Curve),
+            pin("B", "Curve B", Item).of(ValueKind::Curve),
+            pin("P", "Point", Item).of(ValueKind::Point),
+        ],
+        outputs: &[pin("C", "Circle", Item).of(ValueKind::Curve)],
+    },
+    SchemaRecord {
+        guid: "50faccbd-9c92-4175-a5fa-d65e36013db6",
+        inputs: &[
+            pin("P", "Pattern", List),
+            pin("0", "Stream 0", List),
+            pin("1", "Stream 1", List),
+        ],
+        outputs: &[pin("W", "Weave", List)],
+    },
+    SchemaRecord {
+        guid: "5106bafc-d5d4-4983-83e7-7be3ed07f502",
+        inputs: &[
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+            pin("U", "U Count", Item).of(ValueKind::Number),
+            pin("V", "V Count", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("P", "Points", Tree).of(ValueKind::Point),
+            pin("N", "Normals", Tree).of(ValueKind::Vector),
+            pin("uv", "Parameters", Tree).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "510c4a63-b9bf-42e7-9d07-9d71290264da",
+        inputs: &[
+            pin("L", "Line", Item).of(ValueKind::CurveLine),
+            pin("A", "Plane A", Item).of(ValueKind::Plane),
+            pin("B", "Plane B", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[pin("L", "Line", Item).of(ValueKind::CurveLine)],
+    },
+    SchemaRecord {
+        guid: "5184b8cb-b71e-4def-a590-cd2c9bc58906",
+        inputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("D", "Direction", Item).of(ValueKind::Vector),
+            pin("G", "Geometry", List),
+        ],
+        outputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("I", "Index", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "51a2ede9-8f8c-4fdf-a375-999c2062eab7",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "51f61166-7202-45aa-9126-3d83055b269e",
+        inputs: &[pin("T", "Transform", Item).of(ValueKind::Transform)],
+        outputs: &[pin("T", "Transform", Item).of(ValueKind::Transform)],
+    },
+    SchemaRecord {
+        guid: "532b722d-9368-42ee-b99d-64a4732ee99a",
+        inputs: &[
+            pin("A", "First number", Item).of(ValueKind::Number),
+            pin("B", "Second number", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "537b0419-bbc2-4ff4-bf08-afe526367b2c",
+        inputs: &[pin("G", "Geometry", Item), pin("M", "Material", Item)],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "548177c2-d1db-4172-b667-bec979e2d38b",
+        inputs: &[pin("A", "A", Item), pin("B", "B", Item)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "54ac80cf-74f3-43f7-834c-0e3fe94632c6",
+        inputs: &[
+            pin("R", "Rows", Item),
+            pin("C", "Columns", Item),
+            pin("V", "Values", List),
+        ],
+        outputs: &[pin("M", "Matrix", Item).of(ValueKind::Matrix)],
+    },
+    SchemaRecord {
+        guid: "54db2568-3441-4ae2-bcef-92c4cc608e11",
+        inputs: &[pin("A", "A", Item), pin("B", "B", Item)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "55f9ce6a-490c-4f25-a536-a3d47b794752",
+        inputs: &[
+            pin("F", "Field", Item).of(ValueKind::Field),
+            pin("S", "Section", Item),
+            pin("N", "Samples", Item),
+        ],
+        outputs: &[pin("D", "Display", Item)],
+    },
+    SchemaRecord {
+        guid: "56b92eab-d121-43f7-94d3-6cd8f0ddead8",
+        inputs: &[
+            pin("X", "X component", Item),
+            pin("Y", "Y component", Item),
+            pin("Z", "Z component", Item),
+        ],
+        outputs: &[
+            pin("V", "Vector", Item).of(ValueKind::Vector),
+            pin("L", "Length", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "56c9c942-791f-4eeb-a4f0-82b93f1c0909",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "571ca323-6e55-425a-bf9e-ee103c7ba4b9",
+        inputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("C", "Cloud", List),
+        ],
+        outputs: &[
+            pin("P", "Closest Point", Item),
+            pin("i", "CP Index", Item),
+            pin("D", "Distance", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "5723c845-cafc-442d-a667-8c76532845e6",
+        inputs: &[
+            pin("A", "Breps A", List).of(ValueKind::Brep),
+            pin("B", "Breps B", List).of(ValueKind::Brep),
+        ],
+        outputs: &[pin("R", "Result", List)],
+    },
+    SchemaRecord {
+        guid: "57308b30-772d-4919-ac67-e86c18f3a996",
+        inputs: &[pin("A", "A", Item), pin("B", "B", Item)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "575660b1-8c79-4b8d-9222-7ab4a6ddb359",
+        inputs: &[
+            opt("P", "Plane", Item).of(ValueKind::Plane),
+            pin("A", "Point A", Item).of(ValueKind::Point),
+            pin("B", "Point B", Item).of(ValueKind::Point),
+            opt("R", "Radius", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("R", "Rectangle", Item).of(ValueKind::Curve),
+            pin("L", "Length", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "579c9f8c-6fb6-419b-8086-523a2dc99e8a",
+        inputs: &[pin("E", "Expression", Item)],
+        outputs: &[pin("V", "Value", Item)],
+    },
+    SchemaRecord {
+        guid: "57b2184c-8931-4e70-9220-612ec5b3809a",
+        inputs: &[
+            pin("C", "Curves", List).of(ValueKind::Curve),
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("S", "Spans", Item),
+            pin("F", "Flexibility", Item),
+            pin("T", "Trim", Item),
+        ],
+        outputs: &[pin("P", "Patch", Item)],
+    },
+    SchemaRecord {
+        guid: "57da07bd-ecab-415d-9d86-af36d7073abc",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "57e1d392-e3fb-4de9-be98-982854a92351",
+        inputs: &[
+            pin("N", "Numbers", List).of(ValueKind::Number),
+            pin("S", "Strength", Item).of(ValueKind::Number),
+            pin("I", "Iterations", Item),
+            pin("L", "Lock", Item),
+            pin("W", "Wrap", Item).of(ValueKind::Boolean),
+        ],
+        outputs: &[pin("N", "Numbers", List).of(ValueKind::Number)],
+    },
+    SchemaRecord {
+        guid: "5816ec9c-f170-4c59-ac44-364401ff84cd",
+        inputs: &[pin("C", "Curve", Item).of(ValueKind::Curve)],
+        outputs: &[
+            pin("p", "Planar", Item),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("D", "Deviation", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "582f96c6-ed0c-4710-9b5e-a05addba9f42",
+        inputs: &[pin("x", "Input", Item)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "58669268-a825-4688-8072-7d3508fcf91c",
+        inputs: &[
+            pin("A", "First number", Item).of(ValueKind::Number),
+            pin("B", "Second number", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "586706a8-109b-43ec-b581-743e920c951a",
+        inputs: &[
+            pin("N", "Numbers", List).of(ValueKind::Number),
+            pin("G", "Goal", Item),
+            pin("S", "Start", Item),
+        ],
+        outputs: &[pin("S", "Series", List), pin("R", "Remainder", Item)],
+    },
+    SchemaRecord {
+        guid: "5909dbcb-4950-4ce4-9433-7cf9e62ee011",
+        inputs: &[
+            pin("A", "Curve A", Item).of(ValueKind::Curve),
+            pin("B", "Curve B", Item).of(ValueKind::Curve),
+            opt("Fa", "Bulge A", Item),
+            opt("Fb", "Bulge B", Item),
+            pin("C", "Continuity", Item),
+        ],
+        outputs: &[pin("B", "Blend", Item)],
+    },
+    SchemaRecord {
+        guid: "59143f40-32f3-47c1-b9ae-1a09eb9c926b",
+        inputs: &[
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+            pin("U", "U Count", Item).of(ValueKind::Number),
+            pin("V", "V Count", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("F", "Frames", List).of(ValueKind::Plane),
+            pin("uv", "Parameters", List).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "595aded2-8916-402d-87a3-a825244bbe3d",
+        inputs: &[
+            pin("H", "Hour", Item),
+            pin("M", "Minute", Item),
+            pin("S", "Second", Item),
+        ],
+        outputs: &[pin("T", "Time", Item)],
+    },
+    SchemaRecord {
+        guid: "59aaebf8-6654-46b7-8386-89223c773978",
+        inputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+        ],
+        outputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("I", "Indices", List).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "59daf374-bc21-4a5e-8282-5504fb7ae9ae",
+        inputs: &[
+            pin("L", "List", List),
+            pin("i", "Index", Item).of(ValueKind::Number),
+            pin("W", "Wrap", Item).of(ValueKind::Boolean),
+        ],
+        outputs: &[pin("i", "Item", Item)],
+    },
+    SchemaRecord {
+        guid: "59e0b89a-e487-49f8-bab8-b5bab16be14c",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "59e1f848-38d4-4cbf-ad7f-40ffc52acdf5",
+        inputs: &[
+            pin("L", "Location", Item),
+            pin("T", "Time", Item),
+            pin("P", "Orientation", Item),
+        ],
+        outputs: &[
+            pin("D", "Direction", Item).of(ValueKind::Vector),
+            pin("E", "Elevation", Item),
+            pin("H", "Horizon", Item),
+            pin("C", "Colour", Item).of(ValueKind::Color),
+        ],
+    },
+    SchemaRecord {
+        guid: "59e94548-cefd-4774-b3de-48142fc783fb",
+        inputs: &[pin("P", "Polyline", Item).of(ValueKind::Curve)],
+        outputs: &[
+            pin("Cv", "Center(V)", Item),
+            pin("Ce", "Center(E)", Item),
+            pin("Ca", "Center(A)", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "5a13ec19-e4e9-43da-bf65-f93025fa87ca",
+        inputs: &[pin("A", "List (A)", List), pin("B", "List (B)", List)],
+        outputs: &[pin("A", "List (A)", List), pin("B", "List (B)", List)],
+    },
+    SchemaRecord {
+        guid: "5a22dc1a-907c-4e2f-b8da-0e496c4e25bb",
+        inputs: &[pin("x", "Input", Item)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "5a27203a-e05f-4eea-b80f-a5f29a00fdf2",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("P", "Base", Item),
+            pin("G", "Grip", Item),
+            pin("T", "Target", Item),
+        ],
+        outputs: &[
+            pin("G", "Geometry", Item),
+            pin("X", "Transform", Item).of(ValueKind::Transform),
+        ],
+    },
+    SchemaRecord {
+        guid: "5a4ddedd-5af9-49e5-bace-12910a8b9366",
+        inputs: &[
+            pin("A", "Corner A", Item).of(ValueKind::Point),
+            pin("B", "Corner B", Item).of(ValueKind::Point),
+            pin("C", "Corner C", Item).of(ValueKind::Point),
+        ],
+        outputs: &[pin("F", "Face", Item)],
+    },
+    SchemaRecord {
+        guid: "5a93246d-2595-4c28-bc2d-90657634f92a",
+        inputs: &[
+            pin("L", "List", List),
+            pin("S", "Size", List).of(ValueKind::Number),
+        ],
+        outputs: &[pin("C", "Chunks", Tree)],
+    },
+    SchemaRecord {
+        guid: "5b424e1c-d061-43cd-8c20-db84564b0502",
+        inputs: &[pin("N", "Numbers", Tree).of(ValueKind::Number)],
+        outputs: &[pin("N", "Numbers", Tree).of(ValueKind::Number)],
+    },
+    SchemaRecord {
+        guid: "5b742537-9bcb-4f06-9613-866da5bf845e",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("B", "Brep", List).of(ValueKind::Brep),
+        ],
+        outputs: &[
+            pin("C", "Curve", List).of(ValueKind::Curve),
+            pin("P", "Points", List).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "5b850221-b527-4bd6-8c62-e94168cd6efa",
+        inputs: &[pin("I", "Input", List)],
+        outputs: &[pin("R", "Result", Item), pin("Pr", "Partial Results", List)],
+    },
+    SchemaRecord {
+        guid: "5ba20fab-6d71-48ea-a98f-cb034db6bbdc",
+        inputs: &[
+            pin("F", "Field", Item).of(ValueKind::Field),
+            pin("S", "Section", Item),
+            pin("N", "Samples", Item),
+        ],
+        outputs: &[pin("D", "Display", Item)],
+    },
+    SchemaRecord {
+        guid: "5c270622-ee80-45a4-b07a-bd8ffede92a2",
+        inputs: &[
+            pin("C", "Curves", List).of(ValueKind::Curve),
+            pin("D", "Degree", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("S", "Surface", Item).of(ValueKind::Surface)],
+    },
+    SchemaRecord {
+        guid: "5c5fbc42-3e1d-4081-9cf1-148d0b1d9610",
+        inputs: &[
+            pin("P", "Polyline", Item).of(ValueKind::Curve),
+            opt("S", "Strength", Item).of(ValueKind::Number),
+            opt("T", "Times", Item),
+        ],
+        outputs: &[pin("P", "Polyline", Item).of(ValueKind::Curve)],
+    },
+    SchemaRecord {
+        guid: "5ca5de6b-bc71-46c4-a8f7-7f30d7040acb",
+        inputs: &[pin("A", "A", Item), pin("B", "B", Item)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "5cad70f9-5a53-4c5c-a782-54a479b4abe3",
+        inputs: &[pin("A", "A", Item), pin("B", "B", Item)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "5d192b90-1ae3-4439-bbde-b05976fc4ac3",
+        inputs: &[pin("S", "Source", Item), pin("T", "Target", Item)],
+        outputs: &[pin("S", "Surface", Item).of(ValueKind::Surface)],
+    },
+    SchemaRecord {
+        guid: "5d2fb801-2905-4a55-9d48-bbb22c73ad13",
+        inputs: &[pin("B", "Brep", Item).of(ValueKind::Brep)],
+        outputs: &[
+            pin("A", "Area", Item).of(ValueKind::Number),
+            pin("C", "Centroid", Item).of(ValueKind::Point),
+            pin("I", "Inertia", Item),
+            pin("I±", "Inertia (error)", Item),
+            pin("S", "Secondary", Item),
+            pin("S±", "Secondary (error)", Item),
+            pin("G", "Gyration", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "5db0fb89-4f22-4f09-a777-fa5e55aed7ec",
+        inputs: &[
+            pin("A", "First Number", Item).of(ValueKind::Number),
+            pin("B", "Second Number", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("=", "Equality", Item), pin("≠", "Inequality", Item)],
+    },
+    SchemaRecord {
+        guid: "5dd377ec-f6af-43f8-8e92-fc6669013e61",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "5e33c760-adcd-4235-b1dd-05cf72eb7a38",
+        inputs: &[
+            pin("A", "Curve A", Item).of(ValueKind::Curve),
+            pin("B", "Curve B", Item).of(ValueKind::Curve),
+        ],
+        outputs: &[pin("S", "Surface", Item).of(ValueKind::Surface)],
+    },
+    SchemaRecord {
+        guid: "5f127fa4-ca61-418e-bb2d-e3739d900f1f",
+        inputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("S", "System", Item),
+        ],
+        outputs: &[
+            pin("X", "X coordinate", Item).of(ValueKind::Number),
+            pin("Y", "Y coordinate", Item).of(ValueKind::Number),
+            pin("Z", "Z coordinate", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "5f212b16-82a0-4699-be4c-11529a9810ae",
+        inputs: &[pin("x", "Input", Item)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "5fa4e736-0d82-4af0-97fb-30a79f4cbf41",
+        inputs: &[pin("D", "Data", List), pin("S", "Stack", List)],
+        outputs: &[pin("D", "Data", List)],
+    },
+    SchemaRecord {
+        guid: "60103def-1bb7-4700-b294-3a89100525c4",
+        inputs: &[pin("x", "Value", Item)],
+        outputs: &[pin("y", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "610e689b-5adc-47b3-af8f-e3a32b7ea341",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("T", "Transform", Item).of(ValueKind::Transform),
+        ],
+        outputs: &[pin("G", "Geometry", Item)],
+    },
+    SchemaRecord {
+        guid: "61647ba2-31eb-4921-9632-df81e3286f7d",
+        inputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("S", "System", Item),
+        ],
+        outputs: &[
+            pin("P", "Phi", Item),
+            pin("T", "Theta", Item),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "61d81100-c4d3-462d-8b51-d951c0ae32db",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("S", "Source", Item),
+            pin("T", "Target", Item),
+        ],
+        outputs: &[
+            pin("G", "Geometry", Item),
+            pin("X", "Transform", Item).of(ValueKind::Transform),
+        ],
+    },
+    SchemaRecord {
+        guid: "62cc9684-6a39-422e-aefa-ed44643557b9",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("T", "Type", Item),
+            opt("L0", "Start", Item),
+            opt("L1", "End", Item),
+        ],
+        outputs: &[pin("C", "Curve", Item).of(ValueKind::Curve)],
+    },
+    SchemaRecord {
+        guid: "62d5ead4-53c4-4d0b-b5ce-6bd6e0850ab8",
+        inputs: &[pin("P", "Location", Item), pin("D", "Display", Item)],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "63d12974-2915-4ccf-ac26-5d566c3bac92",
+        inputs: &[pin("R", "Real", Item), pin("i", "Imaginary", Item)],
+        outputs: &[pin("C", "Complex", Item).of(ValueKind::Complex)],
+    },
+    SchemaRecord {
+        guid: "63f79e72-36c0-4489-a0c2-9ded0b9ca41f",
+        inputs: &[
+            pin("V", "Vectors", List).of(ValueKind::Vector),
+            opt("U", "Unitize", Item).of(ValueKind::Boolean),
+        ],
+        outputs: &[
+            pin("V", "Vector", Item).of(ValueKind::Vector),
+            pin("L", "Length", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "63fff845-7c61-4dfb-ba12-44d481b4bf0f",
+        inputs: &[
+            pin("V", "Vector", Item).of(ValueKind::Vector),
+            pin("F", "Factor", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("V", "Vector", Item).of(ValueKind::Vector),
+            pin("L", "Length", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "64ff9813-8fe8-4708-ac9f-61b825213e83",
+        inputs: &[
+            pin("B", "Brep", Item).of(ValueKind::Brep),
+            pin("D", "Directions", List),
+            pin("R", "Reflex", Item),
+            pin("A", "Angle", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("E", "Edges", List),
+            pin("I", "Indices", List).of(ValueKind::Number),
+            pin("M", "Map", List),
+        ],
+    },
+    SchemaRecord {
+        guid: "655c5f2f-1e40-42b8-a93a-f05032794449",
+        inputs: &[
+            pin("E", "Expression", Item),
+            pin("a", "Variable a", Item),
+            pin("b", "Variable b", Item),
+            pin("c", "Variable c", Item),
+            pin("x", "Variable x", Item),
+            pin("y", "Variable y", Item),
+            pin("z", "Variable z", Item),
+        ],
+        outputs: &[pin("V", "Value", Item)],
+    },
+    SchemaRecord {
+        guid: "6568e019-f59c-4984-84d6-96bd5bfbe9e7",
+        inputs: &[
+            pin("L", "List", List),
+            pin("I", "Indices", List).of(ValueKind::Number),
+        ],
+        outputs: &[pin("L", "List", List)],
+    },
+    SchemaRecord {
+        guid: "6587fcbf-e3cf-480a-b2f5-641794474194",
+        inputs: &[pin("F", "File", Item)],
+        outputs: &[pin("C", "Content", List)],
+    },
+    SchemaRecord {
+        guid: "66d2a68e-2f1d-43d2-a53b-c6a4d17e627b",
+        inputs: &[pin("C", "Curve", Item).of(ValueKind::Curve)],
+        outputs: &[
+            pin("C", "Polygon", Item),
+            pin("P", "Points", List).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "66eedc35-187d-4dab-b49b-408491b1255f",
+        inputs: &[
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("S", "Size", Item).of(ValueKind::Number),
+            pin("Er", "Extent R", Item),
+            pin("Ep", "Extent P", Item),
+        ],
+        outputs: &[
+            pin("C", "Cells", Tree),
+            pin("P", "Points", Tree).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "670fcdba-da07-4eb4-b1c1-bfa0729d767d",
+        inputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("S", "System", Item),
+        ],
+        outputs: &[
+            pin("X", "X coordinate", Item).of(ValueKind::Number),
+            pin("Y", "Y coordinate", Item).of(ValueKind::Number),
+            pin("Z", "Z coordinate", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "675e31bf-1775-48d7-bb8d-76b77786dd53",
+        inputs: &[pin("V", "Vector", Item).of(ValueKind::Vector)],
+        outputs: &[pin("L", "Length", Item).of(ValueKind::Number)],
+    },
+    SchemaRecord {
+        guid: "679a9c6a-ab97-4c20-b02c-680f9a9a1a44",
+        inputs: &[
+            pin("A", "Corner A", Item).of(ValueKind::Point),
+            pin("B", "Corner B", Item).of(ValueKind::Point),
+            pin("C", "Corner C", Item).of(ValueKind::Point),
+        ],
+        outputs: &[
+            pin("E", "Ellipse", Item),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+        ],
+    },
+    SchemaRecord {
+        guid: "68546dd0-aa82-471c-87e9-81cb16ac50ed",
+        inputs: &[
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+        ],
+        outputs: &[
+            pin("C", "Curves", List).of(ValueKind::Curve),
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("uv", "UV Points", List),
+            pin("N", "Normals", List).of(ValueKind::Vector),
+            pin("t", "Parameters", List).of(ValueKind::Number),
+            pin("T", "Tangents", List).of(ValueKind::Vector),
+        ],
+    },
+    SchemaRecord {
+        guid: "69f3e5ee-4770-44b3-8851-ae10ae555398",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("t", "Parameter", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("F", "Frame", Item).of(ValueKind::Plane)],
+    },
+    SchemaRecord {
+        guid: "6a9ccaab-1b03-484e-bbda-be9c81584a66",
+        inputs: &[
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+            pin("D", "Domain", Item),
+        ],
+        outputs: &[pin("S", "Surface", Item).of(ValueKind::Surface)],
+    },
+    SchemaRecord {
+        guid: "6aa8da2e-6f25-4585-8b37-aa44609beb46",
+        inputs: &[pin("C", "Content", List), pin("U", "Union", Item)],
+        outputs: &[pin("B", "Box", List)],
+    },
+    SchemaRecord {
+        guid: "6b021f56-b194-4210-b9a1-6cef3b7d0848",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("L", "Length", Item).of(ValueKind::Number),
+            opt("N", "Normalized", Item),
+        ],
+        outputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("T", "Tangent", Item).of(ValueKind::Vector),
+            pin("t", "Parameter", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "6b1bd8b2-47a4-4aa6-a471-3fd91c62a486",
+        inputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("C", "Colour", Item).of(ValueKind::Color),
+            pin("S", "Size", Item).of(ValueKind::Number),
+        ],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "6b2a5853-07aa-4329-ba84-0a5d46b51dbd",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("t", "Parameter", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("F", "Frame", Item).of(ValueKind::Plane)],
+    },
+    SchemaRecord {
+        guid: "6b5812f5-bb36-4d74-97fc-5a1f2f77452d",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+        ],
+        outputs: &[pin("C", "Curve", Item).of(ValueKind::Curve)],
+    },
+    SchemaRecord {
+        guid: "6b7ba278-5c9d-42f1-a61d-6209cbd44907",
+        inputs: &[
+            pin("A", "Curve A", Item).of(ValueKind::Curve),
+            pin("B", "Curve B", Item).of(ValueKind::Curve),
+        ],
+        outputs: &[
+            pin("A", "Point A", Item).of(ValueKind::Point),
+            pin("B", "Point B", Item).of(ValueKind::Point),
+            pin("D", "Distance", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "6d4b82a7-8c1d-4bec-af7b-ca321ba4beb1",
+        inputs: &[
+            pin("A", "Line 1", Item).of(ValueKind::CurveLine),
+            pin("B", "Line 2", Item).of(ValueKind::CurveLine),
+        ],
+        outputs: &[
+            pin("tA", "Param A", Item),
+            pin("tB", "Param B", Item),
+            pin("pA", "Point A", Item).of(ValueKind::Point),
+            pin("pB", "Point B", Item).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "6da4b70c-ce98-4d52-a2bb-2fadccf39da0",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("N", "Number", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("F", "Frames", Item).of(ValueKind::Plane),
+            pin("t", "Parameters", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "6da9f120-3ad0-4b6e-9fe0-f8cde3a649b7",
+        inputs: &[
+            pin("L0", "Lower limit", Item),
+            pin("L1", "Upper limit", Item),
+            pin("t", "Parameter", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("C", "Colour", Item).of(ValueKind::Color)],
+    },
+    SchemaRecord {
+        guid: "6db039c4-cad1-4549-bd45-e31cb0f71692",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "6e2ba21a-2252-42f4-8d3f-f5e0f49cc4ef",
+        inputs: &[
+            pin("L", "List", List),
+            pin("i", "Index", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("E", "Element", Item)],
+    },
+    SchemaRecord {
+        guid: "6e5de495-ba76-42d0-9985-a5c265e9aeca",
+        inputs: &[
+            pin("A", "Curve A", Item).of(ValueKind::Curve),
+            pin("B", "Curve B", Item).of(ValueKind::Curve),
+        ],
+        outputs: &[pin("S", "Surface", Item).of(ValueKind::Surface)],
+    },
+    SchemaRecord {
+        guid: "6e9c0577-ae4a-4b21-8880-0ec3daf3eb4d",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("N", "Count", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("T", "Tangents", List).of(ValueKind::Vector),
+            pin("t", "Parameters", List).of(ValueKind::Number),
+            pin("d", "Deviation", List).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "6eaffbb2-3392-441a-8556-2dc126aa8910",
+        inputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            opt("T", "Tolerance", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("I", "Indices", List).of(ValueKind::Number),
+            pin("V", "Valence", List),
+        ],
+    },
+    SchemaRecord {
+        guid: "6ec39468-dae7-4ffa-a766-f2ab22a2c62e",
+        inputs: &[
+            pin("V", "Vector", Item).of(ValueKind::Vector),
+            pin("A", "Amplitude", Item),
+        ],
+        outputs: &[pin("V", "Vector", Item).of(ValueKind::Vector)],
+    },
+    SchemaRecord {
+        guid: "6ec97ea8-c559-47a2-8d0f-ce80c794d1f4",
+        inputs: &[pin("L", "List", List)],
+        outputs: &[pin("L", "List", List)],
+    },
+    SchemaRecord {
+        guid: "6f0993e8-5f2f-4fc0-bd73-b84bc240e78e",
+        inputs: &[
+            pin("V", "Vertices", List).of(ValueKind::Point),
+            pin("D", "Degree", Item).of(ValueKind::Number),
+            pin("A", "Angle", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("L", "Length", Item).of(ValueKind::Number),
+            pin("D", "Domain", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "6f93d366-919f-4dda-a35e-ba03dd62799b",
+        inputs: &[pin("K", "Keys", List), pin("A", "Values A", List)],
+        outputs: &[pin("K", "Keys", List), pin("A", "Values A", List)],
+    },
+    SchemaRecord {
+        guid: "6fb21315-a032-400e-a80f-248687f5507f",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            opt("D", "Distance", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("C", "Curve", Item).of(ValueKind::Curve)],
+    },
+    SchemaRecord {
+        guid: "70905be1-e22f-4fa8-b9ae-e119d417904f",
+        inputs: &[
+            pin("B", "Brep", Item).of(ValueKind::Brep),
+            opt("T", "Tangency", Item),
+        ],
+        outputs: &[
+            pin("C", "Closed", List).of(ValueKind::Boolean),
+            pin("Ci", "Closed Indices", List),
+            pin("O", "Open", List),
+            pin("Oi", "Open Indices", List),
+        ],
+    },
+    SchemaRecord {
+        guid: "70ce4230-da08-4fce-b29d-63dc42a88585",
+        inputs: &[pin("T", "Tree", Tree), pin("X", "Invalid", Item)],
+        outputs: &[pin("D", "Data", Item)],
+    },
+    SchemaRecord {
+        guid: "71506fa8-9bf0-432d-b897-b2e0c5ac316c",
+        inputs: &[
+            pin("U", "Curves U", List).of(ValueKind::Curve),
+            pin("V", "Curves V", List).of(ValueKind::Curve),
+            pin("C", "Continuity", Item),
+        ],
+        outputs: &[pin("S", "Surface", Item).of(ValueKind::Surface)],
+    },
+    SchemaRecord {
+        guid: "7159ef59-e4ef-44b8-8cb2-91231e278292",
+        inputs: &[
+            pin("V", "Vertices", List).of(ValueKind::Point),
+            pin("T", "Tangent", Item).of(ValueKind::Vector),
+            pin("C", "Closed", Item).of(ValueKind::Boolean),
+        ],
+        outputs: &[pin("Crv", "PolyArc", Item)],
+    },
+    SchemaRecord {
+        guid: "717a1e25-a075-4530-bc80-d43ecc2500d9",
+        inputs: &[
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("S", "Size", Item).of(ValueKind::Number),
+            pin("Ex", "Extent X", Item),
+            pin("Ey", "Extent Y", Item),
+        ],
+        outputs: &[
+            pin("C", "Cells", Item),
+            pin("P", "Points", Tree).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "71b5b089-500a-4ea6-81c5-2f960441a0e8",
+        inputs: &[
+            pin("V", "Vertices", List).of(ValueKind::Point),
+            opt("C", "Closed", Item).of(ValueKind::Boolean),
+        ],
+        outputs: &[pin("Pl", "Polyline", Item).of(ValueKind::Curve)],
+    },
+    SchemaRecord {
+        guid: "71e99dbb-2d79-4f02-a8a6-e87a09d54f47",
+        inputs: &[
+            pin("B", "Brep", Item).of(ValueKind::Brep),
+            pin("P", "Points", List).of(ValueKind::Point),
+        ],
+        outputs: &[
+            pin("E", "Edges", List),
+            pin("I", "Indices", List).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "71fcc052-6add-4d70-8d97-cfb37ea9d169",
+        inputs: &[pin("S", "Stream", Tree), pin("G", "Gate", Item)],
+        outputs: &[pin("0", "Target 0", Tree), pin("1", "Target 1", Tree)],
+    },
+    SchemaRecord {
+        guid: "73269f6a-9645-4638-8d5e-88064dd289bd",
+        inputs: &[
+            pin("B", "Brep", Item).of(ValueKind::Brep),
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("V", "Valence", Item),
+            pin("T", "Tolerance", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("E", "Edges", List),
+            pin("I", "Indices", List).of(ValueKind::Number),
+            pin("M", "Map", List),
+        ],
+    },
+    SchemaRecord {
+        guid: "7376fe41-74ec-497e-b367-1ffe5072608b",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("D", "Density", Item),
+            pin("S", "Scale", Item),
+        ],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "748f214a-bc64-4556-9da5-4fa59a30c5c7",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("G", "Geometry", List),
+        ],
+        outputs: &[
+            pin("A", "Point A", Item).of(ValueKind::Point),
+            pin("B", "Point B", Item).of(ValueKind::Point),
+            pin("I", "Index", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "74cad441-2264-45fe-a57d-85034751208a",
+        inputs: &[pin("D", "Data", Tree)],
+        outputs: &[pin("-", "Branch 0", Tree), pin("-", "Branch 1", Tree)],
+    },
+    SchemaRecord {
+        guid: "74d95062-0bec-4a4e-9026-5141fca954a6",
+        inputs: &[pin("I", "Input", List)],
+        outputs: &[pin("R", "Result", Item), pin("Pr", "Partial Result", List)],
+    },
+    SchemaRecord {
+        guid: "75164624-395a-4d24-b60b-6bf91cab0194",
+        inputs: &[
+            pin("R¹", "Rail 1", Item),
+            pin("R²", "Rail 2", Item),
+            pin("S", "Sections", List),
+            pin("H", "Same Height", Item),
+        ],
+        outputs: &[pin("S", "Brep", List).of(ValueKind::Brep)],
+    },
+    SchemaRecord {
+        guid: "758d91a0-4aec-47f8-9671-16739a8a2c5d",
+        inputs: &[
+            pin("F", "Format", Item),
+            pin("C", "Culture", Item),
+            pin("0", "Data 0", Item),
+            pin("1", "Data 1", Item),
+        ],
+        outputs: &[pin("T", "Text", Item).of(ValueKind::Text)],
+    },
+    SchemaRecord {
+        guid: "75ac008b-1bc2-4edd-b967-667d628b9d24",
+        inputs: &[
+            pin("I", "Domain", Item),
+            pin("U", "U Count", Item).of(ValueKind::Number),
+            pin("V", "V Count", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("S", "Segments", List).of(ValueKind::Number)],
+    },
+    SchemaRecord {
+        guid: "75d0442c-1aa3-47cf-bd94-457b42c16e9f",
+        inputs: &[
+            pin("L", "Line", Item).of(ValueKind::CurveLine),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("t", "Param L", Item),
+            pin("uv", "Param P", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "75eb156d-d023-42f9-a85e-2f2456b8bcce",
+        inputs: &[
+            pin("V", "Vertices", List).of(ValueKind::Point),
+            pin("Ts", "Tangent Start", Item),
+            pin("Te", "Tangent End", Item),
+            pin("K", "KnotStyle", Item),
+        ],
+        outputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("L", "Length", Item).of(ValueKind::Number),
+            pin("D", "Domain", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "75eec078-a905-47a1-b0d2-0934182b1e3d",
+        inputs: &[
+            pin("B", "Base", Item),
+            pin("O", "Origin", Item).of(ValueKind::Point),
+        ],
+        outputs: &[pin("Pl", "Plane", Item).of(ValueKind::Plane)],
+    },
+    SchemaRecord {
+        guid: "75ef4190-91a2-42d9-a245-32a7162b0384",
+        inputs: &[
+            pin("I", "Domain", Item),
+            pin("C", "Count", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("S", "Segments", List).of(ValueKind::Number)],
+    },
+    SchemaRecord {
+        guid: "75f4b0fd-9721-47b1-99e7-9c098b342e67",
+        inputs: &[
+            pin("S", "Start Point", Item),
+            pin("Ts", "Start Tangent", Item),
+            pin("E", "End Point", Item),
+            pin("Te", "End Tangent", Item),
+            pin("R", "Ratio", Item),
+        ],
+        outputs: &[
+            pin("A1", "First arc", Item),
+            pin("A2", "Second arc", Item),
+            pin("B", "Bi-Arc", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "7663efbb-d9b8-4c6a-a0da-c3750a7bbe77",
+        inputs: &[pin("x", "Value", Item)],
+        outputs: &[pin("y", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "76975309-75a6-446a-afed-f8653720a9f2",
+        inputs: &[
+            pin("Kd", "Diffuse", Item),
+            pin("Ks", "Specular", Item),
+            pin("Ke", "Emission", Item),
+            pin("T", "Transparency", Item),
+            pin("S", "Shine", Item),
+        ],
+        outputs: &[pin("M", "Material", Item)],
+    },
+    SchemaRecord {
+        guid: "769f5b35-1780-4823-b593-118ecc3560e0",
+        inputs: &[
+            pin("S", "Sample", Item),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+            pin("O", "Obstacles", List),
+        ],
+        outputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("D", "Distance", Item).of(ValueKind::Number),
+            pin("H", "Hit", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "769f9064-17f5-4c4a-921f-c3a0ee05ba3a",
+        inputs: &[
+            pin("A", "Point A", Item).of(ValueKind::Point),
+            pin("B", "Point B", Item).of(ValueKind::Point),
+            pin("L", "Lengths", List),
+            pin("W", "Weights", List),
+            pin("G", "Gravity", Item),
+        ],
+        outputs: &[
+            pin("C", "Catenary", Item),
+            pin("S", "Segments", List).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "76f85ee4-5a88-4511-8ba7-30df07e50533",
+        inputs: &[
+            pin("B", "Base", Item),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+            pin("C", "Count", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("M", "Mesh", Item).of(ValueKind::Mesh)],
+    },
+    SchemaRecord {
+        guid: "773183d0-8c00-4fe4-a38c-f8d2408b7415",
+        inputs: &[
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("S", "Size", Item).of(ValueKind::Number),
+            pin("Er", "Extent R", Item),
+            pin("Ep", "Extent P", Item),
+        ],
+        outputs: &[
+            pin("C", "Cells", Tree),
+            pin("P", "Points", Tree).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "7753fb03-c1f1-4dbe-8557-f01e23aa3b20",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("X", "X", Item),
+            pin("Y", "Y", Item),
+            pin("Z", "Z", Item),
+        ],
+        outputs: &[
+            pin("G", "Geometry", Item),
+            pin("X", "Transform", Item).of(ValueKind::Transform),
+        ],
+    },
+    SchemaRecord {
+        guid: "77bfb6a1-0305-4645-b309-cd6dbf1205d7",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("P", "Base", Item),
+            pin("Ax", "Angle X", Item).of(ValueKind::Number),
+            pin("Ay", "Angle Y", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("G", "Geometry", Item)],
+    },
+    SchemaRecord {
+        guid: "78669f9c-4fea-44fd-ab12-2a69eeec58de",
+        inputs: &[
+            pin("A", "A", Item),
+            pin("B", "B", Item),
+            pin("C", "C", Item),
+        ],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "7874f26c-6f76-4da8-b527-2d567184b2bd",
+        inputs: &[pin("x", "Input", Item)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "78fed580-851b-46fe-af2f-6519a9d378e0",
+        inputs: &[pin("A", "A", Item), pin("B", "B", Item)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "79747717-1874-4c34-b790-faef53b50569",
+        inputs: &[
+            pin("X", "Style", Item),
+            pin("S", "Size", Item).of(ValueKind::Number),
+            pin("R", "Rotation", Item),
+            pin("C", "Colour", Item).of(ValueKind::Color),
+        ],
+        outputs: &[pin("D", "Symbol Display", Item)],
+    },
+    SchemaRecord {
+        guid: "7986486c-621a-48fb-8f27-a28a22c91cc9",
+        inputs: &[pin("I", "Input", List)],
+        outputs: &[pin("AM", "Arithmetic mean", Item)],
+    },
+    SchemaRecord {
+        guid: "7991bc5f-8a01-4768-bfb0-a39357ac6b84",
+        inputs: &[
+            pin("T", "Tree", Tree),
+            pin("X", "Clean Invalid", Item),
+            pin("E", "Clean Empty", Item),
+        ],
+        outputs: &[pin("T", "Tree", Tree)],
+    },
+    SchemaRecord {
+        guid: "79aa7f47-397c-4d3f-9761-aaf421bb7f5f",
+        inputs: &[
+            pin("B", "Base", Item),
+            pin("X", "X", Item),
+            pin("Y", "Y", Item),
+            pin("Z", "Z", Item),
+        ],
+        outputs: &[pin("B", "Box", Item)],
+    },
+    SchemaRecord {
+        guid: "79f9fbb3-8f1d-4d9a-88a9-f7961b1012cd",
+        inputs: &[pin("F", "Factor", Item).of(ValueKind::Number)],
+        outputs: &[pin("V", "Unit vector", Item)],
+    },
+    SchemaRecord {
+        guid: "7ab8d289-26a2-4dd4-b4ad-df5b477999d8",
+        inputs: &[
+            pin("V", "Number", Item).of(ValueKind::Number),
+            pin("B", "Base", Item),
+        ],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "7b181be1-30e7-4a97-915a-1b461741aef8",
+        inputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("N", "Neighbours", List),
+            pin("B", "Box", Item),
+        ],
+        outputs: &[pin("C", "Cell", Item)],
+    },
+    SchemaRecord {
+        guid: "7b312903-4782-438f-aa37-ba43f5083460",
+        inputs: &[pin("x", "Input", Item)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "7b371d04-53e3-47d8-b3dd-7b113c48bc59",
+        inputs: &[pin("A", "Atom", Item)],
+        outputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("E", "Element", Item),
+            pin("C", "Chain", Item),
+            pin("R", "Residue", Item),
+            pin("e", "Charge", Item),
+            pin("O", "Occupancy", Item),
+            pin("T", "Temperature", Item),
+            pin("AN", "Atomic Number", Item),
+            pin("SN", "Serial Number", Item),
+            pin("RN", "Residue Number", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "7bd7b551-ca79-4f01-b95a-7e9ab876f24d",
+        inputs: &[pin("P", "Polyline", Item).of(ValueKind::Curve)],
+        outputs: &[pin("C", "Center", Item).of(ValueKind::Point)],
+    },
+    SchemaRecord {
+        guid: "7c0523e8-79c9-45a2-8777-cf0d46bc5432",
+        inputs: &[pin("G", "Geometry", Item)],
+        outputs: &[
+            pin("V", "Volume", Item).of(ValueKind::Number),
+            pin("C", "Centroid", Item).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "7d2a6064-51f0-45b2-adc4-f417b30dcd15",
+        inputs: &[pin("C", "Complex", Item).of(ValueKind::Complex)],
+        outputs: &[pin("C", "Conjugate", Item)],
+    },
+    SchemaRecord {
+        guid: "7db14002-c09c-4d7b-9f80-e4e2b00dfa1d",
+        inputs: &[
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+            pin("C", "Curves", List).of(ValueKind::Curve),
+        ],
+        outputs: &[pin("F", "Fragments", List)],
+    },
+    SchemaRecord {
+        guid: "7e3185eb-a38c-4949-bcf2-0e80dee3a344",
+        inputs: &[pin("x", "Value", Item)],
+        outputs: &[pin("y", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "7f5c6c55-f846-4a08-9c9a-cfdc285cc6fe",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "7fa15783-70da-485c-98c0-a099e6988c3e",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "8073a420-6bec-49e3-9b18-367f6fd76ac3",
+        inputs: &[
+            pin("C", "Curves", List).of(ValueKind::Curve),
+            opt("P", "Preserve", Item),
+        ],
+        outputs: &[pin("C", "Curves", List).of(ValueKind::Curve)],
+    },
+    SchemaRecord {
+        guid: "807b86e3-be8d-4970-92b5-f8cdcb45b06b",
+        inputs: &[
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("C", "Circle", Item).of(ValueKind::Curve)],
+    },
+    SchemaRecord {
+        guid: "80da90e3-3ea9-4cfe-b7cc-2b6019f850e3",
+        inputs: &[pin("N", "Number", Item).of(ValueKind::Number)],
+        outputs: &[pin("F", "Factorial", Item)],
+    },
+    SchemaRecord {
+        guid: "80e55fc2-933b-4bfb-a353-12358786dba8",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            opt("D", "Distance", Item).of(ValueKind::Number),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[pin("C", "Curve", Item).of(ValueKind::Curve)],
+    },
+    SchemaRecord {
+        guid: "8102032b-9699-4949-ab12-3017a31d1062",
+        inputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("Pl", "Plane", Item).of(ValueKind::Plane),
+            pin("S", "Square", Item),
+            pin("G", "Group", Item),
+        ],
+        outputs: &[
+            pin("Q", "Quads", Tree),
+            pin("P", "Points", Tree).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "81800098-1060-4e2b-80d4-17f835cc825f",
+        inputs: &[pin("A", "Set A", List), pin("B", "Set B", List)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "81dfff08-0c83-4f1b-a358-14791d642d9e",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "81f6afc9-22d9-49f0-8579-1fd7e0df6fa6",
+        inputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            opt("D", "Distance", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("G", "Groups", Tree),
+            pin("I", "Indices", Tree).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "8248da39-0729-4e04-8395-267b3259bc2f",
+        inputs: &[pin("B", "Brep", Item).of(ValueKind::Brep)],
+        outputs: &[
+            pin("Cv", "Convex", List),
+            pin("Cc", "Concave", List),
+            pin("Mx", "Mixed", List),
+        ],
+    },
+    SchemaRecord {
+        guid: "825ea536-aebb-41e9-af32-8baeb2ecb590",
+        inputs: &[pin("I", "Domain", Item)],
+        outputs: &[pin("S", "Start", Item), pin("E", "End", Item)],
+    },
+    SchemaRecord {
+        guid: "82f19c48-9e73-43a4-ae6c-3a8368099b08",
+        inputs: &[pin("A", "Set A", List), pin("B", "Set B", List)],
+        outputs: &[pin("U", "Union", List)],
+    },
+    SchemaRecord {
+        guid: "83c81431-17bc-4bff-bb85-be0a846bd044",
+        inputs: &[pin("S", "SubD", Item)],
+        outputs: &[
+            pin("P", "Point", List).of(ValueKind::Point),
+            pin("C", "Count", List).of(ValueKind::Number),
+            pin("E", "Edges", List),
+            pin("V", "Vertices", List).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "8440fd1b-b6e0-4bdb-aa93-4ec295c213e9",
+        inputs: &[pin("A", "List (A)", List), pin("B", "List (B)", List)],
+        outputs: &[pin("A", "List (A)", List), pin("B", "List (B)", List)],
+    },
+    SchemaRecord {
+        guid: "845527a6-5cea-4ae9-a667-96ae1667a4e8",
+        inputs: &[
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+            pin("S", "Segments", Item).of(ValueKind::Number),
+            opt("Rf", "Fillet Radius", Item),
+        ],
+        outputs: &[
+            pin("P", "Polygon", Item),
+            pin("L", "Length", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "84627490-0fb2-4498-8138-ad134ee4cb36",
+        inputs: &[
+            pin("A", "Curve A", Item).of(ValueKind::Curve),
+            pin("B", "Curve B", Item).of(ValueKind::Curve),
+        ],
+        outputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("tA", "Params A", List),
+            pin("tB", "Params B", List),
+        ],
+    },
+    SchemaRecord {
+        guid: "846470bd-4918-4d00-9388-7e022b2cba73",
+        inputs: &[
+            pin("N", "Count", Item).of(ValueKind::Number),
+            pin("D", "Degree", Item).of(ValueKind::Number),
+            opt("P", "Periodic", Item).of(ValueKind::Boolean),
+        ],
+        outputs: &[pin("K", "Knots", List)],
+    },
+    SchemaRecord {
+        guid: "8465bcce-9e0a-4cf4-bbda-1a7ce5681e10",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("S", "Source", Item),
+            pin("T", "Target", Item),
+        ],
+        outputs: &[
+            pin("G", "Geometry", Item),
+            pin("X", "Transform", Item).of(ValueKind::Transform),
+        ],
+    },
+    SchemaRecord {
+        guid: "8529dbdf-9b6f-42e9-8e1f-c7a2bde56a70",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "8555a743-36c1-42b8-abcc-06d9cb94519f",
+        inputs: &[pin("U", "Domain U", Item), pin("V", "Domain V", Item)],
+        outputs: &[pin("I²", "2D Domain", Item)],
+    },
+    SchemaRecord {
+        guid: "855a2c73-31c0-41d2-b061-57d54229d11b",
+        inputs: &[
+            pin("M", "Mesh", Item).of(ValueKind::Mesh),
+            pin("Cr", "Creases", Item),
+            pin("Co", "Corners", Item),
+            pin("I", "Interpolate", Item),
+        ],
+        outputs: &[pin("S", "SubD", Item)],
+    },
+    SchemaRecord {
+        guid: "859daa86-3ab7-49cb-9eda-f2811c984070",
+        inputs: &[
+            pin("B", "Brep", List).of(ValueKind::Brep),
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("S", "Strict", Item).of(ValueKind::Boolean),
+        ],
+        outputs: &[
+            pin("I", "Inside", Item),
+            pin("i", "Index", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "8600a3fc-30f0-4df6-b126-aaa79ece5bfe",
+        inputs: &[
+            pin("M", "Matrix", Item).of(ValueKind::Matrix),
+            pin("A", "Row A", Item),
+            pin("B", "Row B", Item),
+        ],
+        outputs: &[pin("M", "Matrix", Item).of(ValueKind::Matrix)],
+    },
+    SchemaRecord {
+        guid: "8640c519-9bf6-4e9a-a108-75f9d89b2c58",
+        inputs: &[pin("x", "Input", Item)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "86503240-d884-43f9-9323-efe30488a6e1",
+        inputs: &[pin("T", "Text", Item).of(ValueKind::Text)],
+        outputs: &[pin("C", "Result", List), pin("U", "Unicode", List)],
+    },
+    SchemaRecord {
+        guid: "866222ee-6093-4af8-8944-2f9264885385",
+        inputs: &[
+            pin("Geometry", "Geometry", Item),
+            pin("Target", "Target", Item),
+            pin("Sharp", "Sharp", Item),
+            pin("Features", "Features", List),
+            pin("Length", "Length", Item).of(ValueKind::Number),
+            pin("Iters", "Iterations", Item),
+        ],
+        outputs: &[
+            pin("T", "Triangulation", Item),
+            pin("D", "Dual", Item),
+            pin("C", "Creases", List),
+        ],
+    },
+    SchemaRecord {
+        guid: "866ee39d-9ebf-4e1d-b209-324c56825605",
+        inputs: &[pin("B", "Brep", Item).of(ValueKind::Brep)],
+        outputs: &[
+            pin("FF", "Face|Face Adjacency", Tree),
+            pin("FE", "Face|Edge Adjacency", Tree),
+            pin("EF", "Edge|Face Adjacency", Tree),
+        ],
+    },
+    SchemaRecord {
+        guid: "86866576-6cc0-485a-9cd2-6f7d493f57f7",
+        inputs: &[pin("A", "Stream A", Tree), pin("B", "Stream B", Tree)],
+        outputs: &[pin("S", "Stream", Item)],
+    },
+    SchemaRecord {
+        guid: "86a9944b-dea5-4126-9433-9e95ff07927a",
+        inputs: &[
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("S", "Size", Item).of(ValueKind::Number),
+            pin("Ex", "Extent X", Item),
+            pin("Ey", "Extent Y", Item),
+        ],
+        outputs: &[
+            pin("C", "Cells", Tree),
+            pin("P", "Points", Tree).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "86b28a7e-94d9-4791-8306-e13e10d5f8d5",
+        inputs: &[pin("G", "Geometry", Item)],
+        outputs: &[
+            pin("A", "Area", Item).of(ValueKind::Number),
+            pin("C", "Centroid", Item).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "87391af3-35fe-4a40-b001-2bd4547ccd45",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "874eebe7-835b-4f4f-9811-97e031c41597",
+        inputs: &[pin("O", "Objects", List)],
+        outputs: &[pin("G", "Group", Item)],
+    },
+    SchemaRecord {
+        guid: "87df35c8-6e1d-4e2a-821a-7c1066714409",
+        inputs: &[
+            pin("C", "Content", List),
+            pin("U", "Union", Item),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[pin("B", "Box", List), pin("B", "Box", List)],
+    },
+    SchemaRecord {
+        guid: "87e1d9ef-088b-4d30-9dda-8a7448a17329",
+        inputs: &[pin("T", "Tree", Tree)],
+        outputs: &[pin("T", "Tree", Tree)],
+    },
+    SchemaRecord {
+        guid: "87e7f480-14dc-4478-b1e6-2b8b035d9edc",
+        inputs: &[pin("P", "Polyline", Item).of(ValueKind::Curve)],
+        outputs: &[pin("Cv", "Center(V)", Item), pin("Ce", "Center(E)", Item)],
+    },
+    SchemaRecord {
+        guid: "88060a82-0bf7-46bb-9af8-bdc860cf7e1d",
+        inputs: &[pin("M", "Meshes", List).of(ValueKind::Mesh)],
+        outputs: &[pin("R", "Result", List)],
+    },
+    SchemaRecord {
+        guid: "884646c3-0e70-4ad1-90c5-42601ee26450",
+        inputs: &[
+            pin("P", "Polyline", Item).of(ValueKind::Curve),
+            opt("T", "Tolerance", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("P", "Polyline", Item).of(ValueKind::Curve),
+            pin("R", "Reduction", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "888f9c3c-f1e1-4344-94b0-5ee6a45aee11",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("t", "Parameters", List).of(ValueKind::Number),
+            pin("R", "Radii", List),
+            pin("E", "Caps", Item),
+        ],
+        outputs: &[pin("P", "Pipe", List)],
+    },
+    SchemaRecord {
+        guid: "88cff285-7f5e-41b3-96d5-9588ff9a52b1",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("N", "Direction", Item).of(ValueKind::Vector),
+            pin("D", "Distance", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("C", "Contours", Tree),
+            pin("t", "Parameters", Tree).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "88fb33f9-f467-452b-a0e3-44bdb78a9b06",
+        inputs: &[pin("C", "Complex", Item).of(ValueKind::Complex)],
+        outputs: &[pin("M", "Modulus", Item)],
+    },
+    SchemaRecord {
+        guid: "89cd1a12-0007-4581-99ba-66578665e610",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "8a470a35-d673-4779-a65e-ba95765e59e4",
+        inputs: &[pin("T", "Tree", Tree)],
+        outputs: &[pin("0", "Branch 0", Tree), pin("1", "Branch 1", Tree)],
+    },
+    SchemaRecord {
+        guid: "8a55f680-cf53-4634-a486-b828de92b71d",
+        inputs: &[pin("A", "Set A", List), pin("B", "Set B", List)],
+        outputs: &[pin("U", "Union", List)],
+    },
+    SchemaRecord {
+        guid: "8a5aae11-8775-4ee5-b4fc-db3a1bd89c2f",
+        inputs: &[
+            pin("X", "X coordinate", Item).of(ValueKind::Number),
+            pin("Y", "Y coordinate", Item).of(ValueKind::Number),
+            pin("Z", "Z coordinate", Item).of(ValueKind::Number),
+            pin("S", "System", Item),
+        ],
+        outputs: &[pin("Pt", "Point", Item).of(ValueKind::Point)],
+    },
+    SchemaRecord {
+        guid: "8adbf481-7589-4a40-b490-006531ea001d",
+        inputs: &[
+            pin("B", "Boundary", Item),
+            pin("W", "Width count", Item),
+            pin("H", "Height count", Item),
+        ],
+        outputs: &[
+            pin("M", "Mesh", Item).of(ValueKind::Mesh),
+            pin("A", "Area", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "8b62751f-6fb4-4d03-a238-11ad6db7483e",
+        inputs: &[pin("x", "Input", Item)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "8cc3a196-f6a0-49ea-9ed9-0cb343a3ae64",
+        inputs: &[pin("O", "Origin", Item).of(ValueKind::Point)],
+        outputs: &[pin("P", "Plane", Item).of(ValueKind::Plane)],
+    },
+    SchemaRecord {
+        guid: "8cc9eb88-26a7-4baa-a896-13e5fc12416a",
+        inputs: &[
+            pin("L", "Line", Item).of(ValueKind::CurveLine),
+            pin("C", "Charge", Item),
+            pin("B", "Bounds", Item),
+        ],
+        outputs: &[pin("F", "Field", Item).of(ValueKind::Field)],
+    },
+    SchemaRecord {
+        guid: "8d058945-ce47-4e7c-82af-3269295d7890",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("N", "Count", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("F", "Frames", List).of(ValueKind::Plane),
+            pin("t", "Parameters", List).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "8d372bdc-9800-45e9-8a26-6e33c5253e21",
+        inputs: &[pin("B", "Brep", Item).of(ValueKind::Brep)],
+        outputs: &[
+            pin("F", "Faces", List),
+            pin("E", "Edges", List),
+            pin("V", "Vertices", List).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "8ec86459-bf01-4409-baee-174d0d2b13d0",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "8eed5d78-7810-4ba1-968e-8a1f1db98e39",
+        inputs: &[pin("A", "Set A", List), pin("B", "Set B", List)],
+        outputs: &[pin("U", "Union", List)],
+    },
+    SchemaRecord {
+        guid: "8efd5eb9-a896-486e-9f98-d8d1a07a49f3",
+        inputs: &[
+            pin("P", "Profile", Item),
+            pin("Po", "Orientation (P)", Item),
+            pin("A", "Axis", Item),
+            pin("Ao", "Orientation (A)", Item),
+        ],
+        outputs: &[pin("E", "Extrusion", Item)],
+    },
+    SchemaRecord {
+        guid: "902289da-28dc-454b-98d4-b8f8aa234516",
+        inputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("G", "Geometry", List),
+        ],
+        outputs: &[
+            pin("P", "Closest Point", Item),
+            pin("D", "Distance", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "9025f4ca-159f-4c54-958b-0aad379dae77",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[
+            pin("G", "Geometry", Item),
+            pin("X", "Transform", Item).of(ValueKind::Transform),
+        ],
+    },
+    SchemaRecord {
+        guid: "904e4b56-484a-4814-b35f-aa4baf362117",
+        inputs: &[
+            pin("A", "Brep A", Item).of(ValueKind::Brep),
+            pin("B", "Brep B", Item).of(ValueKind::Brep),
+        ],
+        outputs: &[
+            pin("C", "Curves", List).of(ValueKind::Curve),
+            pin("P", "Points", List).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "90744326-eb53-4a0e-b7ef-4b45f5473d6e",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "9083b87f-a98c-4e41-9591-077ae4220b19",
+        inputs: &[
+            pin("U0", "U min", Item),
+            pin("U1", "U max", Item),
+            pin("V0", "V min", Item),
+            pin("V1", "V max", Item),
+        ],
+        outputs: &[pin("I²", "2D Domain", Item)],
+    },
+    SchemaRecord {
+        guid: "9103c240-a6a9-4223-9b42-dbd19bf38e2b",
+        inputs: &[pin("F", "Factor", Item).of(ValueKind::Number)],
+        outputs: &[pin("V", "Unit vector", Item)],
+    },
+    SchemaRecord {
+        guid: "915f8f93-f5d1-4a7b-aecb-c327bab88ffb",
+        inputs: &[pin("T", "Transform", Item).of(ValueKind::Transform)],
+        outputs: &[pin("F", "Fragments", List)],
+    },
+    SchemaRecord {
+        guid: "916e7ebc-524c-47ce-8936-e50a09a7b43c",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("B", "Brep", List).of(ValueKind::Brep),
+        ],
+        outputs: &[pin("Ci", "Inside", List), pin("Co", "Outside", List)],
+    },
+    SchemaRecord {
+        guid: "919e146f-30ae-4aae-be34-4d72f555e7da",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "921775f7-bf22-4cfc-a4db-c415a56069c4",
+        inputs: &[pin("I", "Input", List)],
+        outputs: &[pin("R", "Result", Item), pin("Pr", "Partial Results", List)],
+    },
+    SchemaRecord {
+        guid: "922dc7e5-0f0e-4c21-ae4b-f6a8654e63f6",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            opt("t", "Tolerance", Item).of(ValueKind::Number),
+            opt("a", "Angle Tolerance", Item),
+        ],
+        outputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("S", "Simplified", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "92af1a02-9b87-43a0-8c45-0ce1b81555ec",
+        inputs: &[
+            pin("α", "Alpha", Item),
+            pin("β", "Beta", Item),
+            pin("γ", "Gamma", Item),
+            pin("A", "A length", Item),
+            pin("B", "B length", Item),
+            pin("C", "C length", Item),
+        ],
+        outputs: &[
+            pin("α", "Alpha", Item),
+            pin("β", "Beta", Item),
+            pin("γ", "Gamma", Item),
+            pin("A", "A length", Item),
+            pin("B", "B length", Item),
+            pin("C", "C length", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "931e6030-ccb3-4a7b-a89a-99dcce8770cd",
+        inputs: &[pin("C", "Curves", List).of(ValueKind::Curve)],
+        outputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("iA", "Index A", List).of(ValueKind::Number),
+            pin("iB", "Index B", List).of(ValueKind::Number),
+            pin("tA", "Param A", List),
+            pin("tB", "Param B", List),
+        ],
+    },
+    SchemaRecord {
+        guid: "9333c5b3-11f9-423c-bbb5-7e5156430219",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("D", "Degree", Item).of(ValueKind::Number),
+            pin("N", "Count", Item).of(ValueKind::Number),
+            pin("T", "Tangents", Item).of(ValueKind::Vector),
+        ],
+        outputs: &[pin("C", "Curve", Item).of(ValueKind::Curve)],
+    },
+    SchemaRecord {
+        guid: "934ede4a-924a-4973-bb05-0dc4b36fae75",
+        inputs: &[
+            pin("A", "Point A", Item).of(ValueKind::Point),
+            pin("B", "Point B", Item).of(ValueKind::Point),
+            opt("U", "Unitize", Item).of(ValueKind::Boolean),
+        ],
+        outputs: &[
+            pin("V", "Vector", Item).of(ValueKind::Vector),
+            pin("L", "Length", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "9396be03-8159-43bf-b3e7-2c86c8d04fc0",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("L", "Line", Item).of(ValueKind::CurveLine),
+            pin("F", "First", Item),
+        ],
+        outputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("t", "Params", List),
+            pin("N", "Count", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "93b1066f-060e-440d-a638-aae8cbe7acb7",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("N", "Number", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("P", "Points", Item).of(ValueKind::Point),
+            pin("T", "Tangents", Item).of(ValueKind::Vector),
+            pin("t", "Parameters", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "93b8e93d-f932-402c-b435-84be04d87666",
+        inputs: &[
+            pin("A", "Point A", Item).of(ValueKind::Point),
+            pin("B", "Point B", Item).of(ValueKind::Point),
+        ],
+        outputs: &[pin("D", "Distance", Item).of(ValueKind::Number)],
+    },
+    SchemaRecord {
+        guid: "93d0dcbc-6207-4745-aaf7-fe57a880f959",
+        inputs: &[
+            pin("S", "Sample", Item),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+            pin("O", "Obstacles", List),
+        ],
+        outputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("D", "Distance", Item).of(ValueKind::Number),
+            pin("I", "Index", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "9445ca40-cc73-4861-a455-146308676855",
+        inputs: &[pin("D", "Domain", Item), pin("N", "Steps", Item)],
+        outputs: &[pin("R", "Range", List)],
+    },
+    SchemaRecord {
+        guid: "946cb61e-18d2-45e3-8840-67b0efa26528",
+        inputs: &[pin("I", "Indices", List).of(ValueKind::Number)],
+        outputs: &[pin("B", "Branch", Item)],
+    },
+    SchemaRecord {
+        guid: "954a8963-bb2c-4847-9012-69ff34acddd5",
+        inputs: &[
+            pin("S", "SubD", Item),
+            pin("T", "Vertex Tag", Item),
+            pin("V", "Vertex IDs", List),
+        ],
+        outputs: &[pin("S", "SubD", Item)],
+    },
+    SchemaRecord {
+        guid: "955d887b-c83b-4c61-bf35-df5d4c4abd9b",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("A", "Angle", Item).of(ValueKind::Number),
+            pin("C", "Center", Item).of(ValueKind::Point),
+            pin("X", "Axis", Item),
+        ],
+        outputs: &[pin("G", "Geometry", Item)],
+    },
+    SchemaRecord {
+        guid: "95866bbe-648e-4e2b-a97c-7d04679e94e0",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("Pt", "Pattern", List),
+        ],
+        outputs: &[pin("D", "Dashes", List), pin("G", "Gaps", List)],
+    },
+    SchemaRecord {
+        guid: "95992b33-89e1-4d36-bd35-2754a11af21e",
+        inputs: &[
+            pin("N", "Numbers", List).of(ValueKind::Number),
+            opt("A", "Additive", Item),
+        ],
+        outputs: &[pin("D", "Domains", List)],
+    },
+    SchemaRecord {
+        guid: "95aef4f6-66fc-477e-b8f8-32395a837831",
+        inputs: &[
+            pin("A", "Meshes A", List).of(ValueKind::Mesh),
+            pin("B", "Meshes B", List).of(ValueKind::Mesh),
+        ],
+        outputs: &[pin("R", "Result", List)],
+    },
+    SchemaRecord {
+        guid: "962034e9-cc27-4394-afc4-5c16e3447cf9",
+        inputs: &[
+            pin("B", "Base", Item),
+            pin("D", "Direction", Item).of(ValueKind::Vector),
+        ],
+        outputs: &[pin("E", "Extrusion", Item)],
+    },
+    SchemaRecord {
+        guid: "9624aeeb-f2a1-49da-b1c7-8789db217177",
+        inputs: &[
+            pin("V", "Values", List),
+            pin("S", "Source", Item),
+            pin("T", "Target", Item),
+        ],
+        outputs: &[pin("R", "Result", List)],
+    },
+    SchemaRecord {
+        guid: "96c8c5f2-5f8e-4bb3-b19f-eb61d9cefa46",
+        inputs: &[pin("A", "Base number", Item), pin("B", "Exponent", Item)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "983c7600-980c-44da-bc53-c804067f667f",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("N", "Count", Item).of(ValueKind::Number),
+            opt("A", "Align", Item),
+        ],
+        outputs: &[
+            pin("F", "Frames", List).of(ValueKind::Plane),
+            pin("t", "Parameters", List).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "98c3c63a-e78a-43ea-a111-514fcf312c95",
+        inputs: &[pin("L", "List", List)],
+        outputs: &[pin("S", "Set", List), pin("M", "Map", List)],
+    },
+    SchemaRecord {
+        guid: "99197a17-d5c7-419b-acde-eca2737f3c58",
+        inputs: &[pin("x", "Input", Item)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "99bee19d-588c-41a0-b9b9-1d00fb03ea1a",
+        inputs: &[pin("T", "Tree", Tree)],
+        outputs: &[
+            pin("P", "Paths", List),
+            pin("L", "Length", List).of(ValueKind::Number),
+            pin("C", "Count", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "9ab93e1a-ebdf-4090-9296-b000cff7b202",
+        inputs: &[
+            pin("L", "List", List),
+            pin("i", "Index", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("A", "List A", List), pin("B", "List B", List)],
+    },
+    SchemaRecord {
+        guid: "9abae6b7-fa1d-448c-9209-4a8155345841",
+        inputs: &[pin("P", "Point", Item).of(ValueKind::Point)],
+        outputs: &[
+            pin("X", "X component", Item),
+            pin("Y", "Y component", Item),
+            pin("Z", "Z component", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "9adffd61-f5d1-4e9e-9572-e8d9145730dc",
+        inputs: &[
+            pin("A", "Point A", Item).of(ValueKind::Point),
+            pin("B", "Point B", Item).of(ValueKind::Point),
+            pin("C", "Point C", Item).of(ValueKind::Point),
+            pin("U", "Coordinate U", Item),
+            pin("V", "Coordinate V", Item),
+            pin("W", "Coordinate W", Item),
+        ],
+        outputs: &[pin("P", "Point", Item).of(ValueKind::Point)],
+    },
+    SchemaRecord {
+        guid: "9aef6eb4-98c3-4b0e-b875-1a7cb1bb1038",
+        inputs: &[
+            pin("A", "Point A", Item).of(ValueKind::Point),
+            pin("B", "Point B", Item).of(ValueKind::Point),
+        ],
+        outputs: &[pin("B", "Box", Item)],
+    },
+    SchemaRecord {
+        guid: "9bc98a1d-2ecc-407e-948a-09a09ed3e69d",
+        inputs: &[
+            pin("A", "Point A", Item).of(ValueKind::Point),
+            pin("B", "Point B", Item).of(ValueKind::Point),
+            pin("C", "Point C", Item).of(ValueKind::Point),
+        ],
+        outputs: &[
+            pin("R", "Rectangle", Item).of(ValueKind::Curve),
+            pin("L", "Length", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "9c007a04-d0d9-48e4-9da3-9ba142bc4d46",
+        inputs: &[pin("A", "A", Item), pin("B", "B", Item)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "9c53bac0-ba66-40bd-8154-ce9829b9db1a",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "9c85271f-89fa-4e9f-9f4a-d75802120ccc",
+        inputs: &[pin("A", "A", Item), pin("B", "B", Item)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "9ce34996-d8c6-40d3-b442-1a7c8c093614",
+        inputs: &[
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("N", "Normal", Item).of(ValueKind::Vector),
+        ],
+        outputs: &[pin("P", "Plane", Item).of(ValueKind::Plane)],
+    },
+    SchemaRecord {
+        guid: "9d0c5284-ea24-4f9f-a183-ef57fc48b5b8",
+        inputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("Pl", "Plane", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[
+            pin("H", "Hull", Item),
+            pin("Hz", "Hull(z)", Item),
+            pin("I", "Indices", List).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "9d2583dd-6cf5-497c-8c40-c9a290598396",
+        inputs: &[
+            pin("S", "Start", Item),
+            pin("E", "End", Item),
+            pin("D", "Direction", Item).of(ValueKind::Vector),
+        ],
+        outputs: &[
+            pin("A", "Arc", Item).of(ValueKind::Curve),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "9d375779-649d-49f1-baaf-04560a51cd3d",
+        inputs: &[pin("C", "Content", Item)],
+        outputs: &[pin("B", "Box", Item)],
+    },
+    SchemaRecord {
+        guid: "9d4854fe-70db-4863-967b-4120d0b6d2e4",
+        inputs: &[
+            pin("B", "Boundary", Item),
+            pin("G1", "Generation 1", List),
+            pin("G2", "Generation 2", List),
+        ],
+        outputs: &[pin("D1", "Diagram 1", List), pin("D2", "Diagram 2", List)],
+    },
+    SchemaRecord {
+        guid: "9d8dec9c-3fd1-481c-9c3d-75ea5e15eb1a",
+        inputs: &[
+            pin("A", "Arc", Item).of(ValueKind::Curve),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+            pin("A", "Angle", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("A", "Arc", Item).of(ValueKind::Curve)],
+    },
+    SchemaRecord {
+        guid: "9ebccbb4-f3e3-4ee1-af31-2f301f2516f0",
+        inputs: &[pin("A", "Base number", Item), pin("B", "Modulus", Item)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "9f6f954c-ba7b-4428-bf1e-1768cdff666c",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("C", "Cell", Item),
+            pin("X", "X Count", Item),
+            pin("Y", "Y Count", Item),
+            pin("Z", "Z Count", Item),
+        ],
+        outputs: &[
+            pin("G", "Geometry", List),
+            pin("X", "Transform", List).of(ValueKind::Transform),
+        ],
+    },
+    SchemaRecord {
+        guid: "9fa1b081-b1c7-4a12-a163-0aa8da9ff6c4",
+        inputs: &[
+            pin("A", "Point A", Item).of(ValueKind::Point),
+            pin("B", "Point B", Item).of(ValueKind::Point),
+            pin("C", "Point C", Item).of(ValueKind::Point),
+        ],
+        outputs: &[
+            pin("A", "Arc", Item).of(ValueKind::Curve),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "a0a38131-c5fc-4984-b05d-34cf57f0c018",
+        inputs: &[pin("N", "Number", Item).of(ValueKind::Number)],
+        outputs: &[pin("F", "Factorial", Item)],
+    },
+    SchemaRecord {
+        guid: "a0d62394-a118-422d-abb3-6af115c75b25",
+        inputs: &[pin("A", "A", Item), pin("B", "B", Item)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "a10e8cdf-7c7a-4aac-aa70-ddb7010ab231",
+        inputs: &[pin("B", "Box", Item)],
+        outputs: &[
+            pin("A", "Corner A", Item).of(ValueKind::Point),
+            pin("B", "Corner B", Item).of(ValueKind::Point),
+            pin("C", "Corner C", Item).of(ValueKind::Point),
+            pin("D", "Corner D", Item).of(ValueKind::Point),
+            pin("E", "Corner E", Item).of(ValueKind::Point),
+            pin("F", "Corner F", Item).of(ValueKind::Point),
+            pin("G", "Corner G", Item).of(ValueKind::Point),
+            pin("H", "Corner H", Item).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "a13fcd5d-81af-4337-a32e-28dd7e23ae4c",
+        inputs: &[pin("D", "Data", Tree)],
+        outputs: &[pin("D", "Data", Tree)],
+    },
+    SchemaRecord {
+        guid: "a1c16251-74f0-400f-9e7c-5e379d739963",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("P", "Parameter", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("L-", "Length", Item).of(ValueKind::Number),
+            pin("L+", "Length", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "a1da39b7-6387-4522-bf2b-2eaee6b14072",
+        inputs: &[pin("S", "Source", Item), pin("T", "Target", Item)],
+        outputs: &[pin("S", "Surface", Item).of(ValueKind::Surface)],
+    },
+    SchemaRecord {
+        guid: "a2d9503d-a83c-4d71-81e0-02af8d09cd0c",
+        inputs: &[pin("x", "Value", Item)],
+        outputs: &[pin("y", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "a3371040-e552-4bc8-b0ff-10a840258e88",
+        inputs: &[pin("x", "Value", Item)],
+        outputs: &[pin("y", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "a35811bc-1034-4491-acb8-608a8cfa27b1",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("A", "Initial", Item),
+            pin("B", "Final", Item),
+        ],
+        outputs: &[pin("G", "Geometry", Item)],
+    },
+    SchemaRecord {
+        guid: "a3f9f19e-3e6c-4ac7-97c3-946de32c3e8e",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            opt("D", "Degree", Item).of(ValueKind::Number),
+            opt("Ft", "Tolerance", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("C", "Curve", Item).of(ValueKind::Curve)],
+    },
+    SchemaRecord {
+        guid: "a4011be0-1c91-45bd-8280-17dd3a9f46f1",
+        inputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+            pin("B", "Boundary", Item),
+            pin("Pl", "Plane", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[pin("C", "Cells", List)],
+    },
+    SchemaRecord {
+        guid: "a435f5c8-28a2-43e8-a52a-0b6e73c2e300",
+        inputs: &[
+            pin("P", "Base plane", Item),
+            pin("xy", "XY angle", Item),
+            pin("z", "Z angle", Item),
+            pin("d", "Offset", Item),
+        ],
+        outputs: &[pin("Pt", "Point", Item).of(ValueKind::Point)],
+    },
+    SchemaRecord {
+        guid: "a45f59c8-11c1-4ea7-9e10-847061b80d75",
+        inputs: &[pin("G", "Group", Item)],
+        outputs: &[pin("O", "Objects", List)],
+    },
+    SchemaRecord {
+        guid: "a4b285fe-2e13-4204-b65c-189aa6704da5",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "a4cd2751-414d-42ec-8916-476ebf62d7fe",
+        inputs: &[pin("D", "Degrees", Item)],
+        outputs: &[pin("R", "Radians", Item)],
+    },
+    SchemaRecord {
+        guid: "a50c4a3b-0177-4c91-8556-db95de6c56c8",
+        inputs: &[pin("x", "Number", Item).of(ValueKind::Number)],
+        outputs: &[
+            pin("N", "Nearest", Item),
+            pin("F", "Floor", Item),
+            pin("C", "Ceiling", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "a50fcd4a-cf42-4c3f-8616-022761e6cc93",
+        inputs: &[pin("V", "Vector", Item).of(ValueKind::Vector)],
+        outputs: &[
+            pin("X", "X component", Item),
+            pin("Y", "Y component", Item),
+            pin("Z", "Z component", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "a559fee2-4b76-4370-8042-c7440cd75049",
+        inputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("M", "Mesh", Item).of(ValueKind::Mesh),
+        ],
+        outputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("I", "Index", Item).of(ValueKind::Number),
+            pin("P", "Parameter", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "a583f722-240a-4fc9-aa1d-021720a4516a",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            opt("Min", "Minimum", Item).of(ValueKind::Number),
+            opt("Max", "Maximum", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("tMin", "Minimum Parameter", Item),
+            pin("dMin", "Minimum Depth", Item),
+            pin("tMax", "Maximum Parameter", Item),
+            pin("dMax", "Maximum Depth", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "a59a68ad-fdd6-41dd-88f0-d7a6fb8d2e16",
+        inputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("S", "Square", Item),
+            pin("G", "Group", Item),
+        ],
+        outputs: &[
+            pin("B", "Boxes", Tree),
+            pin("P", "Points", Tree).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "a5e4f966-417e-465d-afa9-f6607afea056",
+        inputs: &[pin("V", "Vertices", List).of(ValueKind::Point)],
+        outputs: &[pin("Crv", "PolyArc", Item)],
+    },
+    SchemaRecord {
+        guid: "a70aa477-0109-4e75-ba73-78725dca0274",
+        inputs: &[
+            pin("A", "Stream A", Tree),
+            pin("B", "Stream B", Tree),
+            pin("C", "Stream C", Tree),
+            pin("D", "Stream D", Tree),
+            pin("E", "Stream E", Tree),
+            pin("F", "Stream F", Tree),
+            pin("G", "Stream G", Tree),
+            pin("H", "Stream H", Tree),
+        ],
+        outputs: &[pin("S", "Stream", Item)],
+    },
+    SchemaRecord {
+        guid: "a72b0bd3-c7a7-458e-875d-09ae1624638c",
+        inputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+        ],
+        outputs: &[
+            pin("R", "Relationship", Item),
+            pin("P'", "Point", Item).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "a7a41d0a-2188-4f7a-82cc-1a2c4e4ec850",
+        inputs: &[
+            pin("C", "Curves", List).of(ValueKind::Curve),
+            pin("O", "Options", Item),
+        ],
+        outputs: &[pin("L", "Loft", Item)],
+    },
+    SchemaRecord {
+        guid: "a7c9f738-f8bd-4f64-8e7f-33341183e493",
+        inputs: &[
+            pin("F", "Field", Item).of(ValueKind::Field),
+            pin("P", "Point", Item).of(ValueKind::Point),
+        ],
+        outputs: &[
+            pin("T", "Tensor", Item),
+            pin("S", "Strength", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "a80395af-f134-4d6a-9b89-15edf3161619",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "a834e823-ae01-44d8-9066-c138eeb6f391",
+        inputs: &[
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+            pin("L", "Line", Item).of(ValueKind::CurveLine),
+        ],
+        outputs: &[
+            pin("C", "Curves", List).of(ValueKind::Curve),
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("uv", "UV Points", List),
+            pin("N", "Normal", List).of(ValueKind::Vector),
+        ],
+    },
+    SchemaRecord {
+        guid: "a8b97322-2d53-47cd-905e-b932c3ccd74e",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "a8bc9c24-1bce-4b92-b7ba-abced2457c22",
+        inputs: &[pin("x", "Input", Item)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "a8de2000-073d-412d-a0b2-3a4894ba71f8",
+        inputs: &[pin("I", "Input", List), pin("t", "Truncation factor", Item)],
+        outputs: &[pin("T", "Result", List)],
+    },
+    SchemaRecord {
+        guid: "aa1dc107-70de-473e-9636-836030160fc3",
+        inputs: &[
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+            pin("uv", "Point", Item).of(ValueKind::Point),
+        ],
+        outputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("N", "Normal", Item).of(ValueKind::Vector),
+            pin("F", "Frame", Item).of(ValueKind::Plane),
+        ],
+    },
+    SchemaRecord {
+        guid: "aa333235-5922-424c-9002-1e0b866a854b",
+        inputs: &[
+            pin("P", "Base plane", Item),
+            pin("U", "U component", Item),
+            pin("V", "V component", Item),
+            opt("W", "W component", Item),
+        ],
+        outputs: &[pin("Pt", "Point", Item).of(ValueKind::Point)],
+    },
+    SchemaRecord {
+        guid: "aa538b89-3df8-436f-9ae4-bc44525984de",
+        inputs: &[pin("F", "File", Item)],
+        outputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("C", "Curves", List).of(ValueKind::Curve),
+            pin("R", "Regions", List),
+        ],
+    },
+    SchemaRecord {
+        guid: "aaa665bd-fd6e-4ccb-8d2c-c5b33072125d",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("t", "Parameter", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("K", "Curvature", Item),
+            pin("C", "Curvature", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "aab142b1-b870-46de-8e86-654c9a554d90",
+        inputs: &[pin("F", "Face", Item)],
+        outputs: &[
+            pin("A", "Corner A", Item).of(ValueKind::Point),
+            pin("B", "Corner B", Item).of(ValueKind::Point),
+            pin("C", "Corner C", Item).of(ValueKind::Point),
+            pin("D", "Corner D", Item).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "ab14760f-87a6-462e-b481-4a2c26a9a0d7",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("t", "Parameter", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("1", "First derivative", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "ab34845d-4ab9-4ff4-8870-eedd0c5594cb",
+        inputs: &[pin("A", "Set A", List), pin("B", "Set B", List)],
+        outputs: &[pin("U", "Union", List)],
+    },
+    SchemaRecord {
+        guid: "ab454a50-debf-46d1-9bd1-82648416a802",
+        inputs: &[
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("G1", "Generation 1", List),
+            pin("G2", "Generation 2", List),
+        ],
+        outputs: &[pin("D1", "Diagram 1", List), pin("D2", "Diagram 2", List)],
+    },
+    SchemaRecord {
+        guid: "ab766b01-a3f5-4257-831a-fc84d7b288b4",
+        inputs: &[pin("B", "Brep", Item).of(ValueKind::Brep)],
+        outputs: &[
+            pin("A", "Area", Item).of(ValueKind::Number),
+            pin("C", "Centroid", Item).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "ab898d46-b8b3-4ed5-b28f-4f8047920262",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "abf9c670-5462-4cd8-acb3-f1ab0256dbf3",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "ac2bc2cb-70fb-4dd5-9c78-7e1ea97fe278",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "ac750e41-2450-4f98-9658-98fef97b01b2",
+        inputs: &[
+            pin("B", "Brep", Item).of(ValueKind::Brep),
+            pin("D", "Density", Item),
+        ],
+        outputs: &[pin("W", "Wireframe", List)],
+    },
+    SchemaRecord {
+        guid: "ac9b4faf-c9d5-4f6a-a5e9-58c0c2cac116",
+        inputs: &[
+            pin("A", "Stream A", Tree),
+            pin("B", "Stream B", Tree),
+            pin("C", "Stream C", Tree),
+            pin("D", "Stream D", Tree),
+            pin("E", "Stream E", Tree),
+            pin("F", "Stream F", Tree),
+        ],
+        outputs: &[pin("S", "Stream", Item)],
+    },
+    SchemaRecord {
+        guid: "ad476cb7-b6d1-41c8-986b-0df243a64146",
+        inputs: &[pin("x", "Value", Item)],
+        outputs: &[pin("y", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "ad483f40-dc72-40dc-844d-c9e462c7d19f",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "add6be3e-c57f-4740-96e4-5680abaa9169",
+        inputs: &[
+            pin("F", "Field", Item).of(ValueKind::Field),
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("N", "Steps", Item),
+            pin("A", "Accuracy", Item),
+            pin("M", "Method", Item),
+        ],
+        outputs: &[pin("C", "Curve", Item).of(ValueKind::Curve)],
+    },
+    SchemaRecord {
+        guid: "ae57e09b-a1e4-4d05-8491-abd232213bc9",
+        inputs: &[
+            pin("P", "Polyline", Item).of(ValueKind::Curve),
+            pin("Hb", "Base height", Item),
+            pin("Ht", "Top height", Item),
+            pin("A", "Angles", List),
+        ],
+        outputs: &[pin("S", "Shape", Item)],
+    },
+    SchemaRecord {
+        guid: "ae840986-cade-4e5a-96b0-570f007d4fc0",
+        inputs: &[
+            pin("A", "First Number", Item).of(ValueKind::Number),
+            pin("B", "Second Number", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("<", "Smaller than", Item),
+            pin("<=", "… or Equal to", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "af9cdb9d-9617-4827-bb3c-9efd88c76a70",
+        inputs: &[pin("B", "Box", Item)],
+        outputs: &[
+            pin("C", "Center", Item).of(ValueKind::Point),
+            pin("D", "Diagonal", Item),
+            pin("A", "Area", Item).of(ValueKind::Number),
+            pin("V", "Volume", Item).of(ValueKind::Number),
+            pin("d", "Degeneracy", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "afb96615-c59a-45c9-9cac-e27acb1c7ca0",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("R", "Recursive", Item),
+        ],
+        outputs: &[
+            pin("S", "Segments", List).of(ValueKind::Number),
+            pin("V", "Vertices", List).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "afbcbad4-2a2a-4954-8040-d999e316d2bd",
+        inputs: &[
+            pin("A", "Point A", Item).of(ValueKind::Point),
+            pin("B", "Point B", Item).of(ValueKind::Point),
+            pin("C", "Point C", Item).of(ValueKind::Point),
+        ],
+        outputs: &[
+            pin("C", "Centroid", Item).of(ValueKind::Point),
+            pin("AB", "Median AB", Item),
+            pin("BC", "Median BC", Item),
+            pin("CA", "Median CA", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "afbf2fe0-4965-48d2-8470-9e991540093b",
+        inputs: &[
+            pin("M", "Mesh", Item).of(ValueKind::Mesh),
+            pin("S", "Splitters", List),
+        ],
+        outputs: &[pin("R", "Result", List)],
+    },
+    SchemaRecord {
+        guid: "afff17ed-5975-460b-9883-525ae0677088",
+        inputs: &[pin("A", "Arc", Item).of(ValueKind::Curve)],
+        outputs: &[
+            pin("C", "Center", Item).of(ValueKind::Point),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "b075c065-efda-4c9f-9cc9-288362b1b4b9",
+        inputs: &[
+            pin("S", "Point", Item).of(ValueKind::Point),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("uv", "UV Point", Item),
+            pin("D", "Distance", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "b083c06d-9a71-4f40-b354-1d80bba1e858",
+        inputs: &[
+            pin("P1", "Point 1", Item).of(ValueKind::Point),
+            pin("P2", "Point 2", Item).of(ValueKind::Point),
+            pin("P3", "Point 3", Item).of(ValueKind::Point),
+            pin("P4", "Point 4", Item).of(ValueKind::Point),
+        ],
+        outputs: &[
+            pin("C", "Center", Item).of(ValueKind::Point),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+            pin("S", "Sphere", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "b0851fc0-ab55-47d8-bdda-cc6306a40176",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "b1991128-8bf1-4dea-8497-4b7188a64e9d",
+        inputs: &[
+            pin("T", "Text", Item).of(ValueKind::Text),
+            pin("C", "Culture", Item),
+        ],
+        outputs: &[pin("U", "Upper Case", Item), pin("L", "Lower Case", Item)],
+    },
+    SchemaRecord {
+        guid: "b25c5762-f90e-4839-9fc5-74b74ab42b1e",
+        inputs: &[
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+            pin("D", "Distance", Item).of(ValueKind::Number),
+            pin("T", "Retrim", Item),
+        ],
+        outputs: &[pin("S", "Surface", Item).of(ValueKind::Surface)],
+    },
+    SchemaRecord {
+        guid: "b27d53bc-e713-475d-81fd-71cdd8de2e58",
+        inputs: &[pin("F", "Field", Item).of(ValueKind::Field)],
+        outputs: &[pin("F", "Fields", List).of(ValueKind::Field)],
+    },
+    SchemaRecord {
+        guid: "b2dc090f-b022-4264-8889-87e22979336e",
+        inputs: &[
+            pin("M", "Mesh", Item).of(ValueKind::Mesh),
+            pin("P", "Parameter", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("N", "Normal", Item).of(ValueKind::Vector),
+            pin("C", "Colour", Item).of(ValueKind::Color),
+        ],
+    },
+    SchemaRecord {
+        guid: "b341e2e5-c4b3-49a3-b3a4-b4e6e2054516",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "b40f28a2-ba30-4ac2-afe5-a6ece7f985fc",
+        inputs: &[pin("G", "Geometry", Item), pin("T", "Translation", Item)],
+        outputs: &[pin("G", "Geometry", Item)],
+    },
+    SchemaRecord {
+        guid: "b4647919-d041-419e-99f5-fa0dc0ddb8b6",
+        inputs: &[pin("x", "Value", Item)],
+        outputs: &[pin("y", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "b464fccb-50e7-41bd-9789-8438db9bea9f",
+        inputs: &[
+            pin("A", "Vector A", Item).of(ValueKind::Vector),
+            pin("B", "Vector B", Item).of(ValueKind::Vector),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[
+            pin("A", "Angle", Item).of(ValueKind::Number),
+            pin("R", "Reflex", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "b4d4235f-14ff-4d4e-a29a-b358dcd2baf4",
+        inputs: &[pin("S", "Set", List), pin("D", "Data", Item)],
+        outputs: &[
+            pin("H", "Hit", Item),
+            pin("i", "Index", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "b5be5d1f-717f-493c-b958-816957f271fd",
+        inputs: &[
+            pin("A", "Stream A", Tree),
+            pin("B", "Stream B", Tree),
+            pin("C", "Stream C", Tree),
+            pin("D", "Stream D", Tree),
+        ],
+        outputs: &[pin("S", "Stream", Item)],
+    },
+    SchemaRecord {
+        guid: "b6236720-8d88-4289-93c3-ac4c99f9b97b",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "b648d933-ddea-4e75-834c-8f6f3793e311",
+        inputs: &[pin("B", "Brep", Item).of(ValueKind::Brep)],
+        outputs: &[pin("B", "Brep", Item).of(ValueKind::Brep)],
+    },
+    SchemaRecord {
+        guid: "b661519d-43fd-4e5a-b244-d54d9fae2bde",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("A", "Angle", Item).of(ValueKind::Number),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[pin("G", "Geometry", Item)],
+    },
+    SchemaRecord {
+        guid: "b6aedcac-bf43-42d4-899e-d763612f834d",
+        inputs: &[pin("A", "A", Item), pin("B", "B", Item)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "b6cac37c-21b9-46c6-bd0d-17ff67796578",
+        inputs: &[pin("N", "Factor", Item).of(ValueKind::Number)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "b6d7ba20-cf74-4191-a756-2216a36e30a7",
+        inputs: &[
+            pin("V", "Vector", Item).of(ValueKind::Vector),
+            pin("X", "Axis", Item),
+            opt("A", "Angle", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("V", "Vector", Item).of(ValueKind::Vector)],
+    },
+    SchemaRecord {
+        guid: "b6f5cb51-f260-4c74-bf73-deb47de1bf91",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            opt("D", "Distance", Item).of(ValueKind::Number),
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+        ],
+        outputs: &[pin("C", "Curve", List).of(ValueKind::Curve)],
+    },
+    SchemaRecord {
+        guid: "b7798b74-037e-4f0c-8ac7-dc1043d093e0",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("A", "Angle", Item).of(ValueKind::Number),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[
+            pin("G", "Geometry", Item),
+            pin("X", "Transform", Item).of(ValueKind::Transform),
+        ],
+    },
+    SchemaRecord {
+        guid: "b799b7c0-76df-4bdb-b3cc-401b1d021aa5",
+        inputs: &[
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+            pin("uv", "Point", Item).of(ValueKind::Point),
+        ],
+        outputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("C1", "First circle", Item),
+            pin("C2", "Second circle", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "b7c12ed1-b09a-4e15-996f-3fa9f3f16b1c",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("t", "Params C", List),
+            pin("uv", "Params P", List),
+        ],
+    },
+    SchemaRecord {
+        guid: "b7e4e0ef-a01d-48c4-93be-2a12d4417e22",
+        inputs: &[
+            pin("R", "Range", Item),
+            pin("N", "Number", Item).of(ValueKind::Number),
+            pin("S", "Seed", Item).of(ValueKind::Number),
+            pin("I", "Integers", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("R", "Range", List)],
+    },
+    SchemaRecord {
+        guid: "b7f1178f-4222-47fd-9766-5d06e869362b",
+        inputs: &[
+            pin("V", "Vectors", List).of(ValueKind::Vector),
+            opt("U", "Unitize", Item).of(ValueKind::Boolean),
+        ],
+        outputs: &[pin("V", "Vector", Item).of(ValueKind::Vector)],
+    },
+    SchemaRecord {
+        guid: "b8963bb1-aa57-476e-a20e-ed6cf635a49c",
+        inputs: &[pin("A", "A", Item), pin("B", "B", Item)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "b8a66384-fc66-4574-a8a9-ad18e610d623",
+        inputs: &[
+            pin("F", "File", Item),
+            pin("S", "Separator", Item),
+            pin("C", "Comment", Item),
+            pin("X", "X Index", Item),
+            pin("Y", "Y Index", Item),
+            pin("Z", "Z Index", Item),
+        ],
+        outputs: &[pin("P", "Points", List).of(ValueKind::Point)],
+    },
+    SchemaRecord {
+        guid: "b8e2aa8f-8830-4ee1-bb59-613ea279c281",
+        inputs: &[pin("T", "Tree", Tree), pin("G", "Guide", Tree)],
+        outputs: &[pin("T", "Tree", Tree)],
+    },
+    SchemaRecord {
+        guid: "b90eaa92-6e38-4054-a915-afcf486224b3",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("S", "Segments", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("G", "Geometry", List),
+            pin("X", "Transform", List).of(ValueKind::Transform),
+        ],
+    },
+    SchemaRecord {
+        guid: "b9fde5fa-d654-4306-8ee1-6b69e6757604",
+        inputs: &[
+            pin("L", "Line", Item).of(ValueKind::CurveLine),
+            pin("A", "Point A", Item).of(ValueKind::Point),
+            pin("B", "Point B", Item).of(ValueKind::Point),
+        ],
+        outputs: &[pin("L", "Line", Item).of(ValueKind::CurveLine)],
+    },
+    SchemaRecord {
+        guid: "ba2d8f57-0738-42b4-b5a5-fe4d853517eb",
+        inputs: &[pin("M", "Mesh", Item).of(ValueKind::Mesh)],
+        outputs: &[
+            pin("V", "Vertices", List).of(ValueKind::Point),
+            pin("F", "Faces", List),
+            pin("C", "Colours", List).of(ValueKind::Color),
+            pin("N", "Normals", List).of(ValueKind::Vector),
+        ],
+    },
+    SchemaRecord {
+        guid: "ba9bb57a-61cf-4207-a1c4-994e371ba4f9",
+        inputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("B", "Box", Item),
+        ],
+        outputs: &[pin("C", "Cells", List), pin("B", "Boundary", List)],
+    },
+    SchemaRecord {
+        guid: "babecca6-9813-4146-b150-cd72f743e47c",
+        inputs: &[
+            pin("A", "First number", Item).of(ValueKind::Number),
+            pin("B", "Second number", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "bafac914-ede4-4a59-a7b2-cc41bc3de961",
+        inputs: &[
+            pin("S", "Set", List),
+            pin("F", "Find", List),
+            pin("R", "Replace", List),
+        ],
+        outputs: &[pin("R", "Result", List)],
+    },
+    SchemaRecord {
+        guid: "bb2e13da-09ca-43fd-bef8-8d71f3653af9",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("Pl", "Plane", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[
+            pin("S", "Side", Item),
+            pin("L", "Left", Item),
+            pin("R", "Right", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "bb59bffc-f54c-4682-9778-f6c3fe74fce3",
+        inputs: &[
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+            pin("A", "Angle", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("A", "Arc", Item).of(ValueKind::Curve),
+            pin("L", "Length", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "bb64b2fb-f87a-432f-86f8-393f4ee21310",
+        inputs: &[pin("I", "Input", List)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "bb6666e7-d0f4-41ec-a257-df2371619f13",
+        inputs: &[
+            pin("R", "Rail", Item),
+            pin("S", "Sections", List),
+            pin("M", "Miter", Item),
+        ],
+        outputs: &[pin("S", "Brep", List).of(ValueKind::Brep)],
+    },
+    SchemaRecord {
+        guid: "bb6c6501-0500-4678-859b-b838348981d1",
+        inputs: &[pin("C", "Collider", Item), pin("O", "Obstacles", List)],
+        outputs: &[
+            pin("C", "Collision", Item),
+            pin("I", "Index", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "bc3e379e-7206-4e7b-b63a-ff61f4b38a3e",
+        inputs: &[
+            pin("O", "Origin", Item).of(ValueKind::Point),
+            pin("X", "X-Axis", Item),
+            pin("Y", "Y-Axis", Item),
+        ],
+        outputs: &[pin("Pl", "Plane", Item).of(ValueKind::Plane)],
+    },
+    SchemaRecord {
+        guid: "bc4a27fc-cbb9-4802-bd4a-17ab33ad1826",
+        inputs: &[pin("x", "Input", Item)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "bc984576-7aa6-491f-a91d-e444c33675a7",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "bcac2747-348b-4edd-ae1f-77a782cebbdd",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "bd4a8a18-a3cc-40ba-965b-3be91fee563b",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "bd96f893-d57b-4f04-90d0-dca0d72ff2f9",
+        inputs: &[pin("I", "Input", List), pin("t", "Truncation factor", Item)],
+        outputs: &[pin("T", "Result", List)],
+    },
+    SchemaRecord {
+        guid: "bdd2a14a-1302-4152-a484-7198716d1a11",
+        inputs: &[pin("T", "Text", Item).of(ValueKind::Text)],
+        outputs: &[pin("U", "Upper Case", Item), pin("L", "Lower Case", Item)],
+    },
+    SchemaRecord {
+        guid: "be298882-28c9-45b1-980d-7192a531c9a9",
+        inputs: &[
+            pin("P", "Polyline", Item).of(ValueKind::Curve),
+            opt("t", "Tolerance", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("Pl", "Polyline", Item).of(ValueKind::Curve),
+            pin("N", "Count", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "be52336f-a2e1-43b1-b5f5-178ba489508a",
+        inputs: &[pin("P", "Points", List).of(ValueKind::Point)],
+        outputs: &[
+            pin("C", "Circle", Item).of(ValueKind::Curve),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+            pin("D", "Deviation", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "be6636b2-2f1a-4d42-897b-fdef429b6f17",
+        inputs: &[
+            pin("B", "Base", Item),
+            pin("P", "Point", Item).of(ValueKind::Point),
+        ],
+        outputs: &[pin("E", "Extrusion", Item)],
+    },
+    SchemaRecord {
+        guid: "be715e4c-d6d8-447b-a9c3-6fea700d0b83",
+        inputs: &[pin("C", "Complex", Item).of(ValueKind::Complex)],
+        outputs: &[pin("A", "Argument", Item)],
+    },
+    SchemaRecord {
+        guid: "bf106e4c-68f4-476f-b05b-9c15fb50e078",
+        inputs: &[
+            pin("F", "Field", Item).of(ValueKind::Field),
+            pin("S", "Section", Item),
+            pin("N", "Samples", Item),
+            pin("C+", "Positive Colour", Item),
+            pin("C-", "Negative Colour", Item),
+        ],
+        outputs: &[pin("D", "Display", Item)],
+    },
+    SchemaRecord {
+        guid: "bfaaf799-77dc-4f31-9ad8-2f7d1a80aeb0",
+        inputs: &[
+            pin("D", "Data", Tree),
+            pin("S", "Search", List),
+            pin("R", "Replace", List),
+        ],
+        outputs: &[pin("D", "Data", Tree)],
+    },
+    SchemaRecord {
+        guid: "c048ad76-ffcd-43b1-a007-4dd1b2373326",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("t", "Parameter", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("F", "Frame", Item).of(ValueKind::Plane)],
+    },
+    SchemaRecord {
+        guid: "c076845a-1a09-4a95-bdcb-cb31c0936c99",
+        inputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+        ],
+        outputs: &[
+            pin("R", "Relationship", Item),
+            pin("P'", "Point", Item).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "c08ac8f7-cf90-4cdb-9862-2ba66b8408ef",
+        inputs: &[
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("N", "Count", Item).of(ValueKind::Number),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+            pin("O", "Obstacles", List),
+        ],
+        outputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("D", "Distance", List).of(ValueKind::Number),
+            pin("H", "Hits", List),
+        ],
+    },
+    SchemaRecord {
+        guid: "c0b3c6e9-d05d-4c51-a0df-1ce2678c7a33",
+        inputs: &[pin("S", "SubD", Item), pin("D", "Density", Item)],
+        outputs: &[pin("M", "Mesh", Item).of(ValueKind::Mesh)],
+    },
+    SchemaRecord {
+        guid: "c1a57c2a-11c5-4f77-851e-0a7dffef848e",
+        inputs: &[pin("S", "SubD", Item)],
+        outputs: &[pin("M", "Mesh", Item).of(ValueKind::Mesh)],
+    },
+    SchemaRecord {
+        guid: "c1ec65a3-bda4-4fad-87d0-edf86ed9d81c",
+        inputs: &[
+            pin("T", "Tree", Tree),
+            pin("P", "Path", Item),
+            pin("i", "Index", Item).of(ValueKind::Number),
+            pin("W", "Wrap", Item).of(ValueKind::Boolean),
+        ],
+        outputs: &[pin("E", "Element", Item)],
+    },
+    SchemaRecord {
+        guid: "c21e7bd5-b1f2-4448-ac56-206f98f90aa7",
+        inputs: &[
+            pin("R", "Room", Item),
+            pin("W", "Width", Item).of(ValueKind::Number),
+            pin("S", "Samples", Item),
+        ],
+        outputs: &[pin("R", "Rectangle", Item).of(ValueKind::Curve)],
+    },
+    SchemaRecord {
+        guid: "c277f778-6fdf-4890-8f78-347efb23c406",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+            pin("E", "Caps", Item),
+        ],
+        outputs: &[pin("P", "Pipe", List)],
+    },
+    SchemaRecord {
+        guid: "c2c0c6cf-f362-4047-a159-21a72e7c272a",
+        inputs: &[
+            pin("F", "File", Item),
+            pin("R", "Rectangle", Item).of(ValueKind::Curve),
+            pin("X", "X Samples", Item),
+            pin("Y", "Y Samples", Item),
+        ],
+        outputs: &[pin("I", "Image", Item)],
+    },
+    SchemaRecord {
+        guid: "c2c73357-bfd2-45af-89ff-40ca02a3442f",
+        inputs: &[
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+            pin("L", "Line", Item).of(ValueKind::CurveLine),
+            pin("F", "First", Item),
+        ],
+        outputs: &[
+            pin("C", "Curves", List).of(ValueKind::Curve),
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("uv", "UV Points", List),
+            pin("N", "Normal", List).of(ValueKind::Vector),
+        ],
+    },
+    SchemaRecord {
+        guid: "c2e16ca3-9508-4fa4-aeb3-0b1f0ebb72e3",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("t", "Parameter", Item).of(ValueKind::Number),
+            opt("N", "Count", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("d", "Derivatives", List),
+        ],
+    },
+    SchemaRecord {
+        guid: "c3342ea2-e181-46aa-a9b9-e438ccbfb831",
+        inputs: &[
+            pin("A", "Point A", Item).of(ValueKind::Point),
+            pin("B", "Point B", Item).of(ValueKind::Point),
+            pin("C", "Point C", Item).of(ValueKind::Point),
+        ],
+        outputs: &[
+            pin("I", "Incentre", Item),
+            pin("A", "Bisector A", Item),
+            pin("B", "Bisector B", Item),
+            pin("C", "Bisector C", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "c3407fda-b505-4686-9165-38fe7a9274cf",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "c3d1f2b8-8596-4e8d-8861-c28ba8ffb4f4",
+        inputs: &[
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+            opt("G", "Guide", Item),
+        ],
+        outputs: &[
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+            pin("R", "Result", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "c40dc145-9e36-4a69-ac1a-6d825c654993",
+        inputs: &[
+            pin("D", "Data", List),
+            pin("L", "Length", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("D", "Data", List)],
+    },
+    SchemaRecord {
+        guid: "c4373505-a4cf-4992-8db1-fd6e6bb5850d",
+        inputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("C", "Charge", List),
+            pin("Pl", "Plane", Item).of(ValueKind::Plane),
+            pin("T", "Threshold", Item),
+            pin("A", "Accuracy", Item),
+        ],
+        outputs: &[pin("I", "Isocurve", List)],
+    },
+    SchemaRecord {
+        guid: "c48cf4d4-432c-41b6-b77a-77650479a31f",
+        inputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("Pl", "Plane", Item).of(ValueKind::Plane),
+            pin("T", "Threshold", Item),
+            pin("A", "Accuracy", Item),
+        ],
+        outputs: &[pin("I", "Isocurve", List)],
+    },
+    SchemaRecord {
+        guid: "c53932eb-7c8c-4825-ae98-e36bba97232d",
+        inputs: &[pin("x", "Input", Item)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "c6f23658-617f-4ac8-916d-d0d9e7241b25",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("N", "Count", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("G", "Geometry", List),
+            pin("X", "Transform", List).of(ValueKind::Transform),
+        ],
+    },
+    SchemaRecord {
+        guid: "c6fe61e7-25e2-4333-9172-f4e2a123fcfe",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            opt("D", "Distance", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("C", "Curve", Item).of(ValueKind::Curve)],
+    },
+    SchemaRecord {
+        guid: "c717f26f-e4a0-475c-8e1c-b8f77af1bc99",
+        inputs: &[pin("x", "Value", Item)],
+        outputs: &[pin("y", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "c72d0184-bb99-4af4-a629-4662e1c3d428",
+        inputs: &[pin("B", "Brep", Item).of(ValueKind::Brep)],
+        outputs: &[
+            pin("V", "Volume", Item).of(ValueKind::Number),
+            pin("C", "Centroid", Item).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "c73e1ed0-82a2-40b0-b4df-8f10e445d60b",
+        inputs: &[
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            opt("X", "Reverse X", Item),
+            opt("Y", "Reverse Y", Item),
+            opt("S", "Swap axes", Item),
+        ],
+        outputs: &[pin("P", "Plane", Item).of(ValueKind::Plane)],
+    },
+    SchemaRecord {
+        guid: "c75b62fa-0a33-4da7-a5bd-03fd0068fd93",
+        inputs: &[pin("C", "Curve", Item).of(ValueKind::Curve)],
+        outputs: &[pin("L", "Length", Item).of(ValueKind::Number)],
+    },
+    SchemaRecord {
+        guid: "c77a8b3b-c569-4d81-9b59-1c27299a1c45",
+        inputs: &[
+            pin("A", "Corner A", Item).of(ValueKind::Point),
+            pin("B", "Corner B", Item).of(ValueKind::Point),
+            pin("C", "Corner C", Item).of(ValueKind::Point),
+            pin("D", "Corner D", Item).of(ValueKind::Point),
+        ],
+        outputs: &[pin("S", "Surface", Item).of(ValueKind::Surface)],
+    },
+    SchemaRecord {
+        guid: "c7b5c66a-6360-4f5f-aa17-a918d0b1c314",
+        inputs: &[pin("O", "Object", Item)],
+        outputs: &[
+            pin("R", "Referenced", Item),
+            pin("A", "Available", Item),
+            pin("N", "Name", Item),
+            pin("L", "Layer", Item),
+            pin("C", "Colour", Item).of(ValueKind::Color),
+            pin("Id", "Guid", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "c8203c3c-6bcd-4f8c-a906-befd92ebf0cb",
+        inputs: &[
+            pin("F", "Format", Item),
+            pin("0", "Data 0", Item),
+            pin("1", "Data 1", Item),
+        ],
+        outputs: &[pin("T", "Text", Item).of(ValueKind::Text)],
+    },
+    SchemaRecord {
+        guid: "c8cb6a5c-2ffd-4095-ba2a-5c35015e09e4",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("N", "Count", Item).of(ValueKind::Number),
+            pin("S", "Seed", Item).of(ValueKind::Number),
+            pin("P", "Points", List).of(ValueKind::Point),
+        ],
+        outputs: &[pin("P", "Population", List)],
+    },
+    SchemaRecord {
+        guid: "c92cdfc8-3df8-4c4e-abc1-ede092a0aa8a",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            opt("t", "Parameter", Item).of(ValueKind::Number),
+            opt("R", "Radius", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("t", "Parameter", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "c9482db6-bea9-448d-98ff-fed6d69a8efc",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "c9785b8e-2f30-4f90-8ee3-cca710f82402",
+        inputs: &[
+            pin("{0;0}", "Branch {0;0}", Tree),
+            pin("{0;1}", "Branch {0;1}", Tree),
+            pin("{0;2}", "Branch {0;2}", Tree),
+        ],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "c98a6015-7a2f-423c-bc66-bdc505249b45",
+        inputs: &[
+            pin("A", "Point A", Item).of(ValueKind::Point),
+            pin("B", "Point B", Item).of(ValueKind::Point),
+            pin("C", "Point C", Item).of(ValueKind::Point),
+        ],
+        outputs: &[pin("Pl", "Plane", Item).of(ValueKind::Plane)],
+    },
+    SchemaRecord {
+        guid: "c98c1666-5f29-4bb8-aafd-bb5a708e8a95",
+        inputs: &[pin("G", "Geometry", Item)],
+        outputs: &[
+            pin("A", "Area", Item).of(ValueKind::Number),
+            pin("C", "Centroid", Item).of(ValueKind::Point),
+            pin("I", "Inertia", Item),
+            pin("I±", "Inertia (error)", Item),
+            pin("S", "Secondary", Item),
+            pin("S±", "Secondary (error)", Item),
+            pin("G", "Gyration", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "ca80054a-cde0-4f69-a132-10502b24866d",
+        inputs: &[pin("T", "Transforms", List).of(ValueKind::Transform)],
+        outputs: &[pin("X", "Compound", Item)],
+    },
+    SchemaRecord {
+        guid: "cab92254-1c79-4e5a-9972-0a4412b35c88",
+        inputs: &[
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("N", "Count", Item).of(ValueKind::Number),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+            pin("O", "Obstacles", List),
+        ],
+        outputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("D", "Distance", List).of(ValueKind::Number),
+            pin("I", "Index", List).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "cacb2c64-61b5-46db-825d-c61d5d09cc08",
+        inputs: &[pin("K", "Keys", List), pin("A", "Values A", List)],
+        outputs: &[pin("L", "List", List), pin("A", "Values A", List)],
+    },
+    SchemaRecord {
+        guid: "cae37d1c-8146-4e0b-9cf1-14cb3e337b94",
+        inputs: &[
+            pin("A", "First number", Item).of(ValueKind::Number),
+            pin("B", "Second number", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "cb22d3ed-93d8-4629-bdf2-c0c7c25afd2c",
+        inputs: &[pin("N", "Factor", Item).of(ValueKind::Number)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "cb2c7d3c-41b4-4c6d-a6bd-9235bd2851bb",
+        inputs: &[pin("A", "A", Item)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "cb4ca22c-3419-4962-a078-ad4ff7f1f929",
+        inputs: &[pin("M", "Mesh", Item).of(ValueKind::Mesh)],
+        outputs: &[
+            pin("C", "Centers", List),
+            pin("N", "Normals", List).of(ValueKind::Vector),
+        ],
+    },
+    SchemaRecord {
+        guid: "cb4ec4a1-f48e-4685-b58c-72ed27b53681",
+        inputs: &[
+            pin("A", "First number", Item).of(ValueKind::Number),
+            pin("B", "Second number", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "cb56b26c-2595-4d03-bdb2-eb2e6aeba82d",
+        inputs: &[pin("B", "Boundary", Item)],
+        outputs: &[pin("P", "Patch", Item)],
+    },
+    SchemaRecord {
+        guid: "cb95db89-6165-43b6-9c41-5702bc5bf137",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "cc15ba56-fae7-4f05-b599-cb7c43b60e11",
+        inputs: &[pin("x", "Value", Item)],
+        outputs: &[pin("y", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "ccc3f2ff-c9f6-45f8-aa30-8a924a9bda36",
+        inputs: &[
+            pin("L", "Line", Item).of(ValueKind::CurveLine),
+            pin("P", "Point", Item).of(ValueKind::Point),
+        ],
+        outputs: &[pin("Pl", "Plane", Item).of(ValueKind::Plane)],
+    },
+    SchemaRecord {
+        guid: "ccc7b468-e743-4049-891f-299432545898",
+        inputs: &[pin("C", "Curve", Item).of(ValueKind::Curve)],
+        outputs: &[pin("M", "Midpoint", Item)],
+    },
+    SchemaRecord {
+        guid: "ccfd6ba8-ecb1-44df-a47e-08126a653c51",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("D", "Domain", Item),
+        ],
+        outputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("D", "Domain", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "cd9efa8f-0084-4d52-ab13-ad88ff22dc46",
+        inputs: &[pin("S", "SubD", Item)],
+        outputs: &[
+            pin("P", "Point", List).of(ValueKind::Point),
+            pin("I", "Id", List),
+        ],
+    },
+    SchemaRecord {
+        guid: "cdd5d441-3bad-4f19-a370-6cf180b6f0fa",
+        inputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("B", "Brep", Item).of(ValueKind::Brep),
+        ],
+        outputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("D", "Distance", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "cdee962f-4202-456b-a1b4-f3ed9aa0dc29",
+        inputs: &[
+            pin("P", "Curve", Item).of(ValueKind::Curve),
+            pin("A", "Axis", Item),
+            pin("D", "Domain", Item),
+        ],
+        outputs: &[pin("S", "Surface", Item).of(ValueKind::Surface)],
+    },
+    SchemaRecord {
+        guid: "ce46b74e-00c9-43c4-805a-193b69ea4a11",
+        inputs: &[pin("A", "A", Item), pin("B", "B", Item)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "ce5963b4-1cea-4f71-acd2-a3c28ab85662",
+        inputs: &[
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+            pin("S", "Start", Item),
+            pin("E", "End", Item),
+        ],
+        outputs: &[pin("G", "Geodesic", Item)],
+    },
+    SchemaRecord {
+        guid: "cec16c67-7b8b-41f7-a5a5-f675177e524b",
+        inputs: &[
+            pin("K", "Keys", List),
+            pin("V", "Values", List),
+            pin("C", "Culture", Item),
+        ],
+        outputs: &[pin("K", "Keys", List), pin("V", "Values", List)],
+    },
+    SchemaRecord {
+        guid: "cf3a0865-4882-46bd-91a1-d512acf95be4",
+        inputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("G", "Geometry", List),
+            pin("C", "Closest", Item),
+        ],
+        outputs: &[
+            pin("P", "Closest Point", Item),
+            pin("D", "Distance", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "cfb6b17f-ca82-4f5d-b604-d4f69f569de3",
+        inputs: &[
+            pin("O", "Origin", Item).of(ValueKind::Point),
+            pin("Z", "Z-Axis", Item),
+        ],
+        outputs: &[pin("P", "Plane", Item).of(ValueKind::Plane)],
+    },
+    SchemaRecord {
+        guid: "cfc280bb-332a-4828-bb4e-aca6d88859aa",
+        inputs: &[pin("x", "Input", Item)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "cffdbaf3-8d33-4b38-9cad-c264af9fc3f4",
+        inputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("C", "Charge", Item),
+            pin("D", "Decay", Item),
+            pin("B", "Bounds", Item),
+        ],
+        outputs: &[pin("F", "Field", Item).of(ValueKind::Field)],
+    },
+    SchemaRecord {
+        guid: "d0787f37-d976-48c9-a4b0-29d6c4059cf3",
+        inputs: &[pin("x", "Input", Item)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "d0a1b843-873d-4d1d-965c-b5423b35f327",
+        inputs: &[
+            pin("C", "Curves", List).of(ValueKind::Curve),
+            pin("G", "Continuity", Item),
+            pin("L", "Close", Item),
+            pin("B", "Bulge", Item),
+        ],
+        outputs: &[pin("C", "Curve", Item).of(ValueKind::Curve)],
+    },
+    SchemaRecord {
+        guid: "d0a56c9e-2483-45e7-ab98-a450b97f1bc0",
+        inputs: &[
+            pin("R", "Rectangle", Item).of(ValueKind::Curve),
+            pin("H", "Height", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("B", "Box", Item)],
+    },
+    SchemaRecord {
+        guid: "d1028c72-ff86-4057-9eb0-36c687a4d98c",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "d114323a-e6ee-4164-946b-e4ca0ce15efa",
+        inputs: &[
+            pin("C", "Center", Item).of(ValueKind::Point),
+            pin("N", "Normal", Item).of(ValueKind::Vector),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("C", "Circle", Item).of(ValueKind::Curve)],
+    },
+    SchemaRecord {
+        guid: "d18db32b-7099-4eea-85c4-8ba675ee8ec3",
+        inputs: &[pin("A", "A", Item), pin("B", "B", Item)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "d1a28e95-cf96-4936-bf34-8bf142d731bf",
+        inputs: &[pin("A", "Domain start", Item), pin("B", "Domain end", Item)],
+        outputs: &[pin("I", "Domain", Item)],
+    },
+    SchemaRecord {
+        guid: "d1d57181-d594-41e8-8efb-041e29f8a5ca",
+        inputs: &[
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+            pin("uv", "UV point", Item),
+        ],
+        outputs: &[pin("U", "U Isocurve", List), pin("V", "V Isocurve", List)],
+    },
+    SchemaRecord {
+        guid: "d222500b-dfd5-45e0-933e-eabefd07cbfa",
+        inputs: &[pin("x", "Value", Item)],
+        outputs: &[pin("y", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "d24169cc-9922-4923-92bc-b9222efc413f",
+        inputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("M", "Mask", Item),
+        ],
+        outputs: &[pin("N", "Numbers", List).of(ValueKind::Number)],
+    },
+    SchemaRecord {
+        guid: "d2461702-3164-4894-8c10-ed1fc4b52965",
+        inputs: &[pin("A", "Set A", List), pin("B", "Set B", List)],
+        outputs: &[pin("X", "ExDifference", List)],
+    },
+    SchemaRecord {
+        guid: "d27cc1ea-9ef7-47bf-8ee2-c6662da0e3d9",
+        inputs: &[
+            pin("L", "Line", Item).of(ValueKind::CurveLine),
+            pin("B", "Bounds", Item),
+        ],
+        outputs: &[pin("F", "Field", Item).of(ValueKind::Field)],
+    },
+    SchemaRecord {
+        guid: "d2b10b82-f612-4763-91ca-0cbdbe276171",
+        inputs: &[
+            pin("F", "Function", Item),
+            pin("x", "Variable x", Item),
+            pin("y", "Variable y", Item),
+        ],
+        outputs: &[pin("r", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "d2cedf38-1149-4adc-8dbf-b06571cb5106",
+        inputs: &[
+            pin("M", "Mesh", Item).of(ValueKind::Mesh),
+            pin("C", "Colours", List).of(ValueKind::Color),
+        ],
+        outputs: &[pin("M", "Mesh", Item).of(ValueKind::Mesh)],
+    },
+    SchemaRecord {
+        guid: "d2d2a900-780c-4d58-9a35-1f9d8d35df6f",
+        inputs: &[pin("x", "Value", Item)],
+        outputs: &[pin("y", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "d2da1306-259a-4994-85a4-672d8a4c7805",
+        inputs: &[pin("V", "Vector", Item).of(ValueKind::Vector)],
+        outputs: &[pin("V", "Vector", Item).of(ValueKind::Vector)],
+    },
+    SchemaRecord {
+        guid: "d3d195ea-2d59-4ffa-90b1-8b7ff3369f69",
+        inputs: &[pin("F", "Factor", Item).of(ValueKind::Number)],
+        outputs: &[pin("V", "Unit vector", Item)],
+    },
+    SchemaRecord {
+        guid: "d3e721b4-f5ea-4e40-85fc-b68616939e47",
+        inputs: &[pin("F", "Function", Item), pin("x", "Variable X", Item)],
+        outputs: &[pin("y", "Result Y", Item)],
+    },
+    SchemaRecord {
+        guid: "d4136a7b-7422-4660-9404-640474bd2725",
+        inputs: &[
+            pin("A", "Set A", List),
+            pin("B", "Set B", List),
+            pin("C", "Set C", List),
+        ],
+        outputs: &[pin("R", "Result", List)],
+    },
+    SchemaRecord {
+        guid: "d4bc9653-c770-4bee-a31d-d120cbb75b39",
+        inputs: &[
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+            pin("I", "Interior", Item),
+        ],
+        outputs: &[
+            pin("F", "Planar", Item),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+        ],
+    },
+    SchemaRecord {
+        guid: "d51e9b65-aa4e-4fd6-976c-cef35d421d05",
+        inputs: &[pin("E", "Edges", List)],
+        outputs: &[pin("S", "Surfaces", List).of(ValueKind::Surface)],
+    },
+    SchemaRecord {
+        guid: "d5788074-d75d-4021-b1a3-0bf992928584",
+        inputs: &[pin("V", "Vector", Item).of(ValueKind::Vector)],
+        outputs: &[pin("V", "Vector", Item).of(ValueKind::Vector)],
+    },
+    SchemaRecord {
+        guid: "d5967b9f-e8ee-436b-a8ad-29fdcecf32d5",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "d5e28df8-495b-4892-bca8-60748743d955",
+        inputs: &[pin("D", "Date", Item)],
+        outputs: &[
+            pin("Y", "Year", Item),
+            pin("M", "Month", Item),
+            pin("D", "Day", Item),
+            pin("h", "Hour", Item),
+            pin("m", "Minute", Item),
+            pin("s", "Second", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "d6313940-216b-487f-b511-6c8a5b87eae7",
+        inputs: &[pin("S", "Stream", Tree), pin("G", "Gate", Item)],
+        outputs: &[pin("0", "Target 0", Item), pin("1", "Target 1", Item)],
+    },
+    SchemaRecord {
+        guid: "d69a3494-785b-4beb-969b-d2373f65abfd",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "d6b43673-55dd-4e2f-95c4-6c69a14513a6",
+        inputs: &[pin("B", "Brep", Item).of(ValueKind::Brep)],
+        outputs: &[
+            pin("B", "Breps", Item).of(ValueKind::Brep),
+            pin("N0", "Before", Item),
+            pin("N1", "After", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "d6d68c93-d00f-4cd5-ba89-903c7f6be64c",
+        inputs: &[
+            pin("A", "Circle A", Item).of(ValueKind::Curve),
+            pin("B", "Circle B", Item).of(ValueKind::Curve),
+        ],
+        outputs: &[
+            pin("T1", "Tangent 1", Item).of(ValueKind::Vector),
+            pin("T2", "Tangent 2", Item).of(ValueKind::Vector),
+        ],
+    },
+    SchemaRecord {
+        guid: "d788ad7f-6d68-4106-8b2f-9e55e6e107c0",
+        inputs: &[
+            pin("A", "Line A", Item).of(ValueKind::CurveLine),
+            pin("B", "Line B", Item).of(ValueKind::CurveLine),
+        ],
+        outputs: &[pin("Pl", "Plane", Item).of(ValueKind::Plane)],
+    },
+    SchemaRecord {
+        guid: "d7df7658-e02d-4a48-a345-2195a68db4ef",
+        inputs: &[pin("C", "Curve", Item).of(ValueKind::Curve)],
+        outputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("W", "Weights", List),
+        ],
+    },
+    SchemaRecord {
+        guid: "d7ee52ff-89b8-4d1a-8662-3e0dd391d0af",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("B", "Brep", Item).of(ValueKind::Brep),
+            opt("D", "Direction", Item).of(ValueKind::Vector),
+        ],
+        outputs: &[pin("C", "Curve", List).of(ValueKind::Curve)],
+    },
+    SchemaRecord {
+        guid: "d8332545-21b2-4716-96e3-8559a9876e17",
+        inputs: &[pin("L", "List", List), pin("P", "Dispatch pattern", List)],
+        outputs: &[pin("A", "List A", List), pin("B", "List B", List)],
+    },
+    SchemaRecord {
+        guid: "d8698126-0e91-4ae7-ba05-2490258573ea",
+        inputs: &[
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("S", "Shape", Item),
+            pin("I", "Inflate", Item),
+        ],
+        outputs: &[pin("S", "Surface", Item).of(ValueKind::Surface)],
+    },
+    SchemaRecord {
+        guid: "d879e74c-6fe3-4cbf-b3fa-60a7c48b73e7",
+        inputs: &[pin("x", "Input", Item)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "d8b1e7ac-cd31-4748-b262-e07e53068afc",
+        inputs: &[pin("D", "Data", Tree), pin("M", "Masks", List)],
+        outputs: &[pin("P", "Positive", Tree), pin("N", "Negative", Tree)],
+    },
+    SchemaRecord {
+        guid: "d8cf1555-a0d5-43cb-8a10-46f8c014db3a",
+        inputs: &[pin("M", "Mesh", Item).of(ValueKind::Mesh)],
+        outputs: &[pin("C", "Centers", List), pin("R", "Ratio", List)],
+    },
+    SchemaRecord {
+        guid: "d8d68c35-f869-486d-adf3-69ee3cc2d501",
+        inputs: &[
+            pin("P", "Curve", Item).of(ValueKind::Curve),
+            pin("R", "Rail", Item),
+            pin("A", "Axis", Item),
+            pin("S", "Scale", Item),
+        ],
+        outputs: &[pin("S", "Surface", Item).of(ValueKind::Surface)],
+    },
+    SchemaRecord {
+        guid: "d93100b6-d50b-40b2-831a-814659dc38e3",
+        inputs: &[
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("X", "X Size", Item),
+            pin("Y", "Y Size", Item),
+            opt("R", "Radius", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("R", "Rectangle", Item).of(ValueKind::Curve),
+            pin("L", "Length", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "d9a6fbd2-2e9f-472e-8147-33bf0233a115",
+        inputs: &[pin("F", "Fields", List).of(ValueKind::Field)],
+        outputs: &[pin("F", "Field", Item).of(ValueKind::Field)],
+    },
+    SchemaRecord {
+        guid: "da4be42b-ba75-4249-a685-69ce78b6ee44",
+        inputs: &[pin("x", "Input", Item)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "dabc854d-f50e-408a-b001-d043c7de151d",
+        inputs: &[
+            pin("B", "Base", Item),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("S", "Sphere", Item)],
+    },
+    SchemaRecord {
+        guid: "db2a4d25-23fa-4887-8983-ee5293cc82c0",
+        inputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("Pl", "Plane", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[pin("C", "Connectivity", Tree), pin("E", "Edges", List)],
+    },
+    SchemaRecord {
+        guid: "db7d83b1-2898-4ef9-9be5-4e94b4e2048d",
+        inputs: &[pin("B", "Box", Item)],
+        outputs: &[
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("X", "X", Item),
+            pin("Y", "Y", Item),
+            pin("Z", "Z", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "dbe9fce4-b6b3-465f-9615-34833c4763bd",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("t", "Parameter", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("T", "Torsion", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "dc934310-67eb-4d1d-8607-7cc62a501dd9",
+        inputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("Pl", "Plane", Item).of(ValueKind::Plane),
+            pin("X", "Point", Item).of(ValueKind::Point),
+            pin("A", "Accuracy", Item),
+        ],
+        outputs: &[pin("I", "Isocurve", List)],
+    },
+    SchemaRecord {
+        guid: "dca05f6f-e3d9-42e3-b3bb-eb20363fb335",
+        inputs: &[pin("T", "Text", Item).of(ValueKind::Text)],
+        outputs: &[pin("L", "Length", Item).of(ValueKind::Number)],
+    },
+    SchemaRecord {
+        guid: "dcaa922d-5491-4826-9a22-5adefa139f43",
+        inputs: &[
+            pin("A", "Curve A", Item).of(ValueKind::Curve),
+            pin("B", "Curve B", Item).of(ValueKind::Curve),
+            pin("C", "Curve C", Item).of(ValueKind::Curve),
+            pin("P", "Point", Item).of(ValueKind::Point),
+        ],
+        outputs: &[pin("C", "Circle", Item).of(ValueKind::Curve)],
+    },
+    SchemaRecord {
+        guid: "dd17d442-3776-40b3-ad5b-5e188b56bd4c",
+        inputs: &[pin("V", "Values", List)],
+        outputs: &[pin("D", "Differenced", List)],
+    },
+    SchemaRecord {
+        guid: "dd53b24c-003a-4a04-b185-a44d91633cbe",
+        inputs: &[pin("C", "Coordinates", List)],
+        outputs: &[pin("I", "Domain", Item)],
+    },
+    SchemaRecord {
+        guid: "dd8134c0-109b-4012-92be-51d843edfff7",
+        inputs: &[
+            pin("D", "Data", List),
+            pin("N", "Number", Item).of(ValueKind::Number),
+            pin("O", "Order", Item),
+        ],
+        outputs: &[pin("D", "Data", List)],
+    },
+    SchemaRecord {
+        guid: "dd8d834f-40f1-4a84-8e4b-9fa8efe7be41",
+        inputs: &[
+            pin("B", "Base", Item),
+            pin("W", "Width", Item).of(ValueKind::Number),
+            pin("H", "Height", Item).of(ValueKind::Number),
+            pin("Cw", "Width count", Item),
+            pin("Ch", "Height count", Item),
+        ],
+        outputs: &[
+            pin("M", "Mesh", Item).of(ValueKind::Mesh),
+            pin("A", "Area", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "dde71aef-d6ed-40a6-af98-6b0673983c82",
+        inputs: &[
+            pin("V", "Vertices", List).of(ValueKind::Point),
+            opt("D", "Degree", Item).of(ValueKind::Number),
+            opt("P", "Periodic", Item).of(ValueKind::Boolean),
+        ],
+        outputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("L", "Length", Item).of(ValueKind::Number),
+            pin("D", "Domain", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "de4a0d86-2709-4564-935a-88bf4d40af89",
+        inputs: &[pin("A", "A", Item), pin("B", "B", Item)],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "deadf87d-99a6-4980-90c3-f98350aa6f0f",
+        inputs: &[pin("N", "Factor", Item).of(ValueKind::Number)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "deaf8653-5528-4286-807c-3de8b8dad781",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "deffaf1e-270a-4c15-a693-9216b68afd4a",
+        inputs: &[pin("A", "Set A", List), pin("B", "Set B", List)],
+        outputs: &[pin("P", "Product", Tree)],
+    },
+    SchemaRecord {
+        guid: "df6d9197-9a6e-41a2-9c9d-d2221accb49e",
+        inputs: &[pin("B", "Branch", Item)],
+        outputs: &[pin("I", "Indices", List).of(ValueKind::Number)],
+    },
+    SchemaRecord {
+        guid: "e0168047-c46a-48c6-8595-2fb3d8574f23",
+        inputs: &[
+            pin("A", "Circle A", Item).of(ValueKind::Curve),
+            pin("B", "Circle B", Item).of(ValueKind::Curve),
+        ],
+        outputs: &[
+            pin("T1", "Tangent 1", Item).of(ValueKind::Vector),
+            pin("T2", "Tangent 2", Item).of(ValueKind::Vector),
+        ],
+    },
+    SchemaRecord {
+        guid: "e02b3da5-543a-46ac-a867-0ba6b0a524de",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "e03561f8-0e66-41d3-afde-62049f152443",
+        inputs: &[
+            pin("B", "Brep", Item).of(ValueKind::Brep),
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("S", "Strict", Item).of(ValueKind::Boolean),
+        ],
+        outputs: &[pin("I", "Inside", Item)],
+    },
+    SchemaRecord {
+        guid: "e168ff6b-e5c0-48f1-b831-f6996bf3b459",
+        inputs: &[
+            pin("D", "Data", List),
+            pin("t", "Parameter", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("V", "Value", Item)],
+    },
+    SchemaRecord {
+        guid: "e1c4bccc-4ecf-4f18-885d-dfd8983e572a",
+        inputs: &[
+            pin("F", "Function", Item),
+            pin("x", "Variable x", Item),
+            pin("y", "Variable y", Item),
+            pin("z", "Variable z", Item),
+        ],
+        outputs: &[pin("r", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "e202025b-dc8e-4c51-ae19-4415b172886f",
+        inputs: &[
+            pin("R", "Region", Item),
+            pin("N", "Count", Item).of(ValueKind::Number),
+            pin("S", "Seed", Item).of(ValueKind::Number),
+            pin("P", "Points", List).of(ValueKind::Point),
+        ],
+        outputs: &[pin("P", "Population", List)],
+    },
+    SchemaRecord {
+        guid: "e2039b07-d3f3-40f8-af88-d74fed238727",
+        inputs: &[
+            pin("L", "List", List),
+            pin("I", "Item", List),
+            pin("i", "Indices", List).of(ValueKind::Number),
+            pin("W", "Wrap", Item).of(ValueKind::Boolean),
+        ],
+        outputs: &[pin("L", "List", List)],
+    },
+    SchemaRecord {
+        guid: "e2c0f9db-a862-4bd9-810c-ef2610e7a56f",
+        inputs: &[
+            pin("V", "Vertices", List).of(ValueKind::Point),
+            pin("F", "Faces", List),
+            pin("C", "Colours", List).of(ValueKind::Color),
+        ],
+        outputs: &[pin("M", "Mesh", Item).of(ValueKind::Mesh)],
+    },
+    SchemaRecord {
+        guid: "e2c6cab3-91ea-4c01-900c-646642d3e436",
+        inputs: &[
+            pin("P", "Polyline", Item).of(ValueKind::Curve),
+            opt("D", "Distance", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("O", "Offset", List), pin("V", "Valid", List)],
+    },
+    SchemaRecord {
+        guid: "e2d958e8-9f08-44f7-bf47-a684882d0b2a",
+        inputs: &[
+            pin("R", "Region", Item),
+            pin("N", "Count", Item).of(ValueKind::Number),
+            pin("S", "Seed", Item).of(ValueKind::Number),
+            pin("P", "Points", List).of(ValueKind::Point),
+        ],
+        outputs: &[pin("P", "Population", List)],
+    },
+    SchemaRecord {
+        guid: "e3b1a10c-4d49-4140-b8e6-0b5732a26c31",
+        inputs: &[pin("A", "Set A", List), pin("B", "Set B", List)],
+        outputs: &[pin("U", "Union", List)],
+    },
+    SchemaRecord {
+        guid: "e44c1bd7-72cc-4697-80c9-02787baf7bb4",
+        inputs: &[pin("I", "Input", List)],
+        outputs: &[pin("R", "Result", Item), pin("Pr", "Partial Results", List)],
+    },
+    SchemaRecord {
+        guid: "e4cb7168-5e32-4c54-b425-5a31c6fd685a",
+        inputs: &[
+            pin("T", "Text", Item).of(ValueKind::Text),
+            pin("S", "Start", Item),
+            pin("E", "End", Item),
+        ],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "e4ff8101-73c9-4802-8c5d-704d8721b909",
+        inputs: &[
+            pin("B", "Brep", Item).of(ValueKind::Brep),
+            pin("L-", "Minimum", Item).of(ValueKind::Number),
+            pin("L+", "Maximum", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("E", "Edges", List),
+            pin("I", "Indices", List).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "e504d619-4467-437a-92fa-c6822d16b066",
+        inputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("G", "Group", Item),
+            pin("R-", "Min Radius", Item),
+            pin("R+", "Max Radius", Item),
+        ],
+        outputs: &[pin("L", "Links", Tree), pin("T", "Topology", Tree)],
+    },
+    SchemaRecord {
+        guid: "e521f7c8-92f4-481c-888b-eea109e3d6e9",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("C", "Cell", Item),
+            pin("X", "X Count", Item),
+            pin("Y", "Y Count", Item),
+        ],
+        outputs: &[
+            pin("G", "Geometry", List),
+            pin("X", "Transform", List).of(ValueKind::Transform),
+        ],
+    },
+    SchemaRecord {
+        guid: "e5c33a79-53d5-4f2b-9a97-d3d45c780edc",
+        inputs: &[pin("R", "Rectangle", Item).of(ValueKind::Curve)],
+        outputs: &[
+            pin("B", "Base Plane", Item),
+            pin("X", "X Interval", Item),
+            pin("Y", "Y Interval", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "e5c82975-8011-412c-b56d-bb7fc9e7f28d",
+        inputs: &[
+            pin("X", "Style", Item),
+            pin("S1", "Size Primary", Item),
+            pin("S2", "Size Secondary", Item),
+            pin("R", "Rotation", Item),
+            pin("Cf", "Fill", Item),
+            pin("Ce", "Edge", Item),
+            pin("W", "Width", Item).of(ValueKind::Number),
+            pin("A", "Adjust", Item),
+        ],
+        outputs: &[pin("D", "Symbol Display", Item)],
+    },
+    SchemaRecord {
+        guid: "e5ff52c5-40df-4f43-ac3b-d2418d05ae32",
+        inputs: &[
+            pin("Y", "Year", Item),
+            pin("M", "Month", Item),
+            pin("D", "Day", Item),
+        ],
+        outputs: &[pin("T", "Time", Item)],
+    },
+    SchemaRecord {
+        guid: "e64c5fb1-845c-4ab1-8911-5f338516ba67",
+        inputs: &[
+            pin("S", "Start", Item),
+            pin("N", "Step", Item),
+            pin("C", "Count", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("S", "Series", List)],
+    },
+    SchemaRecord {
+        guid: "e6859d1e-2b3d-4704-93ea-32714acae176",
+        inputs: &[pin("D", "Data", Tree)],
+        outputs: &[pin("N", "Null", Item)],
+    },
+    SchemaRecord {
+        guid: "e75d4624-8ee2-4067-ac8d-c56bdc901d83",
+        inputs: &[
+            pin("α", "Alpha", Item),
+            pin("β", "Beta", Item),
+            pin("P", "P length", Item),
+            pin("Q", "Q length", Item),
+            pin("R", "R length", Item),
+        ],
+        outputs: &[
+            pin("α", "Alpha", Item),
+            pin("β", "Beta", Item),
+            pin("P", "P length", Item),
+            pin("Q", "Q length", Item),
+            pin("R", "R length", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "e76040ec-3b91-41e1-8e00-c74c23b89391",
+        inputs: &[
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("D", "Direction", Item).of(ValueKind::Vector),
+        ],
+        outputs: &[
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("A", "Angle", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "e7e43403-f913-4d83-8aff-5b1c7a7f9fbc",
+        inputs: &[
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+            pin("D", "Distance", Item).of(ValueKind::Number),
+            pin("T", "Retrim", Item),
+        ],
+        outputs: &[pin("S", "Surface", Item).of(ValueKind::Surface)],
+    },
+    SchemaRecord {
+        guid: "e7ffb3af-2d77-4804-a260-755308bf8285",
+        inputs: &[pin("P", "Points", List).of(ValueKind::Point)],
+        outputs: &[
+            pin("C", "Center", Item).of(ValueKind::Point),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+            pin("S", "Sphere", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "e87db220-a0a0-4d67-a405-f97fd14b2d7a",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("D", "Direction", Item).of(ValueKind::Vector),
+            pin("N", "Count", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("G", "Geometry", List),
+            pin("X", "Transform", List).of(ValueKind::Transform),
+        ],
+    },
+    SchemaRecord {
+        guid: "e8e00fbb-9710-4cfa-a60f-2aae50b79d06",
+        inputs: &[
+            pin("V", "Vertices", List).of(ValueKind::Point),
+            pin("D", "Degree", Item).of(ValueKind::Number),
+            pin("Ts", "Tangent Start", Item),
+            pin("Te", "Tangent End", Item),
+        ],
+        outputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("L", "Length", Item).of(ValueKind::Number),
+            pin("D", "Domain", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "e9628b21-49d6-4e56-900e-49f4bd4adc85",
+        inputs: &[
+            pin("F", "Function", Item),
+            pin("a", "a", Item),
+            pin("b", "b", Item),
+            pin("c", "c", Item),
+            pin("d", "d", Item),
+            pin("x", "x", Item),
+            pin("y", "y", Item),
+            pin("z", "z", Item),
+        ],
+        outputs: &[pin("r", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "e9b807a3-dd48-4c2c-bada-e4f8e0edbbdb",
+        inputs: &[
+            pin("A", "First number", Item).of(ValueKind::Number),
+            pin("B", "Second number", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "e9eb1dcf-92f6-4d4d-84ae-96222d60f56b",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("T", "Motion", Item).of(ValueKind::Vector),
+        ],
+        outputs: &[
+            pin("G", "Geometry", Item),
+            pin("X", "Transform", Item).of(ValueKind::Transform),
+        ],
+    },
+    SchemaRecord {
+        guid: "ea0f0996-af7a-481d-8099-09c041e6c2d5",
+        inputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("C", "Circle", Item).of(ValueKind::Curve),
+        ],
+        outputs: &[
+            pin("T1", "Tangent 1", Item).of(ValueKind::Vector),
+            pin("T2", "Tangent 2", Item).of(ValueKind::Vector),
+        ],
+    },
+    SchemaRecord {
+        guid: "ebd6c758-19ae-4d74-aed7-b8a0392ff743",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[pin("H", "Highest", Item), pin("L", "Lowest", Item)],
+    },
+    SchemaRecord {
+        guid: "ebf4d987-09b9-4825-a735-cac3d4770c19",
+        inputs: &[
+            pin("L", "Location", Item),
+            pin("T", "Text", Item).of(ValueKind::Text),
+            pin("S", "Size", Item).of(ValueKind::Number),
+        ],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "ec875825-61e4-4c1c-a343-0e0cee0b321b",
+        inputs: &[
+            pin("A", "First number", Item).of(ValueKind::Number),
+            pin("B", "Second number", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "ecee923b-1b93-4cf2-acd6-680835503437",
+        inputs: &[pin("x", "Input", Item)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "ed0742f9-6647-4d95-9dfd-9ad17080ae9c",
+        inputs: &[
+            pin("B", "Brep", Item).of(ValueKind::Brep),
+            pin("L", "Line", Item).of(ValueKind::CurveLine),
+        ],
+        outputs: &[
+            pin("C", "Curves", List).of(ValueKind::Curve),
+            pin("P", "Points", List).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "ed766861-662d-4462-90f6-29f87f8529cf",
+        inputs: &[pin("x", "Input", Item)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "edcf10e1-02a0-48a4-ae2d-70c50d903dc8",
+        inputs: &[
+            pin("M", "Mesh", Item).of(ValueKind::Mesh),
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("C", "Colours", List).of(ValueKind::Color),
+        ],
+        outputs: &[pin("M", "Mesh", Item).of(ValueKind::Mesh)],
+    },
+    SchemaRecord {
+        guid: "ee9261ab-75a4-478f-b483-a50b755b07fd",
+        inputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+            pin("B", "Box", Item),
+            pin("Pl", "Plane", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[pin("C", "Cells", List)],
+    },
+    SchemaRecord {
+        guid: "eeafc956-268e-461d-8e73-ee05c6f72c01",
+        inputs: &[
+            pin("G", "Gate", Item),
+            pin("0", "Stream 0", Tree),
+            pin("1", "Stream 1", Tree),
+        ],
+        outputs: &[pin("S", "Stream", Tree)],
+    },
+    SchemaRecord {
+        guid: "ef6b26f4-f820-48d6-b0c5-85898ef8888b",
+        inputs: &[
+            pin("B", "Brep", Item).of(ValueKind::Brep),
+            pin("C", "Cutter", Item),
+        ],
+        outputs: &[pin("R", "Result", List)],
+    },
+    SchemaRecord {
+        guid: "f02a20f6-bb49-4e3d-b155-8ed5d3c6b000",
+        inputs: &[
+            pin("L", "List", List),
+            pin("J", "Jitter", Item),
+            pin("S", "Seed", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("V", "Values", List),
+            pin("I", "Indices", List).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "f055c5d7-5d97-4964-90c7-8e9eee9a8a39",
+        inputs: &[
+            pin("F", "File", Item),
+            pin("L", "Layer", Item),
+            pin("N", "Name", Item),
+        ],
+        outputs: &[pin("G", "Geometry", List)],
+    },
+    SchemaRecord {
+        guid: "f08233f1-9772-4514-8965-bde4948503df",
+        inputs: &[
+            pin("G", "Guide", Item),
+            pin("N", "Number", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("C", "Cloud", List)],
+    },
+    SchemaRecord {
+        guid: "f0adfc96-b175-46a6-80c7-2b0ee17395c4",
+        inputs: &[pin("I", "Domain", Item)],
+        outputs: &[pin("U", "U component", Item), pin("V", "V component", Item)],
+    },
+    SchemaRecord {
+        guid: "f0b70e8e-7337-4ce4-a7bb-317fc971f918",
+        inputs: &[pin("S", "Shape", Item), pin("T", "Cutters", List)],
+        outputs: &[pin("R", "Result", List)],
+    },
+    SchemaRecord {
+        guid: "f151b0b9-cef8-4809-96fc-9b14f1c3a7b9",
+        inputs: &[
+            pin("D", "Days", Item),
+            pin("H", "Hours", Item),
+            pin("M", "Minutes", Item),
+            pin("S", "Seconds", Item),
+        ],
+        outputs: &[pin("T", "Time", Item)],
+    },
+    SchemaRecord {
+        guid: "f17c37ae-b44a-481a-bd65-b4398be55ec8",
+        inputs: &[
+            pin("S", "Start", Item),
+            pin("E", "End", Item),
+            pin("D", "Direction", Item).of(ValueKind::Vector),
+        ],
+        outputs: &[
+            pin("A", "Arc", Item).of(ValueKind::Curve),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "f18091e9-3264-4dd4-9ba6-32c77fca0ac0",
+        inputs: &[pin("x", "Input", Item)],
+        outputs: &[pin("y", "Output", Item)],
+    },
+    SchemaRecord {
+        guid: "f19b8c33-dff2-4cc2-b95b-b4005ff3c10c",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "f19ee36c-f21f-4e25-be4c-4ca4b30eda0d",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("P", "Base", Item),
+            pin("Ax", "Angle X", Item).of(ValueKind::Number),
+            pin("Ay", "Angle Y", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("G", "Geometry", Item),
+            pin("X", "Transform", Item).of(ValueKind::Transform),
+        ],
+    },
+    SchemaRecord {
+        guid: "f1b75016-5818-4ece-be56-065253a2357d",
+        inputs: &[
+            pin("C", "Curves", List).of(ValueKind::Curve),
+            pin("N", "NodeSize", List),
+            pin("SP", "SizePoints", List),
+            pin("E", "EndOffset", Item),
+            pin("SS", "StrutSize", Item),
+            pin("S", "Segment", Item),
+            pin("KA", "KinkAngle", Item),
+            pin("CF", "CubeFit", Item),
+        ],
+        outputs: &[pin("P", "Pipe", Item)],
+    },
+    SchemaRecord {
+        guid: "f1c0783b-60e9-42a7-8081-925bc755494c",
+        inputs: &[
+            pin("A", "Circle A", Item).of(ValueKind::Curve),
+            pin("B", "Circle B", Item).of(ValueKind::Curve),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("A", "Arc A", Item).of(ValueKind::Curve),
+            pin("B", "Arc B", Item).of(ValueKind::Curve),
+        ],
+    },
+    SchemaRecord {
+        guid: "f1ea5a4b-1a4f-4cf4-ad94-1ecfb9302b6e",
+        inputs: &[
+            pin("A", "Plane A", Item).of(ValueKind::Plane),
+            pin("B", "Plane B", Item).of(ValueKind::Plane),
+            pin("C", "Plane C", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[
+            pin("Pt", "Point", Item).of(ValueKind::Point),
+            pin("AB", "Line AB", Item),
+            pin("AC", "Line AC", Item),
+            pin("BC", "Line BC", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "f2030fa9-db3f-437e-9b50-5607db6daf87",
+        inputs: &[pin("C", "Curve", Item).of(ValueKind::Curve)],
+        outputs: &[pin("C", "Closed", Item).of(ValueKind::Boolean)],
+    },
+    SchemaRecord {
+        guid: "f217f873-92f1-47ae-ad71-ca3c5a45c3f8",
+        inputs: &[pin("V", "Value", Item), pin("D", "Domain", Item)],
+        outputs: &[
+            pin("I", "Includes", Item),
+            pin("D", "Deviation", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "f241e42e-8983-4ed3-b869-621c07630b00",
+        inputs: &[pin("S", "Surface", Item).of(ValueKind::Surface)],
+        outputs: &[pin("U", "U dimension", Item), pin("V", "V dimension", Item)],
+    },
+    SchemaRecord {
+        guid: "f2a97ac6-4f11-4c81-834d-50ecd782675c",
+        inputs: &[
+            pin("F", "Function", Item),
+            pin("a", "a", Item),
+            pin("b", "b", Item),
+            pin("c", "c", Item),
+            pin("d", "d", Item),
+            pin("w", "w", Item),
+            pin("x", "x", Item),
+            pin("y", "y", Item),
+            pin("z", "z", Item),
+        ],
+        outputs: &[pin("r", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "f44b92b0-3b5b-493a-86f4-fd7408c3daf3",
+        inputs: &[pin("N", "Numbers", List).of(ValueKind::Number)],
+        outputs: &[pin("I", "Domain", Item)],
+    },
+    SchemaRecord {
+        guid: "f4568ce6-aade-4511-8f32-f27d8a6bf9e9",
+        inputs: &[
+            pin("E0", "Edge Start", Item),
+            pin("E1", "Edge End", Item),
+            pin("P", "Plane Point", Item),
+            pin("S", "Segments", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("P", "Polygon", Item),
+            pin("C", "Centre", Item),
+            pin("Rc", "Corner Radius", Item),
+            pin("Rc", "Edge Radius", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "f4a20a34-97e6-4ff5-9b26-7f7ed7a1e333",
+        inputs: &[
+            pin("A", "First number", Item).of(ValueKind::Number),
+            pin("B", "Second number", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "f4b0f7b4-5a10-46c4-8191-58d7d66ffdff",
+        inputs: &[
+            pin("A", "Stream A", Tree),
+            pin("B", "Stream B", Tree),
+            pin("C", "Stream C", Tree),
+            pin("D", "Stream D", Tree),
+            pin("E", "Stream E", Tree),
+        ],
+        outputs: &[pin("S", "Stream", Item)],
+    },
+    SchemaRecord {
+        guid: "f565fd67-5a98-4b48-9ea9-2e184a9ef0e6",
+        inputs: &[
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("B", "Box", Item),
+            opt("I", "Inflate", Item),
+        ],
+        outputs: &[pin("S", "Surfaces", Item).of(ValueKind::Surface)],
+    },
+    SchemaRecord {
+        guid: "f5ea9d41-f062-487e-8dbf-7666ca53fbcd",
+        inputs: &[
+            pin("V", "Vertices", List).of(ValueKind::Point),
+            opt("D", "Degree", Item).of(ValueKind::Number),
+            opt("P", "Periodic", Item).of(ValueKind::Boolean),
+        ],
+        outputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("L", "Length", Item).of(ValueKind::Number),
+            pin("D", "Domain", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "f6409a9c-3d2a-4b14-9f2c-e3c3f2cb72f8",
+        inputs: &[pin("B", "Brep", Item).of(ValueKind::Brep)],
+        outputs: &[
+            pin("B", "Brep", Item).of(ValueKind::Brep),
+            pin("C", "Caps", Item),
+            pin("S", "Solid", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "f6f14b09-6497-4564-8403-09e4eb5a6b82",
+        inputs: &[
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("A", "Angle", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("P", "Plane", Item).of(ValueKind::Plane)],
+    },
+    SchemaRecord {
+        guid: "f72c480b-7ee6-42ef-9821-c371e9203b44",
+        inputs: &[
+            pin("A", "Curves A", List).of(ValueKind::Curve),
+            pin("B", "Curves B", List).of(ValueKind::Curve),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+        ],
+        outputs: &[pin("R", "Result", List)],
+    },
+    SchemaRecord {
+        guid: "f73498c5-178b-4e09-ad61-73d172fa6e56",
+        inputs: &[
+            pin("V", "Vertices", List).of(ValueKind::Point),
+            pin("T", "Tangents", List).of(ValueKind::Vector),
+            pin("B", "Blend", Item),
+            pin("D", "Degree", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("L", "Length", Item).of(ValueKind::Number),
+            pin("D", "Domain", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "f7608c4d-836c-4adf-9d1f-3b04e6a2647d",
+        inputs: &[
+            pin("A", "Text A", Item).of(ValueKind::Text),
+            pin("B", "Text B", Item).of(ValueKind::Text),
+            pin("C", "Case", Item),
+        ],
+        outputs: &[pin("D", "Distance", Item).of(ValueKind::Number)],
+    },
+    SchemaRecord {
+        guid: "f80cfe18-9510-4b89-8301-8e58faf423bb",
+        inputs: &[pin("T", "Tree", Tree), pin("P", "Path", Item)],
+        outputs: &[pin("T", "Tree", Tree)],
+    },
+    SchemaRecord {
+        guid: "f881810b-96de-4668-a95a-f9a6d683e65c",
+        inputs: &[
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+            pin("P", "UV Point", Item),
+        ],
+        outputs: &[pin("I", "Inclusion", Item)],
+    },
+    SchemaRecord {
+        guid: "f88a6cd9-1035-4361-b896-4f2dfe79272d",
+        inputs: &[pin("C", "Curve", Item).of(ValueKind::Curve)],
+        outputs: &[
+            pin("Sl", "Shortest Length", Item),
+            pin("Sd", "Shortest Domain", Item),
+            pin("Ll", "Longest Length", Item),
+            pin("Ld", "Longest Domain", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "f8a94819-1e2b-4d67-9100-9e983ac493cc",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "f91778ca-2700-42fc-8ee6-74049a2292b5",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "f986e79a-1215-4822-a1e7-3311dbdeb851",
+        inputs: &[
+            pin("M", "Matrix", Item).of(ValueKind::Matrix),
+            pin("t", "Tolerance", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("M", "Matrix", Item).of(ValueKind::Matrix),
+            pin("S", "Success", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "f9b89a46-bc5d-4f7a-9a6f-134f93ac3af9",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "fa20fe95-5775-417b-92ff-b77c13cbf40c",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "fa314286-867b-41fa-a7f6-3f474197bb81",
+        inputs: &[
+            pin("V", "Value", Item),
+            pin("S", "Source", Item),
+            pin("T", "Target", Item),
+        ],
+        outputs: &[pin("R", "Result", Item)],
+    },
+    SchemaRecord {
+        guid: "fa36c19d-b108-440c-b33d-a0a4642b45cc",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "fa92858a-a180-4545-ad4d-0dc644b3a2a8",
+        inputs: &[pin("S", "Surface", Item).of(ValueKind::Surface)],
+        outputs: &[pin("S", "Surface", Item).of(ValueKind::Surface)],
+    },
+    SchemaRecord {
+        guid: "fab11c30-2d9c-4d15-ab3c-2289f1ae5c21",
+        inputs: &[
+            pin("A", "Breps A", List).of(ValueKind::Brep),
+            pin("B", "Breps B", List).of(ValueKind::Brep),
+        ],
+        outputs: &[pin("R", "Result", List)],
+    },
+    SchemaRecord {
+        guid: "fac0d5be-e3ff-4bbb-9742-ec9a54900d41",
+        inputs: &[
+            pin("T", "Tree", Tree),
+            pin("O", "Offset", Item),
+            pin("Wp", "Wrap Paths", Item),
+            pin("Wi", "Wrap Items", Item),
+        ],
+        outputs: &[pin("A", "Item A", Tree), pin("B", "Item B", Tree)],
+    },
+    SchemaRecord {
+        guid: "fad344bc-09b1-4855-a2e6-437ef5715fe3",
+        inputs: &[pin("O", "Origin", Item).of(ValueKind::Point)],
+        outputs: &[pin("P", "Plane", Item).of(ValueKind::Plane)],
+    },
+    SchemaRecord {
+        guid: "faf6e3bb-4c84-4cbf-bd88-6d6a0db5667a",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "fb012ef9-4734-4049-84a0-b92b85bb09da",
+        inputs: &[
+            pin("A", "Vector A", Item).of(ValueKind::Vector),
+            pin("B", "Vector B", Item).of(ValueKind::Vector),
+            pin("U", "Unitize", Item).of(ValueKind::Boolean),
+        ],
+        outputs: &[
+            pin("V", "Vector", Item).of(ValueKind::Vector),
+            pin("L", "Length", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "fbac3e32-f100-4292-8692-77240a42fd1a",
+        inputs: &[],
+        outputs: &[],
+    },
+    SchemaRecord {
+        guid: "fc6979e4-7e91-4508-8e05-37c680779751",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("t", "Parameter", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("T", "Tangent", Item).of(ValueKind::Vector),
+            pin("A", "Angle", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "fc8ad805-2cbf-4447-b41b-50c0be591fcd",
+        inputs: &[pin("S", "SubD", Item)],
+        outputs: &[
+            pin("P", "Point", List).of(ValueKind::Point),
+            pin("I", "Id", List),
+            pin("T", "Tag", List),
+        ],
+    },
+    SchemaRecord {
+        guid: "fca5ad7e-ecac-401d-a357-edda0a251cbc",
+        inputs: &[
+            pin("G", "Geometry", Item),
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("N", "Count", Item).of(ValueKind::Number),
+            pin("A", "Angle", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("G", "Geometry", List),
+            pin("X", "Transform", List).of(ValueKind::Transform),
+        ],
+    },
+    SchemaRecord {
+        guid: "fd03419e-e1cc-4603-8a57-6dfa56ed5dec",
+        inputs: &[
+            pin("G", "Group", Item),
+            pin("I", "Indices", List).of(ValueKind::Number),
+            pin("W", "Wrap", Item).of(ValueKind::Boolean),
+        ],
+        outputs: &[pin("A", "Group A", Item), pin("B", "Group B", Item)],
+    },
+    SchemaRecord {
+        guid: "fd68754e-6c60-44b2-9927-0a58146e0250",
+        inputs: &[
+            pin("C", "Center", Item).of(ValueKind::Point),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+            pin("N", "Count", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("C", "Cloud", List),
+            pin("N", "Normals", List).of(ValueKind::Vector),
+        ],
+    },
+    SchemaRecord {
+        guid: "fd9fe288-a188-4e9b-a464-1148876d18ed",
+        inputs: &[
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("R", "Radius", Item).of(ValueKind::Number),
+            pin("A", "Angle", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("A", "Arc", Item).of(ValueKind::Curve),
+            pin("L", "Length", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "fdc466a9-d3b8-4056-852a-09dba0f74aca",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("L", "Length", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("P", "Points", List).of(ValueKind::Point),
+            pin("T", "Tangents", List).of(ValueKind::Vector),
+            pin("t", "Parameters", List).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "fdedcd0a-ad40-4307-959d-d2891e2f533e",
+        inputs: &[
+            pin("P", "Plane", Item).of(ValueKind::Plane),
+            pin("Sx", "Size X", Item).of(ValueKind::Number),
+            pin("Sy", "Size Y", Item).of(ValueKind::Number),
+            pin("Ex", "Extent X", Item),
+            pin("Ey", "Extent Y", Item),
+        ],
+        outputs: &[
+            pin("C", "Cells", Tree),
+            pin("P", "Points", Tree).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "fdf09135-fae5-4e5f-b427-b1f384ca3009",
+        inputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("t", "Parameter", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("P", "Point", Item).of(ValueKind::Point),
+            pin("T", "Tangent", Item).of(ValueKind::Vector),
+            pin("L", "Length", Item).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "fe769f85-8900-45dd-ba11-ec9cd6c778c6",
+        inputs: &[
+            pin("T", "Tree", Tree),
+            pin("N0", "Minimum", Item).of(ValueKind::Number),
+            pin("N1", "Maximum", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("T", "Tree", Tree)],
+    },
+    SchemaRecord {
+        guid: "fe99f302-3d0d-4389-8494-bd53f7935a02",
+        inputs: &[
+            pin("A", "Seed A", Item).of(ValueKind::Number),
+            pin("B", "Seed B", Item).of(ValueKind::Number),
+            pin("N", "Number", Item).of(ValueKind::Number),
+        ],
+        outputs: &[pin("S", "Series", List)],
+    },
+    SchemaRecord {
+        guid: "ff187e6a-84bc-4bb9-a572-b39006a0576d",
+        inputs: &[
+            pin("B", "Brep", Item).of(ValueKind::Brep),
+            pin("L-", "Minimum", Item).of(ValueKind::Number),
+            pin("L+", "Maximum", Item).of(ValueKind::Number),
+        ],
+        outputs: &[
+            pin("E", "Edges", List),
+            pin("I", "Indices", List).of(ValueKind::Number),
+        ],
+    },
+    SchemaRecord {
+        guid: "ff880808-6daf-4f6c-88c1-058120ad6ba9",
+        inputs: &[
+            pin("B", "Brep", Item).of(ValueKind::Brep),
+            pin("L", "Line", Item).of(ValueKind::CurveLine),
+            pin("F", "First", Item),
+        ],
+        outputs: &[
+            pin("C", "Curves", List).of(ValueKind::Curve),
+            pin("P", "Points", List).of(ValueKind::Point),
+        ],
+    },
+    SchemaRecord {
+        guid: "ffdfcfc5-3933-4c38-b680-8bb530e243ff",
+        inputs: &[pin("B", "Brep", Item).of(ValueKind::Brep)],
+        outputs: &[
+            pin("V", "Volume", Item).of(ValueKind::Number),
+            pin("C", "Centroid", Item).of(ValueKind::Point),
+            pin("I", "Inertia", Item),
+            pin("I±", "Inertia (error)", Item),
+            pin("S", "Secondary", Item),
+            pin("S±", "Secondary (error)", Item),
+            pin("G", "Gyration", Item),
+        ],
+    },
+    SchemaRecord {
+        guid: "ffe2dbed-9b5d-4f91-8fe3-10c8961ac2f8",
+        inputs: &[
+            pin("S", "Surface", Item).of(ValueKind::Surface),
+            pin("uv", "UV coordinates", List),
+            pin("C", "Closed", Item).of(ValueKind::Boolean),
+        ],
+        outputs: &[
+            pin("C", "Curve", Item).of(ValueKind::Curve),
+            pin("L", "Length", Item).of(ValueKind::Number),
+            pin("D", "Domain", Item),
+        ],
+    },
+];
@@ -5,7 +5,7 @@ use std::collections::BTreeMap;
 use crate::graph::node::MetaMap;
 use crate::graph::value::Value;
 
-use super::{Component, ComponentResult};
+use super::{Component, ComponentResult, PinAccess};
 
 const EPSILON: f64 = 1e-9;
 
@@ -198,6 +198,33 @@ impl Component for ComponentKind {
             Self::Subtraction => evaluate_binary_op(inputs, |a, b| a - b),
         }
     }
+
+    fn input_access(&self) -> &'static [PinAccess] {
+        match self {
+            Self::Tangent
+            | Self::Cosine
+            | Self::ArcSine
+            | Self::Multiplication
+            | Self::Modulus
+            | Self::Minimum
+            | Self::PowerOfE
+            | Self::ArcTangent
+            | Self::NaturalLogarithm
+            | Self::Power
+            | Self::PowerOf2
+            | Self::Addition
+            | Self::ArcCosine
+            | Self::Logarithm
+            | Self::Sinc
+            | Self::Maximum
+            | Self::Division
+            | Self::Sine
+            | Self::PowerOf10
+            | Self::Subtraction => &[PinAccess::Item],
+            Self::Truncate => &[PinAccess::List, PinAccess::Item],
+            _ => &[],
+        }
+    }
 }
 
 impl ComponentKind {
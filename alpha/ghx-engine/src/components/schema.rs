@@ -0,0 +1,434 @@
+//! Pinschema's van de geregistreerde componenten.
+//!
+//! Namen, nicknames, toegang en volgorde van de pinnen komen uit
+//! [`pin_table`](super::pin_table), dat uit `nodelist/*.json` gegenereerd
+//! wordt met `cargo run --bin pin_table` ([`render_pin_table`]). Het verwachte
+//! [`ValueKind`] komt uit het `type`-veld van een nodelist-pin; zonder type
+//! valt de generator terug op de pinnaam ([`kind_from_pin_name`]) en is het
+//! `None` als ook die niets eenduidigs zegt. Een input is optioneel als de
+//! nodelist dat zegt of als de componentmodule hem via
+//! [`ComponentKind::optional_input_pins`] of [`ComponentKind::input_defaults`]
+//! declareert; de defaults zelf komen er pas bij het opbouwen van het schema bij.
+
+use std::fmt::Write as _;
+
+use serde::Serialize;
+
+use super::{ComponentKind, ComponentRegistry, PinAccess};
+use crate::catalog::{Catalog, CatalogPin};
+use crate::graph::value::{Value, ValueKind};
+
+/// Een pin zoals vastgelegd in de gegenereerde tabel.
+#[derive(Debug)]
+pub(super) struct PinRecord {
+    pub nickname: &'static str,
+    pub name: &'static str,
+    pub kind: Option<ValueKind>,
+    pub access: PinAccess,
+    pub optional: bool,
+}
+
+impl PinRecord {
+    /// Legt het verwachte itemtype van deze pin vast.
+    pub(super) const fn of(mut self, kind: ValueKind) -> Self {
+        self.kind = Some(kind);
+        self
+    }
+}
+
+/// De pinnen van één GUID uit de gegenereerde tabel.
+#[derive(Debug)]
+pub(super) struct SchemaRecord {
+    pub guid: &'static str,
+    pub inputs: &'static [PinRecord],
+    pub outputs: &'static [PinRecord],
+}
+
+/// Beschrijving van een component en zijn pinnen.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ComponentSchema {
+    pub guid: &'static str,
+    /// Naam van het component waarnaar de GUID wordt opgelost.
+    pub component: &'static str,
+    pub inputs: Vec<PinSchema>,
+    pub outputs: Vec<PinSchema>,
+}
+
+/// Beschrijving van één pin.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PinSchema {
+    pub nickname: &'static str,
+    pub name: &'static str,
+    /// Verwacht itemtype; bij list- en tree-toegang het type van de items.
+    pub kind: Option<ValueKind>,
+    pub access: PinAccess,
+    pub optional: bool,
+    pub default: Option<PinDefault>,
+}
+
+/// Waarde die een component gebruikt als een optionele input leeg blijft.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum PinDefault {
+    Number(f64),
+    Boolean(bool),
+    Text(&'static str),
+}
+
+impl PinDefault {
+    #[must_use]
+    pub fn to_value(self) -> Value {
+        match self {
+            Self::Number(number) => Value::Number(number),
+            Self::Boolean(flag) => Value::Boolean(flag),
+            Self::Text(text) => Value::Text(text.to_owned()),
+        }
+    }
+}
+
+/// Zoekt de tabelregel voor een genormaliseerde GUID.
+pub(super) fn record(guid: &str) -> Option<&'static SchemaRecord> {
+    super::pin_table::SCHEMAS
+        .binary_search_by(|record| record.guid.cmp(guid))
+        .ok()
+        .map(|index| &super::pin_table::SCHEMAS[index])
+}
+
+/// Bouwt het schema van `kind`. Optionaliteit staat al in de tabel; hier
+/// komen alleen de defaults van het component erbij.
+pub(super) fn build(record: &'static SchemaRecord, kind: ComponentKind) -> ComponentSchema {
+    let defaults = kind.input_defaults();
+
+    let inputs = record
+        .inputs
+        .iter()
+        .map(|pin| {
+            let mut schema = PinSchema::from_record(pin);
+            schema.default = defaults
+                .iter()
+                .find(|(nickname, _)| *nickname == pin.nickname)
+                .map(|(_, default)| *default);
+            schema
+        })
+        .collect();
+
+    ComponentSchema {
+        guid: record.guid,
+        component: kind.name(),
+        inputs,
+        outputs: record.outputs.iter().map(PinSchema::from_record).collect(),
+    }
+}
+
+impl PinSchema {
+    fn from_record(pin: &PinRecord) -> Self {
+        Self {
+            nickname: pin.nickname,
+            name: pin.name,
+            kind: pin.kind,
+            access: pin.access,
+            optional: pin.optional,
+            default: None,
+        }
+    }
+}
+
+/// Vertaalt een Grasshopper-typenaam uit de nodelist naar een [`ValueKind`].
+/// Algemene typen als `Generic Data` of `Geometry` geven `None`.
+#[must_use]
+pub fn kind_from_type_name(name: &str) -> Option<ValueKind> {
+    let kind = match name.trim().to_lowercase().as_str() {
+        "point" => ValueKind::Point,
+        "vector" => ValueKind::Vector,
+        "line" => ValueKind::CurveLine,
+        "curve" | "arc" | "circle" | "rectangle" | "polyline" => ValueKind::Curve,
+        "plane" => ValueKind::Plane,
+        "surface" => ValueKind::Surface,
+        "brep" => ValueKind::Brep,
+        "mesh" => ValueKind::Mesh,
+        "number" | "integer" => ValueKind::Number,
+        "boolean" => ValueKind::Boolean,
+        "text" | "string" => ValueKind::Text,
+        "domain" | "domain²" => ValueKind::Domain,
+        "colour" | "color" => ValueKind::Color,
+        "transform" => ValueKind::Transform,
+        "matrix" => ValueKind::Matrix,
+        "field" => ValueKind::Field,
+        "complex" | "complex number" => ValueKind::Complex,
+        "time" => ValueKind::DateTime,
+        _ => return None,
+    };
+    Some(kind)
+}
+
+/// Pinnamen waarvan het itemtype vastligt, ongeacht het component. Namen die
+/// per component iets anders betekenen (`Result`, `Start`, `Offset`, `Base`,
+/// ...) staan er bewust niet in.
+const PIN_NAME_KINDS: &[(&str, ValueKind)] = &[
+    ("angle", ValueKind::Number),
+    ("area", ValueKind::Number),
+    ("count", ValueKind::Number),
+    ("degree", ValueKind::Number),
+    ("deviation", ValueKind::Number),
+    ("distance", ValueKind::Number),
+    ("extent x", ValueKind::Number),
+    ("extent y", ValueKind::Number),
+    ("factor", ValueKind::Number),
+    ("first number", ValueKind::Number),
+    ("height", ValueKind::Number),
+    ("index", ValueKind::Number),
+    ("indices", ValueKind::Number),
+    ("length", ValueKind::Number),
+    ("maximum", ValueKind::Number),
+    ("minimum", ValueKind::Number),
+    ("parameter", ValueKind::Number),
+    ("parameters", ValueKind::Number),
+    ("radius", ValueKind::Number),
+    ("second number", ValueKind::Number),
+    ("seed", ValueKind::Number),
+    ("segments", ValueKind::Number),
+    ("size", ValueKind::Number),
+    ("strength", ValueKind::Number),
+    ("tolerance", ValueKind::Number),
+    ("u count", ValueKind::Number),
+    ("v count", ValueKind::Number),
+    ("volume", ValueKind::Number),
+    ("width", ValueKind::Number),
+    ("x coordinate", ValueKind::Number),
+    ("y coordinate", ValueKind::Number),
+    ("z coordinate", ValueKind::Number),
+    ("closed", ValueKind::Boolean),
+    ("periodic", ValueKind::Boolean),
+    ("strict", ValueKind::Boolean),
+    ("unitize", ValueKind::Boolean),
+    ("wrap", ValueKind::Boolean),
+    ("center", ValueKind::Point),
+    ("centroid", ValueKind::Point),
+    ("corner", ValueKind::Point),
+    ("origin", ValueKind::Point),
+    ("vertices", ValueKind::Point),
+    ("direction", ValueKind::Vector),
+    ("motion", ValueKind::Vector),
+    ("normal", ValueKind::Vector),
+    ("normals", ValueKind::Vector),
+    ("tangent", ValueKind::Vector),
+    ("tangents", ValueKind::Vector),
+    ("frame", ValueKind::Plane),
+    ("frames", ValueKind::Plane),
+];
+
+/// Leidt het itemtype van een pin zonder nodelist-type af uit zijn naam.
+///
+/// Een achtervoegsel van één teken (`Point A`, `Curve 1`, `List (B)`) telt
+/// niet mee. Typenamen als `Curve` of `Plane` en hun meervoud worden gelezen
+/// zoals [`kind_from_type_name`]; verder geldt [`PIN_NAME_KINDS`].
+#[must_use]
+pub fn kind_from_pin_name(name: &str) -> Option<ValueKind> {
+    let name = name.trim().to_lowercase();
+    let base = match name.rsplit_once(' ') {
+        Some((base, suffix)) if suffix.trim_matches(['(', ')']).chars().count() == 1 => base,
+        _ => name.as_str(),
+    };
+
+    if let Some((_, kind)) = PIN_NAME_KINDS.iter().find(|(pin, _)| *pin == base) {
+        return Some(*kind);
+    }
+    let singular = base
+        .strip_suffix("es")
+        .filter(|stem| stem.ends_with("sh"))
+        .or_else(|| base.strip_suffix('s'))
+        .unwrap_or(base);
+    // `Time` en `Domain` als pinnaam zijn vaak getallen; alleen het
+    // nodelist-type mag ze zo duiden.
+    if matches!(singular, "time" | "domain" | "domain²") {
+        return None;
+    }
+    kind_from_type_name(base).or_else(|| kind_from_type_name(singular))
+}
+
+/// Genereert de inhoud van `src/components/pin_table.rs` voor alle GUIDs uit
+/// `registry` die in `catalog` voorkomen.
+///
+/// # Panics
+///
+/// Als een nodelist-pin een andere toegang dan `item`, `list` of `tree` heeft.
+#[must_use]
+pub fn render_pin_table(registry: &ComponentRegistry, catalog: &Catalog) -> String {
+    let mut guids: Vec<(&str, ComponentKind)> = registry
+        .guids()
+        .filter(|(guid, _)| catalog.lookup(guid).is_some())
+        .collect();
+    guids.sort_unstable_by_key(|(guid, _)| *guid);
+
+    let mut body = String::new();
+    let mut uses_kinds = false;
+    for (guid, kind) in guids {
+        let entry = catalog.lookup(guid).expect("catalog entry");
+        let _ = writeln!(body, "    SchemaRecord {{");
+        let _ = writeln!(body, "        guid: {guid:?},");
+        uses_kinds |= render_pins(&mut body, "inputs", &entry.inputs, Some(kind));
+        uses_kinds |= render_pins(&mut body, "outputs", &entry.outputs, None);
+        let _ = writeln!(body, "    }},");
+    }
+
+    let mut out = String::from(
+        "//! Pinnen per geregistreerde GUID, gegenereerd uit `nodelist/*.json`.\n\
+         //!\n\
+         //! Niet met de hand bewerken; opnieuw genereren met\n\
+         //! `cargo run --bin pin_table`.\n\
+         \n\
+         use super::PinAccess::{self, Item, List, Tree};\n\
+         use super::schema::{PinRecord, SchemaRecord};\n",
+    );
+    if uses_kinds {
+        out.push_str("use crate::graph::value::ValueKind;\n");
+    }
+    out.push_str(
+        "\n\
+         const fn pin(nickname: &'static str, name: &'static str, access: PinAccess) -> PinRecord {\n\
+         \x20   PinRecord {\n\
+         \x20       nickname,\n\
+         \x20       name,\n\
+         \x20       kind: None,\n\
+         \x20       access,\n\
+         \x20       optional: false,\n\
+         \x20   }\n\
+         }\n\
+         \n\
+         const fn opt(nickname: &'static str, name: &'static str, access: PinAccess) -> PinRecord {\n\
+         \x20   PinRecord {\n\
+         \x20       nickname,\n\
+         \x20       name,\n\
+         \x20       kind: None,\n\
+         \x20       access,\n\
+         \x20       optional: true,\n\
+         \x20   }\n\
+         }\n\
+         \n\
+         pub(super) static SCHEMAS: &[SchemaRecord] = &[\n",
+    );
+    out.push_str(&body);
+    out.push_str("];\n");
+    out
+}
+
+/// Schrijft één pinlijst en geeft terug of er een type in voorkomt. Voor
+/// inputs telt ook wat `component` zelf optioneel of met default declareert.
+fn render_pins(
+    out: &mut String,
+    field: &str,
+    pins: &[CatalogPin],
+    component: Option<ComponentKind>,
+) -> bool {
+    let mut uses_kinds = false;
+    let pins: Vec<String> = pins
+        .iter()
+        .map(|pin| {
+            let access = match pin.access.as_str() {
+                "item" => "Item",
+                "list" => "List",
+                "tree" => "Tree",
+                other => panic!("onbekende toegang `{other}` voor pin {}", pin.name),
+            };
+            let declared = component.is_some_and(|kind| {
+                kind.optional_input_pins()
+                    .iter()
+                    .any(|optional| *optional == pin.nickname || *optional == pin.name)
+                    || kind
+                        .input_defaults()
+                        .iter()
+                        .any(|(nickname, _)| *nickname == pin.nickname)
+            });
+            let constructor = if pin.optional || declared {
+                "opt"
+            } else {
+                "pin"
+            };
+            let mut record = format!(
+                "{constructor}({:?}, {:?}, {access})",
+                pin.nickname, pin.name
+            );
+            let kind = pin
+                .kind
+                .as_deref()
+                .and_then(kind_from_type_name)
+                .or_else(|| kind_from_pin_name(&pin.name));
+            if let Some(kind) = kind {
+                let _ = write!(record, ".of(ValueKind::{kind:?})");
+                uses_kinds = true;
+            }
+            record
+        })
+        .collect();
+
+    // Zelfde opmaak als rustfmt: arrays tot 60 tekens blijven op één regel.
+    let items = pins.join(", ");
+    if items.chars().count() <= 60 {
+        let _ = writeln!(out, "        {field}: &[{items}],");
+        return uses_kinds;
+    }
+    let _ = writeln!(out, "        {field}: &[");
+    for pin in pins {
+        let _ = writeln!(out, "            {pin},");
+    }
+    let _ = writeln!(out, "        ],");
+    uses_kinds
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{kind_from_pin_name, kind_from_type_name, render_pin_table};
+    use crate::catalog::Catalog;
+    use crate::components::ComponentRegistry;
+    use crate::graph::value::ValueKind;
+
+    #[test]
+    fn maps_grasshopper_type_names() {
+        assert_eq!(kind_from_type_name("Point"), Some(ValueKind::Point));
+        assert_eq!(kind_from_type_name("Line"), Some(ValueKind::CurveLine));
+        assert_eq!(kind_from_type_name("Integer"), Some(ValueKind::Number));
+        assert_eq!(kind_from_type_name("Colour"), Some(ValueKind::Color));
+        assert_eq!(kind_from_type_name("Generic Data"), None);
+    }
+
+    #[test]
+    fn derives_kinds_from_pin_names() {
+        assert_eq!(kind_from_pin_name("Point A"), Some(ValueKind::Point));
+        assert_eq!(kind_from_pin_name("Curve (B)"), Some(ValueKind::Curve));
+        assert_eq!(kind_from_pin_name("Meshes"), Some(ValueKind::Mesh));
+        assert_eq!(kind_from_pin_name("Radius"), Some(ValueKind::Number));
+        assert_eq!(kind_from_pin_name("Closed"), Some(ValueKind::Boolean));
+        assert_eq!(kind_from_pin_name("Frames"), Some(ValueKind::Plane));
+        assert_eq!(kind_from_pin_name("Domains"), None);
+        assert_eq!(kind_from_pin_name("Result"), None);
+        assert_eq!(kind_from_pin_name("End Point"), None);
+    }
+
+    #[test]
+    fn pin_table_takes_kinds_from_nodelist_types() {
+        let mut catalog = Catalog::new();
+        catalog.add_nodelist_json(
+            r#"[{
+  "category": "Curve",
+  "subcategory": "Primitive",
+  "name": "Line",
+  "nickname": "Ln",
+  "guid": "4c4e56eb-2f04-43f9-95a3-cc46a14f495a",
+  "inputs": [
+    {"name": "Start Point", "nickname": "A", "access": "item", "type": "Point"},
+    {"name": "End Point", "nickname": "B", "access": "item"}
+  ],
+  "outputs": [
+    {"name": "Line", "nickname": "L", "access": "item", "type": "Line"}
+  ]
+}]"#,
+        );
+
+        let table = render_pin_table(&ComponentRegistry::default(), &catalog);
+        assert!(table.contains("use crate::graph::value::ValueKind;"));
+        assert!(table.contains(r#"pin("A", "Start Point", Item).of(ValueKind::Point)"#));
+        assert!(table.contains(r#"pin("B", "End Point", Item),"#));
+        assert!(table.contains(r#"pin("L", "Line", Item).of(ValueKind::CurveLine)"#));
+        assert_eq!(table.matches("SchemaRecord {\n").count(), 1);
+    }
+}
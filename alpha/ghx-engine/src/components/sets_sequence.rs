@@ -7,7 +7,7 @@ use rand::Rng;
 use rand::seq::SliceRandom;
 
 use super::{
-    Component, ComponentError, ComponentResult,
+    Component, ComponentError, ComponentResult, PinAccess,
     coerce::{coerce_boolean, coerce_integer, coerce_number},
 };
 
@@ -168,6 +168,17 @@ impl ComponentKind {
             Self::Jitter => "Jitter",
         }
     }
+
+    pub fn input_access(self) -> &'static [PinAccess] {
+        match self {
+            Self::Range | Self::Series | Self::Fibonacci | Self::Random => &[PinAccess::Item],
+            Self::CullIndex => &[PinAccess::List, PinAccess::List, PinAccess::Item],
+            Self::RandomReduce | Self::RepeatData | Self::DuplicateData | Self::Jitter => {
+                &[PinAccess::List, PinAccess::Item]
+            }
+            _ => &[],
+        }
+    }
 }
 
 #[derive(Debug, Default, Clone, Copy)]
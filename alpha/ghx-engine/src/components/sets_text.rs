@@ -1,6 +1,6 @@
 //! Text manipulation components for the GHX engine.
 
-use super::{Component, ComponentError, ComponentResult};
+use super::{Component, ComponentError, ComponentResult, PinAccess};
 use crate::graph::node::MetaMap;
 use crate::graph::value::Value;
 use regex::Regex;
@@ -404,6 +404,25 @@ impl Component for ComponentKind {
             Self::TextOnSurface => TextOnSurfaceComponent.evaluate(inputs, meta),
         }
     }
+
+    fn input_access(&self) -> &'static [PinAccess] {
+        match self {
+            Self::Concatenate
+            | Self::TextLength
+            | Self::TextSplit
+            | Self::TextFragment
+            | Self::Characters
+            | Self::TextCase
+            | Self::TextTrim
+            | Self::ReplaceText
+            | Self::TextDistance
+            | Self::MatchText
+            | Self::Format
+            | Self::TextOnSurface => &[PinAccess::Item],
+            Self::TextJoin => &[PinAccess::List, PinAccess::Item],
+            Self::SortText => &[PinAccess::List, PinAccess::List, PinAccess::Item],
+        }
+    }
 }
 
 impl ComponentKind {
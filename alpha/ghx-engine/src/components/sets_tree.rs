@@ -2,335 +2,171 @@
 
 use std::collections::BTreeMap;
 
+use std::collections::HashMap;
+
+use meval::{Context, Expr};
+use wildmatch::WildMatch;
+
 use crate::components::coerce::{coerce_boolean, coerce_integer, coerce_text};
 use crate::graph::node::{MetaLookupExt, MetaMap, MetaValue};
+use crate::graph::tree::{DataTree, TreePath};
 use crate::graph::value::Value;
-use wildmatch::WildMatch;
 
 use super::{Component, ComponentError, ComponentResult};
 
-#[derive(Debug)]
-struct Tree {
-    branches: BTreeMap<Vec<usize>, Vec<Value>>,
+/// Returns the value that a tree-access component should treat as a single
+/// parameter (e.g. an offset or a mask): the first item of a tree, or the value
+/// itself.
+fn param_value(value: &Value) -> Value {
+    match value {
+        Value::Tree(tree) => tree.items().next().cloned().unwrap_or(Value::Null),
+        other => other.clone(),
+    }
 }
 
-impl From<&Value> for Tree {
-    fn from(value: &Value) -> Self {
-        let mut branches = BTreeMap::new();
-        collect_branches_recursive(value, vec![], &mut branches, true);
-        Self { branches }
+/// Collects all items of a list-like parameter.
+fn param_items(value: &Value) -> Vec<Value> {
+    match value {
+        Value::Null => Vec::new(),
+        Value::List(items) => items.clone(),
+        Value::Tree(tree) => tree.flattened_items(),
+        other => vec![other.clone()],
     }
 }
 
-impl Tree {
-    fn simplify(self) -> Self {
-        if self.branches.len() <= 1 {
-            return self;
-        }
-
-        let paths: Vec<_> = self.branches.keys().collect();
-        let mut common_prefix_len = paths[0].len();
-        for path in paths.iter().skip(1) {
-            common_prefix_len = common_prefix_len.min(path.len()).min(
-                paths[0]
-                    .iter()
-                    .zip(path.iter())
-                    .take_while(|(a, b)| a == b)
-                    .count(),
-            );
-        }
+fn parse_path(path_str: &str) -> Result<TreePath, ComponentError> {
+    TreePath::parse(path_str)
+        .ok_or_else(|| ComponentError::new(format!("Invalid path: {}", path_str)))
+}
 
-        if common_prefix_len == 0 {
-            return self;
-        }
+fn trim_mask(mask: &str) -> &str {
+    mask.trim().trim_matches(|c| c == '{' || c == '}')
+}
 
-        let branches = self
-            .branches
-            .into_iter()
-            .map(|(path, values)| (path[common_prefix_len..].to_vec(), values))
-            .collect();
-        Self { branches }
-    }
+fn single_output(pin: &str, tree: DataTree) -> ComponentResult {
+    let mut outputs = BTreeMap::new();
+    outputs.insert(pin.to_owned(), Value::Tree(tree));
+    Ok(outputs)
+}
 
-    fn to_value(&self) -> Value {
-        build_tree_from_branches(&self.branches)
+fn shift_paths(tree: DataTree, offset: i64) -> DataTree {
+    let mut shifted = DataTree::new();
+    let count = offset.unsigned_abs() as usize;
+    for (path, items) in tree.into_branches() {
+        let indices = path.indices();
+        let kept = if count >= indices.len() {
+            Vec::new()
+        } else if offset >= 0 {
+            indices[count..].to_vec()
+        } else {
+            indices[..indices.len() - count].to_vec()
+        };
+        shifted.append(TreePath::new(kept), items);
     }
+    shifted
+}
 
-    fn shift_paths(self, offset: isize) -> Self {
-        let branches = self
-            .branches
-            .into_iter()
-            .map(|(path, values)| {
-                let new_path = path
-                    .into_iter()
-                    .map(|i| (i as isize + offset).max(0) as usize)
-                    .collect();
-                (new_path, values)
-            })
+fn flip_matrix(tree: &DataTree) -> DataTree {
+    let max_len = tree.branches().map(|(_, branch)| branch.len()).max().unwrap_or(0);
+    let mut flipped = DataTree::new();
+    for i in 0..max_len {
+        let column = tree
+            .branches()
+            .map(|(_, branch)| branch.get(i).cloned().unwrap_or(Value::Null))
             .collect();
-        Self { branches }
+        flipped.insert_branch(TreePath::new(vec![i]), column);
     }
+    flipped
+}
 
-    fn flattened_items(&self) -> Vec<Value> {
-        self.branches.values().flatten().cloned().collect()
-    }
-
-    fn flip_matrix(self) -> Self {
-        let mut new_branches: BTreeMap<Vec<usize>, Vec<Value>> = BTreeMap::new();
-        let mut max_len = 0;
-        let paths: Vec<_> = self.branches.keys().cloned().collect();
-        for (_, branch) in &self.branches {
-            max_len = max_len.max(branch.len());
-        }
-
-        for i in 0..max_len {
-            let mut new_branch = vec![];
-            for path in &paths {
-                new_branch.push(
-                    self.branches
-                        .get(path)
-                        .and_then(|b| b.get(i))
-                        .cloned()
-                        .unwrap_or(Value::Null),
-                );
-            }
-            new_branches.insert(vec![i], new_branch);
-        }
-
-        Self {
-            branches: new_branches,
-        }
-    }
-
-    fn unflatten_with(guide_tree: &Tree, items: &[Value]) -> Self {
-        let mut new_branches = BTreeMap::new();
-        let mut item_index = 0;
-        for (path, guide_branch) in &guide_tree.branches {
-            let mut new_branch = Vec::new();
-            for _ in 0..guide_branch.len() {
-                if item_index < items.len() {
-                    new_branch.push(items[item_index].clone());
-                    item_index += 1;
-                } else {
-                    break;
-                }
-            }
-            if !new_branch.is_empty() {
-                new_branches.insert(path.clone(), new_branch);
-            }
-        }
-        Self {
-            branches: new_branches,
-        }
-    }
-
-    fn replace_paths(
-        &self,
-        search_masks: &[Value],
-        replace_paths: &[Value],
-    ) -> Result<Self, ComponentError> {
-        let mut new_branches = self.branches.clone();
-        for (search, replace) in search_masks.iter().zip(replace_paths.iter()) {
-            let search_mask = coerce_text(search)?;
-            let replace_path_str = coerce_text(replace)?;
-
-            let search_mask = search_mask.trim_matches(|c| c == '{' || c == '}');
-            let replace_path = parse_path(&replace_path_str)?;
-
-            let wm = WildMatch::new(search_mask);
-            let mut branches_to_replace = vec![];
-
-            for (path, _) in &new_branches {
-                let path_str = path
-                    .iter()
-                    .map(|i| i.to_string())
-                    .collect::<Vec<_>>()
-                    .join(";");
-                if wm.matches(&path_str) {
-                    branches_to_replace.push(path.clone());
-                }
-            }
-
-            for path in branches_to_replace {
-                if let Some(branch) = new_branches.remove(&path) {
-                    new_branches.insert(replace_path.clone(), branch);
-                }
-            }
+fn unflatten_with(guide_tree: &DataTree, items: &[Value]) -> DataTree {
+    let mut result = DataTree::new();
+    let mut remaining = items.iter();
+    for (path, guide_branch) in guide_tree.branches() {
+        let branch: Vec<Value> = remaining.by_ref().take(guide_branch.len()).cloned().collect();
+        if !branch.is_empty() {
+            result.insert_branch(path.clone(), branch);
         }
-        Ok(Self {
-            branches: new_branches,
-        })
     }
+    result
+}
 
-    fn entwine(inputs: &[Value]) -> Self {
-        let mut new_branches = BTreeMap::new();
-        for (i, input) in inputs.iter().enumerate() {
-            if let Value::List(items) = input {
-                if items.iter().all(|item| !matches!(item, Value::List(_))) {
-                    new_branches.insert(vec![i], items.clone());
-                } else {
-                    let tree = Tree::from(input);
-                    for (path, branch) in tree.branches {
-                        let mut new_path = vec![i];
-                        new_path.extend(path);
-                        new_branches.insert(new_path, branch);
-                    }
-                }
-            } else {
-                new_branches.insert(vec![i], vec![input.clone()]);
-            }
-        }
-        Self {
-            branches: new_branches,
-        }
-    }
-
-    fn split_tree(&self, search_mask: &str) -> (Self, Self) {
-        let mut matching_branches = BTreeMap::new();
-        let mut non_matching_branches = BTreeMap::new();
-
-        let search_mask = search_mask.trim_matches(|c| c == '{' || c == '}');
-        let wm = WildMatch::new(search_mask);
-
-        for (path, branch) in &self.branches {
-            let path_str = path
-                .iter()
-                .map(|i| i.to_string())
-                .collect::<Vec<_>>()
-                .join(";");
+fn replace_paths(
+    tree: DataTree,
+    search_masks: &[Value],
+    replace_paths: &[Value],
+) -> Result<DataTree, ComponentError> {
+    let mut branches = tree.into_branches();
+    for (search, replace) in search_masks.iter().zip(replace_paths.iter()) {
+        let search_mask = coerce_text(search)?;
+        let replace_path = parse_path(&coerce_text(replace)?)?;
+        let wm = WildMatch::new(trim_mask(&search_mask));
+
+        let matching: Vec<TreePath> = branches
+            .keys()
+            .filter(|path| wm.matches(&path.to_mask_text()))
+            .cloned()
+            .collect();
 
-            if wm.matches(&path_str) {
-                matching_branches.insert(path.clone(), branch.clone());
-            } else {
-                non_matching_branches.insert(path.clone(), branch.clone());
+        for path in matching {
+            if let Some(branch) = branches.remove(&path) {
+                branches.entry(replace_path.clone()).or_default().extend(branch);
             }
         }
-
-        (
-            Self {
-                branches: matching_branches,
-            },
-            Self {
-                branches: non_matching_branches,
-            },
-        )
-    }
-
-    fn prune(&self, n: i64) -> Self {
-        let branches = self
-            .branches
-            .iter()
-            .filter(|(_, branch)| {
-                let len = branch.len();
-                if n >= 0 {
-                    len <= n as usize
-                } else {
-                    len >= n.abs() as usize
-                }
-            })
-            .map(|(path, branch)| (path.clone(), branch.clone()))
-            .collect();
-        Self { branches }
     }
+    Ok(DataTree::from_branches(branches))
 }
 
-fn collect_branches_recursive(
-    value: &Value,
-    path: Vec<usize>,
-    branches: &mut BTreeMap<Vec<usize>, Vec<Value>>,
-    is_root: bool,
-) {
-    if let Value::List(items) = value {
-        if is_root {
-            for (i, item) in items.iter().enumerate() {
-                collect_branches_recursive(item, vec![i], branches, false);
+fn entwine(inputs: &[Value]) -> DataTree {
+    let mut result = DataTree::new();
+    for (i, input) in inputs.iter().enumerate() {
+        let prefix = TreePath::new(vec![i]);
+        match input {
+            Value::Null => {}
+            Value::List(items) if items.iter().all(|item| !matches!(item, Value::List(_))) => {
+                result.insert_branch(prefix, items.clone());
             }
-        } else if items.iter().all(|item| !matches!(item, Value::List(_))) {
-            branches.insert(path, items.clone());
-        } else {
-            for (i, item) in items.iter().enumerate() {
-                let mut new_path = path.clone();
-                new_path.push(i);
-                collect_branches_recursive(item, new_path, branches, false);
+            Value::List(_) | Value::Tree(_) => {
+                for (path, branch) in DataTree::from_value(input).into_branches() {
+                    result.insert_branch(prefix.joined(&path), branch);
+                }
             }
+            other => result.insert_branch(prefix, vec![other.clone()]),
         }
-    } else {
-        branches.insert(path, vec![value.clone()]);
     }
+    result
 }
 
-#[derive(Debug)]
-enum TempNode {
-    Branch(Vec<Value>),
-    SubTree(BTreeMap<usize, TempNode>),
-}
+fn split_tree(tree: DataTree, search_mask: &str) -> (DataTree, DataTree) {
+    let wm = WildMatch::new(trim_mask(search_mask));
+    let mut matching = DataTree::new();
+    let mut non_matching = DataTree::new();
 
-fn build_tree_from_branches(branches: &BTreeMap<Vec<usize>, Vec<Value>>) -> Value {
-    if branches.is_empty() {
-        return Value::List(vec![]);
+    for (path, branch) in tree.into_branches() {
+        if wm.matches(&path.to_mask_text()) {
+            matching.insert_branch(path, branch);
+        } else {
+            non_matching.insert_branch(path, branch);
+        }
     }
 
-    let mut root = TempNode::SubTree(BTreeMap::new());
-    for (path, values) in branches {
-        insert_branch(&mut root, path, values.clone());
-    }
-    temp_node_to_value(&root)
+    (matching, non_matching)
 }
 
-fn insert_branch(node: &mut TempNode, path: &[usize], values: Vec<Value>) {
-    if path.is_empty() {
-        *node = TempNode::Branch(values);
-        return;
-    }
-
-    let index = path[0];
-    let rest_of_path = &path[1..];
-
-    if let TempNode::SubTree(map) = node {
-        let child = map.entry(index).or_insert_with(|| {
-            if rest_of_path.is_empty() {
-                TempNode::Branch(vec![])
+fn prune(tree: DataTree, n: i64) -> DataTree {
+    let branches = tree
+        .into_branches()
+        .into_iter()
+        .filter(|(_, branch)| {
+            let len = branch.len();
+            if n >= 0 {
+                len <= n as usize
             } else {
-                TempNode::SubTree(BTreeMap::new())
-            }
-        });
-        insert_branch(child, rest_of_path, values);
-    }
-}
-
-fn temp_node_to_value(node: &TempNode) -> Value {
-    match node {
-        TempNode::Branch(items) => Value::List(items.clone()),
-        TempNode::SubTree(map) => {
-            if map.is_empty() {
-                return Value::List(vec![]);
+                len >= n.unsigned_abs() as usize
             }
-            let max_index = *map.keys().max().unwrap_or(&0);
-            let mut list = Vec::with_capacity(max_index + 1);
-            for i in 0..=max_index {
-                let value = map
-                    .get(&i)
-                    .map(temp_node_to_value)
-                    .unwrap_or_else(|| Value::List(vec![]));
-                list.push(value);
-            }
-            Value::List(list)
-        }
-    }
-}
-
-fn parse_path(path_str: &str) -> Result<Vec<usize>, ComponentError> {
-    let trimmed = path_str.trim_matches(|c| c == '{' || c == '}');
-    if trimmed.is_empty() {
-        return Ok(vec![]);
-    }
-    trimmed
-        .split(';')
-        .map(|s| {
-            s.parse::<usize>()
-                .map_err(|_| ComponentError::new(format!("Invalid path segment: {}", s)))
         })
-        .collect()
+        .collect();
+    DataTree::from_branches(branches)
 }
 
 pub const REGISTRATIONS: &[Registration] = &[
@@ -513,6 +349,12 @@ pub const REGISTRATIONS: &[Registration] = &[
         &["Null Check", "Null"],
         ComponentKind::NullCheck(NullCheckComponent),
     ),
+    Registration::new(
+        "Path Mapper",
+        &["{f9b89a46-bc5d-4f7a-9a6f-134f93ac3af9}"],
+        &["Path Mapper", "PMap"],
+        ComponentKind::PathMapper(PathMapperComponent),
+    ),
     Registration::new(
         "Prune Tree",
         &["{fe769f85-8900-45dd-ba11-ec9cd6c778c6}"],
@@ -571,6 +413,7 @@ pub enum ComponentKind {
     DeconstructPath(DeconstructPathComponent),
     NullCheck(NullCheckComponent),
     PruneTree(PruneTreeComponent),
+    PathMapper(PathMapperComponent),
 }
 
 impl ComponentKind {
@@ -601,20 +444,26 @@ impl ComponentKind {
             Self::DeconstructPath(c) => c.evaluate(inputs, meta),
             Self::NullCheck(c) => c.evaluate(inputs, meta),
             Self::PruneTree(c) => c.evaluate(inputs, meta),
+            Self::PathMapper(c) => c.evaluate(inputs, meta),
         }
     }
 
     pub fn name(self) -> &'static str {
-        for registration in REGISTRATIONS {
-            // A pointer comparison should be sufficient.
-            if std::ptr::eq(&registration.kind as *const _, &self as *const _) {
-                return registration.name;
-            }
-        }
-        "Unnamed SetsTree component"
+        let discriminant = std::mem::discriminant(&self);
+        REGISTRATIONS
+            .iter()
+            .find(|registration| std::mem::discriminant(&registration.kind) == discriminant)
+            .map_or("Unnamed SetsTree component", |registration| registration.name)
+    }
+
+    /// Components that work on individual paths are evaluated per branch by
+    /// the evaluator; all others receive complete data trees.
+    pub fn handles_data_trees(self) -> bool {
+        !matches!(self, Self::PathCompare(_) | Self::DeconstructPath(_))
     }
 }
 
+
 #[derive(Debug, Default, Clone, Copy)]
 pub struct SimplifyTreeComponent;
 
@@ -625,11 +474,7 @@ impl Component for SimplifyTreeComponent {
                 "Simplify Tree component requires at least one input.",
             ));
         }
-        let tree = Tree::from(&inputs[0]);
-        let simplified_tree = tree.simplify();
-        let mut outputs = BTreeMap::new();
-        outputs.insert("T".to_string(), simplified_tree.to_value());
-        Ok(outputs)
+        single_output("T", DataTree::from_value(&inputs[0]).simplify())
     }
 }
 
@@ -644,43 +489,40 @@ impl Component for CleanTreeComponent {
             ));
         }
 
-        let remove_nulls = coerce_boolean(&inputs[0]).unwrap_or(true);
-        let remove_invalid = coerce_boolean(&inputs[1]).unwrap_or(true);
-        let remove_empty = coerce_boolean(&inputs[2]).unwrap_or(true);
-        let tree = &inputs[3];
-
-        let cleaned_tree = clean_tree(tree, remove_nulls, remove_invalid, remove_empty);
+        let remove_nulls = coerce_boolean(&param_value(&inputs[0])).unwrap_or(true);
+        let remove_invalid = coerce_boolean(&param_value(&inputs[1])).unwrap_or(true);
+        let remove_empty = coerce_boolean(&param_value(&inputs[2])).unwrap_or(true);
+        let tree = DataTree::from_value(&inputs[3]);
 
-        let mut outputs = BTreeMap::new();
-        outputs.insert("T".to_string(), cleaned_tree);
-        Ok(outputs)
+        single_output(
+            "T",
+            clean_tree(tree, remove_nulls, remove_invalid, remove_empty),
+        )
     }
 }
 
 fn clean_tree(
-    value: &Value,
+    tree: DataTree,
     remove_nulls: bool,
     remove_invalid: bool,
     remove_empty: bool,
-) -> Value {
-    match value {
-        Value::List(items) => {
-            let cleaned_items: Vec<Value> = items
-                .iter()
-                .filter_map(|item| {
-                    let cleaned_item = clean_tree(item, remove_nulls, remove_invalid, remove_empty);
-                    match &cleaned_item {
-                        Value::Null if remove_nulls || remove_invalid => None,
-                        Value::List(list) if list.is_empty() && remove_empty => None,
-                        _ => Some(cleaned_item),
-                    }
-                })
-                .collect();
-            Value::List(cleaned_items)
+) -> DataTree {
+    let mut cleaned = DataTree::new();
+    for (path, items) in tree.into_branches() {
+        let items: Vec<Value> = items
+            .into_iter()
+            .filter(|item| match item {
+                Value::Null => !(remove_nulls || remove_invalid),
+                Value::Number(number) if number.is_nan() => !remove_invalid,
+                _ => true,
+            })
+            .collect();
+        if items.is_empty() && remove_empty {
+            continue;
         }
-        Value::Null if remove_nulls || remove_invalid => Value::Null,
-        _ => value.clone(),
+        cleaned.insert_branch(path, items);
     }
+    cleaned
 }
 
 #[derive(Debug, Default, Clone, Copy)]
@@ -688,39 +530,20 @@ pub struct MergeComponent;
 
 impl Component for MergeComponent {
     fn evaluate(&self, inputs: &[Value], _meta: &MetaMap) -> ComponentResult {
-        if inputs.is_empty() {
-            return Ok(BTreeMap::new());
+        let mut merged = DataTree::new();
+        for input in inputs.iter().filter(|value| !matches!(value, Value::Null)) {
+            merged.merge(DataTree::from_value(input));
         }
 
-        let lists: Vec<_> = inputs
-            .iter()
-            .filter_map(|value| match value {
-                Value::Null => None,
-                Value::List(list) => Some(list.clone()),
-                other => Some(vec![other.clone()]),
-            })
-            .collect();
-
-        if lists.is_empty() {
+        if merged.is_empty() {
             return Ok(BTreeMap::new());
         }
 
-        let max_len = lists.iter().map(|l| l.len()).max().unwrap_or(0);
-        let mut merged = Vec::with_capacity(max_len * lists.len());
-
-        for i in 0..max_len {
-            for list in &lists {
-                if let Some(item) = list.get(i) {
-                    merged.push(item.clone());
-                }
-            }
-        }
-
         let mut outputs = BTreeMap::new();
         // Grasshopper labels the merge output pin as "R" (Result), but we also
         // keep the legacy "S" key to stay backwards compatible with earlier
         // assumptions in our engine.
-        let merged_value = Value::List(merged);
+        let merged_value = Value::Tree(merged);
         outputs.insert("R".to_string(), merged_value.clone());
         outputs.insert("S".to_string(), merged_value);
         Ok(outputs)
@@ -737,19 +560,7 @@ impl Component for GraftTreeComponent {
                 "Graft Tree component requires at least one input.",
             ));
         }
-
-        let mut grafted_tree = Vec::new();
-        if let Value::List(items) = &inputs[0] {
-            for item in items {
-                grafted_tree.push(Value::List(vec![item.clone()]));
-            }
-        } else {
-            grafted_tree.push(Value::List(vec![inputs[0].clone()]));
-        }
-
-        let mut outputs = BTreeMap::new();
-        outputs.insert("T".to_string(), Value::List(grafted_tree));
-        Ok(outputs)
+        single_output("T", DataTree::from_value(&inputs[0]).graft())
     }
 }
 
@@ -763,31 +574,17 @@ impl Component for TrimTreeComponent {
                 "Trim Tree component requires two inputs.",
             ));
         }
-        let tree = Tree::from(&inputs[0]);
-        let depth = coerce_integer(&inputs[1])? as usize;
-
-        let mut new_branches = BTreeMap::new();
-        for (path, values) in tree.branches {
-            let new_path = if path.len() > depth {
-                path[..path.len() - depth].to_vec()
-            } else {
-                vec![]
-            };
-            new_branches
-                .entry(new_path)
-                .or_insert_with(Vec::new)
-                .extend(values);
+        let tree = DataTree::from_value(&inputs[0]);
+        let depth = coerce_integer(&param_value(&inputs[1]))?.max(0) as usize;
+
+        let mut trimmed = DataTree::new();
+        for (path, values) in tree.into_branches() {
+            let indices = path.indices();
+            let kept = indices[..indices.len().saturating_sub(depth)].to_vec();
+            trimmed.append(TreePath::new(kept), values);
         }
 
-        let mut outputs = BTreeMap::new();
-        outputs.insert(
-            "T".to_string(),
-            Tree {
-                branches: new_branches,
-            }
-            .to_value(),
-        );
-        Ok(outputs)
+        single_output("T", trimmed)
     }
 }
 
@@ -804,11 +601,8 @@ impl Component for PathCompareComponent {
         let path_str = coerce_text(&inputs[0])?;
         let mask = coerce_text(&inputs[1])?;
 
-        let path_str = path_str.trim_matches(|c| c == '{' || c == '}');
-        let mask = mask.trim_matches(|c| c == '{' || c == '}');
-
-        let wm = WildMatch::new(mask);
-        let result = wm.matches(path_str);
+        let wm = WildMatch::new(trim_mask(&mask));
+        let result = wm.matches(trim_mask(&path_str));
 
         let mut outputs = BTreeMap::new();
         outputs.insert("C".to_string(), Value::Boolean(result));
@@ -826,63 +620,58 @@ impl Component for RelativeItemsComponent {
                 "Relative Items component requires at least three inputs.",
             ));
         }
-        let tree_a = Tree::from(&inputs[0]);
+        let tree_a = DataTree::from_value(&inputs[0]);
         let tree_b = if inputs.len() > 3 {
-            Tree::from(&inputs[1])
+            DataTree::from_value(&inputs[1])
         } else {
-            tree_a.clone_tree()
+            tree_a.clone()
         };
-        let offset = coerce_integer(if inputs.len() > 3 {
+        let offset = coerce_integer(&param_value(if inputs.len() > 3 {
             &inputs[2]
         } else {
             &inputs[1]
-        })?;
+        }))?;
         let wrap = if inputs.len() > 3 {
-            coerce_boolean(&inputs[3])?
+            coerce_boolean(&param_value(&inputs[3]))?
         } else {
             false
         };
-        let mut outputs = BTreeMap::new();
-        let mut result_a = BTreeMap::new();
-        let mut result_b = BTreeMap::new();
-
-        for (path, branch_a) in &tree_a.branches {
-            if let Some(branch_b) = tree_b.branches.get(path) {
-                let mut new_branch_a = vec![];
-                let mut new_branch_b = vec![];
-
-                for (i, item_a) in branch_a.iter().enumerate() {
-                    let new_index = i as i64 + offset;
-                    let item_b = if wrap {
-                        branch_b.get((new_index.rem_euclid(branch_b.len() as i64)) as usize)
-                    } else {
-                        branch_b.get(new_index as usize)
-                    };
-
-                    if let Some(item_b) = item_b {
-                        new_branch_a.push(item_a.clone());
-                        new_branch_b.push(item_b.clone());
-                    }
+        let mut result_a = DataTree::new();
+        let mut result_b = DataTree::new();
+
+        for (path, branch_a) in tree_a.branches() {
+            let Some(branch_b) = tree_b.branch(path) else {
+                continue;
+            };
+            let mut new_branch_a = vec![];
+            let mut new_branch_b = vec![];
+
+            for (i, item_a) in branch_a.iter().enumerate() {
+                let new_index = i as i64 + offset;
+                let item_b = if wrap && !branch_b.is_empty() {
+                    branch_b.get((new_index.rem_euclid(branch_b.len() as i64)) as usize)
+                } else if new_index >= 0 {
+                    branch_b.get(new_index as usize)
+                } else {
+                    None
+                };
+
+                if let Some(item_b) = item_b {
+                    new_branch_a.push(item_a.clone());
+                    new_branch_b.push(item_b.clone());
                 }
-                result_a.insert(path.clone(), new_branch_a);
-                result_b.insert(path.clone(), new_branch_b);
             }
+            result_a.insert_branch(path.clone(), new_branch_a);
+            result_b.insert_branch(path.clone(), new_branch_b);
         }
 
-        outputs.insert("A".to_string(), Tree { branches: result_a }.to_value());
-        outputs.insert("B".to_string(), Tree { branches: result_b }.to_value());
+        let mut outputs = BTreeMap::new();
+        outputs.insert("A".to_string(), Value::Tree(result_a));
+        outputs.insert("B".to_string(), Value::Tree(result_b));
         Ok(outputs)
     }
 }
 
-impl Tree {
-    fn clone_tree(&self) -> Self {
-        Tree {
-            branches: self.branches.clone(),
-        }
-    }
-}
-
 #[derive(Debug, Default, Clone, Copy)]
 pub struct ShiftPathsComponent;
 
@@ -893,14 +682,10 @@ impl Component for ShiftPathsComponent {
                 "Shift Paths component requires two inputs.",
             ));
         }
-        let tree = Tree::from(&inputs[0]);
-        let offset = coerce_integer(&inputs[1])? as isize;
-
-        let shifted_tree = tree.shift_paths(offset);
+        let tree = DataTree::from_value(&inputs[0]);
+        let offset = coerce_integer(&param_value(&inputs[1]))?;
 
-        let mut outputs = BTreeMap::new();
-        outputs.insert("D".to_string(), shifted_tree.to_value());
-        Ok(outputs)
+        single_output("D", shift_paths(tree, offset))
     }
 }
 
@@ -914,16 +699,12 @@ impl Component for TreeBranchComponent {
                 "Tree Branch component requires two inputs.",
             ));
         }
-        let tree = Tree::from(&inputs[0]);
-        let path_str = coerce_text(&inputs[1])?;
-        let path = parse_path(&path_str)?;
+        let tree = DataTree::from_value(&inputs[0]);
+        let path = parse_path(&coerce_text(&param_value(&inputs[1]))?)?;
 
+        let branch = tree.branch(&path).map(<[Value]>::to_vec).unwrap_or_default();
         let mut outputs = BTreeMap::new();
-        if let Some(branch) = tree.branches.get(&path) {
-            outputs.insert("B".to_string(), Value::List(branch.clone()));
-        } else {
-            outputs.insert("B".to_string(), Value::List(vec![]));
-        }
+        outputs.insert("B".to_string(), Value::List(branch));
         Ok(outputs)
     }
 }
@@ -955,7 +736,7 @@ impl Component for StreamFilterComponent {
                 "Stream Filter component requires at least two inputs.",
             ));
         }
-        let gate = coerce_integer(&inputs[0])? as usize;
+        let gate = coerce_integer(&param_value(&inputs[0]))? as usize;
         if gate < inputs.len() - 1 {
             let mut outputs = BTreeMap::new();
             let output_pin = stream_filter_output_pin(meta);
@@ -977,11 +758,7 @@ impl Component for FlipMatrixComponent {
                 "Flip Matrix component requires one input.",
             ));
         }
-        let tree = Tree::from(&inputs[0]);
-        let flipped_tree = tree.flip_matrix();
-        let mut outputs = BTreeMap::new();
-        outputs.insert("D".to_string(), flipped_tree.to_value());
-        Ok(outputs)
+        single_output("D", flip_matrix(&DataTree::from_value(&inputs[0])))
     }
 }
 
@@ -995,15 +772,10 @@ impl Component for MatchTreeComponent {
                 "Match Tree component requires two inputs.",
             ));
         }
-        let tree_to_modify = Tree::from(&inputs[0]);
-        let guide_tree = Tree::from(&inputs[1]);
-
-        let items = tree_to_modify.flattened_items();
-        let new_tree = Tree::unflatten_with(&guide_tree, &items);
+        let items = DataTree::from_value(&inputs[0]).flattened_items();
+        let guide_tree = DataTree::from_value(&inputs[1]);
 
-        let mut outputs = BTreeMap::new();
-        outputs.insert("T".to_string(), new_tree.to_value());
-        Ok(outputs)
+        single_output("T", unflatten_with(&guide_tree, &items))
     }
 }
 
@@ -1017,7 +789,7 @@ impl Component for StreamGateComponent {
                 "Stream Gate component requires at least two inputs.",
             ));
         }
-        let gate = coerce_integer(&inputs[0])? as usize;
+        let gate = coerce_integer(&param_value(&inputs[0]))? as usize;
         let mut outputs = BTreeMap::new();
 
         for i in 1..inputs.len() {
@@ -1042,18 +814,13 @@ impl Component for ExplodeTreeComponent {
                 "Explode Tree component requires one input.",
             ));
         }
-        let tree = Tree::from(&inputs[0]);
+        let tree = DataTree::from_value(&inputs[0]);
         let mut outputs = BTreeMap::new();
 
-        for (i, (path, branch)) in tree.branches.iter().enumerate() {
-            let mut branch_tree = BTreeMap::new();
-            branch_tree.insert(path.clone(), branch.clone());
+        for (i, (path, branch)) in tree.into_branches().into_iter().enumerate() {
             outputs.insert(
                 format!("Branch {}", i),
-                Tree {
-                    branches: branch_tree,
-                }
-                .to_value(),
+                Value::Tree(DataTree::with_branch(path, branch)),
             );
         }
 
@@ -1072,23 +839,22 @@ impl Component for ConstructPathComponent {
             ));
         }
 
-        let indices = match &inputs[0] {
-            Value::List(l) => l,
-            _ => {
-                return Err(ComponentError::new(
-                    "Construct Path component requires a list of integers.",
-                ));
-            }
-        };
-
-        let path = indices
+        let indices = param_items(&inputs[0])
             .iter()
-            .map(|v| coerce_integer(v).map(|i| i.to_string()))
-            .collect::<Result<Vec<_>, _>>()?
-            .join(";");
+            .map(|value| {
+                coerce_integer(value).and_then(|index| {
+                    usize::try_from(index).map_err(|_| {
+                        ComponentError::new(format!("Invalid path index: {}", index))
+                    })
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
 
         let mut outputs = BTreeMap::new();
-        outputs.insert("B".to_string(), Value::Text(format!("{{{}}}", path)));
+        outputs.insert(
+            "B".to_string(),
+            Value::Text(TreePath::new(indices).to_string()),
+        );
         Ok(outputs)
     }
 }
@@ -1104,28 +870,19 @@ impl Component for TreeStatisticsComponent {
             ));
         }
 
-        let tree = Tree::from(&inputs[0]);
+        let tree = DataTree::from_value(&inputs[0]);
 
         let paths: Vec<Value> = tree
-            .branches
-            .keys()
-            .map(|path| {
-                let path_str = path
-                    .iter()
-                    .map(|i| i.to_string())
-                    .collect::<Vec<_>>()
-                    .join(";");
-                Value::Text(format!("{{{}}}", path_str))
-            })
+            .paths()
+            .map(|path| Value::Text(path.to_string()))
             .collect();
 
         let lengths: Vec<Value> = tree
-            .branches
-            .values()
-            .map(|branch| Value::Number(branch.len() as f64))
+            .branches()
+            .map(|(_, branch)| Value::Number(branch.len() as f64))
             .collect();
 
-        let count = Value::Number(tree.branches.len() as f64);
+        let count = Value::Number(tree.branch_count() as f64);
 
         let mut outputs = BTreeMap::new();
         outputs.insert("P".to_string(), Value::List(paths));
@@ -1146,22 +903,32 @@ impl Component for FlattenTreeComponent {
             ));
         }
 
-        let mut flattened = Vec::new();
-        flatten_recursive(&inputs[0], &mut flattened);
+        let path = match inputs.get(1).map(param_value) {
+            Some(Value::Null) | None => TreePath::root(),
+            Some(value) => parse_path(&coerce_text(&value)?)?,
+        };
 
-        let mut outputs = BTreeMap::new();
-        outputs.insert("T".to_string(), Value::List(flattened));
-        Ok(outputs)
+        let mut items = Vec::new();
+        flatten_recursive(&inputs[0], &mut items);
+
+        single_output("T", DataTree::with_branch(path, items))
     }
 }
 
 fn flatten_recursive(value: &Value, flattened: &mut Vec<Value>) {
-    if let Value::List(items) = value {
-        for item in items {
-            flatten_recursive(item, flattened);
+    match value {
+        Value::List(items) => {
+            for item in items {
+                flatten_recursive(item, flattened);
+            }
+        }
+        Value::Tree(tree) => {
+            for item in tree.items() {
+                flatten_recursive(item, flattened);
+            }
         }
-    } else {
-        flattened.push(value.clone());
+        Value::Null => {}
+        other => flattened.push(other.clone()),
     }
 }
 
@@ -1176,15 +943,10 @@ impl Component for UnflattenTreeComponent {
             ));
         }
 
-        let items_to_unflatten = Tree::from(&inputs[0]).flattened_items();
-        let guide_tree = Tree::from(&inputs[1]);
-
-        let result_tree = Tree::unflatten_with(&guide_tree, &items_to_unflatten);
-        let result_value = result_tree.to_value();
+        let items_to_unflatten = DataTree::from_value(&inputs[0]).flattened_items();
+        let guide_tree = DataTree::from_value(&inputs[1]);
 
-        let mut outputs = BTreeMap::new();
-        outputs.insert("T".to_string(), result_value);
-        Ok(outputs)
+        single_output("T", unflatten_with(&guide_tree, &items_to_unflatten))
     }
 }
 
@@ -1199,30 +961,16 @@ impl Component for ReplacePathsComponent {
             ));
         }
 
-        let tree = Tree::from(&inputs[0]);
-        let search_masks = match &inputs[1] {
-            Value::List(l) => l,
-            _ => {
-                return Err(ComponentError::new(
-                    "Replace Paths component requires a list of search masks.",
-                ));
-            }
-        };
-        let replace_paths = match &inputs[2] {
-            Value::List(l) => l,
-            _ => {
-                return Err(ComponentError::new(
-                    "Replace Paths component requires a list of replace paths.",
-                ));
-            }
-        };
-
-        let result_tree = tree.replace_paths(search_masks, replace_paths)?;
-        let result_value = result_tree.to_value();
+        let tree = DataTree::from_value(&inputs[0]);
+        let search_masks = param_items(&inputs[1]);
+        let replacements = param_items(&inputs[2]);
+        if search_masks.is_empty() || replacements.is_empty() {
+            return Err(ComponentError::new(
+                "Replace Paths component requires search masks and replace paths.",
+            ));
+        }
 
-        let mut outputs = BTreeMap::new();
-        outputs.insert("D".to_string(), result_value);
-        Ok(outputs)
+        single_output("D", replace_paths(tree, &search_masks, &replacements)?)
     }
 }
 
@@ -1237,35 +985,30 @@ impl Component for TreeItemComponent {
             ));
         }
 
-        let tree = Tree::from(&inputs[0]);
-        let path_str = coerce_text(&inputs[1])?;
-        let path = parse_path(&path_str)?;
-        let index = coerce_integer(&inputs[2])?;
+        let tree = DataTree::from_value(&inputs[0]);
+        let path = parse_path(&coerce_text(&param_value(&inputs[1]))?)?;
+        let index = coerce_integer(&param_value(&inputs[2]))?;
         let wrap = if inputs.len() > 3 {
-            coerce_boolean(&inputs[3])?
+            coerce_boolean(&param_value(&inputs[3]))?
         } else {
             false
         };
 
-        let mut outputs = BTreeMap::new();
-        if let Some(branch) = tree.branches.get(&path) {
-            if branch.is_empty() {
-                outputs.insert("E".to_string(), Value::Null);
-            } else {
-                let item = if wrap {
-                    let wrapped_index = index.rem_euclid(branch.len() as i64) as usize;
-                    branch[wrapped_index].clone()
+        let item = match tree.branch(&path) {
+            Some(branch) if !branch.is_empty() => {
+                if wrap {
+                    branch[index.rem_euclid(branch.len() as i64) as usize].clone()
                 } else if index >= 0 && (index as usize) < branch.len() {
                     branch[index as usize].clone()
                 } else {
                     Value::Null
-                };
-                outputs.insert("E".to_string(), item);
+                }
             }
-        } else {
-            outputs.insert("E".to_string(), Value::Null);
-        }
+            _ => Value::Null,
+        };
 
+        let mut outputs = BTreeMap::new();
+        outputs.insert("E".to_string(), item);
         Ok(outputs)
     }
 }
@@ -1275,12 +1018,7 @@ pub struct EntwineComponent;
 
 impl Component for EntwineComponent {
     fn evaluate(&self, inputs: &[Value], _meta: &MetaMap) -> ComponentResult {
-        let result_tree = Tree::entwine(inputs);
-        let result_value = result_tree.to_value();
-
-        let mut outputs = BTreeMap::new();
-        outputs.insert("R".to_string(), result_value);
-        Ok(outputs)
+        single_output("R", entwine(inputs))
     }
 }
 
@@ -1295,14 +1033,14 @@ impl Component for SplitTreeComponent {
             ));
         }
 
-        let tree = Tree::from(&inputs[0]);
-        let mask = coerce_text(&inputs[1])?;
+        let tree = DataTree::from_value(&inputs[0]);
+        let mask = coerce_text(&param_value(&inputs[1]))?;
 
-        let (matching_tree, non_matching_tree) = tree.split_tree(&mask);
+        let (matching_tree, non_matching_tree) = split_tree(tree, &mask);
 
         let mut outputs = BTreeMap::new();
-        outputs.insert("P".to_string(), matching_tree.to_value());
-        outputs.insert("N".to_string(), non_matching_tree.to_value());
+        outputs.insert("P".to_string(), Value::Tree(matching_tree));
+        outputs.insert("N".to_string(), Value::Tree(non_matching_tree));
         Ok(outputs)
     }
 }
@@ -1318,12 +1056,12 @@ impl Component for DeconstructPathComponent {
             ));
         }
 
-        let path_str = coerce_text(&inputs[0])?;
-        let path = parse_path(&path_str)?;
+        let path = parse_path(&coerce_text(&inputs[0])?)?;
 
         let segments: Vec<Value> = path
-            .into_iter()
-            .map(|segment| Value::Number(segment as f64))
+            .indices()
+            .iter()
+            .map(|segment| Value::Number(*segment as f64))
             .collect();
 
         let mut outputs = BTreeMap::new();
@@ -1343,8 +1081,7 @@ impl Component for NullCheckComponent {
             ));
         }
 
-        let tree = Tree::from(&inputs[0]);
-        let items = tree.flattened_items();
+        let items = DataTree::from_value(&inputs[0]).flattened_items();
 
         let mut null_items = Vec::new();
         let mut valid_items = Vec::new();
@@ -1381,14 +1118,297 @@ impl Component for PruneTreeComponent {
             ));
         }
 
-        let tree = Tree::from(&inputs[0]);
-        let n = coerce_integer(&inputs[1])?;
+        let tree = DataTree::from_value(&inputs[0]);
+        let n = coerce_integer(&param_value(&inputs[1]))?;
+
+        single_output("T", prune(tree, n))
+    }
+}
 
-        let pruned_tree = tree.prune(n);
-        let result_value = pruned_tree.to_value();
+/// Path Mapper: herschikt takken met lexicale maskers zoals `{A;B} -> {B;A}` of
+/// `{A;B}(i) -> {A}(i+B)`. De maskers komen uit de `SourceMasks`/`TargetMasks`
+/// meta die de GHX-parser vult. Takken die met geen enkel bronmasker
+/// overeenkomen blijven ongewijzigd.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PathMapperComponent;
+
+impl Component for PathMapperComponent {
+    fn evaluate(&self, inputs: &[Value], meta: &MetaMap) -> ComponentResult {
+        let tree = inputs.first().map(DataTree::from_value).unwrap_or_default();
+
+        let sources = meta_texts(meta, "SourceMasks");
+        let targets = meta_texts(meta, "TargetMasks");
+        let mappings = sources
+            .iter()
+            .zip(&targets)
+            .map(|(source, target)| Ok((PathMask::parse(source)?, PathMask::parse(target)?)))
+            .collect::<Result<Vec<_>, ComponentError>>()?;
+
+        let mapped = if mappings.is_empty() {
+            tree
+        } else {
+            map_paths(tree, &mappings)?
+        };
 
         let mut outputs = BTreeMap::new();
-        outputs.insert("T".to_string(), result_value);
+        let mapped = Value::Tree(mapped);
+        outputs.insert("D".to_string(), mapped.clone());
+        outputs.insert("R".to_string(), mapped);
         Ok(outputs)
     }
 }
+
+fn meta_texts(meta: &MetaMap, key: &str) -> Vec<String> {
+    match meta.get_normalized(key) {
+        Some(MetaValue::Text(text)) => vec![text.clone()],
+        Some(MetaValue::List(items)) => items
+            .iter()
+            .filter_map(|item| match item {
+                MetaValue::Text(text) => Some(text.clone()),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Een lexicaal padmasker: segmenten tussen `{}` en een optionele itemindex
+/// tussen `()`.
+#[derive(Debug)]
+struct PathMask {
+    segments: Vec<String>,
+    item: Option<String>,
+}
+
+impl PathMask {
+    fn parse(text: &str) -> Result<Self, ComponentError> {
+        let text = text.trim();
+        let open = text.find('{');
+        let close = text.rfind('}');
+        let (Some(open), Some(close)) = (open, close) else {
+            return Err(ComponentError::new(format!("Invalid path mask: {}", text)));
+        };
+        if close < open {
+            return Err(ComponentError::new(format!("Invalid path mask: {}", text)));
+        }
+
+        let segments = text[open + 1..close]
+            .split(';')
+            .map(|segment| segment.trim().to_owned())
+            .filter(|segment| !segment.is_empty())
+            .collect();
+
+        let rest = text[close + 1..].trim();
+        let item = rest
+            .strip_prefix('(')
+            .and_then(|rest| rest.strip_suffix(')'))
+            .map(|item| item.trim().to_owned())
+            .filter(|item| !item.is_empty());
+
+        Ok(Self { segments, item })
+    }
+
+    /// Koppelt de variabelen van dit bronmasker aan de indices van `path`.
+    fn bind(&self, path: &TreePath) -> Option<HashMap<String, f64>> {
+        let indices = path.indices();
+        if indices.len() != self.segments.len() {
+            return None;
+        }
+
+        let mut variables = HashMap::new();
+        for (segment, &index) in self.segments.iter().zip(indices) {
+            if segment == "*" || segment == "?" {
+                continue;
+            }
+            if let Ok(literal) = segment.parse::<usize>() {
+                if literal != index {
+                    return None;
+                }
+                continue;
+            }
+            let value = index as f64;
+            if let Some(previous) = variables.insert(segment.clone(), value) {
+                if previous != value {
+                    return None;
+                }
+            }
+        }
+        Some(variables)
+    }
+
+    fn evaluate_path(&self, variables: &HashMap<String, f64>) -> Result<TreePath, ComponentError> {
+        self.segments
+            .iter()
+            .map(|segment| evaluate_mask_expression(segment, variables))
+            .collect::<Result<Vec<_>, _>>()
+            .map(TreePath::new)
+    }
+}
+
+fn evaluate_mask_expression(
+    expression: &str,
+    variables: &HashMap<String, f64>,
+) -> Result<usize, ComponentError> {
+    let expr: Expr = expression.parse().map_err(|error| {
+        ComponentError::new(format!("Invalid path mask expression `{expression}`: {error}"))
+    })?;
+
+    let mut context = Context::new();
+    for (name, value) in variables {
+        context.var(name.clone(), *value);
+    }
+
+    let value = expr.eval_with_context(context).map_err(|error| {
+        ComponentError::new(format!("Could not evaluate path mask `{expression}`: {error}"))
+    })?;
+
+    if !value.is_finite() || value.round() < 0.0 {
+        return Err(ComponentError::new(format!(
+            "Path mask `{expression}` produced an invalid index: {value}"
+        )));
+    }
+    Ok(value.round() as usize)
+}
+
+fn map_paths(tree: DataTree, mappings: &[(PathMask, PathMask)]) -> Result<DataTree, ComponentError> {
+    let mut mapped = DataTree::new();
+    // Items met een expliciete doelindex worden eerst verzameld zodat de
+    // volgorde binnen een tak niet afhangt van de volgorde van de bron.
+    let mut indexed: BTreeMap<TreePath, BTreeMap<usize, Value>> = BTreeMap::new();
+
+    for (path, items) in tree.into_branches() {
+        let Some((source, target, variables)) = mappings
+            .iter()
+            .find_map(|(source, target)| source.bind(&path).map(|vars| (source, target, vars)))
+        else {
+            mapped.append(path, items);
+            continue;
+        };
+
+        let per_item = source.item.is_some() || target.item.is_some();
+        if !per_item {
+            mapped.append(target.evaluate_path(&variables)?, items);
+            continue;
+        }
+
+        let count = items.len() as f64;
+        for (index, item) in items.into_iter().enumerate() {
+            let mut variables = variables.clone();
+            variables.insert("item_count".to_owned(), count);
+            if let Some(name) = source.item.as_ref() {
+                variables.insert(name.clone(), index as f64);
+            }
+
+            let target_path = target.evaluate_path(&variables)?;
+            match target.item.as_ref() {
+                Some(expression) => {
+                    let target_index = evaluate_mask_expression(expression, &variables)?;
+                    indexed
+                        .entry(target_path)
+                        .or_default()
+                        .insert(target_index, item);
+                }
+                None => mapped.append(target_path, [item]),
+            }
+        }
+    }
+
+    for (path, items) in indexed {
+        let len = items.keys().next_back().map_or(0, |last| last + 1);
+        let mut branch = vec![Value::Null; len];
+        for (index, item) in items {
+            branch[index] = item;
+        }
+        mapped.append(path, branch);
+    }
+
+    Ok(mapped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Component, PathMapperComponent, ShiftPathsComponent};
+    use crate::components::OutputMap;
+    use crate::graph::node::{MetaMap, MetaValue};
+    use crate::graph::tree::{DataTree, TreePath};
+    use crate::graph::value::Value;
+
+    fn sample_tree() -> DataTree {
+        let mut tree = DataTree::with_branch(
+            TreePath::new(vec![0, 0]),
+            vec![Value::Number(1.0), Value::Number(2.0)],
+        );
+        tree.insert_branch(TreePath::new(vec![0, 1]), vec![Value::Number(3.0)]);
+        tree
+    }
+
+    fn output_tree(outputs: &OutputMap, pin: &str) -> DataTree {
+        match outputs.get(pin) {
+            Some(Value::Tree(tree)) => tree.clone(),
+            other => panic!("verwachtte een datatree, kreeg {other:?}"),
+        }
+    }
+
+    #[test]
+    fn path_mapper_swaps_path_segments() {
+        let mut meta = MetaMap::new();
+        meta.insert(
+            "SourceMasks".to_owned(),
+            MetaValue::List(vec![MetaValue::Text("{A;B}".to_owned())]),
+        );
+        meta.insert(
+            "TargetMasks".to_owned(),
+            MetaValue::List(vec![MetaValue::Text("{B;A}".to_owned())]),
+        );
+
+        let outputs = PathMapperComponent
+            .evaluate(&[Value::Tree(sample_tree())], &meta)
+            .expect("mapping slaagt");
+        let paths: Vec<String> = output_tree(&outputs, "D")
+            .paths()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(paths, ["{0;0}", "{1;0}"]);
+    }
+
+    #[test]
+    fn path_mapper_places_items_by_index_expression() {
+        let mut meta = MetaMap::new();
+        meta.insert("SourceMasks".to_owned(), MetaValue::Text("{A;B}(i)".to_owned()));
+        meta.insert("TargetMasks".to_owned(), MetaValue::Text("{i}(B)".to_owned()));
+
+        let outputs = PathMapperComponent
+            .evaluate(&[Value::Tree(sample_tree())], &meta)
+            .expect("mapping slaagt");
+        let tree = output_tree(&outputs, "D");
+        assert_eq!(
+            tree.branch(&TreePath::new(vec![0])),
+            Some(&[Value::Number(1.0), Value::Number(3.0)][..])
+        );
+        assert_eq!(
+            tree.branch(&TreePath::new(vec![1])),
+            Some(&[Value::Number(2.0)][..])
+        );
+    }
+
+    #[test]
+    fn shift_paths_drops_leading_or_trailing_indices() {
+        let inputs = [Value::Tree(sample_tree()), Value::Number(1.0)];
+        let outputs = ShiftPathsComponent
+            .evaluate(&inputs, &MetaMap::new())
+            .expect("shift slaagt");
+        let paths: Vec<String> = output_tree(&outputs, "D")
+            .paths()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(paths, ["{0}", "{1}"]);
+
+        let inputs = [Value::Tree(sample_tree()), Value::Number(-1.0)];
+        let outputs = ShiftPathsComponent
+            .evaluate(&inputs, &MetaMap::new())
+            .expect("shift slaagt");
+        let shifted = output_tree(&outputs, "D");
+        assert_eq!(shifted.branch_count(), 1);
+        assert_eq!(shifted.item_count(), 3);
+    }
+}
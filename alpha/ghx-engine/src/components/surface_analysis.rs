@@ -17,13 +17,14 @@ use std::collections::{BTreeMap, HashMap};
 
 use crate::geom::{
     classify_point_in_mesh, closest_point_on_mesh, GeomMesh, Point3 as GeomPoint3,
-    PointContainment, Tolerance as GeomTolerance,
+    PointContainment, Tolerance as GeomTolerance, surface_closest_point,
     // Surface curvature analysis
     analyze_surface_curvature, VertexGridSurface,
 };
 use crate::graph::node::MetaMap;
-use crate::graph::value::Value;
+use crate::graph::value::{BrepFace, Value};
 
+use super::coerce::coerce_brep;
 use super::{Component, ComponentError, ComponentResult};
 
 const PIN_OUTPUT_CURVES: &str = "C";
@@ -71,6 +72,7 @@ const PIN_OUTPUT_FACE_EDGE: &str = "FE";
 const PIN_OUTPUT_EDGE_FACE: &str = "EF";
 
 const EPSILON: f64 = 1e-9;
+const CLOSEST_POINT_SAMPLES: usize = 32;
 
 // ============================================================================
 // Mesh/Surface Detection Helpers
@@ -121,6 +123,14 @@ fn is_surface_or_mesh(value: Option<&Value>) -> bool {
     }
 }
 
+/// Returns the analytic face of a single-face `Value::Brep` input.
+///
+/// Components that evaluate `(u, v)` parameters use this before falling back
+/// to grid reconstruction from meshes.
+fn analytic_face(value: Option<&Value>) -> Option<&BrepFace> {
+    coerce_brep(value?, "Surface").ok()?.single_face()
+}
+
 // ============================================================================
 // VertexGridSurface Extraction Helper
 // ============================================================================
@@ -478,6 +488,19 @@ impl Component for ComponentKind {
 }
 
 impl ComponentKind {
+    /// Components that evaluate analytic `Value::Brep` surfaces directly.
+    #[must_use]
+    pub fn handles_breps(&self) -> bool {
+        matches!(
+            self,
+            Self::EvaluateSurface
+                | Self::PrincipalCurvature
+                | Self::SurfaceCurvature
+                | Self::SurfaceClosestPoint
+                | Self::PointInTrim
+        )
+    }
+
     #[must_use]
     pub fn name(&self) -> &'static str {
         match self {
@@ -889,10 +912,14 @@ fn evaluate_surface_sample_component(inputs: &[Value]) -> ComponentResult {
     
     let uv = coerce_uv(inputs.get(1)).unwrap_or((0.5, 0.5));
     
-    // Try to extract a vertex grid surface for proper evaluation
-    if let Some(grid_surface) = try_extract_vertex_grid_surface(inputs.get(0)) {
-        // Use proper surface analysis
-        let analysis = analyze_surface_curvature(&grid_surface, uv.0, uv.1);
+    // Analytic surfaces are evaluated exactly; meshes with a grid structure
+    // are reconstructed as a vertex grid surface.
+    let analysis = match analytic_face(inputs.first()) {
+        Some(face) => Some(analyze_surface_curvature(&face.surface, uv.0, uv.1)),
+        None => try_extract_vertex_grid_surface(inputs.get(0))
+            .map(|grid_surface| analyze_surface_curvature(&grid_surface, uv.0, uv.1)),
+    };
+    if let Some(analysis) = analysis {
         
         let point = [analysis.point.x, analysis.point.y, analysis.point.z];
         let normal = [analysis.normal.x, analysis.normal.y, analysis.normal.z];
@@ -945,9 +972,13 @@ fn evaluate_principal_curvature(inputs: &[Value]) -> ComponentResult {
     
     let uv = coerce_uv(inputs.get(1)).unwrap_or((0.5, 0.5));
     
-    // Try to extract a vertex grid surface for proper curvature analysis
-    if let Some(grid_surface) = try_extract_vertex_grid_surface(inputs.get(0)) {
-        let analysis = analyze_surface_curvature(&grid_surface, uv.0, uv.1);
+    // Try the analytic surface first, then a vertex grid reconstruction
+    let analysis = match analytic_face(inputs.first()) {
+        Some(face) => Some(analyze_surface_curvature(&face.surface, uv.0, uv.1)),
+        None => try_extract_vertex_grid_surface(inputs.get(0))
+            .map(|grid_surface| analyze_surface_curvature(&grid_surface, uv.0, uv.1)),
+    };
+    if let Some(analysis) = analysis {
         
         if !analysis.valid {
             // Degenerate point - return zeros with warning
@@ -1027,9 +1058,13 @@ fn evaluate_surface_curvature(inputs: &[Value]) -> ComponentResult {
     
     let uv = coerce_uv(inputs.get(1)).unwrap_or((0.5, 0.5));
     
-    // Try to extract a vertex grid surface for proper curvature analysis
-    if let Some(grid_surface) = try_extract_vertex_grid_surface(inputs.get(0)) {
-        let analysis = analyze_surface_curvature(&grid_surface, uv.0, uv.1);
+    // Try the analytic surface first, then a vertex grid reconstruction
+    let analysis = match analytic_face(inputs.first()) {
+        Some(face) => Some(analyze_surface_curvature(&face.surface, uv.0, uv.1)),
+        None => try_extract_vertex_grid_surface(inputs.get(0))
+            .map(|grid_surface| analyze_surface_curvature(&grid_surface, uv.0, uv.1)),
+    };
+    if let Some(analysis) = analysis {
         
         let point = [analysis.point.x, analysis.point.y, analysis.point.z];
         let normal = [analysis.normal.x, analysis.normal.y, analysis.normal.z];
@@ -1078,6 +1113,20 @@ fn evaluate_surface_closest_point(inputs: &[Value]) -> ComponentResult {
         ));
     }
     let target = coerce_point(inputs.get(0), "Surface Closest Point punt")?;
+
+    if let Some(face) = analytic_face(inputs.get(1)) {
+        let query = GeomPoint3::new(target[0], target[1], target[2]);
+        let (u, v, point) = surface_closest_point(&face.surface, query, CLOSEST_POINT_SAMPLES);
+        let closest = [point.x, point.y, point.z];
+        let mut outputs = BTreeMap::new();
+        outputs.insert(PIN_OUTPUT_POINTS.to_owned(), Value::Point(closest));
+        outputs.insert(PIN_OUTPUT_UV_POINT.to_owned(), Value::Point([u, v, 0.0]));
+        outputs.insert(
+            PIN_OUTPUT_DISTANCE.to_owned(),
+            Value::Number(distance(&target, &closest)),
+        );
+        return Ok(outputs);
+    }
     
     // Try to use proper mesh-based closest point computation first
     if let Some(mesh_result) = try_mesh_closest_point(inputs.get(1), target) {
@@ -1494,7 +1543,10 @@ fn evaluate_point_in_trim(inputs: &[Value]) -> ComponentResult {
     )?;
     
     let uv = coerce_uv(inputs.get(1)).unwrap_or((0.5, 0.5));
-    let inside = (0.0..=1.0).contains(&uv.0) && (0.0..=1.0).contains(&uv.1);
+    let inside = match analytic_face(inputs.first()) {
+        Some(face) => face.contains_uv(uv.0, uv.1),
+        None => (0.0..=1.0).contains(&uv.0) && (0.0..=1.0).contains(&uv.1),
+    };
     let mut outputs = BTreeMap::new();
     outputs.insert(PIN_OUTPUT_INSIDE.to_owned(), Value::Boolean(inside));
     Ok(outputs)
@@ -4269,4 +4321,75 @@ mod tests {
         let result = evaluate_dimensions(&[mesh_list]);
         assert!(result.is_err(), "Dimensions should reject mesh wrapped in list");
     }
+
+    fn make_test_sphere_brep() -> Value {
+        use crate::geom::SphereSurface;
+        use crate::graph::value::{BrepValue, SurfaceValue};
+
+        let sphere = SphereSurface::new(GeomPoint3::new(0.0, 0.0, 0.0), 2.0).unwrap();
+        Value::Brep(BrepValue::from_surface(SurfaceValue::Sphere(sphere)))
+    }
+
+    #[test]
+    fn evaluate_surface_uses_analytic_brep() {
+        let outputs = evaluate_surface_sample_component(&[
+            make_test_sphere_brep(),
+            Value::Point([0.0, 0.5, 0.0]),
+        ])
+        .expect("Evaluate Surface should accept a brep");
+
+        let Some(Value::Point(point)) = outputs.get(PIN_OUTPUT_POINTS) else {
+            panic!("missing point output");
+        };
+        assert!((point[0] - 2.0).abs() < 1e-9);
+        assert!(point[1].abs() < 1e-9 && point[2].abs() < 1e-9);
+
+        let Some(Value::Vector(normal)) = outputs.get(PIN_OUTPUT_NORMAL) else {
+            panic!("missing normal output");
+        };
+        assert!((normal[0].abs() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn surface_closest_point_projects_onto_analytic_brep() {
+        let outputs = evaluate_surface_closest_point(&[
+            Value::Point([0.0, 0.0, 5.0]),
+            make_test_sphere_brep(),
+        ])
+        .expect("Surface Closest Point should accept a brep");
+
+        let Some(Value::Point(point)) = outputs.get(PIN_OUTPUT_POINTS) else {
+            panic!("missing point output");
+        };
+        assert!((point[2] - 2.0).abs() < 1e-6);
+        let Some(Value::Number(distance)) = outputs.get(PIN_OUTPUT_DISTANCE) else {
+            panic!("missing distance output");
+        };
+        assert!((distance - 3.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn point_in_trim_respects_trim_loops() {
+        use crate::geom::{PlaneSurface, TrimLoop, TrimRegion, UvPoint, Vec3};
+        use crate::graph::value::{BrepValue, SurfaceValue};
+
+        let plane = PlaneSurface::new(
+            GeomPoint3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        );
+        let outer = TrimLoop::new(
+            vec![UvPoint::new(0.0, 0.0), UvPoint::new(1.0, 0.0), UvPoint::new(0.0, 1.0)],
+            GeomTolerance::default(),
+        )
+        .unwrap();
+        let region = TrimRegion::from_loops(vec![outer], GeomTolerance::default()).unwrap();
+        let brep = Value::Brep(BrepValue::from_trimmed_surface(SurfaceValue::Plane(plane), region));
+
+        let inside = evaluate_point_in_trim(&[brep.clone(), Value::Point([0.2, 0.2, 0.0])]).unwrap();
+        assert_eq!(inside.get(PIN_OUTPUT_INSIDE), Some(&Value::Boolean(true)));
+
+        let outside = evaluate_point_in_trim(&[brep, Value::Point([0.8, 0.8, 0.0])]).unwrap();
+        assert_eq!(outside.get(PIN_OUTPUT_INSIDE), Some(&Value::Boolean(false)));
+    }
 }
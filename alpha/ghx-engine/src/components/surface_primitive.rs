@@ -3,14 +3,17 @@
 //! This module uses `geom::surface` primitives (CylinderSurface, ConeSurface,
 //! SphereSurface, PlaneSurface) and the shared mesh pipeline when the
 //! `mesh_engine_next` feature is enabled. Components remain thin wrappers that
-//! coerce inputs, build geom surfaces, mesh them, and return outputs.
+//! coerce inputs, build geom surfaces, mesh them, and return outputs. Plane
+//! Surface returns its analytic surface as a `Value::Brep` and leaves meshing
+//! to the consumer.
 
 use std::collections::BTreeMap;
 use std::f64::consts::TAU;
 
 use crate::graph::node::MetaMap;
-use crate::graph::value::{Domain, MeshDiagnostics, Value};
+use crate::graph::value::{BrepValue, Domain, MeshDiagnostics, SurfaceValue, Value};
 
+use super::schema::PinDefault;
 use super::{Component, ComponentError, ComponentResult};
 
 // Import geom types for surface primitives and meshing
@@ -206,6 +209,15 @@ impl Component for ComponentKind {
 }
 
 impl ComponentKind {
+    /// Waarden die gebruikt worden als de betreffende input leeg blijft.
+    #[must_use]
+    pub fn input_defaults(&self) -> &'static [(&'static str, PinDefault)] {
+        match self {
+            Self::PlaneThroughBox => &[("I", PinDefault::Number(0.0))],
+            _ => &[],
+        }
+    }
+
     #[must_use]
     pub fn name(&self) -> &'static str {
         match self {
@@ -468,12 +480,15 @@ fn evaluate_plane_surface(inputs: &[Value]) -> ComponentResult {
     let size_x = coerce_number(inputs.get(1), "Plane Surface X")?;
     let size_y = coerce_number(inputs.get(2), "Plane Surface Y")?;
 
-    let dual_output = create_plane_surface(&plane, size_x, size_y);
+    let surface = plane_surface_from_bounds(&plane, -size_x / 2.0, size_x / 2.0, -size_y / 2.0, size_y / 2.0);
 
+    // The analytic plane is meshed only when it is displayed or consumed by a
+    // mesh-based component.
     let mut outputs = BTreeMap::new();
-    // Legacy surface on existing "P" pin for backward compatibility
-    // New mesh on append-only "M" pin with normals, UVs, and diagnostics
-    dual_output.insert_into(&mut outputs, PIN_OUTPUT_PLANE);
+    outputs.insert(
+        PIN_OUTPUT_PLANE.to_owned(),
+        Value::Brep(BrepValue::from_surface(SurfaceValue::Plane(surface))),
+    );
     Ok(outputs)
 }
 
@@ -783,17 +798,6 @@ fn create_cone_surface(plane: &Plane, radius: f64, height: f64) -> (DualMeshOutp
     create_cone_mesh_geom(plane, radius, height)
 }
 
-/// Creates a plane surface mesh using the geom pipeline.
-///
-/// This function now uses `geom::PlaneSurface` and the shared mesh pipeline
-/// to create proper meshes with normals and UVs.
-///
-/// Returns a `DualMeshOutput` containing both `Value::Mesh` (primary) and
-/// `Value::Surface` (legacy) for backward compatibility.
-fn create_plane_surface(plane: &Plane, size_x: f64, size_y: f64) -> DualMeshOutput {
-    create_plane_mesh_geom(plane, size_x, size_y)
-}
-
 /// Creates a planar surface mesh from bounds using the geom pipeline.
 ///
 /// Returns a `DualMeshOutput` containing both `Value::Mesh` (primary) and
@@ -1204,14 +1208,26 @@ fn create_quad_sphere_surface(plane: &Plane, radius: f64) -> DualMeshOutput {
     }
 }
 
-/// Creates a plane surface mesh using geom::PlaneSurface and the shared mesh pipeline.
+/// Builds a `geom::PlaneSurface` spanning the given bounds in plane coordinates.
 ///
-/// Returns a `DualMeshOutput` containing both `Value::Mesh` (primary) and
-/// `Value::Surface` (legacy) for backward compatibility.
-fn create_plane_mesh_geom(plane: &Plane, size_x: f64, size_y: f64) -> DualMeshOutput {
-    let half_x = size_x / 2.0;
-    let half_y = size_y / 2.0;
-    create_planar_mesh_from_bounds_geom(plane, -half_x, half_x, -half_y, half_y)
+/// The surface origin is the `(min_x, min_y)` corner and its axes are scaled
+/// to the bounds, so the `(u, v)` domain is the unit square.
+fn plane_surface_from_bounds(
+    plane: &Plane,
+    min_x: f64,
+    max_x: f64,
+    min_y: f64,
+    max_y: f64,
+) -> GeomPlaneSurface {
+    let u_axis = to_geom_vec(plane.x_axis);
+    let v_axis = to_geom_vec(plane.y_axis);
+    let origin = to_geom_point(plane.apply(min_x, min_y, 0.0));
+
+    GeomPlaneSurface::new(
+        origin,
+        u_axis.mul_scalar(max_x - min_x),
+        v_axis.mul_scalar(max_y - min_y),
+    )
 }
 
 /// Creates a planar surface mesh from bounds using geom::PlaneSurface.
@@ -1225,23 +1241,7 @@ fn create_planar_mesh_from_bounds_geom(
     min_y: f64,
     max_y: f64,
 ) -> DualMeshOutput {
-    // Build the geom PlaneSurface
-    // The plane surface uses u_axis and v_axis scaled to the bounds
-    let u_axis = to_geom_vec(plane.x_axis);
-    let v_axis = to_geom_vec(plane.y_axis);
-    
-    // Calculate corner of the plane (bottom-left in plane coordinates)
-    let origin = to_geom_point(plane.apply(min_x, min_y, 0.0));
-    
-    // Scale axes to span the bounds
-    let u_span = max_x - min_x;
-    let v_span = max_y - min_y;
-    
-    let plane_surface = GeomPlaneSurface::new(
-        origin,
-        u_axis.mul_scalar(u_span),
-        v_axis.mul_scalar(v_span),
-    );
+    let plane_surface = plane_surface_from_bounds(plane, min_x, max_x, min_y, max_y);
 
     // Mesh the plane - simple 2x2 grid is sufficient for a flat surface
     let u_count = 2;
@@ -8,6 +8,7 @@ use delaunator;
 use crate::graph::node::MetaMap;
 use crate::graph::value::{Domain, Domain1D, Domain2D, Value};
 
+use super::schema::PinDefault;
 use super::{Component, ComponentError, ComponentResult};
 
 const PIN_OUTPUT_POINTS: &str = "P";
@@ -221,6 +222,15 @@ impl Component for ComponentKind {
 }
 
 impl ComponentKind {
+    /// Waarden die gebruikt worden als de betreffende input leeg blijft.
+    #[must_use]
+    pub fn input_defaults(&self) -> &'static [(&'static str, PinDefault)] {
+        match self {
+            Self::ClosedEdges => &[("T", PinDefault::Boolean(true))],
+            _ => &[],
+        }
+    }
+
     #[must_use]
     pub fn name(&self) -> &'static str {
         match self {
@@ -2,6 +2,7 @@
 
 use std::collections::BTreeMap;
 
+use crate::geom::{Point3, Transform};
 use crate::graph::node::MetaMap;
 use crate::graph::value::Value;
 
@@ -163,6 +164,13 @@ impl Component for ComponentKind {
 }
 
 impl ComponentKind {
+    /// Componenten die exacte `Value::Curve` invoer rechtstreeks transformeren.
+    /// Rectangle en Triangle Mapping lezen hun referentievormen als punten.
+    #[must_use]
+    pub fn handles_curves(self) -> bool {
+        !matches!(self, Self::RectangleMapping | Self::TriangleMapping)
+    }
+
     #[must_use]
     pub fn name(&self) -> &'static str {
         match self {
@@ -200,18 +208,12 @@ fn evaluate_project_along(inputs: &[Value]) -> ComponentResult {
         let along = dot(vector, plane.normal());
         subtract(vector, scale(direction, along))
     };
+    let transform = transform_value(&point_fn);
     let transformed = map_geometry(&geometry, &mut point_fn, &mut vector_fn);
 
     let mut outputs = BTreeMap::new();
     outputs.insert(PIN_OUTPUT_GEOMETRY.to_owned(), transformed);
-    outputs.insert(
-        PIN_OUTPUT_TRANSFORM.to_owned(),
-        Value::List(vec![
-            Value::Text("Project Along".into()),
-            Value::Point(plane.origin),
-            Value::Vector(direction),
-        ]),
-    );
+    outputs.insert(PIN_OUTPUT_TRANSFORM.to_owned(), transform);
     Ok(outputs)
 }
 
@@ -252,21 +254,13 @@ fn evaluate_orient_direction(inputs: &[Value], include_transform: bool) -> Compo
         add(point_b, rotated)
     };
     let mut vector_fn = |vector: [f64; 3]| rotate_vector(vector, axis, angle);
+    let transform = transform_value(&point_fn);
     let transformed = map_geometry(&geometry, &mut point_fn, &mut vector_fn);
 
     let mut outputs = BTreeMap::new();
     outputs.insert(PIN_OUTPUT_GEOMETRY.to_owned(), transformed);
     if include_transform {
-        outputs.insert(
-            PIN_OUTPUT_TRANSFORM.to_owned(),
-            Value::List(vec![
-                Value::Text("Orient Direction".into()),
-                Value::Point(point_a),
-                Value::Point(point_b),
-                Value::Vector(axis),
-                Value::Number(angle),
-            ]),
-        );
+        outputs.insert(PIN_OUTPUT_TRANSFORM.to_owned(), transform);
     }
     Ok(outputs)
 }
@@ -296,18 +290,12 @@ fn evaluate_rectangle_mapping(inputs: &[Value]) -> ComponentResult {
 
     let mut point_fn = |point: [f64; 3]| map_between_bounds(point, &source_bounds, &target_bounds);
     let mut vector_fn = |vector: [f64; 3]| scale_components(vector, ratios);
+    let transform = transform_value(&point_fn);
     let transformed = map_geometry(&geometry, &mut point_fn, &mut vector_fn);
 
     let mut outputs = BTreeMap::new();
     outputs.insert(PIN_OUTPUT_GEOMETRY.to_owned(), transformed);
-    outputs.insert(
-        PIN_OUTPUT_TRANSFORM.to_owned(),
-        Value::List(vec![
-            Value::Text("Rectangle Mapping".into()),
-            Value::Point(source_bounds.min),
-            Value::Point(target_bounds.min),
-        ]),
-    );
+    outputs.insert(PIN_OUTPUT_TRANSFORM.to_owned(), transform);
     Ok(outputs)
 }
 
@@ -327,19 +315,13 @@ fn evaluate_project(inputs: &[Value], include_transform: bool) -> ComponentResul
         let normal_component = scale(plane.normal(), dot(vector, plane.normal()));
         subtract(vector, normal_component)
     };
+    let transform = transform_value(&point_fn);
     let transformed = map_geometry(&geometry, &mut point_fn, &mut vector_fn);
 
     let mut outputs = BTreeMap::new();
     outputs.insert(PIN_OUTPUT_GEOMETRY.to_owned(), transformed);
     if include_transform {
-        outputs.insert(
-            PIN_OUTPUT_TRANSFORM.to_owned(),
-            Value::List(vec![
-                Value::Text("Project".into()),
-                Value::Point(plane.origin),
-                Value::Vector(plane.normal()),
-            ]),
-        );
+        outputs.insert(PIN_OUTPUT_TRANSFORM.to_owned(), transform);
     }
     Ok(outputs)
 }
@@ -368,19 +350,13 @@ fn evaluate_scale_non_uniform(inputs: &[Value], include_transform: bool) -> Comp
         let local = plane.vector_to_local(vector);
         plane.vector_from_local([local[0] * scale_x, local[1] * scale_y, local[2] * scale_z])
     };
+    let transform = transform_value(&point_fn);
     let transformed = map_geometry(&geometry, &mut point_fn, &mut vector_fn);
 
     let mut outputs = BTreeMap::new();
     outputs.insert(PIN_OUTPUT_GEOMETRY.to_owned(), transformed);
     if include_transform {
-        outputs.insert(
-            PIN_OUTPUT_TRANSFORM.to_owned(),
-            Value::List(vec![
-                Value::Text("Scale NU".into()),
-                Value::Point(plane.origin),
-                Value::Vector([scale_x, scale_y, scale_z]),
-            ]),
-        );
+        outputs.insert(PIN_OUTPUT_TRANSFORM.to_owned(), transform);
     }
     Ok(outputs)
 }
@@ -418,19 +394,13 @@ fn evaluate_shear(inputs: &[Value], include_transform: bool) -> ComponentResult
         local[1] += delta_local[1] * factor;
         plane.vector_from_local(local)
     };
+    let transform = transform_value(&point_fn);
     let transformed = map_geometry(&geometry, &mut point_fn, &mut vector_fn);
 
     let mut outputs = BTreeMap::new();
     outputs.insert(PIN_OUTPUT_GEOMETRY.to_owned(), transformed);
     if include_transform {
-        outputs.insert(
-            PIN_OUTPUT_TRANSFORM.to_owned(),
-            Value::List(vec![
-                Value::Text("Shear".into()),
-                Value::Point(grip),
-                Value::Point(target),
-            ]),
-        );
+        outputs.insert(PIN_OUTPUT_TRANSFORM.to_owned(), transform);
     }
     Ok(outputs)
 }
@@ -451,18 +421,12 @@ fn evaluate_camera_obscura(inputs: &[Value]) -> ComponentResult {
 
     let mut point_fn = |point: [f64; 3]| add(focus, scale(subtract(point, focus), factor));
     let mut vector_fn = |vector: [f64; 3]| scale(vector, factor);
+    let transform = transform_value(&point_fn);
     let transformed = map_geometry(&geometry, &mut point_fn, &mut vector_fn);
 
     let mut outputs = BTreeMap::new();
     outputs.insert(PIN_OUTPUT_GEOMETRY.to_owned(), transformed);
-    outputs.insert(
-        PIN_OUTPUT_TRANSFORM.to_owned(),
-        Value::List(vec![
-            Value::Text("Camera Obscura".into()),
-            Value::Point(focus),
-            Value::Number(factor),
-        ]),
-    );
+    outputs.insert(PIN_OUTPUT_TRANSFORM.to_owned(), transform);
     Ok(outputs)
 }
 
@@ -482,19 +446,13 @@ fn evaluate_scale(inputs: &[Value], include_transform: bool) -> ComponentResult
 
     let mut point_fn = |point: [f64; 3]| add(center, scale(subtract(point, center), factor));
     let mut vector_fn = |vector: [f64; 3]| scale(vector, factor);
+    let transform = transform_value(&point_fn);
     let transformed = map_geometry(&geometry, &mut point_fn, &mut vector_fn);
 
     let mut outputs = BTreeMap::new();
     outputs.insert(PIN_OUTPUT_GEOMETRY.to_owned(), transformed);
     if include_transform {
-        outputs.insert(
-            PIN_OUTPUT_TRANSFORM.to_owned(),
-            Value::List(vec![
-                Value::Text("Scale".into()),
-                Value::Point(center),
-                Value::Number(factor),
-            ]),
-        );
+        outputs.insert(PIN_OUTPUT_TRANSFORM.to_owned(), transform);
     }
     Ok(outputs)
 }
@@ -516,18 +474,12 @@ fn evaluate_triangle_mapping(inputs: &[Value]) -> ComponentResult {
 
     let mut point_fn = |point: [f64; 3]| mapper.map_point(point);
     let mut vector_fn = |vector: [f64; 3]| mapper.map_vector(vector);
+    let transform = transform_value(&point_fn);
     let transformed = map_geometry(&geometry, &mut point_fn, &mut vector_fn);
 
     let mut outputs = BTreeMap::new();
     outputs.insert(PIN_OUTPUT_GEOMETRY.to_owned(), transformed);
-    outputs.insert(
-        PIN_OUTPUT_TRANSFORM.to_owned(),
-        Value::List(vec![
-            Value::Text("Triangle Mapping".into()),
-            Value::Point(source_triangle[0]),
-            Value::Point(target_triangle[0]),
-        ]),
-    );
+    outputs.insert(PIN_OUTPUT_TRANSFORM.to_owned(), transform);
     Ok(outputs)
 }
 
@@ -560,19 +512,13 @@ fn evaluate_shear_angle(inputs: &[Value], include_transform: bool) -> ComponentR
         local[1] += local[2] * shear_y;
         plane.vector_from_local(local)
     };
+    let transform = transform_value(&point_fn);
     let transformed = map_geometry(&geometry, &mut point_fn, &mut vector_fn);
 
     let mut outputs = BTreeMap::new();
     outputs.insert(PIN_OUTPUT_GEOMETRY.to_owned(), transformed);
     if include_transform {
-        outputs.insert(
-            PIN_OUTPUT_TRANSFORM.to_owned(),
-            Value::List(vec![
-                Value::Text("Shear Angle".into()),
-                Value::Number(angle_x),
-                Value::Number(angle_y),
-            ]),
-        );
+        outputs.insert(PIN_OUTPUT_TRANSFORM.to_owned(), transform);
     }
     Ok(outputs)
 }
@@ -602,21 +548,22 @@ fn evaluate_box_mapping(inputs: &[Value]) -> ComponentResult {
 
     let mut point_fn = |point: [f64; 3]| map_between_bounds(point, &source_bounds, &target_bounds);
     let mut vector_fn = |vector: [f64; 3]| scale_components(vector, ratios);
+    let transform = transform_value(&point_fn);
     let transformed = map_geometry(&geometry, &mut point_fn, &mut vector_fn);
 
     let mut outputs = BTreeMap::new();
     outputs.insert(PIN_OUTPUT_GEOMETRY.to_owned(), transformed);
-    outputs.insert(
-        PIN_OUTPUT_TRANSFORM.to_owned(),
-        Value::List(vec![
-            Value::Text("Box Mapping".into()),
-            Value::Point(source_bounds.min),
-            Value::Point(target_bounds.min),
-        ]),
-    );
+    outputs.insert(PIN_OUTPUT_TRANSFORM.to_owned(), transform);
     Ok(outputs)
 }
 
+/// Captures the affine point map of a component as a `Value::Transform`.
+fn transform_value(point_fn: &impl Fn([f64; 3]) -> [f64; 3]) -> Value {
+    Value::Transform(Transform::from_affine_map(|p| {
+        Point3::from_array(point_fn(p.to_array()))
+    }))
+}
+
 fn map_geometry<FPoint, FVector>(
     value: &Value,
     point_fn: &mut FPoint,
@@ -686,6 +633,7 @@ where
                 diagnostics: diagnostics.clone(),
             }
         }
+        Value::Curve(curve) => Value::Curve(curve.map_points(&mut *point_fn)),
         Value::List(values) => {
             let mut mapped = Vec::with_capacity(values.len());
             for value in values {
@@ -2,6 +2,7 @@
 
 use std::collections::BTreeMap;
 
+use crate::geom::{Point3, Transform, Vec3};
 use crate::graph::node::MetaMap;
 use crate::graph::value::Value;
 
@@ -77,6 +78,13 @@ impl Component for ComponentKind {
 }
 
 impl ComponentKind {
+    /// Componenten die exacte `Value::Curve` invoer rechtstreeks transformeren.
+    /// Curve Array leest zijn pad nog als lijnstuk.
+    #[must_use]
+    pub fn handles_curves(self) -> bool {
+        !matches!(self, Self::CurveArray)
+    }
+
     #[must_use]
     pub fn name(&self) -> &'static str {
         match self {
@@ -139,10 +147,7 @@ fn evaluate_box_array(inputs: &[Value], _meta: &MetaMap) -> ComponentResult {
                 let mut point_fn = |point: [f64; 3]| add(point, translation);
                 let mut vector_fn = |vector: [f64; 3]| vector;
                 geometries.push(map_geometry(&geometry, &mut point_fn, &mut vector_fn));
-                transforms.push(Value::List(vec![
-                    Value::Text("Move".into()),
-                    Value::Vector(translation),
-                ]));
+                transforms.push(translation_value(translation));
             }
         }
     }
@@ -206,10 +211,7 @@ fn evaluate_kaleidoscope(inputs: &[Value], _meta: &MetaMap) -> ComponentResult {
             &mut final_point_fn,
             &mut final_vector_fn,
         ));
-        transforms.push(Value::List(vec![
-            Value::Text("Kaleidoscope".into()),
-            Value::Number(i as f64),
-        ]));
+        transforms.push(transform_value(&final_point_fn));
     }
 
     let mut outputs = BTreeMap::new();
@@ -256,11 +258,7 @@ fn evaluate_curve_array(inputs: &[Value], _meta: &MetaMap) -> ComponentResult {
         };
 
         geometries.push(map_geometry(&geometry, &mut point_fn, &mut vector_fn));
-        transforms.push(Value::List(vec![
-            Value::Text("Orient".into()),
-            Value::Point([0.0, 0.0, 0.0]),
-            Value::Point(point),
-        ]));
+        transforms.push(transform_value(&point_fn));
     }
 
     let mut outputs = BTreeMap::new();
@@ -314,10 +312,7 @@ fn evaluate_rectangular_array(inputs: &[Value], _meta: &MetaMap) -> ComponentRes
             let mut point_fn = |point: [f64; 3]| add(point, translation);
             let mut vector_fn = |vector: [f64; 3]| vector;
             geometries.push(map_geometry(&geometry, &mut point_fn, &mut vector_fn));
-            transforms.push(Value::List(vec![
-                Value::Text("Move".into()),
-                Value::Vector(translation),
-            ]));
+            transforms.push(translation_value(translation));
         }
     }
 
@@ -350,10 +345,7 @@ fn evaluate_linear_array(inputs: &[Value], _meta: &MetaMap) -> ComponentResult {
         let mut point_fn = |point: [f64; 3]| add(point, translation);
         let mut vector_fn = |vector: [f64; 3]| vector;
         geometries.push(map_geometry(&geometry, &mut point_fn, &mut vector_fn));
-        transforms.push(Value::List(vec![
-            Value::Text("Move".into()),
-            Value::Vector(translation),
-        ]));
+        transforms.push(translation_value(translation));
     }
 
     let mut outputs = BTreeMap::new();
@@ -394,12 +386,7 @@ fn evaluate_polar_array(inputs: &[Value], _meta: &MetaMap) -> ComponentResult {
         };
         let mut vector_fn = |vector: [f64; 3]| rotate_vector(vector, plane.z_axis, current_angle);
         geometries.push(map_geometry(&geometry, &mut point_fn, &mut vector_fn));
-        transforms.push(Value::List(vec![
-            Value::Text("Rotate".into()),
-            Value::Point(plane.origin),
-            Value::Vector(plane.z_axis),
-            Value::Number(current_angle),
-        ]));
+        transforms.push(transform_value(&point_fn));
     }
 
     let mut outputs = BTreeMap::new();
@@ -409,6 +396,17 @@ fn evaluate_polar_array(inputs: &[Value], _meta: &MetaMap) -> ComponentResult {
     Ok(outputs)
 }
 
+fn translation_value(translation: [f64; 3]) -> Value {
+    Value::Transform(Transform::translate(Vec3::from_array(translation)))
+}
+
+/// Captures the affine point map of one array copy as a `Value::Transform`.
+fn transform_value(point_fn: &impl Fn([f64; 3]) -> [f64; 3]) -> Value {
+    Value::Transform(Transform::from_affine_map(|p| {
+        Point3::from_array(point_fn(p.to_array()))
+    }))
+}
+
 /// Epsilon for numerical comparisons in geometry transformations.
 const EPSILON: f64 = 1e-9;
 
@@ -485,6 +483,7 @@ where
                 diagnostics: diagnostics.clone(),
             }
         }
+        Value::Curve(curve) => Value::Curve(curve.map_points(&mut *point_fn)),
         Value::List(values) => {
             // Process list elements in deterministic order (index 0, 1, 2, ...).
             let mut mapped = Vec::with_capacity(values.len());
@@ -2,6 +2,7 @@
 
 use std::collections::BTreeMap;
 
+use crate::geom::{Point3, Transform, Vec3};
 use crate::graph::node::MetaMap;
 use crate::graph::value::Value;
 
@@ -149,20 +150,12 @@ fn evaluate_move(inputs: &[Value], include_transform: bool) -> ComponentResult {
         outputs.insert(
             PIN_OUTPUT_TRANSFORM.to_owned(),
             if translations.len() == 1 {
-                Value::List(vec![
-                    Value::Text("Move".into()),
-                    Value::Vector(translations[0]),
-                ])
+                translation_value(translations[0])
             } else {
                 Value::List(
                     translations
                         .iter()
-                        .map(|translation| {
-                            Value::List(vec![
-                                Value::Text("Move".into()),
-                                Value::Vector(*translation),
-                            ])
-                        })
+                        .map(|translation| translation_value(*translation))
                         .collect(),
                 )
             },
@@ -194,20 +187,14 @@ fn evaluate_orient(inputs: &[Value], include_transform: bool) -> ComponentResult
         let local = source_plane.vector_to_local(vector);
         target_plane.vector_from_local(local)
     };
+    let transform = transform_value(&point_fn);
     let transformed = map_geometry(&geometry, &mut point_fn, &mut vector_fn);
 
     let mut outputs = BTreeMap::new();
     outputs.insert(PIN_OUTPUT_GEOMETRY.to_owned(), transformed);
 
     if include_transform {
-        outputs.insert(
-            PIN_OUTPUT_TRANSFORM.to_owned(),
-            Value::List(vec![
-                Value::Text("Orient".into()),
-                Value::Point(source_plane.origin),
-                Value::Point(target_plane.origin),
-            ]),
-        );
+        outputs.insert(PIN_OUTPUT_TRANSFORM.to_owned(), transform);
     }
 
     Ok(outputs)
@@ -234,21 +221,14 @@ fn evaluate_rotate_3d(inputs: &[Value], include_transform: bool) -> ComponentRes
         add(rotated, center)
     };
     let mut vector_fn = |vector: [f64; 3]| rotate_vector(vector, axis, angle);
+    let transform = transform_value(&point_fn);
     let transformed = map_geometry(&geometry, &mut point_fn, &mut vector_fn);
 
     let mut outputs = BTreeMap::new();
     outputs.insert(PIN_OUTPUT_GEOMETRY.to_owned(), transformed);
 
     if include_transform {
-        outputs.insert(
-            PIN_OUTPUT_TRANSFORM.to_owned(),
-            Value::List(vec![
-                Value::Text("Rotate 3D".into()),
-                Value::Point(center),
-                Value::Vector(axis),
-                Value::Number(angle),
-            ]),
-        );
+        outputs.insert(PIN_OUTPUT_TRANSFORM.to_owned(), transform);
     }
 
     Ok(outputs)
@@ -274,21 +254,14 @@ fn evaluate_rotate(inputs: &[Value], include_transform: bool) -> ComponentResult
         add(rotated, plane.origin)
     };
     let mut vector_fn = |vector: [f64; 3]| rotate_vector(vector, plane.z_axis, angle);
+    let transform = transform_value(&point_fn);
     let transformed = map_geometry(&geometry, &mut point_fn, &mut vector_fn);
 
     let mut outputs = BTreeMap::new();
     outputs.insert(PIN_OUTPUT_GEOMETRY.to_owned(), transformed);
 
     if include_transform {
-        outputs.insert(
-            PIN_OUTPUT_TRANSFORM.to_owned(),
-            Value::List(vec![
-                Value::Text("Rotate".into()),
-                Value::Point(plane.origin),
-                Value::Vector(plane.z_axis),
-                Value::Number(angle),
-            ]),
-        );
+        outputs.insert(PIN_OUTPUT_TRANSFORM.to_owned(), transform);
     }
 
     Ok(outputs)
@@ -296,6 +269,17 @@ fn evaluate_rotate(inputs: &[Value], include_transform: bool) -> ComponentResult
 
 // HELPER FUNCTIONS
 
+fn translation_value(translation: [f64; 3]) -> Value {
+    Value::Transform(Transform::translate(Vec3::from_array(translation)))
+}
+
+/// Captures the affine point map of a component as a `Value::Transform`.
+fn transform_value(point_fn: &impl Fn([f64; 3]) -> [f64; 3]) -> Value {
+    Value::Transform(Transform::from_affine_map(|p| {
+        Point3::from_array(point_fn(p.to_array()))
+    }))
+}
+
 /// Epsilon for numerical comparisons in geometry transformations.
 const EPSILON: f64 = 1e-9;
 
@@ -373,6 +357,7 @@ where
                 diagnostics: diagnostics.clone(),
             }
         }
+        Value::Curve(curve) => Value::Curve(curve.map_points(&mut *point_fn)),
         Value::List(values) => {
             let mut mapped = Vec::with_capacity(values.len());
             for value in values {
@@ -2,10 +2,11 @@
 
 use std::collections::{BTreeMap, HashSet};
 
+use crate::geom::{Point3, Transform, Vec3};
 use crate::graph::node::MetaMap;
 use crate::graph::value::Value;
 
-use super::{Component, ComponentError, ComponentResult};
+use super::{Component, ComponentError, ComponentResult, PinAccess, coerce};
 
 const PIN_OUTPUT_GROUP: &str = "G";
 const PIN_OUTPUT_TRANSFORM: &str = "T";
@@ -94,9 +95,24 @@ impl Component for ComponentKind {
             Self::SplitGroup => evaluate_split_group(inputs, meta),
         }
     }
+
+    fn input_access(&self) -> &'static [PinAccess] {
+        match self {
+            Self::Transform => &[PinAccess::Item, PinAccess::Item],
+            Self::InverseTransform | Self::Split => &[PinAccess::Item],
+            Self::Compound => &[PinAccess::List],
+            _ => &[],
+        }
+    }
 }
 
 impl ComponentKind {
+    /// Componenten die exacte `Value::Curve` invoer rechtstreeks transformeren.
+    #[must_use]
+    pub fn handles_curves(self) -> bool {
+        matches!(self, Self::Transform)
+    }
+
     #[must_use]
     pub fn name(&self) -> &'static str {
         match self {
@@ -134,59 +150,18 @@ fn evaluate_merge_group(inputs: &[Value], _meta: &MetaMap) -> ComponentResult {
 }
 
 fn evaluate_inverse_transform(inputs: &[Value], _meta: &MetaMap) -> ComponentResult {
-    if inputs.len() != 1 {
+    let Some(value) = inputs.first() else {
         return Err(ComponentError::new(
-            "Inverse Transform component expects a single Transform input.",
+            "Inverse Transform verwacht een transformatie",
         ));
-    }
-    let transform = &inputs[0];
-
-    let inverted_transform = match transform {
-        Value::List(list) => {
-            if let Some(Value::Text(transform_type)) = list.get(0) {
-                match transform_type.as_str() {
-                    "Move" => {
-                        if let Some(Value::Vector(v)) = list.get(1) {
-                            Value::List(vec![
-                                Value::Text("Move".into()),
-                                Value::Vector([-v[0], -v[1], -v[2]]),
-                            ])
-                        } else {
-                            return Err(ComponentError::new("Invalid 'Move' transform format."));
-                        }
-                    }
-                    "Rotate" => {
-                        if let (
-                            Some(Value::Point(p)),
-                            Some(Value::Vector(a)),
-                            Some(Value::Number(angle)),
-                        ) = (list.get(1), list.get(2), list.get(3))
-                        {
-                            Value::List(vec![
-                                Value::Text("Rotate".into()),
-                                Value::Point(*p),
-                                Value::Vector(*a),
-                                Value::Number(-angle),
-                            ])
-                        } else {
-                            return Err(ComponentError::new("Invalid 'Rotate' transform format."));
-                        }
-                    }
-                    _ => {
-                        return Err(ComponentError::new(
-                            "Unsupported transform type for inversion.",
-                        ));
-                    }
-                }
-            } else {
-                return Err(ComponentError::new("Invalid transform format."));
-            }
-        }
-        _ => return Err(ComponentError::new("Invalid transform input.")),
     };
+    let transform = coerce::coerce_transform(value, "Inverse Transform")?;
+    let inverse = transform.inverse().ok_or_else(|| {
+        ComponentError::new("Inverse Transform: de transformatie is niet inverteerbaar")
+    })?;
 
     let mut outputs = BTreeMap::new();
-    outputs.insert(PIN_OUTPUT_TRANSFORM.to_owned(), inverted_transform);
+    outputs.insert(PIN_OUTPUT_TRANSFORM.to_owned(), Value::Transform(inverse));
     Ok(outputs)
 }
 
@@ -197,9 +172,9 @@ fn evaluate_transform(inputs: &[Value], _meta: &MetaMap) -> ComponentResult {
         ));
     }
     let geometry = &inputs[0];
-    let transform = &inputs[1];
+    let transform = coerce::coerce_transform(&inputs[1], "Transform")?;
 
-    let transformed_geometry = apply_transform(geometry, transform)?;
+    let transformed_geometry = apply_transform(geometry, transform);
 
     let mut outputs = BTreeMap::new();
     outputs.insert(PIN_OUTPUT_GEOMETRY.to_owned(), transformed_geometry);
@@ -219,17 +194,15 @@ fn evaluate_group(inputs: &[Value], _meta: &MetaMap) -> ComponentResult {
 }
 
 fn evaluate_split(inputs: &[Value], _meta: &MetaMap) -> ComponentResult {
-    if inputs.len() != 1 {
-        return Err(ComponentError::new(
-            "Split component expects a single Transform input.",
-        ));
-    }
-    let compound_transform = &inputs[0];
-
-    let fragments = match compound_transform {
-        Value::List(list) => list.clone(),
-        _ => return Err(ComponentError::new("Invalid compound transform input.")),
+    let Some(value) = inputs.first() else {
+        return Err(ComponentError::new("Split verwacht een transformatie"));
     };
+    let transform = coerce::coerce_transform(value, "Split")?;
+
+    let fragments = split_transform(transform)
+        .into_iter()
+        .map(Value::Transform)
+        .collect();
 
     let mut outputs = BTreeMap::new();
     outputs.insert(PIN_OUTPUT_FRAGMENTS.to_owned(), Value::List(fragments));
@@ -255,21 +228,26 @@ fn evaluate_ungroup(inputs: &[Value], _meta: &MetaMap) -> ComponentResult {
 }
 
 fn evaluate_compound(inputs: &[Value], _meta: &MetaMap) -> ComponentResult {
-    if inputs.len() != 1 {
+    let Some(value) = inputs.first() else {
         return Err(ComponentError::new(
-            "Compound component expects a single list of Transforms.",
+            "Compound verwacht een lijst met transformaties",
         ));
-    }
-    let transforms = match &inputs[0] {
-        Value::List(list) => list,
-        _ => return Err(ComponentError::new("Invalid input for Compound component.")),
+    };
+    let items = match value {
+        Value::List(list) => list.as_slice(),
+        other => std::slice::from_ref(other),
     };
 
+    // Grasshopper past de transformaties toe in lijstvolgorde, dus elke
+    // volgende transformatie wordt links vermenigvuldigd.
+    let mut compound = Transform::identity();
+    for item in items {
+        let transform = coerce::coerce_transform(item, "Compound")?;
+        compound = transform.compose(compound);
+    }
+
     let mut outputs = BTreeMap::new();
-    outputs.insert(
-        PIN_OUTPUT_COMPOUND.to_owned(),
-        Value::List(transforms.clone()),
-    );
+    outputs.insert(PIN_OUTPUT_COMPOUND.to_owned(), Value::Transform(compound));
     Ok(outputs)
 }
 
@@ -344,47 +322,81 @@ fn coerce_number(value: Option<&Value>, context: &str) -> Result<f64, ComponentE
     }
 }
 
-fn apply_transform(geometry: &Value, transform: &Value) -> Result<Value, ComponentError> {
-    match transform {
-        Value::List(list) => {
-            if let Some(Value::Text(transform_type)) = list.get(0) {
-                match transform_type.as_str() {
-                    "Move" => {
-                        if let Some(Value::Vector(v)) = list.get(1) {
-                            Ok(map_geometry(geometry, &mut |p| add(p, *v), &mut |vec| vec))
-                        } else {
-                            Err(ComponentError::new("Invalid 'Move' transform format."))
-                        }
-                    }
-                    "Rotate" => {
-                        if let (
-                            Some(Value::Point(p)),
-                            Some(Value::Vector(a)),
-                            Some(Value::Number(angle)),
-                        ) = (list.get(1), list.get(2), list.get(3))
-                        {
-                            let mut point_fn = |point: [f64; 3]| {
-                                let translated = subtract(point, *p);
-                                let rotated = rotate_vector(translated, *a, *angle);
-                                add(rotated, *p)
-                            };
-                            Ok(map_geometry(geometry, &mut point_fn, &mut |vec| {
-                                rotate_vector(vec, *a, *angle)
-                            }))
-                        } else {
-                            Err(ComponentError::new("Invalid 'Rotate' transform format."))
-                        }
-                    }
-                    _ => Err(ComponentError::new("Unsupported transform type.")),
-                }
-            } else {
-                Err(ComponentError::new("Invalid transform format."))
-            }
-        }
-        _ => Err(ComponentError::new("Invalid transform input.")),
+fn apply_transform(geometry: &Value, transform: Transform) -> Value {
+    map_geometry(
+        geometry,
+        &mut |p| transform.apply_point(Point3::from_array(p)).to_array(),
+        &mut |v| transform.apply_vec(Vec3::from_array(v)).to_array(),
+    )
+}
+
+/// Splits a transform into the fragments that compose it, in the order in
+/// which they are applied: stretch (scale/shear), rotation and translation.
+///
+/// The linear part is factored with a Gram-Schmidt QR decomposition, so
+/// compounding the fragments again reproduces the input. Identity fragments
+/// are dropped; projective or degenerate transforms are returned whole.
+fn split_transform(transform: Transform) -> Vec<Transform> {
+    if !transform.is_affine() {
+        return vec![transform];
+    }
+    let m = transform.as_matrix();
+    let column = |j: usize| Vec3::new(m[0][j], m[1][j], m[2][j]);
+    let (c0, c1, c2) = (column(0), column(1), column(2));
+
+    let r00 = c0.length();
+    let Some(q0) = c0.normalized() else {
+        return vec![transform];
+    };
+    let r01 = q0.dot(c1);
+    let u1 = c1.sub(q0.mul_scalar(r01));
+    let r11 = u1.length();
+    let Some(q1) = u1.normalized() else {
+        return vec![transform];
+    };
+    let r02 = q0.dot(c2);
+    let r12 = q1.dot(c2);
+    let u2 = c2.sub(q0.mul_scalar(r02)).sub(q1.mul_scalar(r12));
+    let mut r22 = u2.length();
+    let Some(mut q2) = u2.normalized() else {
+        return vec![transform];
+    };
+    // Keep the rotation proper; a mirror ends up in the stretch fragment.
+    if q0.cross(q1).dot(q2) < 0.0 {
+        q2 = q2.neg();
+        r22 = -r22;
+    }
+
+    let stretch = Transform::from_matrix([
+        [r00, r01, r02, 0.0],
+        [0.0, r11, r12, 0.0],
+        [0.0, 0.0, r22, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+    let rotation = Transform::from_axes(Point3::ORIGIN, q0, q1, q2);
+    let translation = Transform::translate(transform.translation());
+
+    let fragments: Vec<Transform> = [stretch, rotation, translation]
+        .into_iter()
+        .filter(|fragment| !is_identity(fragment))
+        .collect();
+    if fragments.is_empty() {
+        vec![Transform::identity()]
+    } else {
+        fragments
     }
 }
 
+fn is_identity(transform: &Transform) -> bool {
+    let identity = Transform::identity();
+    transform
+        .as_matrix()
+        .iter()
+        .flatten()
+        .zip(identity.as_matrix().iter().flatten())
+        .all(|(a, b)| (a - b).abs() < EPSILON)
+}
+
 /// Epsilon for numerical comparisons in geometry transformations.
 const EPSILON: f64 = 1e-9;
 
@@ -492,6 +504,7 @@ where
                 diagnostics: diagnostics.clone(),
             }
         }
+        Value::Curve(curve) => Value::Curve(curve.map_points(&mut *point_fn)),
         Value::List(values) => {
             let mut mapped = Vec::with_capacity(values.len());
             for value in values {
@@ -503,59 +516,154 @@ where
     }
 }
 
-fn add(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
-    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{maths_matrix, transform_euclidean};
 
-fn subtract(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
-    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
-}
+    fn meta() -> MetaMap {
+        MetaMap::new()
+    }
 
-fn scale(vector: [f64; 3], factor: f64) -> [f64; 3] {
-    [vector[0] * factor, vector[1] * factor, vector[2] * factor]
-}
+    fn transform_output(outputs: &BTreeMap<String, Value>, pin: &str) -> Transform {
+        outputs
+            .get(pin)
+            .and_then(|value| value.expect_transform().ok())
+            .expect("transform output")
+    }
 
-fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
-    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
-}
+    fn point_output(outputs: &BTreeMap<String, Value>, pin: &str) -> [f64; 3] {
+        match outputs.get(pin) {
+            Some(Value::Point(point)) => *point,
+            other => panic!("expected point, got {other:?}"),
+        }
+    }
 
-fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
-    [
-        a[1] * b[2] - a[2] * b[1],
-        a[2] * b[0] - a[0] * b[2],
-        a[0] * b[1] - a[1] * b[0],
-    ]
-}
+    fn assert_point_eq(actual: [f64; 3], expected: [f64; 3]) {
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-9, "{actual:?} != {expected:?}");
+        }
+    }
 
-fn length_squared(vector: [f64; 3]) -> f64 {
-    dot(vector, vector)
-}
+    fn move_transform(vector: [f64; 3]) -> Value {
+        let outputs = transform_euclidean::ComponentKind::MoveWithTransform
+            .evaluate(&[Value::Null, Value::Vector(vector)], &meta())
+            .unwrap();
+        Value::Transform(transform_output(&outputs, "X"))
+    }
 
-fn length(vector: [f64; 3]) -> f64 {
-    length_squared(vector).sqrt()
-}
+    fn rotate_z_transform(angle: f64) -> Value {
+        let outputs = transform_euclidean::ComponentKind::Rotate3DWithTransform
+            .evaluate(
+                &[
+                    Value::Null,
+                    Value::Number(angle),
+                    Value::Point([0.0, 0.0, 0.0]),
+                    Value::Vector([0.0, 0.0, 1.0]),
+                ],
+                &meta(),
+            )
+            .unwrap();
+        Value::Transform(transform_output(&outputs, "X"))
+    }
 
-fn normalize(vector: [f64; 3]) -> [f64; 3] {
-    let len = length(vector);
-    if len.abs() < 1e-9 {
-        vector
-    } else {
-        scale(vector, 1.0 / len)
+    #[test]
+    fn compound_applies_transforms_in_list_order() {
+        let compound = ComponentKind::Compound
+            .evaluate(
+                &[Value::List(vec![
+                    move_transform([1.0, 0.0, 0.0]),
+                    rotate_z_transform(std::f64::consts::FRAC_PI_2),
+                ])],
+                &meta(),
+            )
+            .unwrap();
+        let x = transform_output(&compound, PIN_OUTPUT_COMPOUND);
+
+        let outputs = ComponentKind::Transform
+            .evaluate(
+                &[Value::Point([1.0, 0.0, 0.0]), Value::Transform(x)],
+                &meta(),
+            )
+            .unwrap();
+        assert_point_eq(point_output(&outputs, PIN_OUTPUT_GEOMETRY), [0.0, 2.0, 0.0]);
     }
-}
 
-fn rotate_vector(vector: [f64; 3], axis: [f64; 3], angle: f64) -> [f64; 3] {
-    if angle.abs() < 1e-9 {
-        return vector;
+    #[test]
+    fn inverse_transform_undoes_rotation_and_translation() {
+        let compound = ComponentKind::Compound
+            .evaluate(
+                &[Value::List(vec![
+                    rotate_z_transform(0.3),
+                    move_transform([2.0, -1.0, 4.0]),
+                ])],
+                &meta(),
+            )
+            .unwrap();
+        let x = transform_output(&compound, PIN_OUTPUT_COMPOUND);
+        let inverse = ComponentKind::InverseTransform
+            .evaluate(&[Value::Transform(x)], &meta())
+            .unwrap();
+        let inverse = transform_output(&inverse, PIN_OUTPUT_TRANSFORM);
+
+        let point = Point3::new(0.5, 1.5, -2.0);
+        let round_trip = inverse.apply_point(x.apply_point(point));
+        assert_point_eq(round_trip.to_array(), point.to_array());
     }
-    let axis = normalize(axis);
-    let cos_angle = angle.cos();
-    let sin_angle = angle.sin();
-    add(
-        add(
-            scale(vector, cos_angle),
-            scale(cross(axis, vector), sin_angle),
-        ),
-        scale(axis, dot(axis, vector) * (1.0 - cos_angle)),
-    )
-}
\ No newline at end of file
+
+    #[test]
+    fn inverse_transform_rejects_singular_matrices() {
+        let flatten = Transform::scale(1.0, 1.0, 0.0);
+        let err = ComponentKind::InverseTransform
+            .evaluate(&[Value::Transform(flatten)], &meta())
+            .unwrap_err();
+        assert!(err.to_string().contains("niet inverteerbaar"));
+    }
+
+    #[test]
+    fn split_fragments_compound_back_to_the_original() {
+        let original = Transform::translate(Vec3::new(3.0, 0.0, -1.0))
+            .compose(Transform::rotate_axis(Vec3::new(1.0, 1.0, 0.0), 0.7).unwrap())
+            .compose(Transform::scale(2.0, 0.5, -1.0));
+
+        let split = ComponentKind::Split
+            .evaluate(&[Value::Transform(original)], &meta())
+            .unwrap();
+        let Some(Value::List(fragments)) = split.get(PIN_OUTPUT_FRAGMENTS) else {
+            panic!("expected fragment list");
+        };
+        assert_eq!(fragments.len(), 3);
+
+        let compound = ComponentKind::Compound
+            .evaluate(&[Value::List(fragments.clone())], &meta())
+            .unwrap();
+        let rebuilt = transform_output(&compound, PIN_OUTPUT_COMPOUND);
+        for (a, b) in rebuilt
+            .as_matrix()
+            .iter()
+            .flatten()
+            .zip(original.as_matrix().iter().flatten())
+        {
+            assert!((a - b).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn transforms_round_trip_through_matrix_components() {
+        let deconstructed = maths_matrix::ComponentKind::DeconstructMatrix
+            .evaluate(&[move_transform([1.0, 2.0, 3.0])], &meta())
+            .unwrap();
+        assert_eq!(deconstructed.get("R"), Some(&Value::Number(4.0)));
+        let values = deconstructed.get("V").cloned().unwrap();
+
+        let constructed = maths_matrix::ComponentKind::ConstructMatrix
+            .evaluate(&[Value::Number(4.0), Value::Number(4.0), values], &meta())
+            .unwrap();
+        let matrix = constructed.get("M").cloned().unwrap();
+
+        let outputs = ComponentKind::Transform
+            .evaluate(&[Value::Point([0.0, 0.0, 0.0]), matrix], &meta())
+            .unwrap();
+        assert_point_eq(point_output(&outputs, PIN_OUTPUT_GEOMETRY), [1.0, 2.0, 3.0]);
+    }
+}
@@ -3,7 +3,7 @@
 use std::collections::BTreeMap;
 
 use crate::graph::node::MetaMap;
-use crate::graph::value::{Matrix, Value};
+use crate::graph::value::{FieldBounds, FieldSource, FieldValue, Matrix, Value};
 
 use super::{Component, ComponentError, ComponentResult};
 
@@ -144,44 +144,6 @@ impl ComponentKind {
     }
 }
 
-#[derive(Debug, Clone, Default)]
-struct FieldValue {
-    sources: Vec<FieldSource>,
-    bounds: Option<FieldBounds>,
-}
-
-#[derive(Debug, Clone, Copy)]
-struct FieldBounds {
-    min: [f64; 3],
-    max: [f64; 3],
-}
-
-#[derive(Debug, Clone, Copy)]
-enum FieldSource {
-    PointCharge {
-        point: [f64; 3],
-        charge: f64,
-        decay: f64,
-    },
-    LineCharge {
-        start: [f64; 3],
-        end: [f64; 3],
-        charge: f64,
-        decay: f64,
-    },
-    VectorForce {
-        start: [f64; 3],
-        end: [f64; 3],
-    },
-    SpinForce {
-        origin: [f64; 3],
-        normal: [f64; 3],
-        radius: f64,
-        strength: f64,
-        decay: f64,
-    },
-}
-
 #[derive(Debug, Clone, Copy)]
 struct FieldEvaluation {
     vector: [f64; 3],
@@ -564,36 +526,22 @@ fn parse_field(value: Option<&Value>, context: &str) -> Result<FieldValue, Compo
     };
 
     match value {
+        Value::Field(field) => Ok(field.clone()),
+        Value::Null => Ok(FieldValue::default()),
         Value::List(entries) => {
+            // Een lijst met velden (bijvoorbeeld na een lijstcomponent) wordt
+            // als één samengevoegd veld behandeld.
             let mut field = FieldValue::default();
             for entry in entries {
-                match entry {
-                    Value::List(inner) if is_bounds_entry(inner) => {
-                        field.bounds = Some(parse_bounds_entry(inner, context)?);
-                    }
-                    Value::List(inner) if !inner.is_empty() => {
-                        field.sources.push(parse_field_source(inner, context)?);
-                    }
-                    Value::List(inner) if inner.is_empty() => {}
-                    Value::List(inner) if inner.len() == 1 => {
-                        let nested = parse_field(Some(&inner[0]), context)?;
-                        field.sources.extend(nested.sources);
-                        field.bounds = merge_bounds(field.bounds, nested.bounds);
-                    }
-                    other => {
-                        return Err(ComponentError::new(format!(
-                            "{} verwacht veldinformatie, kreeg {}",
-                            context,
-                            other.kind()
-                        )));
-                    }
-                }
+                let nested = parse_field(Some(entry), context)?;
+                field.sources.extend(nested.sources);
+                field.bounds = merge_bounds(field.bounds, nested.bounds);
             }
             Ok(field)
         }
         Value::Text(text) if text.trim().is_empty() => Ok(FieldValue::default()),
         other => Err(ComponentError::new(format!(
-            "{} verwacht veldgegevens, kreeg {}",
+            "{} verwacht een veld, kreeg {}",
             context,
             other.kind()
         ))),
@@ -609,82 +557,18 @@ fn collect_fields(value: Option<&Value>) -> Result<Vec<FieldValue>, ComponentErr
         Value::List(entries) => {
             let mut fields = Vec::new();
             for entry in entries {
-                let parsed = parse_field(Some(entry), "Merge Fields")?;
-                if !parsed.sources.is_empty() || parsed.bounds.is_some() {
-                    fields.push(parsed);
-                } else {
-                    fields.extend(collect_fields(Some(entry))?);
-                }
+                fields.extend(collect_fields(Some(entry))?);
             }
             Ok(fields)
         }
-        _ => Ok(vec![parse_field(Some(value), "Merge Fields")?]),
-    }
-}
-
-fn parse_field_source(values: &[Value], context: &str) -> Result<FieldSource, ComponentError> {
-    let tag = match &values[0] {
-        Value::Text(text) => text.to_ascii_lowercase(),
-        Value::Number(_) | Value::Point(_) | Value::Vector(_) | Value::List(_) => {
-            "point_charge".to_owned()
-        }
-        other => {
-            return Err(ComponentError::new(format!(
-                "{} kon veldbron niet interpreteren uit {}",
-                context,
-                other.kind()
-            )));
-        }
-    };
-
-    match tag.as_str() {
-        "point_charge" | "pointcharge" | "point" => {
-            let point = coerce_point(values.get(1).or_else(|| values.get(0)), context)?;
-            let charge = coerce_number(values.get(2), 1.0, context)?;
-            let decay = coerce_number(values.get(3), 2.0, context)?.max(0.0);
-            Ok(FieldSource::PointCharge {
-                point,
-                charge,
-                decay,
-            })
-        }
-        "line_charge" | "linecharge" | "line" => {
-            let line_value = values.get(1).or_else(|| values.get(0));
-            let line = coerce_line(line_value, context)?;
-            let charge = coerce_number(values.get(2), 1.0, context)?;
-            let decay = coerce_number(values.get(3), 2.0, context)?.max(0.0);
-            Ok(FieldSource::LineCharge {
-                start: line.0,
-                end: line.1,
-                charge,
-                decay,
-            })
-        }
-        "vector_force" | "vectorforce" | "vector" => {
-            let line_value = values.get(1).or_else(|| values.get(0));
-            let line = coerce_line(line_value, context)?;
-            Ok(FieldSource::VectorForce {
-                start: line.0,
-                end: line.1,
-            })
-        }
-        "spin_force" | "spinforce" | "spin" => {
-            let plane = parse_plane(values.get(1), context)?;
-            let strength = coerce_number(values.get(2), 1.0, context)?;
-            let radius = coerce_number(values.get(3), 1.0, context)?.abs();
-            let decay = coerce_number(values.get(4), 2.0, context)?.max(0.0);
-            Ok(FieldSource::SpinForce {
-                origin: plane.origin,
-                normal: plane.normal,
-                radius,
-                strength,
-                decay,
+        _ => {
+            let field = parse_field(Some(value), "Merge Fields")?;
+            Ok(if field.is_empty() {
+                Vec::new()
+            } else {
+                vec![field]
             })
         }
-        other => Err(ComponentError::new(format!(
-            "{} ondersteunt veldbron '{}' niet",
-            context, other
-        ))),
     }
 }
 
@@ -738,61 +622,7 @@ fn parse_bounds(value: Option<&Value>) -> Result<Option<FieldBounds>, ComponentE
 }
 
 fn field_to_value(field: &FieldValue) -> Value {
-    let mut entries = Vec::new();
-    for source in &field.sources {
-        let entry = match source {
-            FieldSource::PointCharge {
-                point,
-                charge,
-                decay,
-            } => Value::List(vec![
-                Value::Text("point_charge".into()),
-                Value::Point(*point),
-                Value::Number(*charge),
-                Value::Number(*decay),
-            ]),
-            FieldSource::LineCharge {
-                start,
-                end,
-                charge,
-                decay,
-            } => Value::List(vec![
-                Value::Text("line_charge".into()),
-                Value::Point(*start),
-                Value::Point(*end),
-                Value::Number(*charge),
-                Value::Number(*decay),
-            ]),
-            FieldSource::VectorForce { start, end } => Value::List(vec![
-                Value::Text("vector_force".into()),
-                Value::Point(*start),
-                Value::Point(*end),
-            ]),
-            FieldSource::SpinForce {
-                origin,
-                normal,
-                radius,
-                strength,
-                decay,
-            } => Value::List(vec![
-                Value::Text("spin_force".into()),
-                Value::Point(*origin),
-                Value::Vector(*normal),
-                Value::Number(*radius),
-                Value::Number(*strength),
-                Value::Number(*decay),
-            ]),
-        };
-        entries.push(entry);
-    }
-    if let Some(bounds) = field.bounds {
-        entries.push(Value::List(vec![
-            Value::Text("bounds".into()),
-            Value::Point(bounds.min),
-            Value::Point(bounds.max),
-        ]));
-    }
-    Value::List(entries)
+    Value::Field(field.clone())
 }
 
 fn evaluate_field_at_point(field: &FieldValue, point: [f64; 3]) -> FieldEvaluation {
@@ -1341,4 +1171,64 @@ fn lerp_colour(a: [f64; 3], b: [f64; 3], t: f64) -> [f64; 3] {
         lerp(a[1], b[1], t),
         lerp(a[2], b[2], t),
     ]
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point_charge(point: [f64; 3]) -> Value {
+        let outputs = evaluate_point_charge(&[Value::Point(point)]).unwrap();
+        outputs.get(PIN_OUTPUT_FIELD).cloned().unwrap()
+    }
+
+    #[test]
+    fn charges_output_typed_fields() {
+        let field = point_charge([1.0, 2.0, 3.0]);
+        let Value::Field(field) = field else {
+            panic!("expected a field, got {field:?}");
+        };
+        assert_eq!(
+            field.sources,
+            vec![FieldSource::PointCharge {
+                point: [1.0, 2.0, 3.0],
+                charge: 1.0,
+                decay: 2.0,
+            }]
+        );
+    }
+
+    #[test]
+    fn merge_and_break_round_trip_through_nested_lists() {
+        // Zoals na een lijstcomponent: velden in geneste lijsten.
+        let fields = Value::List(vec![
+            Value::List(vec![point_charge([0.0, 0.0, 0.0])]),
+            point_charge([5.0, 0.0, 0.0]),
+        ]);
+        let merged = evaluate_merge_fields(&[fields]).unwrap();
+        let merged = merged.get(PIN_OUTPUT_FIELD).unwrap();
+        assert_eq!(merged.expect_field().unwrap().sources.len(), 2);
+
+        let broken = evaluate_break_field(&[merged.clone()]).unwrap();
+        let Some(Value::List(parts)) = broken.get(PIN_OUTPUT_FIELDS) else {
+            panic!("expected list of fields");
+        };
+        assert_eq!(parts.len(), 2);
+        assert!(parts.iter().all(|part| matches!(part, Value::Field(_))));
+    }
+
+    #[test]
+    fn evaluate_field_accepts_a_list_wrapped_field() {
+        let field = Value::List(vec![point_charge([0.0, 0.0, 0.0])]);
+        let outputs = evaluate_field_value(&[field, Value::Point([2.0, 0.0, 0.0])]).unwrap();
+        let Some(Value::Number(strength)) = outputs.get(PIN_OUTPUT_STRENGTH) else {
+            panic!("expected strength");
+        };
+        assert!((strength - 0.125).abs() < 1e-12);
+    }
+
+    #[test]
+    fn field_inputs_reject_other_values() {
+        let err = evaluate_break_field(&[Value::Number(1.0)]).unwrap_err();
+        assert!(err.to_string().contains("verwacht een veld"));
+    }
+}
@@ -6,6 +6,7 @@ use crate::components::coerce::coerce_point_with_default;
 use crate::graph::node::MetaMap;
 use crate::graph::value::Value;
 
+use super::schema::PinDefault;
 use super::{Component, ComponentError, ComponentResult};
 
 const PIN_OUTPUT_PLANE: &str = "P";
@@ -183,6 +184,20 @@ impl Component for ComponentKind {
 }
 
 impl ComponentKind {
+    /// Waarden die gebruikt worden als de betreffende input leeg blijft.
+    #[must_use]
+    pub fn input_defaults(&self) -> &'static [(&'static str, PinDefault)] {
+        match self {
+            Self::PlaneOffset => &[("O", PinDefault::Number(0.0))],
+            Self::FlipPlane => &[
+                ("X", PinDefault::Boolean(false)),
+                ("Y", PinDefault::Boolean(false)),
+                ("S", PinDefault::Boolean(false)),
+            ],
+            _ => &[],
+        }
+    }
+
     #[must_use]
     pub fn name(&self) -> &'static str {
         match self {
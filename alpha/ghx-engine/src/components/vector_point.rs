@@ -6,7 +6,8 @@ use std::collections::BTreeMap;
 use crate::graph::node::MetaMap;
 use crate::graph::value::{ColorValue, PlaneValue, TextTagValue, Value};
 
-use super::{Component, ComponentError, ComponentResult, coerce};
+use super::schema::PinDefault;
+use super::{Component, ComponentError, ComponentResult, PinAccess, coerce};
 
 const PIN_OUTPUT_POINT: &str = "P";
 const PIN_OUTPUT_POINTS: &str = "P";
@@ -208,9 +209,29 @@ impl Component for ComponentKind {
             Self::PullPoint => evaluate_pull_point(inputs),
         }
     }
+
+    fn input_access(&self) -> &'static [PinAccess] {
+        match self {
+            Self::ConstructPoint | Self::Distance => &[PinAccess::Item],
+            // Het vlak blijft een lijst van punten en mag niet opgesplitst worden.
+            Self::Deconstruct => &[PinAccess::Item, PinAccess::List],
+            _ => &[],
+        }
+    }
 }
 
 impl ComponentKind {
+    /// Waarden die gebruikt worden als de betreffende input leeg blijft.
+    #[must_use]
+    pub fn input_defaults(&self) -> &'static [(&'static str, PinDefault)] {
+        match self {
+            Self::CullDuplicates => &[("T", PinDefault::Number(0.001))],
+            Self::PointOriented => &[("W", PinDefault::Number(0.0))],
+            Self::PointGroups => &[("D", PinDefault::Number(0.1))],
+            _ => &[],
+        }
+    }
+
     #[must_use]
     pub fn name(&self) -> &'static str {
         match self {
@@ -1179,11 +1200,17 @@ pub(crate) fn parse_color_value(value: &Value) -> Option<ColorValue> {
         }
         Value::Text(text) => parse_color_text(text),
         Value::Null
+        | Value::Tree(_)
         | Value::CurveLine { .. }
+        | Value::Curve(_)
+        | Value::Plane(_)
         | Value::Surface { .. }
+        | Value::Brep(_)
         | Value::Mesh { .. }
         | Value::Domain(_)
         | Value::Matrix(_)
+        | Value::Transform(_)
+        | Value::Field(_)
         | Value::DateTime(_)
         | Value::Complex(_)
         | Value::Color(_)
@@ -1331,6 +1358,11 @@ fn collect_mask(value: &Value, output: &mut Vec<char>) {
                 collect_mask(entry, output);
             }
         }
+        Value::Tree(tree) => {
+            for entry in tree.items() {
+                collect_mask(entry, output);
+            }
+        }
         Value::Text(text) => {
             for ch in text.chars() {
                 let lower = ch.to_ascii_lowercase();
@@ -1345,10 +1377,15 @@ fn collect_mask(value: &Value, output: &mut Vec<char>) {
         | Value::Point(_)
         | Value::Vector(_)
         | Value::CurveLine { .. }
+        | Value::Curve(_)
+        | Value::Plane(_)
         | Value::Surface { .. }
+        | Value::Brep(_)
         | Value::Mesh { .. }
         | Value::Domain(_)
         | Value::Matrix(_)
+        | Value::Transform(_)
+        | Value::Field(_)
         | Value::DateTime(_)
         | Value::Complex(_)
         | Value::Color(_)
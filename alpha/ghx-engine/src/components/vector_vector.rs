@@ -9,7 +9,8 @@ use crate::graph::node::MetaMap;
 use crate::graph::value::Value;
 
 use super::coerce::Plane;
-use super::{Component, ComponentError, ComponentResult, coerce};
+use super::schema::PinDefault;
+use super::{Component, ComponentError, ComponentResult, PinAccess, coerce};
 
 const EPSILON: f64 = 1e-9;
 
@@ -195,9 +196,49 @@ impl Component for ComponentKind {
             Self::Addition => evaluate_addition(inputs),
         }
     }
+
+    fn input_access(&self) -> &'static [PinAccess] {
+        match self {
+            Self::Angle
+            | Self::CrossProduct
+            | Self::Divide
+            | Self::DotProduct
+            | Self::VectorXyz
+            | Self::SolarIncidence
+            | Self::Multiply
+            | Self::VectorLength
+            | Self::Amplitude
+            | Self::UnitX
+            | Self::UnitY
+            | Self::UnitZ
+            | Self::VectorTwoPoint
+            | Self::DeconstructVector
+            | Self::Rotate
+            | Self::UnitVector
+            | Self::Reverse
+            | Self::Addition => &[PinAccess::Item],
+            // Het vlak blijft een lijst van punten en mag niet opgesplitst worden.
+            Self::AnglePlane => &[PinAccess::Item, PinAccess::Item, PinAccess::List],
+            Self::MassAddition | Self::MassAdditionTotal => &[PinAccess::List, PinAccess::Item],
+        }
+    }
 }
 
 impl ComponentKind {
+    /// Waarden die gebruikt worden als de betreffende input leeg blijft.
+    #[must_use]
+    pub fn input_defaults(&self) -> &'static [(&'static str, PinDefault)] {
+        match self {
+            Self::CrossProduct
+            | Self::DotProduct
+            | Self::MassAddition
+            | Self::MassAdditionTotal
+            | Self::VectorTwoPoint => &[("U", PinDefault::Boolean(false))],
+            Self::Rotate => &[("A", PinDefault::Number(0.0))],
+            _ => &[],
+        }
+    }
+
     #[must_use]
     pub fn name(&self) -> &'static str {
         match self {
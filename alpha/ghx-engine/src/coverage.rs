@@ -0,0 +1,169 @@
+//! Dekkingsrapport: welke componenten van een geladen definitie de engine
+//! werkelijk kan evalueren.
+//!
+//! Elke node krijgt een [`CoverageStatus`]: opgelost naar een werkend
+//! component, opgelost naar een placeholder die altijd
+//! `ComponentError::NotYetImplemented` geeft, of helemaal niet gevonden door
+//! [`ComponentRegistry::resolve`]. Waar mogelijk wordt de GUID opgezocht in de
+//! [`Catalog`], zodat ook voor ontbrekende componenten naam en categorie
+//! bekend zijn.
+
+use serde::Serialize;
+
+use crate::catalog::{Catalog, CatalogEntry};
+use crate::components::ComponentRegistry;
+use crate::graph::Graph;
+
+/// Hoe een node door de registry wordt opgelost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CoverageStatus {
+    Resolved,
+    NotImplemented,
+    Missing,
+}
+
+impl CoverageStatus {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Resolved => "resolved",
+            Self::NotImplemented => "not_implemented",
+            Self::Missing => "missing",
+        }
+    }
+}
+
+/// De dekking van één node.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NodeCoverage {
+    pub node_id: usize,
+    pub guid: Option<String>,
+    pub name: Option<String>,
+    pub nickname: Option<String>,
+    pub status: CoverageStatus,
+    /// Naam van het component waarnaar de node is opgelost.
+    pub component: Option<&'static str>,
+    /// De nodelist-entry met dezelfde GUID, indien bekend.
+    pub catalog: Option<CatalogEntry>,
+}
+
+/// Dekking van alle nodes met totalen per status.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct CoverageReport {
+    pub nodes: Vec<NodeCoverage>,
+    pub resolved: usize,
+    pub not_implemented: usize,
+    pub missing: usize,
+}
+
+impl CoverageReport {
+    /// `true` als elke node naar een werkend component is opgelost.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.not_implemented == 0 && self.missing == 0
+    }
+}
+
+/// Bepaalt de dekking van alle nodes in de graph.
+#[must_use]
+pub fn build_coverage(
+    graph: &Graph,
+    registry: &ComponentRegistry,
+    catalog: &Catalog,
+) -> CoverageReport {
+    let mut report = CoverageReport::default();
+
+    for node in graph.nodes() {
+        let component = registry.resolve(
+            node.guid.as_deref(),
+            node.name.as_deref(),
+            node.nickname.as_deref(),
+        );
+
+        let status = match component {
+            Some(component) if component.is_placeholder() => CoverageStatus::NotImplemented,
+            Some(_) => CoverageStatus::Resolved,
+            None => CoverageStatus::Missing,
+        };
+        match status {
+            CoverageStatus::Resolved => report.resolved += 1,
+            CoverageStatus::NotImplemented => report.not_implemented += 1,
+            CoverageStatus::Missing => report.missing += 1,
+        }
+
+        report.nodes.push(NodeCoverage {
+            node_id: node.id.0,
+            guid: node.guid.clone(),
+            name: node.name.clone(),
+            nickname: node.nickname.clone(),
+            status,
+            component: component.map(|component| component.name()),
+            catalog: node
+                .guid
+                .as_deref()
+                .and_then(|guid| catalog.lookup(guid))
+                .cloned(),
+        });
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CoverageStatus, build_coverage};
+    use crate::catalog::Catalog;
+    use crate::components::ComponentRegistry;
+    use crate::graph::Graph;
+    use crate::graph::node::{Node, NodeId};
+
+    fn node(id: usize, guid: &str) -> Node {
+        let mut node = Node::new(NodeId::new(id));
+        node.guid = Some(guid.to_string());
+        node
+    }
+
+    #[test]
+    fn classifies_nodes_and_names_missing_components() {
+        let mut graph = Graph::new();
+        // Line, Quad Remesh (placeholder) en een GUID die de registry niet kent.
+        graph
+            .add_node(node(1, "{4c4e56eb-2f04-43f9-95a3-cc46a14f495a}"))
+            .unwrap();
+        graph
+            .add_node(node(2, "{1a17d3f0-c8f8-4ee9-8dab-ea1c29db6a49}"))
+            .unwrap();
+        graph
+            .add_node(node(3, "{00000000-0000-0000-0000-0000000000ab}"))
+            .unwrap();
+
+        let mut catalog = Catalog::new();
+        catalog.add_nodelist_json(
+            r#"[{ "category": "Maths", "subcategory": "Util", "name": "Onbekend",
+                 "nickname": "Onb", "guid": "00000000-0000-0000-0000-0000000000AB" }]"#,
+        );
+
+        let report = build_coverage(&graph, &ComponentRegistry::default(), &catalog);
+        let statuses: Vec<_> = report.nodes.iter().map(|node| node.status).collect();
+        assert_eq!(
+            statuses,
+            [
+                CoverageStatus::Resolved,
+                CoverageStatus::NotImplemented,
+                CoverageStatus::Missing,
+            ]
+        );
+        assert_eq!(
+            (report.resolved, report.not_implemented, report.missing),
+            (1, 1, 1)
+        );
+        assert!(!report.is_complete());
+
+        let missing = &report.nodes[2];
+        assert_eq!(missing.component, None);
+        let entry = missing.catalog.as_ref().expect("catalogus-entry");
+        assert_eq!(entry.name, "Onbekend");
+        assert_eq!(entry.category, "Maths");
+    }
+}
@@ -0,0 +1,628 @@
+//! Bewerken van de geladen graph vanuit de editor, met undo en redo.
+//!
+//! Elke bewerking wordt als [`GraphEdit`] uitgevoerd en levert de inverse
+//! bewerking op; die gaat naar de undo-stack. Undo en redo voeren simpelweg de
+//! bewaarde inverse uit, zodat alle wijzigingen via hetzelfde pad lopen. Dat
+//! pad werkt de graph, het [`EvaluationPlan`](crate::graph::evaluator::EvaluationPlan)
+//! en de dirty set bij zonder de graph opnieuw te laden: alleen de nodes
+//! waarvan de invoer veranderde worden opnieuw geëvalueerd.
+
+use wasm_bindgen::prelude::*;
+
+use crate::graph::node::{Node, NodeId};
+use crate::graph::value::Value;
+use crate::graph::wire::Wire;
+use crate::graph::{Graph, RemovedNode};
+use crate::{Engine, collect_input_bindings, js_error};
+
+/// Eén omkeerbare wijziging van de graph.
+#[derive(Debug, Clone)]
+enum GraphEdit {
+    /// Zet een node op een vaste positie terug, inclusief zijn verbindingen.
+    InsertNode(RemovedNode),
+    RemoveNode(NodeId),
+    Connect(Wire),
+    Disconnect(Wire),
+    /// Zet de persistente waarde van een inputpin; `None` wist haar. Met
+    /// `remove_pin` verdwijnt ook de pin zelf, zodat het ongedaan maken van
+    /// een waarde op een nieuwe pin die pin weer opruimt.
+    SetInput {
+        node: NodeId,
+        pin: String,
+        value: Option<Value>,
+        remove_pin: bool,
+    },
+}
+
+/// Undo- en redo-stacks van de editor.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct EditHistory {
+    undo: Vec<GraphEdit>,
+    redo: Vec<GraphEdit>,
+}
+
+impl EditHistory {
+    pub(crate) fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+}
+
+#[wasm_bindgen]
+impl Engine {
+    /// Begin een lege definitie om in de editor op te bouwen.
+    #[wasm_bindgen]
+    pub fn new_graph(&mut self) -> Result<(), JsValue> {
+        self.load_graph(Graph::new())
+            .map_err(|err| js_error(&err.to_string()))
+    }
+
+    /// Voeg een node toe voor het component met deze GUID of naam. Geeft het
+    /// id van de nieuwe node terug.
+    #[wasm_bindgen]
+    pub fn add_node(&mut self, component: &str) -> Result<usize, JsValue> {
+        self.add_component(component)
+            .map(|id| id.0)
+            .map_err(|err| js_error(&err))
+    }
+
+    /// Verwijder een node en al zijn verbindingen.
+    #[wasm_bindgen]
+    pub fn remove_node(&mut self, node_id: usize) -> Result<(), JsValue> {
+        self.remove_component(NodeId::new(node_id))
+            .map_err(|err| js_error(&err))
+    }
+
+    /// Verbind een outputpin met een inputpin.
+    #[wasm_bindgen]
+    pub fn connect(
+        &mut self,
+        from_node: usize,
+        from_pin: &str,
+        to_node: usize,
+        to_pin: &str,
+    ) -> Result<(), JsValue> {
+        self.connect_wire(Wire::new(from_node, from_pin, to_node, to_pin))
+            .map_err(|err| js_error(&err))
+    }
+
+    /// Verbreek een bestaande verbinding.
+    #[wasm_bindgen]
+    pub fn disconnect(
+        &mut self,
+        from_node: usize,
+        from_pin: &str,
+        to_node: usize,
+        to_pin: &str,
+    ) -> Result<(), JsValue> {
+        self.disconnect_wire(Wire::new(from_node, from_pin, to_node, to_pin))
+            .map_err(|err| js_error(&err))
+    }
+
+    /// Zet de persistente waarde van een inputpin: een getal, boolean of
+    /// tekst. `null` of `undefined` wist de waarde.
+    #[wasm_bindgen]
+    pub fn set_input_value(
+        &mut self,
+        node_id: usize,
+        pin: &str,
+        value: JsValue,
+    ) -> Result<(), JsValue> {
+        let value = if value.is_null() || value.is_undefined() {
+            None
+        } else if let Some(number) = value.as_f64() {
+            Some(Value::Number(number))
+        } else if let Some(flag) = value.as_bool() {
+            Some(Value::Boolean(flag))
+        } else if let Some(text) = value.as_string() {
+            Some(Value::Text(text))
+        } else {
+            return Err(js_error(
+                "inputwaarde moet een getal, boolean of tekst zijn",
+            ));
+        };
+
+        self.set_persistent_input(NodeId::new(node_id), pin, value)
+            .map_err(|err| js_error(&err))
+    }
+
+    /// Maak de laatste bewerking ongedaan. Geeft `false` terug als er niets
+    /// ongedaan te maken was.
+    #[wasm_bindgen]
+    pub fn undo(&mut self) -> Result<bool, JsValue> {
+        self.undo_edit().map_err(|err| js_error(&err))
+    }
+
+    /// Voer de laatst ongedaan gemaakte bewerking opnieuw uit.
+    #[wasm_bindgen]
+    pub fn redo(&mut self) -> Result<bool, JsValue> {
+        self.redo_edit().map_err(|err| js_error(&err))
+    }
+
+    #[wasm_bindgen]
+    pub fn can_undo(&self) -> bool {
+        !self.history.undo.is_empty()
+    }
+
+    #[wasm_bindgen]
+    pub fn can_redo(&self) -> bool {
+        !self.history.redo.is_empty()
+    }
+}
+
+impl Engine {
+    /// Voeg een node toe voor een component (Rust-variant van `add_node`).
+    /// De inputpinnen en hun defaults komen uit het pinschema van het component.
+    pub fn add_component(&mut self, component: &str) -> Result<NodeId, String> {
+        let kind = self
+            .registry
+            .resolve(Some(component), Some(component), Some(component))
+            .ok_or_else(|| format!("onbekend component `{component}`"))?;
+        let guid = self.registry.guid_for(kind).map(str::to_owned);
+        let schema = guid.as_deref().and_then(|guid| self.registry.schema(guid));
+
+        let graph = self.graph.as_mut().ok_or("er is geen graph geladen")?;
+        let mut node = Node::default();
+        node.guid = guid.map(|guid| format!("{{{guid}}}"));
+        node.name = Some(kind.name().to_owned());
+        if let Some(schema) = schema {
+            for pin in &schema.inputs {
+                node.add_input_pin(pin.nickname);
+                if let Some(default) = pin.default {
+                    node.set_input(pin.nickname, default.to_value());
+                }
+            }
+        }
+
+        let id = graph.next_node_id();
+        node.id = id;
+        let index = graph.node_count();
+        self.perform(GraphEdit::InsertNode(RemovedNode {
+            index,
+            node,
+            wires: Vec::new(),
+        }))?;
+        Ok(id)
+    }
+
+    /// Verwijder een node (Rust-variant van `remove_node`).
+    pub fn remove_component(&mut self, node_id: NodeId) -> Result<(), String> {
+        self.perform(GraphEdit::RemoveNode(node_id))
+    }
+
+    /// Verbind twee pinnen (Rust-variant van `connect`).
+    pub fn connect_wire(&mut self, wire: Wire) -> Result<(), String> {
+        self.perform(GraphEdit::Connect(wire))
+    }
+
+    /// Verbreek een verbinding (Rust-variant van `disconnect`).
+    pub fn disconnect_wire(&mut self, wire: Wire) -> Result<(), String> {
+        self.perform(GraphEdit::Disconnect(wire))
+    }
+
+    /// Zet of wis een persistente inputwaarde (Rust-variant van
+    /// `set_input_value`).
+    pub fn set_persistent_input(
+        &mut self,
+        node_id: NodeId,
+        pin: &str,
+        value: Option<Value>,
+    ) -> Result<(), String> {
+        self.perform(GraphEdit::SetInput {
+            node: node_id,
+            pin: pin.to_owned(),
+            value,
+            remove_pin: false,
+        })
+    }
+
+    /// Rust-variant van `undo`.
+    pub fn undo_edit(&mut self) -> Result<bool, String> {
+        let Some(edit) = self.history.undo.pop() else {
+            return Ok(false);
+        };
+        match self.apply_edit(edit.clone()) {
+            Ok(inverse) => {
+                self.history.redo.push(inverse);
+                Ok(true)
+            }
+            Err(err) => {
+                self.history.undo.push(edit);
+                Err(err)
+            }
+        }
+    }
+
+    /// Rust-variant van `redo`.
+    pub fn redo_edit(&mut self) -> Result<bool, String> {
+        let Some(edit) = self.history.redo.pop() else {
+            return Ok(false);
+        };
+        match self.apply_edit(edit.clone()) {
+            Ok(inverse) => {
+                self.history.undo.push(inverse);
+                Ok(true)
+            }
+            Err(err) => {
+                self.history.redo.push(edit);
+                Err(err)
+            }
+        }
+    }
+
+    /// Voert een nieuwe bewerking uit; de redo-stack vervalt.
+    fn perform(&mut self, edit: GraphEdit) -> Result<(), String> {
+        let inverse = self.apply_edit(edit)?;
+        self.history.undo.push(inverse);
+        self.history.redo.clear();
+        Ok(())
+    }
+
+    /// Past een bewerking toe op graph, plan en dirty set en geeft de inverse
+    /// terug. Bij een fout blijft alles ongewijzigd.
+    fn apply_edit(&mut self, edit: GraphEdit) -> Result<GraphEdit, String> {
+        let graph = self.graph.as_mut().ok_or("er is geen graph geladen")?;
+        let plan = self
+            .evaluation_plan
+            .as_mut()
+            .ok_or("graph is niet voorbereid voor evaluatie")?;
+
+        let (inverse, bindings_changed) = match edit {
+            GraphEdit::InsertNode(removed) => {
+                let id = removed.node.id;
+                for wire in &removed.wires {
+                    let other = if wire.from_node == id {
+                        wire.to_node
+                    } else {
+                        wire.from_node
+                    };
+                    if other != id && graph.node(other).is_none() {
+                        return Err(format!("node {} niet gevonden in graph", other.0));
+                    }
+                }
+
+                graph
+                    .insert_node(removed.index, removed.node)
+                    .map_err(|err| err.to_string())?;
+                plan.insert_node(graph.node(id).expect("zojuist ingevoegd"));
+                let mut targets = Vec::with_capacity(removed.wires.len());
+                for wire in removed.wires {
+                    let added = graph
+                        .add_wire(wire.clone())
+                        .map_err(|err| err.to_string())
+                        .and_then(|()| plan.add_wire(graph, &wire).map_err(|err| err.to_string()));
+                    if let Err(err) = added {
+                        // De node gaat weer weg, met de verbindingen die al lagen.
+                        graph.remove_node(id).map_err(|err| err.to_string())?;
+                        plan.remove_node(graph, id);
+                        return Err(err);
+                    }
+                    targets.push(wire.to_node);
+                }
+                self.dirty_nodes.extend(targets);
+                self.dirty_nodes.insert(id);
+                (GraphEdit::RemoveNode(id), true)
+            }
+            GraphEdit::RemoveNode(id) => {
+                let removed = graph.remove_node(id).map_err(|err| err.to_string())?;
+                plan.remove_node(graph, id);
+                self.dirty_nodes.remove(&id);
+                for wire in &removed.wires {
+                    if wire.to_node != id {
+                        self.dirty_nodes.insert(wire.to_node);
+                    }
+                }
+                // De geometrie van de node moet uit de diffs verdwijnen.
+                self.changed_nodes_since_geometry_update.insert(id);
+                self.changed_nodes_since_buffer_update.insert(id);
+                (GraphEdit::InsertNode(removed), true)
+            }
+            GraphEdit::Connect(wire) => {
+                validate_pins(graph, &wire)?;
+                if graph.wires().contains(&wire) {
+                    return Err("deze verbinding bestaat al".to_string());
+                }
+                graph
+                    .add_wire(wire.clone())
+                    .map_err(|err| err.to_string())?;
+                if let Err(err) = plan.add_wire(graph, &wire) {
+                    graph.remove_wire(&wire);
+                    return Err(err.to_string());
+                }
+                self.dirty_nodes.insert(wire.to_node);
+                (GraphEdit::Disconnect(wire), false)
+            }
+            GraphEdit::Disconnect(wire) => {
+                if !graph.remove_wire(&wire) {
+                    return Err("deze verbinding bestaat niet".to_string());
+                }
+                plan.remove_wire(graph, &wire);
+                self.dirty_nodes.insert(wire.to_node);
+                (GraphEdit::Connect(wire), false)
+            }
+            GraphEdit::SetInput {
+                node,
+                pin,
+                value,
+                remove_pin,
+            } => {
+                let target = graph
+                    .node_mut(node)
+                    .ok_or_else(|| format!("node {} niet gevonden in graph", node.0))?;
+                let created = !target.input_order().contains(&pin);
+                let previous = match value {
+                    Some(value) => target.inputs.insert(pin.clone(), value),
+                    None => target.remove_input(&pin),
+                };
+                if remove_pin {
+                    target.remove_input_pin(&pin);
+                } else {
+                    target.add_input_pin(pin.clone());
+                }
+                plan.refresh_pins(graph, node);
+                self.dirty_nodes.insert(node);
+                (
+                    GraphEdit::SetInput {
+                        node,
+                        pin,
+                        value: previous,
+                        remove_pin: created,
+                    },
+                    false,
+                )
+            }
+        };
+
+        if bindings_changed {
+            self.input_bindings = collect_input_bindings(graph, &self.registry);
+        }
+        self.result_dirty = true;
+        Ok(inverse)
+    }
+}
+
+/// Controleert dat beide nodes bestaan en, voor zover hun pinnen bekend
+/// zijn, de gebruikte pinnen hebben.
+fn validate_pins(graph: &Graph, wire: &Wire) -> Result<(), String> {
+    let from = graph
+        .node(wire.from_node)
+        .ok_or_else(|| format!("node {} niet gevonden in graph", wire.from_node.0))?;
+    let to = graph
+        .node(wire.to_node)
+        .ok_or_else(|| format!("node {} niet gevonden in graph", wire.to_node.0))?;
+
+    let from_pin = &wire.from_pin.0;
+    let has_output = from.output_order().iter().any(|pin| pin == from_pin)
+        || from.outputs.contains_key(from_pin);
+    if !from.output_order().is_empty() && !has_output {
+        return Err(format!(
+            "node {} heeft geen outputpin `{from_pin}`",
+            wire.from_node.0
+        ));
+    }
+
+    let to_pin = &wire.to_pin.0;
+    if !to.input_order().is_empty() && !to.input_order().iter().any(|pin| pin == to_pin) {
+        return Err(format!(
+            "node {} heeft geen inputpin `{to_pin}`",
+            wire.to_node.0
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GraphEdit;
+    use crate::Engine;
+    use crate::graph::RemovedNode;
+    use crate::graph::node::{Node, NodeId};
+    use crate::graph::value::{CurveValue, Value};
+    use crate::graph::wire::Wire;
+
+    fn line_length(engine: &Engine, line: NodeId) -> Option<f64> {
+        let outputs = engine.last_result()?.node_outputs.get(&line)?;
+        match outputs.get("L")? {
+            Value::Curve(CurveValue::Line(line)) => Some(line.direction().length()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn builds_and_edits_a_definition_with_undo_and_redo() {
+        let mut engine = Engine::new();
+        engine.load_graph(crate::graph::Graph::new()).unwrap();
+
+        let start = engine.add_component("Construct Point").unwrap();
+        let end = engine.add_component("Construct Point").unwrap();
+        let line = engine
+            .add_component("4c4e56eb-2f04-43f9-95a3-cc46a14f495a")
+            .unwrap();
+        assert_ne!(start, end);
+
+        engine
+            .set_persistent_input(end, "X", Some(Value::Number(3.0)))
+            .unwrap();
+        engine
+            .connect_wire(Wire::new(start, "P", line, "A"))
+            .unwrap();
+        engine.connect_wire(Wire::new(end, "P", line, "B")).unwrap();
+        assert!(
+            engine
+                .connect_wire(Wire::new(line, "L", line, "A"))
+                .is_err()
+        );
+
+        engine.evaluate_graph().unwrap();
+        assert_eq!(line_length(&engine, line), Some(3.0));
+
+        engine
+            .set_persistent_input(end, "X", Some(Value::Number(5.0)))
+            .unwrap();
+        engine.evaluate_graph().unwrap();
+        assert_eq!(line_length(&engine, line), Some(5.0));
+
+        assert!(engine.undo_edit().unwrap());
+        engine.evaluate_graph().unwrap();
+        assert_eq!(line_length(&engine, line), Some(3.0));
+
+        engine.remove_component(start).unwrap();
+        engine.evaluate_graph().unwrap();
+        assert_eq!(engine.graph().unwrap().wire_count(), 1);
+
+        assert!(engine.undo_edit().unwrap());
+        engine.evaluate_graph().unwrap();
+        assert_eq!(engine.graph().unwrap().wire_count(), 2);
+        assert_eq!(line_length(&engine, line), Some(3.0));
+
+        assert!(engine.redo_edit().unwrap());
+        assert!(engine.graph().unwrap().node(start).is_none());
+    }
+
+    fn plan_order(engine: &Engine) -> Vec<NodeId> {
+        engine.evaluation_plan.as_ref().unwrap().order().to_vec()
+    }
+
+    #[test]
+    fn rejected_cycles_leave_graph_plan_and_dirty_set_unchanged() {
+        let mut engine = Engine::new();
+        engine.load_graph(crate::graph::Graph::new()).unwrap();
+        let start = engine.add_component("Construct Point").unwrap();
+        let line = engine
+            .add_component("4c4e56eb-2f04-43f9-95a3-cc46a14f495a")
+            .unwrap();
+        engine
+            .connect_wire(Wire::new(start, "P", line, "A"))
+            .unwrap();
+        engine.evaluate_graph().unwrap();
+
+        let order = plan_order(&engine);
+        assert!(
+            engine
+                .connect_wire(Wire::new(line, "L", start, "X"))
+                .is_err()
+        );
+        assert_eq!(plan_order(&engine), order);
+        assert!(engine.dirty_nodes.is_empty());
+        assert_eq!(engine.graph().unwrap().wire_count(), 1);
+
+        // Een teruggezette node met een cyclus wordt volledig teruggedraaid.
+        let id = engine.graph().unwrap().next_node_id();
+        let mut node = Node::new(id);
+        node.add_input_pin("X");
+        let result = engine.apply_edit(GraphEdit::InsertNode(RemovedNode {
+            index: 2,
+            node,
+            wires: vec![
+                Wire::new(line, "L", id, "X"),
+                Wire::new(id, "P", start, "X"),
+            ],
+        }));
+        assert!(result.is_err());
+        assert!(engine.graph().unwrap().node(id).is_none());
+        assert_eq!(engine.graph().unwrap().wire_count(), 1);
+        assert_eq!(plan_order(&engine), order);
+        assert!(engine.dirty_nodes.is_empty());
+
+        engine.evaluate_graph().unwrap();
+    }
+
+    #[test]
+    fn undoing_a_disconnect_restores_the_wire() {
+        let mut engine = Engine::new();
+        engine.load_graph(crate::graph::Graph::new()).unwrap();
+        let start = engine.add_component("Construct Point").unwrap();
+        let end = engine.add_component("Construct Point").unwrap();
+        let line = engine
+            .add_component("4c4e56eb-2f04-43f9-95a3-cc46a14f495a")
+            .unwrap();
+        engine
+            .set_persistent_input(end, "X", Some(Value::Number(2.0)))
+            .unwrap();
+        let wire = Wire::new(start, "P", line, "A");
+        engine.connect_wire(wire.clone()).unwrap();
+        engine.connect_wire(Wire::new(end, "P", line, "B")).unwrap();
+        engine.evaluate_graph().unwrap();
+        assert_eq!(line_length(&engine, line), Some(2.0));
+
+        engine.disconnect_wire(wire.clone()).unwrap();
+        assert!(engine.dirty_nodes.contains(&line));
+        assert!(engine.disconnect_wire(wire.clone()).is_err());
+        engine.evaluate_graph().unwrap();
+        assert_eq!(engine.graph().unwrap().wire_count(), 1);
+
+        assert!(engine.undo_edit().unwrap());
+        assert!(engine.graph().unwrap().wires().contains(&wire));
+        engine.evaluate_graph().unwrap();
+        assert_eq!(line_length(&engine, line), Some(2.0));
+
+        assert!(engine.redo_edit().unwrap());
+        assert!(!engine.graph().unwrap().wires().contains(&wire));
+    }
+
+    #[test]
+    fn clearing_a_persistent_input_is_undoable() {
+        let mut engine = Engine::new();
+        engine.load_graph(crate::graph::Graph::new()).unwrap();
+        let point = engine.add_component("Construct Point").unwrap();
+        let input = |engine: &Engine| {
+            engine
+                .graph()
+                .unwrap()
+                .node(point)
+                .unwrap()
+                .inputs
+                .get("X")
+                .cloned()
+        };
+
+        engine
+            .set_persistent_input(point, "X", Some(Value::Number(4.0)))
+            .unwrap();
+        engine.evaluate_graph().unwrap();
+
+        engine.set_persistent_input(point, "X", None).unwrap();
+        assert_eq!(input(&engine), None);
+        assert!(engine.dirty_nodes.contains(&point));
+
+        assert!(engine.undo_edit().unwrap());
+        assert_eq!(input(&engine), Some(Value::Number(4.0)));
+
+        assert!(engine.redo_edit().unwrap());
+        assert_eq!(input(&engine), None);
+    }
+
+    #[test]
+    fn undoing_a_value_on_a_new_pin_removes_the_pin() {
+        let mut engine = Engine::new();
+        engine.load_graph(crate::graph::Graph::new()).unwrap();
+        let point = engine.add_component("Construct Point").unwrap();
+        let pins = |engine: &Engine| {
+            engine
+                .graph()
+                .unwrap()
+                .node(point)
+                .unwrap()
+                .input_order()
+                .to_vec()
+        };
+        let before = pins(&engine);
+
+        engine
+            .set_persistent_input(point, "Xx", Some(Value::Number(1.0)))
+            .unwrap();
+        assert!(pins(&engine).contains(&"Xx".to_string()));
+
+        assert!(engine.undo_edit().unwrap());
+        assert_eq!(pins(&engine), before);
+        assert_eq!(
+            engine.graph().unwrap().node(point).unwrap().input("Xx"),
+            None
+        );
+
+        assert!(engine.redo_edit().unwrap());
+        assert!(pins(&engine).contains(&"Xx".to_string()));
+        assert!(engine.undo_edit().unwrap());
+        assert_eq!(pins(&engine), before);
+    }
+}
@@ -0,0 +1,421 @@
+//! Bestandsexport van evaluatieresultaten naar OBJ, glTF en JSON.
+//!
+//! De export gebruikt dezelfde omzetting als `Engine::get_geometry`: exacte
+//! curves worden getesselleerd, breps gemesht en verborgen nodes
+//! overgeslagen. Elke bron-node wordt één object in het exportbestand.
+//!
+//! - OBJ bevat punten (`p`), lijnen en polylines (`l`) en polygonen (`f`) in
+//!   de oorspronkelijke Z-up coördinaten; materialen worden niet geschreven.
+//! - glTF wordt als één `.gltf`-bestand met een ingebedde base64-buffer
+//!   geschreven. Coördinaten worden omgezet naar het Y-up assenstelsel van
+//!   glTF (`[x, z, -y]`) en materialen worden PBR-materialen.
+//! - JSON volgt het itemformaat van `get_geometry` per node.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+use serde::Serialize;
+use serde_json::json;
+
+use crate::graph::Graph;
+use crate::graph::evaluator::{EvaluationResult, GeometryEntry};
+use crate::graph::node::NodeId;
+use crate::graph::value::triangulate_polygon_faces;
+use crate::{GeometryItem, MaterialExport, append_geometry_items, is_hidden_node};
+
+const GLTF_ARRAY_BUFFER: u32 = 34_962;
+const GLTF_ELEMENT_ARRAY_BUFFER: u32 = 34_963;
+const GLTF_FLOAT: u32 = 5_126;
+const GLTF_UNSIGNED_INT: u32 = 5_125;
+const GLTF_MODE_POINTS: u32 = 0;
+const GLTF_MODE_LINES: u32 = 1;
+const GLTF_MODE_LINE_STRIP: u32 = 3;
+const GLTF_MODE_TRIANGLES: u32 = 4;
+
+/// Alle exporteerbare geometrie van één bron-node.
+#[derive(Debug, Serialize)]
+struct NodeGeometry<'a> {
+    id: usize,
+    name: String,
+    items: Vec<GeometryItem<'a>>,
+}
+
+/// Schrijft de geometrie van een evaluatie als Wavefront OBJ.
+#[must_use]
+pub fn to_obj(graph: &Graph, result: &EvaluationResult) -> String {
+    let mut out = String::from("# ghx-engine\n");
+    let mut vertex_count = 0_usize;
+
+    for node in collect_node_geometry(graph, result) {
+        let _ = writeln!(out, "o {}", object_name(&node));
+        for item in &node.items {
+            let (points, element) = match item {
+                GeometryItem::Point { coordinates } => (vec![*coordinates], ObjElement::Point),
+                GeometryItem::Line { start, end } => (vec![*start, *end], ObjElement::Line),
+                GeometryItem::Polyline { points } => (points.clone(), ObjElement::Line),
+                GeometryItem::Mesh {
+                    vertices, faces, ..
+                } => (vertices.to_vec(), ObjElement::Faces(faces)),
+                GeometryItem::MeshOwned {
+                    vertices, faces, ..
+                } => (vertices.clone(), ObjElement::Faces(faces)),
+            };
+            if points.is_empty() {
+                continue;
+            }
+
+            for [x, y, z] in &points {
+                let _ = writeln!(out, "v {x} {y} {z}");
+            }
+            let base = vertex_count + 1;
+            match element {
+                ObjElement::Point => {
+                    let _ = writeln!(out, "p {base}");
+                }
+                ObjElement::Line => {
+                    out.push('l');
+                    for index in 0..points.len() {
+                        let _ = write!(out, " {}", base + index);
+                    }
+                    out.push('\n');
+                }
+                ObjElement::Faces(faces) => {
+                    for face in faces.iter().filter(|face| face.len() >= 3) {
+                        out.push('f');
+                        for index in face {
+                            let _ = write!(out, " {}", base + *index as usize);
+                        }
+                        out.push('\n');
+                    }
+                }
+            }
+            vertex_count += points.len();
+        }
+    }
+
+    out
+}
+
+enum ObjElement<'a> {
+    Point,
+    Line,
+    Faces(&'a [Vec<u32>]),
+}
+
+/// Schrijft de geometrie van een evaluatie als glTF 2.0 met ingebedde buffer.
+#[must_use]
+pub fn to_gltf(graph: &Graph, result: &EvaluationResult) -> String {
+    let mut builder = GltfBuilder::default();
+    let mut nodes = Vec::new();
+    let mut meshes = Vec::new();
+
+    for node in collect_node_geometry(graph, result) {
+        let primitives: Vec<serde_json::Value> = node
+            .items
+            .iter()
+            .filter_map(|item| builder.primitive(item))
+            .collect();
+        if primitives.is_empty() {
+            continue;
+        }
+        nodes.push(json!({ "name": object_name(&node), "mesh": meshes.len() }));
+        meshes.push(json!({ "name": node.name, "primitives": primitives }));
+    }
+
+    let scene_nodes: Vec<usize> = (0..nodes.len()).collect();
+    let mut document = json!({
+        "asset": { "version": "2.0", "generator": "ghx-engine" },
+        "scene": 0,
+        "scenes": [{ "nodes": scene_nodes }],
+        "nodes": nodes,
+        "meshes": meshes,
+    });
+
+    if !builder.buffer.is_empty() {
+        document["buffers"] = json!([{
+            "byteLength": builder.buffer.len(),
+            "uri": format!(
+                "data:application/octet-stream;base64,{}",
+                base64_encode(&builder.buffer)
+            ),
+        }]);
+        document["bufferViews"] = json!(builder.buffer_views);
+        document["accessors"] = json!(builder.accessors);
+    }
+    if !builder.materials.is_empty() {
+        document["materials"] = builder
+            .materials
+            .iter()
+            .map(gltf_material)
+            .collect::<serde_json::Value>();
+    }
+
+    document.to_string()
+}
+
+/// Schrijft de geometrie van een evaluatie als JSON, per node in het
+/// itemformaat van `Engine::get_geometry`.
+#[must_use]
+pub fn to_json(graph: &Graph, result: &EvaluationResult) -> String {
+    let nodes = collect_node_geometry(graph, result);
+    json!({ "nodes": nodes }).to_string()
+}
+
+/// Groepeert de zichtbare geometrie per bron-node in node-volgorde.
+fn collect_node_geometry<'a>(graph: &Graph, result: &'a EvaluationResult) -> Vec<NodeGeometry<'a>> {
+    let mut by_node: BTreeMap<NodeId, Vec<&'a GeometryEntry>> = BTreeMap::new();
+    for entry in &result.geometry {
+        by_node.entry(entry.source_node).or_default().push(entry);
+    }
+
+    by_node
+        .into_iter()
+        .filter(|(node_id, _)| !is_hidden_node(graph, *node_id))
+        .filter_map(|(node_id, entries)| {
+            let mut items = Vec::new();
+            for entry in entries {
+                append_geometry_items(entry, &mut items);
+            }
+            if items.is_empty() {
+                return None;
+            }
+
+            let name = graph
+                .node(node_id)
+                .and_then(|node| node.nickname.clone().or_else(|| node.name.clone()))
+                .unwrap_or_else(|| "node".to_owned());
+            Some(NodeGeometry {
+                id: node_id.0,
+                name,
+                items,
+            })
+        })
+        .collect()
+}
+
+fn object_name(node: &NodeGeometry) -> String {
+    let name: String = node
+        .name
+        .chars()
+        .map(|c| if c.is_whitespace() { '_' } else { c })
+        .collect();
+    format!("{name}_{}", node.id)
+}
+
+#[derive(Default)]
+struct GltfBuilder {
+    buffer: Vec<u8>,
+    buffer_views: Vec<serde_json::Value>,
+    accessors: Vec<serde_json::Value>,
+    materials: Vec<MaterialExport>,
+}
+
+impl GltfBuilder {
+    fn primitive(&mut self, item: &GeometryItem) -> Option<serde_json::Value> {
+        let (points, indices, mode, material) = match item {
+            GeometryItem::Point { coordinates } => {
+                (vec![*coordinates], None, GLTF_MODE_POINTS, None)
+            }
+            GeometryItem::Line { start, end } => (vec![*start, *end], None, GLTF_MODE_LINES, None),
+            GeometryItem::Polyline { points } => (points.clone(), None, GLTF_MODE_LINE_STRIP, None),
+            GeometryItem::Mesh {
+                vertices,
+                faces,
+                material,
+            } => (
+                vertices.to_vec(),
+                Some(triangulate_polygon_faces(faces)),
+                GLTF_MODE_TRIANGLES,
+                material.as_ref(),
+            ),
+            GeometryItem::MeshOwned {
+                vertices,
+                faces,
+                material,
+            } => (
+                vertices.clone(),
+                Some(triangulate_polygon_faces(faces)),
+                GLTF_MODE_TRIANGLES,
+                material.as_ref(),
+            ),
+        };
+        if points.is_empty() || indices.as_ref().is_some_and(Vec::is_empty) {
+            return None;
+        }
+
+        let mut primitive = json!({
+            "attributes": { "POSITION": self.push_positions(&points) },
+            "mode": mode,
+        });
+        if let Some(indices) = indices {
+            primitive["indices"] = json!(self.push_indices(&indices));
+        }
+        if let Some(material) = material {
+            primitive["material"] = json!(self.material_index(material));
+        }
+        Some(primitive)
+    }
+
+    fn push_positions(&mut self, points: &[[f64; 3]]) -> usize {
+        let mut min = [f32::INFINITY; 3];
+        let mut max = [f32::NEG_INFINITY; 3];
+        let offset = self.buffer.len();
+        for point in points {
+            let converted = [point[0] as f32, point[2] as f32, (0.0 - point[1]) as f32];
+            for (axis, value) in converted.into_iter().enumerate() {
+                min[axis] = min[axis].min(value);
+                max[axis] = max[axis].max(value);
+                self.buffer.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+
+        self.push_accessor(
+            offset,
+            GLTF_ARRAY_BUFFER,
+            json!({
+                "componentType": GLTF_FLOAT,
+                "count": points.len(),
+                "type": "VEC3",
+                "min": min,
+                "max": max,
+            }),
+        )
+    }
+
+    fn push_indices(&mut self, indices: &[u32]) -> usize {
+        let offset = self.buffer.len();
+        for index in indices {
+            self.buffer.extend_from_slice(&index.to_le_bytes());
+        }
+
+        self.push_accessor(
+            offset,
+            GLTF_ELEMENT_ARRAY_BUFFER,
+            json!({
+                "componentType": GLTF_UNSIGNED_INT,
+                "count": indices.len(),
+                "type": "SCALAR",
+            }),
+        )
+    }
+
+    fn push_accessor(
+        &mut self,
+        offset: usize,
+        target: u32,
+        mut accessor: serde_json::Value,
+    ) -> usize {
+        accessor["bufferView"] = json!(self.buffer_views.len());
+        self.buffer_views.push(json!({
+            "buffer": 0,
+            "byteOffset": offset,
+            "byteLength": self.buffer.len() - offset,
+            "target": target,
+        }));
+        self.accessors.push(accessor);
+        self.accessors.len() - 1
+    }
+
+    fn material_index(&mut self, material: &MaterialExport) -> usize {
+        if let Some(index) = self.materials.iter().position(|known| known == material) {
+            return index;
+        }
+        self.materials.push(material.clone());
+        self.materials.len() - 1
+    }
+}
+
+fn gltf_material(material: &MaterialExport) -> serde_json::Value {
+    let [r, g, b] = material.diffuse;
+    let alpha = (1.0 - material.transparency).clamp(0.0, 1.0);
+    let mut value = json!({
+        "pbrMetallicRoughness": {
+            "baseColorFactor": [r, g, b, alpha],
+            "metallicFactor": 0.0,
+            "roughnessFactor": (1.0 - material.shine).clamp(0.0, 1.0),
+        },
+        "emissiveFactor": material.emission,
+        "doubleSided": true,
+    });
+    if alpha < 1.0 {
+        value["alphaMode"] = json!("BLEND");
+    }
+    value
+}
+
+/// Codeert bytes als standaard base64 (RFC 4648) met `=`-opvulling, voor de
+/// data-URI van de glTF-buffer.
+fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let triple = chunk.iter().enumerate().fold(0_u32, |acc, (index, byte)| {
+            acc | (u32::from(*byte) << (16 - 8 * index))
+        });
+        for position in 0..4 {
+            if position <= chunk.len() {
+                let sextet = (triple >> (18 - 6 * position)) & 0x3f;
+                out.push(char::from(ALPHABET[sextet as usize]));
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{base64_encode, to_gltf, to_json, to_obj};
+    use crate::components::ComponentRegistry;
+    use crate::graph::evaluator;
+    use crate::parse::ghx_xml;
+
+    const MINIMAL_LINE: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../tools/ghx-samples/minimal_line.ghx"
+    ));
+
+    #[test]
+    fn encodes_base64_with_padding() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn exports_minimal_line_to_all_formats() {
+        let graph = ghx_xml::parse_str(MINIMAL_LINE).expect("parse");
+        let result = evaluator::evaluate(&graph, &ComponentRegistry::default()).expect("evaluate");
+
+        let obj = to_obj(&graph, &result);
+        assert!(obj.contains("o Result_Line_3\nv 0 0 0\nv 3 0 0\nl "));
+        assert!(obj.lines().filter(|line| line.starts_with("p ")).count() == 2);
+
+        let gltf: serde_json::Value = serde_json::from_str(&to_gltf(&graph, &result)).unwrap();
+        assert_eq!(gltf["asset"]["version"], "2.0");
+        let line_mesh = gltf["meshes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|mesh| mesh["name"] == "Result Line")
+            .expect("line mesh");
+        let accessor = &gltf["accessors"][line_mesh["primitives"][0]["attributes"]["POSITION"]
+            .as_u64()
+            .unwrap() as usize];
+        assert_eq!(accessor["count"], 2);
+        assert_eq!(accessor["max"][0], 3.0);
+
+        let json: serde_json::Value = serde_json::from_str(&to_json(&graph, &result)).unwrap();
+        let line = json["nodes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|node| node["id"] == 3)
+            .expect("line node");
+        assert_eq!(line["items"][0]["type"], "Line");
+        assert_eq!(line["items"][0]["end"][0], 3.0);
+    }
+}
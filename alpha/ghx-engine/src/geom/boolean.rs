@@ -1004,30 +1004,15 @@ fn classify_split_triangles(
     out
 }
 
-fn boolean_meshes_no_fallback(
-    mesh_a: &GeomMesh,
-    mesh_b: &GeomMesh,
-    op: BooleanOp,
+/// Collects, per triangle of `a` and of `b`, the points where the two meshes cross.
+fn intersect_prepared_meshes(
+    a: &PreparedMesh,
+    b: &PreparedMesh,
+    diagnostics: &mut BooleanDiagnostics,
     tol: Tolerance,
-) -> Result<BooleanResult, BooleanError> {
-    let a = prepare_mesh(mesh_a, tol)?;
-    let b = prepare_mesh(mesh_b, tol)?;
-
-    let tri_count_a = a.triangles.len();
-    let tri_count_b = b.triangles.len();
-
-    let mut per_a: Vec<Vec<Point3>> = vec![Vec::new(); tri_count_a];
-    let mut per_b: Vec<Vec<Point3>> = vec![Vec::new(); tri_count_b];
-
-    let mut diagnostics = BooleanDiagnostics {
-        op,
-        input_a_vertex_count: a.points.len(),
-        input_a_triangle_count: tri_count_a,
-        input_b_vertex_count: b.points.len(),
-        input_b_triangle_count: tri_count_b,
-        tolerance_used: tol.eps,
-        ..Default::default()
-    };
+) -> (Vec<Vec<Point3>>, Vec<Vec<Point3>>) {
+    let mut per_a: Vec<Vec<Point3>> = vec![Vec::new(); a.triangles.len()];
+    let mut per_b: Vec<Vec<Point3>> = vec![Vec::new(); b.triangles.len()];
 
     for (ia, tri_a) in a.triangles.iter().copied().enumerate() {
         let bbox_a = a.tri_bboxes[ia];
@@ -1084,6 +1069,33 @@ fn boolean_meshes_no_fallback(
         dedup_points(pts, tol);
     }
 
+    (per_a, per_b)
+}
+
+fn boolean_meshes_no_fallback(
+    mesh_a: &GeomMesh,
+    mesh_b: &GeomMesh,
+    op: BooleanOp,
+    tol: Tolerance,
+) -> Result<BooleanResult, BooleanError> {
+    let a = prepare_mesh(mesh_a, tol)?;
+    let b = prepare_mesh(mesh_b, tol)?;
+
+    let tri_count_a = a.triangles.len();
+    let tri_count_b = b.triangles.len();
+
+    let mut diagnostics = BooleanDiagnostics {
+        op,
+        input_a_vertex_count: a.points.len(),
+        input_a_triangle_count: tri_count_a,
+        input_b_vertex_count: b.points.len(),
+        input_b_triangle_count: tri_count_b,
+        tolerance_used: tol.eps,
+        ..Default::default()
+    };
+
+    let (per_a, per_b) = intersect_prepared_meshes(&a, &b, &mut diagnostics, tol);
+
     let split_a = split_mesh_by_intersections(&a, &per_a, tol);
     let split_b = split_mesh_by_intersections(&b, &per_b, tol);
 
@@ -1421,6 +1433,100 @@ pub fn boolean_meshes(
     Err(last_err.unwrap_or(BooleanError::EmptyMesh))
 }
 
+/// Fragments of a mesh split by a closed cutter mesh.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeshSplitResult {
+    /// Fragments inside the cutter (including those lying on it).
+    pub inside: Vec<GeomMesh>,
+    /// Fragments outside the cutter.
+    pub outside: Vec<GeomMesh>,
+    pub diagnostics: BooleanDiagnostics,
+}
+
+/// Splits the surface of `mesh` along its intersection with `cutter`.
+///
+/// The intersection curves cut the mesh into fragments (see
+/// [`super::intersect::split_mesh_with_polylines`]); each fragment is then
+/// classified against the cutter by a majority vote over its triangles.
+/// Unlike [`boolean_meshes`] no cutter triangles are added, so the fragments
+/// stay open along the cut. Fragments without a decisive vote count as
+/// indeterminate and end up outside.
+pub fn split_mesh_by_mesh(
+    mesh: &GeomMesh,
+    cutter: &GeomMesh,
+    tol: Tolerance,
+) -> Result<MeshSplitResult, BooleanError> {
+    let a = prepare_mesh(mesh, tol)?;
+    let b = prepare_mesh(cutter, tol)?;
+
+    let mut diagnostics = BooleanDiagnostics {
+        op: BooleanOp::Difference,
+        input_a_vertex_count: a.points.len(),
+        input_a_triangle_count: a.triangles.len(),
+        input_b_vertex_count: b.points.len(),
+        input_b_triangle_count: b.triangles.len(),
+        tolerance_used: tol.eps,
+        ..Default::default()
+    };
+
+    let intersection = super::intersect::mesh_mesh_intersection(mesh, cutter, tol);
+    diagnostics.intersection_segment_count = intersection.segment_count;
+    diagnostics.intersection_point_count = intersection.points.len();
+    diagnostics.coplanar_pair_count = intersection.coplanar_pair_count;
+    if diagnostics.coplanar_pair_count > 0 {
+        diagnostics
+            .warnings
+            .push("coplanar triangle pairs detected; split may be unstable".to_string());
+    }
+
+    let cuts: Vec<Vec<Point3>> = intersection
+        .polylines
+        .into_iter()
+        .map(|polyline| {
+            let mut points = polyline.points;
+            if polyline.closed && !points.is_empty() {
+                points.push(points[0]);
+            }
+            points
+        })
+        .collect();
+    let fragments = if cuts.is_empty() {
+        vec![mesh.clone()]
+    } else {
+        super::intersect::split_mesh_with_polylines(mesh, &cuts, tol)
+    };
+
+    let mut inside = Vec::new();
+    let mut outside = Vec::new();
+    for fragment in fragments {
+        let points: Vec<Point3> = fragment.positions.iter().copied().map(Point3::from).collect();
+        let mut votes = 0_i64;
+        for containment in classify_split_triangles(&points, &fragment.indices, &b, tol) {
+            match containment {
+                TriangleContainment::Inside => votes += 1,
+                TriangleContainment::Outside => votes -= 1,
+                TriangleContainment::OnSurface | TriangleContainment::Indeterminate => {}
+            }
+        }
+
+        diagnostics.kept_triangle_count_a += fragment.triangle_count();
+        if votes > 0 {
+            inside.push(fragment);
+        } else {
+            if votes == 0 {
+                diagnostics.indeterminate_triangle_count += fragment.triangle_count();
+            }
+            outside.push(fragment);
+        }
+    }
+
+    Ok(MeshSplitResult {
+        inside,
+        outside,
+        diagnostics,
+    })
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum BooleanError {
     #[error("mesh is empty")]
@@ -336,6 +336,34 @@ impl Transform {
         }
     }
 
+    /// Construct a transform from a raw row-major 4x4 matrix.
+    /// Translation lives in the last column, like the other constructors.
+    #[must_use]
+    pub const fn from_matrix(m: [[f64; 4]; 4]) -> Self {
+        Self { m }
+    }
+
+    /// Recover the matrix of an affine point map by sampling it at the
+    /// origin and at the tips of the three unit axes.
+    ///
+    /// The result is exact for affine maps; for anything else it is the
+    /// affine map that agrees with `map` at those four points.
+    #[must_use]
+    pub fn from_affine_map(map: impl Fn(Point3) -> Point3) -> Self {
+        let origin = map(Point3::ORIGIN);
+        let x = map(Point3::new(1.0, 0.0, 0.0)) - origin;
+        let y = map(Point3::new(0.0, 1.0, 0.0)) - origin;
+        let z = map(Point3::new(0.0, 0.0, 1.0)) - origin;
+        Self {
+            m: [
+                [x.x, y.x, z.x, origin.x],
+                [x.y, y.y, z.y, origin.y],
+                [x.z, y.z, z.z, origin.z],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
     /// Construct a transform from origin and three orthonormal axes.
     /// The axes are expected to be unit vectors and mutually perpendicular.
     #[must_use]
@@ -565,6 +593,13 @@ impl Transform {
         s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0
     }
 
+    /// Returns `true` when the bottom row is `[0, 0, 0, 1]`, i.e. the
+    /// transform has no projective component.
+    #[must_use]
+    pub fn is_affine(self) -> bool {
+        self.m[3] == [0.0, 0.0, 0.0, 1.0]
+    }
+
     /// Get the translation component of this transform.
     #[must_use]
     pub fn translation(self) -> Vec3 {
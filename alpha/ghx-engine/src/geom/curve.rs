@@ -2333,6 +2333,72 @@ pub fn curve_parameter_at_length<C: Curve3>(curve: &C, target_length: f64, sampl
     parameter_at_arc_length(&table, t0, t1, target_length)
 }
 
+/// Finds the parameter and point on a curve closest to `query`.
+///
+/// The curve is sampled uniformly first; the best sample bracket is then
+/// refined with a golden-section search on the squared distance.
+///
+/// # Arguments
+/// * `curve` - The curve to search.
+/// * `query` - The point to project onto the curve.
+/// * `samples` - Number of coarse samples (more = more robust for wavy curves).
+///
+/// # Returns
+/// `(parameter, closest_point)`.
+#[must_use]
+pub fn curve_closest_point<C: Curve3>(curve: &C, query: Point3, samples: usize) -> (f64, Point3) {
+    let (t0, t1) = curve.domain();
+    let span = t1 - t0;
+    if !span.is_finite() || span == 0.0 {
+        return (t0, curve.point_at(t0));
+    }
+
+    let distance_sq = |t: f64| curve.point_at(t).sub_point(query).length_squared();
+
+    let samples = samples.max(2);
+    let step = span / samples as f64;
+    let mut best_t = t0;
+    let mut best_d = distance_sq(t0);
+    for i in 1..=samples {
+        let t = t0 + step * i as f64;
+        let d = distance_sq(t);
+        if d < best_d {
+            best_d = d;
+            best_t = t;
+        }
+    }
+
+    let inv_phi = (5.0_f64.sqrt() - 1.0) / 2.0;
+    let mut a = (best_t - step).max(t0);
+    let mut b = (best_t + step).min(t1);
+    let mut c = b - inv_phi * (b - a);
+    let mut d = a + inv_phi * (b - a);
+    let mut fc = distance_sq(c);
+    let mut fd = distance_sq(d);
+    for _ in 0..60 {
+        if (b - a).abs() <= 1e-12 * span.abs().max(1.0) {
+            break;
+        }
+        if fc < fd {
+            b = d;
+            d = c;
+            fd = fc;
+            c = b - inv_phi * (b - a);
+            fc = distance_sq(c);
+        } else {
+            a = c;
+            c = d;
+            fc = fd;
+            d = a + inv_phi * (b - a);
+            fd = distance_sq(d);
+        }
+    }
+
+    let refined = 0.5 * (a + b);
+    let t = if distance_sq(refined) <= best_d { refined } else { best_t };
+    (t, curve.point_at(t))
+}
+
 /// Result of computing curve segment lengths.
 #[derive(Debug, Clone)]
 pub struct SegmentLengthAnalysis {
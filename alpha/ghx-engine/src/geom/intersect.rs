@@ -0,0 +1,1298 @@
+//! Intersection queries between curves, planes, rays and triangle meshes.
+//!
+//! The routines here build on existing primitives: curve/plane crossings start
+//! from [`curve_plane_intersections`] and are polished with Newton steps, mesh
+//! sections cut the mesh with a single large triangle through
+//! [`triangle_mesh_intersection_segments`], and ray casts walk a triangle BVH.
+//!
+//! The physical intersections (curve/curve, mesh/curve, mesh/mesh, clashes and
+//! collisions) use the same BVH as broad phase and settle the exact pairs with
+//! [`triangle_triangle_intersection`] or segment closest points. Curves enter
+//! as [`ParamPolyline`]s so that parameters survive the sampling.
+
+use std::collections::{HashMap, HashSet};
+
+use super::boolean::{
+    PointContainment, Segment3, TriTriIntersection, Triangle3, classify_point_in_mesh,
+    triangle_mesh_intersection_segments, triangle_triangle_intersection,
+};
+use super::bvh::Bvh;
+use super::curve::{Curve3, curve_plane_intersections};
+use super::mesh::{GeomMesh, closest_point_on_mesh, closest_point_on_triangle};
+use super::{BBox, Point3, Tolerance, Vec3};
+
+/// Maximum number of Newton steps used to polish a curve/plane crossing.
+const NEWTON_ITERATIONS: usize = 8;
+
+/// A polyline obtained by slicing a mesh with a plane.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SectionPolyline {
+    pub points: Vec<Point3>,
+    /// `true` when the last point connects back to the first one. The first
+    /// point is not repeated at the end.
+    pub closed: bool,
+}
+
+/// A single ray hit on a triangle mesh.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MeshRayHit {
+    pub point: Point3,
+    /// Ray parameter of the hit: `point = origin + direction * t`.
+    pub t: f64,
+    /// Index of the hit triangle within `mesh.indices.chunks_exact(3)`.
+    pub triangle: usize,
+    /// Barycentric weights of the hit for the three triangle vertices.
+    pub barycentric: [f64; 3],
+    /// Unit normal of the hit triangle, following its winding.
+    pub normal: Vec3,
+}
+
+/// Finds the crossings of a curve with a plane and refines them.
+///
+/// Candidates come from [`curve_plane_intersections`]; each one is polished
+/// with Newton steps on the signed plane distance, staying within one sample
+/// step of the candidate. For closed curves a crossing at the seam is reported
+/// once.
+#[must_use]
+pub fn curve_plane_intersections_refined<C: Curve3>(
+    curve: &C,
+    plane_origin: Point3,
+    plane_normal: Vec3,
+    samples: usize,
+) -> Vec<(Point3, f64)> {
+    let Some(normal) = plane_normal.normalized() else {
+        return Vec::new();
+    };
+    let samples = samples.max(2);
+    let (t0, t1) = curve.domain();
+    let span = t1 - t0;
+    let step = span / samples as f64;
+    let duplicate_eps = Tolerance::LOOSE.relative_to(span);
+
+    let mut results: Vec<(Point3, f64)> = Vec::new();
+    for (point, t) in curve_plane_intersections(curve, plane_origin, normal, samples) {
+        let lower = (t - step).max(t0);
+        let upper = (t + step).min(t1);
+        let refined = refine_plane_crossing(curve, plane_origin, normal, (point, t), lower, upper);
+        if results
+            .last()
+            .is_some_and(|(_, previous)| (refined.1 - previous).abs() <= duplicate_eps)
+        {
+            continue;
+        }
+        results.push(refined);
+    }
+
+    if curve.is_closed() && results.len() > 1 {
+        let first = results[0];
+        let last = results[results.len() - 1];
+        if (first.1 - t0).abs() <= duplicate_eps && (t1 - last.1).abs() <= duplicate_eps {
+            results.pop();
+        }
+    }
+
+    results
+}
+
+fn refine_plane_crossing<C: Curve3>(
+    curve: &C,
+    origin: Point3,
+    normal: Vec3,
+    start: (Point3, f64),
+    lower: f64,
+    upper: f64,
+) -> (Point3, f64) {
+    let distance = |point: Point3| point.sub_point(origin).dot(normal);
+    let mut best = (start.0, start.1, distance(start.0).abs());
+    let mut t = start.1;
+
+    for _ in 0..NEWTON_ITERATIONS {
+        let point = curve.point_at(t);
+        let d = distance(point);
+        if d.abs() < best.2 {
+            best = (point, t, d.abs());
+        }
+        if d.abs() <= Tolerance::ZERO_LENGTH.eps {
+            break;
+        }
+        let slope = curve.derivative_at(t).dot(normal);
+        if !slope.is_finite() || slope.abs() <= Tolerance::ZERO_LENGTH.eps {
+            break;
+        }
+        t = (t - d / slope).clamp(lower, upper);
+    }
+
+    (best.0, best.1)
+}
+
+/// Slices a triangle mesh with a plane and stitches the cuts into polylines.
+///
+/// The plane is represented by one triangle that covers the projection of the
+/// mesh bounding box, so the cut segments come straight from
+/// [`triangle_mesh_intersection_segments`]. Triangles lying in the plane do not
+/// contribute segments.
+#[must_use]
+pub fn mesh_plane_section(
+    mesh: &GeomMesh,
+    plane_origin: Point3,
+    plane_normal: Vec3,
+    tol: Tolerance,
+) -> Vec<SectionPolyline> {
+    let Some(normal) = plane_normal.normalized() else {
+        return Vec::new();
+    };
+    let points: Vec<Point3> = mesh.positions.iter().copied().map(Point3::from).collect();
+    let Some(bbox) = BBox::from_points(&points) else {
+        return Vec::new();
+    };
+
+    let distances = points.iter().map(|p| p.sub_point(plane_origin).dot(normal));
+    let (lowest, highest) = distances.fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), d| {
+        (lo.min(d), hi.max(d))
+    });
+    if lowest > tol.eps || highest < -tol.eps {
+        return Vec::new();
+    }
+
+    let diagonal = bbox.diagonal().max(tol.eps);
+    let center = bbox.center();
+    let anchor = center.sub_vec(normal * center.sub_point(plane_origin).dot(normal));
+    let (u_axis, v_axis) = plane_axes(normal);
+    // An equilateral triangle with circumradius R has inradius R/2, which
+    // must exceed half the bbox diagonal to cover the whole section.
+    let radius = diagonal * 1.5;
+    let corner = |angle: f64| {
+        let (sin, cos) = angle.to_radians().sin_cos();
+        anchor.add_vec((u_axis * cos + v_axis * sin) * radius)
+    };
+    let cutter = Triangle3::new(corner(90.0), corner(210.0), corner(330.0));
+
+    let segments = triangle_mesh_intersection_segments(cutter, mesh, tol);
+    let weld = (diagonal * 1e-8).max(tol.eps);
+    stitch_segments(&segments, weld)
+}
+
+/// Joins loose segments into polylines, welding endpoints closer than `weld`.
+///
+/// Open chains are traced first, starting from endpoints that do not have
+/// exactly two neighbours; the remaining edges form closed loops.
+#[must_use]
+pub fn stitch_segments(segments: &[Segment3], weld: f64) -> Vec<SectionPolyline> {
+    let mut welder = PointWelder::new(weld);
+    let mut seen = HashSet::new();
+    let mut edges: Vec<[usize; 2]> = Vec::new();
+    for segment in segments {
+        let a = welder.insert(segment.a);
+        let b = welder.insert(segment.b);
+        if a != b && seen.insert((a.min(b), a.max(b))) {
+            edges.push([a, b]);
+        }
+    }
+
+    let nodes = welder.points;
+    let mut adjacency = vec![Vec::new(); nodes.len()];
+    for (index, [a, b]) in edges.iter().copied().enumerate() {
+        adjacency[a].push(index);
+        adjacency[b].push(index);
+    }
+
+    let mut used = vec![false; edges.len()];
+    let open_starts = (0..nodes.len()).filter(|&node| adjacency[node].len() != 2);
+    let starts: Vec<usize> = open_starts.chain(0..nodes.len()).collect();
+
+    let mut polylines = Vec::new();
+    for start in starts {
+        loop {
+            let mut path = vec![start];
+            let mut current = start;
+            while let Some(&edge) = adjacency[current].iter().find(|&&edge| !used[edge]) {
+                used[edge] = true;
+                let [a, b] = edges[edge];
+                current = if a == current { b } else { a };
+                path.push(current);
+                if current == start {
+                    break;
+                }
+            }
+            if path.len() < 2 {
+                break;
+            }
+
+            let closed = path.len() > 3 && path.first() == path.last();
+            if closed {
+                path.pop();
+            }
+            polylines.push(SectionPolyline {
+                points: path.into_iter().map(|node| nodes[node]).collect(),
+                closed,
+            });
+        }
+    }
+
+    polylines
+}
+
+/// Casts a ray against a triangle mesh.
+///
+/// Returns every hit with `t` in `[t_min, t_max]`, sorted along the ray. Hits
+/// on shared edges or vertices are reported once. The direction does not need
+/// to be normalized; `t` is expressed in multiples of it.
+#[must_use]
+pub fn mesh_ray_hits(
+    mesh: &GeomMesh,
+    origin: Point3,
+    direction: Vec3,
+    t_min: f64,
+    t_max: f64,
+    tol: Tolerance,
+) -> Vec<MeshRayHit> {
+    TriangleSet::new(mesh, tol).map_or_else(Vec::new, |set| {
+        set.ray_hits(origin, direction, t_min, t_max, tol)
+    })
+}
+
+/// A curve approximated by a polyline, with the curve parameter of every
+/// vertex.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParamPolyline {
+    pub points: Vec<Point3>,
+    /// Curve parameter per point; parameters along a segment are linear.
+    pub params: Vec<f64>,
+    /// Estimated distance between the polyline and the curve it approximates;
+    /// zero when the points describe the curve exactly.
+    pub deviation: f64,
+}
+
+impl ParamPolyline {
+    /// Wraps exact polyline vertices. `params` needs one entry per point.
+    #[must_use]
+    pub fn new(points: Vec<Point3>, params: Vec<f64>) -> Self {
+        debug_assert_eq!(points.len(), params.len());
+        Self {
+            points,
+            params,
+            deviation: 0.0,
+        }
+    }
+
+    /// Samples `curve` uniformly over its domain. The deviation is measured
+    /// at the middle of every segment.
+    #[must_use]
+    pub fn sample<C: Curve3>(curve: &C, samples: usize) -> Self {
+        let samples = samples.max(1);
+        let (t0, t1) = curve.domain();
+        let params: Vec<f64> = (0..=samples)
+            .map(|index| t0 + (t1 - t0) * index as f64 / samples as f64)
+            .collect();
+        let points: Vec<Point3> = params.iter().map(|&t| curve.point_at(t)).collect();
+        let deviation = params
+            .windows(2)
+            .zip(points.windows(2))
+            .map(|(t, p)| {
+                let middle = curve.point_at(0.5 * (t[0] + t[1]));
+                middle.distance_to(p[0].lerp(p[1], 0.5))
+            })
+            .fold(0.0, f64::max);
+        Self {
+            points,
+            params,
+            deviation,
+        }
+    }
+
+    fn segment_count(&self) -> usize {
+        self.points.len().saturating_sub(1)
+    }
+
+    fn segment(&self, index: usize) -> (Point3, Point3) {
+        (self.points[index], self.points[index + 1])
+    }
+
+    fn param_at(&self, segment: usize, s: f64) -> f64 {
+        let (t0, t1) = (self.params[segment], self.params[segment + 1]);
+        t0 + (t1 - t0) * s
+    }
+
+    fn is_closed(&self, weld: f64) -> bool {
+        self.points.len() > 3
+            && self.points[0].distance_squared_to(self.points[self.points.len() - 1]) <= weld * weld
+    }
+
+    fn segment_bvh(&self, margin: f64) -> Option<Bvh> {
+        let bboxes: Vec<BBox> = (0..self.segment_count())
+            .filter_map(|index| {
+                let (a, b) = self.segment(index);
+                BBox::from_points(&[a, b]).map(|bbox| bbox.expand_by(margin))
+            })
+            .collect();
+        Bvh::build(&bboxes)
+    }
+}
+
+/// A crossing between two curves.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CurveCurveHit {
+    pub point: Point3,
+    /// Parameter on the first curve.
+    pub t_a: f64,
+    /// Parameter on the second curve.
+    pub t_b: f64,
+}
+
+/// Finds the crossings of two curves.
+///
+/// The polylines act as the broad phase: segment pairs closer than `tol` plus
+/// both deviations become candidates, which are then polished on the curves
+/// themselves with [`refine_curve_curve_hit`] and kept when the curves are
+/// within `tol` of each other. Exact polylines skip the refinement.
+#[must_use]
+pub fn curve_curve_intersections<A: Curve3, B: Curve3>(
+    a: &A,
+    polyline_a: &ParamPolyline,
+    b: &B,
+    polyline_b: &ParamPolyline,
+    tol: Tolerance,
+) -> Vec<CurveCurveHit> {
+    let search = tol.eps + 2.0 * (polyline_a.deviation + polyline_b.deviation);
+    let exact = polyline_a.deviation <= 0.0 && polyline_b.deviation <= 0.0;
+
+    let mut hits = Vec::new();
+    for candidate in polyline_candidates(polyline_a, polyline_b, search, false) {
+        let hit = if exact {
+            candidate
+        } else {
+            let refined = refine_curve_curve_hit(a, b, candidate);
+            if a.point_at(refined.t_a).distance_to(b.point_at(refined.t_b)) > tol.eps {
+                continue;
+            }
+            refined
+        };
+        push_unique_hit(&mut hits, hit, tol.eps);
+    }
+
+    hits.sort_by(|x, y| x.t_a.total_cmp(&y.t_a));
+    hits
+}
+
+/// Finds the points where a curve crosses itself.
+///
+/// Works like [`curve_curve_intersections`] with the curve against itself;
+/// neighbouring segments are never compared. Each event reports the lower
+/// parameter as `t_a`.
+#[must_use]
+pub fn curve_self_intersections<C: Curve3>(
+    curve: &C,
+    polyline: &ParamPolyline,
+    tol: Tolerance,
+) -> Vec<CurveCurveHit> {
+    let search = tol.eps + 4.0 * polyline.deviation;
+    let (t0, t1) = curve.domain();
+    let collapse_eps = Tolerance::LOOSE.relative_to(t1 - t0);
+
+    let mut hits = Vec::new();
+    for candidate in polyline_candidates(polyline, polyline, search, true) {
+        let hit = if polyline.deviation <= 0.0 {
+            candidate
+        } else {
+            let refined = refine_curve_curve_hit(curve, curve, candidate);
+            let gap = curve
+                .point_at(refined.t_a)
+                .distance_to(curve.point_at(refined.t_b));
+            if gap > tol.eps || (refined.t_a - refined.t_b).abs() <= collapse_eps {
+                continue;
+            }
+            refined
+        };
+        let (t_a, t_b) = (hit.t_a.min(hit.t_b), hit.t_a.max(hit.t_b));
+        push_unique_hit(&mut hits, CurveCurveHit { t_a, t_b, ..hit }, tol.eps);
+    }
+
+    hits.sort_by(|x, y| x.t_a.total_cmp(&y.t_a));
+    hits
+}
+
+/// Polishes a curve/curve crossing with Gauss–Newton steps on the squared
+/// distance between the curves. The parameters stay inside both domains and
+/// the closest pair seen is returned; the point is its midpoint.
+#[must_use]
+pub fn refine_curve_curve_hit<A: Curve3, B: Curve3>(
+    a: &A,
+    b: &B,
+    hit: CurveCurveHit,
+) -> CurveCurveHit {
+    let (a0, a1) = a.domain();
+    let (b0, b1) = b.domain();
+    let (mut s, mut t) = (hit.t_a.clamp(a0, a1), hit.t_b.clamp(b0, b1));
+    let mut best = (f64::INFINITY, s, t);
+
+    for _ in 0..NEWTON_ITERATIONS {
+        let residual = a.point_at(s).sub_point(b.point_at(t));
+        let distance_squared = residual.length_squared();
+        if distance_squared < best.0 {
+            best = (distance_squared, s, t);
+        }
+        if distance_squared <= Tolerance::ZERO_LENGTH.eps_squared() {
+            break;
+        }
+
+        let da = a.derivative_at(s);
+        let db = b.derivative_at(t);
+        let (m00, m01, m11) = (da.dot(da), -da.dot(db), db.dot(db));
+        let (g0, g1) = (da.dot(residual), -db.dot(residual));
+        let determinant = m00 * m11 - m01 * m01;
+        if !determinant.is_finite() || determinant.abs() <= Tolerance::ZERO_LENGTH.eps {
+            break;
+        }
+        s = (s - (m11 * g0 - m01 * g1) / determinant).clamp(a0, a1);
+        t = (t - (m00 * g1 - m01 * g0) / determinant).clamp(b0, b1);
+    }
+
+    let (_, s, t) = best;
+    CurveCurveHit {
+        point: a.point_at(s).lerp(b.point_at(t), 0.5),
+        t_a: s,
+        t_b: t,
+    }
+}
+
+/// Intersects a triangle mesh with a polyline.
+///
+/// Every segment is cast against one shared triangle BVH. The hits reuse
+/// [`MeshRayHit`], with `t` holding the curve parameter; hits on segment
+/// joints and on the seam of a closed polyline are reported once.
+#[must_use]
+pub fn mesh_polyline_hits(
+    mesh: &GeomMesh,
+    polyline: &ParamPolyline,
+    tol: Tolerance,
+) -> Vec<MeshRayHit> {
+    let Some(set) = TriangleSet::new(mesh, tol) else {
+        return Vec::new();
+    };
+    let merge_squared = tol.eps.max(Tolerance::LOOSE.eps).powi(2);
+
+    let mut hits: Vec<MeshRayHit> = Vec::new();
+    for index in 0..polyline.segment_count() {
+        let (start, end) = polyline.segment(index);
+        for hit in set.ray_hits(start, end.sub_point(start), 0.0, 1.0, tol) {
+            let duplicate = hits.last().is_some_and(|previous| {
+                previous.point.distance_squared_to(hit.point) <= merge_squared
+            });
+            if !duplicate {
+                hits.push(MeshRayHit {
+                    t: polyline.param_at(index, hit.t),
+                    ..hit
+                });
+            }
+        }
+    }
+
+    if hits.len() > 1
+        && polyline.is_closed(tol.eps)
+        && hits[0]
+            .point
+            .distance_squared_to(hits[hits.len() - 1].point)
+            <= merge_squared
+    {
+        hits.pop();
+    }
+    hits
+}
+
+/// Result of intersecting two triangle meshes.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MeshMeshIntersection {
+    /// Intersection curves, stitched from the triangle/triangle segments.
+    pub polylines: Vec<SectionPolyline>,
+    /// Points where the meshes only touch, away from any polyline.
+    pub points: Vec<Point3>,
+    /// Number of triangle/triangle segments the polylines were stitched from.
+    pub segment_count: usize,
+    /// Number of coplanar triangle pairs; these produce no curves.
+    pub coplanar_pair_count: usize,
+}
+
+/// Intersects two triangle meshes.
+///
+/// Each triangle of `a` queries a BVH over `b`; the exact pairs come from
+/// [`triangle_triangle_intersection`] and the segments are joined with
+/// [`stitch_segments`]. Coplanar overlaps do not produce curves.
+#[must_use]
+pub fn mesh_mesh_intersection(a: &GeomMesh, b: &GeomMesh, tol: Tolerance) -> MeshMeshIntersection {
+    let (Some(set_a), Some(set_b)) = (TriangleSet::new(a, tol), TriangleSet::new(b, tol)) else {
+        return MeshMeshIntersection::default();
+    };
+    let bounds = set_a.bbox().union(set_b.bbox());
+    if !set_a.bbox().intersects(set_b.bbox()) {
+        return MeshMeshIntersection::default();
+    }
+
+    let mut segments = Vec::new();
+    let mut touches = Vec::new();
+    let mut coplanar_pair_count = 0;
+    for (index, triangle) in set_a.triangles.iter().enumerate() {
+        set_b.bvh.query_bbox(set_a.bboxes[index], |other| {
+            match triangle_triangle_intersection(*triangle, set_b.triangles[other], tol) {
+                Some(TriTriIntersection::Segment(segment)) => segments.push(segment),
+                Some(TriTriIntersection::Point(point)) => touches.push(point),
+                Some(TriTriIntersection::Coplanar) => coplanar_pair_count += 1,
+                None => {}
+            }
+            true
+        });
+    }
+
+    let weld = (bounds.diagonal() * 1e-8).max(tol.eps);
+    let polylines = stitch_segments(&segments, weld);
+
+    let mut welder = PointWelder::new(weld);
+    for point in polylines.iter().flat_map(|polyline| polyline.points.iter()) {
+        welder.insert(*point);
+    }
+    let mut points = Vec::new();
+    for point in touches {
+        let before = welder.points.len();
+        if welder.insert(point) == before {
+            points.push(point);
+        }
+    }
+
+    MeshMeshIntersection {
+        polylines,
+        points,
+        segment_count: segments.len(),
+        coplanar_pair_count,
+    }
+}
+
+/// A clash between two meshes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MeshClash {
+    /// Centre of the clash region.
+    pub center: Point3,
+    /// Radius of a sphere around `center` that covers the clash.
+    pub radius: f64,
+    /// Gap between the meshes; zero when they intersect.
+    pub distance: f64,
+}
+
+/// Checks two meshes for a clash.
+///
+/// Intersecting meshes clash with distance zero, centred on the bounding box
+/// of the intersection points. Otherwise the meshes clash when their closest
+/// triangles are at most `max_distance` apart; the clash then sits halfway
+/// between the closest points.
+#[must_use]
+pub fn mesh_clash(
+    a: &GeomMesh,
+    b: &GeomMesh,
+    max_distance: f64,
+    tol: Tolerance,
+) -> Option<MeshClash> {
+    let set_a = TriangleSet::new(a, tol)?;
+    let set_b = TriangleSet::new(b, tol)?;
+    let margin = max_distance.max(0.0);
+    if !set_a.bbox().expand_by(margin).intersects(set_b.bbox()) {
+        return None;
+    }
+
+    let mut contact = Vec::new();
+    for (index, triangle) in set_a.triangles.iter().enumerate() {
+        set_b.bvh.query_bbox(set_a.bboxes[index], |other| {
+            let other = set_b.triangles[other];
+            match triangle_triangle_intersection(*triangle, other, tol) {
+                Some(TriTriIntersection::Segment(segment)) => {
+                    contact.extend([segment.a, segment.b])
+                }
+                Some(TriTriIntersection::Point(point)) => contact.push(point),
+                Some(TriTriIntersection::Coplanar) => {
+                    let (p, q, _) = triangle_distance(*triangle, other);
+                    contact.push(p.lerp(q, 0.5));
+                }
+                None => {}
+            }
+            true
+        });
+    }
+    if let Some(bbox) = BBox::from_points(&contact) {
+        return Some(MeshClash {
+            center: bbox.center(),
+            radius: 0.5 * bbox.diagonal(),
+            distance: 0.0,
+        });
+    }
+    if margin <= 0.0 {
+        return None;
+    }
+
+    let mut best: Option<(Point3, Point3, f64)> = None;
+    for (index, triangle) in set_a.triangles.iter().enumerate() {
+        set_b
+            .bvh
+            .query_bbox(set_a.bboxes[index].expand_by(margin), |other| {
+                let (p, q, distance) = triangle_distance(*triangle, set_b.triangles[other]);
+                if distance <= margin && best.is_none_or(|(_, _, current)| distance < current) {
+                    best = Some((p, q, distance));
+                }
+                true
+            });
+    }
+    best.map(|(p, q, distance)| MeshClash {
+        center: p.lerp(q, 0.5),
+        radius: 0.5 * distance,
+        distance,
+    })
+}
+
+/// Tests whether two meshes collide: their triangles intersect or one mesh
+/// lies inside the other.
+#[must_use]
+pub fn meshes_collide(a: &GeomMesh, b: &GeomMesh, tol: Tolerance) -> bool {
+    let (Some(set_a), Some(set_b)) = (TriangleSet::new(a, tol), TriangleSet::new(b, tol)) else {
+        return false;
+    };
+    if !set_a.bbox().intersects(set_b.bbox()) {
+        return false;
+    }
+
+    let mut touching = false;
+    for (index, triangle) in set_a.triangles.iter().enumerate() {
+        set_b.bvh.query_bbox(set_a.bboxes[index], |other| {
+            touching =
+                triangle_triangle_intersection(*triangle, set_b.triangles[other], tol).is_some();
+            !touching
+        });
+        if touching {
+            return true;
+        }
+    }
+
+    let inside = |point: Point3, mesh: &GeomMesh| {
+        classify_point_in_mesh(point, mesh, tol) == PointContainment::Inside
+    };
+    inside(set_a.triangles[0].a, b) || inside(set_b.triangles[0].a, a)
+}
+
+/// Splits a triangle mesh along polylines that lie on it.
+///
+/// Each cutter segment spans a ribbon along the local surface normal; the
+/// triangles crossing that ribbon are clipped by the plane through the segment
+/// and the normal. The mesh then falls apart into the patches that stay
+/// connected without crossing a cut. Closed cutters repeat their first point.
+#[must_use]
+pub fn split_mesh_with_polylines(
+    mesh: &GeomMesh,
+    cutters: &[Vec<Point3>],
+    tol: Tolerance,
+) -> Vec<GeomMesh> {
+    let points: Vec<Point3> = mesh.positions.iter().copied().map(Point3::from).collect();
+    let Some(bounds) = BBox::from_points(&points) else {
+        return Vec::new();
+    };
+    let diagonal = bounds.diagonal().max(tol.eps);
+    let weld = (diagonal * 1e-8).max(tol.eps);
+
+    let mut welder = PointWelder::new(weld);
+    let remap: Vec<usize> = points.iter().map(|&point| welder.insert(point)).collect();
+    let mut triangles = Vec::with_capacity(mesh.indices.len() / 3);
+    for tri in mesh.indices.chunks_exact(3) {
+        let Some(corners) = tri
+            .iter()
+            .map(|&index| remap.get(index as usize).copied())
+            .collect::<Option<Vec<usize>>>()
+        else {
+            return Vec::new();
+        };
+        if corners[0] != corners[1] && corners[1] != corners[2] && corners[2] != corners[0] {
+            triangles.push([corners[0], corners[1], corners[2]]);
+        }
+    }
+
+    let mut splitter = MeshSplitter {
+        vertices: welder.points,
+        crossings: HashMap::new(),
+        cuts: Vec::new(),
+        weld,
+    };
+    for pair in cutters.iter().flat_map(|cutter| cutter.windows(2)) {
+        let (start, end) = (pair[0], pair[1]);
+        let Some(closest) = closest_point_on_mesh(mesh, start.lerp(end, 0.5)) else {
+            continue;
+        };
+        let along = end.sub_point(start);
+        let (Some(plane_normal), Some(surface_normal)) = (
+            along.cross(closest.normal).normalized(),
+            closest.normal.normalized(),
+        ) else {
+            continue;
+        };
+        let reach = (2.0 * closest.distance_squared.sqrt())
+            .max(0.25 * along.length())
+            .max(diagonal * 1e-3);
+        let offset = surface_normal * reach;
+        let ribbon = [
+            Triangle3::new(
+                start.sub_vec(offset),
+                end.sub_vec(offset),
+                end.add_vec(offset),
+            ),
+            Triangle3::new(
+                start.sub_vec(offset),
+                end.add_vec(offset),
+                start.add_vec(offset),
+            ),
+        ];
+        let ribbon_bbox = ribbon[0].bbox().union(ribbon[1].bbox());
+
+        let cut = splitter.cuts.len();
+        splitter.cuts.push(CutRibbon {
+            start,
+            end,
+            plane_normal,
+            reach,
+        });
+        let mut next = Vec::with_capacity(triangles.len());
+        for corners in triangles {
+            let triangle = Triangle3::new(
+                splitter.vertices[corners[0]],
+                splitter.vertices[corners[1]],
+                splitter.vertices[corners[2]],
+            );
+            let crosses = triangle
+                .bbox()
+                .expand_tolerance(tol)
+                .intersects(ribbon_bbox)
+                && ribbon.iter().any(|side| {
+                    matches!(
+                        triangle_triangle_intersection(triangle, *side, tol),
+                        Some(TriTriIntersection::Segment(_))
+                    )
+                });
+            if crosses {
+                splitter.split(corners, cut, &mut next);
+            } else {
+                next.push(corners);
+            }
+        }
+        triangles = splitter.conform(next, cut);
+    }
+
+    splitter.fragments(&triangles)
+}
+
+/// Möller–Trumbore ray/triangle test returning `(t, barycentric)`.
+fn ray_triangle_hit(
+    origin: Point3,
+    direction: Vec3,
+    triangle: Triangle3,
+    tol: Tolerance,
+) -> Option<(f64, [f64; 3])> {
+    let edge1 = triangle.b.sub_point(triangle.a);
+    let edge2 = triangle.c.sub_point(triangle.a);
+    let p = direction.cross(edge2);
+    let det = edge1.dot(p);
+    if !det.is_finite() || det.abs() <= Tolerance::ZERO_LENGTH.eps {
+        return None;
+    }
+
+    let inv_det = 1.0 / det;
+    let s = origin.sub_point(triangle.a);
+    let u = s.dot(p) * inv_det;
+    let q = s.cross(edge1);
+    let v = direction.dot(q) * inv_det;
+    let eps = tol.eps.max(Tolerance::LOOSE.eps);
+    if u < -eps || v < -eps || u + v > 1.0 + eps {
+        return None;
+    }
+
+    let t = edge2.dot(q) * inv_det;
+    t.is_finite().then_some((t, [1.0 - u - v, u, v]))
+}
+
+/// Returns two unit axes spanning the plane with the given unit normal.
+fn plane_axes(normal: Vec3) -> (Vec3, Vec3) {
+    let helper = if normal.x.abs() <= normal.y.abs() && normal.x.abs() <= normal.z.abs() {
+        Vec3::X
+    } else if normal.y.abs() <= normal.z.abs() {
+        Vec3::Y
+    } else {
+        Vec3::Z
+    };
+    let u_axis = helper.cross(normal).normalized().unwrap_or(Vec3::X);
+    let v_axis = normal.cross(u_axis);
+    (u_axis, v_axis)
+}
+
+/// Triangles of a mesh together with a BVH over their bounding boxes.
+struct TriangleSet {
+    triangles: Vec<Triangle3>,
+    bboxes: Vec<BBox>,
+    bvh: Bvh,
+}
+
+impl TriangleSet {
+    /// Returns `None` for empty meshes or meshes with out-of-range indices.
+    fn new(mesh: &GeomMesh, tol: Tolerance) -> Option<Self> {
+        let points: Vec<Point3> = mesh.positions.iter().copied().map(Point3::from).collect();
+        let mut triangles = Vec::with_capacity(mesh.indices.len() / 3);
+        for tri in mesh.indices.chunks_exact(3) {
+            let corners = [tri[0] as usize, tri[1] as usize, tri[2] as usize];
+            if corners.iter().any(|&index| index >= points.len()) {
+                return None;
+            }
+            triangles.push(Triangle3::new(
+                points[corners[0]],
+                points[corners[1]],
+                points[corners[2]],
+            ));
+        }
+
+        let bboxes: Vec<BBox> = triangles
+            .iter()
+            .map(|triangle| triangle.bbox().expand_tolerance(tol))
+            .collect();
+        let bvh = Bvh::build(&bboxes)?;
+        Some(Self {
+            triangles,
+            bboxes,
+            bvh,
+        })
+    }
+
+    fn bbox(&self) -> BBox {
+        self.bboxes
+            .iter()
+            .copied()
+            .reduce(BBox::union)
+            .expect("a triangle set is never empty")
+    }
+
+    fn ray_hits(
+        &self,
+        origin: Point3,
+        direction: Vec3,
+        t_min: f64,
+        t_max: f64,
+        tol: Tolerance,
+    ) -> Vec<MeshRayHit> {
+        let direction_length = direction.length();
+        if !direction_length.is_finite() || direction_length <= Tolerance::ZERO_LENGTH.eps {
+            return Vec::new();
+        }
+
+        let mut hits = Vec::new();
+        self.bvh
+            .query_ray(origin, direction, t_min, t_max, |index| {
+                let triangle = self.triangles[index];
+                if let Some((t, barycentric)) = ray_triangle_hit(origin, direction, triangle, tol)
+                    && t >= t_min
+                    && t <= t_max
+                {
+                    hits.push(MeshRayHit {
+                        point: origin.add_vec(direction * t),
+                        t,
+                        triangle: index,
+                        barycentric,
+                        normal: triangle.normal().normalized().unwrap_or(Vec3::Z),
+                    });
+                }
+                true
+            });
+
+        hits.sort_by(|a, b| a.t.total_cmp(&b.t));
+        let merge_eps = tol.eps.max(Tolerance::LOOSE.eps) / direction_length;
+        hits.dedup_by(|next, previous| (next.t - previous.t).abs() <= merge_eps);
+        hits
+    }
+}
+
+/// Spatial hash that merges points closer than a weld distance.
+struct PointWelder {
+    cell: f64,
+    weld_squared: f64,
+    points: Vec<Point3>,
+    grid: HashMap<[i64; 3], Vec<usize>>,
+}
+
+impl PointWelder {
+    fn new(weld: f64) -> Self {
+        Self {
+            cell: weld * 4.0,
+            weld_squared: weld * weld,
+            points: Vec::new(),
+            grid: HashMap::new(),
+        }
+    }
+
+    fn key(&self, point: Point3) -> [i64; 3] {
+        [
+            (point.x / self.cell).floor() as i64,
+            (point.y / self.cell).floor() as i64,
+            (point.z / self.cell).floor() as i64,
+        ]
+    }
+
+    fn insert(&mut self, point: Point3) -> usize {
+        let key = self.key(point);
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                for dz in -1..=1 {
+                    let neighbour = [key[0] + dx, key[1] + dy, key[2] + dz];
+                    let Some(candidates) = self.grid.get(&neighbour) else {
+                        continue;
+                    };
+                    for &index in candidates {
+                        if self.points[index].distance_squared_to(point) <= self.weld_squared {
+                            return index;
+                        }
+                    }
+                }
+            }
+        }
+
+        let index = self.points.len();
+        self.points.push(point);
+        self.grid.entry(key).or_default().push(index);
+        index
+    }
+}
+
+/// Collects segment pairs of two polylines that come within `search`, as
+/// parameter pairs at their closest points. With `same` set, `a` and `b` are
+/// the same polyline and neighbouring segments are skipped.
+fn polyline_candidates(
+    a: &ParamPolyline,
+    b: &ParamPolyline,
+    search: f64,
+    same: bool,
+) -> Vec<CurveCurveHit> {
+    let Some(bvh) = b.segment_bvh(search) else {
+        return Vec::new();
+    };
+    let last = b.segment_count().saturating_sub(1);
+    let closed = same && b.is_closed(search);
+
+    let mut candidates: Vec<CurveCurveHit> = Vec::new();
+    for i in 0..a.segment_count() {
+        let (p0, p1) = a.segment(i);
+        let Some(query) = BBox::from_points(&[p0, p1]) else {
+            continue;
+        };
+        bvh.query_bbox(query, |j| {
+            if same && (j <= i + 1 || (closed && i == 0 && j == last)) {
+                return true;
+            }
+            let (q0, q1) = b.segment(j);
+            let (s, t) = segment_closest_params(p0, p1, q0, q1);
+            let p = p0.lerp(p1, s);
+            let q = q0.lerp(q1, t);
+            if p.distance_to(q) <= search {
+                candidates.push(CurveCurveHit {
+                    point: p.lerp(q, 0.5),
+                    t_a: a.param_at(i, s),
+                    t_b: b.param_at(j, t),
+                });
+            }
+            true
+        });
+    }
+
+    let mut unique = Vec::with_capacity(candidates.len());
+    for candidate in candidates {
+        push_unique_hit(&mut unique, candidate, search);
+    }
+    unique
+}
+
+fn push_unique_hit(hits: &mut Vec<CurveCurveHit>, hit: CurveCurveHit, merge: f64) {
+    let merge_squared = merge * merge;
+    if hits
+        .iter()
+        .all(|existing| existing.point.distance_squared_to(hit.point) > merge_squared)
+    {
+        hits.push(hit);
+    }
+}
+
+/// Closest points between segments `p0-p1` and `q0-q1` as segment parameters
+/// (Ericson, Real-Time Collision Detection, 5.1.9).
+fn segment_closest_params(p0: Point3, p1: Point3, q0: Point3, q1: Point3) -> (f64, f64) {
+    let d1 = p1.sub_point(p0);
+    let d2 = q1.sub_point(q0);
+    let r = p0.sub_point(q0);
+    let a = d1.dot(d1);
+    let e = d2.dot(d2);
+    let f = d2.dot(r);
+    let eps = Tolerance::ZERO_LENGTH.eps_squared();
+
+    if a <= eps && e <= eps {
+        return (0.0, 0.0);
+    }
+    if a <= eps {
+        return (0.0, (f / e).clamp(0.0, 1.0));
+    }
+    let c = d1.dot(r);
+    if e <= eps {
+        return ((-c / a).clamp(0.0, 1.0), 0.0);
+    }
+
+    let b = d1.dot(d2);
+    let denominator = a * e - b * b;
+    let s = if denominator > eps * a * e {
+        ((b * f - c * e) / denominator).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let t = (b * s + f) / e;
+    if t < 0.0 {
+        ((-c / a).clamp(0.0, 1.0), 0.0)
+    } else if t > 1.0 {
+        (((b - c) / a).clamp(0.0, 1.0), 1.0)
+    } else {
+        (s, t)
+    }
+}
+
+/// Closest points between two triangles and their distance. Only valid for
+/// triangles that do not intersect.
+fn triangle_distance(a: Triangle3, b: Triangle3) -> (Point3, Point3, f64) {
+    let mut best = (a.a, b.a, f64::INFINITY);
+    let mut consider = |p: Point3, q: Point3| {
+        let distance = p.distance_to(q);
+        if distance < best.2 {
+            best = (p, q, distance);
+        }
+    };
+
+    for p in [a.a, a.b, a.c] {
+        consider(p, closest_point_on_triangle(p, b.a, b.b, b.c).0);
+    }
+    for q in [b.a, b.b, b.c] {
+        consider(closest_point_on_triangle(q, a.a, a.b, a.c).0, q);
+    }
+    for (p0, p1) in [(a.a, a.b), (a.b, a.c), (a.c, a.a)] {
+        for (q0, q1) in [(b.a, b.b), (b.b, b.c), (b.c, b.a)] {
+            let (s, t) = segment_closest_params(p0, p1, q0, q1);
+            consider(p0.lerp(p1, s), q0.lerp(q1, t));
+        }
+    }
+    best
+}
+
+fn point_segment_distance_squared(point: Point3, segment: Segment3) -> f64 {
+    let direction = segment.b.sub_point(segment.a);
+    let length_squared = direction.length_squared();
+    let t = if length_squared <= Tolerance::ZERO_LENGTH.eps_squared() {
+        0.0
+    } else {
+        (point.sub_point(segment.a).dot(direction) / length_squared).clamp(0.0, 1.0)
+    };
+    point.distance_squared_to(segment.a.lerp(segment.b, t))
+}
+
+/// One cutter segment of [`split_mesh_with_polylines`] with its ribbon.
+struct CutRibbon {
+    start: Point3,
+    end: Point3,
+    /// Normal of the cutting plane through the segment and the surface normal.
+    plane_normal: Vec3,
+    /// Half height of the ribbon along the surface normal.
+    reach: f64,
+}
+
+impl CutRibbon {
+    /// Whether `point` lies on the ribbon, within `near` of its plane and of
+    /// the segment ends.
+    fn contains(&self, point: Point3, near: f64) -> bool {
+        let offset = point.sub_point(self.start);
+        if offset.dot(self.plane_normal).abs() > near {
+            return false;
+        }
+        let along = self.end.sub_point(self.start);
+        let length = along.length();
+        let s = offset.dot(along) / length;
+        s >= -near
+            && s <= length + near
+            && point_segment_distance_squared(point, Segment3::new(self.start, self.end))
+                <= self.reach * self.reach
+    }
+
+    fn bbox(&self, near: f64) -> Option<BBox> {
+        BBox::from_points(&[self.start, self.end]).map(|bbox| bbox.expand_by(self.reach + near))
+    }
+}
+
+/// Working state of [`split_mesh_with_polylines`].
+struct MeshSplitter {
+    vertices: Vec<Point3>,
+    /// Vertex created where a cut crosses an edge, keyed by the edge and cut.
+    crossings: HashMap<(usize, usize, usize), usize>,
+    /// Cutter segments; mesh edges lying on them separate fragments.
+    cuts: Vec<CutRibbon>,
+    weld: f64,
+}
+
+impl MeshSplitter {
+    /// Clips one triangle by the plane of a cut and pushes the pieces on both
+    /// sides, fan-triangulated in the original winding. The plane runs across
+    /// the whole triangle; only edges on the ribbon itself separate fragments.
+    fn split(&mut self, corners: [usize; 3], cut: usize, output: &mut Vec<[usize; 3]>) {
+        let (origin, normal) = (self.cuts[cut].start, self.cuts[cut].plane_normal);
+        let distances = corners.map(|corner| self.vertices[corner].sub_point(origin).dot(normal));
+        let sides = distances.map(|distance| {
+            if distance > self.weld {
+                1
+            } else if distance < -self.weld {
+                -1
+            } else {
+                0
+            }
+        });
+        if !(sides.contains(&1) && sides.contains(&-1)) {
+            output.push(corners);
+            return;
+        }
+
+        let mut positive = Vec::with_capacity(4);
+        let mut negative = Vec::with_capacity(4);
+        for i in 0..3 {
+            let j = (i + 1) % 3;
+            let (from, to) = (corners[i], corners[j]);
+            match sides[i] {
+                1 => positive.push(from),
+                -1 => negative.push(from),
+                _ => {
+                    positive.push(from);
+                    negative.push(from);
+                }
+            }
+            if sides[i] * sides[j] == -1 {
+                let key = (from.min(to), from.max(to), cut);
+                let vertices = &mut self.vertices;
+                let crossing = *self.crossings.entry(key).or_insert_with(|| {
+                    let s = distances[i] / (distances[i] - distances[j]);
+                    vertices.push(vertices[from].lerp(vertices[to], s));
+                    vertices.len() - 1
+                });
+                positive.push(crossing);
+                negative.push(crossing);
+            }
+        }
+
+        for polygon in [positive, negative] {
+            for k in 1..polygon.len().saturating_sub(1) {
+                output.push([polygon[0], polygon[k], polygon[k + 1]]);
+            }
+        }
+    }
+
+    /// Splits triangles that were not clipped by `cut` at the crossing
+    /// vertices it left on their edges, so neighbours keep sharing edges.
+    fn conform(&self, triangles: Vec<[usize; 3]>, cut: usize) -> Vec<[usize; 3]> {
+        let mut output = Vec::with_capacity(triangles.len());
+        let mut pending = Vec::new();
+        for corners in triangles {
+            pending.push(corners);
+            while let Some(corners) = pending.pop() {
+                let crossing = (0..3).find_map(|k| {
+                    let (a, b) = (corners[k], corners[(k + 1) % 3]);
+                    let crossing = self.crossings.get(&(a.min(b), a.max(b), cut))?;
+                    Some((k, *crossing))
+                });
+                match crossing {
+                    Some((k, vertex)) => {
+                        let [a, b, opposite] = [0, 1, 2].map(|offset| corners[(k + offset) % 3]);
+                        pending.push([vertex, b, opposite]);
+                        pending.push([a, vertex, opposite]);
+                    }
+                    None => output.push(corners),
+                }
+            }
+        }
+        output
+    }
+
+    /// Groups triangles that share an edge not lying on a cut and returns
+    /// each group as a compact mesh.
+    fn fragments(&self, triangles: &[[usize; 3]]) -> Vec<GeomMesh> {
+        fn find(parent: &mut [usize], mut index: usize) -> usize {
+            while parent[index] != index {
+                parent[index] = parent[parent[index]];
+                index = parent[index];
+            }
+            index
+        }
+
+        let near = self.weld * 2.0;
+        let cut_bvh = Bvh::build(
+            &self
+                .cuts
+                .iter()
+                .filter_map(|cut| cut.bbox(near))
+                .collect::<Vec<_>>(),
+        );
+        let on_cut = |point: Point3| {
+            let Some(bvh) = cut_bvh.as_ref() else {
+                return false;
+            };
+            let mut found = false;
+            bvh.query_bbox(BBox::new(point, point), |index| {
+                found = self.cuts[index].contains(point, near);
+                !found
+            });
+            found
+        };
+
+        let mut edges: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+        for (index, corners) in triangles.iter().enumerate() {
+            for k in 0..3 {
+                let (a, b) = (corners[k], corners[(k + 1) % 3]);
+                edges.entry((a.min(b), a.max(b))).or_default().push(index);
+            }
+        }
+
+        let mut parent: Vec<usize> = (0..triangles.len()).collect();
+        for ((a, b), owners) in &edges {
+            if owners.len() < 2 {
+                continue;
+            }
+            let (pa, pb) = (self.vertices[*a], self.vertices[*b]);
+            if on_cut(pa) && on_cut(pb) && on_cut(pa.lerp(pb, 0.5)) {
+                continue;
+            }
+            let root = find(&mut parent, owners[0]);
+            for &owner in &owners[1..] {
+                let other = find(&mut parent, owner);
+                parent[other] = root;
+            }
+        }
+
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        let mut group_of_root: HashMap<usize, usize> = HashMap::new();
+        for index in 0..triangles.len() {
+            let root = find(&mut parent, index);
+            let group = *group_of_root.entry(root).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[group].push(index);
+        }
+
+        groups
+            .into_iter()
+            .map(|group| {
+                let mut remap = HashMap::new();
+                let mut positions = Vec::new();
+                let mut indices = Vec::with_capacity(group.len() * 3);
+                for corner in group.into_iter().flat_map(|index| triangles[index]) {
+                    let vertex = *remap.entry(corner).or_insert_with(|| {
+                        positions.push(self.vertices[corner].to_array());
+                        positions.len() - 1
+                    });
+                    indices.push(vertex as u32);
+                }
+                GeomMesh::new(positions, indices)
+            })
+            .collect()
+    }
+}
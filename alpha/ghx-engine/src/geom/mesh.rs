@@ -1830,7 +1830,7 @@ pub fn closest_point_on_mesh_simple(mesh: &GeomMesh, query: Point3) -> Option<(P
 /// * The closest point on the triangle.
 /// * Barycentric coordinates (u, v, w) where the closest point = a*w + b*u + c*v
 ///   and w = 1 - u - v.
-fn closest_point_on_triangle(p: Point3, a: Point3, b: Point3, c: Point3) -> (Point3, (f64, f64, f64)) {
+pub(crate) fn closest_point_on_triangle(p: Point3, a: Point3, b: Point3, c: Point3) -> (Point3, (f64, f64, f64)) {
     // Check if P is in vertex region outside A
     let ab = b.sub_point(a);
     let ac = c.sub_point(a);
@@ -11,12 +11,14 @@ mod diagnostics;
 mod displacement;
 mod extrusion;
 mod fillet_chamfer;
+mod intersect;
 mod loft;
 mod metrics;
 mod mesh;
 mod offset;
 mod patch;
 mod pipe;
+mod region;
 mod revolve;
 mod simplify;
 mod solid;
@@ -35,11 +37,11 @@ pub use analysis::{
     edges_by_length, edges_from_directions, edges_from_faces, edges_from_points, surface_frames,
 };
 pub use boolean::{
-    BooleanDiagnostics, BooleanError, BooleanOp, BooleanResult,
+    BooleanDiagnostics, BooleanError, BooleanOp, BooleanResult, MeshSplitResult,
     PointContainment, Segment3, TaggedTriangle, Triangle3, TriangleContainment, TriangleSource,
     TriTriIntersection,
-    boolean_meshes, classify_mesh_triangles, classify_point_in_mesh, tag_mesh_triangles,
-    triangle_mesh_intersection_segments, triangle_triangle_intersection,
+    boolean_meshes, classify_mesh_triangles, classify_point_in_mesh, split_mesh_by_mesh,
+    tag_mesh_triangles, triangle_mesh_intersection_segments, triangle_triangle_intersection,
 };
 pub use cache::{GeomCache, GeomCacheStats};
 pub use core::{BBox, Point3, Tolerance, Transform, Vec3};
@@ -57,7 +59,7 @@ pub use curve::{
     // Curve analysis utilities
     CurvatureAnalysis, SegmentLengthAnalysis,
     analyze_curvature_at, analyze_polyline_segments,
-    curve_angle_at, curve_curvature_center_at, curve_curvature_vector_at,
+    curve_angle_at, curve_closest_point, curve_curvature_center_at, curve_curvature_vector_at,
     curve_length_at, curve_parameter_at_length,
     curve_third_derivative_at, curve_torsion_at,
 };
@@ -125,6 +127,12 @@ pub use fillet_chamfer::{
     TriangleMeshEdge, fillet_legacy_triangle_mesh_edges, fillet_polyline_points,
     fillet_triangle_mesh_edges, list_triangle_mesh_edges,
 };
+pub use intersect::{
+    CurveCurveHit, MeshClash, MeshMeshIntersection, MeshRayHit, ParamPolyline, SectionPolyline,
+    curve_curve_intersections, curve_plane_intersections_refined, curve_self_intersections,
+    mesh_clash, mesh_mesh_intersection, mesh_plane_section, mesh_polyline_hits, mesh_ray_hits,
+    meshes_collide, refine_curve_curve_hit, split_mesh_with_polylines, stitch_segments,
+};
 pub use loft::{
     LoftDiagnostics, LoftError, LoftOptions, LoftType, MeshQuality,
     control_point_loft_mesh, fit_loft_mesh, loft_mesh, loft_mesh_with_context,
@@ -173,9 +181,14 @@ pub use pipe::{
     pipe_polyline, pipe_polyline_with_tolerance,
     pipe_variable_polyline, pipe_variable_polyline_with_tolerance,
 };
+pub use region::{
+    BoxSlitsResult, Region2, RegionBooleanResult, RegionDiagnostics, RegionOp, RegionPlane,
+    RegionSlitsResult, SlitBox, SlitRegion,
+    box_slits, extrude_region, region_boolean, region_slits,
+};
 pub use surface::{
     ConeSurface, CylinderSurface, NurbsSurface, PlaneSurface, SphereSurface, Surface,
-    SurfaceCacheKey, TorusSurface, tessellate_surface_grid,
+    SurfaceCacheKey, TorusSurface, surface_closest_point, tessellate_surface_grid,
     ClosedSurfaceSampling, DivideSurfaceOptions, DivideSurfaceResult, divide_surface,
     IsotrimDiagnostics, IsotrimSurface, isotrim_surface,
     FlippedSurface, SurfaceFlipDiagnostics, SurfaceFlipGuide, flip_surface_orientation,
@@ -0,0 +1,1054 @@
+//! Planar region booleans, slits and region extrusion.
+//!
+//! A [`Region2`] is a closed outer polygon with optional holes, expressed in
+//! the 2D coordinates of a [`RegionPlane`]. [`region_boolean`] overlays the
+//! edges of both operands, splits them at every crossing, touch and collinear
+//! overlap, and keeps each edge whose two sides differ for the requested
+//! operation. Coincident edges of the operands collapse into one edge during
+//! the overlay, so shared boundaries need no special treatment. The kept
+//! edges are chained into loops by always taking the sharpest left turn,
+//! which keeps regions that only touch at a corner apart.
+//!
+//! Each operand is the union of its regions, so overlapping input regions are
+//! allowed. Result outers are counter-clockwise and holes clockwise.
+
+use std::collections::{HashMap, HashSet};
+use std::f64::consts::TAU;
+
+use super::core::{Point3, Tolerance, Vec3};
+use super::diagnostics::GeomMeshDiagnostics;
+use super::mesh::{GeomMesh, finalize_mesh};
+use super::triangulation::triangulate_trim_region;
+use super::trim::{TrimLoop, TrimRegion, UvPoint};
+
+type Point2 = [f64; 2];
+
+/// Plane in which regions live, with orthonormal axes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RegionPlane {
+    pub origin: Point3,
+    pub x_axis: Vec3,
+    pub y_axis: Vec3,
+}
+
+impl RegionPlane {
+    /// The world XY plane.
+    pub const WORLD_XY: Self = Self {
+        origin: Point3::ORIGIN,
+        x_axis: Vec3::X,
+        y_axis: Vec3::Y,
+    };
+
+    /// Builds a plane from an origin and two in-plane directions. The axes
+    /// are orthonormalised with the direction of `x_axis` kept; returns
+    /// `None` when they are parallel or degenerate.
+    #[must_use]
+    pub fn new(origin: Point3, x_axis: Vec3, y_axis: Vec3) -> Option<Self> {
+        let x_axis = x_axis.normalized()?;
+        let normal = x_axis.cross(y_axis).normalized()?;
+        Some(Self {
+            origin,
+            x_axis,
+            y_axis: normal.cross(x_axis),
+        })
+    }
+
+    /// Fits a plane through a closed polygon using Newell's method. The
+    /// origin is the vertex average and the x axis follows the first edge
+    /// that is not perpendicular to the plane. Returns `None` for collinear
+    /// or degenerate polygons.
+    #[must_use]
+    pub fn fit(points: &[Point3]) -> Option<Self> {
+        if points.len() < 3 {
+            return None;
+        }
+
+        let mut normal = Vec3::ZERO;
+        let mut sum = Vec3::ZERO;
+        for (index, current) in points.iter().enumerate() {
+            let next = points[(index + 1) % points.len()];
+            normal = normal.add(Vec3::new(
+                (current.y - next.y) * (current.z + next.z),
+                (current.z - next.z) * (current.x + next.x),
+                (current.x - next.x) * (current.y + next.y),
+            ));
+            sum = sum.add(current.to_vec3());
+        }
+        let origin = Point3::from_array(sum.div_scalar(points.len() as f64).to_array());
+
+        let extent = points
+            .iter()
+            .map(|point| point.sub_point(origin).length())
+            .fold(0.0, f64::max);
+        if normal.length() <= extent * extent * 1e-12 {
+            return None;
+        }
+        let normal = normal.normalized()?;
+
+        let x_axis = points.windows(2).find_map(|pair| {
+            let edge = pair[1].sub_point(pair[0]);
+            let in_plane = edge.sub(normal.mul_scalar(edge.dot(normal)));
+            (in_plane.length() > extent * 1e-9).then_some(in_plane)
+        })?;
+        Self::new(origin, x_axis, normal.cross(x_axis))
+    }
+
+    /// Unit normal, `x_axis × y_axis`.
+    #[must_use]
+    pub fn normal(&self) -> Vec3 {
+        self.x_axis.cross(self.y_axis)
+    }
+
+    /// Projects a point onto the plane and returns its plane coordinates.
+    #[must_use]
+    pub fn to_local(&self, point: Point3) -> Point2 {
+        let offset = point.sub_point(self.origin);
+        [offset.dot(self.x_axis), offset.dot(self.y_axis)]
+    }
+
+    /// Maps plane coordinates back to world space.
+    #[must_use]
+    pub fn to_world(&self, point: Point2) -> Point3 {
+        self.origin
+            .add_vec(self.x_axis.mul_scalar(point[0]))
+            .add_vec(self.y_axis.mul_scalar(point[1]))
+    }
+}
+
+/// A planar region: one outer loop with zero or more holes.
+///
+/// Loops are stored without a repeated closing vertex.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Region2 {
+    pub outer: Vec<Point2>,
+    pub holes: Vec<Vec<Point2>>,
+}
+
+impl Region2 {
+    /// Region without holes. The loop is reoriented counter-clockwise and a
+    /// repeated closing vertex is dropped.
+    #[must_use]
+    pub fn new(outer: Vec<Point2>) -> Self {
+        let mut outer = outer;
+        if outer.len() > 1 && outer.first() == outer.last() {
+            outer.pop();
+        }
+        if signed_area(&outer) < 0.0 {
+            outer.reverse();
+        }
+        Self {
+            outer,
+            holes: Vec::new(),
+        }
+    }
+
+    /// Outer loop followed by the holes.
+    pub fn loops(&self) -> impl Iterator<Item = &[Point2]> {
+        std::iter::once(self.outer.as_slice()).chain(self.holes.iter().map(Vec::as_slice))
+    }
+
+    /// Enclosed area, holes excluded.
+    #[must_use]
+    pub fn area(&self) -> f64 {
+        signed_area(&self.outer).abs()
+            - self
+                .holes
+                .iter()
+                .map(|hole| signed_area(hole).abs())
+                .sum::<f64>()
+    }
+
+    /// Whether `point` lies inside the outer loop and outside every hole.
+    /// Points exactly on the boundary may go either way.
+    #[must_use]
+    pub fn contains(&self, point: Point2) -> bool {
+        point_in_ring(point, &self.outer)
+            && !self.holes.iter().any(|hole| point_in_ring(point, hole))
+    }
+}
+
+/// Boolean operation for [`region_boolean`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegionOp {
+    Union,
+    Intersection,
+    Difference,
+}
+
+impl RegionOp {
+    fn keeps(self, in_a: bool, in_b: bool) -> bool {
+        match self {
+            Self::Union => in_a || in_b,
+            Self::Intersection => in_a && in_b,
+            Self::Difference => in_a && !in_b,
+        }
+    }
+}
+
+/// What happened during a region boolean.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RegionDiagnostics {
+    /// Loops in both operands, degenerate ones included.
+    pub input_loop_count: usize,
+    /// Input loops dropped for having fewer than three distinct vertices or
+    /// no enclosed area.
+    pub degenerate_loop_count: usize,
+    /// Edges in the overlay after splitting and welding.
+    pub edge_count: usize,
+    /// Proper crossings between input edges.
+    pub crossing_count: usize,
+    /// Overlay edges that appeared more than once, e.g. shared boundaries.
+    pub coincident_edge_count: usize,
+    /// Boundary chains that could not be closed into a loop.
+    pub open_chain_count: usize,
+    /// Result holes that did not lie inside any result outer.
+    pub orphan_hole_count: usize,
+    pub warnings: Vec<String>,
+}
+
+impl RegionDiagnostics {
+    fn merge(&mut self, other: Self) {
+        self.input_loop_count += other.input_loop_count;
+        self.degenerate_loop_count += other.degenerate_loop_count;
+        self.edge_count += other.edge_count;
+        self.crossing_count += other.crossing_count;
+        self.coincident_edge_count += other.coincident_edge_count;
+        self.open_chain_count += other.open_chain_count;
+        self.orphan_hole_count += other.orphan_hole_count;
+        for warning in other.warnings {
+            if !self.warnings.contains(&warning) {
+                self.warnings.push(warning);
+            }
+        }
+    }
+
+    fn finish(&mut self) {
+        if self.degenerate_loop_count > 0 {
+            self.warnings.push(format!(
+                "{} degenerate loop(s) ignored",
+                self.degenerate_loop_count
+            ));
+        }
+        if self.open_chain_count > 0 {
+            self.warnings.push(format!(
+                "{} boundary chain(s) could not be closed",
+                self.open_chain_count
+            ));
+        }
+        if self.orphan_hole_count > 0 {
+            self.warnings.push(format!(
+                "{} hole(s) without an outer boundary dropped",
+                self.orphan_hole_count
+            ));
+        }
+    }
+}
+
+/// Result of [`region_boolean`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RegionBooleanResult {
+    pub regions: Vec<Region2>,
+    pub diagnostics: RegionDiagnostics,
+}
+
+/// Combines two sets of regions in the same plane.
+///
+/// Vertices closer than `tol.eps` (scaled up for very large coordinates) are
+/// merged, which also absorbs near-coincident edges.
+#[must_use]
+pub fn region_boolean(
+    a: &[Region2],
+    b: &[Region2],
+    op: RegionOp,
+    tol: Tolerance,
+) -> RegionBooleanResult {
+    let scale = a
+        .iter()
+        .chain(b)
+        .flat_map(Region2::loops)
+        .flatten()
+        .fold(0.0_f64, |acc, point| {
+            acc.max(point[0].abs()).max(point[1].abs())
+        });
+    let weld = tol.eps.max(scale * 1e-10);
+
+    let mut diagnostics = RegionDiagnostics::default();
+    let a = clean_operand(a, weld, &mut diagnostics);
+    let b = clean_operand(b, weld, &mut diagnostics);
+
+    let segments: Vec<[Point2; 2]> = a
+        .iter()
+        .chain(&b)
+        .flat_map(Region2::loops)
+        .flat_map(|ring| {
+            (0..ring.len()).map(move |index| [ring[index], ring[(index + 1) % ring.len()]])
+        })
+        .collect();
+    let splits = split_parameters(&segments, weld, &mut diagnostics);
+
+    // Overlay: split segments, welded vertices and unique undirected edges.
+    let mut welder = Welder2::new(weld);
+    let mut edges = Vec::new();
+    let mut seen = HashSet::new();
+    for (segment, params) in segments.iter().zip(splits) {
+        let ids: Vec<usize> = params
+            .into_iter()
+            .map(|t| welder.insert(lerp2(segment[0], segment[1], t)))
+            .collect();
+        for pair in ids.windows(2) {
+            if pair[0] == pair[1] {
+                continue;
+            }
+            if seen.insert((pair[0].min(pair[1]), pair[0].max(pair[1]))) {
+                edges.push([pair[0], pair[1]]);
+            } else {
+                diagnostics.coincident_edge_count += 1;
+            }
+        }
+    }
+    diagnostics.edge_count = edges.len();
+    let points = welder.points;
+
+    // An edge is kept when the operation differs on its two sides and is
+    // oriented so that the result lies on its left.
+    let inside = |point: Point2| {
+        op.keeps(
+            a.iter().any(|region| region.contains(point)),
+            b.iter().any(|region| region.contains(point)),
+        )
+    };
+    let mut directed = Vec::new();
+    for [start, end] in edges {
+        let (p, q) = (points[start], points[end]);
+        let Some((mid, normal, offset)) = edge_probe_frame(p, q, weld) else {
+            continue;
+        };
+        let left = inside(add2(mid, scale2(normal, offset)));
+        let right = inside(add2(mid, scale2(normal, -offset)));
+        match (left, right) {
+            (true, false) => directed.push([start, end]),
+            (false, true) => directed.push([end, start]),
+            _ => {}
+        }
+    }
+
+    let rings = trace_loops(&points, &directed, &mut diagnostics);
+
+    let mut regions: Vec<Region2> = Vec::new();
+    let mut holes = Vec::new();
+    for ring in rings {
+        let Some(ring) = simplify_ring(ring, weld) else {
+            continue;
+        };
+        if signed_area(&ring) > 0.0 {
+            regions.push(Region2 {
+                outer: ring,
+                holes: Vec::new(),
+            });
+        } else {
+            holes.push(ring);
+        }
+    }
+    for hole in holes {
+        let Some((mid, normal, offset)) = edge_probe_frame(hole[0], hole[1], weld) else {
+            continue;
+        };
+        let probe = add2(mid, scale2(normal, offset));
+        let owner = regions
+            .iter_mut()
+            .filter(|region| point_in_ring(probe, &region.outer))
+            .min_by(|x, y| signed_area(&x.outer).total_cmp(&signed_area(&y.outer)));
+        match owner {
+            Some(region) => region.holes.push(hole),
+            None => diagnostics.orphan_hole_count += 1,
+        }
+    }
+
+    diagnostics.finish();
+    RegionBooleanResult {
+        regions,
+        diagnostics,
+    }
+}
+
+/// A region to be slotted by [`region_slits`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlitRegion {
+    pub plane: RegionPlane,
+    pub region: Region2,
+    /// Width of the slots this region cuts into the regions it crosses.
+    pub slot_width: f64,
+}
+
+/// Result of [`region_slits`], indexed like the input.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RegionSlitsResult {
+    /// Slotted regions per input region.
+    pub regions: Vec<Vec<Region2>>,
+    /// Indices of the regions each input region interlocks with.
+    pub topology: Vec<Vec<usize>>,
+    pub diagnostics: RegionDiagnostics,
+}
+
+/// Cuts interlocking slots where regions in different planes cross.
+///
+/// For every overlap of two regions along their intersection line the first
+/// region is slotted from its far boundary up to the middle of the overlap
+/// and the second region from its near boundary up to the middle, so the two
+/// can be slid together. `gap` is the extra clearance between the slot ends
+/// where the regions meet. Parallel planes never interlock.
+#[must_use]
+pub fn region_slits(inputs: &[SlitRegion], gap: f64, tol: Tolerance) -> RegionSlitsResult {
+    let mut slots: Vec<Vec<Region2>> = vec![Vec::new(); inputs.len()];
+    let mut topology: Vec<Vec<usize>> = vec![Vec::new(); inputs.len()];
+
+    for i in 0..inputs.len() {
+        for j in i + 1..inputs.len() {
+            let (first, second) = (&inputs[i], &inputs[j]);
+            let Some((origin, direction)) = plane_intersection(&first.plane, &second.plane) else {
+                continue;
+            };
+            let first_spans = line_spans(first, origin, direction, tol.eps);
+            let second_spans = line_spans(second, origin, direction, tol.eps);
+
+            let mut interlocked = false;
+            for &(e0, e1) in &first_spans {
+                for &(f0, f1) in &second_spans {
+                    let (s0, s1) = (e0.max(f0), e1.min(f1));
+                    if s1 - s0 <= tol.eps {
+                        continue;
+                    }
+                    // Slots overshoot the boundary so oblique edges are cut
+                    // through completely.
+                    let meet = 0.5 * (s0 + s1);
+                    slots[i].push(slot(
+                        first,
+                        origin,
+                        direction,
+                        meet - 0.5 * gap,
+                        e1 + 4.0 * second.slot_width,
+                        second.slot_width,
+                    ));
+                    slots[j].push(slot(
+                        second,
+                        origin,
+                        direction,
+                        f0 - 4.0 * first.slot_width,
+                        meet + 0.5 * gap,
+                        first.slot_width,
+                    ));
+                    interlocked = true;
+                }
+            }
+            if interlocked {
+                topology[i].push(j);
+                topology[j].push(i);
+            }
+        }
+    }
+
+    let mut diagnostics = RegionDiagnostics::default();
+    let regions = inputs
+        .iter()
+        .zip(&slots)
+        .map(|(input, slots)| {
+            if slots.is_empty() {
+                return vec![input.region.clone()];
+            }
+            let result = region_boolean(
+                std::slice::from_ref(&input.region),
+                slots,
+                RegionOp::Difference,
+                tol,
+            );
+            diagnostics.merge(result.diagnostics);
+            result.regions
+        })
+        .collect();
+
+    RegionSlitsResult {
+        regions,
+        topology,
+        diagnostics,
+    }
+}
+
+/// A box given by a corner and its three edge vectors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SlitBox {
+    pub corner: Point3,
+    pub edges: [Vec3; 3],
+}
+
+/// Result of [`box_slits`], indexed like the input.
+#[derive(Debug, Clone, Default)]
+pub struct BoxSlitsResult {
+    /// Slotted plates per input box, one mesh per remaining piece.
+    pub meshes: Vec<Vec<(GeomMesh, GeomMeshDiagnostics)>>,
+    pub topology: Vec<Vec<usize>>,
+    pub diagnostics: RegionDiagnostics,
+}
+
+/// Treats every box as a plate whose thickness is its shortest edge and cuts
+/// interlocking slots with [`region_slits`]. Each slot is as wide as the
+/// crossing plate plus `gap`. The slotted plates are extruded back to their
+/// original thickness.
+///
+/// Returns an error when a box is degenerate or a plate cannot be meshed.
+pub fn box_slits(boxes: &[SlitBox], gap: f64, tol: Tolerance) -> Result<BoxSlitsResult, String> {
+    let mut plates = Vec::with_capacity(boxes.len());
+    let mut thicknesses = Vec::with_capacity(boxes.len());
+    for (index, slit_box) in boxes.iter().enumerate() {
+        let lengths = slit_box.edges.map(Vec3::length);
+        let thin = (0..3)
+            .min_by(|&x, &y| lengths[x].total_cmp(&lengths[y]))
+            .unwrap_or(2);
+        let (u, v) = ((thin + 1) % 3, (thin + 2) % 3);
+        let centre = slit_box.edges.iter().fold(slit_box.corner, |point, edge| {
+            point.add_vec(edge.mul_scalar(0.5))
+        });
+        let plane = RegionPlane::new(centre, slit_box.edges[u], slit_box.edges[v])
+            .filter(|_| lengths[thin] > tol.eps)
+            .ok_or_else(|| format!("box {index} is degenerate"))?;
+
+        let (half_u, half_v) = (0.5 * lengths[u], 0.5 * lengths[v]);
+        let region = Region2::new(vec![
+            [-half_u, -half_v],
+            [half_u, -half_v],
+            [half_u, half_v],
+            [-half_u, half_v],
+        ]);
+        plates.push(SlitRegion {
+            plane,
+            region,
+            slot_width: lengths[thin] + gap,
+        });
+        thicknesses.push(lengths[thin]);
+    }
+
+    let slits = region_slits(&plates, 0.0, tol);
+    let meshes = plates
+        .iter()
+        .zip(&thicknesses)
+        .zip(&slits.regions)
+        .map(|((plate, &thickness), regions)| {
+            regions
+                .iter()
+                .map(|region| {
+                    extrude_region(&plate.plane, region, -0.5 * thickness, 0.5 * thickness, tol)
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(BoxSlitsResult {
+        meshes,
+        topology: slits.topology,
+        diagnostics: slits.diagnostics,
+    })
+}
+
+/// Extrudes a region along the plane normal between the offsets `bottom`
+/// and `top`, giving a closed prism with triangulated caps.
+pub fn extrude_region(
+    plane: &RegionPlane,
+    region: &Region2,
+    bottom: f64,
+    top: f64,
+    tol: Tolerance,
+) -> Result<(GeomMesh, GeomMeshDiagnostics), String> {
+    let trim_loop = |ring: &[Point2]| {
+        TrimLoop::new(
+            ring.iter()
+                .map(|point| UvPoint::new(point[0], point[1]))
+                .collect(),
+            tol,
+        )
+        .map_err(|error| error.to_string())
+    };
+    let cap = triangulate_trim_region(
+        &TrimRegion {
+            outer: trim_loop(&region.outer)?,
+            holes: region
+                .holes
+                .iter()
+                .map(|hole| trim_loop(hole))
+                .collect::<Result<_, _>>()?,
+        },
+        tol,
+    )?;
+
+    let normal = plane.normal();
+    let lift =
+        |point: Point2, height: f64| plane.to_world(point).add_vec(normal.mul_scalar(height));
+
+    let cap_len = cap.vertices.len() as u32;
+    let mut points: Vec<Point3> = cap
+        .vertices
+        .iter()
+        .map(|uv| lift([uv.u, uv.v], bottom))
+        .chain(cap.vertices.iter().map(|uv| lift([uv.u, uv.v], top)))
+        .collect();
+    let mut indices = Vec::with_capacity(cap.indices.len() * 2);
+    for triangle in cap.indices.chunks_exact(3) {
+        indices.extend_from_slice(&[triangle[0], triangle[2], triangle[1]]);
+        indices.extend_from_slice(&[
+            triangle[0] + cap_len,
+            triangle[1] + cap_len,
+            triangle[2] + cap_len,
+        ]);
+    }
+
+    for ring in region.loops() {
+        for index in 0..ring.len() {
+            let (start, end) = (ring[index], ring[(index + 1) % ring.len()]);
+            let base = points.len() as u32;
+            points.extend_from_slice(&[
+                lift(start, bottom),
+                lift(end, bottom),
+                lift(end, top),
+                lift(start, top),
+            ]);
+            indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+        }
+    }
+
+    Ok(finalize_mesh(points, None, indices, tol))
+}
+
+// ---------------------------------------------------------------------------
+// Overlay helpers
+// ---------------------------------------------------------------------------
+
+/// Cleans every loop of an operand, normalises orientation (outer CCW, holes
+/// CW) and drops regions whose outer loop is degenerate.
+fn clean_operand(
+    regions: &[Region2],
+    weld: f64,
+    diagnostics: &mut RegionDiagnostics,
+) -> Vec<Region2> {
+    let mut cleaned = Vec::with_capacity(regions.len());
+    for region in regions {
+        diagnostics.input_loop_count += 1 + region.holes.len();
+        let Some(mut outer) = clean_ring(&region.outer, weld) else {
+            diagnostics.degenerate_loop_count += 1 + region.holes.len();
+            continue;
+        };
+        if signed_area(&outer) < 0.0 {
+            outer.reverse();
+        }
+
+        let mut holes = Vec::with_capacity(region.holes.len());
+        for hole in &region.holes {
+            match clean_ring(hole, weld) {
+                Some(mut hole) => {
+                    if signed_area(&hole) > 0.0 {
+                        hole.reverse();
+                    }
+                    holes.push(hole);
+                }
+                None => diagnostics.degenerate_loop_count += 1,
+            }
+        }
+        cleaned.push(Region2 { outer, holes });
+    }
+    cleaned
+}
+
+/// Removes consecutive duplicates and the closing vertex. Returns `None` when
+/// fewer than three vertices remain or the loop encloses no area.
+fn clean_ring(ring: &[Point2], weld: f64) -> Option<Vec<Point2>> {
+    let mut cleaned: Vec<Point2> = Vec::with_capacity(ring.len());
+    for &point in ring {
+        if !point.iter().all(|c| c.is_finite()) {
+            return None;
+        }
+        if cleaned
+            .last()
+            .is_none_or(|last| distance2(*last, point) > weld)
+        {
+            cleaned.push(point);
+        }
+    }
+    while cleaned.len() > 1 && distance2(cleaned[0], cleaned[cleaned.len() - 1]) <= weld {
+        cleaned.pop();
+    }
+    if cleaned.len() < 3 {
+        return None;
+    }
+
+    let perimeter: f64 = (0..cleaned.len())
+        .map(|index| distance2(cleaned[index], cleaned[(index + 1) % cleaned.len()]))
+        .sum();
+    (signed_area(&cleaned).abs() > weld * perimeter).then_some(cleaned)
+}
+
+/// Sorted split parameters (including 0 and 1) for every segment. Candidate
+/// pairs come from a sweep over the x extents.
+fn split_parameters(
+    segments: &[[Point2; 2]],
+    weld: f64,
+    diagnostics: &mut RegionDiagnostics,
+) -> Vec<Vec<f64>> {
+    let mut splits = vec![vec![0.0, 1.0]; segments.len()];
+    let min_x = |segment: &[Point2; 2]| segment[0][0].min(segment[1][0]);
+    let mut order: Vec<usize> = (0..segments.len()).collect();
+    order.sort_by(|&x, &y| min_x(&segments[x]).total_cmp(&min_x(&segments[y])));
+
+    for (position, &i) in order.iter().enumerate() {
+        let p = segments[i];
+        let max_x = p[0][0].max(p[1][0]) + weld;
+        let (p_min_y, p_max_y) = (p[0][1].min(p[1][1]), p[0][1].max(p[1][1]));
+        for &j in &order[position + 1..] {
+            let q = segments[j];
+            if min_x(&q) > max_x {
+                break;
+            }
+            if q[0][1].max(q[1][1]) + weld < p_min_y || p_max_y + weld < q[0][1].min(q[1][1]) {
+                continue;
+            }
+
+            // Endpoints on the other segment: touches and collinear overlaps.
+            for end in q {
+                if let Some(t) = parameter_on_segment(p, end, weld) {
+                    splits[i].push(t);
+                }
+            }
+            for end in p {
+                if let Some(t) = parameter_on_segment(q, end, weld) {
+                    splits[j].push(t);
+                }
+            }
+
+            let (r, s) = (sub2(p[1], p[0]), sub2(q[1], q[0]));
+            let denom = cross2(r, s);
+            if denom.abs() <= f64::EPSILON * 16.0 * norm2(r) * norm2(s) {
+                continue;
+            }
+            let w = sub2(q[0], p[0]);
+            let t = cross2(w, s) / denom;
+            let u = cross2(w, r) / denom;
+            if t > 0.0 && t < 1.0 && u > 0.0 && u < 1.0 {
+                splits[i].push(t);
+                splits[j].push(u);
+                diagnostics.crossing_count += 1;
+            }
+        }
+    }
+
+    for params in &mut splits {
+        params.sort_by(f64::total_cmp);
+    }
+    splits
+}
+
+/// Parameter of `point` on the interior of `segment` when it lies within
+/// `weld` of it.
+fn parameter_on_segment(segment: [Point2; 2], point: Point2, weld: f64) -> Option<f64> {
+    let direction = sub2(segment[1], segment[0]);
+    let length_sq = dot2(direction, direction);
+    if length_sq <= 0.0 {
+        return None;
+    }
+    let t = dot2(sub2(point, segment[0]), direction) / length_sq;
+    (t > 0.0 && t < 1.0 && distance2(lerp2(segment[0], segment[1], t), point) <= weld).then_some(t)
+}
+
+/// Midpoint, unit left normal and probe offset for an edge.
+fn edge_probe_frame(p: Point2, q: Point2, weld: f64) -> Option<(Point2, Point2, f64)> {
+    let direction = sub2(q, p);
+    let length = norm2(direction);
+    if length <= 0.0 {
+        return None;
+    }
+    let normal = [-direction[1] / length, direction[0] / length];
+    let offset = (weld * 16.0).min(length * 0.25).max(weld * 2.0);
+    Some((lerp2(p, q, 0.5), normal, offset))
+}
+
+/// Chains directed edges into loops. At every vertex the outgoing edge with
+/// the sharpest left turn is taken, so each loop bounds a single face.
+fn trace_loops(
+    points: &[Point2],
+    directed: &[[usize; 2]],
+    diagnostics: &mut RegionDiagnostics,
+) -> Vec<Vec<Point2>> {
+    let mut outgoing: Vec<Vec<usize>> = vec![Vec::new(); points.len()];
+    for (index, edge) in directed.iter().enumerate() {
+        outgoing[edge[0]].push(index);
+    }
+    let direction = |edge: usize| sub2(points[directed[edge][1]], points[directed[edge][0]]);
+    let next_edge = |edge: usize| {
+        let back = scale2(direction(edge), -1.0);
+        outgoing[directed[edge][1]]
+            .iter()
+            .copied()
+            .min_by(|&x, &y| {
+                clockwise_angle(back, direction(x)).total_cmp(&clockwise_angle(back, direction(y)))
+            })
+    };
+
+    let mut used = vec![false; directed.len()];
+    let mut rings = Vec::new();
+    for start in 0..directed.len() {
+        if used[start] {
+            continue;
+        }
+        let mut ring = Vec::new();
+        let mut edge = start;
+        let closed = loop {
+            if used[edge] {
+                break edge == start;
+            }
+            used[edge] = true;
+            ring.push(points[directed[edge][0]]);
+            match next_edge(edge) {
+                Some(next) => edge = next,
+                None => break false,
+            }
+        };
+        if closed {
+            rings.push(ring);
+        } else {
+            diagnostics.open_chain_count += 1;
+        }
+    }
+    rings
+}
+
+/// Angle in `(0, 2π]` when turning clockwise from `from` to `to`.
+fn clockwise_angle(from: Point2, to: Point2) -> f64 {
+    let angle = -cross2(from, to).atan2(dot2(from, to));
+    if angle <= 0.0 { angle + TAU } else { angle }
+}
+
+/// Removes vertices that lie on the line through their neighbours. Returns
+/// `None` when the loop collapses.
+fn simplify_ring(mut ring: Vec<Point2>, weld: f64) -> Option<Vec<Point2>> {
+    let mut index = 0;
+    let mut unchanged = 0;
+    while ring.len() >= 3 && unchanged < ring.len() {
+        let len = ring.len();
+        let prev = ring[(index + len - 1) % len];
+        let current = ring[index % len];
+        let next = ring[(index + 1) % len];
+        let chord = sub2(next, prev);
+        let chord_len = norm2(chord);
+        let off_line = if chord_len > 0.0 {
+            cross2(chord, sub2(current, prev)).abs() / chord_len
+        } else {
+            0.0
+        };
+        if off_line <= weld {
+            ring.remove(index % len);
+            unchanged = 0;
+        } else {
+            index = (index + 1) % len;
+            unchanged += 1;
+        }
+    }
+    (ring.len() >= 3).then_some(ring)
+}
+
+/// Hash-grid vertex welder.
+struct Welder2 {
+    cell: f64,
+    points: Vec<Point2>,
+    grid: HashMap<(i64, i64), Vec<usize>>,
+}
+
+impl Welder2 {
+    fn new(weld: f64) -> Self {
+        Self {
+            cell: weld,
+            points: Vec::new(),
+            grid: HashMap::new(),
+        }
+    }
+
+    fn key(&self, point: Point2) -> (i64, i64) {
+        (
+            (point[0] / self.cell).floor() as i64,
+            (point[1] / self.cell).floor() as i64,
+        )
+    }
+
+    fn insert(&mut self, point: Point2) -> usize {
+        let (kx, ky) = self.key(point);
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                if let Some(ids) = self.grid.get(&(kx + dx, ky + dy)) {
+                    if let Some(&id) = ids
+                        .iter()
+                        .find(|&&id| distance2(self.points[id], point) <= self.cell)
+                    {
+                        return id;
+                    }
+                }
+            }
+        }
+        let id = self.points.len();
+        self.points.push(point);
+        self.grid.entry((kx, ky)).or_default().push(id);
+        id
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Slit helpers
+// ---------------------------------------------------------------------------
+
+/// Intersection line of two planes as an origin and unit direction, or
+/// `None` for (nearly) parallel planes.
+fn plane_intersection(a: &RegionPlane, b: &RegionPlane) -> Option<(Point3, Vec3)> {
+    let (na, nb) = (a.normal(), b.normal());
+    let direction = na.cross(nb);
+    let length_sq = direction.length_squared();
+    if length_sq <= 1e-12 {
+        return None;
+    }
+    let (ca, cb) = (na.dot(a.origin.to_vec3()), nb.dot(b.origin.to_vec3()));
+    let origin = nb
+        .cross(direction)
+        .mul_scalar(ca)
+        .add(direction.cross(na).mul_scalar(cb))
+        .div_scalar(length_sq);
+    Some((
+        Point3::from_array(origin.to_array()),
+        direction.normalized()?,
+    ))
+}
+
+/// Intervals along the world line `origin + t·direction` that lie inside the
+/// region.
+fn line_spans(input: &SlitRegion, origin: Point3, direction: Vec3, eps: f64) -> Vec<(f64, f64)> {
+    let base = input.plane.to_local(origin);
+    let tip = input.plane.to_local(origin.add_vec(direction));
+    let dir = sub2(tip, base);
+
+    let mut params = Vec::new();
+    for ring in input.region.loops() {
+        for index in 0..ring.len() {
+            let (start, end) = (ring[index], ring[(index + 1) % ring.len()]);
+            let edge = sub2(end, start);
+            let denom = cross2(dir, edge);
+            if denom.abs() <= f64::EPSILON * norm2(edge) {
+                continue;
+            }
+            let w = sub2(start, base);
+            let s = cross2(w, dir) / denom;
+            if (0.0..1.0).contains(&s) {
+                params.push(cross2(w, edge) / denom);
+            }
+        }
+    }
+    params.sort_by(f64::total_cmp);
+    params.dedup_by(|x, y| (*x - *y).abs() <= eps);
+
+    let mut spans: Vec<(f64, f64)> = Vec::new();
+    for pair in params.windows(2) {
+        let mid = 0.5 * (pair[0] + pair[1]);
+        if !input.region.contains(add2(base, scale2(dir, mid))) {
+            continue;
+        }
+        match spans.last_mut() {
+            Some(last) if (last.1 - pair[0]).abs() <= eps => last.1 = pair[1],
+            _ => spans.push((pair[0], pair[1])),
+        }
+    }
+    spans
+}
+
+/// Rectangular slot along the intersection line between `from` and `to`, in
+/// the coordinates of `input.plane`.
+fn slot(
+    input: &SlitRegion,
+    origin: Point3,
+    direction: Vec3,
+    from: f64,
+    to: f64,
+    width: f64,
+) -> Region2 {
+    let side = input
+        .plane
+        .normal()
+        .cross(direction)
+        .mul_scalar(0.5 * width);
+    let at = |t: f64, sign: f64| {
+        input.plane.to_local(
+            origin
+                .add_vec(direction.mul_scalar(t))
+                .add_vec(side.mul_scalar(sign)),
+        )
+    };
+    Region2::new(vec![
+        at(from, -1.0),
+        at(to, -1.0),
+        at(to, 1.0),
+        at(from, 1.0),
+    ])
+}
+
+// ---------------------------------------------------------------------------
+// 2D vector helpers
+// ---------------------------------------------------------------------------
+
+fn signed_area(ring: &[Point2]) -> f64 {
+    let mut area = 0.0;
+    for index in 0..ring.len() {
+        let (p, q) = (ring[index], ring[(index + 1) % ring.len()]);
+        area += p[0] * q[1] - q[0] * p[1];
+    }
+    0.5 * area
+}
+
+fn point_in_ring(point: Point2, ring: &[Point2]) -> bool {
+    let mut inside = false;
+    let mut previous = match ring.last() {
+        Some(&last) => last,
+        None => return false,
+    };
+    for &current in ring {
+        if (current[1] > point[1]) != (previous[1] > point[1]) {
+            let x = current[0]
+                + (point[1] - current[1]) * (previous[0] - current[0]) / (previous[1] - current[1]);
+            if point[0] < x {
+                inside = !inside;
+            }
+        }
+        previous = current;
+    }
+    inside
+}
+
+fn add2(a: Point2, b: Point2) -> Point2 {
+    [a[0] + b[0], a[1] + b[1]]
+}
+
+fn sub2(a: Point2, b: Point2) -> Point2 {
+    [a[0] - b[0], a[1] - b[1]]
+}
+
+fn scale2(a: Point2, s: f64) -> Point2 {
+    [a[0] * s, a[1] * s]
+}
+
+fn dot2(a: Point2, b: Point2) -> f64 {
+    a[0] * b[0] + a[1] * b[1]
+}
+
+fn cross2(a: Point2, b: Point2) -> f64 {
+    a[0] * b[1] - a[1] * b[0]
+}
+
+fn norm2(a: Point2) -> f64 {
+    dot2(a, a).sqrt()
+}
+
+fn distance2(a: Point2, b: Point2) -> f64 {
+    norm2(sub2(a, b))
+}
+
+fn lerp2(a: Point2, b: Point2, t: f64) -> Point2 {
+    [a[0] + (b[0] - a[0]) * t, a[1] + (b[1] - a[1]) * t]
+}
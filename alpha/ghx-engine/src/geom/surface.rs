@@ -1317,6 +1317,78 @@ pub fn tessellate_surface_grid(
     points
 }
 
+/// Finds the `(u, v)` parameters and point on a surface closest to `query`.
+///
+/// The domain is sampled on a regular grid first; the best sample is then
+/// refined with a shrinking compass search on the squared distance. Closed
+/// directions wrap around, open directions are clamped to the domain.
+///
+/// # Returns
+/// `(u, v, closest_point)`.
+#[must_use]
+pub fn surface_closest_point<S: Surface + ?Sized>(
+    surface: &S,
+    query: Point3,
+    samples: usize,
+) -> (f64, f64, Point3) {
+    let (u0, u1) = surface.domain_u();
+    let (v0, v1) = surface.domain_v();
+    let u_span = u1 - u0;
+    let v_span = v1 - v0;
+    if !u_span.is_finite() || !v_span.is_finite() {
+        return (u0, v0, surface.point_at(u0, v0));
+    }
+
+    let wrap_u = surface.is_u_closed();
+    let wrap_v = surface.is_v_closed();
+    let normalize = |u: f64, v: f64| {
+        let u = if wrap_u { wrap_param(u, u0, u1) } else { u.clamp(u0, u1) };
+        let v = if wrap_v { wrap_param(v, v0, v1) } else { v.clamp(v0, v1) };
+        (u, v)
+    };
+    let distance_sq = |u: f64, v: f64| surface.point_at(u, v).sub_point(query).length_squared();
+
+    let samples = samples.max(2);
+    let mut best = (u0, v0);
+    let mut best_d = distance_sq(u0, v0);
+    for j in 0..=samples {
+        let v = v0 + v_span * j as f64 / samples as f64;
+        for i in 0..=samples {
+            let u = u0 + u_span * i as f64 / samples as f64;
+            let d = distance_sq(u, v);
+            if d < best_d {
+                best_d = d;
+                best = (u, v);
+            }
+        }
+    }
+
+    let mut step_u = u_span / samples as f64;
+    let mut step_v = v_span / samples as f64;
+    let min_step = 1e-12 * u_span.abs().max(v_span.abs()).max(1.0);
+    for _ in 0..200 {
+        if step_u.abs() <= min_step && step_v.abs() <= min_step {
+            break;
+        }
+        let mut improved = false;
+        for (du, dv) in [(step_u, 0.0), (-step_u, 0.0), (0.0, step_v), (0.0, -step_v)] {
+            let candidate = normalize(best.0 + du, best.1 + dv);
+            let d = distance_sq(candidate.0, candidate.1);
+            if d < best_d {
+                best_d = d;
+                best = candidate;
+                improved = true;
+            }
+        }
+        if !improved {
+            step_u *= 0.5;
+            step_v *= 0.5;
+        }
+    }
+
+    (best.0, best.1, surface.point_at(best.0, best.1))
+}
+
 // ---------------------------------------------------------------------------
 // Surface Ops (DivideSurface / Isotrim / Flip)
 // ---------------------------------------------------------------------------
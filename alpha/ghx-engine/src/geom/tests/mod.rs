@@ -5,6 +5,8 @@ mod test_bvh_basic;
 mod test_boolean_basic;
 mod test_extrusion_basic;
 mod test_fillet_chamfer_basic;
+mod test_intersect_basic;
+mod test_intersect_physical_basic;
 mod test_patch_basic;
 mod test_mesh_sanity;
 mod test_pipe_basic;
@@ -19,3 +21,4 @@ mod test_sweep_basic;
 mod test_trim_basic;
 mod test_triangulation_basic;
 mod test_analysis_basic;
+mod test_region_basic;
@@ -1,6 +1,6 @@
 use crate::geom::{
     BooleanOp, GeomMesh, Point3, Tolerance, Triangle3, Vec3, boolean_meshes,
-    classify_point_in_mesh, extrude_polyline, triangle_triangle_intersection, ExtrusionCaps,
+    classify_point_in_mesh, extrude_polyline, split_mesh_by_mesh, triangle_triangle_intersection, ExtrusionCaps,
     PointContainment, TriTriIntersection,
 };
 
@@ -111,3 +111,33 @@ fn boolean_union_returns_non_empty_mesh() {
         assert!(result.mesh_diagnostics.boolean_fallback_used);
     }
 }
+
+#[test]
+fn split_mesh_by_mesh_separates_inside_and_outside() {
+    let square = [
+        Point3::new(-0.5, -0.5, 0.0),
+        Point3::new(0.5, -0.5, 0.0),
+        Point3::new(0.5, 0.5, 0.0),
+        Point3::new(-0.5, 0.5, 0.0),
+    ];
+    let (a, _) = extrude_polyline(&square, Vec3::new(0.0, 0.0, 1.0), ExtrusionCaps::BOTH)
+        .expect("extrude mesh A");
+    let (b_raw, _) =
+        extrude_polyline(&square, Vec3::new(0.0, 0.0, 1.0), ExtrusionCaps::BOTH)
+            .expect("extrude mesh B");
+    let b = transform_mesh_z(&b_raw, 0.2, Vec3::new(0.3, 0.1, 0.2));
+
+    let tol = Tolerance::default_geom();
+    let result = split_mesh_by_mesh(&a, &b, tol).expect("split");
+
+    assert_eq!(result.inside.len(), 1);
+    assert_eq!(result.outside.len(), 1);
+    assert!(result.diagnostics.intersection_segment_count > 0);
+
+    // Every vertex of an inside fragment lies inside the cutter or on its surface.
+    for fragment in &result.inside {
+        assert!(fragment.positions.iter().all(|p| {
+            classify_point_in_mesh(Point3::from(*p), &b, tol) != PointContainment::Outside
+        }));
+    }
+}
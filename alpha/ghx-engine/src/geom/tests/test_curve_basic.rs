@@ -1,6 +1,6 @@
 use crate::geom::{
     Arc3, Circle3, Curve3, CurveTessellationOptions, Ellipse3, Line3, NurbsCurve3,
-    Point3, Polyline3, QuadraticBezier3, Tolerance, Vec3, curve_arc_length,
+    Point3, Polyline3, QuadraticBezier3, Tolerance, Vec3, curve_arc_length, curve_closest_point,
     divide_curve_by_count, tessellate_curve_adaptive_points, tessellate_curve_uniform,
 };
 
@@ -674,3 +674,17 @@ fn adaptive_tessellation_produces_uniform_arc_length_spacing() {
         max_len, mean
     );
 }
+
+#[test]
+fn curve_closest_point_projects_onto_circle() {
+    let circle = Circle3::from_center_xaxis_normal(
+        Point3::new(0.0, 0.0, 0.0),
+        Vec3::new(1.0, 0.0, 0.0),
+        Vec3::new(0.0, 0.0, 1.0),
+        2.0,
+    );
+
+    let (t, point) = curve_closest_point(&circle, Point3::new(0.0, 5.0, 0.0), 32);
+    assert!((t - 0.25).abs() < 1e-6, "t = {t}");
+    assert!(point.sub_point(Point3::new(0.0, 2.0, 0.0)).length() < 1e-6);
+}
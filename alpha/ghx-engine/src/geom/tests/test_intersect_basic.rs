@@ -0,0 +1,88 @@
+use crate::geom::{
+    Circle3, ExtrusionCaps, GeomMesh, Point3, Tolerance, Vec3, curve_plane_intersections_refined,
+    extrude_polyline, mesh_plane_section, mesh_ray_hits,
+};
+
+fn unit_cube() -> GeomMesh {
+    let square = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    ];
+    let (cube, _) = extrude_polyline(&square, Vec3::new(0.0, 0.0, 1.0), ExtrusionCaps::BOTH)
+        .expect("extrude cube");
+    cube
+}
+
+#[test]
+fn mesh_plane_section_of_cube_is_one_closed_square() {
+    let cube = unit_cube();
+    let sections = mesh_plane_section(
+        &cube,
+        Point3::new(0.0, 0.0, 0.5),
+        Vec3::new(0.0, 0.0, 1.0),
+        Tolerance::default_geom(),
+    );
+
+    assert_eq!(sections.len(), 1);
+    let section = &sections[0];
+    assert!(section.closed);
+    assert!(section.points.len() >= 4);
+    for point in &section.points {
+        assert!((point.z - 0.5).abs() < 1e-9);
+        let on_boundary = point.x.abs() < 1e-9
+            || (point.x - 1.0).abs() < 1e-9
+            || point.y.abs() < 1e-9
+            || (point.y - 1.0).abs() < 1e-9;
+        assert!(on_boundary, "section point {point:?} lies inside the cube");
+    }
+}
+
+#[test]
+fn mesh_plane_section_misses_plane_outside_mesh() {
+    let cube = unit_cube();
+    let sections = mesh_plane_section(
+        &cube,
+        Point3::new(0.0, 0.0, 2.0),
+        Vec3::new(0.0, 0.0, 1.0),
+        Tolerance::default_geom(),
+    );
+    assert!(sections.is_empty());
+}
+
+#[test]
+fn mesh_ray_hits_are_sorted_and_deduplicated() {
+    let cube = unit_cube();
+    let hits = mesh_ray_hits(
+        &cube,
+        Point3::new(0.5, 0.5, -1.0),
+        Vec3::new(0.0, 0.0, 1.0),
+        0.0,
+        f64::INFINITY,
+        Tolerance::default_geom(),
+    );
+
+    assert_eq!(hits.len(), 2);
+    assert!((hits[0].t - 1.0).abs() < 1e-9);
+    assert!((hits[1].t - 2.0).abs() < 1e-9);
+    assert!((hits[0].point.z).abs() < 1e-9);
+    assert!(hits[0].normal.z.abs() > 0.99);
+}
+
+#[test]
+fn curve_plane_intersections_refined_hits_circle_exactly() {
+    let circle = Circle3::new(Point3::new(0.0, 0.0, 0.0), Vec3::new(0.0, 0.0, 1.0), 2.0);
+    let hits = curve_plane_intersections_refined(
+        &circle,
+        Point3::new(0.5, 0.0, 0.0),
+        Vec3::new(1.0, 0.0, 0.0),
+        16,
+    );
+
+    assert_eq!(hits.len(), 2);
+    for (point, _) in hits {
+        assert!((point.x - 0.5).abs() < 1e-9);
+        assert!((point.x * point.x + point.y * point.y - 4.0).abs() < 1e-9);
+    }
+}
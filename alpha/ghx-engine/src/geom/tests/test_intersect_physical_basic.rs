@@ -0,0 +1,171 @@
+use crate::geom::{
+    Circle3, ExtrusionCaps, GeomMesh, ParamPolyline, Point3, Polyline3, Tolerance, Vec3,
+    curve_curve_intersections, curve_self_intersections, extrude_polyline, mesh_clash,
+    mesh_mesh_intersection, mesh_polyline_hits, meshes_collide, split_mesh_with_polylines,
+};
+
+fn cube(origin: Point3, size: f64) -> GeomMesh {
+    let square = vec![
+        origin,
+        origin.add_vec(Vec3::new(size, 0.0, 0.0)),
+        origin.add_vec(Vec3::new(size, size, 0.0)),
+        origin.add_vec(Vec3::new(0.0, size, 0.0)),
+    ];
+    let (cube, _) = extrude_polyline(&square, Vec3::new(0.0, 0.0, size), ExtrusionCaps::BOTH)
+        .expect("extrude cube");
+    cube
+}
+
+fn unit_grid(divisions: usize) -> GeomMesh {
+    let mut positions = Vec::new();
+    for j in 0..=divisions {
+        for i in 0..=divisions {
+            positions.push([
+                i as f64 / divisions as f64,
+                j as f64 / divisions as f64,
+                0.0,
+            ]);
+        }
+    }
+    let row = divisions as u32 + 1;
+    let mut indices = Vec::new();
+    for j in 0..divisions as u32 {
+        for i in 0..divisions as u32 {
+            let a = j * row + i;
+            indices.extend([a, a + 1, a + row + 1, a, a + row + 1, a + row]);
+        }
+    }
+    GeomMesh::new(positions, indices)
+}
+
+fn exact(points: &[Point3]) -> ParamPolyline {
+    let params = (0..points.len())
+        .map(|index| index as f64 / (points.len() - 1) as f64)
+        .collect();
+    ParamPolyline::new(points.to_vec(), params)
+}
+
+#[test]
+fn curve_curve_intersections_refine_circle_crossings() {
+    let a = Circle3::new(Point3::new(0.0, 0.0, 0.0), Vec3::new(0.0, 0.0, 1.0), 1.0);
+    let b = Circle3::new(Point3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 0.0, 1.0), 1.0);
+    let hits = curve_curve_intersections(
+        &a,
+        &ParamPolyline::sample(&a, 64),
+        &b,
+        &ParamPolyline::sample(&b, 64),
+        Tolerance::LOOSE,
+    );
+
+    assert_eq!(hits.len(), 2);
+    for hit in hits {
+        assert!((hit.point.x - 0.5).abs() < 1e-9);
+        assert!((hit.point.y.abs() - 3f64.sqrt() / 2.0).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn curve_self_intersections_find_polyline_crossing() {
+    let points = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(2.0, 2.0, 0.0),
+        Point3::new(2.0, 0.0, 0.0),
+        Point3::new(0.0, 2.0, 0.0),
+    ];
+    let polyline = exact(&points);
+    let curve = Polyline3::new(points.to_vec(), false).expect("polyline");
+    let hits = curve_self_intersections(&curve, &polyline, Tolerance::LOOSE);
+
+    assert_eq!(hits.len(), 1);
+    assert!(hits[0].point.distance_to(Point3::new(1.0, 1.0, 0.0)) < 1e-9);
+    assert!(hits[0].t_a < hits[0].t_b);
+}
+
+#[test]
+fn mesh_polyline_hits_report_curve_parameters() {
+    let cube = cube(Point3::new(0.0, 0.0, 0.0), 1.0);
+    let polyline = exact(&[
+        Point3::new(0.5, 0.5, -1.0),
+        Point3::new(0.5, 0.5, 0.5),
+        Point3::new(0.5, 0.5, 2.0),
+    ]);
+    let hits = mesh_polyline_hits(&cube, &polyline, Tolerance::default_geom());
+
+    assert_eq!(hits.len(), 2);
+    assert!((hits[0].t - 1.0 / 3.0).abs() < 1e-9);
+    assert!((hits[1].t - 2.0 / 3.0).abs() < 1e-9);
+}
+
+#[test]
+fn mesh_mesh_intersection_of_offset_cubes_is_one_loop() {
+    let a = cube(Point3::new(0.0, 0.0, 0.0), 1.0);
+    let b = cube(Point3::new(0.5, 0.5, 0.5), 1.0);
+    let intersection = mesh_mesh_intersection(&a, &b, Tolerance::default_geom());
+
+    assert_eq!(intersection.polylines.len(), 1);
+    assert!(intersection.polylines[0].closed);
+    assert!(intersection.points.is_empty());
+}
+
+#[test]
+fn mesh_clash_measures_gap_within_distance() {
+    let a = cube(Point3::new(0.0, 0.0, 0.0), 1.0);
+    let b = cube(Point3::new(1.5, 0.0, 0.0), 1.0);
+
+    let clash = mesh_clash(&a, &b, 1.0, Tolerance::default_geom()).expect("clash within 1.0");
+    assert!((clash.distance - 0.5).abs() < 1e-9);
+    assert!((clash.center.x - 1.25).abs() < 1e-9);
+    assert!((clash.radius - 0.25).abs() < 1e-9);
+
+    assert!(mesh_clash(&a, &b, 0.25, Tolerance::default_geom()).is_none());
+}
+
+#[test]
+fn meshes_collide_detects_containment() {
+    let outer = cube(Point3::new(0.0, 0.0, 0.0), 3.0);
+    let inner = cube(Point3::new(1.0, 1.0, 1.0), 1.0);
+    let far = cube(Point3::new(5.0, 0.0, 0.0), 1.0);
+
+    assert!(meshes_collide(&outer, &inner, Tolerance::default_geom()));
+    assert!(meshes_collide(&inner, &outer, Tolerance::default_geom()));
+    assert!(!meshes_collide(&outer, &far, Tolerance::default_geom()));
+}
+
+#[test]
+fn split_mesh_with_polylines_cuts_grid_in_two() {
+    let grid = unit_grid(4);
+    for x in [0.37, 0.5] {
+        let cutter = vec![Point3::new(x, -0.1, 0.0), Point3::new(x, 1.1, 0.0)];
+        let fragments = split_mesh_with_polylines(&grid, &[cutter], Tolerance::default_geom());
+
+        assert_eq!(fragments.len(), 2, "cut at x = {x}");
+        for fragment in &fragments {
+            let left = fragment.positions.iter().all(|p| p[0] <= x + 1e-9);
+            let right = fragment.positions.iter().all(|p| p[0] >= x - 1e-9);
+            assert!(left || right, "fragment straddles the cut at x = {x}");
+        }
+    }
+
+    let partial = vec![Point3::new(0.37, -0.1, 0.0), Point3::new(0.37, 0.6, 0.0)];
+    let fragments = split_mesh_with_polylines(&grid, &[partial], Tolerance::default_geom());
+    assert_eq!(
+        fragments.len(),
+        1,
+        "a cut that stops inside the mesh keeps it whole"
+    );
+}
+
+#[test]
+fn split_mesh_with_closed_polyline_separates_inside() {
+    let grid = unit_grid(8);
+    let mut loop_points: Vec<Point3> = (0..24)
+        .map(|index| {
+            let angle = index as f64 / 24.0 * std::f64::consts::TAU;
+            Point3::new(0.5 + 0.3 * angle.cos(), 0.5 + 0.3 * angle.sin(), 0.0)
+        })
+        .collect();
+    loop_points.push(loop_points[0]);
+
+    let fragments = split_mesh_with_polylines(&grid, &[loop_points], Tolerance::default_geom());
+    assert_eq!(fragments.len(), 2);
+}
@@ -0,0 +1,234 @@
+use crate::geom::{
+    Point3, Region2, RegionOp, RegionPlane, SlitBox, SlitRegion, Tolerance, Vec3, box_slits,
+    extrude_region, region_boolean, region_slits,
+};
+
+fn square(x0: f64, y0: f64, size: f64) -> Region2 {
+    Region2::new(vec![
+        [x0, y0],
+        [x0 + size, y0],
+        [x0 + size, y0 + size],
+        [x0, y0 + size],
+    ])
+}
+
+fn total_area(regions: &[Region2]) -> f64 {
+    regions.iter().map(Region2::area).sum()
+}
+
+#[test]
+fn union_of_overlapping_squares_is_one_loop() {
+    let result = region_boolean(
+        &[square(0.0, 0.0, 2.0)],
+        &[square(1.0, 1.0, 2.0)],
+        RegionOp::Union,
+        Tolerance::LOOSE,
+    );
+
+    assert_eq!(result.regions.len(), 1);
+    assert!(result.regions[0].holes.is_empty());
+    assert_eq!(result.regions[0].outer.len(), 8);
+    assert!((total_area(&result.regions) - 7.0).abs() < 1e-9);
+    assert_eq!(result.diagnostics.crossing_count, 2);
+    assert!(result.diagnostics.warnings.is_empty());
+}
+
+#[test]
+fn intersection_and_difference_of_overlapping_squares() {
+    let a = [square(0.0, 0.0, 2.0)];
+    let b = [square(1.0, 1.0, 2.0)];
+
+    let intersection = region_boolean(&a, &b, RegionOp::Intersection, Tolerance::LOOSE);
+    assert_eq!(intersection.regions.len(), 1);
+    assert!((total_area(&intersection.regions) - 1.0).abs() < 1e-9);
+
+    let difference = region_boolean(&a, &b, RegionOp::Difference, Tolerance::LOOSE);
+    assert_eq!(difference.regions.len(), 1);
+    assert_eq!(difference.regions[0].outer.len(), 6);
+    assert!((total_area(&difference.regions) - 3.0).abs() < 1e-9);
+}
+
+#[test]
+fn difference_with_inner_region_produces_hole() {
+    let result = region_boolean(
+        &[square(0.0, 0.0, 4.0)],
+        &[square(1.0, 1.0, 1.0)],
+        RegionOp::Difference,
+        Tolerance::LOOSE,
+    );
+
+    assert_eq!(result.regions.len(), 1);
+    let region = &result.regions[0];
+    assert_eq!(region.holes.len(), 1);
+    assert!((region.area() - 15.0).abs() < 1e-9);
+    assert!(!region.contains([1.5, 1.5]));
+    assert!(region.contains([3.0, 3.0]));
+}
+
+#[test]
+fn union_merges_squares_sharing_an_edge() {
+    let result = region_boolean(
+        &[square(0.0, 0.0, 1.0)],
+        &[square(1.0, 0.0, 1.0), square(0.5, 1.0, 1.0)],
+        RegionOp::Union,
+        Tolerance::LOOSE,
+    );
+
+    assert_eq!(result.regions.len(), 1);
+    assert!((total_area(&result.regions) - 3.0).abs() < 1e-9);
+    assert!(result.diagnostics.coincident_edge_count >= 1);
+}
+
+#[test]
+fn union_keeps_squares_touching_at_a_corner_apart() {
+    let result = region_boolean(
+        &[square(0.0, 0.0, 1.0)],
+        &[square(1.0, 1.0, 1.0)],
+        RegionOp::Union,
+        Tolerance::LOOSE,
+    );
+
+    assert_eq!(result.regions.len(), 2);
+    assert!(result.regions.iter().all(|region| region.outer.len() == 4));
+}
+
+#[test]
+fn difference_keeps_holes_of_the_first_operand() {
+    let mut framed = square(0.0, 0.0, 4.0);
+    framed
+        .holes
+        .push(vec![[1.0, 1.0], [1.0, 3.0], [3.0, 3.0], [3.0, 1.0]]);
+
+    let result = region_boolean(
+        &[framed],
+        &[square(-1.0, -1.0, 2.5)],
+        RegionOp::Difference,
+        Tolerance::LOOSE,
+    );
+
+    assert_eq!(result.regions.len(), 1);
+    assert!(result.regions[0].holes.is_empty());
+    assert!((total_area(&result.regions) - (12.0 - 2.25 + 0.25)).abs() < 1e-9);
+}
+
+#[test]
+fn region_plane_round_trips_points() {
+    let points = [
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 2.0, 0.0),
+        Point3::new(1.0, 2.0, 3.0),
+        Point3::new(1.0, 0.0, 3.0),
+    ];
+    let plane = RegionPlane::fit(&points).unwrap();
+    assert!(plane.normal().x.abs() > 1.0 - 1e-12);
+
+    for point in points {
+        let back = plane.to_world(plane.to_local(point));
+        assert!(back.sub_point(point).length() < 1e-12);
+    }
+    assert!(
+        RegionPlane::fit(&[
+            Point3::ORIGIN,
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(2.0, 0.0, 0.0)
+        ])
+        .is_none()
+    );
+}
+
+#[test]
+fn crossing_regions_get_interlocking_slits() {
+    let xz = RegionPlane::new(Point3::ORIGIN, Vec3::X, Vec3::Z).unwrap();
+    let yz = RegionPlane::new(Point3::ORIGIN, Vec3::Y, Vec3::Z).unwrap();
+    let panel = Region2::new(vec![[-2.0, 0.0], [2.0, 0.0], [2.0, 2.0], [-2.0, 2.0]]);
+    let inputs = [
+        SlitRegion {
+            plane: xz,
+            region: panel.clone(),
+            slot_width: 0.2,
+        },
+        SlitRegion {
+            plane: yz,
+            region: panel.clone(),
+            slot_width: 0.2,
+        },
+    ];
+
+    let result = region_slits(&inputs, 0.0, Tolerance::LOOSE);
+    assert_eq!(result.topology, vec![vec![1], vec![0]]);
+    for regions in &result.regions {
+        assert_eq!(regions.len(), 1);
+        assert!((total_area(regions) - (8.0 - 0.2)).abs() < 1e-9);
+    }
+
+    // One panel is slotted from the top, the other from the bottom.
+    let first = &result.regions[0][0];
+    let second = &result.regions[1][0];
+    assert_ne!(first.contains([0.0, 1.5]), second.contains([0.0, 1.5]));
+    assert_ne!(first.contains([0.0, 0.5]), second.contains([0.0, 0.5]));
+}
+
+#[test]
+fn parallel_regions_are_not_slotted() {
+    let panel = Region2::new(vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]);
+    let raised = RegionPlane::new(Point3::new(0.0, 0.0, 1.0), Vec3::X, Vec3::Y).unwrap();
+    let inputs = [
+        SlitRegion {
+            plane: RegionPlane::WORLD_XY,
+            region: panel.clone(),
+            slot_width: 0.1,
+        },
+        SlitRegion {
+            plane: raised,
+            region: panel.clone(),
+            slot_width: 0.1,
+        },
+    ];
+
+    let result = region_slits(&inputs, 0.0, Tolerance::LOOSE);
+    assert_eq!(result.topology, vec![Vec::<usize>::new(), Vec::new()]);
+    assert_eq!(result.regions[0], vec![panel]);
+}
+
+#[test]
+fn extruded_region_with_hole_is_closed() {
+    let mut framed = square(0.0, 0.0, 4.0);
+    framed
+        .holes
+        .push(vec![[1.0, 1.0], [1.0, 3.0], [3.0, 3.0], [3.0, 1.0]]);
+
+    let (mesh, diagnostics) =
+        extrude_region(&RegionPlane::WORLD_XY, &framed, 0.0, 1.0, Tolerance::LOOSE).unwrap();
+    assert_eq!(mesh.positions.len(), 16);
+    assert_eq!(diagnostics.open_edge_count, 0);
+    assert_eq!(diagnostics.non_manifold_edge_count, 0);
+}
+
+#[test]
+fn crossing_boxes_become_slotted_plates() {
+    let boxes = [
+        SlitBox {
+            corner: Point3::new(-2.0, -0.1, 0.0),
+            edges: [
+                Vec3::new(4.0, 0.0, 0.0),
+                Vec3::new(0.0, 0.2, 0.0),
+                Vec3::new(0.0, 0.0, 2.0),
+            ],
+        },
+        SlitBox {
+            corner: Point3::new(-0.1, -2.0, 0.0),
+            edges: [
+                Vec3::new(0.2, 0.0, 0.0),
+                Vec3::new(0.0, 4.0, 0.0),
+                Vec3::new(0.0, 0.0, 2.0),
+            ],
+        },
+    ];
+
+    let result = box_slits(&boxes, 0.0, Tolerance::LOOSE).unwrap();
+    assert_eq!(result.topology, vec![vec![1], vec![0]]);
+    for plates in &result.meshes {
+        assert_eq!(plates.len(), 1);
+        assert_eq!(plates[0].1.open_edge_count, 0);
+    }
+}
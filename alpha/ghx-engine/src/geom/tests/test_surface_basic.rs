@@ -2,7 +2,7 @@ use crate::geom::{
     ClosedSurfaceSampling, DivideSurfaceOptions, SurfaceFlipGuide, divide_surface,
     ConeSurface, CylinderSurface, GeomContext, NurbsSurface, PlaneSurface, Point3, SphereSurface,
     Surface, TorusSurface, Tolerance, Vec3, choose_surface_grid_counts, flip_surface_orientation,
-    isotrim_surface, mesh_surface_with_context, tessellate_surface_grid,
+    isotrim_surface, mesh_surface_with_context, surface_closest_point, tessellate_surface_grid,
 };
 
 #[test]
@@ -405,3 +405,17 @@ fn mesh_from_grid_with_options_validates_grid_size() {
     let result = mesh_from_grid_with_options(&points, 1, 2, options);
     assert!(result.is_err(), "should fail with invalid grid dimensions");
 }
+
+#[test]
+fn surface_closest_point_projects_onto_sphere() {
+    let sphere = SphereSurface::new(Point3::new(1.0, 2.0, 3.0), 2.0).unwrap();
+    let query = Point3::new(1.0, 7.0, 3.0);
+
+    let (u, v, point) = surface_closest_point(&sphere, query, 16);
+
+    assert!((point.x - 1.0).abs() < 1e-6);
+    assert!((point.y - 4.0).abs() < 1e-6);
+    assert!((point.z - 3.0).abs() < 1e-6);
+    assert!((u - 0.25).abs() < 1e-6);
+    assert!((v - 0.5).abs() < 1e-6);
+}
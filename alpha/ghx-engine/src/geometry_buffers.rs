@@ -0,0 +1,524 @@
+//! Geometrie-export als getypeerde buffers.
+//!
+//! Alternatief voor de JSON-export van `Engine::get_geometry`: alle geometrie
+//! van de gewijzigde nodes wordt in vier platte buffers verpakt (`positions`,
+//! `normals` en `uvs` als `f32`, `indices` als `u32`) met een recordtabel die
+//! per item de offsets in die buffers beschrijft. JavaScript kan de buffers
+//! zonder kopie bekijken via `new Float32Array(memory.buffer, ptr, len)`,
+//! zolang het [`GeometryBuffers`]-object leeft en het wasm-geheugen niet
+//! groeit.
+//!
+//! Recordtabel (`u32`, [`RECORD_STRIDE`] waarden per item):
+//!
+//! | index | betekenis |
+//! |-------|-----------|
+//! | 0 | node-id |
+//! | 1 | soort: 0 = punt, 1 = lijn, 2 = polyline, 3 = mesh |
+//! | 2, 3 | start en lengte in `positions` (floats, 3 per vertex) |
+//! | 4, 5 | start en lengte in `indices` (alleen meshes, lokaal per item) |
+//! | 6, 7 | start en lengte in `normals` (0 als afwezig) |
+//! | 8, 9 | start en lengte in `uvs` (0 als afwezig) |
+//! | 10 | index in de materiaaltabel of `u32::MAX` |
+//!
+//! Materialen staan in `materials` als [`MATERIAL_STRIDE`] floats: diffuse,
+//! specular en emission (elk RGB), transparantie en glans.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use wasm_bindgen::prelude::*;
+
+use crate::graph::evaluator::GeometryEntry;
+use crate::graph::node::NodeId;
+use crate::graph::value::{BrepValue, CurveValue, MaterialValue, Value, triangulate_polygon_faces};
+use crate::list_as_polyline;
+
+/// Aantal `u32`-waarden per record in de recordtabel.
+pub const RECORD_STRIDE: usize = 11;
+/// Aantal `f32`-waarden per materiaal in de materiaaltabel.
+pub const MATERIAL_STRIDE: usize = 11;
+
+const NO_MATERIAL: u32 = u32::MAX;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+enum ItemKind {
+    Point = 0,
+    Line = 1,
+    Polyline = 2,
+    Mesh = 3,
+}
+
+/// Een geometrie-diff in getypeerde buffers; de tegenhanger van de JSON-diff
+/// van `Engine::get_geometry` met dezelfde added/updated/removed-semantiek.
+#[wasm_bindgen]
+#[derive(Debug, Default)]
+pub struct GeometryBuffers {
+    added: Vec<u32>,
+    updated: Vec<u32>,
+    removed: Vec<u32>,
+    records: Vec<u32>,
+    positions: Vec<f32>,
+    normals: Vec<f32>,
+    uvs: Vec<f32>,
+    indices: Vec<u32>,
+    materials: Vec<f32>,
+}
+
+#[wasm_bindgen]
+impl GeometryBuffers {
+    /// Ids van nodes die voor het eerst geometrie hebben.
+    #[wasm_bindgen(getter)]
+    pub fn added(&self) -> Vec<u32> {
+        self.added.clone()
+    }
+
+    /// Ids van nodes waarvan de geometrie is veranderd.
+    #[wasm_bindgen(getter)]
+    pub fn updated(&self) -> Vec<u32> {
+        self.updated.clone()
+    }
+
+    /// Ids van nodes die geen geometrie meer hebben.
+    #[wasm_bindgen(getter)]
+    pub fn removed(&self) -> Vec<u32> {
+        self.removed.clone()
+    }
+
+    /// De recordtabel (kopie); zie de moduledocumentatie voor de layout.
+    #[wasm_bindgen(getter)]
+    pub fn records(&self) -> Vec<u32> {
+        self.records.clone()
+    }
+
+    /// De materiaaltabel (kopie).
+    #[wasm_bindgen(getter)]
+    pub fn materials(&self) -> Vec<f32> {
+        self.materials.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn record_stride(&self) -> usize {
+        RECORD_STRIDE
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn positions_ptr(&self) -> *const f32 {
+        self.positions.as_ptr()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn positions_len(&self) -> usize {
+        self.positions.len()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn normals_ptr(&self) -> *const f32 {
+        self.normals.as_ptr()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn normals_len(&self) -> usize {
+        self.normals.len()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn uvs_ptr(&self) -> *const f32 {
+        self.uvs.as_ptr()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn uvs_len(&self) -> usize {
+        self.uvs.len()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn indices_ptr(&self) -> *const u32 {
+        self.indices.as_ptr()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn indices_len(&self) -> usize {
+        self.indices.len()
+    }
+}
+
+impl GeometryBuffers {
+    pub(crate) fn removed_only(removed: Vec<u32>) -> Self {
+        Self {
+            removed,
+            ..Self::default()
+        }
+    }
+
+    pub fn positions(&self) -> &[f32] {
+        &self.positions
+    }
+
+    pub fn normals(&self) -> &[f32] {
+        &self.normals
+    }
+
+    pub fn uvs(&self) -> &[f32] {
+        &self.uvs
+    }
+
+    pub fn indices(&self) -> &[u32] {
+        &self.indices
+    }
+
+    pub fn record_table(&self) -> &[u32] {
+        &self.records
+    }
+
+    pub fn added_ids(&self) -> &[u32] {
+        &self.added
+    }
+
+    pub fn updated_ids(&self) -> &[u32] {
+        &self.updated
+    }
+
+    pub fn removed_ids(&self) -> &[u32] {
+        &self.removed
+    }
+
+    /// Voegt de geometrie van een gewijzigde node toe en werkt de diff bij.
+    /// `previous` is de vingerafdruk uit de vorige export; de nieuwe wordt
+    /// teruggegeven (`None` als de node geen geometrie meer heeft). Bij een
+    /// ongewijzigde vingerafdruk worden de buffers van de node weer verwijderd.
+    pub(crate) fn diff_node(
+        &mut self,
+        node: NodeId,
+        entries: &[&GeometryEntry],
+        previous: Option<u64>,
+    ) -> Option<u64> {
+        let start = NodeStart::of(self);
+        for entry in entries {
+            self.push_value(node, &entry.value, entry.material);
+        }
+
+        if self.records.len() == start.records {
+            if previous.is_some() {
+                self.removed.push(node_index(node));
+            }
+            return None;
+        }
+
+        let fingerprint = self.fingerprint_since(&start);
+        match previous {
+            Some(previous) if previous == fingerprint => self.rollback(&start),
+            Some(_) => self.updated.push(node_index(node)),
+            None => self.added.push(node_index(node)),
+        }
+        Some(fingerprint)
+    }
+
+    fn rollback(&mut self, start: &NodeStart) {
+        self.records.truncate(start.records);
+        self.positions.truncate(start.positions);
+        self.normals.truncate(start.normals);
+        self.uvs.truncate(start.uvs);
+        self.indices.truncate(start.indices);
+        self.materials.truncate(start.materials);
+    }
+
+    fn push_value(&mut self, node: NodeId, value: &Value, material: Option<MaterialValue>) {
+        match value {
+            Value::Point(point) => {
+                self.push_curve(node, ItemKind::Point, &[*point]);
+            }
+            Value::CurveLine { p1, p2 } => {
+                self.push_curve(node, ItemKind::Line, &[*p1, *p2]);
+            }
+            Value::Curve(CurveValue::Line(line)) => {
+                let start = [line.start.x, line.start.y, line.start.z];
+                let end = [line.end.x, line.end.y, line.end.z];
+                self.push_curve(node, ItemKind::Line, &[start, end]);
+            }
+            Value::Curve(curve) => {
+                let points = curve.tessellate(CurveValue::DEFAULT_TESSELLATION);
+                self.push_curve(node, ItemKind::Polyline, &points);
+            }
+            Value::Surface { vertices, faces } => {
+                let indices = triangulate_polygon_faces(faces);
+                let parts = MeshParts {
+                    indices: &indices,
+                    ..MeshParts::default()
+                };
+                self.push_record(node, ItemKind::Mesh, vertices, parts, material);
+            }
+            Value::Brep(brep) => {
+                let mesh = brep.to_mesh(BrepValue::DEFAULT_TESSELLATION);
+                let parts = MeshParts {
+                    indices: &mesh.indices,
+                    normals: mesh.normals.as_deref(),
+                    uvs: mesh.uvs.as_deref(),
+                };
+                self.push_record(node, ItemKind::Mesh, &mesh.positions, parts, material);
+            }
+            Value::Mesh {
+                vertices,
+                indices,
+                normals,
+                uvs,
+                ..
+            } => {
+                let whole = indices.len() - indices.len() % 3;
+                let parts = MeshParts {
+                    indices: &indices[..whole],
+                    normals: normals.as_deref(),
+                    uvs: uvs.as_deref(),
+                };
+                self.push_record(node, ItemKind::Mesh, vertices, parts, material);
+            }
+            Value::List(values) => self.push_list(node, values, material),
+            Value::Tree(tree) => {
+                for (_, branch) in tree.branches() {
+                    self.push_list(node, branch, material);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn push_list(&mut self, node: NodeId, values: &[Value], material: Option<MaterialValue>) {
+        if let Some(points) = list_as_polyline(values) {
+            self.push_curve(node, ItemKind::Polyline, &points);
+        } else {
+            for value in values {
+                self.push_value(node, value, material);
+            }
+        }
+    }
+
+    fn push_curve(&mut self, node: NodeId, kind: ItemKind, vertices: &[[f64; 3]]) {
+        self.push_record(node, kind, vertices, MeshParts::default(), None);
+    }
+
+    fn push_record(
+        &mut self,
+        node: NodeId,
+        kind: ItemKind,
+        vertices: &[[f64; 3]],
+        parts: MeshParts<'_>,
+        material: Option<MaterialValue>,
+    ) {
+        if vertices.is_empty() {
+            return;
+        }
+        let indices = parts.indices;
+        // Attributen gelden alleen als ze bij elke vertex horen.
+        let normals = parts
+            .normals
+            .filter(|normals| normals.len() == vertices.len());
+        let uvs = parts.uvs.filter(|uvs| uvs.len() == vertices.len());
+
+        let position_start = self.positions.len();
+        self.positions
+            .extend(vertices.iter().flatten().map(|&value| value as f32));
+        let index_start = self.indices.len();
+        self.indices.extend_from_slice(indices);
+
+        let normal_start = self.normals.len();
+        if let Some(normals) = normals {
+            self.normals
+                .extend(normals.iter().flatten().map(|&value| value as f32));
+        }
+        let uv_start = self.uvs.len();
+        if let Some(uvs) = uvs {
+            self.uvs
+                .extend(uvs.iter().flatten().map(|&value| value as f32));
+        }
+
+        let material_index = match material {
+            Some(material) => {
+                let index = self.materials.len() / MATERIAL_STRIDE;
+                self.materials.extend(material_floats(&material));
+                to_u32(index)
+            }
+            None => NO_MATERIAL,
+        };
+
+        self.records.extend_from_slice(&[
+            node_index(node),
+            kind as u32,
+            to_u32(position_start),
+            to_u32(self.positions.len() - position_start),
+            to_u32(index_start),
+            to_u32(self.indices.len() - index_start),
+            to_u32(normal_start),
+            to_u32(self.normals.len() - normal_start),
+            to_u32(uv_start),
+            to_u32(self.uvs.len() - uv_start),
+            material_index,
+        ]);
+    }
+
+    /// Vingerafdruk van alles wat sinds `start` is toegevoegd. Offsets worden
+    /// relatief gemaakt, zodat dezelfde geometrie op een andere plek in de
+    /// buffers dezelfde vingerafdruk geeft.
+    fn fingerprint_since(&self, start: &NodeStart) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        for record in self.records[start.records..].chunks_exact(RECORD_STRIDE) {
+            // Node-id en absolute offsets overslaan; soort, lengtes en
+            // materiaal-aanwezigheid tellen wel mee.
+            record[1].hash(&mut hasher);
+            record[3].hash(&mut hasher);
+            record[5].hash(&mut hasher);
+            record[7].hash(&mut hasher);
+            record[9].hash(&mut hasher);
+            (record[10] == NO_MATERIAL).hash(&mut hasher);
+        }
+        hash_floats(&self.positions[start.positions..], &mut hasher);
+        hash_floats(&self.normals[start.normals..], &mut hasher);
+        hash_floats(&self.uvs[start.uvs..], &mut hasher);
+        hash_floats(&self.materials[start.materials..], &mut hasher);
+        self.indices[start.indices..].hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+/// Indices en optionele vertex-attributen van een mesh-item.
+#[derive(Debug, Default, Clone, Copy)]
+struct MeshParts<'a> {
+    indices: &'a [u32],
+    normals: Option<&'a [[f64; 3]]>,
+    uvs: Option<&'a [[f64; 2]]>,
+}
+
+/// Bufferlengtes aan het begin van een node, om terug te kunnen rollen.
+#[derive(Debug, Clone, Copy)]
+struct NodeStart {
+    records: usize,
+    positions: usize,
+    normals: usize,
+    uvs: usize,
+    indices: usize,
+    materials: usize,
+}
+
+impl NodeStart {
+    fn of(buffers: &GeometryBuffers) -> Self {
+        Self {
+            records: buffers.records.len(),
+            positions: buffers.positions.len(),
+            normals: buffers.normals.len(),
+            uvs: buffers.uvs.len(),
+            indices: buffers.indices.len(),
+            materials: buffers.materials.len(),
+        }
+    }
+}
+
+fn hash_floats(values: &[f32], hasher: &mut DefaultHasher) {
+    values.len().hash(hasher);
+    for value in values {
+        value.to_bits().hash(hasher);
+    }
+}
+
+fn material_floats(material: &MaterialValue) -> [f32; MATERIAL_STRIDE] {
+    let colour = |colour: crate::graph::value::ColorValue| {
+        [colour.r as f32, colour.g as f32, colour.b as f32]
+    };
+    let [dr, dg, db] = colour(material.diffuse);
+    let [sr, sg, sb] = colour(material.specular);
+    let [er, eg, eb] = colour(material.emission);
+    [
+        dr,
+        dg,
+        db,
+        sr,
+        sg,
+        sb,
+        er,
+        eg,
+        eb,
+        material.transparency as f32,
+        material.shine as f32,
+    ]
+}
+
+fn node_index(node: NodeId) -> u32 {
+    to_u32(node.0)
+}
+
+fn to_u32(value: usize) -> u32 {
+    u32::try_from(value).unwrap_or(u32::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GeometryBuffers, RECORD_STRIDE};
+    use crate::graph::evaluator::GeometryEntry;
+    use crate::graph::node::NodeId;
+    use crate::graph::value::Value;
+
+    fn entry(value: Value) -> GeometryEntry {
+        GeometryEntry {
+            source_node: NodeId::new(3),
+            value,
+            material: None,
+        }
+    }
+
+    #[test]
+    fn packs_meshes_with_attributes_and_points() {
+        let mesh = entry(Value::Mesh {
+            vertices: vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+            indices: vec![0, 1, 2],
+            normals: Some(vec![[0.0, 0.0, 1.0]; 3]),
+            uvs: Some(vec![[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]),
+            diagnostics: None,
+        });
+        let point = entry(Value::Point([5.0, 6.0, 7.0]));
+        let mut buffers = GeometryBuffers::default();
+
+        let fingerprint = buffers.diff_node(NodeId::new(3), &[&mesh, &point], None);
+
+        assert!(fingerprint.is_some());
+        assert_eq!(buffers.added_ids(), &[3]);
+        assert_eq!(buffers.positions().len(), 12);
+        assert_eq!(buffers.normals().len(), 9);
+        assert_eq!(buffers.uvs().len(), 6);
+        assert_eq!(buffers.indices(), &[0, 1, 2]);
+
+        let records = buffers.record_table();
+        assert_eq!(records.len(), 2 * RECORD_STRIDE);
+        assert_eq!(
+            &records[..RECORD_STRIDE],
+            &[3, 3, 0, 9, 0, 3, 0, 9, 0, 6, u32::MAX]
+        );
+        let point_record = &records[RECORD_STRIDE..];
+        assert_eq!(&point_record[..4], &[3, 0, 9, 3]);
+        assert_eq!(&buffers.positions()[9..], &[5.0, 6.0, 7.0]);
+    }
+
+    #[test]
+    fn diff_skips_unchanged_and_reports_updates_and_removals() {
+        let node = NodeId::new(1);
+        let line = entry(Value::CurveLine {
+            p1: [0.0, 0.0, 0.0],
+            p2: [1.0, 0.0, 0.0],
+        });
+        let first = GeometryBuffers::default().diff_node(node, &[&line], None);
+
+        let mut unchanged = GeometryBuffers::default();
+        assert_eq!(unchanged.diff_node(node, &[&line], first), first);
+        assert!(unchanged.added_ids().is_empty() && unchanged.updated_ids().is_empty());
+        assert!(unchanged.positions().is_empty() && unchanged.record_table().is_empty());
+
+        let moved = entry(Value::CurveLine {
+            p1: [0.0, 0.0, 0.0],
+            p2: [2.0, 0.0, 0.0],
+        });
+        let mut updated = GeometryBuffers::default();
+        assert_ne!(updated.diff_node(node, &[&moved], first), first);
+        assert_eq!(updated.updated_ids(), &[1]);
+
+        let mut removed = GeometryBuffers::default();
+        assert_eq!(removed.diff_node(node, &[], first), None);
+        assert_eq!(removed.removed_ids(), &[1]);
+    }
+}
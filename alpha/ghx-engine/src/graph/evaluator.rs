@@ -1,14 +1,18 @@
 //! Evaluatie van grafen in topologische volgorde.
 
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fmt;
 
-use crate::components::{ComponentError, ComponentRegistry, OutputMap};
+use crate::components::{
+    ComponentError, ComponentKind, ComponentOutput, ComponentRegistry, OutputMap, PinAccess,
+};
 use crate::graph::Graph;
 use crate::graph::internal_expression::{InternalExpressionError, apply_internal_expression};
-use crate::graph::node::NodeId;
+use crate::graph::node::{MetaLookupExt, MetaMap, MetaValue, Node, NodeId};
 use crate::graph::topo::{Topology, TopologyError};
+use crate::graph::tree::{DataTree, TreePath};
 use crate::graph::value::{MaterialValue, Value};
+use crate::graph::wire::Wire;
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -22,6 +26,8 @@ pub struct EvaluationResult {
     pub geometry: Vec<GeometryEntry>,
     /// Verzamelde fouten per evaluatieronde, zonder de volledige run te stoppen.
     pub errors: Vec<EvaluationError>,
+    /// Waarschuwingen die de evaluatie niet tegenhielden.
+    pub warnings: Vec<EvaluationWarning>,
     /// Nodes die niet succesvol geëvalueerd konden worden.
     pub failed_nodes: HashSet<NodeId>,
 }
@@ -65,17 +71,7 @@ impl EvaluationPlan {
 
         let mut pin_order = HashMap::new();
         for node in graph.nodes() {
-            let mut pins: Vec<String> = node.input_order().to_vec();
-            if let Some(connections) = incoming.get(&node.id) {
-                let mut extra: Vec<String> = connections.keys().cloned().collect();
-                extra.sort();
-                for pin in extra {
-                    if !pins.iter().any(|existing| existing == &pin) {
-                        pins.push(pin);
-                    }
-                }
-            }
-            pin_order.insert(node.id, pins);
+            pin_order.insert(node.id, node_pins(node, incoming.get(&node.id)));
         }
 
         Ok(Self {
@@ -90,6 +86,104 @@ impl EvaluationPlan {
         &self.order
     }
 
+    /// Neemt een nieuwe, nog onverbonden node op achteraan de volgorde.
+    pub fn insert_node(&mut self, node: &Node) {
+        if !self.order.contains(&node.id) {
+            self.order.push(node.id);
+        }
+        self.pin_order
+            .insert(node.id, node_pins(node, self.incoming.get(&node.id)));
+    }
+
+    /// Verwijdert een node en alle verbindingen vanuit die node. De graph moet
+    /// de node al kwijt zijn; de pinvolgorde van de afnemers wordt herberekend.
+    pub fn remove_node(&mut self, graph: &Graph, id: NodeId) {
+        self.order.retain(|node| *node != id);
+        self.incoming.remove(&id);
+        self.pin_order.remove(&id);
+
+        let mut affected = Vec::new();
+        for (target, per_node) in &mut self.incoming {
+            let before: usize = per_node.values().map(Vec::len).sum();
+            for connections in per_node.values_mut() {
+                connections.retain(|(from, _)| *from != id);
+            }
+            per_node.retain(|_, connections| !connections.is_empty());
+            if per_node.values().map(Vec::len).sum::<usize>() != before {
+                affected.push(*target);
+            }
+        }
+        for target in affected {
+            self.refresh_pins(graph, target);
+        }
+    }
+
+    /// Voegt een verbinding toe die al in de graph staat. De volgorde wordt
+    /// alleen opnieuw gesorteerd als de bron nu na het doel komt; een cyclus
+    /// wordt geweigerd en laat het plan ongewijzigd.
+    pub fn add_wire(&mut self, graph: &Graph, wire: &Wire) -> Result<(), EvaluationError> {
+        self.insert_connection(wire);
+
+        let position = |id: NodeId| self.order.iter().position(|node| *node == id);
+        if position(wire.from_node) >= position(wire.to_node) {
+            match Topology::sort(graph) {
+                Ok(topology) => self.order = topology.order,
+                Err(error) => {
+                    self.remove_connection(wire);
+                    return Err(error.into());
+                }
+            }
+        }
+
+        self.refresh_pins(graph, wire.to_node);
+        Ok(())
+    }
+
+    /// Verwijdert een verbinding; de volgorde blijft geldig.
+    pub fn remove_wire(&mut self, graph: &Graph, wire: &Wire) {
+        self.remove_connection(wire);
+        self.refresh_pins(graph, wire.to_node);
+    }
+
+    /// Herberekent de pinvolgorde van een node, bv. na een nieuwe inputpin.
+    pub fn refresh_pins(&mut self, graph: &Graph, id: NodeId) {
+        if let Some(node) = graph.node(id) {
+            self.pin_order
+                .insert(id, node_pins(node, self.incoming.get(&id)));
+        }
+    }
+
+    fn insert_connection(&mut self, wire: &Wire) {
+        let connections = self
+            .incoming
+            .entry(wire.to_node)
+            .or_default()
+            .entry(wire.to_pin.0.clone())
+            .or_default();
+        connections.push((wire.from_node, wire.from_pin.0.clone()));
+        connections.sort();
+    }
+
+    fn remove_connection(&mut self, wire: &Wire) {
+        let Some(per_node) = self.incoming.get_mut(&wire.to_node) else {
+            return;
+        };
+        if let Some(connections) = per_node.get_mut(&wire.to_pin.0) {
+            if let Some(index) = connections
+                .iter()
+                .position(|(from, pin)| *from == wire.from_node && *pin == wire.from_pin.0)
+            {
+                connections.remove(index);
+            }
+            if connections.is_empty() {
+                per_node.remove(&wire.to_pin.0);
+            }
+        }
+        if per_node.is_empty() {
+            self.incoming.remove(&wire.to_node);
+        }
+    }
+
     fn incoming_connections(&self, node: NodeId, pin: &str) -> Option<&Vec<(NodeId, String)>> {
         self.incoming
             .get(&node)
@@ -104,6 +198,22 @@ impl EvaluationPlan {
     }
 }
 
+/// Inputpinnen van een node in evaluatievolgorde: eerst de volgorde uit het
+/// GHX-bestand, daarna verbonden pinnen die daar niet in staan.
+fn node_pins(node: &Node, connections: Option<&HashMap<String, Vec<(NodeId, String)>>>) -> Vec<String> {
+    let mut pins: Vec<String> = node.input_order().to_vec();
+    if let Some(connections) = connections {
+        let mut extra: Vec<String> = connections.keys().cloned().collect();
+        extra.sort();
+        for pin in extra {
+            if !pins.iter().any(|existing| existing == &pin) {
+                pins.push(pin);
+            }
+        }
+    }
+    pins
+}
+
 /// Fouttype voor evaluatieproblemen.
 #[derive(Debug, Clone)]
 pub enum EvaluationError {
@@ -193,6 +303,41 @@ impl fmt::Display for EvaluationError {
     }
 }
 
+impl EvaluationError {
+    /// De node waarop de fout betrekking heeft, indien bekend.
+    #[must_use]
+    pub fn node_id(&self) -> Option<NodeId> {
+        match self {
+            Self::Topology(_) => None,
+            Self::ComponentNotFound { node_id, .. }
+            | Self::MissingInput { node_id, .. }
+            | Self::MissingDependencyOutput { node_id, .. }
+            | Self::ComponentFailed { node_id, .. }
+            | Self::InternalExpression { node_id, .. }
+            | Self::UnknownNode(node_id) => Some(*node_id),
+        }
+    }
+
+    /// Stabiele naam van het fouttype; niet-geïmplementeerde componenten
+    /// krijgen een eigen soort naast `ComponentFailed`.
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Topology(_) => "Topology",
+            Self::ComponentNotFound { .. } => "ComponentNotFound",
+            Self::MissingInput { .. } => "MissingInput",
+            Self::MissingDependencyOutput { .. } => "MissingDependencyOutput",
+            Self::ComponentFailed {
+                source: ComponentError::NotYetImplemented(_),
+                ..
+            } => "NotYetImplemented",
+            Self::ComponentFailed { .. } => "ComponentFailed",
+            Self::InternalExpression { .. } => "InternalExpression",
+            Self::UnknownNode(_) => "UnknownNode",
+        }
+    }
+}
+
 impl std::error::Error for EvaluationError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
@@ -210,6 +355,55 @@ impl From<TopologyError> for EvaluationError {
     }
 }
 
+/// Waarschuwingen tijdens evaluatie, vergelijkbaar met de oranje ballonnen
+/// in Grasshopper: de node is wel geëvalueerd.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EvaluationWarning {
+    /// Een verbonden input leverde geen data (null, lege lijst of lege boom).
+    EmptyInput { node_id: NodeId, pin: String },
+    /// Een component meldde zelf een waarschuwing in zijn
+    /// [`ComponentOutput`](crate::components::ComponentOutput).
+    Component {
+        node_id: NodeId,
+        component: String,
+        message: String,
+    },
+}
+
+impl EvaluationWarning {
+    /// De node waarop de waarschuwing betrekking heeft.
+    #[must_use]
+    pub fn node_id(&self) -> NodeId {
+        match self {
+            Self::EmptyInput { node_id, .. } | Self::Component { node_id, .. } => *node_id,
+        }
+    }
+
+    /// Stabiele naam van het waarschuwingstype.
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::EmptyInput { .. } => "EmptyInput",
+            Self::Component { .. } => "Component",
+        }
+    }
+}
+
+impl fmt::Display for EvaluationWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyInput { node_id, pin } => {
+                write!(f, "input `{pin}` van node {} ontving geen data", node_id.0)
+            }
+            Self::Component {
+                node_id,
+                component,
+                message,
+            } => write!(f, "{component} (node {}): {message}", node_id.0),
+        }
+    }
+}
+
 /// Evalueert een graph met behulp van de opgegeven componentregistry.
 pub fn evaluate(
     graph: &Graph,
@@ -295,10 +489,15 @@ pub fn evaluate_with_plan(
 
                 if skip_node {
                     Value::Null
-                } else if values.len() == 1 {
-                    values.into_iter().next().unwrap()
                 } else {
-                    Value::List(values)
+                    let combined = combine_connection_values(values);
+                    if is_empty_data(&combined) {
+                        result.warnings.push(EvaluationWarning::EmptyInput {
+                            node_id,
+                            pin: pin.clone(),
+                        });
+                    }
+                    combined
                 }
             } else if let Some(default) = node.inputs.get(pin) {
                 default.clone()
@@ -328,6 +527,9 @@ pub fn evaluate_with_plan(
             }
 
             if !skip_node {
+                if let Some(modifiers) = PinModifiers::from_meta(&node.meta, "input", pin) {
+                    value = modifiers.apply(value);
+                }
                 input_values.push(value);
             }
         }
@@ -337,8 +539,10 @@ pub fn evaluate_with_plan(
             continue;
         }
 
-        match component.evaluate(&input_values, &node.meta) {
-            Ok(outputs) => {
+        match evaluate_component(component, &input_values, &node.meta) {
+            Ok(output) => {
+                push_component_warnings(node_id, component, output.warnings, &mut result.warnings);
+                let outputs = apply_output_modifiers(output.outputs, &node.meta);
                 let stored_outputs = merge_outputs(node.outputs.clone(), outputs);
                 collect_geometry(node_id, &stored_outputs, &mut result.geometry);
                 result.node_outputs.insert(node_id, stored_outputs);
@@ -464,10 +668,15 @@ pub fn evaluate_with_plan_incremental(
 
                 if skip_node {
                     Value::Null
-                } else if values.len() == 1 {
-                    values.into_iter().next().unwrap()
                 } else {
-                    Value::List(values)
+                    let combined = combine_connection_values(values);
+                    if is_empty_data(&combined) {
+                        result.warnings.push(EvaluationWarning::EmptyInput {
+                            node_id,
+                            pin: pin.clone(),
+                        });
+                    }
+                    combined
                 }
             } else if let Some(default) = node.inputs.get(pin) {
                 default.clone()
@@ -498,6 +707,9 @@ pub fn evaluate_with_plan_incremental(
             }
 
             if !skip_node {
+                if let Some(modifiers) = PinModifiers::from_meta(&node.meta, "input", pin) {
+                    value = modifiers.apply(value);
+                }
                 input_values.push(value);
             }
         }
@@ -520,12 +732,21 @@ pub fn evaluate_with_plan_incremental(
                 let stored_outputs = previous_outputs.clone();
                 collect_geometry(node_id, &stored_outputs, &mut result.geometry);
                 result.node_outputs.insert(node_id, stored_outputs);
+                // Componentwaarschuwingen horen bij de hergebruikte uitvoer.
+                if let Some(prev) = previous {
+                    result.warnings.extend(prev.warnings.iter().filter(|warning| {
+                        matches!(warning, EvaluationWarning::Component { .. })
+                            && warning.node_id() == node_id
+                    }).cloned());
+                }
                 continue;
             }
         }
 
-        match component.evaluate(&input_values, &node.meta) {
-            Ok(outputs) => {
+        match evaluate_component(component, &input_values, &node.meta) {
+            Ok(output) => {
+                push_component_warnings(node_id, component, output.warnings, &mut result.warnings);
+                let outputs = apply_output_modifiers(output.outputs, &node.meta);
                 let stored_outputs = merge_outputs(node.outputs.clone(), outputs);
 
                 let previous_outputs = previous.and_then(|prev| prev.node_outputs.get(&node_id));
@@ -553,6 +774,369 @@ pub fn evaluate_with_plan_incremental(
     Ok((result, changed_nodes))
 }
 
+/// Meldt de waarschuwingen van een component. Bij longest-list matching of
+/// datatrees komt dezelfde melding per aanroep terug; elke tekst wordt maar één
+/// keer gemeld.
+fn push_component_warnings(
+    node_id: NodeId,
+    component: ComponentKind,
+    messages: Vec<String>,
+    warnings: &mut Vec<EvaluationWarning>,
+) {
+    for message in messages {
+        let warning = EvaluationWarning::Component {
+            node_id,
+            component: component.name().to_owned(),
+            message,
+        };
+        if !warnings.contains(&warning) {
+            warnings.push(warning);
+        }
+    }
+}
+
+/// Geeft aan of een verbonden input geen bruikbare data bevat.
+fn is_empty_data(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::List(values) => values.is_empty(),
+        Value::Tree(tree) => tree.is_empty(),
+        _ => false,
+    }
+}
+
+/// Voegt de waarden van meerdere verbindingen op één pin samen. Zodra één van
+/// de bronnen een datatree levert, worden de takken samengevoegd; anders blijft
+/// het een platte lijst zoals voorheen.
+fn combine_connection_values(values: Vec<Value>) -> Value {
+    if values.len() == 1 {
+        return values.into_iter().next().unwrap();
+    }
+
+    if values.iter().any(|value| matches!(value, Value::Tree(_))) {
+        let mut tree = DataTree::new();
+        for value in &values {
+            tree.merge(DataTree::from_value(value));
+        }
+        return Value::Tree(tree);
+    }
+
+    Value::List(values)
+}
+
+/// Roept een component aan en verdeelt datatree-invoer per tak.
+///
+/// Componenten die zelf met datatrees werken krijgen de bomen ongewijzigd.
+/// Voor alle andere componenten bepaalt de boom met de meeste takken de
+/// uitvoerpaden; kortere bomen hergebruiken hun laatste tak en gewone waarden
+/// gaan ongewijzigd mee naar elke aanroep.
+fn evaluate_component(
+    component: ComponentKind,
+    inputs: &[Value],
+    meta: &MetaMap,
+) -> Result<ComponentOutput, ComponentError> {
+    if component.handles_data_trees() {
+        return evaluate_matched(component, inputs, meta);
+    }
+
+    let branched_inputs: Vec<Option<Vec<(&TreePath, &Vec<Value>)>>> = inputs
+        .iter()
+        .map(|value| match value {
+            Value::Tree(tree) if !tree.is_empty() => Some(tree.branches().collect()),
+            _ => None,
+        })
+        .collect();
+
+    let Some(master) = branched_inputs
+        .iter()
+        .enumerate()
+        .filter_map(|(index, branches)| branches.as_ref().map(|b| (index, b.len())))
+        .max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(&a.0)))
+        .map(|(index, _)| index)
+    else {
+        if !inputs.iter().any(|value| matches!(value, Value::Tree(_))) {
+            return evaluate_matched(component, inputs, meta);
+        }
+        // Enkel lege bomen: behandel ze als ontbrekende invoer.
+        let inputs: Vec<Value> = inputs
+            .iter()
+            .map(|value| match value {
+                Value::Tree(_) => Value::Null,
+                other => other.clone(),
+            })
+            .collect();
+        return evaluate_matched(component, &inputs, meta);
+    };
+
+    let master_paths: Vec<TreePath> = branched_inputs[master]
+        .as_ref()
+        .map(|branches| branches.iter().map(|(path, _)| (*path).clone()).collect())
+        .unwrap_or_default();
+
+    let mut trees: BTreeMap<String, DataTree> = BTreeMap::new();
+    let mut warnings = Vec::new();
+    for (branch_index, path) in master_paths.iter().enumerate() {
+        let branch_inputs: Vec<Value> = inputs
+            .iter()
+            .zip(&branched_inputs)
+            .map(|(value, branches)| match branches {
+                Some(branches) => {
+                    let (_, items) = branches[branch_index.min(branches.len() - 1)];
+                    branch_value(items)
+                }
+                None if matches!(value, Value::Tree(_)) => Value::Null,
+                None => value.clone(),
+            })
+            .collect();
+
+        let output = evaluate_matched(component, &branch_inputs, meta)?;
+        warnings.extend(output.warnings);
+        for (pin, value) in output.outputs {
+            let tree = trees.entry(pin).or_default();
+            match value {
+                Value::List(items) => tree.append(path.clone(), items),
+                Value::Tree(sub_tree) => {
+                    for (sub_path, items) in sub_tree.into_branches() {
+                        tree.append(path.joined(&sub_path), items);
+                    }
+                }
+                other => tree.append(path.clone(), [other]),
+            }
+        }
+    }
+
+    let outputs = trees
+        .into_iter()
+        .map(|(pin, tree)| (pin, Value::Tree(tree)))
+        .collect();
+    Ok(ComponentOutput { outputs, warnings })
+}
+
+/// Longest-list matching voor pinnen met item-toegang.
+///
+/// Lijsten op item-pinnen worden per item aan het component gegeven; kortere
+/// lijsten herhalen hun laatste item. Zolang geen enkele item-pin een lijst
+/// krijgt, wordt het component één keer rechtstreeks aangeroepen. Leveren de
+/// afzonderlijke aanroepen zelf lijsten op, dan krijgt elke aanroep een eigen
+/// tak `{i}` in de uitvoer.
+fn evaluate_matched(
+    component: ComponentKind,
+    inputs: &[Value],
+    meta: &MetaMap,
+) -> Result<ComponentOutput, ComponentError> {
+    // Pas na de matching omzetten: een exacte curve of een vlak is één item,
+    // ook als het component het als puntenlijst ontvangt.
+    let lowering = Lowering {
+        curves: !component.handles_curves(),
+        breps: !component.handles_breps(),
+    };
+    let call = |inputs: &[Value]| {
+        if inputs.iter().any(|value| lowering.applies_to(value)) {
+            let lowered: Vec<Value> = inputs.iter().map(|value| lowering.lower(value)).collect();
+            component.evaluate_with_warnings(&lowered, meta)
+        } else {
+            component.evaluate_with_warnings(inputs, meta)
+        }
+    };
+
+    let access = component.input_access();
+    let Some(&last_access) = access.last() else {
+        return call(inputs);
+    };
+
+    let matched: Vec<Option<&[Value]>> = inputs
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let pin_access = access.get(index).copied().unwrap_or(last_access);
+            match (pin_access, value) {
+                (PinAccess::Item, Value::List(items)) => Some(items.as_slice()),
+                _ => None,
+            }
+        })
+        .collect();
+
+    if matched.iter().all(Option::is_none) {
+        return call(inputs);
+    }
+    if matched.iter().flatten().any(|items| items.is_empty()) {
+        return Ok(ComponentOutput::default());
+    }
+
+    let iterations = matched.iter().flatten().map(|items| items.len()).max().unwrap_or(0);
+    let mut results: Vec<OutputMap> = Vec::with_capacity(iterations);
+    let mut warnings = Vec::new();
+    for iteration in 0..iterations {
+        let call_inputs: Vec<Value> = inputs
+            .iter()
+            .zip(&matched)
+            .map(|(value, items)| match items {
+                Some(items) => items[iteration.min(items.len() - 1)].clone(),
+                None => value.clone(),
+            })
+            .collect();
+        let output = call(&call_inputs)?;
+        warnings.extend(output.warnings);
+        results.push(output.outputs);
+    }
+
+    let pins: BTreeSet<String> = results.iter().flat_map(|outputs| outputs.keys().cloned()).collect();
+    let mut outputs = OutputMap::new();
+    for pin in pins {
+        let values: Vec<Value> = results
+            .iter_mut()
+            .map(|result| result.remove(&pin).unwrap_or(Value::Null))
+            .collect();
+
+        if values.iter().any(|value| matches!(value, Value::List(_) | Value::Tree(_))) {
+            let mut tree = DataTree::new();
+            for (iteration, value) in values.into_iter().enumerate() {
+                let path = TreePath::new(vec![iteration]);
+                match value {
+                    Value::List(items) => tree.append(path, items),
+                    Value::Tree(sub_tree) => {
+                        for (sub_path, items) in sub_tree.into_branches() {
+                            tree.append(path.joined(&sub_path), items);
+                        }
+                    }
+                    other => tree.append(path, [other]),
+                }
+            }
+            outputs.insert(pin, Value::Tree(tree));
+        } else {
+            outputs.insert(pin, Value::List(values));
+        }
+    }
+    Ok(ComponentOutput { outputs, warnings })
+}
+
+/// Welke exacte geometrie voor een component naar de oudere representatie
+/// moet worden omgezet: curves naar puntenlijsten, breps naar een gemeshte
+/// `Value::Surface`. Vlakken gaan altijd als puntenlijst naar de componenten.
+#[derive(Debug, Clone, Copy)]
+struct Lowering {
+    curves: bool,
+    breps: bool,
+}
+
+impl Lowering {
+    fn applies_to(self, value: &Value) -> bool {
+        match value {
+            Value::Curve(_) => self.curves,
+            Value::Plane(_) => true,
+            Value::Brep(_) => self.breps,
+            Value::List(values) => values.iter().any(|value| self.applies_to(value)),
+            Value::Tree(tree) => tree.items().any(|value| self.applies_to(value)),
+            _ => false,
+        }
+    }
+
+    fn lower(self, value: &Value) -> Value {
+        match value {
+            Value::Curve(curve) if self.curves => curve.to_polyline_value(),
+            Value::Plane(plane) => plane.to_points_value(),
+            Value::Brep(brep) if self.breps => brep.to_surface_value(),
+            Value::List(values) => Value::List(values.iter().map(|value| self.lower(value)).collect()),
+            Value::Tree(tree) => {
+                let mut lowered = DataTree::new();
+                for (path, items) in tree.branches() {
+                    lowered.insert_branch(
+                        path.clone(),
+                        items.iter().map(|value| self.lower(value)).collect(),
+                    );
+                }
+                Value::Tree(lowered)
+            }
+            other => other.clone(),
+        }
+    }
+}
+
+/// Grasshopper-datamodifiers op een pin (`Mapping`, `ReverseData` en
+/// `SimplifyData`), zoals de parser ze als `input.<pin>.*` of `output.<pin>.*`
+/// meta opslaat.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct PinModifiers {
+    mapping: DataMapping,
+    reverse: bool,
+    simplify: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum DataMapping {
+    #[default]
+    None,
+    Flatten,
+    Graft,
+}
+
+impl PinModifiers {
+    fn from_meta(meta: &MetaMap, direction: &str, pin: &str) -> Option<Self> {
+        let base = format!("{direction}.{pin}");
+        let mapping = match meta.get_normalized(&format!("{base}.mapping")) {
+            Some(MetaValue::Integer(1)) => DataMapping::Flatten,
+            Some(MetaValue::Integer(2)) => DataMapping::Graft,
+            Some(MetaValue::Number(number)) if *number == 1.0 => DataMapping::Flatten,
+            Some(MetaValue::Number(number)) if *number == 2.0 => DataMapping::Graft,
+            _ => DataMapping::None,
+        };
+        let flag = |suffix: &str| {
+            meta.get_normalized(&format!("{base}.{suffix}"))
+                .and_then(MetaValue::as_boolean)
+                .unwrap_or(false)
+        };
+
+        let modifiers = Self {
+            mapping,
+            reverse: flag("reverse"),
+            simplify: flag("simplify"),
+        };
+        (modifiers != Self::default()).then_some(modifiers)
+    }
+
+    /// Past de modifiers toe in Grasshopper-volgorde: eerst omkeren, dan
+    /// flatten/graft en tot slot vereenvoudigen.
+    fn apply(self, value: Value) -> Value {
+        if matches!(value, Value::Null) {
+            return value;
+        }
+
+        let mut tree = DataTree::from_value(&value);
+        if self.reverse {
+            tree = tree.reverse_items();
+        }
+        tree = match self.mapping {
+            DataMapping::None => tree,
+            DataMapping::Flatten => tree.flatten(TreePath::root()),
+            DataMapping::Graft => tree.graft(),
+        };
+        if self.simplify {
+            tree = tree.simplify();
+        }
+        Value::Tree(tree)
+    }
+}
+
+fn apply_output_modifiers(outputs: OutputMap, meta: &MetaMap) -> OutputMap {
+    outputs
+        .into_iter()
+        .map(|(pin, value)| match PinModifiers::from_meta(meta, "output", &pin) {
+            Some(modifiers) => {
+                let value = modifiers.apply(value);
+                (pin, value)
+            }
+            None => (pin, value),
+        })
+        .collect()
+}
+
+fn branch_value(items: &[Value]) -> Value {
+    match items {
+        [single] => single.clone(),
+        items => Value::List(items.to_vec()),
+    }
+}
+
 fn merge_outputs(
     mut existing: BTreeMap<String, Value>,
     new_outputs: OutputMap,
@@ -609,7 +1193,9 @@ fn collect_value_geometry(
     match value {
         Value::Point(_)
         | Value::CurveLine { .. }
+        | Value::Curve(_)
         | Value::Surface { .. }
+        | Value::Brep(_)
         | Value::Mesh { .. } => {
             geometry.push(GeometryEntry {
                 source_node: node_id,
@@ -622,6 +1208,11 @@ fn collect_value_geometry(
                 collect_value_geometry(node_id, value, material, geometry);
             }
         }
+        Value::Tree(tree) => {
+            for value in tree.items() {
+                collect_value_geometry(node_id, value, material, geometry);
+            }
+        }
         Value::Material(_) => {}
         _ => {}
     }
@@ -631,6 +1222,7 @@ fn extract_material_value(value: &Value) -> Option<MaterialValue> {
     match value {
         Value::Material(material) => Some(*material),
         Value::List(values) => values.iter().find_map(extract_material_value),
+        Value::Tree(tree) => tree.items().find_map(extract_material_value),
         _ => None,
     }
 }
@@ -638,13 +1230,14 @@ fn extract_material_value(value: &Value) -> Option<MaterialValue> {
 #[cfg(test)]
 mod tests {
     use super::{
-        EvaluationError, EvaluationPlan, GeometryEntry, collect_geometry, evaluate,
-        evaluate_with_plan_incremental,
+        EvaluationError, EvaluationPlan, EvaluationWarning, GeometryEntry, collect_geometry, evaluate, evaluate_with_plan_incremental, push_component_warnings,
     };
-    use crate::components::ComponentRegistry;
+    use crate::components::{ComponentRegistry, PinAccess};
     use crate::graph::Graph;
-    use crate::graph::node::{Node, NodeId};
-    use crate::graph::value::{ColorValue, MaterialValue, Value};
+    use crate::graph::node::{MetaValue, Node, NodeId};
+    use crate::graph::tree::{DataTree, TreePath};
+    use crate::geom::Point3;
+    use crate::graph::value::{ColorValue, CurveValue, MaterialValue, PlaneValue, Value};
     use crate::graph::wire::Wire;
     use std::collections::{BTreeMap, HashSet};
 
@@ -702,6 +1295,37 @@ mod tests {
             .any(|err| matches!(err, EvaluationError::ComponentNotFound { node_id: err_node, .. } if *err_node == node_id)));
     }
 
+    #[test]
+    fn connected_input_without_data_yields_warning() {
+        let mut graph = Graph::new();
+
+        let mut relay = Node::new(NodeId::new(0));
+        relay.guid = Some("{b6236720-8d88-4289-93c3-ac4c99f9b97b}".to_string());
+        let relay_id = graph.add_node(relay).unwrap();
+
+        let mut point = Node::new(NodeId::new(1));
+        point.guid = Some("{3581f42a-9592-4549-bd6b-1c0fc39d067b}".to_string());
+        point.add_input_pin("X");
+        point.add_input_pin("Y");
+        point.add_input_pin("Z");
+        let point_id = graph.add_node(point).unwrap();
+        graph
+            .add_wire(Wire::new(relay_id, "out0", point_id, "X"))
+            .unwrap();
+
+        let registry = ComponentRegistry::default();
+        let result = evaluate(&graph, &registry).expect("evaluatie slaagt");
+
+        assert_eq!(
+            result.warnings,
+            [EvaluationWarning::EmptyInput {
+                node_id: point_id,
+                pin: "X".to_string(),
+            }]
+        );
+        assert!(result.node_outputs.contains_key(&point_id));
+    }
+
     #[test]
     fn continues_after_component_failure() {
         let mut graph = Graph::new();
@@ -777,6 +1401,64 @@ mod tests {
         assert!(second_result.node_outputs.get(&point_id).is_none());
     }
 
+    #[test]
+    fn repeated_component_warnings_are_reported_once() {
+        let registry = ComponentRegistry::default();
+        let component = registry
+            .resolve(Some("{3581f42a-9592-4549-bd6b-1c0fc39d067b}"), None, None)
+            .expect("point component");
+
+        // Eén melding per tak of per item, zoals bij datatrees.
+        let messages = vec!["open mesh".to_string(), "open mesh".to_string()];
+        let mut warnings = Vec::new();
+        push_component_warnings(NodeId::new(3), component, messages, &mut warnings);
+
+        assert_eq!(
+            warnings,
+            [EvaluationWarning::Component {
+                node_id: NodeId::new(3),
+                component: component.name().to_owned(),
+                message: "open mesh".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn incremental_keeps_component_warnings_of_cached_nodes() {
+        let mut graph = Graph::new();
+
+        let mut point = Node::new(NodeId::new(0));
+        point.guid = Some("{3581f42a-9592-4549-bd6b-1c0fc39d067b}".to_string());
+        point.add_input_pin("X");
+        point.set_input("X", Value::Number(1.0));
+        let point_id = graph.add_node(point).unwrap();
+
+        let registry = ComponentRegistry::default();
+        let plan = EvaluationPlan::new(&graph).expect("plan beschikbaar");
+        let dirty: HashSet<NodeId> = HashSet::from([point_id]);
+        let (mut initial_result, _) =
+            evaluate_with_plan_incremental(&graph, &registry, &plan, None, &dirty)
+                .expect("initiële evaluatie");
+        let warning = EvaluationWarning::Component {
+            node_id: point_id,
+            component: "Construct Point".to_string(),
+            message: "waarschuwing".to_string(),
+        };
+        initial_result.warnings.push(warning.clone());
+
+        let (second_result, changed_nodes) = evaluate_with_plan_incremental(
+            &graph,
+            &registry,
+            &plan,
+            Some(&initial_result),
+            &HashSet::new(),
+        )
+        .expect("tweede evaluatie");
+
+        assert!(changed_nodes.is_empty());
+        assert_eq!(second_result.warnings, [warning]);
+    }
+
     #[test]
     fn evaluation_plan_preserves_declared_input_order() {
         let mut graph = Graph::new();
@@ -893,4 +1575,413 @@ mod tests {
         .expect("her-evaluatie zonder wijzigingen slaagt");
         assert!(changed_final.is_empty());
     }
+
+    fn numbers(values: &[f64]) -> Vec<Value> {
+        values.iter().copied().map(Value::Number).collect()
+    }
+
+    #[test]
+    fn data_tree_survives_across_nodes() {
+        let mut graph = Graph::new();
+
+        let mut relay = Node::new(NodeId::new(0));
+        relay.guid = Some("b6236720-8d88-4289-93c3-ac4c99f9b97b".to_string());
+        relay.add_input_pin("Input");
+        relay.set_input("Input", Value::List(numbers(&[1.0, 2.0, 3.0])));
+        let relay_id = graph.add_node(relay).unwrap();
+
+        let mut graft = Node::new(NodeId::new(1));
+        graft.guid = Some("10a8674b-f4bb-4fdf-a56e-94dc606ecf33".to_string());
+        graft.add_input_pin("T");
+        let graft_id = graph.add_node(graft).unwrap();
+
+        let mut addition = Node::new(NodeId::new(2));
+        addition.guid = Some("cae37d1c-8146-4e0b-9cf1-14cb3e337b94".to_string());
+        addition.add_input_pin("A");
+        addition.add_input_pin("B");
+        addition.set_input("B", Value::Number(10.0));
+        let addition_id = graph.add_node(addition).unwrap();
+
+        let mut flatten = Node::new(NodeId::new(3));
+        flatten.guid = Some("a13fcd5d-81af-4337-a32e-28dd7e23ae4c".to_string());
+        flatten.add_input_pin("T");
+        let flatten_id = graph.add_node(flatten).unwrap();
+
+        graph
+            .add_wire(Wire::new(relay_id, "Output", graft_id, "T"))
+            .unwrap();
+        graph
+            .add_wire(Wire::new(graft_id, "T", addition_id, "A"))
+            .unwrap();
+        graph
+            .add_wire(Wire::new(addition_id, "R", flatten_id, "T"))
+            .unwrap();
+
+        let registry = ComponentRegistry::default();
+        let result = evaluate(&graph, &registry).expect("evaluatie slaagt");
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+
+        let Some(Value::Tree(sums)) = result.node_outputs[&addition_id].get("R") else {
+            panic!("optelling levert een datatree");
+        };
+        let paths: Vec<String> = sums.paths().map(ToString::to_string).collect();
+        assert_eq!(paths, ["{0;0}", "{0;1}", "{0;2}"]);
+        assert_eq!(
+            sums.branch(&TreePath::new(vec![0, 1])),
+            Some(&numbers(&[12.0])[..])
+        );
+
+        let Some(Value::Tree(flattened)) = result.node_outputs[&flatten_id].get("T") else {
+            panic!("flatten levert een datatree");
+        };
+        assert_eq!(flattened.branch_count(), 1);
+        assert_eq!(
+            flattened.branch(&TreePath::root()),
+            Some(&numbers(&[11.0, 12.0, 13.0])[..])
+        );
+    }
+
+    #[test]
+    fn shorter_tree_reuses_its_last_branch() {
+        let mut graph = Graph::new();
+
+        let mut a = Node::new(NodeId::new(0));
+        a.guid = Some("b6236720-8d88-4289-93c3-ac4c99f9b97b".to_string());
+        a.add_input_pin("Input");
+        let mut tree_a = DataTree::with_branch(TreePath::new(vec![0]), numbers(&[1.0]));
+        tree_a.insert_branch(TreePath::new(vec![1]), numbers(&[2.0]));
+        tree_a.insert_branch(TreePath::new(vec![2]), numbers(&[3.0]));
+        a.set_input("Input", Value::Tree(tree_a));
+        let a_id = graph.add_node(a).unwrap();
+
+        let mut b = Node::new(NodeId::new(1));
+        b.guid = Some("b6236720-8d88-4289-93c3-ac4c99f9b97b".to_string());
+        b.add_input_pin("Input");
+        let mut tree_b = DataTree::with_branch(TreePath::new(vec![5]), numbers(&[10.0]));
+        tree_b.insert_branch(TreePath::new(vec![6]), numbers(&[20.0]));
+        b.set_input("Input", Value::Tree(tree_b));
+        let b_id = graph.add_node(b).unwrap();
+
+        let mut addition = Node::new(NodeId::new(2));
+        addition.guid = Some("cae37d1c-8146-4e0b-9cf1-14cb3e337b94".to_string());
+        addition.add_input_pin("A");
+        addition.add_input_pin("B");
+        let addition_id = graph.add_node(addition).unwrap();
+
+        graph
+            .add_wire(Wire::new(a_id, "Output", addition_id, "A"))
+            .unwrap();
+        graph
+            .add_wire(Wire::new(b_id, "Output", addition_id, "B"))
+            .unwrap();
+
+        let registry = ComponentRegistry::default();
+        let result = evaluate(&graph, &registry).expect("evaluatie slaagt");
+        let Some(Value::Tree(sums)) = result.node_outputs[&addition_id].get("R") else {
+            panic!("optelling levert een datatree");
+        };
+        assert_eq!(
+            sums.flattened_items(),
+            numbers(&[11.0, 22.0, 23.0])
+        );
+        assert!(sums.branch(&TreePath::new(vec![2])).is_some());
+    }
+
+    #[test]
+    fn item_inputs_use_longest_list_matching() {
+        let mut graph = Graph::new();
+
+        let mut addition = Node::new(NodeId::new(0));
+        addition.guid = Some("cae37d1c-8146-4e0b-9cf1-14cb3e337b94".to_string());
+        addition.add_input_pin("A");
+        addition.add_input_pin("B");
+        addition.set_input("A", Value::List(numbers(&[1.0, 2.0, 3.0])));
+        addition.set_input("B", Value::List(numbers(&[10.0, 20.0])));
+        let addition_id = graph.add_node(addition).unwrap();
+
+        let mut point = Node::new(NodeId::new(1));
+        point.guid = Some("3581f42a-9592-4549-bd6b-1c0fc39d067b".to_string());
+        point.add_input_pin("X");
+        point.add_input_pin("Y");
+        point.add_input_pin("Z");
+        point.set_input("X", Value::List(numbers(&[1.0, 2.0])));
+        point.set_input("Y", Value::Number(5.0));
+        let point_id = graph.add_node(point).unwrap();
+
+        let registry = ComponentRegistry::default();
+        let result = evaluate(&graph, &registry).expect("evaluatie slaagt");
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+
+        assert_eq!(
+            result.node_outputs[&addition_id].get("R"),
+            Some(&Value::List(numbers(&[11.0, 22.0, 23.0])))
+        );
+        let points = result.node_outputs[&point_id]
+            .values()
+            .next()
+            .expect("punt levert een uitvoer");
+        assert_eq!(
+            points,
+            &Value::List(vec![
+                Value::Point([1.0, 5.0, 0.0]),
+                Value::Point([2.0, 5.0, 0.0]),
+            ])
+        );
+    }
+
+    #[test]
+    fn domain_components_match_item_inputs_centrally() {
+        let mut graph = Graph::new();
+
+        let mut domain = Node::new(NodeId::new(0));
+        domain.guid = Some("{d1a28e95-cf96-4936-bf34-8bf142d731bf}".to_string());
+        domain.add_input_pin("A");
+        domain.add_input_pin("B");
+        domain.set_input("A", Value::List(numbers(&[0.0, 1.0, 2.0])));
+        domain.set_input("B", Value::Number(10.0));
+        let domain_id = graph.add_node(domain).unwrap();
+
+        let registry = ComponentRegistry::default();
+        let component = registry
+            .resolve(Some("d1a28e95-cf96-4936-bf34-8bf142d731bf"), None, None)
+            .expect("construct domain");
+        assert_eq!(component.input_access(), &[PinAccess::Item]);
+
+        let result = evaluate(&graph, &registry).expect("evaluatie slaagt");
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        let Some(Value::List(domains)) = result.node_outputs[&domain_id].get("I") else {
+            panic!("construct domain levert een lijst domeinen");
+        };
+        assert_eq!(domains.len(), 3);
+        assert!(domains.iter().all(|domain| matches!(domain, Value::Domain(_))));
+    }
+
+    #[test]
+    fn plane_values_reach_components_as_point_lists() {
+        let mut graph = Graph::new();
+
+        let mut circle = Node::new(NodeId::new(0));
+        circle.guid = Some("807b86e3-be8d-4970-92b5-f8cdcb45b06b".to_string());
+        circle.add_input_pin("P");
+        circle.add_input_pin("R");
+        circle.set_input(
+            "P",
+            Value::Plane(PlaneValue::from_axes(
+                [1.0, 2.0, 0.0],
+                [0.0, 1.0, 0.0],
+                [0.0, 0.0, 1.0],
+            )),
+        );
+        circle.set_input("R", Value::Number(3.0));
+        let circle_id = graph.add_node(circle).unwrap();
+
+        let registry = ComponentRegistry::default();
+        let result = evaluate(&graph, &registry).expect("evaluatie slaagt");
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+
+        let Some(Value::Curve(CurveValue::Circle(circle))) = result.node_outputs[&circle_id].get("C")
+        else {
+            panic!("circle levert een exacte cirkel");
+        };
+        assert_eq!(circle.center, Point3::new(1.0, 2.0, 0.0));
+        assert!(circle.x_axis.cross(circle.y_axis).x.abs() > 1.0 - 1e-9);
+    }
+
+    #[test]
+    fn exact_curves_reach_curve_components_and_are_lowered_for_others() {
+        let mut graph = Graph::new();
+
+        let mut circle = Node::new(NodeId::new(0));
+        circle.guid = Some("807b86e3-be8d-4970-92b5-f8cdcb45b06b".to_string());
+        circle.add_input_pin("P");
+        circle.add_input_pin("R");
+        circle.set_input("R", Value::Number(3.0));
+        let circle_id = graph.add_node(circle).unwrap();
+
+        let mut divide = Node::new(NodeId::new(1));
+        divide.guid = Some("{2162e72e-72fc-4bf8-9459-d4d82fa8aa14}".to_string());
+        divide.add_input_pin("C");
+        divide.add_input_pin("N");
+        divide.set_input("N", Value::Number(7.0));
+        let divide_id = graph.add_node(divide).unwrap();
+
+        let mut control_points = Node::new(NodeId::new(2));
+        control_points.guid = Some("{d7df7658-e02d-4a48-a345-2195a68db4ef}".to_string());
+        control_points.add_input_pin("C");
+        let control_points_id = graph.add_node(control_points).unwrap();
+
+        graph
+            .add_wire(Wire::new(circle_id, "C", divide_id, "C"))
+            .unwrap();
+        graph
+            .add_wire(Wire::new(circle_id, "C", control_points_id, "C"))
+            .unwrap();
+
+        let registry = ComponentRegistry::default();
+        let result = evaluate(&graph, &registry).expect("evaluatie slaagt");
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+
+        assert!(matches!(
+            result.node_outputs[&circle_id].get("C"),
+            Some(Value::Curve(_))
+        ));
+
+        let Some(Value::List(points)) = result.node_outputs[&divide_id].get("P") else {
+            panic!("divide curve levert punten");
+        };
+        assert!(!points.is_empty());
+        for point in points {
+            let Value::Point([x, y, _]) = point else {
+                panic!("verwacht punt, kreeg {point:?}");
+            };
+            assert!(((x * x + y * y).sqrt() - 3.0).abs() < 1e-9);
+        }
+
+        assert!(!result.node_outputs[&control_points_id].is_empty());
+    }
+
+    #[test]
+    fn transforms_and_offsets_keep_exact_curves() {
+        let mut graph = Graph::new();
+
+        let mut circle = Node::new(NodeId::new(0));
+        circle.guid = Some("807b86e3-be8d-4970-92b5-f8cdcb45b06b".to_string());
+        circle.add_input_pin("P");
+        circle.add_input_pin("R");
+        circle.set_input("R", Value::Number(2.0));
+        let circle_id = graph.add_node(circle).unwrap();
+
+        let mut rotate = Node::new(NodeId::new(1));
+        rotate.guid = Some("{b661519d-43fd-4e5a-b244-d54d9fae2bde}".to_string());
+        rotate.add_input_pin("G");
+        rotate.add_input_pin("A");
+        rotate.add_input_pin("P");
+        rotate.set_input("A", Value::Number(std::f64::consts::FRAC_PI_2));
+        rotate.set_input(
+            "P",
+            Value::Plane(PlaneValue::from_axes(
+                [1.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0],
+            )),
+        );
+        let rotate_id = graph.add_node(rotate).unwrap();
+
+        let mut offset = Node::new(NodeId::new(2));
+        offset.guid = Some("{1a38d325-98de-455c-93f1-bca431bc1243}".to_string());
+        offset.add_input_pin("C");
+        offset.add_input_pin("D");
+        offset.set_input("D", Value::Number(0.5));
+        let offset_id = graph.add_node(offset).unwrap();
+
+        graph
+            .add_wire(Wire::new(circle_id, "C", rotate_id, "G"))
+            .unwrap();
+        graph
+            .add_wire(Wire::new(rotate_id, "G", offset_id, "C"))
+            .unwrap();
+
+        let registry = ComponentRegistry::default();
+        let result = evaluate(&graph, &registry).expect("evaluatie slaagt");
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+
+        let Some(Value::Curve(CurveValue::Circle(rotated))) =
+            result.node_outputs[&rotate_id].get("G")
+        else {
+            panic!("rotate levert een exacte cirkel");
+        };
+        assert!(rotated.center.sub_point(Point3::new(1.0, -1.0, 0.0)).length() < 1e-9);
+        assert!((rotated.radius - 2.0).abs() < 1e-9);
+
+        let Some(Value::List(curves)) = result.node_outputs[&offset_id].get("C") else {
+            panic!("offset levert een lijst curves");
+        };
+        let [Value::Curve(CurveValue::Circle(offset))] = curves.as_slice() else {
+            panic!("offset levert een exacte cirkel, kreeg {curves:?}");
+        };
+        assert_eq!(offset.center, rotated.center);
+        assert!(((offset.radius - 2.0).abs() - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn breps_reach_surface_components_and_are_meshed_for_others() {
+        let mut graph = Graph::new();
+
+        let mut plane_surface = Node::new(NodeId::new(0));
+        plane_surface.guid = Some("{439a55a5-2f9e-4f66-9de2-32f24fec2ef5}".to_string());
+        plane_surface.add_input_pin("P");
+        plane_surface.add_input_pin("X");
+        plane_surface.add_input_pin("Y");
+        plane_surface.set_input(
+            "P",
+            Value::List(vec![
+                Value::Point([0.0, 0.0, 0.0]),
+                Value::Point([1.0, 0.0, 0.0]),
+                Value::Point([0.0, 1.0, 0.0]),
+            ]),
+        );
+        plane_surface.set_input("X", Value::Number(2.0));
+        plane_surface.set_input("Y", Value::Number(3.0));
+        let plane_surface_id = graph.add_node(plane_surface).unwrap();
+
+        let mut evaluate_surface = Node::new(NodeId::new(1));
+        evaluate_surface.guid = Some("{353b206e-bde5-4f02-a913-b3b8a977d4b9}".to_string());
+        evaluate_surface.add_input_pin("S");
+        evaluate_surface.add_input_pin("uv");
+        evaluate_surface.set_input("uv", Value::Point([1.0, 1.0, 0.0]));
+        let evaluate_surface_id = graph.add_node(evaluate_surface).unwrap();
+
+        let mut area = Node::new(NodeId::new(2));
+        area.guid = Some("{2e205f24-9279-47b2-b414-d06dcd0b21a7}".to_string());
+        area.add_input_pin("G");
+        let area_id = graph.add_node(area).unwrap();
+
+        graph
+            .add_wire(Wire::new(plane_surface_id, "P", evaluate_surface_id, "S"))
+            .unwrap();
+        graph
+            .add_wire(Wire::new(plane_surface_id, "P", area_id, "G"))
+            .unwrap();
+
+        let registry = ComponentRegistry::default();
+        let result = evaluate(&graph, &registry).expect("evaluatie slaagt");
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+
+        assert!(matches!(
+            result.node_outputs[&plane_surface_id].get("P"),
+            Some(Value::Brep(_))
+        ));
+        assert_eq!(
+            result.node_outputs[&evaluate_surface_id].get("P"),
+            Some(&Value::Point([1.0, 1.5, 0.0]))
+        );
+        let Some(Value::Number(area)) = result.node_outputs[&area_id].get("A") else {
+            panic!("area levert een getal");
+        };
+        assert!((area - 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn applies_input_and_output_pin_modifiers() {
+        let mut graph = Graph::new();
+
+        let mut relay = Node::new(NodeId::new(0));
+        relay.guid = Some("b6236720-8d88-4289-93c3-ac4c99f9b97b".to_string());
+        relay.add_input_pin("Input");
+        relay.set_input("Input", Value::List(numbers(&[1.0, 2.0, 3.0])));
+        relay.insert_meta("input.Input.reverse", true);
+        relay.insert_meta("output.Output.mapping", MetaValue::Integer(2));
+        relay.insert_meta("output.Output.simplify", true);
+        let relay_id = graph.add_node(relay).unwrap();
+
+        let registry = ComponentRegistry::default();
+        let result = evaluate(&graph, &registry).expect("evaluatie slaagt");
+        let Some(Value::Tree(tree)) = result.node_outputs[&relay_id].get("Output") else {
+            panic!("relay levert een datatree");
+        };
+
+        let paths: Vec<String> = tree.paths().map(ToString::to_string).collect();
+        assert_eq!(paths, ["{0}", "{1}", "{2}"]);
+        assert_eq!(tree.flattened_items(), numbers(&[3.0, 2.0, 1.0]));
+    }
 }
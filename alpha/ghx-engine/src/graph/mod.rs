@@ -6,7 +6,9 @@ use std::fmt;
 pub mod evaluator;
 pub mod internal_expression;
 pub mod node;
+pub mod report;
 pub mod topo;
+pub mod tree;
 pub mod value;
 pub mod wire;
 
@@ -79,6 +81,47 @@ impl Graph {
         Ok(())
     }
 
+    /// Voeg een node in op een vaste positie in de nodelijst en behoud het id,
+    /// ook als dat id 0 is. Bedoeld om een verwijderde node terug te zetten.
+    pub fn insert_node(&mut self, index: usize, node: Node) -> Result<NodeId, GraphError> {
+        let id = node.id;
+        if self.node_index.contains_key(&id) {
+            return Err(GraphError::DuplicateNode(id));
+        }
+
+        let index = index.min(self.nodes.len());
+        self.next_id = self.next_id.max(id.0 + 1);
+        self.nodes.insert(index, node);
+        self.rebuild_indices();
+        Ok(id)
+    }
+
+    /// Verwijder een node samen met alle verbindingen van en naar die node.
+    pub fn remove_node(&mut self, id: NodeId) -> Result<RemovedNode, GraphError> {
+        let index = *self.node_index.get(&id).ok_or(GraphError::UnknownNode(id))?;
+
+        let (wires, kept): (Vec<Wire>, Vec<Wire>) = std::mem::take(&mut self.wires)
+            .into_iter()
+            .partition(|wire| wire.from_node == id || wire.to_node == id);
+        self.wires = kept;
+
+        let node = self.nodes.remove(index);
+        self.rebuild_indices();
+        Ok(RemovedNode { index, node, wires })
+    }
+
+    /// Verwijder één verbinding. Geeft `false` terug als de verbinding niet
+    /// bestond.
+    pub fn remove_wire(&mut self, wire: &Wire) -> bool {
+        match self.wires.iter().position(|existing| existing == wire) {
+            Some(index) => {
+                self.wires.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
     #[must_use]
     pub fn node(&self, id: NodeId) -> Option<&Node> {
         self.node_index
@@ -117,6 +160,12 @@ impl Graph {
             .map(|ids| ids.as_slice())
     }
 
+    /// Het id dat [`Graph::add_node`] aan de volgende node zonder id geeft.
+    #[must_use]
+    pub fn next_node_id(&self) -> NodeId {
+        NodeId::new(self.next_id)
+    }
+
     #[must_use]
     pub fn node_count(&self) -> usize {
         self.nodes.len()
@@ -127,12 +176,44 @@ impl Graph {
         self.wires.len()
     }
 
+    fn rebuild_indices(&mut self) {
+        self.node_index.clear();
+        self.guid_index.clear();
+        self.name_index.clear();
+
+        for idx in 0..self.nodes.len() {
+            let id = self.nodes[idx].id;
+            self.node_index.insert(id, idx);
+            if let Some(guid) = self.nodes[idx].guid.clone() {
+                self.guid_index
+                    .entry(normalize_guid(&guid))
+                    .or_default()
+                    .push(id);
+            }
+            if let Some(name) = self.nodes[idx].name.clone() {
+                self.index_name(id, &name);
+            }
+            if let Some(nickname) = self.nodes[idx].nickname.clone() {
+                self.index_name(id, &nickname);
+            }
+        }
+    }
+
     fn index_name(&mut self, id: NodeId, name: &str) {
         let key = normalize_name(name);
         self.name_index.entry(key).or_default().push(id);
     }
 }
 
+/// Een uit de graph verwijderde node met zijn oorspronkelijke positie en
+/// verbindingen, zodat hij teruggezet kan worden.
+#[derive(Debug, Clone)]
+pub struct RemovedNode {
+    pub index: usize,
+    pub node: Node,
+    pub wires: Vec<Wire>,
+}
+
 /// Fouten die kunnen optreden bij het opbouwen van de graph.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum GraphError {
@@ -206,4 +287,37 @@ mod tests {
         assert!(graph.add_wire(wire).is_ok());
         assert_eq!(graph.wire_count(), 1);
     }
+
+    #[test]
+    fn removing_and_restoring_node_keeps_id_and_wires() {
+        let mut graph = Graph::new();
+        let mut first = Node::new(NodeId::new(0));
+        first.nickname = Some("Eerste".to_owned());
+        graph.add_node(first).unwrap();
+        graph.add_node(Node::new(NodeId::new(1))).unwrap();
+        graph.add_node(Node::new(NodeId::new(2))).unwrap();
+        graph
+            .add_wire(Wire::new(NodeId::new(0), "A", NodeId::new(1), "B"))
+            .unwrap();
+        graph
+            .add_wire(Wire::new(NodeId::new(1), "A", NodeId::new(2), "B"))
+            .unwrap();
+
+        let removed = graph.remove_node(NodeId::new(0)).unwrap();
+        assert_eq!(removed.index, 0);
+        assert_eq!(removed.wires.len(), 1);
+        assert_eq!(graph.wire_count(), 1);
+        assert!(graph.nodes_with_name("eerste").is_none());
+        assert!(graph.node(NodeId::new(2)).is_some());
+
+        graph.insert_node(removed.index, removed.node).unwrap();
+        for wire in removed.wires {
+            graph.add_wire(wire).unwrap();
+        }
+        assert_eq!(graph.nodes()[0].id, NodeId::new(0));
+        assert_eq!(graph.nodes_with_name("eerste").unwrap(), [NodeId::new(0)]);
+        assert_eq!(graph.wire_count(), 2);
+        assert!(graph.remove_wire(&Wire::new(NodeId::new(0), "A", NodeId::new(1), "B")));
+        assert!(!graph.remove_wire(&Wire::new(NodeId::new(0), "A", NodeId::new(1), "B")));
+    }
 }
@@ -175,6 +175,17 @@ impl Node {
         self.inputs.insert(pin_string, value);
     }
 
+    /// Verwijder een inputpin met zijn opgeslagen waarde.
+    pub fn remove_input_pin(&mut self, pin: &str) -> Option<Value> {
+        self.input_order.retain(|existing| existing != pin);
+        self.inputs.remove(pin)
+    }
+
+    /// Verwijder de opgeslagen input-waarde; de pin zelf blijft bestaan.
+    pub fn remove_input(&mut self, pin: &str) -> Option<Value> {
+        self.inputs.remove(pin)
+    }
+
     /// Haal een verwijzing naar een input op.
     pub fn input(&self, pin: &str) -> Option<&Value> {
         self.inputs.get(pin)
@@ -218,6 +229,12 @@ impl Node {
         &self.input_order
     }
 
+    /// Geeft de originele volgorde van outputpinnen terug.
+    #[must_use]
+    pub fn output_order(&self) -> &[String] {
+        &self.output_order
+    }
+
     /// Registreer een interne expressie voor een inputpin.
     pub fn set_input_expression<S: Into<String>, T: Into<String>>(
         &mut self,
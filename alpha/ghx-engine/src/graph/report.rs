@@ -0,0 +1,267 @@
+//! Gestructureerd fouten- en waarschuwingenrapport voor de UI.
+//!
+//! Elke fout of waarschuwing wordt een [`ReportEntry`] met de context van de
+//! betrokken node (id, GUID, naam, nickname), de pin en het soort fout. Fouten
+//! die ontstaan doordat een afhankelijke node faalde, krijgen de keten van
+//! upstream oorzaken mee tot aan de oorspronkelijke fout, zodat de UI zowel
+//! de rode ballon op de node als de werkelijke bron kan tonen.
+
+use std::collections::HashSet;
+
+use serde::Serialize;
+
+use super::Graph;
+use super::evaluator::{EvaluationError, EvaluationWarning};
+use super::node::NodeId;
+
+/// Alle fouten en waarschuwingen van een evaluatie.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct EvaluationReport {
+    pub errors: Vec<ReportEntry>,
+    pub warnings: Vec<ReportEntry>,
+}
+
+/// Eén fout of waarschuwing met nodecontext.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReportEntry {
+    /// Stabiele naam van het soort, bv. `ComponentFailed` of `EmptyInput`.
+    pub kind: &'static str,
+    pub message: String,
+    pub node_id: Option<usize>,
+    pub guid: Option<String>,
+    pub name: Option<String>,
+    pub nickname: Option<String>,
+    /// De input-pin van de node waarop de melding betrekking heeft.
+    pub pin: Option<String>,
+    /// De naam van het component dat faalde.
+    pub component: Option<String>,
+    /// Upstream oorzaken, van de directe afhankelijkheid tot de bron.
+    pub causes: Vec<ReportCause>,
+}
+
+/// Een upstream node in de oorzakenketen van een fout.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReportCause {
+    pub node_id: usize,
+    pub guid: Option<String>,
+    pub name: Option<String>,
+    pub nickname: Option<String>,
+    /// De output-pin die geen waarde leverde.
+    pub pin: Option<String>,
+    /// Soort en melding van de fout op deze node; leeg als de node zelf
+    /// slaagde maar de pin geen waarde had.
+    pub kind: Option<&'static str>,
+    pub message: Option<String>,
+}
+
+/// Bouwt het rapport voor de fouten en waarschuwingen van een evaluatie.
+#[must_use]
+pub fn build_report(
+    graph: &Graph,
+    errors: &[EvaluationError],
+    warnings: &[EvaluationWarning],
+) -> EvaluationReport {
+    EvaluationReport {
+        errors: errors
+            .iter()
+            .map(|error| error_entry(graph, errors, error))
+            .collect(),
+        warnings: warnings
+            .iter()
+            .map(|warning| warning_entry(graph, warning))
+            .collect(),
+    }
+}
+
+fn error_entry(graph: &Graph, errors: &[EvaluationError], error: &EvaluationError) -> ReportEntry {
+    let mut entry = ReportEntry::for_node(graph, error.kind(), error.to_string(), error.node_id());
+
+    match error {
+        EvaluationError::ComponentNotFound {
+            guid,
+            name,
+            nickname,
+            ..
+        } => {
+            entry.guid.clone_from(guid);
+            entry.name.clone_from(name);
+            entry.nickname.clone_from(nickname);
+        }
+        EvaluationError::MissingInput { pin, .. }
+        | EvaluationError::InternalExpression { pin, .. } => {
+            entry.pin = Some(pin.clone());
+        }
+        EvaluationError::MissingDependencyOutput {
+            node_id,
+            dependency,
+            pin,
+        } => {
+            entry.pin = graph
+                .wires()
+                .iter()
+                .find(|wire| {
+                    wire.from_node == *dependency
+                        && wire.from_pin.0 == *pin
+                        && wire.to_node == *node_id
+                })
+                .map(|wire| wire.to_pin.0.clone());
+            entry.causes = cause_chain(graph, errors, *dependency, pin);
+        }
+        EvaluationError::ComponentFailed { component, .. } => {
+            entry.component = Some(component.clone());
+        }
+        EvaluationError::Topology(_) | EvaluationError::UnknownNode(_) => {}
+    }
+
+    entry
+}
+
+fn warning_entry(graph: &Graph, warning: &EvaluationWarning) -> ReportEntry {
+    let mut entry = ReportEntry::for_node(
+        graph,
+        warning.kind(),
+        warning.to_string(),
+        Some(warning.node_id()),
+    );
+    match warning {
+        EvaluationWarning::EmptyInput { pin, .. } => entry.pin = Some(pin.clone()),
+        EvaluationWarning::Component { component, .. } => {
+            entry.component = Some(component.clone());
+        }
+    }
+    entry
+}
+
+/// Volgt gefaalde afhankelijkheden stroomopwaarts tot een node zonder
+/// afhankelijkheidsfout.
+fn cause_chain(
+    graph: &Graph,
+    errors: &[EvaluationError],
+    dependency: NodeId,
+    pin: &str,
+) -> Vec<ReportCause> {
+    let mut chain = Vec::new();
+    let mut visited = HashSet::new();
+    let mut current = Some((dependency, pin.to_owned()));
+
+    while let Some((node_id, pin)) = current.take() {
+        if !visited.insert(node_id) {
+            break;
+        }
+
+        let node = graph.node(node_id);
+        let error = errors.iter().find(|error| error.node_id() == Some(node_id));
+        chain.push(ReportCause {
+            node_id: node_id.0,
+            guid: node.and_then(|node| node.guid.clone()),
+            name: node.and_then(|node| node.name.clone()),
+            nickname: node.and_then(|node| node.nickname.clone()),
+            pin: Some(pin),
+            kind: error.map(EvaluationError::kind),
+            message: error.map(ToString::to_string),
+        });
+
+        if let Some(EvaluationError::MissingDependencyOutput {
+            dependency, pin, ..
+        }) = error
+        {
+            current = Some((*dependency, pin.clone()));
+        }
+    }
+
+    chain
+}
+
+impl ReportEntry {
+    fn for_node(
+        graph: &Graph,
+        kind: &'static str,
+        message: String,
+        node_id: Option<NodeId>,
+    ) -> Self {
+        let node = node_id.and_then(|id| graph.node(id));
+        Self {
+            kind,
+            message,
+            node_id: node_id.map(|id| id.0),
+            guid: node.and_then(|node| node.guid.clone()),
+            name: node.and_then(|node| node.name.clone()),
+            nickname: node.and_then(|node| node.nickname.clone()),
+            pin: None,
+            component: None,
+            causes: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::build_report;
+    use crate::components::ComponentRegistry;
+    use crate::graph::Graph;
+    use crate::graph::evaluator::evaluate;
+    use crate::graph::node::{Node, NodeId};
+    use crate::graph::value::Value;
+    use crate::graph::wire::Wire;
+
+    const LINE_GUID: &str = "{4c4e56eb-2f04-43f9-95a3-cc46a14f495a}";
+
+    fn line_node(id: usize, nickname: &str) -> Node {
+        let mut node = Node::new(NodeId::new(id));
+        node.guid = Some(LINE_GUID.to_string());
+        node.nickname = Some(nickname.to_string());
+        node.add_input_pin("A");
+        node.add_input_pin("B");
+        node.set_input("B", Value::Point([1.0, 0.0, 0.0]));
+        node
+    }
+
+    #[test]
+    fn reports_upstream_cause_chain_with_node_context() {
+        let mut graph = Graph::new();
+        let mut missing = Node::new(NodeId::new(1));
+        missing.guid = Some("{00000000-0000-0000-0000-000000000001}".to_string());
+        missing.nickname = Some("Bron".to_string());
+        let missing_id = graph.add_node(missing).unwrap();
+        let first_id = graph.add_node(line_node(2, "Eerste")).unwrap();
+        let second_id = graph.add_node(line_node(3, "Tweede")).unwrap();
+        graph
+            .add_wire(Wire::new(missing_id, "P", first_id, "A"))
+            .unwrap();
+        graph
+            .add_wire(Wire::new(first_id, "L", second_id, "A"))
+            .unwrap();
+
+        let result = evaluate(&graph, &ComponentRegistry::default()).unwrap();
+        let report = build_report(&graph, &result.errors, &result.warnings);
+
+        let root = report
+            .errors
+            .iter()
+            .find(|entry| entry.node_id == Some(missing_id.0))
+            .expect("missing component entry");
+        assert_eq!(root.kind, "ComponentNotFound");
+        assert_eq!(root.nickname.as_deref(), Some("Bron"));
+
+        let downstream = report
+            .errors
+            .iter()
+            .find(|entry| entry.node_id == Some(second_id.0))
+            .expect("downstream entry");
+        assert_eq!(downstream.kind, "MissingDependencyOutput");
+        assert_eq!(downstream.guid.as_deref(), Some(LINE_GUID));
+        assert_eq!(downstream.pin.as_deref(), Some("A"));
+        let chain: Vec<_> = downstream
+            .causes
+            .iter()
+            .map(|cause| (cause.node_id, cause.pin.as_deref(), cause.kind))
+            .collect();
+        assert_eq!(
+            chain,
+            [
+                (first_id.0, Some("L"), Some("MissingDependencyOutput")),
+                (missing_id.0, Some("P"), Some("ComponentNotFound")),
+            ]
+        );
+    }
+}
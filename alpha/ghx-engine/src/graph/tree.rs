@@ -0,0 +1,443 @@
+//! Grasshopper datatrees: takken met `{0;1;2}`-paden die als één waarde door de
+//! graph reizen.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use super::value::Value;
+
+/// Pad van een tak binnen een datatree, zoals `{0;1;2}`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TreePath(Vec<usize>);
+
+impl TreePath {
+    #[must_use]
+    pub fn new(indices: Vec<usize>) -> Self {
+        Self(indices)
+    }
+
+    /// Het standaardpad `{0}` dat Grasshopper voor losse lijsten gebruikt.
+    #[must_use]
+    pub fn root() -> Self {
+        Self(vec![0])
+    }
+
+    /// Leest een pad in de vorm `{0;1;2}`; de accolades zijn optioneel.
+    #[must_use]
+    pub fn parse(text: &str) -> Option<Self> {
+        let trimmed = text
+            .trim()
+            .trim_start_matches('{')
+            .trim_end_matches('}')
+            .trim();
+        if trimmed.is_empty() {
+            return Some(Self::default());
+        }
+
+        trimmed
+            .split(';')
+            .map(|segment| segment.trim().parse::<usize>().ok())
+            .collect::<Option<Vec<_>>>()
+            .map(Self)
+    }
+
+    #[must_use]
+    pub fn indices(&self) -> &[usize] {
+        &self.0
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Geeft een nieuw pad terug met `index` als extra laatste element.
+    #[must_use]
+    pub fn appended(&self, index: usize) -> Self {
+        let mut indices = self.0.clone();
+        indices.push(index);
+        Self(indices)
+    }
+
+    /// Plakt de indices van `other` achter dit pad.
+    #[must_use]
+    pub fn joined(&self, other: &TreePath) -> Self {
+        let mut indices = self.0.clone();
+        indices.extend_from_slice(&other.0);
+        Self(indices)
+    }
+
+    /// Tekstweergave zonder accolades (`0;1;2`), handig voor wildcard-maskers.
+    #[must_use]
+    pub fn to_mask_text(&self) -> String {
+        self.0
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(";")
+    }
+}
+
+impl From<Vec<usize>> for TreePath {
+    fn from(indices: Vec<usize>) -> Self {
+        Self(indices)
+    }
+}
+
+impl fmt::Display for TreePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{{}}}", self.to_mask_text())
+    }
+}
+
+/// Een datatree: geordende takken met elk een lijst van items.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DataTree {
+    branches: BTreeMap<TreePath, Vec<Value>>,
+}
+
+impl DataTree {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maakt een boom met één tak op het opgegeven pad.
+    #[must_use]
+    pub fn with_branch(path: TreePath, items: Vec<Value>) -> Self {
+        let mut tree = Self::new();
+        tree.branches.insert(path, items);
+        tree
+    }
+
+    #[must_use]
+    pub fn from_branches(branches: BTreeMap<TreePath, Vec<Value>>) -> Self {
+        Self { branches }
+    }
+
+    /// Zet een willekeurige waarde om naar een datatree.
+    ///
+    /// - `Value::Tree` wordt gekopieerd;
+    /// - een platte lijst wordt één tak `{0}`;
+    /// - geneste lijsten (de oude boomrepresentatie) worden per niveau een
+    ///   padindex;
+    /// - een los item wordt tak `{0}` met één item en `Null` een lege boom.
+    #[must_use]
+    pub fn from_value(value: &Value) -> Self {
+        match value {
+            Value::Tree(tree) => tree.clone(),
+            Value::Null => Self::new(),
+            Value::List(items) if items.iter().any(|item| matches!(item, Value::List(_))) => {
+                let mut tree = Self::new();
+                for (index, item) in items.iter().enumerate() {
+                    collect_nested_branches(item, TreePath::new(vec![index]), &mut tree.branches);
+                }
+                tree
+            }
+            Value::List(items) => Self::with_branch(TreePath::root(), items.clone()),
+            other => Self::with_branch(TreePath::root(), vec![other.clone()]),
+        }
+    }
+
+    #[must_use]
+    pub fn branch(&self, path: &TreePath) -> Option<&[Value]> {
+        self.branches.get(path).map(Vec::as_slice)
+    }
+
+    /// Vervangt (of maakt) de tak op `path`.
+    pub fn insert_branch(&mut self, path: TreePath, items: Vec<Value>) {
+        self.branches.insert(path, items);
+    }
+
+    /// Voegt items achteraan toe aan de tak op `path`.
+    pub fn append(&mut self, path: TreePath, items: impl IntoIterator<Item = Value>) {
+        self.branches.entry(path).or_default().extend(items);
+    }
+
+    pub fn remove_branch(&mut self, path: &TreePath) -> Option<Vec<Value>> {
+        self.branches.remove(path)
+    }
+
+    pub fn branches(&self) -> impl Iterator<Item = (&TreePath, &Vec<Value>)> {
+        self.branches.iter()
+    }
+
+    pub fn paths(&self) -> impl Iterator<Item = &TreePath> {
+        self.branches.keys()
+    }
+
+    #[must_use]
+    pub fn into_branches(self) -> BTreeMap<TreePath, Vec<Value>> {
+        self.branches
+    }
+
+    #[must_use]
+    pub fn branch_count(&self) -> usize {
+        self.branches.len()
+    }
+
+    #[must_use]
+    pub fn item_count(&self) -> usize {
+        self.branches.values().map(Vec::len).sum()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.branches.is_empty()
+    }
+
+    /// Alle items in padvolgorde.
+    pub fn items(&self) -> impl Iterator<Item = &Value> {
+        self.branches.values().flatten()
+    }
+
+    #[must_use]
+    pub fn flattened_items(&self) -> Vec<Value> {
+        self.items().cloned().collect()
+    }
+
+    /// Voegt de takken van `other` samen met deze boom; items op hetzelfde pad
+    /// worden achteraan toegevoegd.
+    pub fn merge(&mut self, other: DataTree) {
+        for (path, items) in other.branches {
+            self.append(path, items);
+        }
+    }
+
+    /// Grasshopper "Flatten": alle items in één tak op `path`.
+    #[must_use]
+    pub fn flatten(self, path: TreePath) -> Self {
+        let items = self.branches.into_values().flatten().collect();
+        Self::with_branch(path, items)
+    }
+
+    /// Grasshopper "Graft": elk item krijgt een eigen tak `{pad;index}`.
+    #[must_use]
+    pub fn graft(self) -> Self {
+        let mut grafted = Self::new();
+        for (path, items) in self.branches {
+            if items.is_empty() {
+                grafted.branches.insert(path, items);
+                continue;
+            }
+            for (index, item) in items.into_iter().enumerate() {
+                grafted.branches.insert(path.appended(index), vec![item]);
+            }
+        }
+        grafted
+    }
+
+    /// Grasshopper "Simplify": verwijdert de padindices die alle takken
+    /// gemeenschappelijk aan het begin hebben. Elk pad behoudt minstens één
+    /// index.
+    #[must_use]
+    pub fn simplify(self) -> Self {
+        if self.branches.len() <= 1 {
+            return self;
+        }
+
+        let paths: Vec<&TreePath> = self.branches.keys().collect();
+        let first = paths[0].indices();
+        let mut common = first.len();
+        for path in &paths[1..] {
+            let shared = first
+                .iter()
+                .zip(path.indices())
+                .take_while(|(a, b)| a == b)
+                .count();
+            common = common.min(shared);
+        }
+        let shortest = paths.iter().map(|path| path.len()).min().unwrap_or(0);
+        common = common.min(shortest.saturating_sub(1));
+
+        if common == 0 {
+            return self;
+        }
+
+        let branches = self
+            .branches
+            .into_iter()
+            .map(|(path, items)| (TreePath::new(path.indices()[common..].to_vec()), items))
+            .collect();
+        Self { branches }
+    }
+
+    /// Keert de volgorde van de items binnen elke tak om.
+    #[must_use]
+    pub fn reverse_items(mut self) -> Self {
+        for items in self.branches.values_mut() {
+            items.reverse();
+        }
+        self
+    }
+
+    /// De oude geneste-lijstrepresentatie waarin elke padindex een lijstniveau is.
+    #[must_use]
+    pub fn to_nested_list(&self) -> Value {
+        if self.branches.is_empty() {
+            return Value::List(Vec::new());
+        }
+
+        let mut root = NestedNode::SubTree(BTreeMap::new());
+        for (path, items) in &self.branches {
+            root.insert(path.indices(), items.clone());
+        }
+        root.into_value()
+    }
+}
+
+impl Hash for DataTree {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for (path, items) in &self.branches {
+            path.hash(state);
+            items.hash(state);
+        }
+    }
+}
+
+impl fmt::Display for DataTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Tree [{} branches, {} items]",
+            self.branch_count(),
+            self.item_count()
+        )
+    }
+}
+
+fn collect_nested_branches(
+    value: &Value,
+    path: TreePath,
+    branches: &mut BTreeMap<TreePath, Vec<Value>>,
+) {
+    match value {
+        Value::List(items) if items.iter().any(|item| matches!(item, Value::List(_))) => {
+            for (index, item) in items.iter().enumerate() {
+                collect_nested_branches(item, path.appended(index), branches);
+            }
+        }
+        Value::List(items) => {
+            branches.insert(path, items.clone());
+        }
+        other => {
+            branches.insert(path, vec![other.clone()]);
+        }
+    }
+}
+
+enum NestedNode {
+    Branch(Vec<Value>),
+    SubTree(BTreeMap<usize, NestedNode>),
+}
+
+impl NestedNode {
+    fn insert(&mut self, path: &[usize], items: Vec<Value>) {
+        let Some((&index, rest)) = path.split_first() else {
+            *self = Self::Branch(items);
+            return;
+        };
+
+        if let Self::Branch(_) = self {
+            *self = Self::SubTree(BTreeMap::new());
+        }
+        if let Self::SubTree(children) = self {
+            children
+                .entry(index)
+                .or_insert_with(|| Self::SubTree(BTreeMap::new()))
+                .insert(rest, items);
+        }
+    }
+
+    fn into_value(self) -> Value {
+        match self {
+            Self::Branch(items) => Value::List(items),
+            Self::SubTree(children) => {
+                let Some(&max_index) = children.keys().max() else {
+                    return Value::List(Vec::new());
+                };
+                let mut children = children;
+                let list = (0..=max_index)
+                    .map(|index| {
+                        children
+                            .remove(&index)
+                            .map_or_else(|| Value::List(Vec::new()), Self::into_value)
+                    })
+                    .collect();
+                Value::List(list)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DataTree, TreePath};
+    use crate::graph::value::Value;
+
+    fn numbers(values: &[f64]) -> Vec<Value> {
+        values.iter().copied().map(Value::Number).collect()
+    }
+
+    #[test]
+    fn path_roundtrips_through_text() {
+        let path = TreePath::parse("{0;12;3}").expect("geldig pad");
+        assert_eq!(path.indices(), &[0, 12, 3]);
+        assert_eq!(path.to_string(), "{0;12;3}");
+        assert_eq!(TreePath::parse("1; 2"), Some(TreePath::new(vec![1, 2])));
+        assert!(TreePath::parse("{a;1}").is_none());
+    }
+
+    #[test]
+    fn flat_list_becomes_single_branch() {
+        let tree = DataTree::from_value(&Value::List(numbers(&[1.0, 2.0, 3.0])));
+        assert_eq!(tree.branch_count(), 1);
+        assert_eq!(tree.branch(&TreePath::root()).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn nested_lists_map_to_paths() {
+        let value = Value::List(vec![
+            Value::List(numbers(&[1.0, 2.0])),
+            Value::List(numbers(&[3.0])),
+        ]);
+        let tree = DataTree::from_value(&value);
+        assert_eq!(tree.branch_count(), 2);
+        assert_eq!(tree.branch(&TreePath::new(vec![1])), Some(&numbers(&[3.0])[..]));
+        assert_eq!(tree.to_nested_list(), value);
+    }
+
+    #[test]
+    fn graft_flatten_and_simplify() {
+        let tree = DataTree::with_branch(TreePath::new(vec![0, 2]), numbers(&[1.0, 2.0]));
+
+        let grafted = tree.clone().graft();
+        let paths: Vec<String> = grafted.paths().map(ToString::to_string).collect();
+        assert_eq!(paths, ["{0;2;0}", "{0;2;1}"]);
+
+        let simplified = grafted.clone().simplify();
+        let paths: Vec<String> = simplified.paths().map(ToString::to_string).collect();
+        assert_eq!(paths, ["{0}", "{1}"]);
+
+        let flattened = grafted.flatten(TreePath::root());
+        assert_eq!(flattened.branch_count(), 1);
+        assert_eq!(flattened.flattened_items(), numbers(&[1.0, 2.0]));
+    }
+
+    #[test]
+    fn merge_appends_items_on_shared_paths() {
+        let mut tree = DataTree::with_branch(TreePath::root(), numbers(&[1.0]));
+        let mut other = DataTree::with_branch(TreePath::root(), numbers(&[2.0]));
+        other.insert_branch(TreePath::new(vec![1]), numbers(&[3.0]));
+        tree.merge(other);
+
+        assert_eq!(tree.branch(&TreePath::root()), Some(&numbers(&[1.0, 2.0])[..]));
+        assert_eq!(tree.item_count(), 3);
+    }
+}
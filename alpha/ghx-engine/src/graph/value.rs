@@ -8,7 +8,16 @@ use std::hash::{Hash, Hasher};
 use num_complex::Complex;
 use time::PrimitiveDateTime;
 
+use crate::geom::{
+    Arc3, Circle3, ConeSurface, Curve3, CurveTessellationOptions, CylinderSurface, Ellipse3,
+    GeomMesh, Line3, NurbsCurve3, NurbsSurface, PlaneSurface, Point3, Polyline3, SphereSurface,
+    Surface, SurfaceCacheKey, SurfaceTessellationOptions, Tolerance, TorusSurface, Transform, TrimRegion,
+    UvPoint, Vec3, choose_surface_grid_counts, mesh_surface, mesh_surface_adaptive,
+    tessellate_curve_adaptive_points, triangulate_trim_region_with_steiner_points,
+};
+
 use super::node::{MetaLookupExt, MetaMap, MetaValue};
+use super::tree::DataTree;
 
 /// Een complex getal, aliased van `num_complex::Complex`.
 pub type ComplexValue = Complex<f64>;
@@ -1492,6 +1501,678 @@ impl From<MeshDiagnostics> for Value {
     }
 }
 
+// ============================================================================
+// CurveValue - Analytic curves carried through the graph
+// ============================================================================
+
+/// An exact curve as produced by the curve components.
+///
+/// Curves keep their analytic definition (domain, closedness, exact
+/// derivatives) while flowing through the graph. They are only turned into
+/// point lists when a consumer needs a polyline: at display/export time or
+/// for components that have not been ported to exact curves yet.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CurveValue {
+    Line(Line3),
+    Arc(Arc3),
+    Circle(Circle3),
+    Ellipse(Ellipse3),
+    Nurbs(NurbsCurve3),
+    Polyline(Polyline3),
+}
+
+impl CurveValue {
+    /// Tessellation settings that match the point lists the curve components
+    /// produced before curves became first-class values.
+    pub const DEFAULT_TESSELLATION: CurveTessellationOptions =
+        CurveTessellationOptions::new(0.02, 32);
+
+    /// Short type name, used in `Display` output.
+    #[must_use]
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::Line(_) => "Line",
+            Self::Arc(_) => "Arc",
+            Self::Circle(_) => "Circle",
+            Self::Ellipse(_) => "Ellipse",
+            Self::Nurbs(_) => "Nurbs",
+            Self::Polyline(_) => "Polyline",
+        }
+    }
+
+    fn as_curve(&self) -> &dyn Curve3 {
+        match self {
+            Self::Line(curve) => curve,
+            Self::Arc(curve) => curve,
+            Self::Circle(curve) => curve,
+            Self::Ellipse(curve) => curve,
+            Self::Nurbs(curve) => curve,
+            Self::Polyline(curve) => curve,
+        }
+    }
+
+    /// Start point of the curve.
+    #[must_use]
+    pub fn start_point(&self) -> [f64; 3] {
+        let (t0, _) = self.domain();
+        point_to_array(self.point_at(t0))
+    }
+
+    /// End point of the curve.
+    #[must_use]
+    pub fn end_point(&self) -> [f64; 3] {
+        let (_, t1) = self.domain();
+        point_to_array(self.point_at(t1))
+    }
+
+    /// Converts the curve to a polyline.
+    ///
+    /// Lines and polylines return their exact vertices; other curves are
+    /// tessellated adaptively. Closed curves repeat their first point at the
+    /// end so the result can be drawn as a line strip.
+    #[must_use]
+    pub fn tessellate(&self, options: CurveTessellationOptions) -> Vec<[f64; 3]> {
+        let mut points: Vec<[f64; 3]> = match self {
+            Self::Line(line) => return vec![point_to_array(line.start), point_to_array(line.end)],
+            Self::Polyline(polyline) => polyline.points().iter().copied().map(point_to_array).collect(),
+            Self::Arc(curve) => tessellated(curve, options),
+            Self::Circle(curve) => tessellated(curve, options),
+            Self::Ellipse(curve) => tessellated(curve, options),
+            Self::Nurbs(curve) => tessellated(curve, options),
+        };
+
+        if self.is_closed() && points.len() > 1 && points.first() != points.last() {
+            let first = points[0];
+            points.push(first);
+        }
+        points
+    }
+
+    /// Applies an affine point map to the curve.
+    ///
+    /// Lines, polylines and NURBS curves map their defining points. Circles,
+    /// arcs and ellipses stay exact as long as the map keeps their axes
+    /// perpendicular; a circle scaled unevenly becomes an ellipse. Any other
+    /// result is tessellated first and returned as a mapped polyline.
+    #[must_use]
+    pub fn map_points(&self, mut point_fn: impl FnMut([f64; 3]) -> [f64; 3]) -> Self {
+        let mut map = |point: Point3| Point3::from_array(point_fn(point.to_array()));
+        match self {
+            Self::Line(line) => return Self::Line(Line3::new(map(line.start), map(line.end))),
+            Self::Polyline(polyline) => {
+                let points = polyline.points().iter().map(|point| map(*point)).collect();
+                if let Ok(mapped) = Polyline3::new(points, polyline.is_closed()) {
+                    return Self::Polyline(mapped);
+                }
+            }
+            Self::Nurbs(curve) => {
+                let mut mapped = curve.clone();
+                for point in &mut mapped.control_points {
+                    *point = map(*point);
+                }
+                return Self::Nurbs(mapped);
+            }
+            Self::Circle(circle) => {
+                let center = map(circle.center);
+                let x = map(circle.center.add_vec(circle.x_axis)).sub_point(center);
+                let y = map(circle.center.add_vec(circle.y_axis)).sub_point(center);
+                match mapped_axes(x, y) {
+                    Some((x_axis, y_axis, sx, sy)) if is_uniform(sx, sy) => {
+                        return Self::Circle(Circle3 {
+                            center,
+                            x_axis,
+                            y_axis,
+                            radius: circle.radius * sx,
+                        });
+                    }
+                    Some((x_axis, y_axis, sx, sy)) => {
+                        return Self::Ellipse(Ellipse3 {
+                            center,
+                            x_axis,
+                            y_axis,
+                            radius_x: circle.radius * sx,
+                            radius_y: circle.radius * sy,
+                        });
+                    }
+                    None => {}
+                }
+            }
+            Self::Arc(arc) => {
+                let center = map(arc.center);
+                let x = map(arc.center.add_vec(arc.x_axis)).sub_point(center);
+                let y = map(arc.center.add_vec(arc.y_axis)).sub_point(center);
+                let uniform = mapped_axes(x, y).filter(|&(_, _, sx, sy)| is_uniform(sx, sy));
+                if let Some((x_axis, y_axis, sx, _)) = uniform {
+                    return Self::Arc(Arc3 {
+                        center,
+                        x_axis,
+                        y_axis,
+                        radius: arc.radius * sx,
+                        ..*arc
+                    });
+                }
+            }
+            Self::Ellipse(ellipse) => {
+                let center = map(ellipse.center);
+                let x = map(ellipse.center.add_vec(ellipse.x_axis)).sub_point(center);
+                let y = map(ellipse.center.add_vec(ellipse.y_axis)).sub_point(center);
+                if let Some((x_axis, y_axis, sx, sy)) = mapped_axes(x, y) {
+                    return Self::Ellipse(Ellipse3 {
+                        center,
+                        x_axis,
+                        y_axis,
+                        radius_x: ellipse.radius_x * sx,
+                        radius_y: ellipse.radius_y * sy,
+                    });
+                }
+            }
+        }
+
+        let closed = self.is_closed();
+        let points = self
+            .tessellate(Self::DEFAULT_TESSELLATION)
+            .into_iter()
+            .map(|point| map(Point3::from_array(point)))
+            .collect();
+        match Polyline3::new(points, closed) {
+            Ok(polyline) => Self::Polyline(polyline),
+            Err(_) => self.clone(),
+        }
+    }
+
+    /// Representation as used before curves were first-class values: a
+    /// `CurveLine` for lines and a list of points for everything else.
+    #[must_use]
+    pub fn to_polyline_value(&self) -> Value {
+        if let Self::Line(line) = self {
+            return Value::CurveLine {
+                p1: point_to_array(line.start),
+                p2: point_to_array(line.end),
+            };
+        }
+        Value::List(
+            self.tessellate(Self::DEFAULT_TESSELLATION)
+                .into_iter()
+                .map(Value::Point)
+                .collect(),
+        )
+    }
+}
+
+impl Curve3 for CurveValue {
+    fn point_at(&self, t: f64) -> Point3 {
+        self.as_curve().point_at(t)
+    }
+
+    fn domain(&self) -> (f64, f64) {
+        self.as_curve().domain()
+    }
+
+    fn is_closed(&self) -> bool {
+        match self {
+            // NurbsCurve3 only reports closedness through an explicit tolerance.
+            Self::Nurbs(curve) => curve.is_closed_with_tol(Tolerance::default()),
+            other => other.as_curve().is_closed(),
+        }
+    }
+
+    fn derivative_at(&self, t: f64) -> Vec3 {
+        self.as_curve().derivative_at(t)
+    }
+
+    fn second_derivative_at(&self, t: f64) -> Vec3 {
+        self.as_curve().second_derivative_at(t)
+    }
+
+    fn is_arc_length_parameterized(&self) -> bool {
+        self.as_curve().is_arc_length_parameterized()
+    }
+
+    fn total_arc_length(&self) -> Option<f64> {
+        match self {
+            Self::Line(line) => Some(line.direction().length()),
+            Self::Arc(arc) => Some(arc.radius * arc.sweep_angle.abs()),
+            Self::Circle(circle) => Some(std::f64::consts::TAU * circle.radius),
+            other => other.as_curve().total_arc_length(),
+        }
+    }
+}
+
+impl fmt::Display for CurveValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [x0, y0, z0] = self.start_point();
+        let [x1, y1, z1] = self.end_point();
+        write!(
+            f,
+            "{} [{},{},{}] to [{},{},{}]",
+            self.type_name(),
+            x0,
+            y0,
+            z0,
+            x1,
+            y1,
+            z1
+        )
+    }
+}
+
+fn tessellated(curve: &impl Curve3, options: CurveTessellationOptions) -> Vec<[f64; 3]> {
+    tessellate_curve_adaptive_points(curve, options)
+        .into_iter()
+        .map(point_to_array)
+        .collect()
+}
+
+/// Unit axes and scale factors of two mapped, originally perpendicular unit
+/// axes, or `None` if the map sheared or collapsed them.
+fn mapped_axes(x: Vec3, y: Vec3) -> Option<(Vec3, Vec3, f64, f64)> {
+    let (sx, sy) = (x.length(), y.length());
+    if sx <= 1e-12 || sy <= 1e-12 || x.dot(y).abs() > 1e-9 * sx * sy {
+        return None;
+    }
+    Some((x.div_scalar(sx), y.div_scalar(sy), sx, sy))
+}
+
+fn is_uniform(sx: f64, sy: f64) -> bool {
+    (sx - sy).abs() <= 1e-9 * sx.max(sy)
+}
+
+fn point_to_array(point: Point3) -> [f64; 3] {
+    [point.x, point.y, point.z]
+}
+
+// ============================================================================
+// BrepValue - Analytic surfaces with trim loops
+// ============================================================================
+
+/// An analytic surface as produced by the surface components.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SurfaceValue {
+    Plane(PlaneSurface),
+    Cylinder(CylinderSurface),
+    Cone(ConeSurface),
+    Sphere(SphereSurface),
+    Torus(TorusSurface),
+    Nurbs(NurbsSurface),
+}
+
+impl SurfaceValue {
+    /// Short type name, used in `Display` output.
+    #[must_use]
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::Plane(_) => "Plane",
+            Self::Cylinder(_) => "Cylinder",
+            Self::Cone(_) => "Cone",
+            Self::Sphere(_) => "Sphere",
+            Self::Torus(_) => "Torus",
+            Self::Nurbs(_) => "Nurbs",
+        }
+    }
+
+    fn as_surface(&self) -> &dyn Surface {
+        match self {
+            Self::Plane(surface) => surface,
+            Self::Cylinder(surface) => surface,
+            Self::Cone(surface) => surface,
+            Self::Sphere(surface) => surface,
+            Self::Torus(surface) => surface,
+            Self::Nurbs(surface) => surface,
+        }
+    }
+}
+
+impl Surface for SurfaceValue {
+    fn point_at(&self, u: f64, v: f64) -> Point3 {
+        self.as_surface().point_at(u, v)
+    }
+
+    fn domain_u(&self) -> (f64, f64) {
+        self.as_surface().domain_u()
+    }
+
+    fn domain_v(&self) -> (f64, f64) {
+        self.as_surface().domain_v()
+    }
+
+    fn is_u_closed(&self) -> bool {
+        self.as_surface().is_u_closed()
+    }
+
+    fn is_v_closed(&self) -> bool {
+        self.as_surface().is_v_closed()
+    }
+
+    fn pole_v_start(&self) -> bool {
+        self.as_surface().pole_v_start()
+    }
+
+    fn pole_v_end(&self) -> bool {
+        self.as_surface().pole_v_end()
+    }
+
+    fn partial_derivatives_at(&self, u: f64, v: f64) -> (Vec3, Vec3) {
+        self.as_surface().partial_derivatives_at(u, v)
+    }
+
+    fn normal_at(&self, u: f64, v: f64) -> Option<Vec3> {
+        self.as_surface().normal_at(u, v)
+    }
+
+    fn second_partial_derivatives_at(&self, u: f64, v: f64) -> (Vec3, Vec3, Vec3) {
+        self.as_surface().second_partial_derivatives_at(u, v)
+    }
+
+    fn cache_key(&self) -> SurfaceCacheKey {
+        self.as_surface().cache_key()
+    }
+}
+
+/// One face of a [`BrepValue`]: an analytic surface, optionally trimmed by
+/// loops in its `(u, v)` domain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BrepFace {
+    pub surface: SurfaceValue,
+    /// Trim loops in the parameter space of `surface`; `None` means the
+    /// full (untrimmed) domain.
+    pub trim: Option<TrimRegion>,
+}
+
+impl BrepFace {
+    /// An untrimmed face covering the whole surface domain.
+    #[must_use]
+    pub fn new(surface: SurfaceValue) -> Self {
+        Self {
+            surface,
+            trim: None,
+        }
+    }
+
+    /// A face restricted to `trim`.
+    #[must_use]
+    pub fn trimmed(surface: SurfaceValue, trim: TrimRegion) -> Self {
+        Self {
+            surface,
+            trim: Some(trim),
+        }
+    }
+
+    /// Whether `(u, v)` lies on the visible part of the face.
+    #[must_use]
+    pub fn contains_uv(&self, u: f64, v: f64) -> bool {
+        let (u0, u1) = self.surface.domain_u();
+        let (v0, v1) = self.surface.domain_v();
+        let tol = Tolerance::default();
+        let in_domain = u >= u0 - tol.eps && u <= u1 + tol.eps && v >= v0 - tol.eps && v <= v1 + tol.eps;
+        match &self.trim {
+            Some(region) => in_domain && region.contains(UvPoint::new(u, v), tol),
+            None => in_domain,
+        }
+    }
+
+    /// Meshes the face. Planar faces use a single quad; curved faces are
+    /// refined adaptively according to `options`.
+    #[must_use]
+    pub fn mesh(&self, options: SurfaceTessellationOptions) -> GeomMesh {
+        let planar = matches!(self.surface, SurfaceValue::Plane(_));
+        match &self.trim {
+            None if planar => mesh_surface(&self.surface, 2, 2).0,
+            None => mesh_surface_adaptive(&self.surface, options).0,
+            Some(region) => self.mesh_trimmed(region, planar, options),
+        }
+    }
+
+    fn mesh_trimmed(
+        &self,
+        region: &TrimRegion,
+        planar: bool,
+        options: SurfaceTessellationOptions,
+    ) -> GeomMesh {
+        let tol = Tolerance::default();
+        let steiner_points = if planar {
+            Vec::new()
+        } else {
+            interior_grid_points(&self.surface, region, options)
+        };
+
+        let Ok(result) = triangulate_trim_region_with_steiner_points(region, &steiner_points, tol)
+        else {
+            return GeomMesh::new(Vec::new(), Vec::new());
+        };
+
+        let mut positions = Vec::with_capacity(result.vertices.len());
+        let mut normals = Vec::with_capacity(result.vertices.len());
+        let mut uvs = Vec::with_capacity(result.vertices.len());
+        for uv in &result.vertices {
+            positions.push(point_to_array(self.surface.point_at(uv.u, uv.v)));
+            let normal = self
+                .surface
+                .normal_at(uv.u, uv.v)
+                .unwrap_or_else(|| Vec3::new(0.0, 0.0, 1.0));
+            normals.push([normal.x, normal.y, normal.z]);
+            uvs.push([uv.u, uv.v]);
+        }
+        GeomMesh::with_attributes(positions, result.indices, Some(uvs), Some(normals))
+    }
+}
+
+/// Interior grid points of a trimmed face, used as Steiner points so curved
+/// surfaces are not triangulated from their boundary alone.
+fn interior_grid_points(
+    surface: &SurfaceValue,
+    region: &TrimRegion,
+    options: SurfaceTessellationOptions,
+) -> Vec<UvPoint> {
+    let (u_count, v_count) = choose_surface_grid_counts(surface, options);
+    let bounds = region.bounds();
+    let du = bounds.u_span() / u_count.max(2) as f64;
+    let dv = bounds.v_span() / v_count.max(2) as f64;
+    let clearance = 0.25 * du.min(dv);
+
+    let boundary: Vec<UvPoint> = std::iter::once(&region.outer)
+        .chain(region.holes.iter())
+        .flat_map(|trim_loop| trim_loop.points().iter().copied())
+        .collect();
+
+    let mut points = Vec::new();
+    for j in 1..v_count.max(2) {
+        for i in 1..u_count.max(2) {
+            let point = UvPoint::new(bounds.u_min + du * i as f64, bounds.v_min + dv * j as f64);
+            if region.contains(point, Tolerance::default())
+                && boundary.iter().all(|b| b.distance(point) > clearance)
+            {
+                points.push(point);
+            }
+        }
+    }
+    points
+}
+
+/// A boundary representation made of analytic (optionally trimmed) faces.
+///
+/// Like [`CurveValue`], breps keep their exact definition while they flow
+/// through the graph; they are meshed only for display/export or for
+/// components that still work on meshes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BrepValue {
+    pub faces: Vec<BrepFace>,
+}
+
+impl BrepValue {
+    /// Meshing settings used for display/export.
+    pub const DEFAULT_TESSELLATION: SurfaceTessellationOptions =
+        SurfaceTessellationOptions::new(0.01, 1.0);
+
+    /// A single-face brep covering the full surface domain.
+    #[must_use]
+    pub fn from_surface(surface: SurfaceValue) -> Self {
+        Self {
+            faces: vec![BrepFace::new(surface)],
+        }
+    }
+
+    /// A single-face brep restricted to `trim`.
+    #[must_use]
+    pub fn from_trimmed_surface(surface: SurfaceValue, trim: TrimRegion) -> Self {
+        Self {
+            faces: vec![BrepFace::trimmed(surface, trim)],
+        }
+    }
+
+    /// The face of a single-face brep (a "surface" in Grasshopper terms).
+    #[must_use]
+    pub fn single_face(&self) -> Option<&BrepFace> {
+        match self.faces.as_slice() {
+            [face] => Some(face),
+            _ => None,
+        }
+    }
+
+    /// Meshes all faces into one triangle mesh.
+    #[must_use]
+    pub fn to_mesh(&self, options: SurfaceTessellationOptions) -> GeomMesh {
+        let mut positions = Vec::new();
+        let mut indices = Vec::new();
+        let mut normals = Vec::new();
+        let mut uvs = Vec::new();
+        let mut complete_attributes = true;
+
+        for face in &self.faces {
+            let mesh = face.mesh(options);
+            let offset = positions.len() as u32;
+            let count = mesh.positions.len();
+            match (mesh.normals, mesh.uvs) {
+                (Some(face_normals), Some(face_uvs))
+                    if face_normals.len() == count && face_uvs.len() == count =>
+                {
+                    normals.extend(face_normals);
+                    uvs.extend(face_uvs);
+                }
+                _ => complete_attributes = false,
+            }
+            positions.extend(mesh.positions);
+            indices.extend(mesh.indices.into_iter().map(|index| index + offset));
+        }
+
+        if complete_attributes {
+            GeomMesh::with_attributes(positions, indices, Some(uvs), Some(normals))
+        } else {
+            GeomMesh::new(positions, indices)
+        }
+    }
+
+    /// Meshes the brep into a `Value::Mesh`.
+    #[must_use]
+    pub fn to_mesh_value(&self, options: SurfaceTessellationOptions) -> Value {
+        let mesh = self.to_mesh(options);
+        Value::Mesh {
+            vertices: mesh.positions,
+            indices: mesh.indices,
+            normals: mesh.normals,
+            uvs: mesh.uvs,
+            diagnostics: None,
+        }
+    }
+
+    /// Representation as used before breps were first-class values: a
+    /// legacy `Value::Surface` with one triangle per face entry.
+    #[must_use]
+    pub fn to_surface_value(&self) -> Value {
+        let mesh = self.to_mesh(Self::DEFAULT_TESSELLATION);
+        Value::Surface {
+            faces: mesh
+                .indices
+                .chunks_exact(3)
+                .map(<[u32]>::to_vec)
+                .collect(),
+            vertices: mesh.positions,
+        }
+    }
+}
+
+impl fmt::Display for BrepValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.single_face() {
+            Some(face) if face.trim.is_some() => {
+                write!(f, "Trimmed {} Surface", face.surface.type_name())
+            }
+            Some(face) => write!(f, "{} Surface", face.surface.type_name()),
+            None => write!(f, "Brep ({} faces)", self.faces.len()),
+        }
+    }
+}
+
+// ============================================================================
+// FieldValue - Vector fields
+// ============================================================================
+
+/// A vector field built from the Vector → Field components: a sum of
+/// sources, optionally limited to an axis-aligned box.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FieldValue {
+    pub sources: Vec<FieldSource>,
+    pub bounds: Option<FieldBounds>,
+}
+
+impl FieldValue {
+    /// A field consisting of one source.
+    #[must_use]
+    pub fn from_source(source: FieldSource, bounds: Option<FieldBounds>) -> Self {
+        Self {
+            sources: vec![source],
+            bounds,
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty() && self.bounds.is_none()
+    }
+}
+
+impl fmt::Display for FieldValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.sources.len() {
+            1 => write!(f, "Field (1 source)"),
+            count => write!(f, "Field ({count} sources)"),
+        }
+    }
+}
+
+/// Axis-aligned box that limits where a field is defined.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FieldBounds {
+    pub min: [f64; 3],
+    pub max: [f64; 3],
+}
+
+/// One contribution to a [`FieldValue`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FieldSource {
+    PointCharge {
+        point: [f64; 3],
+        charge: f64,
+        decay: f64,
+    },
+    LineCharge {
+        start: [f64; 3],
+        end: [f64; 3],
+        charge: f64,
+        decay: f64,
+    },
+    VectorForce {
+        start: [f64; 3],
+        end: [f64; 3],
+    },
+    SpinForce {
+        origin: [f64; 3],
+        normal: [f64; 3],
+        radius: f64,
+        strength: f64,
+        decay: f64,
+    },
+}
+
 /// Beschikbare waardetypes binnen de evaluator.
 #[derive(Debug, Clone, PartialEq)]
 pub enum Value {
@@ -1509,6 +2190,10 @@ pub enum Value {
     Vector([f64; 3]),
     /// Een lijnsegment, beschreven door twee punten.
     CurveLine { p1: [f64; 3], p2: [f64; 3] },
+    /// Een exacte (analytische) curve; zie [`CurveValue`].
+    Curve(CurveValue),
+    /// Een georiënteerd vlak; zie [`PlaneValue`].
+    Plane(PlaneValue),
     /// Een (prismatische) mesh representatie.
     /// 
     /// **Legacy type** - kept for backward compatibility.
@@ -1518,6 +2203,8 @@ pub enum Value {
         vertices: Vec<[f64; 3]>,
         faces: Vec<Vec<u32>>,
     },
+    /// Een brep met analytische (eventueel getrimde) vlakken; zie [`BrepValue`].
+    Brep(BrepValue),
     /// A triangle mesh with optional attributes and diagnostics.
     ///
     /// This is the preferred mesh representation for the new geometry engine.
@@ -1565,10 +2252,16 @@ pub enum Value {
     Domain(Domain),
     /// Een matrix van numerieke waarden.
     Matrix(Matrix),
+    /// Een affiene (of projectieve) 4x4-transformatie.
+    Transform(Transform),
+    /// Een vectorveld opgebouwd uit puntladingen, krachten en begrenzing.
+    Field(FieldValue),
     /// Een datum-tijdwaarde zonder tijdzone.
     DateTime(DateTimeValue),
     /// Een lijst van waarden.
     List(Vec<Value>),
+    /// Een Grasshopper-datatree met takken per `{0;1;2}`-pad.
+    Tree(DataTree),
     /// Een tekstuele waarde.
     Text(String),
     /// Een tekstlabel met oriëntatie en optionele kleur.
@@ -1604,7 +2297,14 @@ impl Hash for Value {
                 p1.iter().for_each(|x| x.to_bits().hash(state));
                 p2.iter().for_each(|x| x.to_bits().hash(state));
             }
+            Value::Plane(plane) => {
+                [plane.origin, plane.x_axis, plane.y_axis, plane.z_axis]
+                    .iter()
+                    .flatten()
+                    .for_each(|x| x.to_bits().hash(state));
+            }
             Value::List(l) => l.hash(state),
+            Value::Tree(tree) => tree.hash(state),
             Value::Text(s) => s.hash(state),
             Value::DateTime(dt) => dt.hash(state),
             Value::Color(c) => {
@@ -1614,10 +2314,19 @@ impl Hash for Value {
             }
             // Non-trivial hash impls below.
             // For now, these are not hashed, which is not ideal but avoids complexity.
+            Value::Curve(_) => {}
             Value::Surface { .. } => {}
+            Value::Brep(_) => {}
+            Value::Field(_) => {}
             Value::Mesh { .. } => {}
             Value::Domain(_) => {}
             Value::Matrix(_) => {}
+            Value::Transform(t) => {
+                t.as_matrix()
+                    .iter()
+                    .flatten()
+                    .for_each(|x| x.to_bits().hash(state));
+            }
             Value::Tag(_) => {}
             Value::Material(_) => {}
             Value::Symbol(_) => {}
@@ -1639,6 +2348,12 @@ impl fmt::Display for Value {
                 "Line [{},{},{}] to [{},{},{}]",
                 p1[0], p1[1], p1[2], p2[0], p2[1], p2[2]
             ),
+            Self::Curve(curve) => write!(f, "{curve}"),
+            Self::Plane(plane) => {
+                let [x, y, z] = plane.origin;
+                let [nx, ny, nz] = plane.z_axis;
+                write!(f, "Plane [{x},{y},{z}] normal [{nx},{ny},{nz}]")
+            }
             Self::Surface { vertices, faces } => {
                 write!(
                     f,
@@ -1647,6 +2362,7 @@ impl fmt::Display for Value {
                     faces.len()
                 )
             }
+            Self::Brep(brep) => write!(f, "{brep}"),
             Self::Mesh { vertices, indices, normals, uvs, .. } => {
                 let tri_count = indices.len() / 3;
                 let attrs = match (normals.is_some(), uvs.is_some()) {
@@ -1672,8 +2388,17 @@ impl fmt::Display for Value {
                 ),
             },
             Self::Matrix(m) => write!(f, "Matrix [{}x{}]", m.rows, m.columns),
+            Self::Field(field) => write!(f, "{field}"),
+            Self::Transform(t) => {
+                if t.is_affine() {
+                    write!(f, "Transform (Affine)")
+                } else {
+                    write!(f, "Transform (Projective)")
+                }
+            }
             Self::DateTime(dt) => write!(f, "{}", dt.primitive()),
             Self::List(l) => write!(f, "List [{} items]", l.len()),
+            Self::Tree(tree) => write!(f, "{tree}"),
             Self::Text(s) => write!(f, "{}", s),
             Self::Tag(t) => write!(f, "Tag: {}", t.text),
             Self::Color(c) => write!(f, "Color [R={}, G={}, B={}]", c.r, c.g, c.b),
@@ -1708,12 +2433,18 @@ impl Value {
             Self::Point(_) => ValueKind::Point,
             Self::Vector(_) => ValueKind::Vector,
             Self::CurveLine { .. } => ValueKind::CurveLine,
+            Self::Curve(_) => ValueKind::Curve,
+            Self::Plane(_) => ValueKind::Plane,
             Self::Surface { .. } => ValueKind::Surface,
+            Self::Brep(_) => ValueKind::Brep,
             Self::Mesh { .. } => ValueKind::Mesh,
             Self::Domain(_) => ValueKind::Domain,
             Self::Matrix(_) => ValueKind::Matrix,
+            Self::Transform(_) => ValueKind::Transform,
+            Self::Field(_) => ValueKind::Field,
             Self::DateTime(_) => ValueKind::DateTime,
             Self::List(_) => ValueKind::List,
+            Self::Tree(_) => ValueKind::Tree,
             Self::Text(_) => ValueKind::Text,
             Self::Tag(_) => ValueKind::Tag,
             Self::Color(_) => ValueKind::Color,
@@ -2035,6 +2766,22 @@ impl Value {
         }
     }
 
+    /// Verwacht een `Transform` en retourneert de waarde.
+    pub fn expect_transform(&self) -> Result<Transform, ValueError> {
+        match self {
+            Self::Transform(transform) => Ok(*transform),
+            _ => Err(ValueError::type_mismatch("Transform", self.kind())),
+        }
+    }
+
+    /// Verwacht een `Field` en retourneert een verwijzing.
+    pub fn expect_field(&self) -> Result<&FieldValue, ValueError> {
+        match self {
+            Self::Field(field) => Ok(field),
+            _ => Err(ValueError::type_mismatch("Field", self.kind())),
+        }
+    }
+
     /// Verwacht een `DateTime` en retourneert de waarde.
     pub fn expect_date_time(&self) -> Result<PrimitiveDateTime, ValueError> {
         match self {
@@ -2083,6 +2830,30 @@ impl Value {
         }
     }
 
+    /// Verwacht een `Curve` en retourneert een referentie naar de curve.
+    pub fn expect_curve(&self) -> Result<&CurveValue, ValueError> {
+        match self {
+            Self::Curve(curve) => Ok(curve),
+            _ => Err(ValueError::type_mismatch("Curve", self.kind())),
+        }
+    }
+
+    /// Verwacht een `Brep` en retourneert een referentie naar de brep.
+    pub fn expect_brep(&self) -> Result<&BrepValue, ValueError> {
+        match self {
+            Self::Brep(brep) => Ok(brep),
+            _ => Err(ValueError::type_mismatch("Brep", self.kind())),
+        }
+    }
+
+    /// Verwacht een `Tree` en retourneert een referentie naar de datatree.
+    pub fn expect_tree(&self) -> Result<&DataTree, ValueError> {
+        match self {
+            Self::Tree(tree) => Ok(tree),
+            _ => Err(ValueError::type_mismatch("Tree", self.kind())),
+        }
+    }
+
     /// Verwacht een `Symbol` en retourneert een referentie naar de symboolgegevens.
     pub fn expect_symbol(&self) -> Result<&SymbolValue, ValueError> {
         match self {
@@ -2131,7 +2902,7 @@ impl fmt::Display for ValueError {
 impl std::error::Error for ValueError {}
 
 /// Beschrijft het soort `Value`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
 pub enum ValueKind {
     Null,
     Number,
@@ -2139,11 +2910,17 @@ pub enum ValueKind {
     Point,
     Vector,
     CurveLine,
+    Curve,
+    Plane,
     Surface,
+    Brep,
     Mesh,
     Domain,
     List,
+    Tree,
     Matrix,
+    Transform,
+    Field,
     Complex,
     DateTime,
     Text,
@@ -2162,13 +2939,19 @@ impl fmt::Display for ValueKind {
             Self::Point => "Point",
             Self::Vector => "Vector",
             Self::CurveLine => "CurveLine",
+            Self::Curve => "Curve",
+            Self::Plane => "Plane",
             Self::Surface => "Surface",
+            Self::Brep => "Brep",
             Self::Mesh => "Mesh",
             Self::Domain => "Domain",
             Self::Matrix => "Matrix",
+            Self::Transform => "Transform",
+            Self::Field => "Field",
             Self::Complex => "Complex",
             Self::DateTime => "DateTime",
             Self::List => "List",
+            Self::Tree => "Tree",
             Self::Text => "Text",
             Self::Tag => "Tag",
             Self::Color => "Color",
@@ -2233,6 +3016,36 @@ impl PlaneValue {
             z_axis: [0.0, 0.0, 1.0],
         }
     }
+
+    /// Maak een vlak uit een oorsprong en twee assen; de z-as is hun
+    /// kruisproduct.
+    #[must_use]
+    pub fn from_axes(origin: [f64; 3], x_axis: [f64; 3], y_axis: [f64; 3]) -> Self {
+        let z_axis = [
+            x_axis[1] * y_axis[2] - x_axis[2] * y_axis[1],
+            x_axis[2] * y_axis[0] - x_axis[0] * y_axis[2],
+            x_axis[0] * y_axis[1] - x_axis[1] * y_axis[0],
+        ];
+        Self::new(origin, x_axis, y_axis, z_axis)
+    }
+
+    /// De puntenlijst `[oorsprong, oorsprong + x, oorsprong + y]` waarmee de
+    /// componenten vlakken onderling doorgeven.
+    #[must_use]
+    pub fn to_points_value(&self) -> Value {
+        let offset = |axis: [f64; 3]| {
+            Value::Point([
+                self.origin[0] + axis[0],
+                self.origin[1] + axis[1],
+                self.origin[2] + axis[2],
+            ])
+        };
+        Value::List(vec![
+            Value::Point(self.origin),
+            offset(self.x_axis),
+            offset(self.y_axis),
+        ])
+    }
 }
 
 /// RGB kleurwaarden genormaliseerd tussen 0 en 1.
@@ -2352,6 +3165,30 @@ impl Matrix {
             values,
         })
     }
+
+    /// Zet een transformatie om naar een 4x4-matrix (rij-gewijs).
+    #[must_use]
+    pub fn from_transform(transform: &Transform) -> Self {
+        Self {
+            rows: 4,
+            columns: 4,
+            values: transform.as_matrix().iter().flatten().copied().collect(),
+        }
+    }
+
+    /// Interpreteert een 4x4-matrix als transformatie; andere afmetingen
+    /// leveren `None` op.
+    #[must_use]
+    pub fn to_transform(&self) -> Option<Transform> {
+        if self.rows != 4 || self.columns != 4 {
+            return None;
+        }
+        let mut m = [[0.0; 4]; 4];
+        for (r, row) in m.iter_mut().enumerate() {
+            row.copy_from_slice(&self.values[r * 4..r * 4 + 4]);
+        }
+        Some(Transform::from_matrix(m))
+    }
 }
 
 /// Een één-dimensionaal numeriek domein.
@@ -2382,7 +3219,8 @@ pub enum Domain {
 
 #[cfg(test)]
 mod tests {
-    use super::{ComplexValue, DateTimeValue, Value, ValueError, ValueKind};
+    use super::{ComplexValue, CurveValue, DateTimeValue, Value, ValueError, ValueKind};
+    use crate::geom::{Arc3, Circle3, Point3, Vec3};
     use num_complex::Complex;
     use time::macros::datetime;
 
@@ -4006,4 +4844,68 @@ mod tests {
         // Other fields should be preserved
         assert_eq!(updated.open_edge_count, 2);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn trimmed_brep_meshes_inside_its_trim_loops() {
+        use super::{BrepValue, SurfaceValue};
+        use crate::geom::{Point3, SphereSurface, Surface, Tolerance, TrimLoop, TrimRegion, UvPoint};
+
+        let sphere = SphereSurface::new(Point3::new(0.0, 0.0, 0.0), 1.0).unwrap();
+        let outer = TrimLoop::new(
+            vec![
+                UvPoint::new(0.1, 0.3),
+                UvPoint::new(0.4, 0.3),
+                UvPoint::new(0.4, 0.7),
+                UvPoint::new(0.1, 0.7),
+            ],
+            Tolerance::default(),
+        )
+        .unwrap();
+        let region = TrimRegion::from_loops(vec![outer], Tolerance::default()).unwrap();
+        let brep = BrepValue::from_trimmed_surface(SurfaceValue::Sphere(sphere), region);
+
+        let mesh = brep.to_mesh(BrepValue::DEFAULT_TESSELLATION);
+        assert!(mesh.indices.len() >= 6);
+        let uvs = mesh.uvs.as_ref().expect("trimmed meshes carry uvs");
+        for (position, uv) in mesh.positions.iter().zip(uvs) {
+            assert!((0.1 - 1e-9..=0.4 + 1e-9).contains(&uv[0]));
+            assert!((0.3 - 1e-9..=0.7 + 1e-9).contains(&uv[1]));
+            let expected = sphere.point_at(uv[0], uv[1]);
+            assert!((position[0] - expected.x).abs() < 1e-12);
+            assert!((position[1] - expected.y).abs() < 1e-12);
+            assert!((position[2] - expected.z).abs() < 1e-12);
+        }
+        // Curved faces get interior points, not just their four corners.
+        assert!(mesh.positions.len() > 4);
+    }
+
+    #[test]
+    fn mapped_curves_stay_exact_under_similarities() {
+        let circle = CurveValue::Circle(Circle3::new(Point3::ORIGIN, Vec3::Z, 1.0));
+
+        let moved = circle.map_points(|[x, y, z]| [2.0 * x + 1.0, 2.0 * y, 2.0 * z]);
+        let CurveValue::Circle(moved) = moved else {
+            panic!("uniform scale keeps a circle, got {moved:?}");
+        };
+        assert_eq!(moved.center, Point3::new(1.0, 0.0, 0.0));
+        assert!((moved.radius - 2.0).abs() < 1e-12);
+
+        let stretched = circle.map_points(|[x, y, z]| [3.0 * x, y, z]);
+        let CurveValue::Ellipse(stretched) = stretched else {
+            panic!("uneven scale turns a circle into an ellipse, got {stretched:?}");
+        };
+        assert!((stretched.radius_x - 3.0).abs() < 1e-12);
+        assert!((stretched.radius_y - 1.0).abs() < 1e-12);
+
+        let arc = CurveValue::Arc(Arc3::new(Point3::ORIGIN, Vec3::Z, 1.0, 0.0, 1.0));
+        let shear = |[x, y, z]: [f64; 3]| [x + y, y, z];
+        let CurveValue::Polyline(sheared) = arc.map_points(shear) else {
+            panic!("a sheared arc is tessellated");
+        };
+        assert!(sheared.points().len() > 2);
+        assert_eq!(
+            sheared.points()[0],
+            Point3::from_array(shear(arc.start_point()))
+        );
+    }
+}
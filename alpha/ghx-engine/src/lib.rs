@@ -1,19 +1,36 @@
 #![warn(clippy::all, clippy::pedantic)]
 #![allow(clippy::module_name_repetitions)]
 
+pub mod catalog;
 pub mod components;
+pub mod coverage;
+mod editor;
+pub mod export;
 pub mod geom;
+mod geometry_buffers;
 pub mod graph;
 pub mod parse;
+mod presets;
+mod workspace;
+
+pub use geometry_buffers::GeometryBuffers;
+pub use presets::{Preset, PresetInput, PresetValue};
+pub use workspace::Workspace;
 
 use std::collections::{BTreeMap, HashSet};
 use std::fmt;
 
+use catalog::Catalog;
 use components::{ComponentKind, ComponentRegistry};
+use coverage::{CoverageReport, build_coverage};
+use editor::EditHistory;
 use graph::Graph;
-use graph::evaluator::{self, EvaluationError, EvaluationPlan, EvaluationResult, GeometryEntry};
-use graph::node::{MetaLookupExt, MetaMap, MetaValue, NodeId};
-use graph::value::{ColorValue, MaterialValue, Value};
+use graph::evaluator::{
+    self, EvaluationError, EvaluationPlan, EvaluationResult, EvaluationWarning, GeometryEntry,
+};
+use graph::report::{EvaluationReport, build_report};
+use graph::node::{MetaLookupExt, MetaMap, MetaValue, Node, NodeId};
+use graph::value::{BrepValue, ColorValue, CurveValue, MaterialValue, Value};
 use serde::Serialize;
 use wasm_bindgen::JsError;
 use wasm_bindgen::prelude::*;
@@ -95,6 +112,12 @@ enum InputKind {
     Slider,
     Toggle,
     ValueList,
+    Panel,
+    ColourSwatch,
+    DigitScroller,
+    MdSlider,
+    ColourPicker,
+    GraphMapper,
 }
 
 #[derive(Debug, Serialize)]
@@ -126,6 +149,46 @@ enum InputControl {
         selected_index: usize,
         value: f64,
     },
+    #[serde(rename = "panel")]
+    Panel {
+        id: String,
+        name: String,
+        text: String,
+        multiline: bool,
+    },
+    /// Kanalen in het bereik 0-255.
+    #[serde(rename = "colour-swatch")]
+    ColourSwatch {
+        id: String,
+        name: String,
+        color: [f64; 3],
+    },
+    #[serde(rename = "digit-scroller")]
+    DigitScroller {
+        id: String,
+        name: String,
+        value: f64,
+    },
+    #[serde(rename = "md-slider")]
+    MdSlider {
+        id: String,
+        name: String,
+        value: [f64; 2],
+    },
+    /// Kanalen in het bereik 0-255.
+    #[serde(rename = "colour-picker")]
+    ColourPicker {
+        id: String,
+        name: String,
+        color: [f64; 3],
+    },
+    /// Steunpunten van de mappingcurve, gesorteerd op x.
+    #[serde(rename = "graph-mapper")]
+    GraphMapper {
+        id: String,
+        name: String,
+        points: Vec<[f64; 2]>,
+    },
 }
 
 #[derive(Debug, Serialize)]
@@ -256,12 +319,18 @@ pub struct Engine {
     graph: Option<Graph>,
     last_result: Option<EvaluationResult>,
     last_errors: Vec<EvaluationError>,
+    last_warnings: Vec<EvaluationWarning>,
     input_bindings: Vec<InputBinding>,
     evaluation_plan: Option<EvaluationPlan>,
     dirty_nodes: HashSet<NodeId>,
     result_dirty: bool,
     geometry_map: BTreeMap<NodeId, Vec<GeometryItem<'static>>>,
     changed_nodes_since_geometry_update: HashSet<NodeId>,
+    buffer_fingerprints: BTreeMap<NodeId, u64>,
+    changed_nodes_since_buffer_update: HashSet<NodeId>,
+    catalog: Catalog,
+    history: EditHistory,
+    presets: Vec<Preset>,
 }
 
 #[wasm_bindgen]
@@ -274,12 +343,18 @@ impl Engine {
             graph: None,
             last_result: None,
             last_errors: Vec::new(),
+            last_warnings: Vec::new(),
             input_bindings: Vec::new(),
             evaluation_plan: None,
             dirty_nodes: HashSet::new(),
             result_dirty: false,
             geometry_map: BTreeMap::new(),
             changed_nodes_since_geometry_update: HashSet::new(),
+            buffer_fingerprints: BTreeMap::new(),
+            changed_nodes_since_buffer_update: HashSet::new(),
+            catalog: Catalog::new(),
+            history: EditHistory::default(),
+            presets: Vec::new(),
         }
     }
 
@@ -293,45 +368,32 @@ impl Engine {
     #[wasm_bindgen]
     pub fn load_ghx(&mut self, xml: &str) -> Result<(), JsValue> {
         let graph = parse::ghx_xml::parse_str(xml).map_err(to_js_error)?;
-        let input_bindings = collect_input_bindings(&graph, &self.registry);
-        let evaluation_plan = evaluator::EvaluationPlan::new(&graph).map_err(to_js_error)?;
-        let node_ids: Vec<NodeId> = graph.nodes().iter().map(|node| node.id).collect();
+        self.install_graph(graph).map_err(to_js_error)
+    }
 
-        self.graph = Some(graph);
-        self.input_bindings = input_bindings;
-        self.last_result = None;
-        self.last_errors.clear();
-        self.evaluation_plan = Some(evaluation_plan);
-        self.dirty_nodes.clear();
-        self.dirty_nodes.extend(node_ids);
-        self.result_dirty = true;
-        self.geometry_map.clear();
-        self.changed_nodes_since_geometry_update.clear();
+    /// Laad een binair `.gh`-bestand (GH_IO-archief) in de engine.
+    #[wasm_bindgen]
+    pub fn load_gh(&mut self, bytes: &[u8]) -> Result<(), JsValue> {
+        let graph = parse::gh_binary::parse_bytes(bytes).map_err(to_js_error)?;
+        self.install_graph(graph).map_err(to_js_error)
+    }
 
-        Ok(())
+    /// Schrijf de geladen (en eventueel aangepaste) graph terug als GHX, met
+    /// de huidige slider-, toggle- en value-list-standen.
+    #[wasm_bindgen]
+    pub fn save_ghx(&self) -> Result<String, JsValue> {
+        self.to_ghx().map_err(|err| js_error(&err))
     }
 
     /// Haal input controls (sliders, toggles) op voor UI-generatie.
     #[wasm_bindgen]
     pub fn get_sliders(&self) -> Result<JsValue, JsValue> {
-        let graph = match self.graph.as_ref() {
-            Some(graph) => graph,
-            None => return Err(js_error("er is geen GHX-bestand geladen")),
-        };
-
-        let mut controls = Vec::with_capacity(self.input_bindings.len());
-        for binding in &self.input_bindings {
-            let control = match input_control_state(graph, binding) {
-                Ok(control) => control,
-                Err(err) => return Err(js_error(&err)),
-            };
-            controls.push(control);
-        }
-
+        let controls = self.input_controls().map_err(|err| js_error(&err))?;
         serde_wasm_bindgen::to_value(&controls).map_err(|err| JsError::new(&err.to_string()).into())
     }
 
-    /// Stel een slider- of togglewaarde in op basis van id of naam.
+    /// Stel een getal- of togglewaarde in op basis van id of naam (slider,
+    /// toggle, value list of Digit Scroller).
     #[wasm_bindgen]
     pub fn set_slider_value(&mut self, id_or_name: &str, value: JsValue) -> Result<(), JsValue> {
         let val = if let Some(n) = value.as_f64() {
@@ -346,138 +408,111 @@ impl Engine {
             .map_err(|e| js_error(&e))
     }
 
-    /// Evalueer de geladen graph.
+    /// Stel de tekst van een panel in.
     #[wasm_bindgen]
-    pub fn evaluate(&mut self) -> Result<(), JsValue> {
-        if !self.result_dirty && self.dirty_nodes.is_empty() {
-            return Ok(());
-        }
-
-        let mut dirty_nodes = std::mem::take(&mut self.dirty_nodes);
-
-        let graph = match self.graph.as_ref() {
-            Some(graph) => graph,
-            None => {
-                self.dirty_nodes = dirty_nodes;
-                return Err(js_error("er is geen GHX-bestand geladen"));
-            }
-        };
+    pub fn set_input_text(&mut self, id_or_name: &str, text: &str) -> Result<(), JsValue> {
+        self.update_input_value(id_or_name, Value::Text(text.to_owned()))
+            .map_err(|e| js_error(&e))
+    }
 
-        let plan = match self.evaluation_plan.as_ref() {
-            Some(plan) => plan,
-            None => {
-                self.dirty_nodes = dirty_nodes;
-                return Err(js_error("graph is niet voorbereid voor evaluatie"));
-            }
-        };
+    /// Stel de kleur van een Colour Swatch of Colour Picker in, met kanalen
+    /// in het bereik 0-255.
+    #[wasm_bindgen]
+    pub fn set_input_colour(
+        &mut self,
+        id_or_name: &str,
+        r: f64,
+        g: f64,
+        b: f64,
+    ) -> Result<(), JsValue> {
+        let color = ColorValue::from_rgb255(r, g, b);
+        self.update_input_value(id_or_name, Value::Color(color))
+            .map_err(|e| js_error(&e))
+    }
 
-        let previous = self.last_result.as_ref();
-        let evaluation = evaluator::evaluate_with_plan_incremental(
-            graph,
-            &self.registry,
-            plan,
-            previous,
-            &dirty_nodes,
-        );
+    /// Stel het punt van een MD Slider in.
+    #[wasm_bindgen]
+    pub fn set_input_point(&mut self, id_or_name: &str, x: f64, y: f64) -> Result<(), JsValue> {
+        self.update_input_value(id_or_name, Value::Point([x, y, 0.0]))
+            .map_err(|e| js_error(&e))
+    }
 
-        match evaluation {
-            Ok((result, changed)) => {
-                self.last_errors = result.errors.clone();
-                self.last_result = Some(result);
-                self.result_dirty = false;
-                self.changed_nodes_since_geometry_update.extend(changed);
-            }
-            Err(error) => {
-                self.last_errors = vec![error.clone()];
-                self.dirty_nodes = dirty_nodes;
-                return Err(to_js_error(error));
-            }
-        }
+    /// Stel de curve van een Graph Mapper in als array van `[x, y]`-paren.
+    #[wasm_bindgen]
+    pub fn set_input_curve(&mut self, id_or_name: &str, points: JsValue) -> Result<(), JsValue> {
+        let value = curve_points_value(points)?;
+        self.update_input_value(id_or_name, value)
+            .map_err(|e| js_error(&e))
+    }
 
-        dirty_nodes.clear();
-        Ok(())
+    /// Evalueer de geladen graph.
+    #[wasm_bindgen]
+    pub fn evaluate(&mut self) -> Result<(), JsValue> {
+        self.evaluate_graph().map_err(|err| js_error(&err))
     }
 
     /// Haalt de geometrie op van de laatste evaluatie in een "diff" formaat.
     #[wasm_bindgen]
     pub fn get_geometry(&mut self) -> Result<JsValue, JsValue> {
+        let diff = self.geometry_diff().map_err(|err| js_error(&err))?;
+        serde_wasm_bindgen::to_value(&diff).map_err(|err| JsError::new(&err.to_string()).into())
+    }
+
+    /// Haalt de geometrie-diff van de laatste evaluatie op als getypeerde
+    /// buffers. Houdt een eigen diff-toestand bij, los van `get_geometry`.
+    #[wasm_bindgen]
+    pub fn get_geometry_buffers(&mut self) -> Result<GeometryBuffers, JsValue> {
         if self.result_dirty {
             return Err(js_error("graph is nog niet geëvalueerd"));
         }
 
-        let result = match self.last_result.as_ref() {
-            Some(result) => result,
-            None => {
-                let diff = GeometryDiff {
-                    removed: self.geometry_map.keys().map(|id| id.0).collect(),
-                    ..Default::default()
-                };
-                self.geometry_map.clear();
-                self.changed_nodes_since_geometry_update.clear();
-                return serde_wasm_bindgen::to_value(&diff)
-                    .map_err(|err| JsError::new(&err.to_string()).into());
-            }
+        let Some(result) = self.last_result.as_ref() else {
+            let removed = self
+                .buffer_fingerprints
+                .keys()
+                .map(|id| id.0 as u32)
+                .collect();
+            self.buffer_fingerprints.clear();
+            self.changed_nodes_since_buffer_update.clear();
+            return Ok(GeometryBuffers::removed_only(removed));
         };
-
-        let mut diff = GeometryDiff::default();
         let graph = self.graph.as_ref().unwrap();
 
-        let geometry_by_node: BTreeMap<NodeId, Vec<GeometryEntry>> =
-            result
-                .geometry
-                .iter()
-                .fold(BTreeMap::new(), |mut acc, entry| {
-                    acc.entry(entry.source_node)
-                        .or_default()
-                        .push(entry.clone());
-                    acc
-                });
-
-        let changed_nodes = std::mem::take(&mut self.changed_nodes_since_geometry_update);
+        let mut geometry_by_node: BTreeMap<NodeId, Vec<&GeometryEntry>> = BTreeMap::new();
+        for entry in &result.geometry {
+            geometry_by_node
+                .entry(entry.source_node)
+                .or_default()
+                .push(entry);
+        }
 
-        for node_id in &changed_nodes {
-            let is_hidden = graph
-                .node(*node_id)
-                .and_then(|node| node.meta("hidden"))
-                .and_then(MetaValue::as_boolean)
-                .unwrap_or(false);
+        let mut changed_nodes: Vec<NodeId> =
+            std::mem::take(&mut self.changed_nodes_since_buffer_update)
+                .into_iter()
+                .collect();
+        changed_nodes.sort_unstable();
 
-            let new_items: Vec<GeometryItem<'static>> = if is_hidden {
-                Vec::new()
+        let mut buffers = GeometryBuffers::default();
+        for node_id in changed_nodes {
+            let entries = if is_hidden_node(graph, node_id) {
+                &[][..]
             } else {
                 geometry_by_node
-                    .get(node_id)
-                    .map(|entries| {
-                        let mut items = Vec::new();
-                        for entry in entries {
-                            append_geometry_items(entry, &mut items);
-                        }
-                        items.into_iter().map(|item| item.deep_clone()).collect()
-                    })
-                    .unwrap_or_default()
+                    .get(&node_id)
+                    .map_or(&[][..], Vec::as_slice)
             };
-
-            if let Some(existing_items) = self.geometry_map.get(node_id) {
-                if new_items.is_empty() {
-                    diff.removed.push(node_id.0);
-                    self.geometry_map.remove(node_id);
-                } else if existing_items.iter().ne(new_items.iter()) {
-                    diff.updated.push(GeometryDiffItem {
-                        id: node_id.0,
-                        items: new_items.clone(),
-                    });
-                    self.geometry_map.insert(*node_id, new_items);
+            let previous = self.buffer_fingerprints.get(&node_id).copied();
+            match buffers.diff_node(node_id, entries, previous) {
+                Some(fingerprint) => {
+                    self.buffer_fingerprints.insert(node_id, fingerprint);
+                }
+                None => {
+                    self.buffer_fingerprints.remove(&node_id);
                 }
-            } else if !new_items.is_empty() {
-                diff.added.push(GeometryDiffItem {
-                    id: node_id.0,
-                    items: new_items.clone(),
-                });
-                self.geometry_map.insert(*node_id, new_items);
             }
         }
 
-        serde_wasm_bindgen::to_value(&diff).map_err(|err| JsError::new(&err.to_string()).into())
+        Ok(buffers)
     }
 
     /// Haal de fouten van de laatste evaluatie op als leesbare strings.
@@ -488,6 +523,44 @@ impl Engine {
             .map_err(|err| JsError::new(&err.to_string()).into())
     }
 
+    /// Haal fouten en waarschuwingen van de laatste evaluatie op als
+    /// gestructureerd rapport met nodecontext en upstream oorzaken.
+    #[wasm_bindgen]
+    pub fn get_error_report(&self) -> Result<JsValue, JsValue> {
+        serde_wasm_bindgen::to_value(&self.error_report())
+            .map_err(|err| JsError::new(&err.to_string()).into())
+    }
+
+    /// Haal het pinschema op van het component met deze GUID; `null` als de
+    /// GUID onbekend is of niet in de nodelist staat.
+    #[wasm_bindgen]
+    pub fn get_component_schema(&self, guid: &str) -> Result<JsValue, JsValue> {
+        serde_wasm_bindgen::to_value(&self.registry.schema(guid))
+            .map_err(|err| JsError::new(&err.to_string()).into())
+    }
+
+    /// Haal de pinschema's van alle geregistreerde componenten op.
+    #[wasm_bindgen]
+    pub fn get_component_schemas(&self) -> Result<JsValue, JsValue> {
+        serde_wasm_bindgen::to_value(&self.registry.schemas())
+            .map_err(|err| JsError::new(&err.to_string()).into())
+    }
+
+    /// Voeg de componenten uit één `nodelist/*.json` bestand toe aan de
+    /// catalogus voor het dekkingsrapport. Geeft het aantal entries terug.
+    #[wasm_bindgen]
+    pub fn load_catalog(&mut self, json: &str) -> usize {
+        self.catalog.add_nodelist_json(json)
+    }
+
+    /// Haal per node op of het component werkt, een placeholder is of
+    /// ontbreekt, aangevuld met naam en categorie uit de catalogus.
+    #[wasm_bindgen]
+    pub fn get_coverage_report(&self) -> Result<JsValue, JsValue> {
+        let report = self.coverage_report().map_err(|err| js_error(&err))?;
+        serde_wasm_bindgen::to_value(&report).map_err(|err| JsError::new(&err.to_string()).into())
+    }
+
     /// Haalt een tekstuele weergave op van de topologisch gesorteerde graaf.
     #[wasm_bindgen]
     pub fn get_topology_map(&self) -> Result<JsValue, JsValue> {
@@ -564,32 +637,12 @@ fn collect_input_bindings(graph: &Graph, registry: &ComponentRegistry) -> Vec<In
     let mut bindings = Vec::new();
 
     for node in graph.nodes() {
-        let component = registry.resolve(
-            node.guid.as_deref(),
-            node.name.as_deref(),
-            node.nickname.as_deref(),
-        );
-
-        let kind = match component {
-            Some(ComponentKind::ParamsInput(
-                components::params_input::ComponentKind::NumberSlider,
-            )) => Some(InputKind::Slider),
-            Some(ComponentKind::ParamsInput(
-                components::params_input::ComponentKind::BooleanToggle,
-            )) => Some(InputKind::Toggle),
-            Some(ComponentKind::ParamsInput(
-                components::params_input::ComponentKind::ValueList,
-            )) => Some(InputKind::ValueList),
-            _ => None,
-        };
+        // Een panel met bronnen toont zijn invoer en is dan geen input.
+        let kind = input_kind(registry, node)
+            .filter(|kind| *kind != InputKind::Panel || !has_incoming_wires(graph, node.id));
 
         if let Some(kind) = kind {
-            let output_pin = node
-                .outputs
-                .keys()
-                .next()
-                .cloned()
-                .unwrap_or_else(|| "Output".to_string());
+            let output_pin = input_output_pin(node);
 
             let mut search_keys = Vec::new();
             if let Some(name) = node.name.as_deref() {
@@ -612,11 +665,38 @@ fn collect_input_bindings(graph: &Graph, registry: &ComponentRegistry) -> Vec<In
     bindings
 }
 
-fn input_control_state(graph: &Graph, binding: &InputBinding) -> Result<InputControl, String> {
-    let node = graph
-        .node(binding.node_id)
-        .ok_or_else(|| "interne inputreferentie is ongeldig".to_owned())?;
+/// Het soort input-control van een node, of `None` als de node geen input is.
+fn input_kind(registry: &ComponentRegistry, node: &Node) -> Option<InputKind> {
+    use components::params_input::ComponentKind as Input;
+
+    match registry.resolve(
+        node.guid.as_deref(),
+        node.name.as_deref(),
+        node.nickname.as_deref(),
+    )? {
+        ComponentKind::ParamsInput(Input::NumberSlider) => Some(InputKind::Slider),
+        ComponentKind::ParamsInput(Input::BooleanToggle) => Some(InputKind::Toggle),
+        ComponentKind::ParamsInput(Input::ValueList) => Some(InputKind::ValueList),
+        ComponentKind::ParamsInput(Input::Panel) => Some(InputKind::Panel),
+        ComponentKind::ParamsInput(Input::ColourSwatch) => Some(InputKind::ColourSwatch),
+        ComponentKind::ParamsInput(Input::DigitScroller) => Some(InputKind::DigitScroller),
+        ComponentKind::ParamsInput(Input::MDSlider) => Some(InputKind::MdSlider),
+        ComponentKind::ParamsInput(Input::ColourPicker) => Some(InputKind::ColourPicker),
+        ComponentKind::ParamsInput(Input::GraphMapper) => Some(InputKind::GraphMapper),
+        _ => None,
+    }
+}
+
+/// De output-pin waarop een input-node zijn waarde zet.
+fn input_output_pin(node: &Node) -> String {
+    node.outputs
+        .keys()
+        .next()
+        .cloned()
+        .unwrap_or_else(|| "Output".to_string())
+}
 
+fn input_control_state(node: &Node, binding: &InputBinding) -> Result<InputControl, String> {
     let name = node
         .nickname
         .as_deref()
@@ -667,52 +747,319 @@ fn input_control_state(graph: &Graph, binding: &InputBinding) -> Result<InputCon
                 value,
             })
         }
-    }
-}
-
-fn value_list_items(meta: &MetaMap) -> Vec<ValueListItem> {
-    value_list_values(meta)
-        .iter()
-        .map(|value| ValueListItem {
-            label: format_value_label(value),
-        })
-        .collect()
-}
+        InputKind::Panel => {
+            let text = match node.meta("userText") {
+                Some(MetaValue::Text(text)) => text.clone(),
+                _ => String::new(),
+            };
+            let multiline = node
+                .meta("Multiline")
+                .and_then(MetaValue::as_boolean)
+                .unwrap_or(true);
 
-fn value_list_values(meta: &MetaMap) -> Vec<Value> {
-    match meta
-        .get_normalized("ListItems")
-        .or_else(|| meta.get_normalized("Values"))
-    {
-        Some(MetaValue::List(entries)) => entries.iter().filter_map(meta_value_to_value).collect(),
-        _ => Vec::new(),
-    }
-}
+            Ok(InputControl::Panel {
+                id: binding.id.clone(),
+                name,
+                text,
+                multiline,
+            })
+        }
+        InputKind::ColourSwatch => {
+            let color = match node.meta("SwatchColorRGB") {
+                Some(MetaValue::List(channels)) => meta_numbers(channels)
+                    .and_then(|numbers| numbers.get(..3).map(|rgb| [rgb[0], rgb[1], rgb[2]])),
+                _ => None,
+            };
 
-fn value_list_selected_index(meta: &MetaMap, total_items: usize) -> usize {
-    let mut index = meta
-        .get_normalized("SelectedIndex")
-        .or_else(|| meta.get_normalized("Value"))
-        .and_then(meta_value_to_index)
-        .unwrap_or(0);
+            Ok(InputControl::ColourSwatch {
+                id: binding.id.clone(),
+                name,
+                color: color.unwrap_or([0.0; 3]),
+            })
+        }
+        InputKind::DigitScroller => Ok(InputControl::DigitScroller {
+            id: binding.id.clone(),
+            name,
+            value: meta_number(&node.meta, "Value")?.unwrap_or(0.0),
+        }),
+        InputKind::MdSlider => {
+            let value = match node.meta("Value") {
+                Some(MetaValue::List(coordinates)) => meta_numbers(coordinates)
+                    .and_then(|numbers| numbers.get(..2).map(|xy| [xy[0], xy[1]])),
+                _ => None,
+            };
 
-    if total_items == 0 {
-        return 0;
-    }
+            Ok(InputControl::MdSlider {
+                id: binding.id.clone(),
+                name,
+                value: value.unwrap_or([0.0; 2]),
+            })
+        }
+        InputKind::ColourPicker => {
+            let color = match node.meta("Value") {
+                Some(MetaValue::Text(text)) => parse_colour_text(text),
+                _ => None,
+            };
 
-    if index >= total_items {
-        index = total_items - 1;
+            Ok(InputControl::ColourPicker {
+                id: binding.id.clone(),
+                name,
+                color: color.unwrap_or([128.0; 3]),
+            })
+        }
+        InputKind::GraphMapper => Ok(InputControl::GraphMapper {
+            id: binding.id.clone(),
+            name,
+            points: components::params_input::graph_mapper_points(&node.meta)
+                .unwrap_or_else(|| components::params_input::IDENTITY_GRAPH.to_vec()),
+        }),
     }
+}
 
-    index
+/// Zet een JS-array van `[x, y]`-paren om naar een lijst punten.
+fn curve_points_value(points: JsValue) -> Result<Value, JsValue> {
+    let points: Vec<[f64; 2]> = serde_wasm_bindgen::from_value(points)
+        .map_err(|_| js_error("Graph Mapper curve moet een array van [x, y]-paren zijn"))?;
+    Ok(Value::List(
+        points
+            .into_iter()
+            .map(|[x, y]| Value::Point([x, y, 0.0]))
+            .collect(),
+    ))
 }
 
-fn meta_value_to_value(value: &MetaValue) -> Option<Value> {
-    match value {
-        MetaValue::Number(n) => Some(Value::Number(*n)),
-        MetaValue::Integer(i) => Some(Value::Number(*i as f64)),
-        MetaValue::Boolean(b) => Some(Value::Boolean(*b)),
-        MetaValue::Text(t) => Some(Value::Text(t.clone())),
+/// Schrijft een nieuwe waarde naar een input-node: de metadata die
+/// Grasshopper bewaart en de waarde op de output-pin. Een ongeldige waarde laat
+/// de node ongewijzigd.
+fn write_input_value(
+    node: &mut Node,
+    kind: InputKind,
+    output_pin: String,
+    value: Value,
+) -> Result<(), String> {
+    match kind {
+        InputKind::Slider => {
+            let value_f64 = match value {
+                Value::Number(n) => n,
+                _ => return Err("sliderwaarde moet een getal zijn".to_string()),
+            };
+
+            let clamped = snap_slider_value(&node.meta, value_f64)?;
+            node.insert_meta("value", clamped);
+            node.set_output(output_pin, Value::Number(clamped));
+        }
+        InputKind::Toggle => {
+            let value_bool = match value {
+                Value::Boolean(b) => b,
+                _ => return Err("togglewaarde moet een boolean zijn".to_string()),
+            };
+
+            node.insert_meta("Value", value_bool);
+            node.set_output(output_pin, Value::Boolean(value_bool));
+        }
+        InputKind::ValueList => {
+            let index_value = match value {
+                Value::Number(n) => n,
+                _ => return Err("Value List waarde moet een getal zijn".to_string()),
+            };
+
+            if !index_value.is_finite() {
+                return Err("Value List index moet een eindig getal zijn".to_string());
+            }
+
+            let items = value_list_values(&node.meta);
+            let selected_index = clamp_value_list_index(index_value, items.len());
+
+            node.insert_meta("SelectedIndex", selected_index as f64);
+            node.insert_meta("Value", selected_index as f64);
+
+            let output_value = items.get(selected_index).cloned().unwrap_or(Value::Null);
+            node.set_output(output_pin, output_value);
+        }
+        InputKind::Panel => {
+            let text = match value {
+                Value::Text(text) => text,
+                _ => return Err("paneltekst moet een tekst zijn".to_string()),
+            };
+
+            node.insert_meta("userText", text.clone());
+            node.set_output(output_pin, Value::Text(text));
+        }
+        InputKind::ColourSwatch => {
+            let [r, g, b] = colour_channels(&value)?;
+
+            let channels = vec![r.into(), g.into(), b.into()];
+            node.insert_meta("SwatchColorRGB", MetaValue::List(channels));
+            node.set_output(
+                output_pin,
+                Value::Color(ColorValue::from_rgb255(r, g, b)),
+            );
+        }
+        InputKind::DigitScroller => {
+            let number = match value {
+                Value::Number(n) if n.is_finite() => n,
+                _ => return Err("Digit Scroller waarde moet een eindig getal zijn".to_string()),
+            };
+
+            node.insert_meta("Value", number);
+            node.set_output(output_pin, Value::Number(number));
+        }
+        InputKind::MdSlider => {
+            let (x, y) = match &value {
+                Value::Point([x, y, _]) => (*x, *y),
+                Value::List(items) => match items.as_slice() {
+                    [Value::Number(x), Value::Number(y)] => (*x, *y),
+                    _ => return Err("MD Slider waarde moet een 2D-punt zijn".to_string()),
+                },
+                _ => return Err("MD Slider waarde moet een 2D-punt zijn".to_string()),
+            };
+
+            if !x.is_finite() || !y.is_finite() {
+                return Err("MD Slider waarde moet eindige coördinaten hebben".to_string());
+            }
+
+            node.insert_meta("Value", MetaValue::List(vec![x.into(), y.into()]));
+            node.set_output(
+                output_pin,
+                Value::List(vec![Value::Number(x), Value::Number(y)]),
+            );
+        }
+        InputKind::ColourPicker => {
+            let [r, g, b] = colour_channels(&value)?;
+
+            let text = format!("Color [A=255, R={r}, G={g}, B={b}]");
+            node.insert_meta("Value", text.clone());
+            node.set_output(output_pin, Value::Text(text));
+        }
+        InputKind::GraphMapper => {
+            let points = graph_mapper_points_from_value(&value)?;
+
+            let meta = points
+                .iter()
+                .map(|[x, y]| MetaValue::List(vec![(*x).into(), (*y).into()]))
+                .collect();
+            node.insert_meta("GraphPoints", MetaValue::List(meta));
+        }
+    }
+    Ok(())
+}
+
+fn has_incoming_wires(graph: &Graph, node_id: NodeId) -> bool {
+    graph.wires().iter().any(|wire| wire.to_node == node_id)
+}
+
+fn meta_numbers(values: &[MetaValue]) -> Option<Vec<f64>> {
+    values
+        .iter()
+        .map(|value| match value {
+            MetaValue::Number(n) => Some(*n),
+            MetaValue::Integer(i) => Some(*i as f64),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Leest de RGB-kanalen uit een Grasshopper-kleurtekst zoals
+/// `"Color [A=255, R=128, G=128, B=128]"`.
+fn parse_colour_text(text: &str) -> Option<[f64; 3]> {
+    let mut rgb = [None; 3];
+    for part in text.split(|c| matches!(c, '[' | ']' | ',')) {
+        let Some((key, value)) = part.split_once('=') else {
+            continue;
+        };
+        let slot = match key.trim() {
+            "R" => &mut rgb[0],
+            "G" => &mut rgb[1],
+            "B" => &mut rgb[2],
+            _ => continue,
+        };
+        *slot = value.trim().parse::<f64>().ok();
+    }
+    Some([rgb[0]?, rgb[1]?, rgb[2]?])
+}
+
+/// Zet een lijst punten om naar steunpunten van een Graph Mapper, gesorteerd
+/// op x.
+fn graph_mapper_points_from_value(value: &Value) -> Result<Vec<[f64; 2]>, String> {
+    let Value::List(items) = value else {
+        return Err("Graph Mapper curve moet een lijst punten zijn".to_string());
+    };
+
+    let mut points = Vec::with_capacity(items.len());
+    for item in items {
+        let point = match item {
+            Value::Point([x, y, _]) => [*x, *y],
+            Value::List(pair) => match pair.as_slice() {
+                [Value::Number(x), Value::Number(y)] => [*x, *y],
+                _ => return Err("Graph Mapper punt moet twee getallen hebben".to_string()),
+            },
+            _ => return Err("Graph Mapper curve moet een lijst punten zijn".to_string()),
+        };
+        if point.iter().any(|c| !c.is_finite()) {
+            return Err("Graph Mapper punt moet eindige coördinaten hebben".to_string());
+        }
+        points.push(point);
+    }
+
+    if points.len() < 2 {
+        return Err("Graph Mapper curve heeft minstens twee punten nodig".to_string());
+    }
+
+    points.sort_by(|a, b| a[0].total_cmp(&b[0]));
+    Ok(points)
+}
+
+/// Zet een kleur om naar kanalen in het bereik 0-255.
+fn colour_channels(value: &Value) -> Result<[f64; 3], String> {
+    match value {
+        Value::Color(color) => Ok([color.r, color.g, color.b].map(|c| (c * 255.0).round())),
+        _ => Err("kleurwaarde moet een kleur zijn".to_string()),
+    }
+}
+
+fn value_list_items(meta: &MetaMap) -> Vec<ValueListItem> {
+    value_list_values(meta)
+        .iter()
+        .map(|value| ValueListItem {
+            label: format_value_label(value),
+        })
+        .collect()
+}
+
+fn value_list_values(meta: &MetaMap) -> Vec<Value> {
+    match meta
+        .get_normalized("ListItems")
+        .or_else(|| meta.get_normalized("Values"))
+    {
+        Some(MetaValue::List(entries)) => entries.iter().filter_map(meta_value_to_value).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn value_list_selected_index(meta: &MetaMap, total_items: usize) -> usize {
+    let mut index = meta
+        .get_normalized("SelectedIndex")
+        .or_else(|| meta.get_normalized("Value"))
+        .and_then(meta_value_to_index)
+        .unwrap_or(0);
+
+    if total_items == 0 {
+        return 0;
+    }
+
+    if index >= total_items {
+        index = total_items - 1;
+    }
+
+    index
+}
+
+fn meta_value_to_value(value: &MetaValue) -> Option<Value> {
+    match value {
+        MetaValue::Number(n) => Some(Value::Number(*n)),
+        MetaValue::Integer(i) => Some(Value::Number(*i as f64)),
+        MetaValue::Boolean(b) => Some(Value::Boolean(*b)),
+        MetaValue::Text(t) => Some(Value::Text(t.clone())),
         MetaValue::List(list) => {
             let values: Vec<Value> = list.iter().filter_map(meta_value_to_value).collect();
 
@@ -784,6 +1131,14 @@ fn clamp_value_list_index(raw: f64, total_items: usize) -> usize {
     index as usize
 }
 
+fn is_hidden_node(graph: &Graph, node_id: NodeId) -> bool {
+    graph
+        .node(node_id)
+        .and_then(|node| node.meta("hidden"))
+        .and_then(MetaValue::as_boolean)
+        .unwrap_or(false)
+}
+
 fn append_geometry_items<'a>(entry: &'a GeometryEntry, items: &mut Vec<GeometryItem<'a>>) {
     append_geometry_value(&entry.value, entry.material, items);
 }
@@ -805,6 +1160,18 @@ fn append_geometry_value<'a>(
                 end: *p2,
             });
         }
+        Value::Curve(CurveValue::Line(line)) => {
+            items.push(GeometryItem::Line {
+                start: [line.start.x, line.start.y, line.start.z],
+                end: [line.end.x, line.end.y, line.end.z],
+            });
+        }
+        Value::Curve(curve) => {
+            // Exacte curves worden pas hier, bij de export, getesselleerd.
+            items.push(GeometryItem::Polyline {
+                points: curve.tessellate(CurveValue::DEFAULT_TESSELLATION),
+            });
+        }
         Value::Surface { vertices, faces } => {
             items.push(GeometryItem::Mesh {
                 vertices,
@@ -812,6 +1179,15 @@ fn append_geometry_value<'a>(
                 material: material.map(MaterialExport::from),
             });
         }
+        Value::Brep(brep) => {
+            // Ook breps worden pas bij de export gemesht.
+            let mesh = brep.to_mesh(BrepValue::DEFAULT_TESSELLATION);
+            items.push(GeometryItem::MeshOwned {
+                vertices: mesh.positions,
+                faces: mesh.indices.chunks_exact(3).map(<[u32]>::to_vec).collect(),
+                material: material.map(MaterialExport::from),
+            });
+        }
         Value::Mesh { vertices, indices, .. } => {
             // Convert triangle indices to polygon faces for the legacy output format
             let faces: Vec<Vec<u32>> = indices
@@ -834,12 +1210,28 @@ fn append_geometry_value<'a>(
                 }
             }
         }
+        Value::Tree(tree) => {
+            // Elke tak wordt als losse lijst behandeld zodat puntenlijsten per
+            // tak als polyline zichtbaar blijven.
+            for (_, branch) in tree.branches() {
+                if let Some(polyline) = list_as_polyline(branch) {
+                    items.push(GeometryItem::Polyline { points: polyline });
+                } else {
+                    for entry in branch {
+                        append_geometry_value(entry, material, items);
+                    }
+                }
+            }
+        }
         Value::Null
         | Value::Number(_)
         | Value::Vector(_)
+        | Value::Plane(_)
         | Value::Boolean(_)
         | Value::Domain(_)
         | Value::Matrix(_)
+        | Value::Transform(_)
+        | Value::Field(_)
         | Value::Text(_)
         | Value::DateTime(_)
         | Value::Complex(_)
@@ -869,8 +1261,64 @@ fn list_as_polyline(values: &[Value]) -> Option<Vec<[f64; 3]>> {
 #[cfg(test)]
 mod tests {
     use super::{GeometryEntry, GeometryItem, append_geometry_items, list_as_polyline};
+    use crate::geom::{Circle3, Point3, Vec3};
     use crate::graph::node::NodeId;
-    use crate::graph::value::Value;
+    use crate::graph::value::{CurveValue, Value};
+
+    #[test]
+    fn tessellates_exact_curves_on_export() {
+        let mut items = Vec::new();
+        let circle = Circle3::new(Point3::new(0.0, 0.0, 0.0), Vec3::new(0.0, 0.0, 1.0), 2.0);
+        let entry = GeometryEntry {
+            source_node: NodeId::new(0),
+            value: Value::Curve(CurveValue::Circle(circle)),
+            material: None,
+        };
+
+        append_geometry_items(&entry, &mut items);
+
+        assert_eq!(items.len(), 1);
+        match &items[0] {
+            GeometryItem::Polyline { points } => {
+                assert!(points.len() > 8);
+                assert_eq!(points.first(), points.last());
+                for point in points {
+                    let radius = (point[0].powi(2) + point[1].powi(2)).sqrt();
+                    assert!((radius - 2.0).abs() < 1e-9);
+                }
+            }
+            other => panic!("verwacht Polyline, kreeg {other:?}"),
+        }
+    }
+
+    #[test]
+    fn meshes_breps_on_export() {
+        use crate::geom::PlaneSurface;
+        use crate::graph::value::{BrepValue, SurfaceValue};
+
+        let plane = PlaneSurface::new(
+            Point3::new(0.0, 0.0, 0.0),
+            Vec3::new(2.0, 0.0, 0.0),
+            Vec3::new(0.0, 3.0, 0.0),
+        );
+        let entry = GeometryEntry {
+            source_node: NodeId::new(0),
+            value: Value::Brep(BrepValue::from_surface(SurfaceValue::Plane(plane))),
+            material: None,
+        };
+        let mut items = Vec::new();
+
+        append_geometry_items(&entry, &mut items);
+
+        match items.as_slice() {
+            [GeometryItem::MeshOwned { vertices, faces, .. }] => {
+                assert_eq!(vertices.len(), 4);
+                assert_eq!(faces.len(), 2);
+                assert!(vertices.contains(&[2.0, 3.0, 0.0]));
+            }
+            other => panic!("verwacht een mesh, kreeg {other:?}"),
+        }
+    }
 
     #[test]
     fn detects_polyline_from_point_list() {
@@ -961,6 +1409,37 @@ fn required_meta_number(meta: &MetaMap, key: &str) -> Result<f64, String> {
     meta_number(meta, key)?.ok_or_else(|| format!("meta sleutel `{key}` ontbreekt voor slider"))
 }
 
+/// Clampt een sliderwaarde binnen de range van de slider en rondt haar af
+/// op de step.
+fn snap_slider_value(meta: &MetaMap, value: f64) -> Result<f64, String> {
+    if !value.is_finite() {
+        return Err("sliderwaarde moet een eindig getal zijn".to_string());
+    }
+
+    let min = meta_number(meta, "min")?;
+    let max = meta_number(meta, "max")?;
+    let step = meta_number(meta, "step")?;
+
+    let mut clamped = clamp(
+        value,
+        min.unwrap_or(f64::NEG_INFINITY),
+        max.unwrap_or(f64::INFINITY),
+    );
+
+    if let Some(step) = step.filter(|s| *s > 0.0) {
+        if let Some(min) = min {
+            clamped = min + ((clamped - min) / step).round() * step;
+        }
+        clamped = clamp(
+            clamped,
+            min.unwrap_or(f64::NEG_INFINITY),
+            max.unwrap_or(f64::INFINITY),
+        );
+    }
+
+    Ok(clamped)
+}
+
 fn clamp(value: f64, min: f64, max: f64) -> f64 {
     value.max(min).min(max)
 }
@@ -986,90 +1465,287 @@ fn js_error(message: &str) -> JsValue {
 }
 
 impl Engine {
-    /// Interne methode om input waarden bij te werken (niet blootgesteld via WASM).
-    pub fn update_input_value(&mut self, id_or_name: &str, value: Value) -> Result<(), String> {
-        let index = match self.find_input_index(id_or_name) {
-            Some(index) => index,
-            None => return Err("onbekende inputreferentie".to_string()),
-        };
+    /// Vervangt de geladen graph en zet alle afgeleide toestand terug.
+    fn install_graph(&mut self, graph: Graph) -> Result<(), EvaluationError> {
+        let input_bindings = collect_input_bindings(&graph, &self.registry);
+        let evaluation_plan = EvaluationPlan::new(&graph)?;
+        let node_ids: Vec<NodeId> = graph.nodes().iter().map(|node| node.id).collect();
 
-        let graph = match self.graph.as_mut() {
+        self.graph = Some(graph);
+        self.input_bindings = input_bindings;
+        self.last_result = None;
+        self.last_errors.clear();
+        self.last_warnings.clear();
+        self.evaluation_plan = Some(evaluation_plan);
+        self.dirty_nodes.clear();
+        self.dirty_nodes.extend(node_ids);
+        self.result_dirty = true;
+        self.geometry_map.clear();
+        self.changed_nodes_since_geometry_update.clear();
+        self.buffer_fingerprints.clear();
+        self.changed_nodes_since_buffer_update.clear();
+        self.history.clear();
+
+        Ok(())
+    }
+
+    /// Laad een reeds geparste graph (Rust-variant van `load_ghx`).
+    pub fn load_graph(&mut self, graph: Graph) -> Result<(), EvaluationError> {
+        self.install_graph(graph)
+    }
+
+    /// De geladen graph, indien aanwezig.
+    #[must_use]
+    pub fn graph(&self) -> Option<&Graph> {
+        self.graph.as_ref()
+    }
+
+    /// Het resultaat van de laatste geslaagde evaluatie.
+    #[must_use]
+    pub fn last_result(&self) -> Option<&EvaluationResult> {
+        self.last_result.as_ref()
+    }
+
+    /// Het fouten- en waarschuwingenrapport van de laatste evaluatie
+    /// (Rust-variant van `get_error_report`).
+    #[must_use]
+    pub fn error_report(&self) -> EvaluationReport {
+        match self.graph.as_ref() {
+            Some(graph) => build_report(graph, &self.last_errors, &self.last_warnings),
+            None => EvaluationReport::default(),
+        }
+    }
+
+    /// Het dekkingsrapport van de geladen graph
+    /// (Rust-variant van `get_coverage_report`).
+    pub fn coverage_report(&self) -> Result<CoverageReport, String> {
+        let graph = self
+            .graph
+            .as_ref()
+            .ok_or_else(|| "er is geen GHX-bestand geladen".to_string())?;
+        Ok(build_coverage(graph, &self.registry, &self.catalog))
+    }
+
+    /// De geladen graph als GHX-archief (Rust-variant van `save_ghx`).
+    pub fn to_ghx(&self) -> Result<String, String> {
+        let graph = self
+            .graph
+            .as_ref()
+            .ok_or_else(|| "er is geen GHX-bestand geladen".to_string())?;
+        parse::ghx_writer::write_str(graph).map_err(|err| err.to_string())
+    }
+
+    /// Geeft toegang tot de catalogus, bv. om nodelist-bestanden in te lezen
+    /// vanuit Rust.
+    pub fn catalog_mut(&mut self) -> &mut Catalog {
+        &mut self.catalog
+    }
+
+    /// De input controls als JSON, in hetzelfde formaat als `get_sliders`.
+    pub fn input_controls_json(&self) -> Result<String, String> {
+        let controls = self.input_controls()?;
+        serde_json::to_string(&controls).map_err(|err| err.to_string())
+    }
+
+    /// Bouwt de actuele toestand van alle input controls op.
+    fn input_controls(&self) -> Result<Vec<InputControl>, String> {
+        let graph = match self.graph.as_ref() {
             Some(graph) => graph,
             None => return Err("er is geen GHX-bestand geladen".to_string()),
         };
 
-        let binding = self.input_bindings[index].clone();
-        let node = match graph.node_mut(binding.node_id) {
-            Some(node) => node,
-            None => return Err("interne inputreferentie is ongeldig".to_string()),
-        };
-
-        match binding.kind {
-            InputKind::Slider => {
-                let value_f64 = match value {
-                    Value::Number(n) => n,
-                    _ => return Err("sliderwaarde moet een getal zijn".to_string()),
-                };
+        let mut controls = Vec::with_capacity(self.input_bindings.len());
+        for binding in &self.input_bindings {
+            let node = graph
+                .node(binding.node_id)
+                .ok_or_else(|| "interne inputreferentie is ongeldig".to_owned())?;
+            controls.push(input_control_state(node, binding)?);
+        }
 
-                if !value_f64.is_finite() {
-                    return Err("sliderwaarde moet een eindig getal zijn".to_string());
-                }
+        Ok(controls)
+    }
 
-                let min = meta_number(&node.meta, "min")?;
-                let max = meta_number(&node.meta, "max")?;
-                let step = meta_number(&node.meta, "step")?;
+    /// Evalueert de dirty nodes van de geladen graph incrementeel
+    /// (Rust-variant van `evaluate`).
+    pub fn evaluate_graph(&mut self) -> Result<(), String> {
+        if !self.result_dirty && self.dirty_nodes.is_empty() {
+            return Ok(());
+        }
 
-                let mut clamped = clamp(
-                    value_f64,
-                    min.unwrap_or(f64::NEG_INFINITY),
-                    max.unwrap_or(f64::INFINITY),
-                );
+        let mut dirty_nodes = std::mem::take(&mut self.dirty_nodes);
 
-                if let Some(step) = step.filter(|s| *s > 0.0) {
-                    if let Some(min) = min {
-                        clamped = min + ((clamped - min) / step).round() * step;
-                    }
-                    clamped = clamp(
-                        clamped,
-                        min.unwrap_or(f64::NEG_INFINITY),
-                        max.unwrap_or(f64::INFINITY),
-                    );
-                }
+        let graph = match self.graph.as_ref() {
+            Some(graph) => graph,
+            None => {
+                self.dirty_nodes = dirty_nodes;
+                return Err("er is geen GHX-bestand geladen".to_string());
+            }
+        };
 
-                node.insert_meta("value", clamped);
-                node.set_output(binding.output_pin, Value::Number(clamped));
+        let plan = match self.evaluation_plan.as_ref() {
+            Some(plan) => plan,
+            None => {
+                self.dirty_nodes = dirty_nodes;
+                return Err("graph is niet voorbereid voor evaluatie".to_string());
             }
-            InputKind::Toggle => {
-                let value_bool = match value {
-                    Value::Boolean(b) => b,
-                    _ => return Err("togglewaarde moet een boolean zijn".to_string()),
-                };
+        };
 
-                node.insert_meta("Value", value_bool);
-                node.set_output(binding.output_pin, Value::Boolean(value_bool));
+        let previous = self.last_result.as_ref();
+        let evaluation = evaluator::evaluate_with_plan_incremental(
+            graph,
+            &self.registry,
+            plan,
+            previous,
+            &dirty_nodes,
+        );
+
+        match evaluation {
+            Ok((result, changed)) => {
+                self.last_errors = result.errors.clone();
+                self.last_warnings = result.warnings.clone();
+                self.last_result = Some(result);
+                self.result_dirty = false;
+                self.changed_nodes_since_buffer_update
+                    .extend(changed.iter().copied());
+                self.changed_nodes_since_geometry_update.extend(changed);
             }
-            InputKind::ValueList => {
-                let index_value = match value {
-                    Value::Number(n) => n,
-                    _ => return Err("Value List waarde moet een getal zijn".to_string()),
+            Err(error) => {
+                self.last_errors = vec![error.clone()];
+                self.last_warnings.clear();
+                self.dirty_nodes = dirty_nodes;
+                return Err(error.to_string());
+            }
+        }
+
+        dirty_nodes.clear();
+        Ok(())
+    }
+
+    /// Bepaalt de geometrie-diff sinds de vorige aanroep en werkt de
+    /// bijgehouden geometrie bij.
+    fn geometry_diff(&mut self) -> Result<GeometryDiff<'static>, String> {
+        if self.result_dirty {
+            return Err("graph is nog niet geëvalueerd".to_string());
+        }
+
+        let result = match self.last_result.as_ref() {
+            Some(result) => result,
+            None => {
+                let diff = GeometryDiff {
+                    removed: self.geometry_map.keys().map(|id| id.0).collect(),
+                    ..Default::default()
                 };
+                self.geometry_map.clear();
+                self.changed_nodes_since_geometry_update.clear();
+                return Ok(diff);
+            }
+        };
 
-                if !index_value.is_finite() {
-                    return Err("Value List index moet een eindig getal zijn".to_string());
-                }
+        let mut diff = GeometryDiff::default();
+        let graph = self.graph.as_ref().unwrap();
 
-                let items = value_list_values(&node.meta);
-                let selected_index = clamp_value_list_index(index_value, items.len());
+        let geometry_by_node: BTreeMap<NodeId, Vec<GeometryEntry>> =
+            result
+                .geometry
+                .iter()
+                .fold(BTreeMap::new(), |mut acc, entry| {
+                    acc.entry(entry.source_node)
+                        .or_default()
+                        .push(entry.clone());
+                    acc
+                });
+
+        let changed_nodes = std::mem::take(&mut self.changed_nodes_since_geometry_update);
+
+        for node_id in &changed_nodes {
+            let is_hidden = is_hidden_node(graph, *node_id);
 
-                node.insert_meta("SelectedIndex", selected_index as f64);
-                node.insert_meta("Value", selected_index as f64);
+            let new_items: Vec<GeometryItem<'static>> = if is_hidden {
+                Vec::new()
+            } else {
+                geometry_by_node
+                    .get(node_id)
+                    .map(|entries| {
+                        let mut items = Vec::new();
+                        for entry in entries {
+                            append_geometry_items(entry, &mut items);
+                        }
+                        items.into_iter().map(|item| item.deep_clone()).collect()
+                    })
+                    .unwrap_or_default()
+            };
 
-                let output_value = items.get(selected_index).cloned().unwrap_or(Value::Null);
-                node.set_output(binding.output_pin, output_value);
+            if let Some(existing_items) = self.geometry_map.get(node_id) {
+                if new_items.is_empty() {
+                    diff.removed.push(node_id.0);
+                    self.geometry_map.remove(node_id);
+                } else if existing_items.iter().ne(new_items.iter()) {
+                    diff.updated.push(GeometryDiffItem {
+                        id: node_id.0,
+                        items: new_items.clone(),
+                    });
+                    self.geometry_map.insert(*node_id, new_items);
+                }
+            } else if !new_items.is_empty() {
+                diff.added.push(GeometryDiffItem {
+                    id: node_id.0,
+                    items: new_items.clone(),
+                });
+                self.geometry_map.insert(*node_id, new_items);
             }
         }
 
-        self.dirty_nodes.insert(binding.node_id);
+        Ok(diff)
+    }
+
+    /// Interne methode om input waarden bij te werken (niet blootgesteld via WASM).
+    pub fn update_input_value(&mut self, id_or_name: &str, value: Value) -> Result<(), String> {
+        let (_, node) = self.stage_input_value(id_or_name, value)?;
+        self.commit_input_value(node)
+    }
+
+    /// Schrijft een nieuwe waarde naar een kopie van de input-node; de engine
+    /// zelf verandert niet. Geeft de binding van het control en de bijgewerkte
+    /// node terug, die met `commit_input_value` in de graph komt.
+    fn stage_input_value(
+        &self,
+        id_or_name: &str,
+        value: Value,
+    ) -> Result<(&InputBinding, Node), String> {
+        let index = match self.find_input_index(id_or_name) {
+            Some(index) => index,
+            None => return Err("onbekende inputreferentie".to_string()),
+        };
+
+        let graph = match self.graph.as_ref() {
+            Some(graph) => graph,
+            None => return Err("er is geen GHX-bestand geladen".to_string()),
+        };
+
+        let binding = &self.input_bindings[index];
+        let mut node = match graph.node(binding.node_id) {
+            Some(node) => node.clone(),
+            None => return Err("interne inputreferentie is ongeldig".to_string()),
+        };
+
+        write_input_value(&mut node, binding.kind, binding.output_pin.clone(), value)?;
+        Ok((binding, node))
+    }
+
+    /// Vervangt een input-node door zijn versie uit `stage_input_value`.
+    fn commit_input_value(&mut self, node: Node) -> Result<(), String> {
+        let graph = match self.graph.as_mut() {
+            Some(graph) => graph,
+            None => return Err("er is geen GHX-bestand geladen".to_string()),
+        };
+
+        let node_id = node.id;
+        match graph.node_mut(node_id) {
+            Some(target) => *target = node,
+            None => return Err("interne inputreferentie is ongeldig".to_string()),
+        }
+
+        self.dirty_nodes.insert(node_id);
         self.result_dirty = true;
         Ok(())
     }
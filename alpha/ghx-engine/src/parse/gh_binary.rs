@@ -0,0 +1,514 @@
+//! Parser voor binaire `.gh`-bestanden.
+//!
+//! Een `.gh`-bestand is een GH_IO-archief: dezelfde boom van chunks en items
+//! als het `<Archive>`-document in een `.ghx`, maar geschreven met een .NET
+//! `BinaryWriter` en daarna zlib-gecomprimeerd. Na het decoderen wordt de boom
+//! omgezet naar het [`RawChunk`]/[`RawItem`]-model van de XML-parser, zodat
+//! beide formaten dezelfde graph-opbouw delen.
+//!
+//! Layout (little-endian, strings met 7-bit gecodeerde lengte + UTF-8):
+//!
+//! ```text
+//! chunk := name:string index:i32 item_count:i32 item* chunk_count:i32 chunk*
+//! item  := type_code:i32 name:string index:i32 payload
+//! ```
+//!
+//! Een negatieve index betekent "geen index". De payload hangt af van het
+//! GH_Types-typecode (zie [`read_payload`]).
+
+use crate::graph::Graph;
+
+use super::ghx_xml::{self, ParseError, ParseResult, RawChunk, RawChunks, RawItem, RawItems};
+use super::inflate;
+
+/// Maximale nestingsdiepte van chunks; beschermt tegen corrupte archieven.
+const MAX_CHUNK_DEPTH: usize = 256;
+
+/// Leest een `.gh`-archief en converteert het naar een [`Graph`].
+///
+/// XML-invoer (een `.ghx` die als bytes is aangeleverd) wordt doorgestuurd
+/// naar [`ghx_xml::parse_str`].
+pub fn parse_bytes(bytes: &[u8]) -> ParseResult<Graph> {
+    if let Some(xml) = as_xml_text(bytes) {
+        return ghx_xml::parse_str(xml);
+    }
+    let root = read_archive(bytes)?;
+    ghx_xml::build_archive_graph(&root.chunks)
+}
+
+/// Decodeert de chunkboom van een `.gh`-archief zonder er een graph van te
+/// maken.
+pub(crate) fn read_archive(bytes: &[u8]) -> ParseResult<RawChunk> {
+    let payload = decompress(bytes)?;
+    let mut reader = ByteReader::new(&payload);
+    let root = read_chunk(&mut reader, 0)?;
+    Ok(root)
+}
+
+fn as_xml_text(bytes: &[u8]) -> Option<&str> {
+    let text = std::str::from_utf8(bytes).ok()?;
+    let trimmed = text.trim_start_matches('\u{feff}').trim_start();
+    trimmed.starts_with('<').then_some(text)
+}
+
+fn decompress(bytes: &[u8]) -> ParseResult<Vec<u8>> {
+    if inflate::is_zlib_header(bytes) {
+        return inflate::zlib_decompress(bytes).map_err(|err| binary_error(err.to_string()));
+    }
+    // Oudere writers schrijven ruwe DEFLATE-data zonder zlib-omhulling.
+    inflate::inflate(bytes).map_err(|err| binary_error(err.to_string()))
+}
+
+fn read_chunk(reader: &mut ByteReader<'_>, depth: usize) -> ParseResult<RawChunk> {
+    if depth > MAX_CHUNK_DEPTH {
+        return Err(binary_error("chunks zijn te diep genest"));
+    }
+
+    let name = reader.read_string()?;
+    let index = reader.read_index()?;
+
+    let item_count = reader.read_count()?;
+    let mut items = Vec::with_capacity(item_count.min(reader.remaining()));
+    for _ in 0..item_count {
+        items.push(read_item(reader)?);
+    }
+
+    let chunk_count = reader.read_count()?;
+    let mut chunks = Vec::with_capacity(chunk_count.min(reader.remaining()));
+    for _ in 0..chunk_count {
+        chunks.push(read_chunk(reader, depth + 1)?);
+    }
+
+    Ok(RawChunk {
+        name,
+        index,
+        items: RawItems { items },
+        chunks: RawChunks { chunks },
+    })
+}
+
+fn read_item(reader: &mut ByteReader<'_>) -> ParseResult<RawItem> {
+    let type_code = reader.read_i32()?;
+    let name = reader.read_string()?;
+    let index = reader.read_index()?;
+    let payload = read_payload(reader, type_code)?;
+
+    let mut item = RawItem {
+        name,
+        index,
+        type_name: Some(payload.type_name.to_owned()),
+        type_code: Some(type_code.to_string()),
+        ..RawItem::default()
+    };
+    match payload.text {
+        PayloadText::Text(text) => item.text = Some(text),
+        PayloadText::Argb(argb) => item.argb = Some(argb),
+        PayloadText::None => {}
+    }
+    Ok(item)
+}
+
+struct Payload {
+    type_name: &'static str,
+    text: PayloadText,
+}
+
+enum PayloadText {
+    Text(String),
+    Argb(String),
+    None,
+}
+
+impl Payload {
+    fn text(type_name: &'static str, text: String) -> Self {
+        Self {
+            type_name,
+            text: PayloadText::Text(text),
+        }
+    }
+
+    fn numbers(type_name: &'static str, values: &[f64]) -> Self {
+        Self::text(type_name, join_numbers(values))
+    }
+}
+
+/// Leest de payload van een item en zet die om naar dezelfde tekstvorm als
+/// in een `.ghx` (getallen met punt als decimaalteken, samengestelde waarden
+/// kommagescheiden en kleuren als `A;R;G;B`).
+fn read_payload(reader: &mut ByteReader<'_>, type_code: i32) -> ParseResult<Payload> {
+    let payload = match type_code {
+        1 => Payload::text("gh_bool", (reader.read_u8()? != 0).to_string()),
+        2 => Payload::text("gh_byte", reader.read_u8()?.to_string()),
+        3 => Payload::text("gh_int32", reader.read_i32()?.to_string()),
+        4 => Payload::text("gh_int64", reader.read_i64()?.to_string()),
+        5 => Payload::text("gh_single", reader.read_f32()?.to_string()),
+        6 => Payload::text("gh_double", reader.read_f64()?.to_string()),
+        7 => Payload::text("gh_decimal", read_decimal(reader)?),
+        8 => Payload::text("gh_date", reader.read_i64()?.to_string()),
+        9 => Payload::text("gh_guid", read_guid(reader)?),
+        10 => Payload::text("gh_string", reader.read_string()?),
+        20 => {
+            let length = reader.read_count()?;
+            reader.read_bytes(length)?;
+            Payload {
+                type_name: "gh_bytearray",
+                text: PayloadText::None,
+            }
+        }
+        21 => {
+            let length = reader.read_count()?;
+            let values = (0..length)
+                .map(|_| reader.read_f64())
+                .collect::<ParseResult<Vec<_>>>()?;
+            Payload::numbers("gh_doublearray", &values)
+        }
+        30 => Payload::numbers("gh_drawing_point", &read_i32s(reader, 2)?),
+        31 => Payload::numbers("gh_drawing_pointf", &read_f32s(reader, 2)?),
+        32 => Payload::numbers("gh_drawing_size", &read_i32s(reader, 2)?),
+        33 => Payload::numbers("gh_drawing_sizef", &read_f32s(reader, 2)?),
+        34 => Payload::numbers("gh_drawing_rectangle", &read_i32s(reader, 4)?),
+        35 => Payload::numbers("gh_drawing_rectanglef", &read_f32s(reader, 4)?),
+        36 => {
+            let [b, g, r, a] = reader.read_i32()?.to_le_bytes();
+            Payload {
+                type_name: "gh_drawing_color",
+                text: PayloadText::Argb(format!("{a};{r};{g};{b}")),
+            }
+        }
+        37 => {
+            let length = reader.read_count()?;
+            reader.read_bytes(length)?;
+            Payload {
+                type_name: "gh_drawing_bitmap",
+                text: PayloadText::None,
+            }
+        }
+        50 => Payload::numbers("gh_point2d", &read_f64s(reader, 2)?),
+        51 => Payload::numbers("gh_point3d", &read_f64s(reader, 3)?),
+        52 => Payload::numbers("gh_point4d", &read_f64s(reader, 4)?),
+        60 => Payload::numbers("gh_interval1d", &read_f64s(reader, 2)?),
+        61 => Payload::numbers("gh_interval2d", &read_f64s(reader, 4)?),
+        70 => Payload::numbers("gh_line", &read_f64s(reader, 6)?),
+        71 => Payload::numbers("gh_boundingbox", &read_f64s(reader, 6)?),
+        72 => Payload::numbers("gh_plane", &read_f64s(reader, 9)?),
+        80 => {
+            let [major, minor, revision] =
+                [reader.read_i32()?, reader.read_i32()?, reader.read_i32()?];
+            Payload::text("gh_version", format!("{major}.{minor}.{revision}"))
+        }
+        other => {
+            return Err(binary_error(format!("onbekend itemtype {other}")));
+        }
+    };
+    Ok(payload)
+}
+
+fn read_f64s(reader: &mut ByteReader<'_>, count: usize) -> ParseResult<Vec<f64>> {
+    (0..count).map(|_| reader.read_f64()).collect()
+}
+
+fn read_f32s(reader: &mut ByteReader<'_>, count: usize) -> ParseResult<Vec<f64>> {
+    (0..count)
+        .map(|_| reader.read_f32().map(f64::from))
+        .collect()
+}
+
+fn read_i32s(reader: &mut ByteReader<'_>, count: usize) -> ParseResult<Vec<f64>> {
+    (0..count)
+        .map(|_| reader.read_i32().map(f64::from))
+        .collect()
+}
+
+fn join_numbers(values: &[f64]) -> String {
+    values
+        .iter()
+        .map(f64::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Een .NET `Guid` bewaart de eerste drie groepen little-endian.
+fn read_guid(reader: &mut ByteReader<'_>) -> ParseResult<String> {
+    let b = reader.read_bytes(16)?;
+    Ok(format!(
+        "{:08x}-{:04x}-{:04x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
+        u32::from_le_bytes([b[0], b[1], b[2], b[3]]),
+        u16::from_le_bytes([b[4], b[5]]),
+        u16::from_le_bytes([b[6], b[7]]),
+        b[8],
+        b[9],
+        b[10],
+        b[11],
+        b[12],
+        b[13],
+        b[14],
+        b[15]
+    ))
+}
+
+/// Een .NET `decimal`: 96-bit mantisse, schaal en teken in het vlaggenwoord.
+fn read_decimal(reader: &mut ByteReader<'_>) -> ParseResult<String> {
+    let lo = reader.read_i32()? as u32;
+    let mid = reader.read_i32()? as u32;
+    let hi = reader.read_i32()? as u32;
+    let flags = reader.read_i32()? as u32;
+
+    let mantissa = (u128::from(hi) << 64) | (u128::from(mid) << 32) | u128::from(lo);
+    let scale = ((flags >> 16) & 0xFF) as usize;
+    let negative = flags & 0x8000_0000 != 0;
+
+    let mut digits = mantissa.to_string();
+    if scale > 0 {
+        if digits.len() <= scale {
+            digits = format!("{}{}", "0".repeat(scale + 1 - digits.len()), digits);
+        }
+        digits.insert(digits.len() - scale, '.');
+    }
+    if negative && mantissa != 0 {
+        digits.insert(0, '-');
+    }
+    Ok(digits)
+}
+
+fn binary_error(message: impl Into<String>) -> ParseError {
+    ParseError::Binary(message.into())
+}
+
+struct ByteReader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    fn remaining(&self) -> usize {
+        self.data.len() - self.position
+    }
+
+    fn read_bytes(&mut self, length: usize) -> ParseResult<&'a [u8]> {
+        let end = self
+            .position
+            .checked_add(length)
+            .filter(|end| *end <= self.data.len())
+            .ok_or_else(|| binary_error("onverwacht einde van het archief"))?;
+        let bytes = &self.data[self.position..end];
+        self.position = end;
+        Ok(bytes)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> ParseResult<[u8; N]> {
+        let mut array = [0u8; N];
+        array.copy_from_slice(self.read_bytes(N)?);
+        Ok(array)
+    }
+
+    fn read_u8(&mut self) -> ParseResult<u8> {
+        Ok(self.read_array::<1>()?[0])
+    }
+
+    fn read_i32(&mut self) -> ParseResult<i32> {
+        Ok(i32::from_le_bytes(self.read_array()?))
+    }
+
+    fn read_i64(&mut self) -> ParseResult<i64> {
+        Ok(i64::from_le_bytes(self.read_array()?))
+    }
+
+    fn read_f32(&mut self) -> ParseResult<f32> {
+        Ok(f32::from_le_bytes(self.read_array()?))
+    }
+
+    fn read_f64(&mut self) -> ParseResult<f64> {
+        Ok(f64::from_le_bytes(self.read_array()?))
+    }
+
+    /// Een aantal (items, chunks, arraylengte); negatief is ongeldig.
+    fn read_count(&mut self) -> ParseResult<usize> {
+        let count = self.read_i32()?;
+        usize::try_from(count).map_err(|_| binary_error(format!("negatief aantal {count}")))
+    }
+
+    fn read_index(&mut self) -> ParseResult<Option<usize>> {
+        Ok(usize::try_from(self.read_i32()?).ok())
+    }
+
+    /// .NET `BinaryWriter.Write(string)`: 7-bit gecodeerde bytelengte + UTF-8.
+    fn read_string(&mut self) -> ParseResult<String> {
+        let mut length: usize = 0;
+        let mut shift = 0;
+        loop {
+            let byte = self.read_u8()?;
+            length |= usize::from(byte & 0x7F) << shift;
+            if byte & 0x80 == 0 {
+                break;
+            }
+            shift += 7;
+            if shift > 28 {
+                return Err(binary_error("ongeldige stringlengte"));
+            }
+        }
+        let bytes = self.read_bytes(length)?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| binary_error("string is geen geldige UTF-8"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_bytes, read_archive};
+    use crate::graph::value::Value;
+
+    /// Schrijft een archief in dezelfde layout als GH_IO.
+    #[derive(Default)]
+    struct ArchiveWriter {
+        bytes: Vec<u8>,
+    }
+
+    impl ArchiveWriter {
+        fn string(&mut self, text: &str) -> &mut Self {
+            let mut length = text.len();
+            loop {
+                let byte = (length & 0x7F) as u8;
+                length >>= 7;
+                if length == 0 {
+                    self.bytes.push(byte);
+                    break;
+                }
+                self.bytes.push(byte | 0x80);
+            }
+            self.bytes.extend_from_slice(text.as_bytes());
+            self
+        }
+
+        fn i32(&mut self, value: i32) -> &mut Self {
+            self.bytes.extend_from_slice(&value.to_le_bytes());
+            self
+        }
+
+        fn f64(&mut self, value: f64) -> &mut Self {
+            self.bytes.extend_from_slice(&value.to_le_bytes());
+            self
+        }
+
+        fn chunk(&mut self, name: &str, index: i32, items: i32) -> &mut Self {
+            self.string(name).i32(index).i32(items)
+        }
+
+        fn item(&mut self, type_code: i32, name: &str) -> &mut Self {
+            self.i32(type_code).string(name).i32(-1)
+        }
+
+        fn guid(&mut self, name: &str, guid: [u8; 16]) -> &mut Self {
+            self.item(9, name);
+            self.bytes.extend_from_slice(&guid);
+            self
+        }
+
+        /// Het archief als zlib-stroom, zoals Grasshopper het opslaat.
+        fn zlib(&self) -> Vec<u8> {
+            miniz_oxide::deflate::compress_to_vec_zlib(&self.bytes, 6)
+        }
+    }
+
+    /// GUID van de Point-component uit `point_default.ghx` in .NET-bytevolgorde.
+    const POINT_GUID: [u8; 16] = [
+        0x2b, 0x0b, 0x09, 0x24, 0x2a, 0x43, 0xf2, 0x41, 0x84, 0xe0, 0x3d, 0x18, 0xa8, 0x9b, 0x7b,
+        0x90,
+    ];
+
+    /// Binaire tegenhanger van `tools/tools/ghx-samples/point_default.ghx`.
+    fn point_archive() -> ArchiveWriter {
+        let mut w = ArchiveWriter::default();
+        w.chunk("Root", -1, 1)
+            .item(80, "ArchiveVersion")
+            .i32(0)
+            .i32(2)
+            .i32(2)
+            .i32(1);
+        w.chunk("Definition", -1, 0).i32(1);
+        w.chunk("DefinitionObjects", -1, 1)
+            .item(3, "ObjectCount")
+            .i32(1)
+            .i32(1);
+        w.chunk("Object", 0, 2)
+            .guid("GUID", POINT_GUID)
+            .item(10, "Name")
+            .string("Point")
+            .i32(1);
+        w.chunk("Container", -1, 1)
+            .item(10, "NickName")
+            .string("DefaultPoint")
+            .i32(1);
+        w.chunk("param_input", 0, 1)
+            .item(10, "NickName")
+            .string("P")
+            .i32(1);
+        w.chunk("PersistentData", -1, 0).i32(1);
+        w.chunk("Branch", -1, 0).i32(1);
+        w.chunk("Item", 0, 1)
+            .item(51, "wrapper")
+            .f64(10.5)
+            .f64(20.0)
+            .f64(-5.2)
+            .i32(0);
+        // Sluit param_input, Container, Object, DefinitionObjects en Definition af.
+        w.i32(0).i32(0).i32(0).i32(0).i32(0);
+        w
+    }
+
+    #[test]
+    fn decodes_chunk_tree_with_typed_items() {
+        let root = read_archive(&point_archive().zlib()).expect("archive decoded");
+        assert_eq!(root.name, "Root");
+        let version = &root.items.items[0];
+        assert_eq!(version.type_name.as_deref(), Some("gh_version"));
+        assert_eq!(version.text.as_deref(), Some("0.2.2"));
+
+        let object = &root.chunks.chunks[0].chunks.chunks[0].chunks.chunks[0];
+        assert_eq!(object.index, Some(0));
+        assert_eq!(
+            object.items.items[0].text.as_deref(),
+            Some("24090b2b-432a-41f2-84e0-3d18a89b7b90")
+        );
+    }
+
+    #[test]
+    fn parses_binary_archive_into_graph() {
+        let graph = parse_bytes(&point_archive().zlib()).expect("graph parsed");
+        assert_eq!(graph.node_count(), 1);
+        let node = graph.nodes().first().unwrap();
+        assert_eq!(node.nickname.as_deref(), Some("DefaultPoint"));
+        match node.inputs.get("P") {
+            Some(Value::Point(p)) => assert_eq!(*p, [10.5, 20.0, -5.2]),
+            other => panic!("Expected a Point value, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn accepts_xml_bytes() {
+        let xml = include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../tools/tools/ghx-samples/point_default.ghx"
+        ));
+        let graph = parse_bytes(xml.as_bytes()).expect("xml graph parsed");
+        assert_eq!(graph.node_count(), 1);
+    }
+
+    #[test]
+    fn reports_truncated_archives() {
+        let mut archive = point_archive();
+        archive.bytes.truncate(archive.bytes.len() / 2);
+        let err = read_archive(&archive.zlib()).unwrap_err();
+        assert!(err.to_string().contains("onverwacht einde"));
+    }
+
+    #[test]
+    fn rejects_corrupt_compression() {
+        let mut bytes = point_archive().zlib();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xFF;
+        assert!(parse_bytes(&bytes).is_err());
+    }
+}
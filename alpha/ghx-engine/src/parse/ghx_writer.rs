@@ -0,0 +1,1105 @@
+//! Schrijft een [`Graph`] terug naar een GHX-archief (`<Archive>`) dat
+//! Grasshopper kan openen.
+//!
+//! De writer is de tegenhanger van [`ghx_xml`](super::ghx_xml): alles wat de
+//! parser uit een archief leest komt op dezelfde plek terug. Per node wordt
+//! een `Object`-chunk geschreven met de component-GUID en een `Container` met
+//! naam, nickname en een instance-GUID.
+//!
+//! - Componenten krijgen `param_input`/`param_output`-chunks. Draden worden
+//!   `Source`-items die naar de instance-GUID van de bronpin verwijzen;
+//!   persistente inputwaarden worden `PersistentData`.
+//! - Losse parameters, sliders, toggles, panels, value lists, colour swatches
+//!   en path mappers hebben geen pinchunks: hun bronnen en data staan op de
+//!   container zelf, net als de slider-, toggle- en value-list-toestand uit
+//!   de node-meta.
+//!
+//! Instance-GUIDs worden afgeleid van node-id en pinindex, zodat dezelfde
+//! graph altijd hetzelfde bestand oplevert. De oorspronkelijke
+//! canvasposities zijn niet bewaard; nodes worden per kolom (de diepte in de
+//! graph) onder elkaar gezet. Persistente waarden zonder GH_IO-vorm (bv.
+//! breps of meshes) leveren een [`WriteError`] op in plaats van stilzwijgend
+//! te verdwijnen.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+
+use quick_xml::escape::escape;
+use thiserror::Error;
+
+use super::ghx_xml::{
+    BOOLEAN_TOGGLE_GUID, COLOUR_SWATCH_GUID, PANEL_GUID, PATH_MAPPER_GUID, SLIDER_GUIDS,
+    VALUE_LIST_GUID, identify_floating_param, normalize_guid_str,
+};
+use crate::graph::Graph;
+use crate::graph::node::{MetaValue, Node, NodeId};
+use crate::graph::topo::Topology;
+use crate::graph::tree::TreePath;
+use crate::graph::value::{CurveValue, Domain, PlaneValue, Value, ValueKind};
+
+const COLUMN_WIDTH: f64 = 250.0;
+const ROW_HEIGHT: f64 = 120.0;
+
+/// Fouten tijdens het schrijven van een archief.
+#[derive(Debug, Error)]
+pub enum WriteError {
+    /// Een persistente inputwaarde heeft geen GHX-vorm.
+    #[error(
+        "input `{pin}` van node {node_id} bevat een {kind} die niet als GHX geschreven kan worden"
+    )]
+    UnsupportedValue {
+        node_id: usize,
+        pin: String,
+        kind: ValueKind,
+    },
+}
+
+/// Schrijft de graph als GHX-archief.
+pub fn write_str(graph: &Graph) -> Result<String, WriteError> {
+    let writer = ArchiveWriter::new(graph);
+    let objects = graph
+        .nodes()
+        .iter()
+        .enumerate()
+        .map(|(index, node)| writer.object_chunk(index, node))
+        .collect::<Result<Vec<Chunk>, WriteError>>()?;
+
+    let mut definition_objects = Chunk::new("DefinitionObjects");
+    definition_objects.item("ObjectCount", int32(objects.len() as i64));
+    definition_objects.chunks = objects;
+
+    let mut header = Chunk::new("DocumentHeader");
+    header.item(
+        "DocumentID",
+        guid(&instance_guid(NodeId::new(0), Role::Document, 0)),
+    );
+    header.item("Preview", string("Shaded"));
+
+    let mut properties = Chunk::new("DefinitionProperties");
+    properties.item("Description", string(""));
+    properties.item("Name", string("ghx-engine"));
+
+    let mut definition = Chunk::new("Definition");
+    definition.item("plugin_version", version(1, 0, 7));
+    definition.chunks = vec![header, properties, definition_objects];
+
+    let mut root = Chunk::new("Root");
+    root.item("ArchiveVersion", version(0, 2, 2));
+    root.chunks = vec![definition];
+
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"utf-8\" standalone=\"yes\"?>\n");
+    out.push_str("<Archive name=\"Root\">\n");
+    out.push_str("  <!--Grasshopper archive-->\n");
+    write_contents(&mut out, &root, 1);
+    out.push_str("</Archive>\n");
+    Ok(out)
+}
+
+/// Soort object waarvoor een instance-GUID wordt afgeleid.
+#[derive(Debug, Clone, Copy)]
+enum Role {
+    Object = 0,
+    Input = 1,
+    Output = 2,
+    Document = 0xffff,
+}
+
+fn instance_guid(node: NodeId, role: Role, index: usize) -> String {
+    format!(
+        "{:08x}-{:04x}-4000-8000-{:012x}",
+        index, role as u16, node.0
+    )
+}
+
+/// Houdt bij welke GUID bij welke outputpin hoort en waar elke node staat.
+struct ArchiveWriter<'a> {
+    graph: &'a Graph,
+    output_pins: HashMap<NodeId, Vec<String>>,
+    input_pins: HashMap<NodeId, Vec<String>>,
+    positions: HashMap<NodeId, (f64, f64)>,
+}
+
+impl<'a> ArchiveWriter<'a> {
+    fn new(graph: &'a Graph) -> Self {
+        let mut output_pins: HashMap<NodeId, Vec<String>> = HashMap::new();
+        let mut input_pins: HashMap<NodeId, Vec<String>> = HashMap::new();
+        for node in graph.nodes() {
+            output_pins.insert(node.id, node.output_order().to_vec());
+            input_pins.insert(node.id, node.input_order().to_vec());
+        }
+        // Pinnen die alleen via een draad bekend zijn (bv. uit het
+        // vereenvoudigde `<ghx>`-formaat) komen achteraan.
+        for wire in graph.wires() {
+            push_unique(
+                output_pins.entry(wire.from_node).or_default(),
+                &wire.from_pin.0,
+            );
+            push_unique(input_pins.entry(wire.to_node).or_default(), &wire.to_pin.0);
+        }
+
+        Self {
+            graph,
+            output_pins,
+            input_pins,
+            positions: layout(graph),
+        }
+    }
+
+    /// De GUID waarnaar een `Source`-item voor deze outputpin verwijst.
+    fn source_guid(&self, node_id: NodeId, pin: &str) -> String {
+        let node = self.graph.node(node_id);
+        if node.is_some_and(is_parameter) {
+            return instance_guid(node_id, Role::Object, 0);
+        }
+        let index = self.output_pins[&node_id]
+            .iter()
+            .position(|existing| existing == pin)
+            .unwrap_or(0);
+        instance_guid(node_id, Role::Output, index)
+    }
+
+    fn sources(&self, node_id: NodeId, pin: &str) -> Vec<String> {
+        self.graph
+            .wires()
+            .iter()
+            .filter(|wire| wire.to_node == node_id && wire.to_pin.0 == pin)
+            .map(|wire| self.source_guid(wire.from_node, &wire.from_pin.0))
+            .collect()
+    }
+
+    fn object_chunk(&self, index: usize, node: &Node) -> Result<Chunk, WriteError> {
+        let component_guid = node
+            .guid
+            .as_deref()
+            .and_then(normalize_guid_str)
+            .unwrap_or_default();
+        let name = node.name.clone().unwrap_or_default();
+
+        let mut object = Chunk::indexed("Object", index);
+        object.item("GUID", guid(&component_guid));
+        object.item("Name", string(&name));
+
+        let mut container = Chunk::new("Container");
+        container.item("Description", string(""));
+        container.item(
+            "InstanceGuid",
+            guid(&instance_guid(node.id, Role::Object, 0)),
+        );
+        container.item("Name", string(&name));
+        container.item("NickName", string(node.nickname.as_deref().unwrap_or("")));
+        if node.meta("hidden").and_then(MetaValue::as_boolean) == Some(true) {
+            container.item("Hidden", boolean(true));
+        }
+        container.chunks.push(self.attributes(node.id));
+
+        if is_parameter(node) {
+            self.write_parameter(&mut container, node, &component_guid)?;
+        } else {
+            self.write_component_params(&mut container, node)?;
+        }
+
+        object.chunks.push(container);
+        Ok(object)
+    }
+
+    fn attributes(&self, node_id: NodeId) -> Chunk {
+        let (x, y) = self.positions.get(&node_id).copied().unwrap_or_default();
+        let mut attributes = Chunk::new("Attributes");
+        attributes.item(
+            "Bounds",
+            fields(
+                "gh_drawing_rectanglef",
+                35,
+                &[("X", x - 50.0), ("Y", y - 20.0), ("W", 100.0), ("H", 40.0)],
+            ),
+        );
+        attributes.item(
+            "Pivot",
+            fields("gh_drawing_pointf", 31, &[("X", x), ("Y", y)]),
+        );
+        attributes
+    }
+
+    /// Losse parameters en invoerobjecten: bronnen, data en toestand staan op
+    /// de container.
+    fn write_parameter(
+        &self,
+        container: &mut Chunk,
+        node: &Node,
+        component_guid: &str,
+    ) -> Result<(), WriteError> {
+        let sources = self.sources(node.id, "Input");
+        container.item("Optional", boolean(false));
+        write_sources(container, &sources);
+
+        if let Some(pin) = self.output_pins[&node.id].first() {
+            write_mapping(container, node, "output", pin);
+        }
+        if let Some(data) = node_persistent_data(node, "Input")? {
+            container.chunks.push(data);
+        }
+
+        if SLIDER_GUIDS.contains(&component_guid) {
+            container.chunks.push(slider_chunk(node));
+        } else if component_guid == PANEL_GUID {
+            write_panel(container, node);
+        } else if component_guid == VALUE_LIST_GUID {
+            write_value_list(container, node);
+        } else if component_guid == BOOLEAN_TOGGLE_GUID {
+            let value = node.meta("Value").and_then(MetaValue::as_boolean);
+            container.item("ToggleValue", boolean(value.unwrap_or(false)));
+        } else if component_guid == COLOUR_SWATCH_GUID {
+            write_colour_swatch(container, node);
+        } else if component_guid == PATH_MAPPER_GUID {
+            write_path_masks(container, node);
+        }
+        Ok(())
+    }
+
+    fn write_component_params(&self, container: &mut Chunk, node: &Node) -> Result<(), WriteError> {
+        for (index, pin) in self.input_pins[&node.id].iter().enumerate() {
+            let instance = instance_guid(node.id, Role::Input, index);
+            let mut param = param_chunk("param_input", index, pin, &instance);
+            write_sources(&mut param, &self.sources(node.id, pin));
+            if let Some(expression) = node.input_expression(pin) {
+                param.item("InternalExpression", string(expression));
+            }
+            write_mapping(&mut param, node, "input", pin);
+            if let Some(data) = node_persistent_data(node, pin)? {
+                param.chunks.push(data);
+            }
+            container.chunks.push(param);
+        }
+
+        for (index, pin) in self.output_pins[&node.id].iter().enumerate() {
+            let instance = instance_guid(node.id, Role::Output, index);
+            let mut param = param_chunk("param_output", index, pin, &instance);
+            write_sources(&mut param, &[]);
+            write_mapping(&mut param, node, "output", pin);
+            container.chunks.push(param);
+        }
+        Ok(())
+    }
+}
+
+/// Objecten die de parser zonder pinchunks inleest.
+fn is_parameter(node: &Node) -> bool {
+    let guid = node.guid.as_deref().and_then(normalize_guid_str);
+    let guid = guid.as_deref();
+    if guid.is_some_and(|guid| {
+        SLIDER_GUIDS.contains(&guid)
+            || [
+                PANEL_GUID,
+                VALUE_LIST_GUID,
+                COLOUR_SWATCH_GUID,
+                BOOLEAN_TOGGLE_GUID,
+                PATH_MAPPER_GUID,
+            ]
+            .contains(&guid)
+    }) || identify_floating_param(guid).is_some()
+    {
+        return true;
+    }
+
+    // Relays en andere parameters met bronnen op de container.
+    node.input_order() == ["Input"] && node.output_order().iter().all(|pin| pin == "Output")
+}
+
+fn param_chunk(name: &'static str, index: usize, pin: &str, instance: &str) -> Chunk {
+    let mut param = Chunk::indexed(name, index);
+    param.item("Description", string(""));
+    param.item("InstanceGuid", guid(instance));
+    param.item("Name", string(pin));
+    param.item("NickName", string(pin));
+    param.item("Optional", boolean(false));
+    param
+}
+
+fn write_sources(chunk: &mut Chunk, sources: &[String]) {
+    for (index, source) in sources.iter().enumerate() {
+        chunk.indexed_item("Source", index, guid(source));
+    }
+    chunk.item("SourceCount", int32(sources.len() as i64));
+}
+
+fn write_mapping(chunk: &mut Chunk, node: &Node, direction: &str, pin: &str) {
+    let base = format!("{direction}.{pin}");
+    if let Some(MetaValue::Integer(mapping)) = node.meta(&format!("{base}.mapping")) {
+        chunk.item("Mapping", int32(*mapping));
+    }
+    if node.meta(&format!("{base}.reverse")) == Some(&MetaValue::Boolean(true)) {
+        chunk.item("ReverseData", boolean(true));
+    }
+    if node.meta(&format!("{base}.simplify")) == Some(&MetaValue::Boolean(true)) {
+        chunk.item("SimplifyData", boolean(true));
+    }
+}
+
+fn slider_chunk(node: &Node) -> Chunk {
+    let number = |key: &str| match node.meta(key) {
+        Some(MetaValue::Number(number)) => Some(*number),
+        Some(MetaValue::Integer(integer)) => Some(*integer as f64),
+        _ => None,
+    };
+    let step = number("step");
+    // Grasshopper kent geen stapgrootte, alleen het aantal decimalen.
+    let digits = step.map_or(3, |step| {
+        (0..=6)
+            .find(|digits| {
+                let scaled = step * 10_f64.powi(*digits);
+                (scaled - scaled.round()).abs() < 1e-9
+            })
+            .unwrap_or(6)
+    });
+
+    let mut slider = Chunk::new("Slider");
+    slider.item("Digits", int32(i64::from(digits)));
+    slider.item("GripDisplay", int32(1));
+    slider.item("Interval", int32(i64::from(digits == 0)));
+    if let Some(max) = number("max") {
+        slider.item("Max", double(max));
+    }
+    if let Some(min) = number("min") {
+        slider.item("Min", double(min));
+    }
+    slider.item("SnapCount", int32(0));
+    if let Some(step) = step {
+        slider.item("Step", double(step));
+    }
+    slider.item("Value", double(number("value").unwrap_or(0.0)));
+    slider
+}
+
+fn write_panel(container: &mut Chunk, node: &Node) {
+    if let Some(MetaValue::Text(text)) = node.meta("userText") {
+        container.item("UserText", string(text));
+    }
+    if let Some(multiline) = node.meta("Multiline").and_then(MetaValue::as_boolean) {
+        let mut properties = Chunk::new("PanelProperties");
+        properties.item("Multiline", boolean(multiline));
+        container.chunks.push(properties);
+    }
+}
+
+fn write_value_list(container: &mut Chunk, node: &Node) {
+    let Some(MetaValue::List(items)) = node.meta("ListItems") else {
+        return;
+    };
+    let names = match node.meta("ListNames") {
+        Some(MetaValue::List(names)) => names.as_slice(),
+        _ => &[],
+    };
+    let selected = match node.meta("SelectedIndex") {
+        Some(MetaValue::Number(index)) => *index as usize,
+        Some(MetaValue::Integer(index)) => *index as usize,
+        _ => 0,
+    };
+
+    container.item("ListCount", int32(items.len() as i64));
+    for (index, item) in items.iter().enumerate() {
+        let expression = meta_text(item);
+        let name = match names.get(index) {
+            Some(MetaValue::Text(name)) => name.clone(),
+            _ => expression.clone(),
+        };
+        let mut chunk = Chunk::indexed("ListItem", index);
+        chunk.item("Expression", string(&expression));
+        chunk.item("Name", string(&name));
+        chunk.item("Selected", boolean(index == selected));
+        container.chunks.push(chunk);
+    }
+}
+
+fn write_colour_swatch(container: &mut Chunk, node: &Node) {
+    let Some(MetaValue::List(rgb)) = node.meta("SwatchColorRGB") else {
+        return;
+    };
+    let channels: Vec<String> = rgb.iter().map(meta_text).collect();
+    if let [r, g, b] = channels.as_slice() {
+        container.item("SwatchColor", argb(format!("255;{r};{g};{b}")));
+    }
+}
+
+fn write_path_masks(container: &mut Chunk, node: &Node) {
+    let (Some(MetaValue::List(sources)), Some(MetaValue::List(targets))) =
+        (node.meta("SourceMasks"), node.meta("TargetMasks"))
+    else {
+        return;
+    };
+    for (index, (source, target)) in sources.iter().zip(targets).enumerate() {
+        let mut lexer = Chunk::indexed("Lexer", index);
+        lexer.item("Source", string(&meta_text(source)));
+        lexer.item("Target", string(&meta_text(target)));
+        container.chunks.push(lexer);
+    }
+}
+
+fn meta_text(value: &MetaValue) -> String {
+    match value {
+        MetaValue::Number(number) => number.to_string(),
+        MetaValue::Integer(integer) => integer.to_string(),
+        MetaValue::Boolean(flag) => flag.to_string(),
+        MetaValue::Text(text) => text.clone(),
+        MetaValue::List(items) => items.iter().map(meta_text).collect::<Vec<_>>().join(","),
+    }
+}
+
+/// De `PersistentData`-chunk van een inputpin, als die pin een waarde heeft.
+fn node_persistent_data(node: &Node, pin: &str) -> Result<Option<Chunk>, WriteError> {
+    let Some(value) = node.inputs.get(pin) else {
+        return Ok(None);
+    };
+    persistent_data(value).map_err(|kind| WriteError::UnsupportedValue {
+        node_id: node.id.0,
+        pin: pin.to_owned(),
+        kind,
+    })
+}
+
+/// Zet een inputwaarde om naar een `PersistentData`-chunk. Lijsten worden
+/// één tak, bomen houden hun paden. Een waarde zonder GHX-vorm geeft haar
+/// soort terug als fout.
+fn persistent_data(value: &Value) -> Result<Option<Chunk>, ValueKind> {
+    let branches: Vec<(TreePath, Vec<&Value>)> = match value {
+        Value::Null => return Ok(None),
+        Value::List(items) => vec![(TreePath::root(), items.iter().collect())],
+        Value::Tree(tree) => tree
+            .branches()
+            .map(|(path, items)| (path.clone(), items.iter().collect()))
+            .collect(),
+        other => vec![(TreePath::root(), vec![other])],
+    };
+
+    let mut data = Chunk::new("PersistentData");
+    for (branch_index, (path, items)) in branches.into_iter().enumerate() {
+        let mut branch = Chunk::indexed("Branch", branch_index);
+        for (index, value) in items.into_iter().enumerate() {
+            let mut item = Chunk::indexed("Item", index);
+            item.items = persistent_item(value)?;
+            branch.chunks.push(item);
+        }
+        branch.item("Count", int32(branch.chunks.len() as i64));
+        branch.item("Path", string(&path.to_string()));
+        data.chunks.push(branch);
+    }
+
+    if data.chunks.iter().all(|branch| branch.chunks.is_empty()) {
+        return Ok(None);
+    }
+    data.item("Count", int32(data.chunks.len() as i64));
+    Ok(Some(data))
+}
+
+/// De payload-items van één persistente waarde. Cirkels (vlak + straal) en
+/// polylijnen (geïndexeerde punten) beslaan meerdere items, net zoals de
+/// parser ze inleest.
+fn persistent_item(value: &Value) -> Result<Vec<(&'static str, ItemValue)>, ValueKind> {
+    let item = match value {
+        Value::Number(number) => ("number", double(*number)),
+        Value::Boolean(flag) => ("boolean", boolean(*flag)),
+        Value::Text(text) => ("text", string(text)),
+        Value::Point(point) => ("Coordinate", point3d(*point)),
+        Value::Vector(vector) => ("vector", point3d(*vector)),
+        Value::CurveLine { p1, p2 } => ("line", line(*p1, *p2)),
+        Value::Curve(CurveValue::Line(segment)) => (
+            "line",
+            line(
+                [segment.start.x, segment.start.y, segment.start.z],
+                [segment.end.x, segment.end.y, segment.end.z],
+            ),
+        ),
+        Value::Plane(plane) => ("plane", plane_item(plane)),
+        Value::Curve(CurveValue::Circle(circle)) => {
+            let plane = PlaneValue::from_axes(
+                [circle.center.x, circle.center.y, circle.center.z],
+                [circle.x_axis.x, circle.x_axis.y, circle.x_axis.z],
+                [circle.y_axis.x, circle.y_axis.y, circle.y_axis.z],
+            );
+            return Ok(vec![
+                ("plane", plane_item(&plane)),
+                ("radius", double(circle.radius)),
+            ]);
+        }
+        Value::Curve(CurveValue::Polyline(polyline)) => {
+            let points = polyline
+                .points()
+                .iter()
+                .map(|point| [point.x, point.y, point.z]);
+            return Ok(polyline_items(points, polyline.is_closed()));
+        }
+        // Oudere componenten geven polylijnen door als lijst van punten.
+        Value::List(items)
+            if items.len() > 1 && items.iter().all(|item| matches!(item, Value::Point(_))) =>
+        {
+            let points = items.iter().filter_map(|item| match item {
+                Value::Point(point) => Some(*point),
+                _ => None,
+            });
+            return Ok(polyline_items(points, false));
+        }
+        Value::Domain(Domain::One(domain)) => (
+            "Interval",
+            fields(
+                "gh_interval1d",
+                60,
+                &[("A", domain.start), ("B", domain.end)],
+            ),
+        ),
+        Value::Domain(Domain::Two(domain)) => (
+            "Interval",
+            fields(
+                "gh_interval2d",
+                61,
+                &[
+                    ("Au", domain.u.start),
+                    ("Bu", domain.u.end),
+                    ("Av", domain.v.start),
+                    ("Bv", domain.v.end),
+                ],
+            ),
+        ),
+        Value::Color(color) => {
+            let channel = |value: f64| (value * 255.0).round().clamp(0.0, 255.0);
+            (
+                "color",
+                argb(format!(
+                    "255;{};{};{}",
+                    channel(color.r),
+                    channel(color.g),
+                    channel(color.b)
+                )),
+            )
+        }
+        other => return Err(other.kind()),
+    };
+    Ok(vec![item])
+}
+
+fn polyline_items(
+    points: impl Iterator<Item = [f64; 3]>,
+    closed: bool,
+) -> Vec<(&'static str, ItemValue)> {
+    let mut items: Vec<(&'static str, ItemValue)> = points
+        .enumerate()
+        .map(|(index, point)| {
+            let mut vertex = point3d(point);
+            vertex.index = Some(index);
+            ("V", vertex)
+        })
+        .collect();
+    items.push(("closed", boolean(closed)));
+    items
+}
+
+/// Kolom = langste pad vanaf een bron, rij = volgorde binnen de kolom.
+fn layout(graph: &Graph) -> HashMap<NodeId, (f64, f64)> {
+    let order = Topology::sort(graph)
+        .map(|topology| topology.order)
+        .unwrap_or_else(|_| graph.nodes().iter().map(|node| node.id).collect());
+
+    let mut depth: HashMap<NodeId, usize> = HashMap::new();
+    for id in &order {
+        let column = graph
+            .wires()
+            .iter()
+            .filter(|wire| wire.to_node == *id)
+            .filter_map(|wire| depth.get(&wire.from_node))
+            .map(|column| column + 1)
+            .max()
+            .unwrap_or(0);
+        depth.insert(*id, column);
+    }
+
+    let mut rows: HashMap<usize, usize> = HashMap::new();
+    graph
+        .nodes()
+        .iter()
+        .map(|node| {
+            let column = depth.get(&node.id).copied().unwrap_or(0);
+            let row = rows.entry(column).or_default();
+            let position = (
+                100.0 + column as f64 * COLUMN_WIDTH,
+                100.0 + *row as f64 * ROW_HEIGHT,
+            );
+            *row += 1;
+            (node.id, position)
+        })
+        .collect()
+}
+
+fn push_unique(pins: &mut Vec<String>, pin: &str) {
+    if !pins.iter().any(|existing| existing == pin) {
+        pins.push(pin.to_owned());
+    }
+}
+
+/// Eén GH_IO-chunk in opbouw.
+#[derive(Debug)]
+struct Chunk {
+    name: &'static str,
+    index: Option<usize>,
+    items: Vec<(&'static str, ItemValue)>,
+    chunks: Vec<Chunk>,
+}
+
+impl Chunk {
+    fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            index: None,
+            items: Vec::new(),
+            chunks: Vec::new(),
+        }
+    }
+
+    fn indexed(name: &'static str, index: usize) -> Self {
+        Self {
+            index: Some(index),
+            ..Self::new(name)
+        }
+    }
+
+    fn item(&mut self, name: &'static str, value: ItemValue) {
+        self.items.push((name, value));
+    }
+
+    fn indexed_item(&mut self, name: &'static str, index: usize, mut value: ItemValue) {
+        value.index = Some(index);
+        self.items.push((name, value));
+    }
+}
+
+#[derive(Debug)]
+struct ItemValue {
+    index: Option<usize>,
+    type_name: &'static str,
+    type_code: u8,
+    body: ItemBody,
+}
+
+#[derive(Debug)]
+enum ItemBody {
+    Text(String),
+    Fields(Vec<(&'static str, f64)>),
+    Argb(String),
+}
+
+fn text_item(type_name: &'static str, type_code: u8, text: String) -> ItemValue {
+    ItemValue {
+        index: None,
+        type_name,
+        type_code,
+        body: ItemBody::Text(text),
+    }
+}
+
+fn string(text: &str) -> ItemValue {
+    text_item("gh_string", 10, text.to_owned())
+}
+
+fn guid(guid: &str) -> ItemValue {
+    text_item("gh_guid", 9, guid.to_owned())
+}
+
+fn boolean(flag: bool) -> ItemValue {
+    text_item("gh_bool", 1, flag.to_string())
+}
+
+fn int32(value: i64) -> ItemValue {
+    text_item("gh_int32", 3, value.to_string())
+}
+
+fn double(value: f64) -> ItemValue {
+    text_item("gh_double", 6, value.to_string())
+}
+
+fn point3d([x, y, z]: [f64; 3]) -> ItemValue {
+    fields("gh_point3d", 51, &[("X", x), ("Y", y), ("Z", z)])
+}
+
+fn line(a: [f64; 3], b: [f64; 3]) -> ItemValue {
+    fields(
+        "gh_line",
+        70,
+        &[
+            ("Ax", a[0]),
+            ("Ay", a[1]),
+            ("Az", a[2]),
+            ("Bx", b[0]),
+            ("By", b[1]),
+            ("Bz", b[2]),
+        ],
+    )
+}
+
+fn plane_item(plane: &PlaneValue) -> ItemValue {
+    let [ox, oy, oz] = plane.origin;
+    let [xx, xy, xz] = plane.x_axis;
+    let [yx, yy, yz] = plane.y_axis;
+    fields(
+        "gh_plane",
+        72,
+        &[
+            ("Ox", ox),
+            ("Oy", oy),
+            ("Oz", oz),
+            ("Xx", xx),
+            ("Xy", xy),
+            ("Xz", xz),
+            ("Yx", yx),
+            ("Yy", yy),
+            ("Yz", yz),
+        ],
+    )
+}
+
+fn fields(type_name: &'static str, type_code: u8, values: &[(&'static str, f64)]) -> ItemValue {
+    ItemValue {
+        index: None,
+        type_name,
+        type_code,
+        body: ItemBody::Fields(values.to_vec()),
+    }
+}
+
+fn argb(text: String) -> ItemValue {
+    ItemValue {
+        index: None,
+        type_name: "gh_drawing_color",
+        type_code: 36,
+        body: ItemBody::Argb(text),
+    }
+}
+
+fn version(major: i32, minor: i32, revision: i32) -> ItemValue {
+    fields(
+        "gh_version",
+        80,
+        &[
+            ("Major", f64::from(major)),
+            ("Minor", f64::from(minor)),
+            ("Revision", f64::from(revision)),
+        ],
+    )
+}
+
+/// Schrijft de items en kindchunks van een chunk, zonder de chunk-tag zelf.
+fn write_contents(out: &mut String, chunk: &Chunk, depth: usize) {
+    let indent = "  ".repeat(depth);
+
+    if !chunk.items.is_empty() {
+        let _ = writeln!(out, "{indent}<items count=\"{}\">", chunk.items.len());
+        for (name, item) in &chunk.items {
+            write_item(out, name, item, depth + 1);
+        }
+        let _ = writeln!(out, "{indent}</items>");
+    }
+
+    if !chunk.chunks.is_empty() {
+        let _ = writeln!(out, "{indent}<chunks count=\"{}\">", chunk.chunks.len());
+        for child in &chunk.chunks {
+            let inner = "  ".repeat(depth + 1);
+            match child.index {
+                Some(index) => {
+                    let _ = writeln!(
+                        out,
+                        "{inner}<chunk name=\"{}\" index=\"{index}\">",
+                        child.name
+                    );
+                }
+                None => {
+                    let _ = writeln!(out, "{inner}<chunk name=\"{}\">", child.name);
+                }
+            }
+            write_contents(out, child, depth + 2);
+            let _ = writeln!(out, "{inner}</chunk>");
+        }
+        let _ = writeln!(out, "{indent}</chunks>");
+    }
+}
+
+fn write_item(out: &mut String, name: &str, item: &ItemValue, depth: usize) {
+    let indent = "  ".repeat(depth);
+    let index = item
+        .index
+        .map(|index| format!(" index=\"{index}\""))
+        .unwrap_or_default();
+    let _ = write!(
+        out,
+        "{indent}<item name=\"{name}\"{index} type_name=\"{}\" type_code=\"{}\">",
+        item.type_name, item.type_code
+    );
+    match &item.body {
+        ItemBody::Text(text) => {
+            let _ = writeln!(out, "{}</item>", escape(text.as_str()));
+        }
+        ItemBody::Fields(values) => {
+            out.push('\n');
+            for (field, value) in values {
+                let _ = writeln!(out, "{indent}  <{field}>{value}</{field}>");
+            }
+            let _ = writeln!(out, "{indent}</item>");
+        }
+        ItemBody::Argb(text) => {
+            let _ = writeln!(out, "\n{indent}  <ARGB>{text}</ARGB>\n{indent}</item>");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{WriteError, write_str};
+    use crate::geom::{Circle3, Point3, Polyline3, Vec3};
+    use crate::graph::Graph;
+    use crate::graph::node::{MetaValue, Node, NodeId};
+    use crate::graph::value::{CurveValue, PlaneValue, Value, ValueKind};
+    use crate::graph::wire::Wire;
+    use crate::parse::ghx_xml::parse_str;
+
+    fn node_by_nickname<'a>(graph: &'a Graph, nickname: &str) -> &'a Node {
+        graph
+            .nodes()
+            .iter()
+            .find(|node| node.nickname.as_deref() == Some(nickname))
+            .unwrap_or_else(|| panic!("node {nickname} ontbreekt"))
+    }
+
+    #[test]
+    fn round_trips_wires_persistent_values_and_slider_state() {
+        let xml = include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../tools/ghx-samples/minimal_line.ghx"
+        ));
+        let mut graph = parse_str(xml).expect("graph parsed");
+        let slider = node_by_nickname(&graph, "Length").id;
+        graph.node_mut(slider).unwrap().insert_meta("value", 7.5);
+
+        let written = write_str(&graph).expect("graph written");
+        assert!(written.starts_with("<?xml"));
+        let reread = parse_str(&written).expect("written archive parses");
+
+        assert_eq!(reread.node_count(), graph.node_count());
+        assert_eq!(reread.wire_count(), graph.wire_count());
+
+        let slider = node_by_nickname(&reread, "Length");
+        assert_eq!(slider.meta("value"), Some(&MetaValue::Number(7.5)));
+        assert_eq!(slider.meta("step"), Some(&MetaValue::Number(0.5)));
+        assert_eq!(slider.meta("max"), Some(&MetaValue::Number(10.0)));
+
+        let end = node_by_nickname(&reread, "End Point");
+        assert_eq!(end.inputs.get("Y"), Some(&Value::Number(0.0)));
+        let line = node_by_nickname(&reread, "Result Line");
+        let mut incoming: Vec<_> = reread
+            .wires()
+            .iter()
+            .filter(|wire| wire.to_node == line.id)
+            .map(|wire| (wire.from_pin.0.as_str(), wire.to_pin.0.as_str()))
+            .collect();
+        incoming.sort_unstable();
+        assert_eq!(incoming, [("P", "A"), ("P", "B")]);
+        assert!(
+            reread
+                .wires()
+                .iter()
+                .any(|wire| wire.from_node == slider.id && wire.to_node == end.id)
+        );
+    }
+
+    #[test]
+    fn writes_toggle_value_list_and_typed_persistent_data() {
+        let mut graph = Graph::new();
+
+        let mut toggle = Node::new(NodeId::new(1));
+        toggle.guid = Some("{2e78987b-9dfb-42a2-8b76-3923ac8bd91a}".to_owned());
+        toggle.nickname = Some("Aan".to_owned());
+        toggle.insert_meta("Value", true);
+        toggle.set_output("Output", Value::Boolean(true));
+        graph.add_node(toggle).unwrap();
+
+        let mut list = Node::new(NodeId::new(2));
+        list.guid = Some("{00027467-0d24-4fa7-b178-8dc0ac5f42ec}".to_owned());
+        list.nickname = Some("Keuze".to_owned());
+        list.insert_meta(
+            "ListItems",
+            MetaValue::List(vec![
+                MetaValue::Number(1.0),
+                MetaValue::Text("\"a&b\"".into()),
+            ]),
+        );
+        list.insert_meta(
+            "ListNames",
+            MetaValue::List(vec!["Een".into(), "Tekst".into()]),
+        );
+        list.insert_meta("SelectedIndex", 1.0);
+        graph.add_node(list).unwrap();
+
+        let mut number = Node::new(NodeId::new(3));
+        number.guid = Some("{3e8ca6be-fda8-4aaf-b5c0-3c54c8bb7312}".to_owned());
+        number.nickname = Some("Getallen".to_owned());
+        number.set_input(
+            "Input",
+            Value::List(vec![Value::Number(1.5), Value::Number(-2.0)]),
+        );
+        number.set_output("Num", Value::Null);
+        graph.add_node(number).unwrap();
+
+        let mut point = Node::new(NodeId::new(4));
+        point.guid = Some("{3581f42a-9592-4549-bd6b-1c0fc39d067b}".to_owned());
+        point.nickname = Some("Punt".to_owned());
+        point.set_input("X", Value::Number(0.0));
+        point.add_input_pin("Y");
+        point.set_input("Z", Value::Vector([0.0, 0.0, 1.0]));
+        point.set_output("P", Value::Null);
+        graph.add_node(point).unwrap();
+        graph
+            .add_wire(Wire::new(NodeId::new(3), "Num", NodeId::new(4), "Y"))
+            .unwrap();
+
+        let written = write_str(&graph).expect("graph written");
+        let reread = parse_str(&written).expect("written archive parses");
+
+        let toggle = node_by_nickname(&reread, "Aan");
+        assert_eq!(toggle.meta("Value"), Some(&MetaValue::Boolean(true)));
+
+        let list = node_by_nickname(&reread, "Keuze");
+        assert_eq!(list.meta("SelectedIndex"), Some(&MetaValue::Number(1.0)));
+        assert_eq!(
+            list.meta("ListNames"),
+            Some(&MetaValue::List(vec!["Een".into(), "Tekst".into()]))
+        );
+        assert_eq!(
+            list.outputs.get("Output"),
+            Some(&Value::Text("\"a&b\"".into()))
+        );
+
+        let number = node_by_nickname(&reread, "Getallen");
+        assert_eq!(
+            number.inputs.get("Input"),
+            Some(&Value::List(vec![Value::Number(1.5), Value::Number(-2.0)]))
+        );
+
+        let point = node_by_nickname(&reread, "Punt");
+        assert_eq!(point.input_order(), ["X", "Y", "Z"]);
+        assert_eq!(point.inputs.get("Z"), Some(&Value::Vector([0.0, 0.0, 1.0])));
+        let wire = &reread.wires()[0];
+        assert_eq!(
+            (wire.from_node, wire.from_pin.0.as_str()),
+            (number.id, "Num")
+        );
+        assert_eq!((wire.to_node, wire.to_pin.0.as_str()), (point.id, "Y"));
+    }
+
+    #[test]
+    fn round_trips_planes_circles_and_polylines() {
+        let plane = PlaneValue::from_axes([1.0, 2.0, 3.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]);
+        let circle = Circle3::from_center_xaxis_normal(
+            Point3::new(0.0, 0.0, 1.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 0.0, 1.0),
+            2.5,
+        );
+        let square = Polyline3::new(
+            vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(1.0, 1.0, 0.0),
+                Point3::new(0.0, 1.0, 0.0),
+            ],
+            true,
+        )
+        .unwrap();
+
+        let mut graph = Graph::new();
+        let mut node = Node::new(NodeId::new(0));
+        node.guid = Some("{807b86e3-be8d-4970-92b5-f8cdcb45b06b}".to_owned());
+        node.nickname = Some("Vormen".to_owned());
+        node.set_input("P", Value::Plane(plane));
+        node.set_input(
+            "L",
+            Value::List(vec![
+                Value::Plane(plane),
+                Value::Plane(PlaneValue::default()),
+            ]),
+        );
+        node.set_input("C", Value::Curve(CurveValue::Circle(circle)));
+        node.set_input("Q", Value::Curve(CurveValue::Polyline(square)));
+        node.set_output("Out", Value::Null);
+        graph.add_node(node).unwrap();
+
+        let loaded = parse_str(&write_str(&graph).expect("graph written")).expect("archive parses");
+        let saved = write_str(&loaded).expect("loaded graph written");
+        let reloaded = parse_str(&saved).expect("saved archive parses");
+
+        let original = &graph.nodes()[0].inputs;
+        for graph in [&loaded, &reloaded] {
+            let inputs = &node_by_nickname(graph, "Vormen").inputs;
+            for pin in ["P", "L", "C", "Q"] {
+                assert_eq!(inputs.get(pin), original.get(pin), "pin {pin}");
+            }
+        }
+        assert!(saved.contains("gh_plane"));
+    }
+
+    #[test]
+    fn rejects_values_without_ghx_form() {
+        let mut graph = Graph::new();
+        let mut node = Node::new(NodeId::new(5));
+        node.guid = Some("{3581f42a-9592-4549-bd6b-1c0fc39d067b}".to_owned());
+        node.set_input(
+            "X",
+            Value::Surface {
+                vertices: vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+                faces: vec![vec![0, 1, 2]],
+            },
+        );
+        graph.add_node(node).unwrap();
+
+        let error = write_str(&graph).expect_err("surface heeft geen GHX-vorm");
+        let WriteError::UnsupportedValue { node_id, pin, kind } = error;
+        assert_eq!((node_id, pin.as_str(), kind), (5, "X", ValueKind::Surface));
+    }
+
+    #[test]
+    fn round_trips_a_grasshopper_authored_definition() {
+        let xml = include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../web/testfiles/brugtest.ghx"
+        ));
+        let graph = parse_str(xml).expect("graph parsed");
+        let reread =
+            parse_str(&write_str(&graph).expect("graph written")).expect("written archive parses");
+
+        assert_eq!(reread.node_count(), graph.node_count());
+        for node in graph.nodes() {
+            let copy = reread
+                .node(node.id)
+                .unwrap_or_else(|| panic!("node {} ontbreekt", node.id.0));
+            let label = node.nickname.as_deref().unwrap_or("?");
+            assert_eq!(copy.guid, node.guid, "guid van {label}");
+            assert_eq!(copy.name, node.name, "naam van {label}");
+            assert_eq!(copy.nickname, node.nickname, "nickname van {label}");
+            assert_eq!(copy.input_order(), node.input_order(), "inputs van {label}");
+            assert_eq!(
+                copy.output_order(),
+                node.output_order(),
+                "outputs van {label}"
+            );
+            assert_eq!(copy.inputs, node.inputs, "waarden van {label}");
+            assert_eq!(copy.meta, node.meta, "meta van {label}");
+            for pin in node.input_order() {
+                assert_eq!(
+                    copy.input_expression(pin),
+                    node.input_expression(pin),
+                    "expressie op {label}.{pin}"
+                );
+            }
+        }
+
+        let wires: HashSet<&Wire> = graph.wires().iter().collect();
+        let reread_wires: HashSet<&Wire> = reread.wires().iter().collect();
+        assert_eq!(reread.wire_count(), graph.wire_count());
+        assert_eq!(reread_wires, wires);
+    }
+}
@@ -1,17 +1,34 @@
 //! Parser voor GHX XML-bestanden.
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::num::{ParseFloatError, ParseIntError};
 
+use crate::geom::{Circle3, Point3, Polyline3, Vec3};
 use crate::graph::node::{MetaValue, Node, NodeId};
-use crate::graph::value::Value;
+use crate::graph::tree::{DataTree, TreePath};
+use crate::graph::value::{ColorValue, CurveValue, Domain, Domain1D, Domain2D, PlaneValue, Value};
 use crate::graph::wire::Wire;
 use crate::graph::{Graph, GraphError};
 
 use crate::components::coerce::parse_boolean_text;
+use crate::components::params_util::{CLUSTER_INPUT_GUID, CLUSTER_OUTPUT_GUID};
 
 const META_OUTPUT_PINS: &str = "OutputPins";
 
+pub(super) const SLIDER_GUIDS: [&str; 2] = [
+    "57da07bd-ecab-415d-9d86-af36d7073abc",
+    "5e0b22ab-f3aa-4cc2-8329-7e548bb9a58b",
+];
+pub(super) const PANEL_GUID: &str = "59e0b89a-e487-49f8-bab8-b5bab16be14c";
+pub(super) const VALUE_LIST_GUID: &str = "00027467-0d24-4fa7-b178-8dc0ac5f42ec";
+pub(super) const COLOUR_SWATCH_GUID: &str = "9c53bac0-ba66-40bd-8154-ce9829b9db1a";
+pub(super) const BOOLEAN_TOGGLE_GUID: &str = "2e78987b-9dfb-42a2-8b76-3923ac8bd91a";
+pub(super) const PATH_MAPPER_GUID: &str = "f9b89a46-bc5d-4f7a-9a6f-134f93ac3af9";
+pub(super) const DIGIT_SCROLLER_GUID: &str = "33bcf975-a0b2-4b54-99fd-585c893b9e88";
+pub(super) const MD_SLIDER_GUID: &str = "318dacd7-9073-4ede-b043-a0c132eb77e0";
+pub(super) const COLOUR_PICKER_GUID: &str = "339c0ee1-cf11-444f-8e10-65c9150ea755";
+pub(super) const GRAPH_MAPPER_GUID: &str = "bc984576-7aa6-491f-a91d-e444c33675a7";
+
 use quick_xml::de::from_str;
 use serde::Deserialize;
 use thiserror::Error;
@@ -37,6 +54,9 @@ pub enum ParseError {
     /// Fout tijdens het uitlezen van een node-index.
     #[error("ongeldige indexwaarde: {0}")]
     Index(#[from] ParseIntError),
+    /// Het binaire `.gh`-archief kon niet gedecodeerd worden.
+    #[error("binaire GH-fout: {0}")]
+    Binary(String),
 }
 
 impl From<GraphError> for ParseError {
@@ -154,27 +174,28 @@ fn build_simple_nodes(objects: Vec<GhxObject>) -> Vec<(usize, Node)> {
 fn parse_archive_document(input: &str) -> ParseResult<Graph> {
     log::debug!("Start parsing Archive-structuur GHX document");
     let document: ArchiveDocument = from_str(input)?;
+    build_archive_graph(&document.chunks)
+}
 
-    let definition = document
-        .chunks
+/// Bouwt een [`Graph`] uit de chunks onder de root van een GH_IO-archief.
+/// Gedeeld door de XML- en de binaire parser.
+pub(crate) fn build_archive_graph(root_chunks: &RawChunks) -> ParseResult<Graph> {
+    let definition = root_chunks
         .find_case_insensitive("Definition")
         .ok_or_else(|| ParseError::Graph("Definition chunk ontbreekt".to_owned()))?;
     let definition_objects = definition
         .find_case_insensitive("DefinitionObjects")
         .ok_or_else(|| ParseError::Graph("DefinitionObjects chunk ontbreekt".to_owned()))?;
 
-    let object_chunks: Vec<&RawChunk> = definition_objects
-        .children()
-        .filter(|chunk| chunk.name.eq_ignore_ascii_case("Object"))
-        .collect();
-
     let mut graph = Graph::new();
     let mut output_lookup: HashMap<String, (NodeId, String)> = HashMap::new();
     let mut pending_wires: Vec<PendingWire> = Vec::new();
 
-    let parsed_objects = collect_archive_objects(object_chunks)?;
+    let parsed_objects = collect_definition_objects(definition_objects, 0)?;
 
-    for parsed in parsed_objects {
+    for (index, mut parsed) in parsed_objects.into_iter().enumerate() {
+        // Na het uitklappen van clusters zijn de objectindexen niet meer uniek.
+        parsed.node.id = NodeId::new(index);
         let node_id = graph.add_node(parsed.node)?;
 
         if let Some(instance_guid) = parsed.instance_guid.as_ref() {
@@ -216,6 +237,204 @@ fn parse_archive_document(input: &str) -> ParseResult<Graph> {
     Ok(graph)
 }
 
+/// Leest alle objecten onder een `DefinitionObjects`-chunk. Clusters met een
+/// ingebedde definitie worden vervangen door hun (uitgeklapte) inhoud.
+fn collect_definition_objects(
+    definition_objects: &RawChunk,
+    depth: usize,
+) -> ParseResult<Vec<ArchiveObjectParseResult>> {
+    let object_chunks: Vec<&RawChunk> = definition_objects
+        .children()
+        .filter(|chunk| chunk.name.eq_ignore_ascii_case("Object"))
+        .collect();
+
+    let parsed_objects = collect_archive_objects(object_chunks.clone())?;
+
+    let mut collected = Vec::with_capacity(parsed_objects.len());
+    for (index, (chunk, parsed)) in object_chunks.into_iter().zip(parsed_objects).enumerate() {
+        let is_cluster = chunk
+            .item_value("GUID")
+            .and_then(normalize_guid_str)
+            .is_some_and(|guid| CLUSTER_GUIDS.contains(&guid.as_str()));
+        let document = if is_cluster {
+            find_cluster_definition(chunk)
+        } else {
+            None
+        };
+
+        match document {
+            Some(document) => {
+                collected.extend(expand_cluster(parsed, document, index, depth)?);
+            }
+            None => {
+                if is_cluster {
+                    log::warn!("Cluster zonder ingebedde definitie kan niet worden uitgeklapt");
+                }
+                collected.push(parsed);
+            }
+        }
+    }
+
+    Ok(collected)
+}
+
+const CLUSTER_GUIDS: &[&str] = &[
+    "865c8275-d9db-4b9a-92d4-883ef3b00b4a",
+    "f31d8d7a-7536-4ac8-9c96-fde6ecda4d0a",
+];
+const CLUSTER_DOCUMENT_CHUNK: &str = "ClusterDocument";
+const MAX_CLUSTER_DEPTH: usize = 32;
+
+/// Zoekt de `DefinitionObjects` van de definitie die in een cluster is
+/// ingebed. De ondiepste treffer hoort bij dit cluster; diepere treffers
+/// horen bij geneste clusters.
+fn find_cluster_definition(object: &RawChunk) -> Option<&RawChunk> {
+    let container = object.find_case_insensitive("Container")?;
+    let document = container
+        .find_case_insensitive(CLUSTER_DOCUMENT_CHUNK)
+        .or_else(|| object.find_case_insensitive(CLUSTER_DOCUMENT_CHUNK))?;
+
+    let mut queue: VecDeque<&RawChunk> = VecDeque::from([document]);
+    while let Some(chunk) = queue.pop_front() {
+        if chunk.name.eq_ignore_ascii_case("DefinitionObjects") {
+            return Some(chunk);
+        }
+        queue.extend(chunk.children());
+    }
+    None
+}
+
+/// Vervangt een cluster door de objecten uit zijn definitie. De Cluster
+/// Input-hooks nemen de bronnen en standaardwaarden van de clusterinputs
+/// over; de Cluster Output-hooks worden geregistreerd onder de GUID's van de
+/// clusteroutputs, zodat draden buiten het cluster er direct op aansluiten.
+fn expand_cluster(
+    cluster: ArchiveObjectParseResult,
+    document: &RawChunk,
+    index: usize,
+    depth: usize,
+) -> ParseResult<Vec<ArchiveObjectParseResult>> {
+    if depth >= MAX_CLUSTER_DEPTH {
+        return Err(ParseError::Graph("clusters zijn te diep genest".to_owned()));
+    }
+
+    let scope = cluster
+        .instance_guid
+        .clone()
+        .unwrap_or_else(|| format!("cluster-{depth}-{index}"));
+    let cluster_name = cluster
+        .node
+        .nickname
+        .clone()
+        .or_else(|| cluster.node.name.clone())
+        .unwrap_or_else(|| "Cluster".to_owned());
+
+    let mut inner = collect_definition_objects(document, depth + 1)?;
+
+    // Instance-GUID's binnen het cluster zijn alleen uniek binnen de
+    // clusterdefinitie; hetzelfde cluster kan meerdere keren voorkomen.
+    for object in &mut inner {
+        object.instance_guid = object
+            .instance_guid
+            .take()
+            .map(|guid| scoped_guid(&scope, &guid));
+        for output in &mut object.outputs {
+            output.guid = output.guid.take().map(|guid| scoped_guid(&scope, &guid));
+        }
+        for pending in &mut object.pending_inputs {
+            for source in &mut pending.sources {
+                *source = scoped_guid(&scope, source);
+            }
+        }
+        if object.node.meta("cluster").is_none() {
+            object.node.insert_meta("cluster", cluster_name.clone());
+        }
+    }
+
+    let input_hooks = cluster_hooks(&inner, CLUSTER_INPUT_GUID);
+    let mut used_inputs = vec![false; input_hooks.len()];
+    for pin in cluster.node.input_order() {
+        let Some(slot) = match_cluster_hook(&inner, &input_hooks, &mut used_inputs, pin) else {
+            log::warn!("Clusterinput '{pin}' heeft geen bijbehorende Cluster Input");
+            continue;
+        };
+        let hook = &mut inner[input_hooks[slot]];
+        hook.node.add_input_pin("Input");
+        if let Some(value) = cluster.node.inputs.get(pin) {
+            hook.node.set_input("Input", value.clone());
+        }
+        if let Some(pending) = cluster.pending_inputs.iter().find(|p| &p.pin == pin) {
+            hook.pending_inputs.push(PendingInput {
+                pin: "Input".to_owned(),
+                sources: pending.sources.clone(),
+            });
+        }
+    }
+
+    let output_hooks = cluster_hooks(&inner, CLUSTER_OUTPUT_GUID);
+    let mut used_outputs = vec![false; output_hooks.len()];
+    for output in &cluster.outputs {
+        let Some(slot) = match_cluster_hook(&inner, &output_hooks, &mut used_outputs, &output.pin)
+        else {
+            log::warn!(
+                "Clusteroutput '{}' heeft geen bijbehorende Cluster Output",
+                output.pin
+            );
+            continue;
+        };
+        let hook = &mut inner[output_hooks[slot]];
+        if let (Some(guid), Some(pin)) = (output.guid.clone(), hook.default_output_pin.clone()) {
+            hook.outputs.push(OutputInfo {
+                guid: Some(guid),
+                pin,
+            });
+        }
+    }
+
+    Ok(inner)
+}
+
+fn scoped_guid(scope: &str, guid: &str) -> String {
+    format!("{scope}/{guid}")
+}
+
+fn cluster_hooks(objects: &[ArchiveObjectParseResult], hook_guid: &str) -> Vec<usize> {
+    objects
+        .iter()
+        .enumerate()
+        .filter(|(_, object)| {
+            object
+                .node
+                .guid
+                .as_deref()
+                .and_then(normalize_guid_str)
+                .is_some_and(|guid| guid == hook_guid)
+        })
+        .map(|(position, _)| position)
+        .collect()
+}
+
+/// Koppelt een clusterpin aan een hook: eerst op nickname, anders de eerste
+/// nog ongebruikte hook in documentvolgorde.
+fn match_cluster_hook(
+    objects: &[ArchiveObjectParseResult],
+    hooks: &[usize],
+    used: &mut [bool],
+    pin: &str,
+) -> Option<usize> {
+    let by_name = hooks.iter().enumerate().position(|(slot, &position)| {
+        !used[slot]
+            && objects[position]
+                .node
+                .nickname
+                .as_deref()
+                .is_some_and(|nickname| nickname.eq_ignore_ascii_case(pin))
+    });
+    let slot = by_name.or_else(|| used.iter().position(|taken| !taken))?;
+    used[slot] = true;
+    Some(slot)
+}
+
 #[cfg(feature = "parallel")]
 fn collect_archive_objects(
     chunks: Vec<&RawChunk>,
@@ -276,22 +495,18 @@ fn parse_archive_object(chunk: &RawChunk, index: usize) -> ParseResult<ArchiveOb
         }
     }
 
-    let is_slider = component_guid_norm.as_deref().map_or(false, |guid| {
-        guid == "57da07bd-ecab-415d-9d86-af36d7073abc"
-            || guid == "5e0b22ab-f3aa-4cc2-8329-7e548bb9a58b"
-    });
-    let is_panel = component_guid_norm
-        .as_deref()
-        .map_or(false, |guid| guid == "59e0b89a-e487-49f8-bab8-b5bab16be14c");
-    let is_value_list = component_guid_norm
+    let is_slider = component_guid_norm
         .as_deref()
-        .map_or(false, |guid| guid == "00027467-0d24-4fa7-b178-8dc0ac5f42ec");
-    let is_colour_swatch = component_guid_norm
-        .as_deref()
-        .map_or(false, |guid| guid == "9c53bac0-ba66-40bd-8154-ce9829b9db1a");
-    let is_boolean_toggle = component_guid_norm
-        .as_deref()
-        .map_or(false, |guid| guid == "2e78987b-9dfb-42a2-8b76-3923ac8bd91a");
+        .map_or(false, |guid| SLIDER_GUIDS.contains(&guid));
+    let is_panel = component_guid_norm.as_deref() == Some(PANEL_GUID);
+    let is_value_list = component_guid_norm.as_deref() == Some(VALUE_LIST_GUID);
+    let is_colour_swatch = component_guid_norm.as_deref() == Some(COLOUR_SWATCH_GUID);
+    let is_boolean_toggle = component_guid_norm.as_deref() == Some(BOOLEAN_TOGGLE_GUID);
+    let is_path_mapper = component_guid_norm.as_deref() == Some(PATH_MAPPER_GUID);
+    let is_digit_scroller = component_guid_norm.as_deref() == Some(DIGIT_SCROLLER_GUID);
+    let is_md_slider = component_guid_norm.as_deref() == Some(MD_SLIDER_GUID);
+    let is_colour_picker = component_guid_norm.as_deref() == Some(COLOUR_PICKER_GUID);
+    let is_graph_mapper = component_guid_norm.as_deref() == Some(GRAPH_MAPPER_GUID);
 
     if is_slider {
         apply_slider_meta(container, &mut node);
@@ -322,6 +537,26 @@ fn parse_archive_object(chunk: &RawChunk, index: usize) -> ParseResult<ArchiveOb
         apply_colour_swatch_meta(container, &mut node);
     }
 
+    if is_path_mapper {
+        apply_path_mapper_meta(container, &mut node);
+    }
+
+    if is_digit_scroller {
+        apply_digit_scroller_meta(container, &mut node);
+    }
+
+    if is_md_slider {
+        apply_md_slider_meta(container, &mut node);
+    }
+
+    if is_colour_picker {
+        apply_colour_picker_meta(container, &mut node);
+    }
+
+    if is_graph_mapper {
+        apply_graph_mapper_meta(container, &mut node);
+    }
+
     if is_boolean_toggle {
         if let Some(val_str) = container.item_value("ToggleValue") {
             let value = parse_boolean_text(val_str).unwrap_or(false);
@@ -351,6 +586,15 @@ fn parse_archive_object(chunk: &RawChunk, index: usize) -> ParseResult<ArchiveOb
         });
     }
 
+    // Losse parameters zonder bronnen leveren hun geïnternaliseerde data.
+    if container_sources.is_empty()
+        && identify_floating_param(component_guid_norm.as_deref()).is_some()
+    {
+        if let Some(value) = parse_persistent_value(container) {
+            node.set_input("Input", value);
+        }
+    }
+
     let output_chunks = collect_param_chunks(container, &["param_output", "outputparam"]);
     for (output_index, output_chunk) in output_chunks.into_iter().enumerate() {
         let info = parse_param_chunk(
@@ -406,6 +650,11 @@ fn parse_archive_object(chunk: &RawChunk, index: usize) -> ParseResult<ArchiveOb
         Some("OUT".to_owned())
     } else if is_panel || is_value_list || is_colour_swatch || is_boolean_toggle {
         Some("Output".to_owned())
+    } else if is_digit_scroller || is_md_slider || is_colour_picker || is_graph_mapper {
+        if !node.outputs.contains_key("Output") {
+            node.set_output("Output", Value::Null);
+        }
+        Some("Output".to_owned())
     } else if let Some(param_name) = identify_floating_param(component_guid_norm.as_deref()) {
         if !node.outputs.contains_key(&param_name) {
             node.set_output(&param_name, Value::Null);
@@ -422,6 +671,14 @@ fn parse_archive_object(chunk: &RawChunk, index: usize) -> ParseResult<ArchiveOb
         None
     };
 
+    // Losse parameters bewaren hun datamodifiers op de container zelf; die
+    // gelden voor de data die de parameter doorgeeft.
+    if outputs.is_empty() {
+        if let Some(pin) = default_output_pin.as_deref() {
+            apply_pin_mapping_meta(&mut node, "output", pin, &parse_param_mapping(container));
+        }
+    }
+
     register_output_pin_names(&mut node);
 
     Ok(ArchiveObjectParseResult {
@@ -447,7 +704,7 @@ fn register_output_pin_names(node: &mut Node) {
     node.insert_meta(META_OUTPUT_PINS, MetaValue::List(pin_names));
 }
 
-fn identify_floating_param(guid: Option<&str>) -> Option<String> {
+pub(super) fn identify_floating_param(guid: Option<&str>) -> Option<String> {
     let guid = guid?;
     match guid {
         // Primitive Types
@@ -493,6 +750,9 @@ fn identify_floating_param(guid: Option<&str>) -> Option<String> {
         "f91778ca-2700-42fc-8ee6-74049a2292b5" => Some("Geometry Cache".to_owned()),
         "fa20fe95-5775-417b-92ff-b77c13cbf40c" => Some("MPoint".to_owned()),
 
+        // Cluster hooks
+        CLUSTER_INPUT_GUID | CLUSTER_OUTPUT_GUID => Some("Output".to_owned()),
+
         _ => None,
     }
 }
@@ -556,6 +816,7 @@ fn apply_slider_meta(container: &RawChunk, node: &mut Node) {
 
 fn apply_value_list_meta(container: &RawChunk, node: &mut Node) {
     let mut items = Vec::new();
+    let mut names = Vec::new();
     let mut selected_index = 0;
     let mut current_output_value = Value::Null;
 
@@ -602,13 +863,53 @@ fn apply_value_list_meta(container: &RawChunk, node: &mut Node) {
             Value::Boolean(b) => MetaValue::Boolean(b),
             _ => MetaValue::Text(expression), // Fallback
         });
+        names.push(MetaValue::Text(name));
     }
 
     node.insert_meta("ListItems", MetaValue::List(items));
+    node.insert_meta("ListNames", MetaValue::List(names));
     node.insert_meta("SelectedIndex", selected_index as f64);
     node.set_output("Output", current_output_value);
 }
 
+fn apply_path_mapper_meta(container: &RawChunk, node: &mut Node) {
+    // De lexicale maskers staan per mapping in een eigen chunk ("Lexer" of
+    // "Mapping"); oudere bestanden bewaren ze als losse items op de container.
+    // Losse `Source`-items op de container zijn wirebronnen, geen maskers.
+    let mut sources = Vec::new();
+    let mut targets = Vec::new();
+
+    for chunk in container.children().filter(|chunk| {
+        let name = chunk.name.to_ascii_lowercase();
+        name.contains("lexer") || name.contains("mapping")
+    }) {
+        let source = chunk
+            .item_value("Source")
+            .or_else(|| chunk.item_value("SourceLexer"));
+        let target = chunk
+            .item_value("Target")
+            .or_else(|| chunk.item_value("TargetLexer"));
+        if let (Some(source), Some(target)) = (source, target) {
+            sources.push(MetaValue::Text(source.to_owned()));
+            targets.push(MetaValue::Text(target.to_owned()));
+        }
+    }
+
+    if sources.is_empty() {
+        let container_sources = container.item_values("SourceLexer");
+        let container_targets = container.item_values("TargetLexer");
+        for (source, target) in container_sources.into_iter().zip(container_targets) {
+            sources.push(MetaValue::Text(source.to_owned()));
+            targets.push(MetaValue::Text(target.to_owned()));
+        }
+    }
+
+    if !sources.is_empty() {
+        node.insert_meta("SourceMasks", MetaValue::List(sources));
+        node.insert_meta("TargetMasks", MetaValue::List(targets));
+    }
+}
+
 fn apply_colour_swatch_meta(container: &RawChunk, node: &mut Node) {
     let argb_str = container.item_argb("SwatchColor").unwrap_or("255;0;0;0");
     let parts: Vec<&str> = argb_str.split(';').collect();
@@ -628,11 +929,116 @@ fn apply_colour_swatch_meta(container: &RawChunk, node: &mut Node) {
     node.set_output("Output", Value::Null);
 }
 
+/// Zoekt de items met de gegeven naam in een container en de chunks eronder.
+/// `Attributes` en `PersistentData` worden overgeslagen: daar staan geen
+/// bedieningswaarden.
+fn control_items<'a>(chunk: &'a RawChunk, name: &str, found: &mut Vec<&'a RawItem>) {
+    found.extend(
+        chunk
+            .items
+            .items
+            .iter()
+            .filter(|item| item.name.eq_ignore_ascii_case(name)),
+    );
+    for child in chunk.children().filter(|child| {
+        !child.name.eq_ignore_ascii_case("Attributes")
+            && !child.name.eq_ignore_ascii_case("PersistentData")
+    }) {
+        control_items(child, name, found);
+    }
+}
+
+fn control_item<'a>(container: &'a RawChunk, name: &str) -> Option<&'a RawItem> {
+    let mut found = Vec::new();
+    control_items(container, name, &mut found);
+    found.into_iter().next()
+}
+
+fn apply_digit_scroller_meta(container: &RawChunk, node: &mut Node) {
+    let Some(value) = control_item(container, "Value")
+        .and_then(|item| item.text.as_deref())
+        .and_then(parse_f64)
+    else {
+        return;
+    };
+    node.insert_meta("Value", value);
+    node.set_output("Output", Value::Number(value));
+}
+
+fn apply_md_slider_meta(container: &RawChunk, node: &mut Node) {
+    let Some([x, y]) = control_item(container, "Value")
+        .and_then(item_numbers)
+        .and_then(|numbers| numbers.get(..2).map(|xy| [xy[0], xy[1]]))
+    else {
+        return;
+    };
+    node.insert_meta("Value", MetaValue::List(vec![x.into(), y.into()]));
+    node.set_output("Output", Value::List(vec![Value::Number(x), Value::Number(y)]));
+}
+
+/// Slaat de kleur op in de tekstvorm die Grasshopper zelf toont, zodat de
+/// Colour Picker en de invoerbediening dezelfde waarde lezen.
+fn apply_colour_picker_meta(container: &RawChunk, node: &mut Node) {
+    let Some(item) = control_item(container, "Value") else {
+        return;
+    };
+    let channels: Option<Vec<f64>> = match item.argb.as_deref() {
+        Some(argb) => argb.split(';').map(parse_f64).collect(),
+        None => None,
+    };
+    let text = match channels.as_deref() {
+        Some([a, r, g, b]) => format!("Color [A={a}, R={r}, G={g}, B={b}]"),
+        Some([r, g, b]) => format!("Color [A=255, R={r}, G={g}, B={b}]"),
+        _ => match item.text.as_deref().map(str::trim) {
+            Some(text) if !text.is_empty() => text.to_owned(),
+            _ => return,
+        },
+    };
+    node.insert_meta("Value", text.clone());
+    node.set_output("Output", Value::Text(text));
+}
+
+/// Leest de steunpunten van een Graph Mapper. Elk `GraphPoints`-item is een
+/// `gh_point2d`; een item in tekstvorm mag ook `x,y`-paren gescheiden door
+/// `;` bevatten.
+fn apply_graph_mapper_meta(container: &RawChunk, node: &mut Node) {
+    let mut items = Vec::new();
+    control_items(container, "GraphPoints", &mut items);
+
+    let mut points = Vec::new();
+    for item in items {
+        if let Some([x, y, ..]) = item.field_numbers().as_deref() {
+            points.push([*x, *y]);
+            continue;
+        }
+        for pair in item.text.as_deref().unwrap_or_default().split(';') {
+            let numbers: Option<Vec<f64>> = pair.split(',').map(parse_f64).collect();
+            if let Some([x, y]) = numbers.as_deref() {
+                points.push([*x, *y]);
+            }
+        }
+    }
+
+    if points.is_empty() {
+        return;
+    }
+    points.sort_by(|a, b| a[0].total_cmp(&b[0]));
+    let meta = points
+        .into_iter()
+        .map(|[x, y]| MetaValue::List(vec![x.into(), y.into()]))
+        .collect();
+    node.insert_meta("GraphPoints", MetaValue::List(meta));
+}
+
 fn collect_param_chunks<'a>(root: &'a RawChunk, target_names: &[&str]) -> Vec<&'a RawChunk> {
     let mut collected = Vec::new();
 
     fn visit<'a>(chunk: &'a RawChunk, target_names: &[&str], output: &mut Vec<&'a RawChunk>) {
         for child in chunk.children() {
+            // Pinnen van objecten binnen een ingebed cluster horen niet bij dit object.
+            if child.name.eq_ignore_ascii_case(CLUSTER_DOCUMENT_CHUNK) {
+                continue;
+            }
             if target_names
                 .iter()
                 .any(|name| child.name.eq_ignore_ascii_case(name))
@@ -709,42 +1115,153 @@ fn normalize_pin_name(pin_name: String, component_guid: Option<&str>, is_output:
     pin_name
 }
 
+/// Leest de `PersistentData` van een parameter volledig in. Eén item blijft een
+/// losse waarde, meerdere items in één tak worden een [`Value::List`] en
+/// meerdere takken een [`Value::Tree`].
 fn parse_persistent_value(chunk: &RawChunk) -> Option<Value> {
     let persistent = chunk.find_case_insensitive("PersistentData")?;
-    let branch = persistent
-        .children()
-        .find(|child| child.name.eq_ignore_ascii_case("Branch"))?;
-    let item_chunk = branch
+
+    let mut branches: BTreeMap<TreePath, Vec<Value>> = BTreeMap::new();
+    let branch_chunks = persistent
         .children()
-        .find(|child| child.name.eq_ignore_ascii_case("Item"))?;
-    let value_item = select_persistent_value_item(item_chunk)?;
-    let text = value_item.text.as_deref()?.trim();
-    if text.is_empty() {
-        return None;
+        .filter(|child| child.name.eq_ignore_ascii_case("Branch"));
+    for (branch_index, branch) in branch_chunks.enumerate() {
+        let path = branch
+            .item_value("Path")
+            .and_then(TreePath::parse)
+            .unwrap_or_else(|| TreePath::new(vec![branch.index.unwrap_or(branch_index)]));
+
+        let mut item_chunks: Vec<(usize, &RawChunk)> = branch
+            .children()
+            .filter(|child| child.name.eq_ignore_ascii_case("Item"))
+            .enumerate()
+            .map(|(position, item)| (item.index.unwrap_or(position), item))
+            .collect();
+        item_chunks.sort_by_key(|(index, _)| *index);
+
+        branches.entry(path).or_default().extend(
+            item_chunks
+                .into_iter()
+                .filter_map(|(_, item)| parse_persistent_item(item)),
+        );
     }
 
-    let type_name = value_item
-        .type_name
-        .as_deref()
+    if branches.len() > 1 {
+        return Some(Value::Tree(DataTree::from_branches(branches)));
+    }
+    let (_, mut values) = branches.into_iter().next()?;
+    match values.len() {
+        0 => None,
+        1 => values.pop(),
+        _ => Some(Value::List(values)),
+    }
+}
+
+/// Zet één `Item`-chunk om naar een waarde. De meeste goo-typen schrijven één
+/// payload-item; cirkels (vlak + straal) en polylijnen (meerdere punten)
+/// worden uit meerdere items opgebouwd.
+fn parse_persistent_item(chunk: &RawChunk) -> Option<Value> {
+    let goo_type = chunk
+        .item_value("TypeName")
         .unwrap_or_default()
         .to_ascii_lowercase();
+    let payload: Vec<&RawItem> = chunk
+        .items
+        .items
+        .iter()
+        .filter(|item| is_persistent_value_item(item))
+        .collect();
 
-    if type_name.contains("point") {
-        if let Some(point) = parse_point_value(text) {
-            return Some(Value::Point(point));
-        }
+    if let Some(circle) = parse_persistent_circle(&payload) {
+        return Some(circle);
     }
 
-    if type_name.contains("vector") {
-        if let Some(vector) = parse_point_value(text) {
-            return Some(Value::Vector(vector));
-        }
+    let points: Vec<&RawItem> = payload
+        .iter()
+        .copied()
+        .filter(|item| item_type(item).contains("point"))
+        .collect();
+    if points.len() > 1 || (goo_type.contains("polyline") && !points.is_empty()) {
+        return parse_persistent_polyline(&points, &payload);
+    }
+
+    payload
+        .first()
+        .and_then(|item| parse_persistent_item_value(item, &goo_type))
+        .or_else(|| {
+            // Terugval voor onbekende structuren: de laatste tekstuele waarde.
+            let text = chunk.items.items.iter().rev().find(|item| has_text(item))?;
+            Some(Value::Text(text.text.as_deref()?.trim().to_owned()))
+        })
+}
+
+fn parse_persistent_item_value(item: &RawItem, goo_type: &str) -> Option<Value> {
+    let type_name = item_type(item);
+    let text = item.text.as_deref().map(str::trim).unwrap_or_default();
+    let is_vector =
+        goo_type.contains("vector") || item.name.to_ascii_lowercase().contains("vector");
+
+    if type_name.contains("point") || type_name.contains("vector") {
+        let coordinates = item_numbers(item)?;
+        let point = match coordinates.as_slice() {
+            [x, y] => [*x, *y, 0.0],
+            [x, y, z, ..] => [*x, *y, *z],
+            _ => return None,
+        };
+        return Some(if is_vector || type_name.contains("vector") {
+            Value::Vector(point)
+        } else {
+            Value::Point(point)
+        });
+    }
+
+    if type_name.contains("plane") {
+        let [ox, oy, oz, xx, xy, xz, yx, yy, yz] = item_numbers(item)?.try_into().ok()?;
+        return Some(Value::Plane(PlaneValue::from_axes(
+            [ox, oy, oz],
+            [xx, xy, xz],
+            [yx, yy, yz],
+        )));
+    }
+
+    if type_name.contains("line") {
+        let [ax, ay, az, bx, by, bz] = item_numbers(item)?.try_into().ok()?;
+        return Some(Value::CurveLine {
+            p1: [ax, ay, az],
+            p2: [bx, by, bz],
+        });
+    }
+
+    if type_name.contains("interval2d") {
+        let [u0, u1, v0, v1] = item_numbers(item)?.try_into().ok()?;
+        return Some(Value::Domain(Domain::Two(Domain2D {
+            u: domain1d(u0, u1),
+            v: domain1d(v0, v1),
+        })));
+    }
+
+    if type_name.contains("interval") {
+        let [start, end] = item_numbers(item)?.try_into().ok()?;
+        return Some(Value::Domain(Domain::One(domain1d(start, end))));
+    }
+
+    if type_name.contains("color") || type_name.contains("colour") {
+        let argb = item.argb.as_deref().unwrap_or(text);
+        let parts: Vec<f64> = argb.split(';').map(parse_f64).collect::<Option<Vec<_>>>()?;
+        let [_, r, g, b] = parts.try_into().ok()?;
+        return Some(Value::Color(ColorValue::from_rgb255(r, g, b)));
+    }
+
+    if text.is_empty() {
+        return None;
     }
 
     if type_name.contains("double")
         || type_name.contains("single")
         || type_name.contains("int")
         || type_name.contains("number")
+        || type_name.contains("decimal")
+        || type_name.contains("byte")
     {
         if let Some(number) = parse_f64(text) {
             return Some(Value::Number(number));
@@ -761,17 +1278,49 @@ fn parse_persistent_value(chunk: &RawChunk) -> Option<Value> {
     Some(Value::Text(text.to_owned()))
 }
 
-fn select_persistent_value_item(chunk: &RawChunk) -> Option<&RawItem> {
-    chunk
-        .items
-        .items
+fn parse_persistent_circle(payload: &[&RawItem]) -> Option<Value> {
+    let plane = payload
+        .iter()
+        .find(|item| item_type(item).contains("plane"))?;
+    let radius = payload
+        .iter()
+        .find(|item| item.name.eq_ignore_ascii_case("radius"))
+        .and_then(|item| parse_f64(item.text.as_deref()?))?;
+    let [ox, oy, oz, xx, xy, xz, yx, yy, yz] = item_numbers(plane)?.try_into().ok()?;
+
+    let x_axis = Vec3::new(xx, xy, xz);
+    let y_axis = Vec3::new(yx, yy, yz);
+    let circle = Circle3::from_center_xaxis_normal(
+        Point3::new(ox, oy, oz),
+        x_axis,
+        x_axis.cross(y_axis),
+        radius,
+    );
+    Some(Value::Curve(CurveValue::Circle(circle)))
+}
+
+fn parse_persistent_polyline(points: &[&RawItem], payload: &[&RawItem]) -> Option<Value> {
+    let mut vertices: Vec<(usize, Point3)> = Vec::with_capacity(points.len());
+    for (position, item) in points.iter().enumerate() {
+        let [x, y, z, ..] = item_numbers(item)?[..] else {
+            return None;
+        };
+        vertices.push((item.index.unwrap_or(position), Point3::new(x, y, z)));
+    }
+    vertices.sort_by_key(|(index, _)| *index);
+
+    let closed = payload
         .iter()
-        .find(|item| is_persistent_value_item(item))
-        .or_else(|| chunk.items.items.iter().rev().find(|item| has_text(item)))
+        .find(|item| item.name.eq_ignore_ascii_case("closed"))
+        .and_then(|item| parse_boolean_text(item.text.as_deref()?))
+        .unwrap_or(false);
+    let vertices = vertices.into_iter().map(|(_, point)| point).collect();
+    let polyline = Polyline3::new(vertices, closed).ok()?;
+    Some(Value::Curve(CurveValue::Polyline(polyline)))
 }
 
 fn is_persistent_value_item(item: &RawItem) -> bool {
-    if !has_text(item) {
+    if !has_text(item) && item.argb.is_none() && item.field_numbers().is_none() {
         return false;
     }
 
@@ -790,15 +1339,36 @@ fn has_text(item: &RawItem) -> bool {
         .unwrap_or(false)
 }
 
-fn parse_point_value(text: &str) -> Option<[f64; 3]> {
-    let parts: Vec<Option<f64>> = text.split(',').map(parse_f64).collect();
-    if parts.len() != 3 {
-        return None;
+fn item_type(item: &RawItem) -> String {
+    item.type_name
+        .as_deref()
+        .unwrap_or_default()
+        .to_ascii_lowercase()
+}
+
+/// Numerieke componenten van een samengesteld item: in `.ghx` als
+/// kindelementen (`<X>`, `<Ox>`, ...), in tekstvorm kommagescheiden.
+fn item_numbers(item: &RawItem) -> Option<Vec<f64>> {
+    if let Some(numbers) = item.field_numbers() {
+        return Some(numbers);
+    }
+    item.text
+        .as_deref()?
+        .split(',')
+        .map(|part| part.trim().parse::<f64>().ok())
+        .collect()
+}
+
+fn domain1d(start: f64, end: f64) -> Domain1D {
+    Domain1D {
+        start,
+        end,
+        min: start.min(end),
+        max: start.max(end),
+        span: end - start,
+        length: (end - start).abs(),
+        center: (start + end) / 2.0,
     }
-    let x = parts[0]?;
-    let y = parts[1]?;
-    let z = parts[2]?;
-    Some([x, y, z])
 }
 
 fn parse_f64(value: &str) -> Option<f64> {
@@ -806,7 +1376,7 @@ fn parse_f64(value: &str) -> Option<f64> {
     normalized.parse::<f64>().ok()
 }
 
-fn normalize_guid_str(raw: &str) -> Option<String> {
+pub(super) fn normalize_guid_str(raw: &str) -> Option<String> {
     let trimmed = raw.trim();
     if trimmed.is_empty() {
         return None;
@@ -966,43 +1536,120 @@ struct ArchiveDocument {
 }
 
 #[derive(Debug, Default, Deserialize)]
-struct RawChunks {
+pub(crate) struct RawChunks {
     #[serde(default, rename = "chunk")]
-    chunks: Vec<RawChunk>,
+    pub(crate) chunks: Vec<RawChunk>,
 }
 
 #[derive(Debug, Default, Deserialize)]
-struct RawItems {
+pub(crate) struct RawItems {
     #[serde(default, rename = "item")]
-    items: Vec<RawItem>,
+    pub(crate) items: Vec<RawItem>,
 }
 
 #[derive(Debug, Default, Deserialize)]
-struct RawChunk {
+pub(crate) struct RawChunk {
     #[serde(rename = "@name")]
-    name: String,
+    pub(crate) name: String,
     #[serde(rename = "@index")]
-    index: Option<usize>,
+    pub(crate) index: Option<usize>,
     #[serde(default)]
-    items: RawItems,
+    pub(crate) items: RawItems,
     #[serde(default)]
-    chunks: RawChunks,
+    pub(crate) chunks: RawChunks,
 }
 
-#[derive(Debug, Deserialize)]
-struct RawItem {
+#[derive(Debug, Default, Deserialize)]
+pub(crate) struct RawItem {
     #[serde(rename = "@name")]
-    name: String,
+    pub(crate) name: String,
     #[serde(rename = "@index")]
-    index: Option<usize>,
+    pub(crate) index: Option<usize>,
     #[serde(rename = "@type_name")]
-    type_name: Option<String>,
+    pub(crate) type_name: Option<String>,
     #[serde(rename = "@type_code")]
-    type_code: Option<String>,
+    pub(crate) type_code: Option<String>,
     #[serde(rename = "$text")]
-    text: Option<String>,
+    pub(crate) text: Option<String>,
     #[serde(rename = "ARGB", default)]
-    argb: Option<String>,
+    pub(crate) argb: Option<String>,
+    /// Componenten van samengestelde GH_IO-typen (punten, intervallen,
+    /// lijnen en vlakken) die in `.ghx` als kindelementen zijn geschreven.
+    #[serde(rename = "X", default)]
+    pub(crate) x: Option<String>,
+    #[serde(rename = "Y", default)]
+    pub(crate) y: Option<String>,
+    #[serde(rename = "Z", default)]
+    pub(crate) z: Option<String>,
+    #[serde(rename = "W", default)]
+    pub(crate) w: Option<String>,
+    #[serde(rename = "A", default)]
+    pub(crate) a: Option<String>,
+    #[serde(rename = "B", default)]
+    pub(crate) b: Option<String>,
+    #[serde(rename = "Ox", default)]
+    pub(crate) ox: Option<String>,
+    #[serde(rename = "Oy", default)]
+    pub(crate) oy: Option<String>,
+    #[serde(rename = "Oz", default)]
+    pub(crate) oz: Option<String>,
+    #[serde(rename = "Xx", default)]
+    pub(crate) xx: Option<String>,
+    #[serde(rename = "Xy", default)]
+    pub(crate) xy: Option<String>,
+    #[serde(rename = "Xz", default)]
+    pub(crate) xz: Option<String>,
+    #[serde(rename = "Yx", default)]
+    pub(crate) yx: Option<String>,
+    #[serde(rename = "Yy", default)]
+    pub(crate) yy: Option<String>,
+    #[serde(rename = "Yz", default)]
+    pub(crate) yz: Option<String>,
+    #[serde(rename = "Ax", default)]
+    pub(crate) ax: Option<String>,
+    #[serde(rename = "Ay", default)]
+    pub(crate) ay: Option<String>,
+    #[serde(rename = "Az", default)]
+    pub(crate) az: Option<String>,
+    #[serde(rename = "Bx", default)]
+    pub(crate) bx: Option<String>,
+    #[serde(rename = "By", default)]
+    pub(crate) by: Option<String>,
+    #[serde(rename = "Bz", default)]
+    pub(crate) bz: Option<String>,
+    #[serde(rename = "Au", default)]
+    pub(crate) au: Option<String>,
+    #[serde(rename = "Bu", default)]
+    pub(crate) bu: Option<String>,
+    #[serde(rename = "Av", default)]
+    pub(crate) av: Option<String>,
+    #[serde(rename = "Bv", default)]
+    pub(crate) bv: Option<String>,
+}
+
+impl RawItem {
+    /// Geeft de componenten uit kindelementen terug, van het grootste
+    /// volledige GH_IO-type naar het kleinste.
+    fn field_numbers(&self) -> Option<Vec<f64>> {
+        let groups: [&[&Option<String>]; 7] = [
+            &[
+                &self.ox, &self.oy, &self.oz, &self.xx, &self.xy, &self.xz, &self.yx, &self.yy,
+                &self.yz,
+            ],
+            &[&self.ax, &self.ay, &self.az, &self.bx, &self.by, &self.bz],
+            &[&self.au, &self.bu, &self.av, &self.bv],
+            &[&self.x, &self.y, &self.z, &self.w],
+            &[&self.x, &self.y, &self.z],
+            &[&self.x, &self.y],
+            &[&self.a, &self.b],
+        ];
+        groups.iter().find_map(|fields| {
+            fields
+                .iter()
+                .map(|field| field.as_deref().and_then(parse_f64))
+                .collect()
+        })
+    }
 }
 
 #[derive(Debug)]
@@ -1146,7 +1793,8 @@ impl RawChunk {
 mod tests {
     use super::parse_str;
     use crate::graph::node::MetaValue;
-    use crate::graph::value::Value;
+    use crate::graph::tree::TreePath;
+    use crate::graph::value::{ColorValue, CurveValue, Domain, PlaneValue, Value};
 
     #[test]
     fn parses_point_with_default_value() {
@@ -1168,6 +1816,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn inlines_nested_cluster_definitions() {
+        let xml = include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../tools/ghx-samples/nested_cluster.ghx"
+        ));
+        let graph = parse_str(xml).expect("graph with clusters parsed");
+
+        // Twee hooks + Addition, twee hooks + Multiplication en de Number-param.
+        assert_eq!(graph.node_count(), 7);
+        assert!(
+            graph
+                .nodes()
+                .iter()
+                .all(|node| node.name.as_deref() != Some("Cluster"))
+        );
+        let multiplication = graph
+            .nodes()
+            .iter()
+            .find(|node| node.name.as_deref() == Some("Multiplication"))
+            .unwrap();
+        assert_eq!(
+            multiplication.meta("cluster"),
+            Some(&MetaValue::Text("Double".to_owned()))
+        );
+        assert_eq!(graph.wire_count(), 6);
+    }
+
     #[test]
     fn parses_minimal_line_graph_with_slider_meta() {
         let xml = include_str!(concat!(
@@ -1278,6 +1954,164 @@ mod tests {
         assert!(!panel_text.is_empty());
     }
 
+    /// Bouwt een archief met één losse parameter (`guid`) waarvan de container
+    /// de opgegeven `PersistentData`-takken bevat.
+    fn floating_param_archive(guid: &str, branches: &str) -> String {
+        format!(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<Archive name="Root">
+  <chunks count="1">
+    <chunk name="Definition">
+      <chunks count="1">
+        <chunk name="DefinitionObjects">
+          <chunks count="1">
+            <chunk name="Object" index="0">
+              <items count="1">
+                <item name="GUID" type_name="gh_guid" type_code="9">{guid}</item>
+              </items>
+              <chunks count="1">
+                <chunk name="Container">
+                  <items count="1">
+                    <item name="NickName" type_name="gh_string" type_code="10">Data</item>
+                  </items>
+                  <chunks count="1">
+                    <chunk name="PersistentData">
+                      <chunks>{branches}</chunks>
+                    </chunk>
+                  </chunks>
+                </chunk>
+              </chunks>
+            </chunk>
+          </chunks>
+        </chunk>
+      </chunks>
+    </chunk>
+  </chunks>
+</Archive>
+"#
+        )
+    }
+
+    fn internalised_input(guid: &str, branches: &str) -> Value {
+        let graph = parse_str(&floating_param_archive(guid, branches)).expect("parsed");
+        let node = graph.nodes().first().unwrap();
+        node.inputs
+            .get("Input")
+            .cloned()
+            .expect("internalised data")
+    }
+
+    #[test]
+    fn parses_internalised_point_list_with_child_elements() {
+        let value = internalised_input(
+            "fbac3e32-f100-4292-8692-77240a42fd1a",
+            r#"<chunk name="Branch" index="0">
+                 <items><item name="Path" type_name="gh_string" type_code="10">{0}</item></items>
+                 <chunks>
+                   <chunk name="Item" index="1">
+                     <items><item name="Coordinate" type_name="gh_point3d" type_code="51"><X>4</X><Y>5</Y><Z>6</Z></item></items>
+                   </chunk>
+                   <chunk name="Item" index="0">
+                     <items><item name="Coordinate" type_name="gh_point3d" type_code="51"><X>1</X><Y>2</Y><Z>3</Z></item></items>
+                   </chunk>
+                 </chunks>
+               </chunk>"#,
+        );
+        assert_eq!(
+            value,
+            Value::List(vec![
+                Value::Point([1.0, 2.0, 3.0]),
+                Value::Point([4.0, 5.0, 6.0])
+            ])
+        );
+    }
+
+    #[test]
+    fn parses_internalised_multi_branch_tree() {
+        let value = internalised_input(
+            "16ef3e75-e315-4899-b531-d3166b42dac9",
+            r#"<chunk name="Branch" index="0">
+                 <items><item name="Path" type_name="gh_string" type_code="10">{0;0}</item></items>
+                 <chunks>
+                   <chunk name="Item" index="0">
+                     <items>
+                       <item name="TypeName" type_name="gh_string" type_code="10">Grasshopper.Kernel.Types.GH_Vector</item>
+                       <item name="vector" type_name="gh_point3d" type_code="51"><X>0</X><Y>0</Y><Z>1</Z></item>
+                     </items>
+                   </chunk>
+                 </chunks>
+               </chunk>
+               <chunk name="Branch" index="1">
+                 <items><item name="Path" type_name="gh_string" type_code="10">{0;1}</item></items>
+               </chunk>"#,
+        );
+        let Value::Tree(tree) = value else {
+            panic!("expected tree, got {value:?}");
+        };
+        assert_eq!(tree.branch_count(), 2);
+        assert_eq!(
+            tree.branch(&TreePath::new(vec![0, 0])),
+            Some(&[Value::Vector([0.0, 0.0, 1.0])][..])
+        );
+        assert_eq!(tree.branch(&TreePath::new(vec![0, 1])), Some(&[][..]));
+    }
+
+    #[test]
+    fn parses_internalised_geometry_item_types() {
+        let item = |items: &str| format!(r#"<chunk name="Item"><items>{items}</items></chunk>"#);
+        let branch = [
+            item(r#"<item name="plane" type_name="gh_plane" type_code="72"><Ox>1</Ox><Oy>0</Oy><Oz>0</Oz><Xx>1</Xx><Xy>0</Xy><Xz>0</Xz><Yx>0</Yx><Yy>1</Yy><Yz>0</Yz></item>"#),
+            item(r#"<item name="interval" type_name="gh_interval1d" type_code="60"><A>2</A><B>-1</B></item>"#),
+            item(r#"<item name="color" type_name="gh_drawing_color" type_code="36"><ARGB>255;255;0;51</ARGB></item>"#),
+            item(r#"<item name="line" type_name="gh_line" type_code="70"><Ax>0</Ax><Ay>0</Ay><Az>0</Az><Bx>3</Bx><By>0</By><Bz>0</Bz></item>"#),
+            item(r#"<item name="plane" type_name="gh_plane" type_code="72"><Ox>0</Ox><Oy>0</Oy><Oz>0</Oz><Xx>1</Xx><Xy>0</Xy><Xz>0</Xz><Yx>0</Yx><Yy>1</Yy><Yz>0</Yz></item>
+                   <item name="radius" type_name="gh_double" type_code="6">2.5</item>"#),
+            item(r#"<item name="V" index="0" type_name="gh_point3d" type_code="51"><X>0</X><Y>0</Y><Z>0</Z></item>
+                   <item name="V" index="1" type_name="gh_point3d" type_code="51"><X>1</X><Y>0</Y><Z>0</Z></item>
+                   <item name="V" index="2" type_name="gh_point3d" type_code="51"><X>1</X><Y>1</Y><Z>0</Z></item>"#),
+            item(r#"<item name="text" type_name="gh_string" type_code="10">internal</item>"#),
+        ]
+        .concat();
+        let value = internalised_input(
+            "ac2bc2cb-70fb-4dd5-9c78-7e1ea97fe278",
+            &format!(r#"<chunk name="Branch"><chunks>{branch}</chunks></chunk>"#),
+        );
+
+        let Value::List(items) = value else {
+            panic!("expected list, got {value:?}");
+        };
+        assert_eq!(items.len(), 7);
+        assert_eq!(
+            items[0],
+            Value::Plane(PlaneValue::new(
+                [1.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0],
+                [0.0, 0.0, 1.0],
+            ))
+        );
+        assert!(crate::components::coerce::coerce_plane(&items[0], "test").is_ok());
+        assert!(
+            matches!(&items[1], Value::Domain(Domain::One(d)) if d.start == 2.0 && d.end == -1.0)
+        );
+        assert_eq!(
+            items[2],
+            Value::Color(ColorValue::from_rgb255(255.0, 0.0, 51.0))
+        );
+        assert_eq!(
+            items[3],
+            Value::CurveLine {
+                p1: [0.0, 0.0, 0.0],
+                p2: [3.0, 0.0, 0.0]
+            }
+        );
+        assert!(matches!(&items[4], Value::Curve(CurveValue::Circle(c)) if c.radius == 2.5));
+        assert!(
+            matches!(&items[5], Value::Curve(CurveValue::Polyline(p)) if p.points().len() == 3)
+        );
+        assert_eq!(items[6], Value::Text("internal".to_owned()));
+    }
+
     #[test]
     fn parses_colour_swatch_meta() {
         let xml = r#"<?xml version="1.0" encoding="utf-8"?>
@@ -0,0 +1,127 @@
+//! DEFLATE- (RFC 1951) en zlib-decompressie (RFC 1950) via `miniz_oxide`.
+//!
+//! Binaire `.gh`-archieven zijn zlib-gecomprimeerd. `miniz_oxide` is pure
+//! Rust en compileert dus ook naar wasm. De uitvoer is begrensd, zodat een
+//! kwaadaardig of beschadigd archief niet onbeperkt geheugen kan opeisen.
+
+use core::fmt;
+
+use miniz_oxide::inflate::{
+    DecompressError, TINFLStatus, decompress_to_vec_with_limit, decompress_to_vec_zlib_with_limit,
+};
+
+/// Maximale grootte van een gedecomprimeerd archief.
+const MAX_OUTPUT_SIZE: usize = 256 * 1024 * 1024;
+
+/// Fout tijdens het decomprimeren.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct InflateError(String);
+
+impl fmt::Display for InflateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl InflateError {
+    fn from_miniz(error: &DecompressError, limit: usize) -> Self {
+        match error.status {
+            TINFLStatus::HasMoreOutput => {
+                Self(format!("gedecomprimeerde data is groter dan {limit} bytes"))
+            }
+            TINFLStatus::Adler32Mismatch => Self("Adler-32 controle mislukt".to_owned()),
+            _ => Self(format!("ongeldige DEFLATE-data: {error}")),
+        }
+    }
+}
+
+type InflateResult<T> = Result<T, InflateError>;
+
+/// Decomprimeert een zlib-stroom (header, DEFLATE-data en Adler-32 controle).
+pub(crate) fn zlib_decompress(data: &[u8]) -> InflateResult<Vec<u8>> {
+    zlib_decompress_with_limit(data, MAX_OUTPUT_SIZE)
+}
+
+fn zlib_decompress_with_limit(data: &[u8], limit: usize) -> InflateResult<Vec<u8>> {
+    if !is_zlib_header(data) {
+        return Err(InflateError("geen geldige zlib-header".to_owned()));
+    }
+    if data[1] & 0x20 != 0 {
+        return Err(InflateError(
+            "zlib-stromen met preset dictionary worden niet ondersteund".to_owned(),
+        ));
+    }
+    decompress_to_vec_zlib_with_limit(data, limit)
+        .map_err(|error| InflateError::from_miniz(&error, limit))
+}
+
+/// Decomprimeert ruwe DEFLATE-data zonder zlib-omhulling.
+pub(crate) fn inflate(data: &[u8]) -> InflateResult<Vec<u8>> {
+    decompress_to_vec_with_limit(data, MAX_OUTPUT_SIZE)
+        .map_err(|error| InflateError::from_miniz(&error, MAX_OUTPUT_SIZE))
+}
+
+/// Controleert of de eerste twee bytes een zlib-header vormen.
+pub(crate) fn is_zlib_header(data: &[u8]) -> bool {
+    data.len() >= 2
+        && data[0] & 0x0F == 8
+        && data[0] >> 4 <= 7
+        && (u16::from(data[0]) << 8 | u16::from(data[1])) % 31 == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{inflate, zlib_decompress, zlib_decompress_with_limit};
+
+    #[test]
+    fn decodes_fixed_huffman_zlib_stream() {
+        // zlib.compress(b"hello")
+        let data = [
+            0x78, 0x9c, 0xcb, 0x48, 0xcd, 0xc9, 0xc9, 0x07, 0x00, 0x06, 0x2c, 0x02, 0x15,
+        ];
+        assert_eq!(zlib_decompress(&data).unwrap(), b"hello");
+    }
+
+    #[test]
+    fn decodes_dynamic_huffman_zlib_stream() {
+        // zlib.compress(b"".join(b"%d," % (i * i % 97) for i in range(200)), 9)
+        let data = [
+            0x78, 0xda, 0xed, 0x90, 0xc9, 0x8d, 0x05, 0x21, 0x0c, 0x05, 0x13, 0xaa, 0x43, 0xdb,
+            0x06, 0x03, 0xf9, 0x27, 0x36, 0x05, 0x09, 0xfc, 0x04, 0x46, 0x42, 0x88, 0xe5, 0xad,
+            0xfe, 0x08, 0x06, 0x87, 0x68, 0x72, 0x52, 0xcd, 0x38, 0xf4, 0x60, 0x07, 0x45, 0x0e,
+            0xc6, 0x62, 0x25, 0x49, 0x05, 0x9d, 0x1c, 0x11, 0xc5, 0xfa, 0x88, 0x64, 0x16, 0x47,
+            0xb4, 0x5c, 0x05, 0x3c, 0x0f, 0xe6, 0xc7, 0xa6, 0x27, 0xb9, 0xd8, 0x9b, 0x39, 0xc8,
+            0x64, 0xab, 0xa6, 0xd4, 0x24, 0x82, 0xdd, 0xb4, 0x8c, 0x4d, 0x25, 0x21, 0x92, 0x53,
+            0xec, 0xc9, 0x3a, 0x2c, 0xf7, 0x7a, 0xeb, 0x5d, 0x7d, 0xf4, 0xab, 0x2f, 0x48, 0xa8,
+            0x04, 0x69, 0x92, 0xe3, 0x09, 0x29, 0xa7, 0xa8, 0xd2, 0x1a, 0x68, 0xa3, 0x99, 0x96,
+            0xfb, 0x9a, 0x1b, 0xe1, 0x06, 0x89, 0x17, 0xaa, 0x6f, 0x40, 0x63, 0x1a, 0xd6, 0xc8,
+            0x06, 0xef, 0x57, 0x22, 0x6f, 0x1d, 0x4b, 0x59, 0xad, 0x6e, 0x49, 0xab, 0x5a, 0xb8,
+            0x5e, 0x79, 0x47, 0x70, 0x9c, 0x45, 0xf0, 0xfd, 0xcf, 0xe4, 0xc7, 0x4c, 0xfe, 0x00,
+            0x90, 0x13, 0x6d, 0xad,
+        ];
+        let expected: String = (0..200u32).map(|i| format!("{},", i * i % 97)).collect();
+        assert_eq!(zlib_decompress(&data).unwrap(), expected.as_bytes());
+    }
+
+    #[test]
+    fn decodes_stored_blocks() {
+        let data = [0x01, 0x03, 0x00, 0xfc, 0xff, b'a', b'b', b'c'];
+        assert_eq!(inflate(&data).unwrap(), b"abc");
+    }
+
+    #[test]
+    fn rejects_corrupt_checksums() {
+        let data = [
+            0x78, 0x9c, 0xcb, 0x48, 0xcd, 0xc9, 0xc9, 0x07, 0x00, 0x06, 0x2c, 0x02, 0x16,
+        ];
+        assert!(zlib_decompress(&data).is_err());
+    }
+
+    #[test]
+    fn rejects_output_beyond_the_limit() {
+        let bomb = miniz_oxide::deflate::compress_to_vec_zlib(&[0u8; 4096], 9);
+        assert_eq!(zlib_decompress_with_limit(&bomb, 4096).unwrap().len(), 4096);
+        let error = zlib_decompress_with_limit(&bomb, 1024).unwrap_err();
+        assert!(error.to_string().contains("groter dan"), "{error}");
+    }
+}
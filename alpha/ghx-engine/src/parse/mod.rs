@@ -1,3 +1,7 @@
-//! Functies voor het inlezen van GHX-bestanden (stub).
+//! Functies voor het inlezen van GHX- en GH-bestanden en het terugschrijven
+//! naar GHX.
 
+pub mod gh_binary;
 pub mod ghx_xml;
+pub mod ghx_writer;
+mod inflate;
@@ -0,0 +1,622 @@
+//! Benoemde presets: momentopnamen van alle inputs van de geladen graph.
+//!
+//! Een preset bewaart per input-node (slider, toggle, value list, colour
+//! swatch, panel, digit scroller, MD slider, colour picker en graph mapper)
+//! de toestand die de gebruiker kan instellen. Bij het
+//! toepassen wordt elke waarde eerst op id en daarna op naam bij een node van
+//! hetzelfde soort gezocht, zodat een preset ook na het herladen van een
+//! aangepaste definitie bruikbaar blijft. Een preset wordt in zijn geheel
+//! gevalideerd voordat er iets verandert: lukt één waarde niet, dan blijft de
+//! graph ongewijzigd. Alleen nodes waarvan de toestand echt verandert worden
+//! dirty gemarkeerd.
+//!
+//! Presets horen bij de engine en niet bij de graph; ze blijven bewaard als er
+//! een ander GHX-bestand geladen wordt. Via JSON kunnen ze worden
+//! geëxporteerd en weer ingelezen.
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsError;
+use wasm_bindgen::prelude::*;
+
+use crate::components::{self, ComponentRegistry};
+use crate::graph::Graph;
+use crate::graph::node::{MetaLookupExt, MetaValue, Node, NodeId};
+use crate::graph::value::{ColorValue, Value};
+use crate::{
+    Engine, InputKind, has_incoming_wires, input_kind, input_output_pin, js_error, meta_numbers,
+    normalize_name, parse_colour_text, value_list_selected_index, value_list_values,
+    write_input_value,
+};
+
+/// Een benoemde verzameling inputwaarden.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Preset {
+    pub name: String,
+    pub inputs: Vec<PresetInput>,
+}
+
+/// De bewaarde toestand van één input-node.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PresetInput {
+    /// Node-id zoals in `get_sliders`.
+    pub id: String,
+    /// Nickname of naam van de node; wordt gebruikt als het id niet past.
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(flatten)]
+    pub value: PresetValue,
+}
+
+/// Waarde van een input, getagd met het soort control.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum PresetValue {
+    #[serde(rename = "slider")]
+    Slider { value: f64 },
+    #[serde(rename = "toggle")]
+    Toggle { value: bool },
+    #[serde(rename = "value-list")]
+    ValueList { selected_index: usize },
+    /// RGB-kanalen in het bereik 0-255, zoals `SwatchColorRGB`.
+    #[serde(rename = "colour-swatch")]
+    ColourSwatch { color: [f64; 3] },
+    #[serde(rename = "panel")]
+    Panel { text: String },
+    #[serde(rename = "digit-scroller")]
+    DigitScroller { value: f64 },
+    #[serde(rename = "md-slider")]
+    MdSlider { value: [f64; 2] },
+    /// RGB-kanalen in het bereik 0-255, zoals in de `Value`-tekst.
+    #[serde(rename = "colour-picker")]
+    ColourPicker { color: [f64; 3] },
+    /// Steunpunten van de mappingcurve, gesorteerd op x.
+    #[serde(rename = "graph-mapper")]
+    GraphMapper { points: Vec<[f64; 2]> },
+}
+
+impl PresetValue {
+    fn kind(&self) -> InputKind {
+        match self {
+            Self::Slider { .. } => InputKind::Slider,
+            Self::Toggle { .. } => InputKind::Toggle,
+            Self::ValueList { .. } => InputKind::ValueList,
+            Self::ColourSwatch { .. } => InputKind::ColourSwatch,
+            Self::Panel { .. } => InputKind::Panel,
+            Self::DigitScroller { .. } => InputKind::DigitScroller,
+            Self::MdSlider { .. } => InputKind::MdSlider,
+            Self::ColourPicker { .. } => InputKind::ColourPicker,
+            Self::GraphMapper { .. } => InputKind::GraphMapper,
+        }
+    }
+
+    /// De waarde zoals `update_input_value` haar verwacht.
+    fn to_value(&self) -> Value {
+        match self {
+            Self::Slider { value } | Self::DigitScroller { value } => Value::Number(*value),
+            Self::Toggle { value } => Value::Boolean(*value),
+            Self::ValueList { selected_index } => Value::Number(*selected_index as f64),
+            Self::ColourSwatch { color: [r, g, b] } | Self::ColourPicker { color: [r, g, b] } => {
+                Value::Color(ColorValue::from_rgb255(*r, *g, *b))
+            }
+            Self::Panel { text } => Value::Text(text.clone()),
+            Self::MdSlider { value: [x, y] } => {
+                Value::List(vec![Value::Number(*x), Value::Number(*y)])
+            }
+            Self::GraphMapper { points } => Value::List(
+                points
+                    .iter()
+                    .map(|[x, y]| Value::Point([*x, *y, 0.0]))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Engine {
+    /// Bewaar de huidige inputwaarden als preset met deze naam. Een bestaande
+    /// preset met dezelfde naam wordt overschreven.
+    #[wasm_bindgen]
+    pub fn save_preset(&mut self, name: &str) -> Result<(), JsValue> {
+        self.capture_preset(name).map_err(|err| js_error(&err))
+    }
+
+    /// Geeft alle presets terug, gesorteerd op naam.
+    #[wasm_bindgen]
+    pub fn list_presets(&self) -> Result<JsValue, JsValue> {
+        serde_wasm_bindgen::to_value(self.presets())
+            .map_err(|err| JsError::new(&err.to_string()).into())
+    }
+
+    /// Past de preset met deze naam toe. Geeft het aantal gewijzigde inputs
+    /// terug.
+    #[wasm_bindgen]
+    pub fn apply_preset(&mut self, name: &str) -> Result<usize, JsValue> {
+        self.activate_preset(name).map_err(|err| js_error(&err))
+    }
+
+    /// Verwijdert een preset. Geeft `false` terug als hij niet bestond.
+    #[wasm_bindgen]
+    pub fn delete_preset(&mut self, name: &str) -> bool {
+        self.remove_preset(name)
+    }
+
+    /// Alle presets als JSON-string.
+    #[wasm_bindgen]
+    pub fn export_presets(&self) -> Result<String, JsValue> {
+        self.presets_json().map_err(|err| js_error(&err))
+    }
+
+    /// Leest presets uit een JSON-string in, zoals gemaakt door
+    /// `export_presets`. Geeft het aantal ingelezen presets terug.
+    #[wasm_bindgen]
+    pub fn import_presets(&mut self, json: &str) -> Result<usize, JsValue> {
+        self.import_presets_json(json).map_err(|err| js_error(&err))
+    }
+}
+
+impl Engine {
+    /// Legt de huidige inputwaarden vast als preset (Rust-variant van
+    /// `save_preset`).
+    pub fn capture_preset(&mut self, name: &str) -> Result<(), String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("een preset heeft een naam nodig".to_string());
+        }
+
+        let graph = self
+            .graph
+            .as_ref()
+            .ok_or_else(|| "er is geen GHX-bestand geladen".to_string())?;
+
+        let inputs = graph
+            .nodes()
+            .iter()
+            .filter_map(|node| {
+                let kind = input_kind(&self.registry, node)?;
+                if kind == InputKind::Panel && has_incoming_wires(graph, node.id) {
+                    return None;
+                }
+                let value = current_value(node, kind)?;
+                Some(PresetInput {
+                    id: node.id.0.to_string(),
+                    name: node.nickname.clone().or_else(|| node.name.clone()),
+                    value,
+                })
+            })
+            .collect();
+
+        self.store_preset(Preset {
+            name: name.to_string(),
+            inputs,
+        });
+        Ok(())
+    }
+
+    /// De bewaarde presets, gesorteerd op naam.
+    pub fn presets(&self) -> &[Preset] {
+        &self.presets
+    }
+
+    /// Past een bewaarde preset toe (Rust-variant van `apply_preset`).
+    pub fn activate_preset(&mut self, name: &str) -> Result<usize, String> {
+        let preset = self
+            .presets
+            .iter()
+            .find(|preset| preset.name == name.trim())
+            .cloned()
+            .ok_or_else(|| format!("onbekende preset '{name}'"))?;
+
+        let graph = self
+            .graph
+            .as_mut()
+            .ok_or_else(|| "er is geen GHX-bestand geladen".to_string())?;
+
+        let mut targets = Vec::with_capacity(preset.inputs.len());
+        for input in &preset.inputs {
+            let node_id = resolve_target(graph, &self.registry, input).ok_or_else(|| {
+                format!(
+                    "preset '{}': geen passende input voor '{}'",
+                    preset.name,
+                    input.name.as_deref().unwrap_or(&input.id)
+                )
+            })?;
+            if targets.iter().any(|(existing, _, _)| *existing == node_id) {
+                return Err(format!(
+                    "preset '{}': input '{}' komt meer dan eens voor",
+                    preset.name, input.id
+                ));
+            }
+            let node = graph
+                .node(node_id)
+                .ok_or_else(|| "interne inputreferentie is ongeldig".to_string())?;
+            let kind = input.value.kind();
+            let mut updated = node.clone();
+            write_input_value(
+                &mut updated,
+                kind,
+                input_output_pin(node),
+                input.value.to_value(),
+            )?;
+            targets.push((node_id, kind, updated));
+        }
+
+        let mut changed = 0;
+        for (node_id, kind, updated) in targets {
+            let node = graph
+                .node_mut(node_id)
+                .ok_or_else(|| "interne inputreferentie is ongeldig".to_string())?;
+            if current_value(node, kind) == current_value(&updated, kind) {
+                continue;
+            }
+            *node = updated;
+            self.dirty_nodes.insert(node_id);
+            changed += 1;
+        }
+
+        if changed > 0 {
+            self.result_dirty = true;
+        }
+        Ok(changed)
+    }
+
+    /// Verwijdert een preset (Rust-variant van `delete_preset`).
+    pub fn remove_preset(&mut self, name: &str) -> bool {
+        let before = self.presets.len();
+        self.presets.retain(|preset| preset.name != name.trim());
+        self.presets.len() != before
+    }
+
+    /// Alle presets als JSON (Rust-variant van `export_presets`).
+    pub fn presets_json(&self) -> Result<String, String> {
+        serde_json::to_string(&self.presets).map_err(|err| err.to_string())
+    }
+
+    /// Leest presets uit JSON in (Rust-variant van `import_presets`). Presets
+    /// met een bestaande naam vervangen de bewaarde versie.
+    pub fn import_presets_json(&mut self, json: &str) -> Result<usize, String> {
+        let presets: Vec<Preset> =
+            serde_json::from_str(json).map_err(|err| format!("ongeldige preset-JSON: {err}"))?;
+        if presets.iter().any(|preset| preset.name.trim().is_empty()) {
+            return Err("een preset heeft een naam nodig".to_string());
+        }
+
+        let count = presets.len();
+        for mut preset in presets {
+            preset.name = preset.name.trim().to_string();
+            self.store_preset(preset);
+        }
+        Ok(count)
+    }
+
+    fn store_preset(&mut self, preset: Preset) {
+        match self
+            .presets
+            .binary_search_by(|existing| existing.name.as_str().cmp(&preset.name))
+        {
+            Ok(index) => self.presets[index] = preset,
+            Err(index) => self.presets.insert(index, preset),
+        }
+    }
+}
+
+/// Zoekt de node voor een preset-waarde: eerst op id, dan op naam, altijd
+/// met hetzelfde soort control.
+fn resolve_target(
+    graph: &Graph,
+    registry: &ComponentRegistry,
+    input: &PresetInput,
+) -> Option<NodeId> {
+    let kind = input.value.kind();
+    let matches = |node: &&Node| input_kind(registry, node) == Some(kind);
+
+    let by_id = input
+        .id
+        .trim()
+        .parse::<usize>()
+        .ok()
+        .and_then(|id| graph.node(NodeId::new(id)))
+        .filter(matches);
+    if let Some(node) = by_id {
+        return Some(node.id);
+    }
+
+    let name = normalize_name(input.name.as_deref()?);
+    if name.is_empty() {
+        return None;
+    }
+    graph
+        .nodes()
+        .iter()
+        .filter(matches)
+        .find(|node| {
+            [node.nickname.as_deref(), node.name.as_deref()]
+                .into_iter()
+                .flatten()
+                .any(|candidate| normalize_name(candidate) == name)
+        })
+        .map(|node| node.id)
+}
+
+fn current_value(node: &Node, kind: InputKind) -> Option<PresetValue> {
+    match kind {
+        InputKind::Slider => {
+            meta_f64(node.meta.get_normalized("value")?).map(|value| PresetValue::Slider { value })
+        }
+        InputKind::Toggle => Some(PresetValue::Toggle {
+            value: node
+                .meta("Value")
+                .and_then(MetaValue::as_boolean)
+                .unwrap_or(false),
+        }),
+        InputKind::ValueList => {
+            let total = value_list_values(&node.meta).len();
+            Some(PresetValue::ValueList {
+                selected_index: value_list_selected_index(&node.meta, total),
+            })
+        }
+        InputKind::ColourSwatch => match node.meta("SwatchColorRGB")? {
+            MetaValue::List(channels) if channels.len() >= 3 => {
+                let mut color = [0.0; 3];
+                for (slot, channel) in color.iter_mut().zip(channels) {
+                    *slot = meta_f64(channel)?;
+                }
+                Some(PresetValue::ColourSwatch { color })
+            }
+            _ => None,
+        },
+        InputKind::Panel => match node.meta("userText") {
+            Some(MetaValue::Text(text)) => Some(PresetValue::Panel { text: text.clone() }),
+            Some(_) => None,
+            None => Some(PresetValue::Panel {
+                text: String::new(),
+            }),
+        },
+        InputKind::DigitScroller => Some(PresetValue::DigitScroller {
+            value: node.meta("Value").map_or(Some(0.0), meta_f64)?,
+        }),
+        InputKind::MdSlider => match node.meta("Value")? {
+            MetaValue::List(coordinates) => {
+                let numbers = meta_numbers(coordinates)?;
+                let [x, y] = numbers.get(..2)? else {
+                    return None;
+                };
+                Some(PresetValue::MdSlider { value: [*x, *y] })
+            }
+            _ => None,
+        },
+        InputKind::ColourPicker => match node.meta("Value")? {
+            MetaValue::Text(text) => {
+                parse_colour_text(text).map(|color| PresetValue::ColourPicker { color })
+            }
+            _ => None,
+        },
+        InputKind::GraphMapper => Some(PresetValue::GraphMapper {
+            points: components::params_input::graph_mapper_points(&node.meta)?,
+        }),
+    }
+}
+
+fn meta_f64(value: &MetaValue) -> Option<f64> {
+    match value {
+        MetaValue::Number(n) => Some(*n),
+        MetaValue::Integer(i) => Some(*i as f64),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Engine;
+    use crate::graph::Graph;
+    use crate::graph::node::{MetaValue, Node, NodeId};
+    use crate::graph::value::{ColorValue, Value};
+
+    use super::PresetValue;
+
+    fn input_node(id: usize, guid: &str, nickname: &str) -> Node {
+        let mut node = Node::new(NodeId::new(id));
+        node.guid = Some(guid.to_string());
+        node.nickname = Some(nickname.to_string());
+        node
+    }
+
+    fn engine_with_inputs() -> Engine {
+        let mut graph = Graph::new();
+
+        let mut slider = input_node(0, "57da07bd-ecab-415d-9d86-af36d7073abc", "Length");
+        slider.insert_meta("min", 0.0);
+        slider.insert_meta("max", 10.0);
+        slider.insert_meta("step", 0.5);
+        slider.insert_meta("value", 2.0);
+        slider.set_output("Output", Value::Number(2.0));
+        graph.add_node(slider).unwrap();
+
+        let mut toggle = input_node(1, "2e78987b-9dfb-42a2-8b76-3923ac8bd91a", "Flip");
+        toggle.insert_meta("Value", false);
+        toggle.set_output("Output", Value::Boolean(false));
+        graph.add_node(toggle).unwrap();
+
+        let mut swatch = input_node(2, "9c53bac0-ba66-40bd-8154-ce9829b9db1a", "Tint");
+        swatch.insert_meta(
+            "SwatchColorRGB",
+            MetaValue::List(vec![
+                MetaValue::Number(255.0),
+                MetaValue::Number(0.0),
+                MetaValue::Number(0.0),
+            ]),
+        );
+        graph.add_node(swatch).unwrap();
+
+        let mut panel = input_node(3, "59e0b89a-e487-49f8-bab8-b5bab16be14c", "Label");
+        panel.insert_meta("userText", "eerste");
+        panel.set_output("Output", Value::Text("eerste".to_string()));
+        graph.add_node(panel).unwrap();
+
+        let mut engine = Engine::new();
+        engine.load_graph(graph).unwrap();
+        engine.evaluate_graph().unwrap();
+        engine
+    }
+
+    #[test]
+    fn applying_a_preset_restores_inputs_and_dirties_only_changed_nodes() {
+        let mut engine = engine_with_inputs();
+        engine.capture_preset("Basis").unwrap();
+        assert_eq!(engine.presets()[0].inputs.len(), 4);
+
+        engine
+            .update_input_value("Length", Value::Number(7.3))
+            .unwrap();
+        {
+            let graph = engine.graph.as_mut().unwrap();
+            graph
+                .node_mut(NodeId::new(3))
+                .unwrap()
+                .insert_meta("userText", "tweede");
+        }
+        engine.evaluate_graph().unwrap();
+        assert!(engine.dirty_nodes.is_empty());
+
+        assert_eq!(engine.activate_preset("Basis").unwrap(), 2);
+        let mut dirty: Vec<_> = engine.dirty_nodes.iter().map(|id| id.0).collect();
+        dirty.sort_unstable();
+        assert_eq!(dirty, vec![0, 3]);
+
+        engine.evaluate_graph().unwrap();
+        let graph = engine.graph.as_ref().unwrap();
+        assert_eq!(
+            graph.node(NodeId::new(0)).unwrap().output("Output"),
+            Some(&Value::Number(2.0))
+        );
+        assert_eq!(
+            graph.node(NodeId::new(3)).unwrap().output("Output"),
+            Some(&Value::Text("eerste".to_string()))
+        );
+
+        assert_eq!(engine.activate_preset("Basis").unwrap(), 0);
+    }
+
+    #[test]
+    fn presets_roundtrip_through_json_and_apply_atomically() {
+        let mut engine = engine_with_inputs();
+        engine.capture_preset("Basis").unwrap();
+        let json = engine.presets_json().unwrap();
+
+        let mut other = engine_with_inputs();
+        assert_eq!(other.import_presets_json(&json).unwrap(), 1);
+        assert_eq!(other.presets(), engine.presets());
+
+        let broken = json
+            .replace("\"Label\"", "\"Onbekend\"")
+            .replace("\"3\"", "\"9\"");
+        other.import_presets_json(&broken).unwrap();
+        other
+            .update_input_value("Flip", Value::Boolean(true))
+            .unwrap();
+        other.evaluate_graph().unwrap();
+
+        assert!(other.activate_preset("Basis").is_err());
+        assert!(other.dirty_nodes.is_empty());
+        let graph = other.graph.as_ref().unwrap();
+        assert_eq!(
+            graph.node(NodeId::new(1)).unwrap().output("Output"),
+            Some(&Value::Boolean(true))
+        );
+
+        assert!(other.remove_preset("Basis"));
+        assert!(other.presets().is_empty());
+    }
+
+    #[test]
+    fn presets_capture_and_restore_scrollers_pickers_and_mappers() {
+        let mut graph = Graph::new();
+
+        let mut scroller = input_node(0, "33bcf975-a0b2-4b54-99fd-585c893b9e88", "Seed");
+        scroller.insert_meta("Value", 12.5);
+        scroller.set_output("Output", Value::Number(12.5));
+        graph.add_node(scroller).unwrap();
+
+        let mut md_slider = input_node(1, "318dacd7-9073-4ede-b043-a0c132eb77e0", "Pos");
+        md_slider.insert_meta(
+            "Value",
+            MetaValue::List(vec![MetaValue::Number(0.25), MetaValue::Number(0.75)]),
+        );
+        graph.add_node(md_slider).unwrap();
+
+        let mut picker = input_node(2, "339c0ee1-cf11-444f-8e10-65c9150ea755", "Kleur");
+        picker.insert_meta("Value", "Color [A=255, R=10, G=20, B=30]");
+        graph.add_node(picker).unwrap();
+
+        let mut mapper = input_node(3, "bc984576-7aa6-491f-a91d-e444c33675a7", "Curve");
+        mapper.insert_meta(
+            "GraphPoints",
+            MetaValue::List(vec![
+                MetaValue::List(vec![MetaValue::Number(0.0), MetaValue::Number(0.0)]),
+                MetaValue::List(vec![MetaValue::Number(1.0), MetaValue::Number(0.5)]),
+            ]),
+        );
+        graph.add_node(mapper).unwrap();
+
+        let mut engine = Engine::new();
+        engine.load_graph(graph).unwrap();
+        engine.evaluate_graph().unwrap();
+        engine.capture_preset("Basis").unwrap();
+
+        let values: Vec<_> = engine.presets()[0]
+            .inputs
+            .iter()
+            .map(|input| input.value.clone())
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                PresetValue::DigitScroller { value: 12.5 },
+                PresetValue::MdSlider {
+                    value: [0.25, 0.75]
+                },
+                PresetValue::ColourPicker {
+                    color: [10.0, 20.0, 30.0]
+                },
+                PresetValue::GraphMapper {
+                    points: vec![[0.0, 0.0], [1.0, 0.5]]
+                },
+            ]
+        );
+
+        engine
+            .update_input_value("Seed", Value::Number(3.0))
+            .unwrap();
+        engine
+            .update_input_value("Pos", Value::Point([0.5, 0.5, 0.0]))
+            .unwrap();
+        engine
+            .update_input_value(
+                "Kleur",
+                Value::Color(ColorValue::from_rgb255(0.0, 0.0, 0.0)),
+            )
+            .unwrap();
+        engine
+            .update_input_value(
+                "Curve",
+                Value::List(vec![
+                    Value::Point([0.0, 1.0, 0.0]),
+                    Value::Point([1.0, 0.0, 0.0]),
+                ]),
+            )
+            .unwrap();
+        engine.evaluate_graph().unwrap();
+
+        assert_eq!(engine.activate_preset("Basis").unwrap(), 4);
+        engine.evaluate_graph().unwrap();
+        engine.capture_preset("Terug").unwrap();
+        assert_eq!(engine.presets()[0].inputs, engine.presets()[1].inputs);
+
+        let graph = engine.graph.as_ref().unwrap();
+        assert_eq!(
+            graph.node(NodeId::new(0)).unwrap().output("Output"),
+            Some(&Value::Number(12.5))
+        );
+        assert_eq!(engine.activate_preset("Basis").unwrap(), 0);
+    }
+}
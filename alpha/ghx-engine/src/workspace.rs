@@ -0,0 +1,702 @@
+//! Werkruimte waarin meerdere GHX-documenten naast elkaar geladen zijn.
+//!
+//! Elk document houdt zijn eigen [`Engine`] (graph, evaluatieplan en
+//! geometrie-diff). De werkruimte koppelt input controls met dezelfde
+//! nickname tussen documenten, zodat één slider in de UI alle documenten
+//! aanstuurt. Daarbij gelden de volgende regels:
+//!
+//! - Nicknames worden vergeleken na trimmen en zonder hoofdletter-
+//!   gevoeligheid. Controls zonder nickname of naam worden niet gekoppeld.
+//! - Alleen controls van hetzelfde soort (bv. slider, toggle of panel) worden
+//!   gekoppeld. Een control met dezelfde nickname maar een ander soort blijft
+//!   een los control met een document-specifiek id en wordt als conflict
+//!   gemarkeerd.
+//! - Het eerst geladen document met het control is leidend: zijn `min`,
+//!   `max`, `step` en waarde worden aan de UI getoond. Wijken de range of de
+//!   step van een ander document af (of de items van een value list), dan
+//!   krijgt het gedeelde control `conflict: true`.
+//! - Een nieuwe waarde wordt eerst op het leidende document toegepast. De
+//!   daar geclampte en op de step afgeronde waarde gaat daarna naar de
+//!   overige documenten, die haar binnen hun eigen range clampen. Past de
+//!   waarde in één document niet, dan verandert geen enkel document.
+//! - Bij het laden van een document neemt het de actuele waarde van reeds
+//!   gekoppelde controls over.
+//!
+//! Geometrie-id's worden per document genamespaced als `"<document>:<node>"`.
+
+use serde::Serialize;
+use wasm_bindgen::JsError;
+use wasm_bindgen::prelude::*;
+
+use crate::graph::Graph;
+use crate::graph::value::{ColorValue, Value};
+use crate::{
+    Engine, GeometryBuffers, GeometryItem, InputControl, InputKind, curve_points_value,
+    input_control_state, js_error, parse,
+};
+
+/// Meerdere geladen GHX-documenten met gedeelde inputs.
+#[wasm_bindgen]
+pub struct Workspace {
+    documents: Vec<Document>,
+    removed_geometry: Vec<String>,
+}
+
+struct Document {
+    name: String,
+    engine: Engine,
+}
+
+/// Een input control, eventueel gedeeld door meerdere documenten.
+struct SharedInput {
+    key: String,
+    control: InputControl,
+    /// Documentindex en lokaal input-id per gekoppeld control; het eerste
+    /// lid is leidend.
+    members: Vec<(usize, String)>,
+    conflict: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct SharedControl<'a> {
+    #[serde(flatten)]
+    control: &'a InputControl,
+    documents: Vec<&'a str>,
+    conflict: bool,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct WorkspaceGeometryDiff {
+    added: Vec<WorkspaceGeometryItem>,
+    updated: Vec<WorkspaceGeometryItem>,
+    removed: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct WorkspaceGeometryItem {
+    id: String,
+    document: String,
+    items: Vec<GeometryItem<'static>>,
+}
+
+impl Default for Workspace {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[wasm_bindgen]
+impl Workspace {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Workspace {
+        Workspace {
+            documents: Vec::new(),
+            removed_geometry: Vec::new(),
+        }
+    }
+
+    /// Laad een GHX-document onder de gegeven naam. Een bestaand document met
+    /// dezelfde naam wordt vervangen en behoudt zijn positie.
+    #[wasm_bindgen]
+    pub fn add_ghx(&mut self, name: &str, xml: &str) -> Result<(), JsValue> {
+        let graph = parse::ghx_xml::parse_str(xml).map_err(|err| js_error(&err.to_string()))?;
+        self.insert_document(name, graph)
+            .map_err(|err| js_error(&err))
+    }
+
+    /// Laad een binair `.gh`-document onder de gegeven naam.
+    #[wasm_bindgen]
+    pub fn add_gh(&mut self, name: &str, bytes: &[u8]) -> Result<(), JsValue> {
+        let graph =
+            parse::gh_binary::parse_bytes(bytes).map_err(|err| js_error(&err.to_string()))?;
+        self.insert_document(name, graph)
+            .map_err(|err| js_error(&err))
+    }
+
+    /// Verwijder een document; zijn geometrie komt bij de volgende
+    /// `get_geometry` in `removed` terecht.
+    #[wasm_bindgen]
+    pub fn remove_document(&mut self, name: &str) -> bool {
+        let Some(index) = self.document_index(name) else {
+            return false;
+        };
+        let document = self.documents.remove(index);
+        self.retire_geometry(&document);
+        true
+    }
+
+    /// Namen van de geladen documenten in laadvolgorde.
+    #[wasm_bindgen]
+    pub fn document_names(&self) -> Vec<String> {
+        self.documents
+            .iter()
+            .map(|document| document.name.clone())
+            .collect()
+    }
+
+    /// Haal de samengevoegde input controls van alle documenten op.
+    #[wasm_bindgen]
+    pub fn get_sliders(&self) -> Result<JsValue, JsValue> {
+        let inputs = self.shared_inputs().map_err(|err| js_error(&err))?;
+        let controls: Vec<SharedControl> = inputs
+            .iter()
+            .map(|input| SharedControl {
+                control: &input.control,
+                documents: input
+                    .members
+                    .iter()
+                    .map(|(index, _)| self.documents[*index].name.as_str())
+                    .collect(),
+                conflict: input.conflict,
+            })
+            .collect();
+
+        serde_wasm_bindgen::to_value(&controls).map_err(|err| JsError::new(&err.to_string()).into())
+    }
+
+    /// Stel een gedeelde inputwaarde in voor alle gekoppelde documenten.
+    #[wasm_bindgen]
+    pub fn set_slider_value(&mut self, id_or_name: &str, value: JsValue) -> Result<(), JsValue> {
+        let val = if let Some(n) = value.as_f64() {
+            Value::Number(n)
+        } else if let Some(b) = value.as_bool() {
+            Value::Boolean(b)
+        } else {
+            return Err(js_error("sliderwaarde moet een getal of boolean zijn"));
+        };
+
+        self.update_input_value(id_or_name, val)
+            .map_err(|e| js_error(&e))
+    }
+
+    /// Stel de gedeelde tekst van een panel in.
+    #[wasm_bindgen]
+    pub fn set_input_text(&mut self, id_or_name: &str, text: &str) -> Result<(), JsValue> {
+        self.update_input_value(id_or_name, Value::Text(text.to_owned()))
+            .map_err(|e| js_error(&e))
+    }
+
+    /// Stel de gedeelde kleur van een Colour Swatch of Colour Picker in
+    /// (kanalen 0-255).
+    #[wasm_bindgen]
+    pub fn set_input_colour(
+        &mut self,
+        id_or_name: &str,
+        r: f64,
+        g: f64,
+        b: f64,
+    ) -> Result<(), JsValue> {
+        let color = ColorValue::from_rgb255(r, g, b);
+        self.update_input_value(id_or_name, Value::Color(color))
+            .map_err(|e| js_error(&e))
+    }
+
+    /// Stel het gedeelde punt van een MD Slider in.
+    #[wasm_bindgen]
+    pub fn set_input_point(&mut self, id_or_name: &str, x: f64, y: f64) -> Result<(), JsValue> {
+        self.update_input_value(id_or_name, Value::Point([x, y, 0.0]))
+            .map_err(|e| js_error(&e))
+    }
+
+    /// Stel de gedeelde curve van een Graph Mapper in als array van
+    /// `[x, y]`-paren.
+    #[wasm_bindgen]
+    pub fn set_input_curve(&mut self, id_or_name: &str, points: JsValue) -> Result<(), JsValue> {
+        let value = curve_points_value(points)?;
+        self.update_input_value(id_or_name, value)
+            .map_err(|e| js_error(&e))
+    }
+
+    /// Evalueer alle documenten. Een fout in één document houdt de overige
+    /// documenten niet tegen; de eerste fout wordt teruggegeven.
+    #[wasm_bindgen]
+    pub fn evaluate(&mut self) -> Result<(), JsValue> {
+        self.evaluate_documents().map_err(|err| js_error(&err))
+    }
+
+    /// Haalt de geometrie-diff van alle documenten op met genamespacede id's.
+    #[wasm_bindgen]
+    pub fn get_geometry(&mut self) -> Result<JsValue, JsValue> {
+        let diff = self.geometry_diff().map_err(|err| js_error(&err))?;
+        serde_wasm_bindgen::to_value(&diff).map_err(|err| JsError::new(&err.to_string()).into())
+    }
+
+    /// Haalt de getypeerde geometriebuffers van één document op. De node-id's
+    /// in de buffers zijn lokaal voor dat document.
+    #[wasm_bindgen]
+    pub fn get_geometry_buffers(&mut self, document: &str) -> Result<GeometryBuffers, JsValue> {
+        let index = self
+            .document_index(document)
+            .ok_or_else(|| js_error("onbekend document"))?;
+        self.documents[index].engine.get_geometry_buffers()
+    }
+
+    /// Haal de fouten van alle documenten op, voorafgegaan door de
+    /// documentnaam.
+    #[wasm_bindgen]
+    pub fn get_errors(&self) -> Result<JsValue, JsValue> {
+        let messages: Vec<String> = self
+            .documents
+            .iter()
+            .flat_map(|document| {
+                document
+                    .engine
+                    .last_errors
+                    .iter()
+                    .map(move |error| format!("{}: {error}", document.name))
+            })
+            .collect();
+        serde_wasm_bindgen::to_value(&messages).map_err(|err| JsError::new(&err.to_string()).into())
+    }
+}
+
+impl Workspace {
+    /// Geeft de engine van een geladen document terug.
+    pub fn document(&self, name: &str) -> Option<&Engine> {
+        self.document_index(name)
+            .map(|index| &self.documents[index].engine)
+    }
+
+    /// Geeft de engine van een geladen document muteerbaar terug.
+    pub fn document_mut(&mut self, name: &str) -> Option<&mut Engine> {
+        self.document_index(name)
+            .map(|index| &mut self.documents[index].engine)
+    }
+
+    /// Interne methode om een gedeelde inputwaarde bij te werken (niet
+    /// blootgesteld via WASM).
+    pub fn update_input_value(&mut self, id_or_name: &str, value: Value) -> Result<(), String> {
+        let inputs = self.shared_inputs()?;
+        let input = find_shared_input(&inputs, &self.documents, id_or_name)
+            .ok_or_else(|| "onbekende inputreferentie".to_string())?;
+
+        let Some(((primary, primary_id), secondaries)) = input.members.split_first() else {
+            return Ok(());
+        };
+
+        // Eerst alle documenten controleren, dan pas wijzigen: een waarde die
+        // in één document niet past laat de hele werkruimte ongewijzigd.
+        let document = &self.documents[*primary];
+        let (binding, node) = document
+            .engine
+            .stage_input_value(primary_id, value)
+            .map_err(|err| format!("{}: {err}", document.name))?;
+        let resolved = control_value(&input_control_state(&node, binding)?);
+
+        let mut staged = vec![(*primary, node)];
+        for (index, id) in secondaries {
+            let document = &self.documents[*index];
+            let (_, node) = document
+                .engine
+                .stage_input_value(id, resolved.clone())
+                .map_err(|err| format!("{}: {err}", document.name))?;
+            staged.push((*index, node));
+        }
+
+        for (index, node) in staged {
+            self.documents[index].engine.commit_input_value(node)?;
+        }
+        Ok(())
+    }
+
+    fn document_index(&self, name: &str) -> Option<usize> {
+        let name = name.trim();
+        self.documents
+            .iter()
+            .position(|document| document.name == name)
+    }
+
+    fn insert_document(&mut self, name: &str, graph: Graph) -> Result<(), String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("documentnaam mag niet leeg zijn".to_string());
+        }
+
+        let mut engine = Engine::new();
+        engine
+            .install_graph(graph)
+            .map_err(|err| format!("{name}: {err}"))?;
+        let document = Document {
+            name: name.to_owned(),
+            engine,
+        };
+
+        match self.document_index(name) {
+            Some(index) => {
+                let previous = std::mem::replace(&mut self.documents[index], document);
+                self.retire_geometry(&previous);
+            }
+            None => self.documents.push(document),
+        }
+
+        self.synchronize_inputs()
+    }
+
+    /// Markeert alle getoonde geometrie van een document als verwijderd.
+    fn retire_geometry(&mut self, document: &Document) {
+        self.removed_geometry.extend(
+            document
+                .engine
+                .geometry_map
+                .keys()
+                .map(|node_id| namespaced_id(&document.name, &node_id.0.to_string())),
+        );
+    }
+
+    /// Zet de waarde van het leidende control door naar alle gekoppelde
+    /// controls die ervan afwijken.
+    fn synchronize_inputs(&mut self) -> Result<(), String> {
+        for input in self.shared_inputs()? {
+            let Some(((primary, primary_id), secondaries)) = input.members.split_first() else {
+                continue;
+            };
+            let value = current_input_value(&self.documents[*primary].engine, primary_id)?;
+
+            for (index, id) in secondaries {
+                let document = &mut self.documents[*index];
+                if current_input_value(&document.engine, id)? == value {
+                    continue;
+                }
+                document
+                    .engine
+                    .update_input_value(id, value.clone())
+                    .map_err(|err| format!("{}: {err}", document.name))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Groepeert de input controls van alle documenten volgens de regels in
+    /// de moduledocumentatie.
+    fn shared_inputs(&self) -> Result<Vec<SharedInput>, String> {
+        let mut inputs: Vec<SharedInput> = Vec::new();
+
+        for (index, document) in self.documents.iter().enumerate() {
+            let controls = document
+                .engine
+                .input_controls()
+                .map_err(|err| format!("{}: {err}", document.name))?;
+
+            for mut control in controls {
+                let (id, name) = control_identity(&control);
+                let local_id = id.to_owned();
+                let namespaced = namespaced_id(&document.name, id);
+                let key = if name == id {
+                    namespaced.clone()
+                } else {
+                    crate::normalize_name(name)
+                };
+
+                let existing = inputs.iter_mut().find(|input| input.key == key);
+                if let Some(shared) = existing {
+                    if control_kind(&shared.control) == control_kind(&control) {
+                        shared.conflict |= !same_range(&shared.control, &control);
+                        shared.members.push((index, local_id));
+                        continue;
+                    }
+                    shared.conflict = true;
+                    set_control_id(&mut control, namespaced.clone());
+                    inputs.push(SharedInput {
+                        key: namespaced,
+                        control,
+                        members: vec![(index, local_id)],
+                        conflict: true,
+                    });
+                    continue;
+                }
+
+                set_control_id(&mut control, namespaced);
+                inputs.push(SharedInput {
+                    key,
+                    control,
+                    members: vec![(index, local_id)],
+                    conflict: false,
+                });
+            }
+        }
+
+        Ok(inputs)
+    }
+
+    fn evaluate_documents(&mut self) -> Result<(), String> {
+        let mut first_error = None;
+        for document in &mut self.documents {
+            if let Err(err) = document.engine.evaluate_graph() {
+                first_error.get_or_insert_with(|| format!("{}: {err}", document.name));
+            }
+        }
+
+        match first_error {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    fn geometry_diff(&mut self) -> Result<WorkspaceGeometryDiff, String> {
+        let mut diff = WorkspaceGeometryDiff {
+            removed: std::mem::take(&mut self.removed_geometry),
+            ..Default::default()
+        };
+
+        for document in &mut self.documents {
+            let document_diff = document
+                .engine
+                .geometry_diff()
+                .map_err(|err| format!("{}: {err}", document.name))?;
+
+            let name = &document.name;
+            let wrap = |item: crate::GeometryDiffItem<'static>| WorkspaceGeometryItem {
+                id: namespaced_id(name, &item.id.to_string()),
+                document: name.clone(),
+                items: item.items,
+            };
+            diff.added.extend(document_diff.added.into_iter().map(wrap));
+            diff.updated
+                .extend(document_diff.updated.into_iter().map(wrap));
+            diff.removed.extend(
+                document_diff
+                    .removed
+                    .into_iter()
+                    .map(|id| namespaced_id(name, &id.to_string())),
+            );
+        }
+
+        Ok(diff)
+    }
+}
+
+fn namespaced_id(document: &str, id: &str) -> String {
+    format!("{document}:{id}")
+}
+
+/// Zoekt een gedeeld control op genamespacet id (van elk lid) of op
+/// nickname.
+fn find_shared_input<'a>(
+    inputs: &'a [SharedInput],
+    documents: &[Document],
+    id_or_name: &str,
+) -> Option<&'a SharedInput> {
+    let trimmed = id_or_name.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+
+    let by_id = inputs.iter().find(|input| {
+        input
+            .members
+            .iter()
+            .any(|(index, id)| namespaced_id(&documents[*index].name, id) == trimmed)
+    });
+    by_id.or_else(|| {
+        let normalized = crate::normalize_name(trimmed);
+        inputs.iter().find(|input| input.key == normalized)
+    })
+}
+
+/// Leest de actuele waarde van een input control als evaluatorwaarde.
+fn current_input_value(engine: &Engine, id: &str) -> Result<Value, String> {
+    let controls = engine.input_controls()?;
+    let control = controls
+        .iter()
+        .find(|control| control_identity(control).0 == id)
+        .ok_or_else(|| "interne inputreferentie is ongeldig".to_string())?;
+
+    Ok(control_value(control))
+}
+
+/// De waarde van een control in de vorm die `update_input_value` verwacht.
+fn control_value(control: &InputControl) -> Value {
+    match control {
+        InputControl::Slider { value, .. } => Value::Number(*value),
+        InputControl::Toggle { value, .. } => Value::Boolean(*value),
+        InputControl::ValueList { selected_index, .. } => Value::Number(*selected_index as f64),
+        InputControl::Panel { text, .. } => Value::Text(text.clone()),
+        InputControl::ColourSwatch { color, .. } | InputControl::ColourPicker { color, .. } => {
+            Value::Color(ColorValue::from_rgb255(color[0], color[1], color[2]))
+        }
+        InputControl::DigitScroller { value, .. } => Value::Number(*value),
+        InputControl::MdSlider { value, .. } => Value::Point([value[0], value[1], 0.0]),
+        InputControl::GraphMapper { points, .. } => Value::List(
+            points
+                .iter()
+                .map(|[x, y]| Value::Point([*x, *y, 0.0]))
+                .collect(),
+        ),
+    }
+}
+
+fn control_identity(control: &InputControl) -> (&str, &str) {
+    match control {
+        InputControl::Slider { id, name, .. }
+        | InputControl::Toggle { id, name, .. }
+        | InputControl::ValueList { id, name, .. }
+        | InputControl::Panel { id, name, .. }
+        | InputControl::ColourSwatch { id, name, .. }
+        | InputControl::DigitScroller { id, name, .. }
+        | InputControl::MdSlider { id, name, .. }
+        | InputControl::ColourPicker { id, name, .. }
+        | InputControl::GraphMapper { id, name, .. } => (id, name),
+    }
+}
+
+fn control_kind(control: &InputControl) -> InputKind {
+    match control {
+        InputControl::Slider { .. } => InputKind::Slider,
+        InputControl::Toggle { .. } => InputKind::Toggle,
+        InputControl::ValueList { .. } => InputKind::ValueList,
+        InputControl::Panel { .. } => InputKind::Panel,
+        InputControl::ColourSwatch { .. } => InputKind::ColourSwatch,
+        InputControl::DigitScroller { .. } => InputKind::DigitScroller,
+        InputControl::MdSlider { .. } => InputKind::MdSlider,
+        InputControl::ColourPicker { .. } => InputKind::ColourPicker,
+        InputControl::GraphMapper { .. } => InputKind::GraphMapper,
+    }
+}
+
+fn set_control_id(control: &mut InputControl, new_id: String) {
+    match control {
+        InputControl::Slider { id, .. }
+        | InputControl::Toggle { id, .. }
+        | InputControl::ValueList { id, .. }
+        | InputControl::Panel { id, .. }
+        | InputControl::ColourSwatch { id, .. }
+        | InputControl::DigitScroller { id, .. }
+        | InputControl::MdSlider { id, .. }
+        | InputControl::ColourPicker { id, .. }
+        | InputControl::GraphMapper { id, .. } => *id = new_id,
+    }
+}
+
+fn same_range(primary: &InputControl, other: &InputControl) -> bool {
+    match (primary, other) {
+        (
+            InputControl::Slider { min, max, step, .. },
+            InputControl::Slider {
+                min: other_min,
+                max: other_max,
+                step: other_step,
+                ..
+            },
+        ) => min == other_min && max == other_max && step == other_step,
+        (
+            InputControl::ValueList { items, .. },
+            InputControl::ValueList {
+                items: other_items, ..
+            },
+        ) => items
+            .iter()
+            .map(|item| &item.label)
+            .eq(other_items.iter().map(|item| &item.label)),
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Workspace;
+    use crate::graph::node::NodeId;
+    use crate::graph::value::Value;
+
+    const MINIMAL_LINE: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../tools/ghx-samples/minimal_line.ghx"
+    ));
+
+    /// Minimal line sample with a narrower `Length` slider.
+    fn narrow_line() -> String {
+        MINIMAL_LINE
+            .replace(r#"max="10""#, r#"max="4""#)
+            .replace(r#"value="3""#, r#"value="1""#)
+    }
+
+    fn slider_value(workspace: &Workspace, document: &str) -> f64 {
+        let engine = workspace.document(document).expect("document");
+        match super::current_input_value(engine, "0").expect("slider") {
+            Value::Number(value) => value,
+            other => panic!("verwacht een getal, kreeg {other:?}"),
+        }
+    }
+
+    #[test]
+    fn links_sliders_by_nickname_and_fans_out_values() {
+        let mut workspace = Workspace::new();
+        workspace.add_ghx("wireframe", MINIMAL_LINE).unwrap();
+        workspace.add_ghx("brug", &narrow_line()).unwrap();
+
+        let inputs = workspace.shared_inputs().unwrap();
+        assert_eq!(inputs.len(), 1);
+        assert_eq!(inputs[0].members.len(), 2);
+        assert!(inputs[0].conflict);
+        assert_eq!(super::control_identity(&inputs[0].control).0, "wireframe:0");
+
+        // The secondary document adopts the primary default on load.
+        assert_eq!(slider_value(&workspace, "brug"), 3.0);
+
+        workspace
+            .update_input_value(" length ", Value::Number(6.0))
+            .unwrap();
+        assert_eq!(slider_value(&workspace, "wireframe"), 6.0);
+        assert_eq!(slider_value(&workspace, "brug"), 4.0);
+
+        workspace
+            .update_input_value("brug:0", Value::Number(2.0))
+            .unwrap();
+        assert_eq!(slider_value(&workspace, "wireframe"), 2.0);
+        assert!(
+            workspace
+                .update_input_value("onbekend", Value::Number(1.0))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn rejected_values_leave_every_document_unchanged() {
+        let mut workspace = Workspace::new();
+        workspace.add_ghx("wireframe", MINIMAL_LINE).unwrap();
+        workspace.add_ghx("brug", &narrow_line()).unwrap();
+
+        // De slider van het tweede document kan geen waarde meer aannemen.
+        let engine = workspace.document_mut("brug").unwrap();
+        engine
+            .graph
+            .as_mut()
+            .unwrap()
+            .node_mut(NodeId::new(0))
+            .unwrap()
+            .insert_meta("step", "fijn");
+        let dirty_before = engine.dirty_nodes.clone();
+
+        assert!(
+            workspace
+                .update_input_value("length", Value::Number(6.0))
+                .is_err()
+        );
+        assert_eq!(slider_value(&workspace, "wireframe"), 3.0);
+        assert_eq!(
+            workspace.document("brug").unwrap().dirty_nodes,
+            dirty_before
+        );
+    }
+
+    #[test]
+    fn namespaces_geometry_ids_per_document() {
+        let mut workspace = Workspace::new();
+        workspace.add_ghx("wireframe", MINIMAL_LINE).unwrap();
+        workspace.add_ghx("brug", MINIMAL_LINE).unwrap();
+        workspace.evaluate_documents().unwrap();
+
+        let diff = workspace.geometry_diff().unwrap();
+        assert!(diff.added.iter().any(|item| item.id == "wireframe:3"));
+        assert!(diff.added.iter().any(|item| item.id == "brug:3"));
+        assert!(diff.removed.is_empty());
+
+        assert!(workspace.remove_document("brug"));
+        workspace.evaluate_documents().unwrap();
+        let diff = workspace.geometry_diff().unwrap();
+        assert!(diff.added.is_empty());
+        assert!(diff.removed.iter().any(|id| id == "brug:3"));
+        assert!(diff.removed.iter().all(|id| id.starts_with("brug:")));
+        assert_eq!(workspace.document_names(), ["wireframe"]);
+    }
+}
@@ -1,9 +1,10 @@
-use ghx_engine::Engine;
+use ghx_engine::{Engine, GeometryBuffers};
 use ghx_engine::components::ComponentRegistry;
+use ghx_engine::geom::{Line3, Point3};
 use ghx_engine::graph::Graph;
 use ghx_engine::graph::evaluator::{self, EvaluationResult};
-use ghx_engine::graph::node::{Node, NodeId};
-use ghx_engine::graph::value::Value;
+use ghx_engine::graph::node::{MetaValue, Node, NodeId};
+use ghx_engine::graph::value::{ColorValue, CurveValue, Value};
 use ghx_engine::parse::ghx_xml;
 
 #[test]
@@ -31,6 +32,151 @@ fn slider_updates_require_existing_identifier() {
     );
 }
 
+#[test]
+fn exposes_and_updates_non_slider_input_controls() {
+    let controls = [
+        ("59e0b89a-e487-49f8-bab8-b5bab16be14c", "Label"),
+        ("9c53bac0-ba66-40bd-8154-ce9829b9db1a", "Tint"),
+        ("33bcf975-a0b2-4b54-99fd-585c893b9e88", "Count"),
+        ("318dacd7-9073-4ede-b043-a0c132eb77e0", "Offset"),
+        ("339c0ee1-cf11-444f-8e10-65c9150ea755", "Picker"),
+        ("bc984576-7aa6-491f-a91d-e444c33675a7", "Falloff"),
+    ];
+    let mut graph = Graph::new();
+    for (index, (guid, nickname)) in controls.into_iter().enumerate() {
+        let mut node = Node::new(NodeId::new(index));
+        node.guid = Some(guid.to_string());
+        node.nickname = Some(nickname.to_string());
+        if nickname == "Falloff" {
+            node.set_input("Input", Value::Number(0.25));
+        }
+        graph.add_node(node).expect("add node");
+    }
+
+    let mut engine = Engine::new();
+    engine.load_graph(graph).expect("load graph");
+
+    let json: serde_json::Value =
+        serde_json::from_str(&engine.input_controls_json().expect("controls")).unwrap();
+    let types: Vec<&str> = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|control| control["type"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        types,
+        [
+            "panel",
+            "colour-swatch",
+            "digit-scroller",
+            "md-slider",
+            "colour-picker",
+            "graph-mapper"
+        ]
+    );
+
+    engine
+        .update_input_value("Label", Value::Text("hallo".to_string()))
+        .expect("panel text");
+    engine
+        .update_input_value(
+            "Tint",
+            Value::Color(ColorValue::from_rgb255(0.0, 128.0, 255.0)),
+        )
+        .expect("swatch colour");
+    engine
+        .update_input_value("Count", Value::Number(3.0))
+        .expect("digit scroller");
+    engine
+        .update_input_value("Offset", Value::Point([0.5, 2.0, 0.0]))
+        .expect("md slider point");
+    engine
+        .update_input_value(
+            "Picker",
+            Value::Color(ColorValue::from_rgb255(10.0, 20.0, 30.0)),
+        )
+        .expect("picker colour");
+    let curve = [[1.0, 1.0], [0.0, 0.0], [0.5, 1.0]]
+        .into_iter()
+        .map(|[x, y]| Value::Point([x, y, 0.0]))
+        .collect();
+    engine
+        .update_input_value("Falloff", Value::List(curve))
+        .expect("graph mapper curve");
+    assert!(
+        engine
+            .update_input_value("Offset", Value::Text("geen punt".to_string()))
+            .is_err()
+    );
+
+    let json: serde_json::Value =
+        serde_json::from_str(&engine.input_controls_json().expect("controls")).unwrap();
+    assert_eq!(json[0]["text"], "hallo");
+    assert_eq!(json[1]["color"], serde_json::json!([0.0, 128.0, 255.0]));
+    assert_eq!(json[2]["value"], 3.0);
+    assert_eq!(json[3]["value"], serde_json::json!([0.5, 2.0]));
+    assert_eq!(json[4]["color"], serde_json::json!([10.0, 20.0, 30.0]));
+    assert_eq!(
+        json[5]["points"],
+        serde_json::json!([[0.0, 0.0], [0.5, 1.0], [1.0, 1.0]])
+    );
+
+    engine.evaluate_graph().expect("evaluate");
+    let result = engine.last_result().expect("result");
+    let outputs = |id: usize| &result.node_outputs[&NodeId::new(id)];
+    assert_eq!(outputs(0)["Output"], Value::Text("hallo".to_string()));
+    assert_eq!(outputs(2)["Output"], Value::Number(3.0));
+    assert_eq!(
+        outputs(4)["Output"],
+        Value::Text("Color [A=255, R=10, G=20, B=30]".to_string())
+    );
+    assert!(matches!(outputs(5)["Output"], Value::Number(y) if (y - 0.5).abs() < 1e-9));
+}
+
+#[test]
+fn archive_input_controls_keep_their_saved_state() {
+    let graph = ghx_xml::parse_str(include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../tools/ghx-samples/input_controls.ghx"
+    )))
+    .expect("parse ghx");
+    let falloff = graph
+        .nodes()
+        .iter()
+        .find(|node| node.nickname.as_deref() == Some("Falloff"))
+        .expect("graph mapper node")
+        .id;
+    let mut engine = Engine::new();
+    engine.load_graph(graph).expect("load graph");
+
+    let json: serde_json::Value =
+        serde_json::from_str(&engine.input_controls_json().expect("controls")).unwrap();
+    let control = |name: &str| {
+        json.as_array()
+            .unwrap()
+            .iter()
+            .find(|control| control["name"] == name)
+            .unwrap_or_else(|| panic!("control {name} missing"))
+            .clone()
+    };
+    assert_eq!(control("Count")["value"], 0.25);
+    assert_eq!(control("Offset")["value"], serde_json::json!([0.3, 0.7]));
+    assert_eq!(control("Picker")["color"], serde_json::json!([10.0, 20.0, 30.0]));
+    assert_eq!(
+        control("Falloff")["points"],
+        serde_json::json!([[0.0, 1.0], [0.5, 0.5], [1.0, 0.0]])
+    );
+
+    engine.evaluate_graph().expect("evaluate");
+    let result = engine.last_result().expect("result");
+    assert!(matches!(
+        result.node_outputs[&falloff]["Output"],
+        Value::Number(y) if (y - 0.75).abs() < 1e-9
+    ));
+    assert!(result.warnings.is_empty());
+}
+
 #[test]
 fn parses_brugtest_boolean_toggle() {
     let xml = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../web/testfiles/brugtest.ghx"));
@@ -69,13 +215,15 @@ fn lijntest2_line_outputs_expected_points() {
         .geometry
         .iter()
         .find_map(|entry| match &entry.value {
-            Value::CurveLine { p1, p2 } => Some((p1, p2)),
+            Value::Curve(CurveValue::Line(line)) => {
+                Some((line.start.to_array(), line.end.to_array()))
+            }
             _ => None,
         })
         .expect("line geometry present");
 
-    assert_point_close(line.0, [0.0, 0.0, 0.0]);
-    assert_point_close(line.1, [1000.0, 0.0, 0.0]);
+    assert_point_close(&line.0, [0.0, 0.0, 0.0]);
+    assert_point_close(&line.1, [1000.0, 0.0, 0.0]);
 }
 
 #[test]
@@ -90,6 +238,53 @@ fn geometry_requires_evaluation_first() {
     assert!(engine.get_geometry().is_err());
 }
 
+#[test]
+fn geometry_buffers_follow_added_updated_removed_diff() {
+    let xml = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../tools/ghx-samples/minimal_line.ghx"
+    ));
+    let mut engine = Engine::new();
+    engine.load_ghx(xml).expect("load ghx");
+    assert!(engine.get_geometry_buffers().is_err());
+
+    engine.evaluate().expect("evaluate");
+    let first = engine.get_geometry_buffers().expect("buffers");
+    let (line_node, line) = buffer_line(&first).expect("line record");
+    assert!(first.added_ids().contains(&line_node));
+    assert_eq!(line, [0.0, 0.0, 0.0, 3.0, 0.0, 0.0]);
+
+    engine.evaluate().expect("evaluate");
+    let unchanged = engine.get_geometry_buffers().expect("buffers");
+    assert!(unchanged.added_ids().is_empty() && unchanged.updated_ids().is_empty());
+    assert!(unchanged.positions().is_empty());
+
+    engine
+        .update_input_value("Length", Value::Number(4.5))
+        .expect("valid slider name");
+    engine.evaluate().expect("evaluate");
+    let updated = engine.get_geometry_buffers().expect("buffers");
+    let (node, line) = buffer_line(&updated).expect("updated line record");
+    assert_eq!(node, line_node);
+    assert!(updated.updated_ids().contains(&line_node));
+    assert!(updated.added_ids().is_empty());
+    assert_eq!(line[3], 4.5);
+}
+
+/// Returns the node id and positions of the first line record.
+fn buffer_line(buffers: &GeometryBuffers) -> Option<(u32, Vec<f32>)> {
+    let stride = buffers.record_stride();
+    buffers
+        .record_table()
+        .chunks_exact(stride)
+        .find(|record| record[1] == 1)
+        .map(|record| {
+            let start = record[2] as usize;
+            let len = record[3] as usize;
+            (record[0], buffers.positions()[start..start + len].to_vec())
+        })
+}
+
 #[test]
 fn line_sample_produces_curve_line() {
     let result = evaluate_sample(include_str!(concat!(
@@ -99,7 +294,7 @@ fn line_sample_produces_curve_line() {
     let curve_count = result
         .geometry
         .iter()
-        .filter(|entry| matches!(&entry.value, Value::CurveLine { .. }))
+        .filter(|entry| matches!(&entry.value, Value::Curve(CurveValue::Line(_))))
         .count();
     assert_eq!(curve_count, 1, "expected exactly one curve line");
 
@@ -107,13 +302,15 @@ fn line_sample_produces_curve_line() {
         .geometry
         .iter()
         .find_map(|entry| match &entry.value {
-            Value::CurveLine { p1, p2 } => Some((p1, p2)),
+            Value::Curve(CurveValue::Line(line)) => {
+                Some((line.start.to_array(), line.end.to_array()))
+            }
             _ => None,
         })
         .expect("line geometry present");
 
-    assert_point_close(line.0, [0.0, 0.0, 0.0]);
-    assert_point_close(line.1, [3.0, 0.0, 0.0]);
+    assert_point_close(&line.0, [0.0, 0.0, 0.0]);
+    assert_point_close(&line.1, [3.0, 0.0, 0.0]);
 }
 
 #[test]
@@ -144,12 +341,12 @@ fn line_sample_matches_expected_snapshot() {
     let curve_entry = result
         .geometry
         .iter()
-        .find(|entry| matches!(&entry.value, Value::CurveLine { .. }))
+        .find(|entry| matches!(&entry.value, Value::Curve(CurveValue::Line(_))))
         .expect("curve line present");
-    let expected = Value::CurveLine {
-        p1: [0.0, 0.0, 0.0],
-        p2: [3.0, 0.0, 0.0],
-    };
+    let expected = Value::Curve(CurveValue::Line(Line3::new(
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(3.0, 0.0, 0.0),
+    )));
 
     assert_value_close(&curve_entry.value, &expected, 1e-9);
 }
@@ -286,6 +483,153 @@ fn polyline_component_preserves_input_points_exactly() {
     }
 }
 
+#[test]
+fn galerij_applies_flatten_and_graft_output_mapping() {
+    let xml = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../web/testfiles/galerij.ghx"));
+    let graph = ghx_xml::parse_str(xml).expect("parse galerij");
+    let result = evaluator::evaluate(&graph, &ComponentRegistry::default()).expect("evaluate");
+
+    let mapped_node = |name: &str, key: &str, mapping: i64| {
+        graph
+            .nodes()
+            .iter()
+            .find(|node| {
+                node.name.as_deref() == Some(name)
+                    && node.meta(key) == Some(&MetaValue::Integer(mapping))
+                    && result.node_outputs.contains_key(&node.id)
+            })
+            .unwrap_or_else(|| panic!("{name} met {key} = {mapping} verwacht"))
+    };
+
+    // Series met een geflatte uitgang levert precies één tak {0}.
+    let series = mapped_node("Series", "output.S.mapping", 1);
+    let Some(Value::Tree(series_tree)) = result.node_outputs[&series.id].get("S") else {
+        panic!("Series S moet een datatree zijn");
+    };
+    let paths: Vec<String> = series_tree.paths().map(ToString::to_string).collect();
+    assert_eq!(paths, ["{0}"]);
+
+    // Merge met een gegrafte R-uitgang: elk item in een eigen tak. De legacy
+    // S-pin heeft geen modifier en houdt alle items in één tak.
+    let merge = mapped_node("Merge", "output.R.mapping", 2);
+    let outputs = &result.node_outputs[&merge.id];
+    let (Some(Value::Tree(grafted)), Some(Value::Tree(plain))) = (outputs.get("R"), outputs.get("S"))
+    else {
+        panic!("Merge levert datatrees op R en S");
+    };
+    assert_eq!(plain.branch_count(), 1);
+    assert_eq!(grafted.branch_count(), plain.item_count());
+    assert!(grafted.branches().all(|(path, items)| path.len() == 2 && items.len() == 1));
+}
+
+#[test]
+fn test_loft_grafts_circle_output() {
+    let xml = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../web/testfiles/test_loft.ghx"));
+    let graph = ghx_xml::parse_str(xml).expect("parse test_loft");
+    let result = evaluator::evaluate(&graph, &ComponentRegistry::default()).expect("evaluate");
+
+    let circle = graph
+        .nodes()
+        .iter()
+        .find(|node| node.meta("output.C.mapping") == Some(&MetaValue::Integer(2)))
+        .expect("gegrafte Circle-uitgang");
+    let Some(Value::Tree(tree)) = result.node_outputs[&circle.id].get("C") else {
+        panic!("gegrafte uitgang moet een datatree zijn");
+    };
+    assert!(tree.branch_count() > 1);
+    assert!(
+        tree.branches()
+            .all(|(_, items)| matches!(items.as_slice(), [Value::Curve(_)]))
+    );
+}
+
+#[test]
+fn nested_clusters_are_inlined_and_evaluated() {
+    let xml = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../tools/ghx-samples/nested_cluster.ghx"
+    ));
+    let graph = ghx_xml::parse_str(xml).expect("parse nested cluster");
+    let result = evaluator::evaluate(&graph, &ComponentRegistry::default()).expect("evaluate");
+    assert!(
+        result.errors.is_empty(),
+        "unexpected errors: {:?}",
+        result.errors
+    );
+
+    let number = graph
+        .nodes()
+        .iter()
+        .find(|node| node.nickname.as_deref() == Some("Result"))
+        .expect("number param present");
+    let outputs = result.node_outputs.get(&number.id).expect("number evaluated");
+    // (4 + 1) * 2 through the outer and the nested cluster.
+    assert!(
+        outputs
+            .values()
+            .any(|value| matches!(value, Value::Number(n) if (*n - 10.0).abs() < 1e-12))
+    );
+}
+
+#[test]
+fn internalised_point_param_evaluates_without_sources() {
+    let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<Archive name="Root">
+  <chunks count="1">
+    <chunk name="Definition">
+      <chunks count="1">
+        <chunk name="DefinitionObjects">
+          <chunks count="1">
+            <chunk name="Object" index="0">
+              <items count="1">
+                <item name="GUID" type_name="gh_guid" type_code="9">fbac3e32-f100-4292-8692-77240a42fd1a</item>
+              </items>
+              <chunks count="1">
+                <chunk name="Container">
+                  <chunks count="1">
+                    <chunk name="PersistentData">
+                      <chunks count="1">
+                        <chunk name="Branch" index="0">
+                          <chunks count="2">
+                            <chunk name="Item" index="0">
+                              <items count="1">
+                                <item name="Coordinate" type_name="gh_point3d" type_code="51"><X>1</X><Y>2</Y><Z>3</Z></item>
+                              </items>
+                            </chunk>
+                            <chunk name="Item" index="1">
+                              <items count="1">
+                                <item name="Coordinate" type_name="gh_point3d" type_code="51"><X>4</X><Y>5</Y><Z>6</Z></item>
+                              </items>
+                            </chunk>
+                          </chunks>
+                        </chunk>
+                      </chunks>
+                    </chunk>
+                  </chunks>
+                </chunk>
+              </chunks>
+            </chunk>
+          </chunks>
+        </chunk>
+      </chunks>
+    </chunk>
+  </chunks>
+</Archive>
+"#;
+    let result = evaluate_sample(xml);
+    assert!(
+        result.errors.is_empty(),
+        "unexpected errors: {:?}",
+        result.errors
+    );
+    let points = result
+        .geometry
+        .iter()
+        .filter(|entry| matches!(entry.value, Value::Point(_)))
+        .count();
+    assert_eq!(points, 2);
+}
+
 fn evaluate_sample(xml: &str) -> EvaluationResult {
     let graph = ghx_xml::parse_str(xml).expect("parse ghx");
     let registry = ComponentRegistry::default();
@@ -305,6 +649,10 @@ fn assert_value_close(actual: &Value, expected: &Value, tol: f64) {
             assert_point_close(lp1, *rp1);
             assert_point_close(lp2, *rp2);
         }
+        (Value::Curve(CurveValue::Line(left)), Value::Curve(CurveValue::Line(right))) => {
+            assert_point_close(&left.start.to_array(), right.start.to_array());
+            assert_point_close(&left.end.to_array(), right.end.to_array());
+        }
         (
             Value::Surface {
                 vertices: lv,
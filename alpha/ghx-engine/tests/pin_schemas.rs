@@ -0,0 +1,232 @@
+use std::fs;
+use std::path::Path;
+
+use ghx_engine::catalog::{Catalog, CatalogPin};
+use ghx_engine::components::schema::{PinSchema, render_pin_table};
+use ghx_engine::components::{ComponentRegistry, PinAccess};
+
+const NODELIST_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../nodelist");
+const PIN_TABLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/components/pin_table.rs");
+
+fn load_nodelist() -> Catalog {
+    let mut catalog = Catalog::new();
+    catalog
+        .add_nodelist_dir(Path::new(NODELIST_DIR))
+        .expect("read nodelist directory");
+    catalog
+}
+
+fn access_name(access: PinAccess) -> &'static str {
+    match access {
+        PinAccess::Item => "item",
+        PinAccess::List => "list",
+        PinAccess::Tree => "tree",
+    }
+}
+
+fn assert_pins_match(guid: &str, side: &str, schema: &[PinSchema], nodelist: &[CatalogPin]) {
+    let actual: Vec<_> = schema
+        .iter()
+        .map(|pin| (pin.nickname, pin.name, access_name(pin.access)))
+        .collect();
+    let expected: Vec<_> = nodelist
+        .iter()
+        .map(|pin| {
+            (
+                pin.nickname.as_str(),
+                pin.name.as_str(),
+                pin.access.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        actual, expected,
+        "{side} of {guid} differ from the nodelist; run `cargo run --bin pin_table`"
+    );
+
+    for (pin, listed) in schema.iter().zip(nodelist) {
+        assert!(
+            pin.optional || !listed.optional,
+            "{side} pin {} of {guid} is optional in the nodelist",
+            pin.nickname
+        );
+    }
+}
+
+#[test]
+fn pin_table_is_up_to_date() {
+    let rendered = render_pin_table(&ComponentRegistry::default(), &load_nodelist());
+    let on_disk = fs::read_to_string(PIN_TABLE).expect("read pin table");
+    assert!(
+        on_disk == rendered,
+        "src/components/pin_table.rs is stale; run `cargo run --bin pin_table`"
+    );
+}
+
+#[test]
+fn pin_schemas_match_nodelist() {
+    let registry = ComponentRegistry::default();
+    let catalog = load_nodelist();
+    assert!(
+        catalog.len() >= 1000,
+        "nodelist incomplete: {}",
+        catalog.len()
+    );
+
+    let mut with_schema = 0;
+    for (guid, _) in registry.guids() {
+        let schema = registry.schema(guid);
+        match catalog.lookup(guid) {
+            Some(entry) => {
+                let schema = schema.unwrap_or_else(|| panic!("no pin schema for {guid}"));
+                assert_pins_match(guid, "inputs", &schema.inputs, &entry.inputs);
+                assert_pins_match(guid, "outputs", &schema.outputs, &entry.outputs);
+                with_schema += 1;
+            }
+            None => assert!(schema.is_none(), "{guid} is not in the nodelist"),
+        }
+    }
+
+    assert_eq!(registry.schemas().len(), with_schema);
+}
+
+/// Pinnen die bewust van de nodelist afwijken: vlakken komen als lijst van
+/// drie punten binnen en mogen niet per item opgesplitst worden.
+const ACCESS_EXCEPTIONS: &[(&str, &str)] = &[
+    ("Deconstruct Point", "S"),
+    ("Vector Angle (Plane)", "P"),
+];
+
+#[test]
+fn declared_input_access_matches_schema() {
+    let registry = ComponentRegistry::default();
+
+    let mut checked = 0;
+    for (guid, kind) in registry.guids() {
+        let access = kind.input_access();
+        let Some(schema) = registry.schema(guid) else {
+            continue;
+        };
+        if access.is_empty() {
+            continue;
+        }
+        for (index, pin) in schema.inputs.iter().enumerate() {
+            if ACCESS_EXCEPTIONS.contains(&(kind.name(), pin.nickname)) {
+                continue;
+            }
+            let declared = access[index.min(access.len() - 1)];
+            assert_eq!(
+                pin.access,
+                declared,
+                "input {index} ({}) of {} ({guid})",
+                pin.nickname,
+                kind.name()
+            );
+        }
+        checked += 1;
+    }
+    assert!(checked > 0, "no component declares its input access");
+}
+
+/// Modules die vlakken, polylijnen of willekeurige geometrie nog als
+/// puntenlijst ontvangen en zelf over hun invoer itereren; zie
+/// `ComponentKind::input_access`.
+const SELF_MATCHING_MODULES: &[&str] = &[
+    "CurvePrimitive",
+    "CurveDivision",
+    "CurveAnalysis",
+    "CurveSpline",
+    "CurveUtil",
+    "SurfacePrimitive",
+    "SurfaceFreeform",
+    "SurfaceAnalysis",
+    "SurfaceSubd",
+    "SurfaceUtil",
+    "TransformAffine",
+    "TransformArray",
+    "TransformEuclidean",
+    "TransformUtil",
+    "VectorPoint",
+    "VectorPlane",
+    "VectorGrid",
+    "VectorField",
+    "SetsList",
+    "SetsSets",
+    "SetsTree",
+    "DisplayPreview",
+    "MeshPrimitive",
+    "MeshAnalysis",
+    "MeshTriangulation",
+    "ParamsGeometry",
+    "ParamsPrimitive",
+    "ParamsInput",
+    "ParamsUtil",
+];
+
+#[test]
+fn components_with_item_inputs_declare_access() {
+    let registry = ComponentRegistry::default();
+
+    for (guid, kind) in registry.guids() {
+        let debug = format!("{kind:?}");
+        let module = debug.split('(').next().unwrap_or_default();
+        if SELF_MATCHING_MODULES.contains(&module) {
+            continue;
+        }
+        let Some(schema) = registry.schema(guid) else {
+            continue;
+        };
+        if schema.inputs.iter().any(|pin| pin.access == PinAccess::Item) {
+            assert!(
+                !kind.input_access().is_empty(),
+                "{} ({guid}) has item inputs but no declared access",
+                kind.name()
+            );
+        }
+    }
+}
+
+#[test]
+fn defaults_and_optional_pins_name_existing_inputs() {
+    let registry = ComponentRegistry::default();
+
+    for (guid, kind) in registry.guids() {
+        let Some(schema) = registry.schema(guid) else {
+            continue;
+        };
+        let known = |name: &str| {
+            schema
+                .inputs
+                .iter()
+                .any(|pin| pin.nickname == name || pin.name == name)
+        };
+        for (nickname, _) in kind.input_defaults() {
+            assert!(
+                known(nickname),
+                "default for unknown input {nickname} of {} ({guid})",
+                kind.name()
+            );
+        }
+        for name in kind.optional_input_pins() {
+            assert!(
+                known(name),
+                "unknown optional input {name} of {} ({guid})",
+                kind.name()
+            );
+        }
+    }
+
+    let cull = registry
+        .schema("6eaffbb2-3392-441a-8556-2dc126aa8910")
+        .expect("cull duplicates schema");
+    let tolerance = cull
+        .inputs
+        .iter()
+        .find(|pin| pin.nickname == "T")
+        .expect("tolerance pin");
+    assert!(tolerance.optional);
+    assert_eq!(
+        tolerance.default.map(|default| default.to_value()),
+        Some(ghx_engine::graph::value::Value::Number(0.001))
+    );
+}
@@ -6,12 +6,35 @@ function toNumericOrNull(value) {
   return Number.isFinite(numeric) ? numeric : null;
 }
 
+// Control types the slider panel can render; digit scrollers behave like sliders.
+const SUPPORTED_CONTROL_TYPES = new Set([
+  'slider',
+  'toggle',
+  'value-list',
+  'digit-scroller',
+  'panel',
+  'colour-swatch',
+  'colour-picker',
+  'md-slider',
+  'graph-mapper',
+]);
+
+function toNumericPairs(value) {
+  if (!Array.isArray(value)) {
+    return [];
+  }
+  return value
+    .filter((pair) => Array.isArray(pair) && pair.length >= 2)
+    .map(([x, y]) => [toNumericOrNull(x) ?? 0, toNumericOrNull(y) ?? 0]);
+}
+
 function normalizeSliders(value) {
   if (!Array.isArray(value)) {
     return [];
   }
   return value
     .filter((entry) => entry && typeof entry === 'object')
+    .filter((entry) => SUPPORTED_CONTROL_TYPES.has(entry.type || 'slider'))
     .map((entry) => {
       const entryType = entry.type || 'slider';
       const base = {
@@ -52,6 +75,17 @@ function normalizeSliders(value) {
         base.items = normalizedItems;
         base.selectedIndex = selectedIndex;
         base.value = selectedIndex;
+      } else if (entryType === 'panel') {
+        base.value = typeof entry.text === 'string' ? entry.text : '';
+        base.multiline = Boolean(entry.multiline);
+      } else if (entryType === 'colour-swatch' || entryType === 'colour-picker') {
+        const color = Array.isArray(entry.color) ? entry.color : [];
+        base.value = [0, 1, 2].map((index) => toNumericOrNull(color[index]) ?? 0);
+      } else if (entryType === 'md-slider') {
+        const point = Array.isArray(entry.value) ? entry.value : [];
+        base.value = [toNumericOrNull(point[0]) ?? 0, toNumericOrNull(point[1]) ?? 0];
+      } else if (entryType === 'graph-mapper') {
+        base.value = toNumericPairs(entry.points);
       } else {
         base.min = toNumericOrNull(entry.min);
         base.max = toNumericOrNull(entry.max);
@@ -115,6 +149,10 @@ async function init() {
     loadGhx: engine.load_ghx.bind(engine),
     getSliders: engine.get_sliders.bind(engine),
     setSliderValue: engine.set_slider_value.bind(engine),
+    setInputText: engine.set_input_text.bind(engine),
+    setInputColour: engine.set_input_colour.bind(engine),
+    setInputPoint: engine.set_input_point.bind(engine),
+    setInputCurve: engine.set_input_curve.bind(engine),
     evaluate: engine.evaluate.bind(engine),
     getGeometry: engine.get_geometry.bind(engine),
     getErrors: engine.get_errors.bind(engine),
@@ -123,6 +161,10 @@ async function init() {
   const invokeLoadGhx = wasmApi.loadGhx;
   const invokeGetSliders = wasmApi.getSliders;
   const invokeSetSliderValue = wasmApi.setSliderValue;
+  const invokeSetInputText = wasmApi.setInputText;
+  const invokeSetInputColour = wasmApi.setInputColour;
+  const invokeSetInputPoint = wasmApi.setInputPoint;
+  const invokeSetInputCurve = wasmApi.setInputCurve;
   const invokeEvaluate = wasmApi.evaluate;
   const invokeGetGeometry = wasmApi.getGeometry;
   const invokeGetErrors = wasmApi.getErrors;
@@ -148,8 +190,27 @@ async function init() {
     }
   }
 
+  // Control type per id from the last snapshot, used to pick the setter.
+  const controlTypes = new Map();
+
   function applySliderValue(sliderId, value) {
-    invokeSetSliderValue(sliderId, value);
+    switch (controlTypes.get(String(sliderId))) {
+      case 'panel':
+        invokeSetInputText(sliderId, String(value));
+        break;
+      case 'colour-swatch':
+      case 'colour-picker':
+        invokeSetInputColour(sliderId, value[0], value[1], value[2]);
+        break;
+      case 'md-slider':
+        invokeSetInputPoint(sliderId, value[0], value[1]);
+        break;
+      case 'graph-mapper':
+        invokeSetInputCurve(sliderId, value);
+        break;
+      default:
+        invokeSetSliderValue(sliderId, value);
+    }
   }
 
   function loadGhxIntoEngine(contents) {
@@ -169,6 +230,10 @@ async function init() {
     }
 
     const sliders = normalizeSliders(sliderData);
+    controlTypes.clear();
+    for (const slider of sliders) {
+      controlTypes.set(slider.id, slider.type);
+    }
 
     if (replace) {
       ui.renderSliders(sliders);
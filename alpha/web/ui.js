@@ -325,6 +325,253 @@ function createValueListElement(list, handlers) {
   };
 }
 
+function createLabelRow(control, fallbackName) {
+  const labelRow = document.createElement('div');
+  labelRow.className = 'slider-label';
+
+  const nameSpan = document.createElement('span');
+  nameSpan.textContent = control.name ?? control.id ?? fallbackName;
+  const valueSpan = document.createElement('span');
+  labelRow.append(nameSpan, valueSpan);
+
+  return { labelRow, nameSpan, valueSpan };
+}
+
+function createPanelElement(panel, handlers) {
+  const wrapper = document.createElement('div');
+  wrapper.className = 'slider panel-control';
+  wrapper.dataset.sliderId = panel.id;
+
+  const { labelRow, nameSpan } = createLabelRow(panel, 'Panel');
+
+  const inputsRow = document.createElement('div');
+  inputsRow.className = 'slider-inputs';
+
+  const input = document.createElement(panel.multiline ? 'textarea' : 'input');
+  if (!panel.multiline) {
+    input.type = 'text';
+  }
+  input.value = panel.value ?? '';
+  input.setAttribute('aria-label', nameSpan.textContent);
+
+  input.addEventListener('change', (event) => {
+    if (typeof handlers.onSliderChange === 'function') {
+      handlers.onSliderChange(panel.id, event.target.value);
+    }
+  });
+
+  inputsRow.append(input);
+  wrapper.append(labelRow, inputsRow);
+
+  return {
+    element: wrapper,
+    controller: {
+      update(value) {
+        if (document.activeElement !== input) {
+          input.value = value ?? '';
+        }
+      },
+    },
+  };
+}
+
+function colourToHex(color) {
+  const channels = Array.isArray(color) ? color : [0, 0, 0];
+  return `#${channels
+    .slice(0, 3)
+    .map((channel) => {
+      const clamped = Math.min(255, Math.max(0, Math.round(Number(channel) || 0)));
+      return clamped.toString(16).padStart(2, '0');
+    })
+    .join('')}`;
+}
+
+function hexToColour(hex) {
+  const match = /^#?([0-9a-f]{2})([0-9a-f]{2})([0-9a-f]{2})$/i.exec(hex ?? '');
+  if (!match) {
+    return null;
+  }
+  return match.slice(1, 4).map((channel) => Number.parseInt(channel, 16));
+}
+
+function createColourElement(colour, handlers) {
+  const wrapper = document.createElement('div');
+  wrapper.className = 'slider colour-control';
+  wrapper.dataset.sliderId = colour.id;
+
+  const { labelRow, nameSpan, valueSpan } = createLabelRow(colour, 'Colour');
+
+  const inputsRow = document.createElement('div');
+  inputsRow.className = 'slider-inputs';
+
+  const input = document.createElement('input');
+  input.type = 'color';
+  input.setAttribute('aria-label', nameSpan.textContent);
+
+  const updateDisplay = (value) => {
+    const hex = colourToHex(value);
+    input.value = hex;
+    valueSpan.textContent = hex;
+  };
+
+  updateDisplay(colour.value);
+
+  input.addEventListener('change', (event) => {
+    const channels = hexToColour(event.target.value);
+    if (!channels) {
+      return;
+    }
+    updateDisplay(channels);
+    if (typeof handlers.onSliderChange === 'function') {
+      handlers.onSliderChange(colour.id, channels);
+    }
+  });
+
+  inputsRow.append(input);
+  wrapper.append(labelRow, inputsRow);
+
+  return {
+    element: wrapper,
+    controller: {
+      update(value) {
+        updateDisplay(value);
+      },
+    },
+  };
+}
+
+function createPointElement(point, handlers) {
+  const wrapper = document.createElement('div');
+  wrapper.className = 'slider point-control';
+  wrapper.dataset.sliderId = point.id;
+
+  const { labelRow, nameSpan, valueSpan } = createLabelRow(point, 'MD Slider');
+
+  const inputsRow = document.createElement('div');
+  inputsRow.className = 'slider-inputs';
+
+  const inputs = ['x', 'y'].map((axis) => {
+    const input = document.createElement('input');
+    input.type = 'number';
+    input.step = 'any';
+    input.setAttribute('aria-label', `${nameSpan.textContent} (${axis})`);
+    return input;
+  });
+
+  let committedValue = [0, 0];
+
+  const updateDisplay = (value) => {
+    const [x, y] = Array.isArray(value) ? value : [0, 0];
+    committedValue = [Number(x) || 0, Number(y) || 0];
+    inputs.forEach((input, index) => {
+      input.value = String(committedValue[index]);
+    });
+    valueSpan.textContent = `(${committedValue.map((c) => formatValue(c, 0.001)).join(', ')})`;
+  };
+
+  updateDisplay(point.value);
+
+  const commit = () => {
+    const value = inputs.map((input) => Number(input.value));
+    if (value.some((component) => !Number.isFinite(component))) {
+      updateDisplay(committedValue);
+      return;
+    }
+    updateDisplay(value);
+    if (typeof handlers.onSliderChange === 'function') {
+      handlers.onSliderChange(point.id, value);
+    }
+  };
+
+  inputs.forEach((input) => {
+    input.addEventListener('change', commit);
+  });
+
+  inputsRow.append(...inputs);
+  wrapper.append(labelRow, inputsRow);
+
+  return {
+    element: wrapper,
+    controller: {
+      update(value) {
+        updateDisplay(value);
+      },
+    },
+  };
+}
+
+function formatGraphPoints(points) {
+  return (Array.isArray(points) ? points : [])
+    .map(([x, y]) => `${x}, ${y}`)
+    .join('\n');
+}
+
+function parseGraphPoints(text) {
+  const points = [];
+  for (const line of String(text).split('\n')) {
+    if (!line.trim()) {
+      continue;
+    }
+    const parts = line.split(',').map((part) => Number(part.trim()));
+    if (parts.length !== 2 || parts.some((part) => !Number.isFinite(part))) {
+      return null;
+    }
+    points.push(parts);
+  }
+  return points;
+}
+
+function createGraphMapperElement(graph, handlers) {
+  const wrapper = document.createElement('div');
+  wrapper.className = 'slider graph-mapper-control';
+  wrapper.dataset.sliderId = graph.id;
+
+  const { labelRow, nameSpan, valueSpan } = createLabelRow(graph, 'Graph Mapper');
+
+  const inputsRow = document.createElement('div');
+  inputsRow.className = 'slider-inputs';
+
+  // Eén steunpunt per regel, als "x, y".
+  const input = document.createElement('textarea');
+  input.rows = 3;
+  input.setAttribute('aria-label', nameSpan.textContent);
+
+  let committedPoints = [];
+
+  const updateDisplay = (points) => {
+    committedPoints = Array.isArray(points) ? points : [];
+    input.value = formatGraphPoints(committedPoints);
+    valueSpan.textContent = `${committedPoints.length} punten`;
+  };
+
+  updateDisplay(graph.value);
+
+  input.addEventListener('change', (event) => {
+    const points = parseGraphPoints(event.target.value);
+    if (!points || points.length < 2) {
+      updateDisplay(committedPoints);
+      return;
+    }
+    if (typeof handlers.onSliderChange === 'function') {
+      handlers.onSliderChange(graph.id, points);
+    }
+  });
+
+  inputsRow.append(input);
+  wrapper.append(labelRow, inputsRow);
+
+  return {
+    element: wrapper,
+    controller: {
+      update(value) {
+        if (document.activeElement !== input) {
+          updateDisplay(value);
+        }
+      },
+    },
+  };
+}
+
 export function setupUi() {
   const canvas = document.getElementById('viewport');
   const fileInput = document.getElementById('ghx-input');
@@ -408,6 +655,14 @@ export function setupUi() {
         result = createToggleElement(control, handlers);
       } else if (control.type === 'value-list') {
         result = createValueListElement(control, handlers);
+      } else if (control.type === 'panel') {
+        result = createPanelElement(control, handlers);
+      } else if (control.type === 'colour-swatch' || control.type === 'colour-picker') {
+        result = createColourElement(control, handlers);
+      } else if (control.type === 'md-slider') {
+        result = createPointElement(control, handlers);
+      } else if (control.type === 'graph-mapper') {
+        result = createGraphMapperElement(control, handlers);
       } else {
         // Default to slider
         result = createSliderElement(control, handlers);